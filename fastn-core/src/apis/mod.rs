@@ -5,6 +5,7 @@ pub(crate) mod edit;
 pub(crate) mod edit_source;
 pub(crate) mod sync;
 pub(crate) mod sync2;
+pub(crate) mod translation;
 pub(crate) mod view_source;
 
 pub(crate) use self::edit::edit;
@@ -0,0 +1,24 @@
+pub async fn translation_status_page(
+    req: fastn_core::http::Request,
+) -> fastn_core::Result<fastn_core::http::Response> {
+    match translation_status_page_worker(req).await {
+        Ok(body) => Ok(fastn_core::http::ok(body)),
+        Err(err) => fastn_core::http::api_error(err.to_string()),
+    }
+}
+
+async fn translation_status_page_worker(
+    req: fastn_core::http::Request,
+) -> fastn_core::Result<Vec<u8>> {
+    let mut config = fastn_core::Config::read(None, false, Some(&req)).await?;
+    let translation_status_ftd = fastn_core::translation::dashboard(&config).await?;
+
+    let main_document = fastn_core::Document {
+        id: "translation-status.ftd".to_string(),
+        content: translation_status_ftd,
+        parent_path: config.root.as_str().to_string(),
+        package_name: config.package.name.clone(),
+    };
+
+    fastn_core::package::package_doc::read_ftd(&mut config, &main_document, "/", false, false).await
+}
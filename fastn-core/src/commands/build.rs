@@ -8,7 +8,25 @@ pub async fn build(
     fastn_core::utils::enable_parse_caching(true);
 
     tokio::fs::create_dir_all(config.build_dir()).await?;
-    let documents = get_documents_for_current_package(config).await?;
+    let mut documents = get_documents_for_current_package(config).await?;
+
+    // a translation package has every document of the original package, the ones not translated
+    // yet are shown from the original package
+    let translated_documents = if config.is_translation_package() {
+        fastn_core::translation::get_translated_documents(config).await?
+    } else {
+        Default::default()
+    };
+    for (id, translated) in translated_documents.iter() {
+        if let fastn_core::TranslatedDocument::Missing {
+            original: original @ fastn_core::File::Ftd(_),
+        } = translated
+        {
+            documents
+                .entry(id.to_string())
+                .or_insert_with(|| original.clone());
+        }
+    }
 
     // No need to build static files when file is passed during fastn_core build (no-static behaviour)
     let no_static: bool = file.is_some();
@@ -43,6 +61,17 @@ pub async fn build(
         }
     }
 
+    // images are optimised before the pages, `ftd.image` renders the variants of the image
+    if !no_static {
+        for main in documents.values() {
+            if let fastn_core::File::Image(image) = main {
+                if let Some(info) = fastn_core::web_image::optimise(config, image).await? {
+                    config.images.insert(image.id.clone(), info);
+                }
+            }
+        }
+    }
+
     for main in documents.values() {
         if file.is_some() && file != Some(main.get_id().as_str()) {
             continue;
@@ -92,10 +121,24 @@ pub async fn build(
                         continue;
                     }
                 }
-                let resp = fastn_core::package::package_doc::process_ftd(
-                    config, doc, base_url, no_static, test,
-                )
-                .await;
+                let resp = match translated_documents.get(doc.id.as_str()) {
+                    Some(translated) => {
+                        fastn_core::package::package_doc::process_translated_ftd(
+                            config,
+                            doc.id.as_str(),
+                            translated,
+                            base_url,
+                            no_static,
+                        )
+                        .await
+                    }
+                    None => {
+                        fastn_core::package::package_doc::process_ftd(
+                            config, doc, base_url, no_static, test,
+                        )
+                        .await
+                    }
+                };
                 match (resp, ignore_failed) {
                     (Ok(_), _) => (),
                     (_, true) => {
@@ -188,10 +231,15 @@ pub async fn build(
             .as_str(),
             start,
         );
+        for issue in std::mem::take(&mut config.accessibility_issues) {
+            eprintln!("{}", issue);
+        }
     }
 
     if !no_static {
         config.download_fonts().await?;
+        fastn_core::web_font::optimise(config).await?;
+        fastn_core::web_svg::update_pages(config).await?;
     }
     Ok(())
 }
@@ -3,6 +3,9 @@ pub async fn diff(
     files: Option<Vec<String>>,
     all: bool,
 ) -> fastn_core::Result<()> {
+    if config.is_git_backed() {
+        return fastn_core::git::diff(config, files).await;
+    }
     let snapshots = fastn_core::snapshot::get_latest_snapshots(&config.root).await?;
     let all = all || files.is_some();
     let documents = if let Some(ref files) = files {
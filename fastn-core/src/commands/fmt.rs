@@ -0,0 +1,51 @@
+pub const COMMAND: &str = "fmt";
+
+pub fn command() -> clap::Command {
+    clap::Command::new(COMMAND)
+        .about("Format the ftd documents of this package")
+        .arg(clap::arg!(file: <FILE>... "The file(s) to format (leave empty to format entire package)").required(false))
+        .arg(clap::arg!(--check "Only show the documents that are not formatted, fails if there are any"))
+}
+
+pub async fn handle_command(matches: &clap::ArgMatches) -> fastn_core::Result<()> {
+    use fastn_core::utils::ValueOf;
+
+    let config = fastn_core::Config::read(None, false, None).await?;
+    let only = matches
+        .values_of_("file")
+        .into_iter()
+        .map(|v| v.trim_start_matches("./").to_string())
+        .collect::<Vec<_>>();
+    let check = matches.get_flag("check");
+
+    let mut unformatted = vec![];
+    for file in config.get_files(&config.package).await? {
+        let document = match file {
+            fastn_core::File::Ftd(document) => document,
+            _ => continue,
+        };
+        if !only.is_empty() && !only.contains(&document.id) {
+            continue;
+        }
+        let formatted = ftd::p1::format(document.content.as_str(), document.id.as_str())?;
+        if formatted.eq(&document.content) {
+            continue;
+        }
+        if check {
+            println!("Not formatted: {}", document.id);
+            println!("{}", diffy::create_patch(&document.content, &formatted));
+        } else {
+            fastn_core::utils::update1(&config.root, &document.id, formatted.as_bytes()).await?;
+            println!("Formatted: {}", document.id);
+        }
+        unformatted.push(document.id);
+    }
+
+    if check && !unformatted.is_empty() {
+        return fastn_core::usage_error(format!(
+            "{} document(s) not formatted, run `fastn fmt` to format them",
+            unformatted.len()
+        ));
+    }
+    Ok(())
+}
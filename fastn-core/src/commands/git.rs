@@ -0,0 +1,42 @@
+pub const COMMAND: &str = "git";
+
+pub fn command() -> clap::Command {
+    clap::Command::new(COMMAND)
+        .about("Move history between fastn and git")
+        .subcommand_required(true)
+        .subcommand(
+            clap::Command::new("export")
+                .about("Export fastn history and CRs as git branches")
+                .arg(
+                    clap::arg!(--prefix <PREFIX> "Prefix of the exported branch names")
+                        .default_value("fastn/"),
+                ),
+        )
+        .subcommand(
+            clap::Command::new("import")
+                .about("Import history of a git branch as fastn history")
+                .arg(clap::arg!(rev: [REV] "The branch or commit to import").default_value("HEAD")),
+        )
+        .hide(true) // hidden since the feature is not being released yet.
+}
+
+pub async fn handle_command(matches: &clap::ArgMatches) -> fastn_core::Result<()> {
+    use fastn_core::utils::ValueOf;
+
+    let config = fastn_core::Config::read(None, true, None).await?;
+    match matches.subcommand() {
+        Some(("export", matches)) => {
+            let branches =
+                fastn_core::git::export(&config, matches.value_of_("prefix").unwrap()).await?;
+            for branch in branches {
+                println!("Exported: {}", branch);
+            }
+        }
+        Some(("import", matches)) => {
+            let count = fastn_core::git::import(&config, matches.value_of_("rev").unwrap()).await?;
+            println!("Imported {} commit(s)", count);
+        }
+        _ => unreachable!("clap ensures a subcommand is present"),
+    }
+    Ok(())
+}
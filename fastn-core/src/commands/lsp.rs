@@ -0,0 +1,12 @@
+pub const COMMAND: &str = "lsp";
+
+pub fn command() -> clap::Command {
+    clap::Command::new(COMMAND)
+        .about("Start the language server for ftd documents, talks over stdio")
+        .hide(true) // hidden since the feature is not being released yet.
+}
+
+pub async fn handle_command(_matches: &clap::ArgMatches) -> fastn_core::Result<()> {
+    let config = fastn_core::Config::read(None, false, None).await?;
+    fastn_core::lsp::serve(config).await
+}
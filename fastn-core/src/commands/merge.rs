@@ -22,30 +22,273 @@ pub async fn merge(
 }
 
 async fn merge_cr_into_cr(
-    _config: &fastn_core::Config,
-    _src: usize,
-    _dest: usize,
-    _file: Option<&str>,
-) -> fastn_core::Result<()> {
-    unimplemented!()
-}
-
-async fn merge_main_into_cr(
     config: &fastn_core::Config,
+    src: usize,
     dest: usize,
     file: Option<&str>,
 ) -> fastn_core::Result<()> {
     use itertools::Itertools;
     use sha2::Digest;
 
-    let remote_manifest: std::collections::BTreeMap<String, fastn_core::history::FileEdit> = config
-        .get_remote_manifest(true)
-        .await?
-        .into_iter()
-        .filter(|(k, _)| !(k.starts_with("-/") || k.starts_with(".tracks/")))
-        .collect();
-    let cr_manifest = config.get_cr_manifest(dest).await?;
-    let (cr_track_manifest, cr_file_manifest) = cr_manifest.into_iter().fold(
+    if src.eq(&dest) {
+        return fastn_core::usage_error(format!("Can't merge CR#{} into itself", src));
+    }
+
+    let (src_track_manifest, src_file_manifest) =
+        split_cr_manifest(config.get_cr_manifest(src).await?);
+    let (dest_track_manifest, dest_file_manifest) =
+        split_cr_manifest(config.get_cr_manifest(dest).await?);
+
+    // meta and about of a CR belong to that CR only, they are not merged
+    let src_cr_own_files = [
+        config.path_without_root(&config.cr_meta_path(src))?,
+        config.path_without_root(&config.cr_about_path(src))?,
+    ];
+    let src_deleted_files = config.path_without_root(&config.cr_deleted_file_path(src))?;
+    let dest_deleted_files = config.path_without_root(&config.cr_deleted_file_path(dest))?;
+
+    let mut new_file_status: std::collections::BTreeMap<
+        String,
+        fastn_core::sync_utils::FileStatus,
+    > = Default::default();
+    let mut conflicted_file_status = vec![];
+
+    for (src_cr_file_name, src_cr_file_edit) in src_file_manifest.iter() {
+        if src_cr_file_edit.is_deleted() || src_cr_own_files.contains(src_cr_file_name) {
+            continue;
+        }
+
+        if src_cr_file_name.eq(&src_deleted_files) {
+            // files deleted in src CR are marked deleted in dest CR as well, unless dest CR has
+            // edited them
+            let src_deleted_list = fastn_core::cr::resolve_cr_deleted(
+                tokio::fs::read_to_string(
+                    config.history_path(src_cr_file_name.as_str(), src_cr_file_edit.version),
+                )
+                .await?
+                .as_str(),
+                src,
+            )
+            .await?;
+            let (dest_deleted_list, dest_deleted_file_edit) =
+                match dest_file_manifest.get(&dest_deleted_files) {
+                    Some(file_edit) if !file_edit.is_deleted() => (
+                        fastn_core::cr::resolve_cr_deleted(
+                            tokio::fs::read_to_string(
+                                config.history_path(dest_deleted_files.as_str(), file_edit.version),
+                            )
+                            .await?
+                            .as_str(),
+                            dest,
+                        )
+                        .await?,
+                        Some(file_edit),
+                    ),
+                    _ => (vec![], None),
+                };
+            let mut dest_deleted_map = dest_deleted_list
+                .into_iter()
+                .map(|v| (v.filename.to_string(), v))
+                .collect::<std::collections::BTreeMap<String, fastn_core::cr::CRDeleted>>();
+            let mut changed = false;
+            for src_deleted in src_deleted_list {
+                if file.map(|v| src_deleted.filename.ne(v)).unwrap_or(false)
+                    || dest_deleted_map.contains_key(&src_deleted.filename)
+                {
+                    continue;
+                }
+                let dest_cr_file_name = config
+                    .path_without_root(&config.cr_path(dest).join(src_deleted.filename.as_str()))?;
+                if let Some(dest_file_edit) = dest_file_manifest.get(&dest_cr_file_name) {
+                    if !dest_file_edit.is_deleted() {
+                        let content = tokio::fs::read(
+                            config.history_path(dest_cr_file_name.as_str(), dest_file_edit.version),
+                        )
+                        .await?;
+                        conflicted_file_status.push(fastn_core::sync_utils::FileStatus::Update {
+                            path: dest_cr_file_name,
+                            content,
+                            version: dest_file_edit.version,
+                            status: fastn_core::sync_utils::Status::CloneEditedRemoteDeleted(
+                                src_deleted.version,
+                            ),
+                        });
+                        continue;
+                    }
+                }
+                changed = true;
+                dest_deleted_map.insert(src_deleted.filename.to_string(), src_deleted);
+            }
+            if changed {
+                let content = fastn_core::cr::generate_deleted_files_content(
+                    dest_deleted_map.into_values().collect_vec().as_slice(),
+                )
+                .into_bytes();
+                new_file_status.insert(
+                    dest_deleted_files.to_string(),
+                    match dest_deleted_file_edit {
+                        Some(file_edit) => fastn_core::sync_utils::FileStatus::Update {
+                            path: dest_deleted_files.to_string(),
+                            content,
+                            version: file_edit.version,
+                            status: fastn_core::sync_utils::Status::NoConflict,
+                        },
+                        None => fastn_core::sync_utils::FileStatus::Add {
+                            path: dest_deleted_files.to_string(),
+                            content,
+                            status: fastn_core::sync_utils::Status::NoConflict,
+                        },
+                    },
+                );
+            }
+            continue;
+        }
+
+        let filename = fastn_core::cr::cr_path_to_file_name(src, src_cr_file_name.as_str())?;
+        if let Some(file) = file {
+            if filename.ne(file) {
+                continue;
+            }
+        }
+        let dest_cr_file_name =
+            config.path_without_root(&config.cr_path(dest).join(filename.as_str()))?;
+
+        let theirs_content_bytes = tokio::fs::read(
+            config.history_path(src_cr_file_name.as_str(), src_cr_file_edit.version),
+        )
+        .await?;
+        let src_tracked_version =
+            get_tracked_version(config, &src_track_manifest, src_cr_file_name, &filename).await?;
+
+        let dest_file_edit = match dest_file_manifest.get(&dest_cr_file_name) {
+            Some(file_edit) if !file_edit.is_deleted() => file_edit,
+            _ => {
+                // Not present in dest CR, so add it there along with its tracking info
+                new_file_status.insert(
+                    dest_cr_file_name.to_string(),
+                    fastn_core::sync_utils::FileStatus::Add {
+                        path: dest_cr_file_name.to_string(),
+                        content: theirs_content_bytes,
+                        status: fastn_core::sync_utils::Status::NoConflict,
+                    },
+                );
+                if let Some(version) = src_tracked_version {
+                    let track_file_path_str = config.path_without_root(
+                        &config.track_path(&config.root.join(dest_cr_file_name.as_str())),
+                    )?;
+                    new_file_status.insert(
+                        track_file_path_str.to_string(),
+                        fastn_core::sync_utils::FileStatus::Add {
+                            path: track_file_path_str,
+                            content: fastn_core::track::generate_tracking_info_content(&[
+                                fastn_core::track::TrackingInfo::new(
+                                    filename.as_str(),
+                                    version,
+                                    None,
+                                ),
+                            ])
+                            .into_bytes(),
+                            status: fastn_core::sync_utils::Status::NoConflict,
+                        },
+                    );
+                }
+                continue;
+            }
+        };
+
+        let ours_content_bytes = tokio::fs::read(
+            config.history_path(dest_cr_file_name.as_str(), dest_file_edit.version),
+        )
+        .await?;
+        if sha2::Sha256::digest(&ours_content_bytes)
+            .eq(&sha2::Sha256::digest(&theirs_content_bytes))
+        {
+            continue;
+        }
+
+        // Both the CRs are based on some version of the file in main, which is their common
+        // ancestor. If the CRs track different versions, dest's version is used as ancestor, so
+        // the changes in main since then are brought in along with the changes of src CR.
+        let dest_tracked_version =
+            get_tracked_version(config, &dest_track_manifest, &dest_cr_file_name, &filename)
+                .await?;
+        let ancestor_content = match dest_tracked_version.or(src_tracked_version) {
+            Some(version) => {
+                tokio::fs::read_to_string(config.history_path(filename.as_str(), version)).await
+            }
+            // Added in both the CRs
+            None => Ok("".to_string()),
+        };
+        let (ancestor_content, ours_content, theirs_content) = match (
+            ancestor_content,
+            String::from_utf8(ours_content_bytes.clone()),
+            String::from_utf8(theirs_content_bytes),
+        ) {
+            (Ok(ancestor), Ok(ours), Ok(theirs)) => (ancestor, ours, theirs),
+            _ => {
+                // binary file like images, can't resolve conflict
+                conflicted_file_status.push(fastn_core::sync_utils::FileStatus::Update {
+                    path: dest_cr_file_name.to_string(),
+                    content: ours_content_bytes,
+                    version: dest_file_edit.version,
+                    status: fastn_core::sync_utils::Status::Conflict(src_cr_file_edit.version),
+                });
+                continue;
+            }
+        };
+
+        match fastn_core::conflict::merge(&ancestor_content, &ours_content, &theirs_content) {
+            Ok(data) => {
+                new_file_status.insert(
+                    dest_cr_file_name.to_string(),
+                    fastn_core::sync_utils::FileStatus::Update {
+                        path: dest_cr_file_name.to_string(),
+                        content: data.into_bytes(),
+                        version: dest_file_edit.version,
+                        status: fastn_core::sync_utils::Status::NoConflict,
+                    },
+                );
+            }
+            Err(marked_content) => {
+                // Overlapping hunks, these need to be resolved using `fastn resolve-conflict`
+                write_conflict(config, dest_cr_file_name.as_str(), marked_content.as_str()).await?;
+                conflicted_file_status.push(fastn_core::sync_utils::FileStatus::Update {
+                    path: dest_cr_file_name.to_string(),
+                    content: ours_content_bytes,
+                    version: dest_file_edit.version,
+                    status: fastn_core::sync_utils::Status::Conflict(src_cr_file_edit.version),
+                });
+            }
+        }
+    }
+
+    conflicted_file_status
+        .iter()
+        .map(|v| fastn_core::commands::sync_status::print_status(v, false))
+        .collect_vec();
+
+    // files merged without conflict are synced, the conflicted ones are left for
+    // `fastn resolve-conflict`
+    let changed_files = new_file_status
+        .into_values()
+        .filter_map(|v| v.sync_request(None))
+        .collect_vec();
+    if !changed_files.is_empty() {
+        fastn_core::apis::sync2::do_sync(config, changed_files.as_slice()).await?;
+    }
+    Ok(())
+}
+
+/// Splits the manifest of a CR into the manifest of its tracking files and the manifest of
+/// rest of its files.
+#[allow(clippy::type_complexity)]
+fn split_cr_manifest(
+    cr_manifest: std::collections::BTreeMap<String, fastn_core::history::FileEdit>,
+) -> (
+    std::collections::HashMap<String, fastn_core::history::FileEdit>,
+    std::collections::HashMap<String, fastn_core::history::FileEdit>,
+) {
+    cr_manifest.into_iter().fold(
         (
             std::collections::HashMap::new(),
             std::collections::HashMap::new(),
@@ -58,7 +301,64 @@ async fn merge_main_into_cr(
             }
             (cr_track_status, cr_file_status)
         },
+    )
+}
+
+/// Returns the version of `filename` in main which `cr_file_name` tracks, if any.
+async fn get_tracked_version(
+    config: &fastn_core::Config,
+    cr_track_manifest: &std::collections::HashMap<String, fastn_core::history::FileEdit>,
+    cr_file_name: &str,
+    filename: &str,
+) -> fastn_core::Result<Option<i32>> {
+    let track_file_path_str =
+        config.path_without_root(&config.track_path(&config.root.join(cr_file_name)))?;
+    let track_file_edit = match cr_track_manifest.get(&track_file_path_str) {
+        Some(file_edit) if !file_edit.is_deleted() => file_edit,
+        _ => return Ok(None),
+    };
+    let track_file_path =
+        config.history_path(track_file_path_str.as_str(), track_file_edit.version);
+    Ok(fastn_core::track::get_tracking_info_(&track_file_path)
+        .await?
+        .into_iter()
+        .find(|v| v.filename.eq(filename))
+        .map(|v| v.version))
+}
+
+/// Stores the merged content of `path`, with conflicting hunks surrounded by conflict markers,
+/// in `.fastn/merge` for `fastn resolve-conflict` to resolve.
+async fn write_conflict(
+    config: &fastn_core::Config,
+    path: &str,
+    marked_content: &str,
+) -> fastn_core::Result<()> {
+    fastn_core::utils::update(config.merge_dir().join(path), marked_content.as_bytes()).await?;
+    println!(
+        "{} has {} conflicting hunk(s), Help: Use `fastn resolve-conflict {}`",
+        path,
+        fastn_core::conflict::hunks(marked_content).len(),
+        path
     );
+    Ok(())
+}
+
+async fn merge_main_into_cr(
+    config: &fastn_core::Config,
+    dest: usize,
+    file: Option<&str>,
+) -> fastn_core::Result<()> {
+    use itertools::Itertools;
+    use sha2::Digest;
+
+    let remote_manifest: std::collections::BTreeMap<String, fastn_core::history::FileEdit> = config
+        .get_remote_manifest(true)
+        .await?
+        .into_iter()
+        .filter(|(k, _)| !(k.starts_with("-/") || k.starts_with(".tracks/")))
+        .collect();
+    let (cr_track_manifest, cr_file_manifest) =
+        split_cr_manifest(config.get_cr_manifest(dest).await?);
     let mut conflicted_file_status = vec![];
     let deleted_files_path = config.cr_deleted_file_path(dest);
     let deleted_file_str = config.path_without_root(&deleted_files_path)?;
@@ -158,7 +458,7 @@ async fn merge_main_into_cr(
 
         // get corresponding track file
         let track_file_path_str =
-            config.path_without_root(&config.track_dir().join(cr_file_path))?;
+            config.path_without_root(&config.track_path(&config.root.join(cr_file_path)))?;
         let track_file_edit = match cr_track_manifest.get(&track_file_path_str) {
             Some(file_edit) => file_edit,
             _ => {
@@ -275,10 +575,7 @@ async fn merge_main_into_cr(
 
         let ours_content = String::from_utf8(ours_content_bytes.clone())?;
 
-        match diffy::MergeOptions::new()
-            .set_conflict_style(diffy::ConflictStyle::Merge)
-            .merge(&ancestor_content, &ours_content, &theirs_content)
-        {
+        match fastn_core::conflict::merge(&ancestor_content, &ours_content, &theirs_content) {
             Ok(data) => {
                 new_file_status.insert(
                     cr_file_path.to_string(),
@@ -303,10 +600,11 @@ async fn merge_main_into_cr(
                     },
                 );
             }
-            Err(_) => {
-                // Can't resolve conflict
+            Err(marked_content) => {
+                // Overlapping hunks, these need to be resolved using `fastn resolve-conflict`
+                write_conflict(config, cr_file_path, marked_content.as_str()).await?;
                 conflicted_file_status.push(fastn_core::sync_utils::FileStatus::Update {
-                    path: filename.to_string(),
+                    path: cr_file_path.to_string(),
                     content: ours_content_bytes.clone(),
                     version: track_info.version,
                     status: fastn_core::sync_utils::Status::Conflict(file_edit.version),
@@ -320,11 +618,13 @@ async fn merge_main_into_cr(
         .map(|v| fastn_core::commands::sync_status::print_status(v, false))
         .collect_vec();
 
-    if conflicted_file_status.is_empty() {
-        let changed_files = new_file_status
-            .into_values()
-            .filter_map(|v| v.sync_request(None))
-            .collect_vec();
+    // files merged without conflict are synced, the conflicted ones are left for
+    // `fastn resolve-conflict`
+    let changed_files = new_file_status
+        .into_values()
+        .filter_map(|v| v.sync_request(None))
+        .collect_vec();
+    if !changed_files.is_empty() {
         fastn_core::apis::sync2::do_sync(config, changed_files.as_slice()).await?;
     }
     Ok(())
@@ -345,21 +645,8 @@ async fn merge_cr_into_main(
         .into_iter()
         .filter(|(k, _)| !(k.starts_with("-/") || k.starts_with(".tracks/")))
         .collect();
-    let cr_manifest = config.get_cr_manifest(src).await?;
-    let (cr_track_manifest, cr_file_manifest) = cr_manifest.into_iter().fold(
-        (
-            std::collections::HashMap::new(),
-            std::collections::HashMap::new(),
-        ),
-        |(mut cr_track_status, mut cr_file_status), (file, file_edit)| {
-            if file.starts_with(".tracks/") {
-                cr_track_status.insert(file, file_edit);
-            } else {
-                cr_file_status.insert(file, file_edit);
-            }
-            (cr_track_status, cr_file_status)
-        },
-    );
+    let (cr_track_manifest, cr_file_manifest) =
+        split_cr_manifest(config.get_cr_manifest(src).await?);
     let cr_statuses = {
         let mut cr_statuses = cr_file_manifest
             .iter()
@@ -479,7 +766,7 @@ async fn merge_cr_into_main(
         }
 
         let cr_file_path = config.history_path(cr_file_name.as_str(), cr_file_edit.version);
-        let filename = fastn_core::cr::cr_path_to_file_name(src, cr_file_path.as_str())?;
+        let filename = fastn_core::cr::cr_path_to_file_name(src, cr_file_name.as_str())?;
         if let Some(file) = file {
             if filename.ne(file) {
                 continue;
@@ -505,7 +792,7 @@ async fn merge_cr_into_main(
         };
 
         let track_file_path_str =
-            config.path_without_root(&config.track_dir().join(cr_file_name))?;
+            config.path_without_root(&config.track_path(&config.root.join(cr_file_name)))?;
         let track_file_edit = match cr_track_manifest.get(&track_file_path_str) {
             Some(file_edit) if !file_edit.is_deleted() => file_edit,
             _ => {
@@ -522,9 +809,7 @@ async fn merge_cr_into_main(
             }
         };
 
-        let ours_content_bytes =
-            tokio::fs::read(config.history_path(cr_file_path.as_str(), cr_file_edit.version))
-                .await?;
+        let ours_content_bytes = cr_file_content.clone();
 
         let track_file_path =
             config.history_path(track_file_path_str.as_str(), track_file_edit.version);
@@ -614,10 +899,7 @@ async fn merge_cr_into_main(
 
         let ours_content = String::from_utf8(ours_content_bytes.clone())?;
 
-        match diffy::MergeOptions::new()
-            .set_conflict_style(diffy::ConflictStyle::Merge)
-            .merge(&ancestor_content, &ours_content, &theirs_content)
-        {
+        match fastn_core::conflict::merge(&ancestor_content, &ours_content, &theirs_content) {
             Ok(data) => {
                 new_file_status.insert(
                     filename.to_string(),
@@ -629,8 +911,9 @@ async fn merge_cr_into_main(
                     },
                 );
             }
-            Err(_) => {
-                // Can't resolve conflict
+            Err(marked_content) => {
+                // Overlapping hunks, these need to be resolved using `fastn resolve-conflict`
+                write_conflict(config, filename.as_str(), marked_content.as_str()).await?;
                 conflicted_file_status.push(fastn_core::sync_utils::FileStatus::Update {
                     path: filename.to_string(),
                     content: ours_content_bytes.clone(),
@@ -647,18 +930,33 @@ async fn merge_cr_into_main(
         .map(|v| fastn_core::commands::sync_status::print_status(v, false))
         .collect_vec();
 
-    if conflicted_file_status.is_empty() {
-        let mut sync_request_files = new_file_status
-            .into_values()
-            .filter_map(|v| v.sync_request(Some(src)))
-            .collect_vec();
-        sync_request_files.extend(cr_statuses.into_iter().filter_map(|v| v.sync_request(None)));
-        if file.is_none() {
-            let about_status = add_close_cr_status(config, src, &cr_file_manifest).await?;
-            if let Some(sync_req) = about_status.sync_request(None) {
-                sync_request_files.push(sync_req);
-            }
+    // files merged without conflict are synced and removed from the CR, the conflicted ones are
+    // kept in the CR, which stays open until they are resolved
+    let mut merged_cr_files = vec![];
+    for filename in new_file_status.keys() {
+        merged_cr_files.push(config.path_without_root(&config.cr_path(src).join(filename))?);
+        merged_cr_files.push(
+            config.path_without_root(&config.track_path(&config.cr_path(src).join(filename)))?,
+        );
+    }
+    let merged_all = conflicted_file_status.is_empty();
+    let mut sync_request_files = new_file_status
+        .into_values()
+        .filter_map(|v| v.sync_request(Some(src)))
+        .collect_vec();
+    sync_request_files.extend(
+        cr_statuses
+            .into_iter()
+            .filter(|v| merged_all || merged_cr_files.contains(&v.get_file_path()))
+            .filter_map(|v| v.sync_request(None)),
+    );
+    if merged_all && file.is_none() {
+        let about_status = add_close_cr_status(config, src, &cr_file_manifest).await?;
+        if let Some(sync_req) = about_status.sync_request(None) {
+            sync_request_files.push(sync_req);
         }
+    }
+    if !sync_request_files.is_empty() {
         fastn_core::apis::sync2::do_sync(config, sync_request_files.as_slice()).await?;
     }
 
@@ -819,3 +1117,134 @@ async fn add_close_cr_status(
     fastn_core::commands::sync2::sync(config, changed_files).await?;
     Ok(())
 }*/
+
+#[cfg(test)]
+mod tests {
+    /// Creates a package in a temporary directory, with an empty history.
+    async fn package(name: &str) -> fastn_core::Config {
+        let root = camino::Utf8PathBuf::try_from(std::env::temp_dir())
+            .unwrap()
+            .join(format!("fastn-merge-{}-{}", name, std::process::id()));
+        if root.exists() {
+            tokio::fs::remove_dir_all(&root).await.unwrap();
+        }
+        fastn_core::utils::update(
+            &root.join("FASTN.ftd"),
+            b"-- import: fastn\n\n-- fastn.package: merge-test\n",
+        )
+        .await
+        .unwrap();
+        fastn_core::utils::update(
+            &root.join(".remote-state/history.ftd"),
+            b"-- import: fastn\n",
+        )
+        .await
+        .unwrap();
+        fastn_core::Config::read(Some(root.to_string()), false, None)
+            .await
+            .unwrap()
+    }
+
+    async fn add(config: &fastn_core::Config, files: &[(String, String)]) {
+        let files = files
+            .iter()
+            .map(
+                |(path, content)| fastn_core::apis::sync2::SyncRequestFile::Add {
+                    path: path.to_string(),
+                    content: content.as_bytes().to_vec(),
+                    src_cr: None,
+                },
+            )
+            .collect::<Vec<_>>();
+        fastn_core::apis::sync2::do_sync(config, files.as_slice())
+            .await
+            .unwrap();
+    }
+
+    /// Adds `files` to CR `cr_number`, each of them tracking the latest version of the file
+    /// in main.
+    async fn add_to_cr(config: &fastn_core::Config, cr_number: usize, files: &[(&str, &str)]) {
+        let manifest = config.get_remote_manifest(false).await.unwrap();
+        let mut cr_files = vec![];
+        for (filename, content) in files {
+            let cr_file = format!("{}/{}", fastn_core::cr::cr_path(cr_number), filename);
+            cr_files.push((cr_file.clone(), content.to_string()));
+            if let Some(file_edit) = manifest.get(*filename) {
+                cr_files.push((
+                    config
+                        .path_without_root(&config.track_path(&config.root.join(cr_file)))
+                        .unwrap(),
+                    fastn_core::track::generate_tracking_info_content(&[
+                        fastn_core::track::TrackingInfo::new(filename, file_edit.version, None),
+                    ]),
+                ));
+            }
+        }
+        add(config, cr_files.as_slice()).await;
+    }
+
+    async fn cr_content(config: &fastn_core::Config, cr_number: usize, filename: &str) -> String {
+        let cr_file = format!("{}/{}", fastn_core::cr::cr_path(cr_number), filename);
+        let file_edit = config
+            .get_cr_manifest(cr_number)
+            .await
+            .unwrap()
+            .remove(&cr_file)
+            .unwrap();
+        tokio::fs::read_to_string(config.history_path(cr_file.as_str(), file_edit.version))
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn merge_cr_into_cr() {
+        let config = package("cr-into-cr").await;
+        add(
+            &config,
+            &[
+                ("index.ftd".to_string(), "a\nb\nc\nd\n".to_string()),
+                ("conflict.ftd".to_string(), "x\n".to_string()),
+            ],
+        )
+        .await;
+        add_to_cr(
+            &config,
+            1,
+            &[
+                ("index.ftd", "A\nb\nc\nd\n"),
+                ("conflict.ftd", "x1\n"),
+                ("new.ftd", "new\n"),
+            ],
+        )
+        .await;
+        add_to_cr(
+            &config,
+            2,
+            &[("index.ftd", "a\nb\nc\nD\n"), ("conflict.ftd", "x2\n")],
+        )
+        .await;
+
+        super::merge(&config, Some("1"), "2", None).await.unwrap();
+
+        // non-overlapping changes are merged and files added in CR#1 are added to CR#2, even
+        // though conflict.ftd is in conflict
+        assert_eq!(
+            cr_content(&config, 2, "index.ftd").await,
+            "A\nb\nc\nD\n".to_string()
+        );
+        assert_eq!(cr_content(&config, 2, "new.ftd").await, "new\n".to_string());
+        assert_eq!(
+            cr_content(&config, 2, "conflict.ftd").await,
+            "x2\n".to_string()
+        );
+        let marked = tokio::fs::read_to_string(config.merge_dir().join("-/2/conflict.ftd"))
+            .await
+            .unwrap();
+        assert_eq!(
+            fastn_core::conflict::hunks(marked.as_str()),
+            vec![("x2\n".to_string(), "x1\n".to_string())]
+        );
+
+        tokio::fs::remove_dir_all(&config.root).await.unwrap();
+    }
+}
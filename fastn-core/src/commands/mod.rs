@@ -7,6 +7,9 @@ pub mod create_cr;
 pub mod create_package;
 pub mod diff;
 pub mod edit;
+pub mod fmt;
+pub mod git;
+pub mod lsp;
 pub mod mark_resolved;
 pub mod mark_upto_date;
 pub mod merge;
@@ -21,5 +24,6 @@ pub mod stop_tracking;
 pub mod sync;
 pub mod sync2;
 pub mod sync_status;
+pub mod translation;
 pub mod translation_status;
 pub mod update;
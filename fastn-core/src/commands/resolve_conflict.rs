@@ -1,3 +1,4 @@
+#[allow(clippy::too_many_arguments)]
 pub async fn resolve_conflict(
     config: &fastn_core::Config,
     path: &str,
@@ -6,6 +7,7 @@ pub async fn resolve_conflict(
     print: bool,
     revive_it: bool,
     delete_it: bool,
+    hunk: Option<usize>,
 ) -> fastn_core::Result<()> {
     let number_of_times_flag_used = [use_ours, use_theirs, print, revive_it, delete_it]
         .iter()
@@ -13,6 +15,20 @@ pub async fn resolve_conflict(
     if number_of_times_flag_used > 1 {
         return fastn_core::usage_error("AmbiguousOptionError: Use only one flag".to_string());
     }
+    if config.merge_dir().join(path).exists() {
+        if revive_it || delete_it {
+            return fastn_core::usage_error(format!(
+                "`{}` has conflicting hunks, Help: Use `--use-ours`, `--use-theirs` or `--print`",
+                path
+            ));
+        }
+        return resolve_hunks(config, path, use_ours, use_theirs, print, hunk).await;
+    }
+    if hunk.is_some() && !(use_ours || use_theirs || print) {
+        return fastn_core::usage_error(
+            "`--hunk` can only be used with `--use-ours`, `--use-theirs` or `--print`".to_string(),
+        );
+    }
     let get_files_status = config.get_files_status().await?;
     let file_status =
         if let Some(file_status) = get_files_status.iter().find(|v| v.get_file_path().eq(path)) {
@@ -23,6 +39,12 @@ pub async fn resolve_conflict(
             });
         };
     let conflicted_data = get_conflict_data(config, file_status).await?;
+    if let (Some(hunk), Some(marker)) = (hunk, conflicted_data.marker.as_ref()) {
+        // resolving a single hunk of the conflict, rest of the hunks are kept in `.fastn/merge`
+        // for the subsequent `fastn resolve-conflict` calls
+        fastn_core::utils::update(&config.merge_dir().join(path), marker.as_bytes()).await?;
+        return resolve_hunks(config, path, use_ours, use_theirs, print, Some(hunk)).await;
+    }
     if use_ours {
         let content = conflicted_data
             .ours
@@ -104,6 +126,73 @@ pub async fn resolve_conflict(
     Ok(())
 }
 
+/// Resolves the conflicting hunks of `path` stored in `.fastn/merge`. Once all the hunks are
+/// resolved, the merged content is written to `path` and the conflict is marked resolved.
+async fn resolve_hunks(
+    config: &fastn_core::Config,
+    path: &str,
+    use_ours: bool,
+    use_theirs: bool,
+    print: bool,
+    hunk: Option<usize>,
+) -> fastn_core::Result<()> {
+    let merge_path = config.merge_dir().join(path);
+    let marked_content = tokio::fs::read_to_string(&merge_path).await?;
+
+    let content = if use_ours {
+        fastn_core::conflict::resolve(
+            marked_content.as_str(),
+            hunk,
+            fastn_core::conflict::Side::Ours,
+        )?
+    } else if use_theirs {
+        fastn_core::conflict::resolve(
+            marked_content.as_str(),
+            hunk,
+            fastn_core::conflict::Side::Theirs,
+        )?
+    } else if print {
+        let hunks = fastn_core::conflict::hunks(marked_content.as_str());
+        for (index, (ours, theirs)) in hunks.iter().enumerate() {
+            if hunk.map(|v| v != index + 1).unwrap_or(false) {
+                continue;
+            }
+            println!("Hunk {}/{}", index + 1, hunks.len());
+            println!("<<<<<<< ours\n{}=======\n{}>>>>>>> theirs", ours, theirs);
+        }
+        return Ok(());
+    } else {
+        edit::edit(marked_content.as_str()).map_err(|e| fastn_core::Error::UsageError {
+            message: format!("{}, Help: Use `fastn resolve-conflict --print {}`", e, path,),
+        })?
+    };
+
+    if fastn_core::conflict::has_conflicts(content.as_str()) {
+        fastn_core::utils::update(&merge_path, content.as_bytes()).await?;
+        println!(
+            "{} conflicting hunk(s) remaining in {}",
+            fastn_core::conflict::hunks(content.as_str()).len(),
+            path
+        );
+        return Ok(());
+    }
+
+    fastn_core::utils::update(&config.root.join(path), content.as_bytes()).await?;
+    tokio::fs::remove_file(&merge_path).await?;
+
+    // conflicts in CR merges are not part of the workspace, only the files of main are
+    if let Some(file_status) = config
+        .get_files_status()
+        .await?
+        .iter()
+        .find(|v| v.get_file_path().eq(path) && v.is_conflicted())
+    {
+        mark_resolve(config, file_status, false).await?;
+    }
+    println!("Resolved all the conflicts in {}", path);
+    Ok(())
+}
+
 async fn mark_resolve(
     config: &fastn_core::Config,
     file_status: &fastn_core::sync_utils::FileStatus,
@@ -588,6 +588,13 @@ pub async fn create_cr_page(
     fastn_core::apis::cr::create_cr_page(req).await
 }
 
+pub async fn translation_status_page(
+    req: fastn_core::http::Request,
+) -> fastn_core::Result<fastn_core::http::Response> {
+    let _lock = LOCK.read().await;
+    fastn_core::apis::translation::translation_status_page(req).await
+}
+
 struct AppData {
     edition: Option<String>,
     external_js: Vec<String>,
@@ -659,6 +666,7 @@ async fn route(
         ("get", "/-/editor-sync/") => editor_sync(req).await,
         ("post", "/-/create-cr/") => create_cr(req).await,
         ("get", "/-/create-cr-page/") => create_cr_page(req).await,
+        ("get", "/-/translation-status/") => translation_status_page(req).await,
         ("get", "/-/clear-cache/") => clear_cache(req).await,
         ("get", "/-/poll/") => fastn_core::watcher::poll().await,
         (_, _) => {
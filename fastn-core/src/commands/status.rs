@@ -1,4 +1,7 @@
 pub async fn status(config: &fastn_core::Config, source: Option<&str>) -> fastn_core::Result<()> {
+    if config.is_git_backed() {
+        return fastn_core::git::status(config, source).await;
+    }
     let snapshots = fastn_core::snapshot::get_latest_snapshots(&config.root).await?;
     let workspaces = fastn_core::snapshot::get_workspace(config).await?;
     match source {
@@ -3,6 +3,9 @@ pub async fn sync2(
     files: Option<Vec<String>>,
     // cr_number: Option<&str>,
 ) -> fastn_core::Result<()> {
+    if config.is_git_backed() {
+        return fastn_core::git::sync(config, files).await;
+    }
     simple_sync(config, files).await
     /*if let Some(cr_number) = cr_number {
         let cr_number = cr_number.parse::<usize>()?;
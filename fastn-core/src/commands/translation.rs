@@ -0,0 +1,90 @@
+pub const COMMAND: &str = "translation";
+
+pub fn command() -> clap::Command {
+    clap::Command::new(COMMAND)
+        .about("Work on the translation of a fastn package")
+        .subcommand_required(true)
+        .subcommand(
+            clap::Command::new("init")
+                .about("Create the documents of the original package missing in this translation")
+                .arg(
+                    clap::arg!(--"translation-of" <PACKAGE> "Create FASTN.ftd of a new translation of this package")
+                        .requires("language"),
+                )
+                .arg(clap::arg!(--language <LANGUAGE> "Language of the new translation package"))
+                .arg(
+                    clap::arg!(--provider <PROVIDER> "Machine translation used to pre-fill the documents")
+                        .value_parser(["copy", "stub"])
+                        .default_value("copy"),
+                ),
+        )
+        .subcommand(
+            clap::Command::new("diff")
+                .about("Show changes in the original documents since the translation was last marked up to date")
+                .arg(clap::arg!(file: [FILE] "Show the changes of this document only")),
+        )
+        .hide(true) // hidden since the feature is not being released yet.
+}
+
+pub async fn handle_command(matches: &clap::ArgMatches) -> fastn_core::Result<()> {
+    use fastn_core::utils::ValueOf;
+
+    match matches.subcommand() {
+        Some(("init", matches)) => {
+            if let Some(original) = matches.value_of_("translation-of") {
+                create_manifest(original, matches.value_of_("language").unwrap()).await?;
+            }
+            let config = fastn_core::Config::read(None, true, None).await?;
+            let provider =
+                fastn_core::translation::get_provider(matches.value_of_("provider").unwrap())?;
+            let created =
+                fastn_core::translation::create_skeleton(&config, provider.as_ref()).await?;
+            for id in created.iter() {
+                println!("Created: {}", id);
+            }
+            println!(
+                "{} document(s) created using `{}` provider, review them and run `fastn mark-upto-date <file>`",
+                created.len(),
+                provider.name()
+            );
+        }
+        Some(("diff", matches)) => {
+            let config = fastn_core::Config::read(None, true, None).await?;
+            let diffs =
+                fastn_core::translation::get_outdated_diffs(&config, matches.value_of_("file"))
+                    .await?;
+            if diffs.is_empty() {
+                println!("All translated documents are up to date with the original");
+            }
+            for (id, diff) in diffs {
+                println!("Out-dated: {}", id);
+                println!("{}", diff);
+            }
+        }
+        _ => unreachable!("clap ensures a subcommand is present"),
+    }
+    Ok(())
+}
+
+/// Writes `FASTN.ftd` of a new translation package in the current directory.
+async fn create_manifest(original: &str, language: &str) -> fastn_core::Result<()> {
+    let root: camino::Utf8PathBuf = std::env::current_dir()?.try_into()?;
+    let fastn_path = root.join("FASTN.ftd");
+    if fastn_path.exists() {
+        return fastn_core::usage_error(format!(
+            "{} already exists, remove `--translation-of` to update this translation",
+            fastn_path
+        ));
+    }
+    let content = indoc::formatdoc! {"
+        -- import: fastn
+
+        -- fastn.package: {original}-{language}
+        translation-of: {original}
+        language: {language}
+        ",
+        original = original.trim_end_matches('/'),
+        language = language,
+    };
+    fastn_core::utils::update(&fastn_path, content.as_bytes()).await
+}
@@ -30,6 +30,14 @@ pub struct Config {
     pub original_directory: camino::Utf8PathBuf,
     pub all_packages: std::cell::RefCell<std::collections::BTreeMap<String, fastn_core::Package>>,
     pub downloaded_assets: std::collections::BTreeMap<String, String>,
+    /// `<document>:<line>: <message>` for each accessibility problem found by `fastn build
+    /// --test`, printed after the document is processed
+    pub accessibility_issues: Vec<String>,
+    /// The fonts subsetted by `fastn build`, by their `html_name`, used in place of the font
+    /// declared by the package when writing the `@font-face` rules
+    pub(crate) subsetted_fonts: std::collections::HashMap<String, fastn_core::Font>,
+    /// The images of the package optimised by `fastn build`, by their id
+    pub(crate) images: std::collections::BTreeMap<String, fastn_core::web_image::ImageInfo>,
     pub global_ids: std::collections::HashMap<String, String>,
     // Related to current request, or per request
     pub extra_data: serde_json::Map<String, serde_json::Value>,
@@ -187,6 +195,20 @@ impl Config {
         self.fastn_dir().join("conflicted")
     }
 
+    /// `merge_dir` stores the files that `fastn merge` could not merge automatically. Each file
+    /// contains the merged content with the conflicting hunks surrounded by conflict markers, and
+    /// is removed once `fastn resolve-conflict` has resolved all of its hunks.
+    pub fn merge_dir(&self) -> camino::Utf8PathBuf {
+        self.fastn_dir().join("merge")
+    }
+
+    /// `image_cache_dir` stores the resized and converted variants of the images of the package,
+    /// keyed by the hash of the original image, so that `fastn build` only processes new or
+    /// changed images.
+    pub fn image_cache_dir(&self) -> camino::Utf8PathBuf {
+        self.fastn_dir().join("image-cache")
+    }
+
     /// every package's `.history` contains a file `.latest.ftd`. It looks a bit link this:
     ///
     /// ```ftd
@@ -217,6 +239,12 @@ impl Config {
         self.package.translation_of.is_some()
     }
 
+    /// `is_git_backed()` tells if the package uses its git repository instead of `.remote-state`
+    /// to keep its history, i.e. `FASTN.ftd` has `backing-store: git`.
+    pub fn is_git_backed(&self) -> bool {
+        self.package.backing_store.as_deref() == Some("git")
+    }
+
     /// original_path() returns the path of the original package if the current package is a
     /// translation package. it returns the path in `.packages` folder where the
     pub fn original_path(&self) -> fastn_core::Result<camino::Utf8PathBuf> {
@@ -271,20 +299,23 @@ impl Config {
                 .unique_by(|dep| dep.package.name.clone())
                 .collect_vec()
                 .iter()
-                .fold(self.package.get_font_html(), |accumulator, dep| {
-                    format!(
-                        "{pre}\n{new}",
-                        pre = accumulator,
-                        new = dep.package.get_font_html()
-                    )
-                });
+                .fold(
+                    self.package.get_font_html(&self.subsetted_fonts),
+                    |accumulator, dep| {
+                        format!(
+                            "{pre}\n{new}",
+                            pre = accumulator,
+                            new = dep.package.get_font_html(&self.subsetted_fonts)
+                        )
+                    },
+                );
             generated_style = self.all_packages.borrow().values().fold(
                 generated_style,
                 |accumulator, package| {
                     format!(
                         "{pre}\n{new}",
                         pre = accumulator,
-                        new = package.get_font_html()
+                        new = package.get_font_html(&self.subsetted_fonts)
                     )
                 },
             );
@@ -296,7 +327,9 @@ impl Config {
         };
     }
 
-    pub(crate) async fn download_fonts(&self) -> fastn_core::Result<()> {
+    /// `all_fonts()` returns the fonts of the package and of all its dependencies, along with the
+    /// name of the package defining the font.
+    pub(crate) fn all_fonts(&self) -> Vec<(String, fastn_core::Font)> {
         use itertools::Itertools;
 
         let mut fonts = vec![];
@@ -306,14 +339,27 @@ impl Config {
             .into_iter()
             .unique_by(|dep| dep.package.name.clone())
         {
-            fonts.extend(dep.package.fonts);
+            let name = dep.package.name.clone();
+            fonts.extend(dep.package.fonts.into_iter().map(|v| (name.clone(), v)));
         }
 
         for package in self.all_packages.borrow().values() {
-            fonts.extend(package.fonts.clone());
+            fonts.extend(
+                package
+                    .fonts
+                    .iter()
+                    .map(|v| (package.name.clone(), v.clone())),
+            );
         }
 
-        for font in fonts.iter() {
+        fonts
+            .into_iter()
+            .unique_by(|(package, font)| (package.clone(), font.name.clone()))
+            .collect()
+    }
+
+    pub(crate) async fn download_fonts(&self) -> fastn_core::Result<()> {
+        for (_, font) in self.all_fonts().iter() {
             if let Some(url) = font.get_url() {
                 if fastn_core::config::utils::is_http_url(&url) {
                     continue;
@@ -1299,6 +1345,9 @@ impl Config {
             current_document: None,
             all_packages: Default::default(),
             downloaded_assets: Default::default(),
+            accessibility_issues: Default::default(),
+            subsetted_fonts: Default::default(),
+            images: Default::default(),
             global_ids: Default::default(),
             request: req.map(ToOwned::to_owned),
             named_parameters: vec![],
@@ -0,0 +1,205 @@
+const OURS_MARKER: &str = "<<<<<<<";
+const ANCESTOR_MARKER: &str = "|||||||";
+const SEPARATOR_MARKER: &str = "=======";
+const THEIRS_MARKER: &str = ">>>>>>>";
+
+/// Three-way merge of text files. `merge` returns `Ok` with the merged content if there are no
+/// overlapping changes, else returns `Err` with the content where the conflicting hunks are
+/// surrounded by conflict markers:
+///
+/// ```txt
+/// unchanged line
+/// <<<<<<< ours
+/// our version of the line
+/// =======
+/// their version of the line
+/// >>>>>>> theirs
+/// another unchanged line
+/// ```
+///
+/// Changes that do not overlap are merged automatically, only the overlapping ones are left as
+/// conflicting hunks. `fastn resolve-conflict --hunk <n>` then resolves them one at a time.
+pub(crate) fn merge(ancestor: &str, ours: &str, theirs: &str) -> Result<String, String> {
+    diffy::MergeOptions::new()
+        .set_conflict_style(diffy::ConflictStyle::Merge)
+        .merge(ancestor, ours, theirs)
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub(crate) enum Chunk {
+    Resolved(String),
+    Conflict { ours: String, theirs: String },
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) enum Side {
+    Ours,
+    Theirs,
+}
+
+impl Chunk {
+    fn is_conflict(&self) -> bool {
+        matches!(self, Chunk::Conflict { .. })
+    }
+}
+
+/// Splits merged content into resolved chunks and conflicting hunks.
+pub(crate) fn chunks(content: &str) -> Vec<Chunk> {
+    enum State {
+        Resolved,
+        Ours,
+        Ancestor,
+        Theirs,
+    }
+
+    let mut chunks = vec![];
+    let mut state = State::Resolved;
+    let mut resolved = String::new();
+    let mut ours = String::new();
+    let mut theirs = String::new();
+
+    for line in content.split_inclusive('\n') {
+        match state {
+            State::Resolved if line.starts_with(OURS_MARKER) => {
+                if !resolved.is_empty() {
+                    chunks.push(Chunk::Resolved(std::mem::take(&mut resolved)));
+                }
+                state = State::Ours;
+            }
+            State::Resolved => resolved.push_str(line),
+            State::Ours | State::Ancestor if line.starts_with(SEPARATOR_MARKER) => {
+                state = State::Theirs;
+            }
+            State::Ours if line.starts_with(ANCESTOR_MARKER) => state = State::Ancestor,
+            State::Ours => ours.push_str(line),
+            State::Ancestor => {}
+            State::Theirs if line.starts_with(THEIRS_MARKER) => {
+                chunks.push(Chunk::Conflict {
+                    ours: std::mem::take(&mut ours),
+                    theirs: std::mem::take(&mut theirs),
+                });
+                state = State::Resolved;
+            }
+            State::Theirs => theirs.push_str(line),
+        }
+    }
+
+    match state {
+        State::Resolved => {
+            if !resolved.is_empty() {
+                chunks.push(Chunk::Resolved(resolved));
+            }
+        }
+        // unterminated hunk, keep it as a conflict so that nothing gets lost
+        _ => chunks.push(Chunk::Conflict { ours, theirs }),
+    }
+
+    chunks
+}
+
+/// Returns the conflicting hunks as `(ours, theirs)` pairs, in the order they appear.
+pub(crate) fn hunks(content: &str) -> Vec<(String, String)> {
+    chunks(content)
+        .into_iter()
+        .filter_map(|chunk| match chunk {
+            Chunk::Conflict { ours, theirs } => Some((ours, theirs)),
+            Chunk::Resolved(_) => None,
+        })
+        .collect()
+}
+
+pub(crate) fn has_conflicts(content: &str) -> bool {
+    chunks(content).iter().any(Chunk::is_conflict)
+}
+
+/// Resolves the conflicting hunk at 1-based index `hunk` using `side`. If `hunk` is `None`
+/// every conflicting hunk is resolved using `side`.
+pub(crate) fn resolve(
+    content: &str,
+    hunk: Option<usize>,
+    side: Side,
+) -> fastn_core::Result<String> {
+    let mut chunks = chunks(content);
+    let total = chunks.iter().filter(|v| v.is_conflict()).count();
+    if let Some(hunk) = hunk {
+        if hunk == 0 || hunk > total {
+            return fastn_core::usage_error(format!(
+                "Hunk {} not found, there are {} conflicting hunk(s)",
+                hunk, total
+            ));
+        }
+    }
+
+    let mut index = 0;
+    for chunk in chunks.iter_mut() {
+        let (ours, theirs) = match chunk {
+            Chunk::Conflict { ours, theirs } => (ours, theirs),
+            Chunk::Resolved(_) => continue,
+        };
+        index += 1;
+        if hunk.map(|v| v != index).unwrap_or(false) {
+            continue;
+        }
+        *chunk = Chunk::Resolved(match side {
+            Side::Ours => std::mem::take(ours),
+            Side::Theirs => std::mem::take(theirs),
+        });
+    }
+
+    Ok(to_string(chunks.as_slice()))
+}
+
+pub(crate) fn to_string(chunks: &[Chunk]) -> String {
+    let mut content = String::new();
+    for chunk in chunks {
+        match chunk {
+            Chunk::Resolved(resolved) => content.push_str(resolved),
+            Chunk::Conflict { ours, theirs } => {
+                content.push_str(format!("{} ours\n", OURS_MARKER).as_str());
+                content.push_str(ours);
+                content.push_str(format!("{}\n", SEPARATOR_MARKER).as_str());
+                content.push_str(theirs);
+                content.push_str(format!("{} theirs\n", THEIRS_MARKER).as_str());
+            }
+        }
+    }
+    content
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn non_overlapping_changes_merge() {
+        let ancestor = "-- ftd.text: a\n\n-- ftd.text: b\n\n-- ftd.text: c\n";
+        let ours = "-- ftd.text: A\n\n-- ftd.text: b\n\n-- ftd.text: c\n";
+        let theirs = "-- ftd.text: a\n\n-- ftd.text: b\n\n-- ftd.text: C\n";
+        assert_eq!(
+            super::merge(ancestor, ours, theirs),
+            Ok("-- ftd.text: A\n\n-- ftd.text: b\n\n-- ftd.text: C\n".to_string())
+        );
+    }
+
+    #[test]
+    fn overlapping_changes_resolve_per_hunk() {
+        let ancestor = "a\nb\nc\nd\ne\nf\ng\n";
+        let ours = "A\nb\nc\nd\ne\nf\nG\n";
+        let theirs = "a1\nb\nc\nd\ne\nf\ng1\n";
+        let marked = super::merge(ancestor, ours, theirs).unwrap_err();
+        assert_eq!(
+            super::hunks(marked.as_str()),
+            vec![
+                ("A\n".to_string(), "a1\n".to_string()),
+                ("G\n".to_string(), "g1\n".to_string())
+            ]
+        );
+        assert_eq!(super::to_string(&super::chunks(marked.as_str())), marked);
+
+        let partial = super::resolve(marked.as_str(), Some(2), super::Side::Theirs).unwrap();
+        assert!(super::has_conflicts(partial.as_str()));
+        assert_eq!(
+            super::resolve(partial.as_str(), Some(1), super::Side::Ours).unwrap(),
+            "A\nb\nc\nd\ne\nf\ng1\n"
+        );
+        assert!(super::resolve(partial.as_str(), Some(2), super::Side::Ours).is_err());
+    }
+}
@@ -232,10 +232,11 @@ pub(crate) fn cr_path_to_file_name(
     cr_number: usize,
     cr_file_path: &str,
 ) -> fastn_core::Result<String> {
-    let cr_path = cr_path(cr_number);
+    let cr_path = format!("{}/", cr_path(cr_number));
+    let cr_file_path = cr_file_path.trim_matches('/');
     Ok(cr_file_path
-        .trim_matches('/')
-        .trim_start_matches(cr_path.as_str())
+        .strip_prefix(cr_path.as_str())
+        .unwrap_or(cr_file_path)
         .to_string())
 }
 
@@ -81,6 +81,7 @@ pub async fn interpret_helper<'a>(
                         doc_id: module,
                         line_number: ast.line_number(),
                         message: "Cannot find the module".to_string(),
+                        span: None,
                     },
                 )?;
                 let line_number = ast.line_number();
@@ -201,6 +202,7 @@ pub async fn resolve_import_2022<'a>(
                 "document-full-id".to_string(),
                 "document-suffix".to_string(),
                 "document-name".to_string(),
+                "document-modified".to_string(),
                 "user-details".to_string(),
                 "fastn-apps".to_string(),
                 "is-reader".to_string(),
@@ -264,6 +266,7 @@ pub async fn resolve_import_2022<'a>(
                 "document-id".to_string(),
                 "document-full-id".to_string(),
                 "document-name".to_string(),
+                "document-modified".to_string(),
                 "document-suffix".to_string(),
                 "package-id".to_string(),
                 "package-tree".to_string(),
@@ -486,6 +489,13 @@ pub async fn resolve_foreign_variable2022(
                         text: dark_mode.trim_start_matches('/').to_string(),
                     });
                 }
+                let image_fields = lib
+                    .config
+                    .images
+                    .get(light_path.as_str())
+                    .filter(|_| package.name.eq(&lib.config.package.name))
+                    .map(|info| info.image_src_fields(light_mode.as_str()))
+                    .unwrap_or_default();
                 #[allow(deprecated)]
                 Ok(ftd::interpreter::Value::Record {
                     name: "ftd#image-src".to_string(),
@@ -507,6 +517,7 @@ pub async fn resolve_foreign_variable2022(
                             },
                         ),
                     ])
+                    .chain(image_fields)
                     .collect(),
                 })
             }
@@ -69,6 +69,9 @@ pub enum Error {
     #[error("GenericError: {}", _0)]
     GenericError(String),
 
+    #[error("GitError: {}", _0)]
+    GitError(String),
+
     #[error("GroupNotFound: id: {id}, {message}")]
     GroupNotFound { id: String, message: String },
 
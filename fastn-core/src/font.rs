@@ -51,6 +51,31 @@ impl Font {
         None
     }
 
+    /// The truetype or opentype file of the font, the one `fastn build` can subset.
+    pub(crate) fn sfnt_url(&self) -> Option<&str> {
+        self.truetype.as_deref().or(self.opentype.as_deref())
+    }
+
+    pub(crate) fn is_normal_style(&self) -> bool {
+        self.style
+            .as_deref()
+            .map(|v| v.eq("normal"))
+            .unwrap_or(true)
+    }
+
+    /// Returns the font served from the subsetted `woff2` file, the original file is kept as
+    /// fallback for browsers without woff2 support.
+    pub(crate) fn subsetted(&self, woff2: &str, unicode_range: &str) -> Font {
+        Font {
+            woff: None,
+            woff2: Some(woff2.to_string()),
+            embedded_opentype: None,
+            svg: None,
+            unicode_range: Some(unicode_range.to_string()),
+            ..self.clone()
+        }
+    }
+
     pub fn to_html(&self, package_name: &str) -> String {
         let mut attrs = vec![];
         if let Some(ref ur) = self.unicode_range {
@@ -70,8 +95,9 @@ impl Font {
         }
 
         let mut src: Vec<String> = vec![];
-        append_src("woff", &self.woff, &mut src);
+        // browsers use the first format they support, so the smallest format goes first
         append_src("woff2", &self.woff2, &mut src);
+        append_src("woff", &self.woff, &mut src);
         append_src("truetype", &self.truetype, &mut src);
         append_src("opentype", &self.opentype, &mut src);
         append_src("embedded-opentype", &self.embedded_opentype, &mut src);
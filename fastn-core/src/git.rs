@@ -0,0 +1,672 @@
+// fastn talks to git through the `git` binary, like `fastn_core::controller` does when it clones a
+// package, so we do not depend on libgit2 and respect the user's git configuration.
+
+/// Directories fastn uses for its own bookkeeping, these are never imported from or exported
+/// to git.
+const FASTN_DIRS: [&str; 7] = [
+    ".build/",
+    ".clone-state/",
+    ".fastn/",
+    ".history/",
+    ".packages/",
+    ".remote-state/",
+    ".tracks/",
+];
+
+fn is_fastn_path(path: &str) -> bool {
+    FASTN_DIRS.iter().any(|v| path.starts_with(v))
+}
+
+/// `files` as git pathspecs, leaving out fastn's own directories.
+fn pathspecs(files: &[String]) -> Vec<String> {
+    files
+        .iter()
+        .cloned()
+        .chain(FASTN_DIRS.iter().map(|v| format!(":(exclude){}", v)))
+        .collect()
+}
+
+async fn git_bytes(
+    root: &camino::Utf8Path,
+    args: &[&str],
+    envs: &[(&str, String)],
+    stdin: Option<&[u8]>,
+) -> fastn_core::Result<Vec<u8>> {
+    use tokio::io::AsyncWriteExt;
+
+    let mut child = tokio::process::Command::new("git")
+        .args(args)
+        .current_dir(root)
+        .envs(envs.iter().cloned())
+        .stdin(if stdin.is_some() {
+            std::process::Stdio::piped()
+        } else {
+            std::process::Stdio::null()
+        })
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .spawn()
+        .map_err(|e| fastn_core::Error::GitError(format!("git {}: {}", args.join(" "), e)))?;
+
+    if let Some(stdin) = stdin {
+        if let Some(mut child_stdin) = child.stdin.take() {
+            child_stdin.write_all(stdin).await?;
+        }
+    }
+
+    let output = child.wait_with_output().await?;
+    if !output.status.success() {
+        return Err(fastn_core::Error::GitError(format!(
+            "git {}: {}",
+            args.join(" "),
+            String::from_utf8_lossy(output.stderr.as_slice()).trim()
+        )));
+    }
+    Ok(output.stdout)
+}
+
+async fn git(root: &camino::Utf8Path, args: &[&str]) -> fastn_core::Result<String> {
+    Ok(String::from_utf8(git_bytes(root, args, &[], None).await?)?)
+}
+
+async fn ensure_repository(root: &camino::Utf8Path) -> fastn_core::Result<()> {
+    if git(root, &["rev-parse", "--git-dir"]).await.is_err() {
+        return fastn_core::usage_error(format!(
+            "{} is not in a git repository, Help: Use `git init` to create one",
+            root
+        ));
+    }
+    Ok(())
+}
+
+/// A branch being built by `export`: the blob of every file in it, and the last commit.
+#[derive(Clone, Default)]
+struct Branch {
+    files: std::collections::BTreeMap<String, String>,
+    head: Option<String>,
+}
+
+impl Branch {
+    async fn commit(
+        &mut self,
+        config: &fastn_core::Config,
+        message: &str,
+        author: &str,
+        timestamp: u128,
+        extra_parent: Option<&str>,
+    ) -> fastn_core::Result<()> {
+        let index_file = config.fastn_dir().join("git-export-index");
+        let envs = [("GIT_INDEX_FILE", index_file.to_string())];
+        git_bytes(&config.root, &["read-tree", "--empty"], &envs, None).await?;
+        let index_info = self
+            .files
+            .iter()
+            .map(|(path, blob)| format!("100644 {}\t{}\n", blob, path))
+            .collect::<String>();
+        git_bytes(
+            &config.root,
+            &["update-index", "--add", "--index-info"],
+            &envs,
+            Some(index_info.as_bytes()),
+        )
+        .await?;
+        let tree = String::from_utf8(git_bytes(&config.root, &["write-tree"], &envs, None).await?)?;
+
+        let date = format!("@{} +0000", timestamp / 1_000_000_000);
+        let envs = [
+            ("GIT_AUTHOR_NAME", author.to_string()),
+            ("GIT_AUTHOR_EMAIL", "".to_string()),
+            ("GIT_AUTHOR_DATE", date.clone()),
+            ("GIT_COMMITTER_NAME", author.to_string()),
+            ("GIT_COMMITTER_EMAIL", "".to_string()),
+            ("GIT_COMMITTER_DATE", date),
+        ];
+        let mut args = vec!["commit-tree", tree.trim(), "-m", message];
+        if let Some(head) = self.head.as_ref() {
+            args.extend(["-p", head.as_str()]);
+        }
+        if let Some(extra_parent) = extra_parent {
+            args.extend(["-p", extra_parent]);
+        }
+        let commit = String::from_utf8(git_bytes(&config.root, &args, &envs, None).await?)?;
+        self.head = Some(commit.trim().to_string());
+        Ok(())
+    }
+}
+
+/// Exports `.remote-state/history.ftd` as git commits. All the files synced together (they share
+/// the timestamp) become one commit. Main is exported to `<prefix>main` and every CR to
+/// `<prefix>cr-<number>`, branched off main as it was when the CR was first synced. Files merged
+/// from a CR into main become merge commits. Returns the names of the exported branches.
+pub(crate) async fn export(
+    config: &fastn_core::Config,
+    prefix: &str,
+) -> fastn_core::Result<Vec<String>> {
+    ensure_repository(&config.root).await?;
+    // the commits are built in an index of their own, in `.fastn`
+    tokio::fs::create_dir_all(config.fastn_dir()).await?;
+
+    let mut edits: std::collections::BTreeMap<u128, Vec<(String, fastn_core::history::FileEdit)>> =
+        Default::default();
+    for file_history in config.get_history().await? {
+        for file_edit in file_history.file_edit {
+            edits
+                .entry(file_edit.timestamp)
+                .or_default()
+                .push((file_history.filename.to_string(), file_edit));
+        }
+    }
+
+    let main = "main".to_string();
+    let mut branches: std::collections::BTreeMap<String, Branch> = Default::default();
+    branches.insert(main.to_string(), Branch::default());
+
+    for (timestamp, file_edits) in edits {
+        let mut changes: std::collections::BTreeMap<
+            String,
+            Vec<(String, fastn_core::history::FileEdit)>,
+        > = Default::default();
+        for (filename, file_edit) in file_edits {
+            if is_fastn_path(filename.as_str()) {
+                continue;
+            }
+            let (branch, path) = match fastn_core::cr::get_cr_path_from_url(filename.as_str()) {
+                Some(cr_number) => (
+                    format!("cr-{}", cr_number),
+                    fastn_core::cr::cr_path_to_file_name(cr_number, filename.as_str())?,
+                ),
+                None => (main.to_string(), filename.to_string()),
+            };
+            changes.entry(branch).or_default().push((path, file_edit));
+        }
+
+        // main first, so that a CR created in the same sync branches off the latest main
+        let mut branch_names = changes.keys().cloned().collect::<Vec<String>>();
+        branch_names.sort_by_key(|v| v.ne(&main));
+        for branch_name in branch_names {
+            let branch_changes = changes.remove(&branch_name).unwrap_or_default();
+            let mut branch = match branches.get(&branch_name) {
+                Some(branch) => branch.clone(),
+                None => branches.get(&main).cloned().unwrap_or_default(),
+            };
+            let cr_number = branch_name
+                .strip_prefix("cr-")
+                .and_then(|v| v.parse::<usize>().ok());
+
+            let mut author = None;
+            let mut message = None;
+            let mut src_cr = None;
+            for (path, file_edit) in branch_changes.iter() {
+                author = author.or_else(|| file_edit.author.clone());
+                message = message.or_else(|| file_edit.message.clone());
+                src_cr = src_cr.or(file_edit.src_cr);
+                let stored_name = match cr_number {
+                    Some(cr_number) => format!("{}/{}", fastn_core::cr::cr_path(cr_number), path),
+                    None => path.to_string(),
+                };
+
+                if path.starts_with("-/") {
+                    // CR's own files: about, meta and the list of files deleted in the CR
+                    if let Some(cr_number) = cr_number {
+                        let deleted_path =
+                            config.path_without_root(&config.cr_deleted_file_path(cr_number))?;
+                        if stored_name.eq(&deleted_path) && !file_edit.is_deleted() {
+                            let content = tokio::fs::read_to_string(
+                                config.history_path(stored_name.as_str(), file_edit.version),
+                            )
+                            .await?;
+                            for deleted in
+                                fastn_core::cr::resolve_cr_deleted(content.as_str(), cr_number)
+                                    .await?
+                            {
+                                branch.files.remove(&deleted.filename);
+                            }
+                        }
+                    }
+                    continue;
+                }
+
+                if file_edit.is_deleted() {
+                    branch.files.remove(path);
+                    continue;
+                }
+                let history_path = config.history_path(stored_name.as_str(), file_edit.version);
+                let blob = git(
+                    &config.root,
+                    &["hash-object", "-w", "--no-filters", history_path.as_str()],
+                )
+                .await?;
+                branch
+                    .files
+                    .insert(path.to_string(), blob.trim().to_string());
+            }
+
+            let message = message.filter(|v| !v.trim().is_empty()).unwrap_or_else(|| {
+                let files = branch_changes
+                    .iter()
+                    .map(|(path, _)| path.as_str())
+                    .collect::<Vec<&str>>();
+                match src_cr {
+                    Some(cr) if cr_number.is_none() => format!("Merge CR#{}", cr),
+                    _ => format!("fastn sync: {}", files.join(", ")),
+                }
+            });
+            let merged_cr_head = match src_cr {
+                Some(cr) if cr_number.is_none() => branches
+                    .get(&format!("cr-{}", cr))
+                    .and_then(|v| v.head.clone()),
+                _ => None,
+            };
+            branch
+                .commit(
+                    config,
+                    message.as_str(),
+                    author.as_deref().unwrap_or("fastn"),
+                    timestamp,
+                    merged_cr_head.as_deref(),
+                )
+                .await?;
+            branches.insert(branch_name, branch);
+        }
+    }
+
+    let mut exported = vec![];
+    for (name, branch) in branches {
+        if let Some(head) = branch.head {
+            let branch_name = format!("{}{}", prefix, name);
+            git(
+                &config.root,
+                &[
+                    "update-ref",
+                    format!("refs/heads/{}", branch_name).as_str(),
+                    head.as_str(),
+                ],
+            )
+            .await?;
+            exported.push(branch_name);
+        }
+    }
+    Ok(exported)
+}
+
+/// Imports the first-parent history of `rev` into `.remote-state`, so the package can be used
+/// with `fastn sync`, `fastn merge` etc. Every commit becomes an edit of the files it changed,
+/// with the commit's time, author and subject. Returns the number of imported commits.
+pub(crate) async fn import(config: &fastn_core::Config, rev: &str) -> fastn_core::Result<usize> {
+    use itertools::Itertools;
+
+    ensure_repository(&config.root).await?;
+    if config.history_file().exists() {
+        return fastn_core::usage_error(format!(
+            "{} already has fastn history, can't import git history into it",
+            config.package.name
+        ));
+    }
+
+    let log = git(
+        &config.root,
+        &[
+            "log",
+            "--reverse",
+            "--first-parent",
+            "--format=%H%x1f%at%x1f%an%x1f%s",
+            rev,
+        ],
+    )
+    .await?;
+
+    let mut file_history: std::collections::BTreeMap<String, fastn_core::history::FileHistory> =
+        Default::default();
+    let mut parent: Option<String> = None;
+    let mut count = 0;
+    for line in log.lines() {
+        let mut parts = line.splitn(4, '\u{1f}');
+        let (commit, time, author, subject) =
+            match (parts.next(), parts.next(), parts.next(), parts.next()) {
+                (Some(commit), Some(time), Some(author), Some(subject)) => {
+                    (commit, time, author, subject)
+                }
+                _ => continue,
+            };
+        let timestamp = time.parse::<u128>()? * 1_000_000_000;
+
+        let mut args = vec![
+            "diff-tree",
+            "-r",
+            "-z",
+            "--no-commit-id",
+            "--no-renames",
+            "--name-status",
+        ];
+        match parent.as_ref() {
+            Some(parent) => args.push(parent.as_str()),
+            None => args.push("--root"),
+        }
+        args.push(commit);
+        let changes = git(&config.root, args.as_slice()).await?;
+
+        for (status, path) in changes.split('\0').filter(|v| !v.is_empty()).tuples() {
+            if is_fastn_path(path) {
+                continue;
+            }
+            let operation = match status {
+                "A" => fastn_core::history::FileOperation::Added,
+                "D" => fastn_core::history::FileOperation::Deleted,
+                _ => fastn_core::history::FileOperation::Updated,
+            };
+            let version = fastn_core::snapshot::get_new_version(
+                file_history.values().collect_vec().as_slice(),
+                path,
+            );
+            if !operation.is_deleted() {
+                let content = git_bytes(
+                    &config.root,
+                    &["cat-file", "blob", format!("{}:{}", commit, path).as_str()],
+                    &[],
+                    None,
+                )
+                .await?;
+                fastn_core::utils::update(&config.history_path(path, version), content.as_slice())
+                    .await?;
+            }
+            file_history
+                .entry(path.to_string())
+                .or_insert_with(|| fastn_core::history::FileHistory {
+                    filename: path.to_string(),
+                    file_edit: vec![],
+                })
+                .file_edit
+                .insert(
+                    0,
+                    fastn_core::history::FileEdit {
+                        message: Some(subject.to_string()),
+                        timestamp,
+                        version,
+                        author: Some(author.to_string()),
+                        src_cr: None,
+                        operation,
+                    },
+                );
+        }
+        parent = Some(commit.to_string());
+        count += 1;
+    }
+
+    fastn_core::utils::update(
+        &config.history_file(),
+        fastn_core::history::FileHistory::to_ftd(file_history.values().collect_vec().as_slice())
+            .as_bytes(),
+    )
+    .await?;
+
+    // the working tree is at `rev`, so the clone is up-to-date with the imported history
+    let workspace = file_history
+        .values()
+        .filter_map(|v| {
+            v.file_edit
+                .first()
+                .filter(|file_edit| !file_edit.is_deleted())
+                .map(|file_edit| file_edit.clone().into_workspace(v.filename.as_str()))
+        })
+        .collect_vec();
+    config.write_workspace(workspace.as_slice()).await?;
+
+    Ok(count)
+}
+
+/// `fastn status` for packages with `backing-store: git`.
+pub(crate) async fn status(
+    config: &fastn_core::Config,
+    source: Option<&str>,
+) -> fastn_core::Result<()> {
+    let changed_files = changed_files(config, source).await?;
+    for (status, path) in changed_files.iter() {
+        println!("{}: {}", status, path);
+    }
+    if changed_files.is_empty() {
+        println!("Nothing to sync, clean working tree");
+    }
+    Ok(())
+}
+
+/// The files changed in the working tree, with their status, leaving out fastn's own
+/// directories.
+async fn changed_files(
+    config: &fastn_core::Config,
+    source: Option<&str>,
+) -> fastn_core::Result<Vec<(&'static str, String)>> {
+    ensure_repository(&config.root).await?;
+    let mut args = vec![
+        "status".to_string(),
+        "--porcelain".to_string(),
+        "--untracked-files=all".to_string(),
+        "--".to_string(),
+    ];
+    args.extend(pathspecs(&[source.unwrap_or(".").to_string()]));
+    let args = args.iter().map(|v| v.as_str()).collect::<Vec<&str>>();
+    let output = git(&config.root, args.as_slice()).await?;
+
+    let mut changed_files = vec![];
+    for line in output.lines() {
+        let (code, path) = match (line.get(..2), line.get(3..)) {
+            (Some(code), Some(path)) => (code, path),
+            _ => continue,
+        };
+        if is_fastn_path(path) {
+            continue;
+        }
+        let status = match code {
+            "??" | "A " | "AM" => "Added",
+            "DD" | "AU" | "UD" | "UA" | "DU" | "AA" | "UU" => "Conflicted",
+            _ if code.contains('D') => "Deleted",
+            _ => "Modified",
+        };
+        changed_files.push((status, path.to_string()));
+    }
+    Ok(changed_files)
+}
+
+/// `fastn diff` for packages with `backing-store: git`.
+pub(crate) async fn diff(
+    config: &fastn_core::Config,
+    files: Option<Vec<String>>,
+) -> fastn_core::Result<()> {
+    ensure_repository(&config.root).await?;
+    let mut args = vec!["diff".to_string(), "HEAD".to_string(), "--".to_string()];
+    args.extend(pathspecs(
+        files.unwrap_or_else(|| vec![".".to_string()]).as_slice(),
+    ));
+    let args = args.iter().map(|v| v.as_str()).collect::<Vec<&str>>();
+    print!("{}", git(&config.root, args.as_slice()).await?);
+    Ok(())
+}
+
+/// `fastn sync` for packages with `backing-store: git`: commits the changed files, and if the
+/// branch has an upstream, rebases on it and pushes.
+pub(crate) async fn sync(
+    config: &fastn_core::Config,
+    files: Option<Vec<String>>,
+) -> fastn_core::Result<()> {
+    ensure_repository(&config.root).await?;
+    // fastn's own directories, like `.build` and `.packages`, are never committed
+    let files = pathspecs(files.unwrap_or_else(|| vec![".".to_string()]).as_slice());
+
+    let mut args = vec!["add", "--all", "--"];
+    args.extend(files.iter().map(|v| v.as_str()));
+    git(&config.root, args.as_slice()).await?;
+
+    let mut args = vec!["diff", "--cached", "--name-only", "--"];
+    args.extend(files.iter().map(|v| v.as_str()));
+    let staged = git(&config.root, args.as_slice()).await?;
+    if !staged.trim().is_empty() {
+        let message = format!(
+            "fastn sync: {}",
+            staged.lines().collect::<Vec<&str>>().join(", ")
+        );
+        let mut args = vec!["commit", "--quiet", "-m", message.as_str(), "--"];
+        args.extend(files.iter().map(|v| v.as_str()));
+        git(&config.root, args.as_slice()).await?;
+        for file in staged.lines() {
+            println!("Synced: {}", file);
+        }
+    }
+
+    let has_upstream = git(
+        &config.root,
+        &["rev-parse", "--abbrev-ref", "--symbolic-full-name", "@{u}"],
+    )
+    .await
+    .is_ok();
+    if has_upstream {
+        git(&config.root, &["pull", "--rebase", "--quiet"]).await?;
+        git(&config.root, &["push", "--quiet"]).await?;
+    } else if staged.trim().is_empty() {
+        println!("Everything is upto date.");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    /// Creates a package in a temporary directory, in a git repository if `init` is true.
+    async fn package(name: &str, init: bool) -> fastn_core::Config {
+        let root = camino::Utf8PathBuf::try_from(std::env::temp_dir())
+            .unwrap()
+            .join(format!("fastn-git-{}-{}", name, std::process::id()));
+        if root.exists() {
+            tokio::fs::remove_dir_all(&root).await.unwrap();
+        }
+        for (path, content) in [
+            (
+                "FASTN.ftd",
+                "-- import: fastn\n\n-- fastn.package: git-test\nbacking-store: git\n",
+            ),
+            ("index.ftd", "-- ftd.text: hello\n"),
+            (".build/index.html", "<html></html>"),
+            (".packages/foo.com/index.ftd", "-- ftd.text: foo\n"),
+        ] {
+            fastn_core::utils::update(&root.join(path), content.as_bytes())
+                .await
+                .unwrap();
+        }
+        if init {
+            for args in [
+                vec!["init", "--quiet"],
+                vec!["config", "user.name", "fastn"],
+                vec!["config", "user.email", "fastn@localhost"],
+            ] {
+                super::git(&root, args.as_slice()).await.unwrap();
+            }
+        }
+        fastn_core::Config::read(Some(root.to_string()), false, None)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn status_leaves_out_fastn_dirs() {
+        let config = package("status", true).await;
+        assert_eq!(
+            super::changed_files(&config, None).await.unwrap(),
+            vec![
+                ("Added", "FASTN.ftd".to_string()),
+                ("Added", "index.ftd".to_string())
+            ]
+        );
+        tokio::fs::remove_dir_all(&config.root).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn sync_commits_all_but_fastn_dirs() {
+        let config = package("sync", true).await;
+        super::sync(&config, None).await.unwrap();
+        assert_eq!(
+            super::git(&config.root, &["ls-files"]).await.unwrap(),
+            "FASTN.ftd\nindex.ftd\n"
+        );
+        assert!(super::changed_files(&config, None)
+            .await
+            .unwrap()
+            .is_empty());
+
+        fastn_core::utils::update(&config.root.join("index.ftd"), b"-- ftd.text: hi\n")
+            .await
+            .unwrap();
+        assert_eq!(
+            super::changed_files(&config, None).await.unwrap(),
+            vec![("Modified", "index.ftd".to_string())]
+        );
+        super::sync(&config, None).await.unwrap();
+        assert_eq!(
+            super::git(&config.root, &["log", "--format=%s"])
+                .await
+                .unwrap(),
+            "fastn sync: index.ftd\nfastn sync: FASTN.ftd, index.ftd\n"
+        );
+        tokio::fs::remove_dir_all(&config.root).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn export_history_to_branches() {
+        let config = package("export", true).await;
+        fastn_core::utils::update(&config.history_file(), b"-- import: fastn\n")
+            .await
+            .unwrap();
+        for content in ["-- ftd.text: hello\n", "-- ftd.text: hi\n"] {
+            fastn_core::apis::sync2::do_sync(
+                &config,
+                &[
+                    match config
+                        .get_remote_manifest(false)
+                        .await
+                        .unwrap()
+                        .get("index.ftd")
+                    {
+                        Some(file_edit) => fastn_core::apis::sync2::SyncRequestFile::Update {
+                            path: "index.ftd".to_string(),
+                            content: content.as_bytes().to_vec(),
+                            version: file_edit.version,
+                            src_cr: None,
+                        },
+                        None => fastn_core::apis::sync2::SyncRequestFile::Add {
+                            path: "index.ftd".to_string(),
+                            content: content.as_bytes().to_vec(),
+                            src_cr: None,
+                        },
+                    },
+                ],
+            )
+            .await
+            .unwrap();
+        }
+
+        assert_eq!(
+            super::export(&config, "fastn/").await.unwrap(),
+            vec!["fastn/main".to_string()]
+        );
+        assert_eq!(
+            super::git(&config.root, &["log", "--format=%s", "fastn/main"])
+                .await
+                .unwrap(),
+            "fastn sync: index.ftd\nfastn sync: index.ftd\n"
+        );
+        assert_eq!(
+            super::git(&config.root, &["show", "fastn/main:index.ftd"])
+                .await
+                .unwrap(),
+            "-- ftd.text: hi\n"
+        );
+        tokio::fs::remove_dir_all(&config.root).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn export_needs_a_repository() {
+        let config = package("no-repository", false).await;
+        assert!(matches!(
+            super::export(&config, "fastn/").await,
+            Err(fastn_core::Error::UsageError { .. })
+        ));
+        assert!(!config.root.join(".git").exists());
+        tokio::fs::remove_dir_all(&config.root).await.unwrap();
+    }
+}
@@ -7,11 +7,13 @@ mod auto_import;
 mod cache;
 pub mod commands;
 mod config;
+mod conflict;
 mod controller;
 mod cr;
 mod doc;
 mod file;
 mod font;
+mod git;
 mod history;
 mod package;
 pub(crate) mod watcher;
@@ -22,14 +24,18 @@ mod ds;
 mod error;
 mod i18n;
 pub mod library;
+pub mod lsp;
 mod proxy;
 pub mod sitemap;
 mod snapshot;
 mod sync_utils;
 mod track;
 mod tracker;
-mod translation;
+pub mod translation;
 mod version;
+mod web_font;
+mod web_image;
+mod web_svg;
 // mod wasm;
 mod library2022;
 mod workspace;
@@ -388,46 +394,6 @@ fn translation_package_status(config: &fastn_core::Config) -> fastn_core::Result
     })
 }
 
-fn get_messages(
-    status: &fastn_core::TranslatedDocument,
-    config: &fastn_core::Config,
-) -> fastn_core::Result<String> {
-    Ok(match status {
-        TranslatedDocument::Missing { .. } => {
-            let path = config.root.join("fastn/translation/missing.ftd");
-            if path.is_file() {
-                std::fs::read_to_string(path)?
-            } else {
-                include_str!("../ftd/translation/missing.ftd").to_string()
-            }
-        }
-        TranslatedDocument::NeverMarked { .. } => {
-            let path = config.root.join("fastn/translation/never-marked.ftd");
-            if path.is_file() {
-                std::fs::read_to_string(path)?
-            } else {
-                include_str!("../ftd/translation/never-marked.ftd").to_string()
-            }
-        }
-        TranslatedDocument::Outdated { .. } => {
-            let path = config.root.join("fastn/translation/out-of-date.ftd");
-            if path.is_file() {
-                std::fs::read_to_string(path)?
-            } else {
-                include_str!("../ftd/translation/out-of-date.ftd").to_string()
-            }
-        }
-        TranslatedDocument::UptoDate { .. } => {
-            let path = config.root.join("fastn/translation/upto-date.ftd");
-            if path.is_file() {
-                std::fs::read_to_string(path)?
-            } else {
-                include_str!("../ftd/translation/upto-date.ftd").to_string()
-            }
-        }
-    })
-}
-
 pub fn get_env_ftd_file() -> String {
     std::env::vars()
         .filter(|(key, val)| {
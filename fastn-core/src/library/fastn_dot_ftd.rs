@@ -280,6 +280,34 @@ pub(crate) async fn get2022_(lib: &fastn_core::Library) -> String {
         );
     }
 
+    // `diff` is declared without a value in the base and can not be updated from here, so the
+    // translation status messages read the diff from `translation-diff` instead
+    if lib.config.package.translation_of.is_some() {
+        fastn_base = format!(
+            indoc::indoc! {"
+                {fastn_base}
+
+                {i18n_data}
+            "},
+            fastn_base = fastn_base,
+            i18n_data = i18n_data(lib).await,
+        );
+
+        if let Some(ref diff) = lib.translated_data.diff {
+            fastn_base = format!(
+                indoc::indoc! {"
+                    {fastn_base}
+
+                    -- string translation-diff:
+
+                    {diff}
+                "},
+                fastn_base = fastn_base,
+                diff = diff,
+            );
+        }
+    }
+
     if let Some((ref filename, ref content)) = lib.markdown {
         fastn_base = format!(
             indoc::indoc! {"
@@ -15,6 +15,7 @@ pub async fn processor<'a>(
                 message: format!("Cant find versions: {:?}", e),
                 doc_id: doc.name.to_string(),
                 line_number: section.line_number,
+                span: None,
             })?;
 
     let version = if let Some((v, _)) = document_id.split_once('/') {
@@ -22,6 +23,7 @@ pub async fn processor<'a>(
             message: format!("{:?}", e),
             doc_id: doc.name.to_string(),
             line_number: section.line_number,
+            span: None,
         })?
     } else {
         fastn_core::Version::base()
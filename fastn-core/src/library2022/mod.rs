@@ -49,6 +49,7 @@ impl Library2022 {
                     .to_string(),
                 doc_id: current_processing_module.to_string(),
                 line_number: 0,
+                span: None,
             })?;
 
         self.config
@@ -60,6 +61,7 @@ impl Library2022 {
                 message: format!("Can't find current package: {}", current_package_name),
                 doc_id: "".to_string(),
                 line_number: 0,
+                span: None,
             })
     }
 
@@ -267,6 +269,9 @@ impl Library2022 {
             "document-name" => {
                 processor::document::document_name(value, kind, doc, &self.config).await
             }
+            "document-modified" => {
+                processor::document::document_modified(value, kind, doc, &self.config).await
+            }
             "fetch-file" => {
                 processor::fetch_file::fetch_files(value, kind, doc, &self.config).await
             }
@@ -291,6 +296,7 @@ impl Library2022 {
                 doc_id: self.document_id.to_string(),
                 line_number,
                 message: format!("fastn-Error: No such processor: {}", t),
+                span: None,
             }),
         }
     }
@@ -317,6 +323,7 @@ fn get_processor_data(
                 "Cannot find kind for `{}`",
                 variable_definition.name.as_str(),
             ),
+            span: None,
         })?;
         let processor =
             variable_definition
@@ -325,6 +332,7 @@ fn get_processor_data(
                     message: format!("No processor found for `{}`", ast_name),
                     doc_id: doc.name.to_string(),
                     line_number,
+                    span: None,
                 })?;
         Ok((processor, variable_definition.value, kind.kind))
     } else {
@@ -342,6 +350,7 @@ fn get_processor_data(
                     message: format!("No processor found for `{}`", ast_name),
                     doc_id: doc.name.to_string(),
                     line_number,
+                    span: None,
                 })?;
         Ok((processor, variable_invocation.value, kind.kind))
     }
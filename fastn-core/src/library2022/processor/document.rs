@@ -158,3 +158,47 @@ pub async fn document_name<'a>(
         text: file_path.trim().to_string(),
     })
 }
+
+/// When the file of the current document, or of the `document` header, was last modified, as a
+/// `datetime`.
+pub async fn document_modified<'a>(
+    value: ftd::ast::VariableValue,
+    kind: ftd::interpreter::Kind,
+    doc: &ftd::interpreter::TDoc<'a>,
+    config: &fastn_core::Config,
+) -> ftd::interpreter::Result<ftd::interpreter::Value> {
+    let headers = match value.get_record(doc.name) {
+        Ok(val) => val.2.to_owned(),
+        Err(_e) => ftd::ast::HeaderValues::new(vec![]),
+    };
+
+    let doc_id =
+        match headers.get_optional_string_by_key("document", doc.name, value.line_number())? {
+            Some(document) => document,
+            None => config.doc_id().unwrap_or_else(|| {
+                doc.name
+                    .to_string()
+                    .replace(config.package.name.as_str(), "")
+            }),
+        };
+
+    let file_path =
+        config
+            .get_file_path(&doc_id)
+            .await
+            .map_err(|e| ftd::ftd2021::p1::Error::ParseError {
+                message: e.to_string(),
+                doc_id: doc.name.to_string(),
+                line_number: value.line_number(),
+            })?;
+
+    let modified = fastn_core::utils::modified_rfc3339(&config.root.join(file_path.trim()))
+        .ok_or_else(|| ftd::interpreter::Error::ParseError {
+            message: format!("Can't read the modification time of `{}`", file_path.trim()),
+            doc_id: doc.name.to_string(),
+            line_number: value.line_number(),
+            span: None,
+        })?;
+
+    doc.from_json(&modified, &kind, value.line_number())
+}
@@ -21,6 +21,7 @@ pub async fn fetch_files<'a>(
             message: "`path` not found".to_string(),
             doc_id: doc.name.to_string(),
             line_number: value.line_number(),
+            span: None,
         })?;
 
     Ok(ftd::interpreter::Value::String {
@@ -30,6 +31,7 @@ pub async fn fetch_files<'a>(
                 message: v.to_string(),
                 doc_id: doc.name.to_string(),
                 line_number: value.line_number(),
+                span: None,
             })?,
     })
 }
@@ -57,6 +57,7 @@ fn extract_types(
                 message: format!("Expected record of ftd.type-data found: {:?}", value),
                 doc_id: doc.name.to_string(),
                 line_number,
+                span: None,
             })
         }
     };
@@ -74,6 +75,7 @@ fn extract_types(
                     doc_id: doc.name.to_string(),
                     line_number,
                     message: format!("Expected string kind for name found: {:?}", variable_name),
+                    span: None,
                 })
             }
         };
@@ -86,6 +88,7 @@ fn extract_types(
             message: format!("`variable` header not found: {:?}", value),
             doc_id: doc.name.to_string(),
             line_number,
+            span: None,
         });
     };
 
@@ -99,6 +102,7 @@ fn extract_types(
                 ),
                 doc_id: doc.name.to_string(),
                 line_number,
+                span: None,
             })
         }
     };
@@ -117,6 +121,7 @@ fn extract_types(
                 message: format!("Expected Variable reference, found: {:?}", t),
                 doc_id: doc.name.to_string(),
                 line_number,
+                span: None,
             })
         }
     };
@@ -134,6 +139,7 @@ fn extract_types(
                 ),
                 doc_id: doc.name.to_string(),
                 line_number,
+                span: None,
             })
         }
     };
@@ -197,6 +203,7 @@ fn extract_desktop_mobile_values(
                 ),
                 doc_id: doc.name.to_string(),
                 line_number,
+                span: None,
             });
         }
     }
@@ -242,6 +249,7 @@ fn extract_type_data(
                 ),
                 doc_id: doc.name.to_string(),
                 line_number,
+                span: None,
             });
         }
     }
@@ -27,6 +27,7 @@ pub fn process(
             message: "Cannot pass both caption and body".to_string(),
             doc_id: doc.name.to_string(),
             line_number,
+            span: None,
         });
     }
 
@@ -49,39 +50,7 @@ pub fn process(
 
         if let Some(extra_data) = sitemap.get_extra_data_by_id(doc_id.as_str()) {
             if let Some(data) = extra_data.get(key.as_str()) {
-                return match kind {
-                    ftd::interpreter::Kind::Integer => {
-                        let value = data.parse::<i64>().map_err(|e| {
-                            ftd::interpreter::Error::ParseError {
-                                message: e.to_string(),
-                                doc_id: doc.name.to_string(),
-                                line_number,
-                            }
-                        })?;
-                        doc.from_json(&value, &kind, line_number)
-                    }
-                    ftd::interpreter::Kind::Decimal { .. } => {
-                        let value = data.parse::<f64>().map_err(|e| {
-                            ftd::interpreter::Error::ParseError {
-                                message: e.to_string(),
-                                doc_id: doc.name.to_string(),
-                                line_number,
-                            }
-                        })?;
-                        doc.from_json(&value, &kind, line_number)
-                    }
-                    ftd::interpreter::Kind::Boolean { .. } => {
-                        let value = data.parse::<bool>().map_err(|e| {
-                            ftd::interpreter::Error::ParseError {
-                                message: e.to_string(),
-                                doc_id: doc.name.to_string(),
-                                line_number,
-                            }
-                        })?;
-                        doc.from_json(&value, &kind, line_number)
-                    }
-                    _ => doc.from_json(data, &kind, line_number),
-                };
+                return value_from_str(data, &kind, doc, line_number);
             }
         }
     }
@@ -96,6 +65,7 @@ pub fn process(
                         message: format!("only json file supported {}", path),
                         doc_id: doc.name.to_string(),
                         line_number,
+                        span: None,
                     });
                 }
             }
@@ -104,6 +74,7 @@ pub fn process(
                     message: format!("file does not have any extension {}", path),
                     doc_id: doc.name.to_string(),
                     line_number,
+                    span: None,
                 });
             }
         }
@@ -113,6 +84,7 @@ pub fn process(
                 message: format!("file path not found {}", path),
                 doc_id: doc.name.to_string(),
                 line_number,
+                span: None,
             }
         })?;
         return doc.from_json(
@@ -137,6 +109,7 @@ pub fn process(
                 message: format!("caption name not passed for section: {}", section_name),
                 doc_id: doc.name.to_string(),
                 line_number,
+                span: None,
             })
         }
     };
@@ -155,3 +128,40 @@ pub fn process(
 
     doc.from_json(&serde_json::json!(caption), &kind, line_number)
 }
+
+/// `text` as a value of `kind` (or of the kind it is an optional of): integers, decimals and
+/// booleans are parsed, anything else is taken as a string.
+pub(crate) fn value_from_str(
+    text: &str,
+    kind: &ftd::interpreter::Kind,
+    doc: &ftd::interpreter::TDoc,
+    line_number: usize,
+) -> ftd::interpreter::Result<ftd::interpreter::Value> {
+    let parse_error = |e: String| ftd::interpreter::Error::ParseError {
+        message: format!("Can't parse `{}`: {}", text, e),
+        doc_id: doc.name.to_string(),
+        line_number,
+        span: None,
+    };
+    match kind.clone().inner() {
+        ftd::interpreter::Kind::Integer => {
+            let value = text
+                .parse::<i64>()
+                .map_err(|e| parse_error(e.to_string()))?;
+            doc.from_json(&value, kind, line_number)
+        }
+        ftd::interpreter::Kind::Decimal { .. } => {
+            let value = text
+                .parse::<f64>()
+                .map_err(|e| parse_error(e.to_string()))?;
+            doc.from_json(&value, kind, line_number)
+        }
+        ftd::interpreter::Kind::Boolean { .. } => {
+            let value = text
+                .parse::<bool>()
+                .map_err(|e| parse_error(e.to_string()))?;
+            doc.from_json(&value, kind, line_number)
+        }
+        _ => doc.from_json(&text, kind, line_number),
+    }
+}
@@ -42,6 +42,7 @@ pub async fn process<'a>(
             message: format!("invalid url: {:?}", e),
             doc_id: doc.name.to_string(),
             line_number,
+            span: None,
         })?;
 
     let mut body = vec![];
@@ -114,6 +115,7 @@ pub async fn process<'a>(
             message: format!("`http` processor API response error: {}", e),
             doc_id: doc.name.to_string(),
             line_number,
+            span: None,
         })?;
     let response_json: serde_json::Value = serde_json::from_str(&response_string)
         .map_err(|e| ftd::interpreter::Error::Serde { source: e })?;
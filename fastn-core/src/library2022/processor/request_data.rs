@@ -46,5 +46,68 @@ pub fn process(
         }
     }
 
+    let inner = kind.clone().inner();
+    if inner.is_integer() || inner.is_decimal() || inner.is_boolean() || inner.is_string() {
+        return process_scalar(value, kind, doc, &data);
+    }
+
     doc.from_json(&data, &kind, value.line_number())
 }
+
+/// A single value of the request, e.g. the page of a paginated `ftd.table`, read from the query
+/// parameter given by the `key` header, the name of the variable by default. The caption is used
+/// when the request does not have it.
+fn process_scalar(
+    value: ftd::ast::VariableValue,
+    kind: ftd::interpreter::Kind,
+    doc: &ftd::interpreter::TDoc,
+    data: &std::collections::HashMap<String, serde_json::Value>,
+) -> ftd::interpreter::Result<ftd::interpreter::Value> {
+    let line_number = value.line_number();
+    let value = match value {
+        ftd::ast::VariableValue::Optional {
+            value: inner,
+            line_number,
+        } => (*inner).unwrap_or(ftd::ast::VariableValue::Optional {
+            value: Box::new(None),
+            line_number,
+        }),
+        t => t,
+    };
+    let (section_name, headers) = match value.get_record(doc.name) {
+        Ok(val) => (val.0.to_owned(), val.2.to_owned()),
+        Err(e) => return Err(e.into()),
+    };
+    let key = match headers.get_optional_string_by_key("key", doc.name, line_number)? {
+        Some(k) => k,
+        None => section_name
+            .rsplit_once(' ')
+            .map(|(_, name)| name.to_string())
+            .unwrap_or_else(|| section_name.to_string()),
+    };
+
+    let text = match data.get(key.as_str()) {
+        Some(serde_json::Value::String(s)) => s.to_string(),
+        Some(serde_json::Value::Null) | None => match value.caption() {
+            Some(caption) => caption,
+            None if kind.is_optional() => {
+                return doc.from_json(&serde_json::Value::Null, &kind, line_number)
+            }
+            None => {
+                return ftd::interpreter::utils::e2(
+                    format!("`{}` not found in the request", key),
+                    doc.name,
+                    line_number,
+                )
+            }
+        },
+        Some(json) => return doc.from_json(json, &kind, line_number),
+    };
+
+    fastn_core::library2022::processor::get_data::value_from_str(
+        text.as_str(),
+        &kind,
+        doc,
+        line_number,
+    )
+}
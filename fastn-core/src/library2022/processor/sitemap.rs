@@ -158,6 +158,10 @@ pub fn to_sitemap_compat(
                 .cloned()
                 .map(|v| v.into()),
             document: None,
+            modified: toc_item
+                .file_location
+                .as_ref()
+                .and_then(|v| fastn_core::utils::modified_rfc3339(v)),
         };
         toc_compat
     }
@@ -204,6 +208,10 @@ pub fn to_sitemap_compat(
             number: None,
             is_disabled: false,
             document: None,
+            modified: subsection
+                .file_location
+                .as_ref()
+                .and_then(|v| fastn_core::utils::modified_rfc3339(v)),
         }
     }
 
@@ -243,6 +251,10 @@ pub fn to_sitemap_compat(
             is_disabled: false,
             image_src: section.extra_data.get("img-src").cloned().map(|v| v.into()),
             document: None,
+            modified: section
+                .file_location
+                .as_ref()
+                .and_then(|v| fastn_core::utils::modified_rfc3339(v)),
         }
     }
 
@@ -0,0 +1,369 @@
+/// The keywords that can follow `-- ` at the start of a section, besides component names.
+const SECTION_KEYWORDS: [&str; 13] = [
+    "import",
+    "component",
+    "web-component",
+    "record",
+    "or-type",
+    "end",
+    "string",
+    "integer",
+    "decimal",
+    "boolean",
+    "optional",
+    "ftd.ui",
+    "ftd.color",
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompletionItem {
+    pub label: String,
+    pub kind: CompletionKind,
+    pub detail: String,
+    pub insert_text: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CompletionKind {
+    Keyword,
+    Component,
+    Header,
+    Variable,
+    Record,
+    OrType,
+    Function,
+}
+
+impl CompletionKind {
+    /// The `CompletionItemKind` of the language server protocol.
+    pub fn to_lsp(self) -> u8 {
+        match self {
+            CompletionKind::Keyword => 14,
+            CompletionKind::Component => 7,
+            CompletionKind::Header => 10,
+            CompletionKind::Variable => 6,
+            CompletionKind::Record => 22,
+            CompletionKind::OrType => 13,
+            CompletionKind::Function => 3,
+        }
+    }
+}
+
+/// Completions at `line` (0 based) and `character` of `source`:
+///
+/// - `-- |` completes component names and section keywords,
+/// - `-- foo.|` completes the headers of `foo` as block headers,
+/// - `$|` completes variables,
+/// - a header key inside a section completes the headers of that section's component.
+pub fn completion(
+    source: &str,
+    line: usize,
+    character: usize,
+    symbols: &[fastn_core::lsp::symbols::Symbol],
+) -> Vec<CompletionItem> {
+    let current = match source.split('\n').nth(line) {
+        Some(current) => current,
+        None => return vec![],
+    };
+    let before = current.chars().take(character).collect::<String>();
+
+    if let Some(word) = before.rsplit_once('$').map(|v| v.1) {
+        if word.chars().all(is_name_char) {
+            return symbols
+                .iter()
+                .filter(|s| s.kind == fastn_core::lsp::symbols::SymbolKind::Variable)
+                .map(|s| symbol_completion(s, s.name.clone()))
+                .collect();
+        }
+    }
+
+    let trimmed = before.trim_start();
+    if let Some(name) = trimmed
+        .strip_prefix("-- ")
+        .or_else(|| trimmed.strip_prefix("/-- "))
+    {
+        if name.contains(':') {
+            return vec![];
+        }
+        if let Some(section) = enclosing_section(source, line) {
+            if name.starts_with(format!("{}.", section).as_str()) {
+                return section_headers(section.as_str(), symbols)
+                    .into_iter()
+                    .map(|mut item| {
+                        item.label = format!("{}.{}", section, item.label);
+                        item.insert_text = format!("{}:", item.label);
+                        item
+                    })
+                    .collect();
+            }
+        }
+        return SECTION_KEYWORDS
+            .iter()
+            .map(|k| CompletionItem {
+                label: k.to_string(),
+                kind: CompletionKind::Keyword,
+                detail: "keyword".to_string(),
+                insert_text: k.to_string(),
+            })
+            .chain(
+                symbols
+                    .iter()
+                    .filter(|s| s.is_component())
+                    .map(|s| symbol_completion(s, format!("{}:", s.name))),
+            )
+            .collect();
+    }
+
+    if !before.contains(':') && !before.trim().starts_with(";;") && !before.starts_with(' ') {
+        if let Some(section) = enclosing_section(source, line) {
+            return section_headers(section.as_str(), symbols);
+        }
+    }
+
+    vec![]
+}
+
+/// The symbol, or header of the enclosing section, under the cursor.
+pub fn hover(
+    source: &str,
+    line: usize,
+    character: usize,
+    symbols: &[fastn_core::lsp::symbols::Symbol],
+) -> Option<String> {
+    let word = word_at(source, line, character)?;
+    if let Some(symbol) = find_symbol(word.as_str(), symbols) {
+        return Some(symbol.to_markdown());
+    }
+
+    let section = enclosing_section(source, line)?;
+    let symbol = symbols.iter().find(|s| s.name.eq(&section))?;
+    let key = word
+        .strip_prefix(format!("{}.", section).as_str())
+        .unwrap_or(word.as_str());
+    let argument = symbol.arguments.iter().find(|a| a.name.eq(key))?;
+    let mut markdown = format!("```ftd\n{}\n```", argument.detail);
+    if let Some(ref doc) = argument.doc {
+        markdown.push_str(format!("\n\n{}", doc).as_str());
+    }
+    Some(markdown)
+}
+
+/// Where the symbol under the cursor is defined.
+pub fn definition(
+    source: &str,
+    line: usize,
+    character: usize,
+    symbols: &[fastn_core::lsp::symbols::Symbol],
+) -> Option<fastn_core::lsp::symbols::Location> {
+    let word = word_at(source, line, character)?;
+    find_symbol(word.as_str(), symbols)?.location.clone()
+}
+
+/// Looks up `word` (`foo`, `lib.foo`, `$lib.foo.field`) among the symbols, trying shorter
+/// prefixes so that record fields and or-type variants resolve to their definition.
+fn find_symbol<'a>(
+    word: &str,
+    symbols: &'a [fastn_core::lsp::symbols::Symbol],
+) -> Option<&'a fastn_core::lsp::symbols::Symbol> {
+    let mut name = word.trim_start_matches(['$', '*']);
+    loop {
+        if let Some(symbol) = symbols.iter().find(|s| s.name.eq(name)) {
+            return Some(symbol);
+        }
+        name = name.rsplit_once('.')?.0;
+    }
+}
+
+fn section_headers(
+    section: &str,
+    symbols: &[fastn_core::lsp::symbols::Symbol],
+) -> Vec<CompletionItem> {
+    symbols
+        .iter()
+        .find(|s| s.name.eq(section))
+        .map(|s| {
+            s.arguments
+                .iter()
+                .map(|a| CompletionItem {
+                    label: a.name.clone(),
+                    kind: CompletionKind::Header,
+                    detail: a.detail.clone(),
+                    insert_text: format!("{}: ", a.name),
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn symbol_completion(
+    symbol: &fastn_core::lsp::symbols::Symbol,
+    insert_text: String,
+) -> CompletionItem {
+    CompletionItem {
+        label: symbol.name.clone(),
+        kind: match symbol.kind {
+            fastn_core::lsp::symbols::SymbolKind::Component
+            | fastn_core::lsp::symbols::SymbolKind::WebComponent => CompletionKind::Component,
+            fastn_core::lsp::symbols::SymbolKind::Variable => CompletionKind::Variable,
+            fastn_core::lsp::symbols::SymbolKind::Record => CompletionKind::Record,
+            fastn_core::lsp::symbols::SymbolKind::OrType => CompletionKind::OrType,
+            fastn_core::lsp::symbols::SymbolKind::Function => CompletionKind::Function,
+        },
+        detail: symbol.detail.clone(),
+        insert_text,
+    }
+}
+
+/// The name of the component invoked by the closest section above `line`, skipping `-- end`
+/// and block header lines.
+fn enclosing_section(source: &str, line: usize) -> Option<String> {
+    source
+        .split('\n')
+        .take(line + 1)
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+        .filter_map(|l| {
+            let l = l.trim_start();
+            l.strip_prefix("-- ").or_else(|| l.strip_prefix("/-- "))
+        })
+        .filter_map(|l| l.split_once(':').map(|v| v.0.trim()))
+        .filter(|name| !name.eq(&"end"))
+        .map(|name| name.rsplit(' ').next().unwrap_or(name).to_string())
+        .fold(None, |found: Option<String>, name| match found {
+            // a block header `-- foo.bar:` belongs to the section `-- foo:` above it
+            Some(found) if found.starts_with(format!("{}.", name).as_str()) => Some(name),
+            Some(found) => Some(found),
+            None => Some(name),
+        })
+}
+
+fn word_at(source: &str, line: usize, character: usize) -> Option<String> {
+    let chars = source.split('\n').nth(line)?.chars().collect::<Vec<_>>();
+    let character = character.min(chars.len());
+    let start = chars[..character]
+        .iter()
+        .rposition(|c| !is_name_char(*c) && *c != '$')
+        .map(|v| v + 1)
+        .unwrap_or(0);
+    let end = chars[character..]
+        .iter()
+        .position(|c| !is_name_char(*c))
+        .map(|v| v + character)
+        .unwrap_or(chars.len());
+    if start >= end {
+        return None;
+    }
+    Some(chars[start..end].iter().collect())
+}
+
+/// The character (as counted by `chars()`) of `line` at `character` UTF-16 code units, which is
+/// how the language server protocol counts positions.
+pub fn from_utf16(line: &str, character: usize) -> usize {
+    let mut units = 0;
+    for (index, c) in line.chars().enumerate() {
+        if units >= character {
+            return index;
+        }
+        units += c.len_utf16();
+    }
+    line.chars().count()
+}
+
+/// The number of UTF-16 code units in the first `character` characters of `line`.
+pub fn to_utf16(line: &str, character: usize) -> usize {
+    line.chars().take(character).map(|c| c.len_utf16()).sum()
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '-' | '_' | '.' | '#')
+}
+
+#[cfg(test)]
+mod tests {
+    fn symbols(source: &str) -> Vec<fastn_core::lsp::symbols::Symbol> {
+        let mut symbols = fastn_core::lsp::symbols::from_source(
+            None,
+            "foo",
+            source,
+            camino::Utf8Path::new("foo.ftd"),
+        );
+        symbols.extend(fastn_core::lsp::symbols::built_ins());
+        symbols
+    }
+
+    const SOURCE: &str = indoc::indoc! {"
+        ;; A card with a title
+        -- component card:
+        ;; shown in bold
+        caption title:
+        boolean $open: false
+
+        -- ftd.text: $card.title
+
+        -- end: card
+
+        -- string name: Alice
+
+        -- card: Hello
+        ti
+        "};
+
+    #[test]
+    fn completes_headers_of_the_section() {
+        let labels = super::completion(SOURCE, 13, 2, symbols(SOURCE).as_slice())
+            .into_iter()
+            .map(|v| v.label)
+            .collect::<Vec<_>>();
+        assert_eq!(labels, vec!["title", "open"]);
+    }
+
+    #[test]
+    fn completes_components_and_variables() {
+        let source = format!("{}\n-- \n\n-- ftd.text: $", SOURCE);
+        let symbols = symbols(source.as_str());
+        let components = super::completion(source.as_str(), 15, 3, symbols.as_slice());
+        assert!(components.iter().any(|v| v.label.eq("card")));
+        assert!(components.iter().any(|v| v.label.eq("ftd.text")));
+        assert!(!components.iter().any(|v| v.label.eq("name")));
+
+        let variables = super::completion(source.as_str(), 17, 14, symbols.as_slice());
+        assert!(variables.iter().any(|v| v.label.eq("name")));
+        assert!(!variables.iter().any(|v| v.label.eq("card")));
+    }
+
+    #[test]
+    fn hover_and_definition() {
+        let symbols = symbols(SOURCE);
+        assert_eq!(
+            super::hover(SOURCE, 12, 5, symbols.as_slice()).unwrap(),
+            indoc::indoc! {"
+                ```ftd
+                -- component card:
+                caption title:
+                mutable boolean open:
+                ```
+
+                A card with a title"}
+        );
+
+        let source = SOURCE.replace("\nti\n", "\ntitle: Hi\n");
+        assert_eq!(
+            super::hover(source.as_str(), 13, 2, symbols.as_slice()).unwrap(),
+            "```ftd\ncaption title\n```\n\nshown in bold"
+        );
+        let location = super::definition(SOURCE, 12, 4, symbols.as_slice()).unwrap();
+        assert_eq!((location.line_number, location.columns), (2, 13..17));
+    }
+
+    #[test]
+    fn utf16_positions() {
+        // `🎉` is two UTF-16 code units, `é` is one
+        let line = "-- ftd.text: 🎉 é $name";
+        assert_eq!(super::from_utf16(line, 15), 14);
+        assert_eq!(super::from_utf16(line, 18), 17);
+        assert_eq!(super::from_utf16(line, 100), 22);
+        assert_eq!(super::to_utf16(line, 17), 18);
+        assert_eq!(super::to_utf16(line, 13), 13);
+    }
+}
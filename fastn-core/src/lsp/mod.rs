@@ -0,0 +1,483 @@
+pub mod features;
+pub mod symbols;
+
+/// Language server for `.ftd` documents, speaking the language server protocol (JSON-RPC
+/// over stdio).
+///
+/// The documents open in the editor are kept in memory, every change is run through the
+/// interpreter (the same way `fastn build` does) and its errors published as diagnostics.
+/// Completion, hover and go to definition work off the symbols of the document, of its
+/// imports (including the auto imports of `FASTN.ftd`, and modules of dependencies) and the
+/// `ftd` built-ins.
+struct Server {
+    config: fastn_core::Config,
+    /// open documents: uri -> content
+    documents: std::collections::HashMap<String, String>,
+    built_ins: Vec<fastn_core::lsp::symbols::Symbol>,
+}
+
+pub async fn serve(config: fastn_core::Config) -> fastn_core::Result<()> {
+    let mut server = Server {
+        config,
+        documents: Default::default(),
+        built_ins: fastn_core::lsp::symbols::built_ins(),
+    };
+    let mut stdin = tokio::io::BufReader::new(tokio::io::stdin());
+    let mut stdout = tokio::io::stdout();
+
+    while let Some(body) = read_message(&mut stdin).await? {
+        // a message that is not JSON, or not a JSON-RPC object, gets an error response (with a
+        // `null` id, as its id can not be known) instead of ending the session
+        let message = match serde_json::from_slice::<serde_json::Value>(body.as_slice()) {
+            Ok(message) if message.is_object() => message,
+            Ok(_) => {
+                write_message(&mut stdout, error_response(-32600, "invalid request")).await?;
+                continue;
+            }
+            Err(e) => {
+                write_message(
+                    &mut stdout,
+                    error_response(-32700, format!("parse error: {}", e).as_str()),
+                )
+                .await?;
+                continue;
+            }
+        };
+        let method = message
+            .get("method")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default();
+        let params = message.get("params").cloned().unwrap_or_default();
+        if method.eq("exit") {
+            break;
+        }
+
+        let (result, notifications) = server.handle(method, params).await;
+        for (method, params) in notifications {
+            write_message(
+                &mut stdout,
+                serde_json::json!({"jsonrpc": "2.0", "method": method, "params": params}),
+            )
+            .await?;
+        }
+
+        // messages without an id are notifications, and do not get a response
+        let id = match message.get("id") {
+            Some(id) => id.clone(),
+            None => continue,
+        };
+        let response = match result {
+            Some(result) => serde_json::json!({"jsonrpc": "2.0", "id": id, "result": result}),
+            None => serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "error": {"code": -32601, "message": format!("method not found: {}", method)},
+            }),
+        };
+        write_message(&mut stdout, response).await?;
+    }
+    Ok(())
+}
+
+type Notifications = Vec<(&'static str, serde_json::Value)>;
+
+impl Server {
+    /// Returns the result of the request (`None` if the method is not supported) and the
+    /// notifications to send to the client.
+    async fn handle(
+        &mut self,
+        method: &str,
+        params: serde_json::Value,
+    ) -> (Option<serde_json::Value>, Notifications) {
+        let uri = params["textDocument"]["uri"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string();
+        // the character of the position is in UTF-16 code units, it is converted to characters
+        // of the line once the source is known
+        let position = (
+            params["position"]["line"].as_u64().unwrap_or_default() as usize,
+            params["position"]["character"].as_u64().unwrap_or_default() as usize,
+        );
+        let character = |source: &str| {
+            fastn_core::lsp::features::from_utf16(
+                source.split('\n').nth(position.0).unwrap_or_default(),
+                position.1,
+            )
+        };
+
+        match method {
+            "initialize" => (
+                Some(serde_json::json!({
+                    "capabilities": {
+                        // full document sync
+                        "textDocumentSync": 1,
+                        "completionProvider": {"triggerCharacters": ["-", " ", "$", "."]},
+                        "hoverProvider": true,
+                        "definitionProvider": true,
+                    },
+                    "serverInfo": {"name": "fastn", "version": env!("CARGO_PKG_VERSION")},
+                })),
+                vec![],
+            ),
+            "shutdown" => (Some(serde_json::Value::Null), vec![]),
+            "textDocument/didOpen" => {
+                let text = params["textDocument"]["text"].as_str().unwrap_or_default();
+                self.documents.insert(uri.clone(), text.to_string());
+                (None, vec![self.publish_diagnostics(uri.as_str()).await])
+            }
+            "textDocument/didChange" => {
+                // with full sync the last change has the whole document
+                if let Some(text) = params["contentChanges"]
+                    .as_array()
+                    .and_then(|changes| changes.last())
+                    .and_then(|change| change["text"].as_str())
+                {
+                    self.documents.insert(uri.clone(), text.to_string());
+                }
+                (None, vec![self.publish_diagnostics(uri.as_str()).await])
+            }
+            "textDocument/didSave" => (None, vec![self.publish_diagnostics(uri.as_str()).await]),
+            "textDocument/didClose" => {
+                self.documents.remove(&uri);
+                (
+                    None,
+                    vec![(
+                        "textDocument/publishDiagnostics",
+                        serde_json::json!({"uri": uri, "diagnostics": []}),
+                    )],
+                )
+            }
+            "textDocument/completion" => {
+                let (source, symbols) = self.symbols(uri.as_str());
+                let items = fastn_core::lsp::features::completion(
+                    source.as_str(),
+                    position.0,
+                    character(source.as_str()),
+                    symbols.as_slice(),
+                )
+                .into_iter()
+                .map(|item| {
+                    serde_json::json!({
+                        "label": item.label,
+                        "kind": item.kind.to_lsp(),
+                        "detail": item.detail,
+                        "insertText": item.insert_text,
+                    })
+                })
+                .collect::<Vec<_>>();
+                (Some(serde_json::Value::Array(items)), vec![])
+            }
+            "textDocument/hover" => {
+                let (source, symbols) = self.symbols(uri.as_str());
+                let hover = fastn_core::lsp::features::hover(
+                    source.as_str(),
+                    position.0,
+                    character(source.as_str()),
+                    symbols.as_slice(),
+                )
+                .map(|markdown| {
+                    serde_json::json!({"contents": {"kind": "markdown", "value": markdown}})
+                });
+                (Some(hover.unwrap_or_default()), vec![])
+            }
+            "textDocument/definition" => {
+                let (source, symbols) = self.symbols(uri.as_str());
+                let location = fastn_core::lsp::features::definition(
+                    source.as_str(),
+                    position.0,
+                    character(source.as_str()),
+                    symbols.as_slice(),
+                )
+                .and_then(|location| {
+                    let uri = url::Url::from_file_path(location.path.as_std_path()).ok()?;
+                    let line = location.line_number.saturating_sub(1);
+                    let source = self.source(uri.as_str()).unwrap_or_default();
+                    let text = source.split('\n').nth(line).unwrap_or_default();
+                    Some(serde_json::json!({
+                        "uri": uri.to_string(),
+                        "range": {
+                            "start": {
+                                "line": line,
+                                "character":
+                                    fastn_core::lsp::features::to_utf16(text, location.columns.start),
+                            },
+                            "end": {
+                                "line": line,
+                                "character":
+                                    fastn_core::lsp::features::to_utf16(text, location.columns.end),
+                            },
+                        },
+                    }))
+                });
+                (Some(location.unwrap_or_default()), vec![])
+            }
+            _ => (None, vec![]),
+        }
+    }
+
+    /// The document for `uri`: the open content if the editor has it open, else read from disk.
+    fn document(&self, uri: &str) -> Option<fastn_core::Document> {
+        let path =
+            camino::Utf8PathBuf::from_path_buf(url::Url::parse(uri).ok()?.to_file_path().ok()?)
+                .ok()?;
+        let id = path.strip_prefix(&self.config.root).ok()?.to_string();
+        Some(fastn_core::Document {
+            package_name: self.config.package.name.to_string(),
+            id,
+            content: self.source(uri)?,
+            parent_path: self.config.root.to_string(),
+        })
+    }
+
+    /// The content of `uri`: the open content if the editor has it open, else read from disk.
+    fn source(&self, uri: &str) -> Option<String> {
+        match self.documents.get(uri) {
+            Some(content) => Some(content.to_string()),
+            None => std::fs::read_to_string(url::Url::parse(uri).ok()?.to_file_path().ok()?).ok(),
+        }
+    }
+
+    async fn publish_diagnostics(&self, uri: &str) -> (&'static str, serde_json::Value) {
+        let diagnostics = match self.document(uri) {
+            Some(document) => {
+                let doc_id = document.id_with_package();
+                interpret(&self.config, &document)
+                    .await
+                    .into_iter()
+                    .filter(|d| d.doc_id.eq(&doc_id) || d.doc_id.is_empty())
+                    .map(|d| {
+                        // the characters are in UTF-16 code units, as the protocol counts them
+                        let content = document.content.as_str();
+                        let (line, start, end) = match d.span(content) {
+                            Some(span) => {
+                                let line_start =
+                                    content[..span.start].rfind('\n').map_or(0, |v| v + 1);
+                                let start = content[line_start..span.start].encode_utf16().count();
+                                (
+                                    span.line_number - 1,
+                                    start,
+                                    start + content[span.start..span.end].encode_utf16().count(),
+                                )
+                            }
+                            None => (d.line_number.saturating_sub(1), 0, 0),
+                        };
+                        serde_json::json!({
+                            "range": {
+                                "start": {"line": line, "character": start},
+                                "end": {"line": line, "character": end},
+                            },
+                            "severity": match d.severity {
+                                ftd::diagnostic::Severity::Error => 1,
+                                ftd::diagnostic::Severity::Warning => 2,
+                            },
+                            "source": "fastn",
+                            "message": d.message,
+                        })
+                    })
+                    .collect()
+            }
+            None => vec![],
+        };
+        (
+            "textDocument/publishDiagnostics",
+            serde_json::json!({"uri": uri, "diagnostics": diagnostics}),
+        )
+    }
+
+    /// The source of the document along with every symbol that can be referred to from it:
+    /// its own definitions, those of its imports (with the import alias as prefix, and without
+    /// for `exposing`) and the `ftd` built-ins.
+    fn symbols(&self, uri: &str) -> (String, Vec<fastn_core::lsp::symbols::Symbol>) {
+        let document = match self.document(uri) {
+            Some(document) => document,
+            None => return (Default::default(), self.built_ins.clone()),
+        };
+        let doc_id = document.id_with_package();
+        let path = self.config.root.join(document.id.as_str());
+        let mut symbols = fastn_core::lsp::symbols::from_source(
+            None,
+            doc_id.as_str(),
+            document.content.as_str(),
+            path.as_path(),
+        );
+
+        let prefixed = self.config.package.get_prefixed_body(
+            document.content.as_str(),
+            document.id.as_str(),
+            true,
+        );
+        let (sections, _) = ftd::p1::parse_with_errors(prefixed.as_str(), doc_id.as_str(), 0);
+        let (asts, _) =
+            ftd::ast::AST::from_sections_with_errors(sections.as_slice(), doc_id.as_str());
+        for ast in asts {
+            let import = match ast {
+                ftd::ast::AST::Import(import) => import,
+                _ => continue,
+            };
+            let path = match self.module_path(import.module.as_str()) {
+                Some(path) => path,
+                None => continue,
+            };
+            let source = std::fs::read_to_string(&path).unwrap_or_default();
+            let imported = fastn_core::lsp::symbols::from_source(
+                Some(import.alias.as_str()),
+                import.module.as_str(),
+                source.as_str(),
+                path.as_path(),
+            );
+            let exposed = imported
+                .iter()
+                .filter_map(|s| {
+                    let name = s.name.strip_prefix(format!("{}.", import.alias).as_str())?;
+                    match import.exposing {
+                        Some(ftd::ast::Exposing::All) => {}
+                        Some(ftd::ast::Exposing::Things(ref things))
+                            if things.iter().any(|t| t.eq(name)) => {}
+                        _ => return None,
+                    }
+                    let mut symbol = s.clone();
+                    symbol.name = name.to_string();
+                    Some(symbol)
+                })
+                .collect::<Vec<_>>();
+            symbols.extend(imported);
+            symbols.extend(exposed);
+        }
+        symbols.extend(self.built_ins.iter().cloned());
+        (document.content, symbols)
+    }
+
+    /// The file of `module` (`<package>/<id>`, or `<alias>/<id>` for dependencies with an
+    /// alias), in this package or one of its dependencies (under `.packages`).
+    fn module_path(&self, module: &str) -> Option<camino::Utf8PathBuf> {
+        let mut module = module.trim_matches('/').to_string();
+        for (alias, package) in self.config.package.aliases() {
+            if module.eq(alias) || module.starts_with(format!("{}/", alias).as_str()) {
+                module = module.replacen(alias, package.name.as_str(), 1);
+                break;
+            }
+        }
+
+        let packages =
+            std::iter::once((self.config.package.name.as_str(), self.config.root.clone())).chain(
+                self.config.package.dependencies.iter().map(|d| {
+                    (
+                        d.package.name.as_str(),
+                        self.config.packages_root.join(d.package.name.as_str()),
+                    )
+                }),
+            );
+        for (name, root) in packages {
+            let id = if module.eq(name) {
+                "/".to_string()
+            } else if let Some(id) = module.strip_prefix(format!("{}/", name).as_str()) {
+                id.to_string()
+            } else {
+                continue;
+            };
+            if let Some(path) = fastn_core::package::package_doc::file_id_to_names(id.as_str())
+                .into_iter()
+                .filter(|name| name.ends_with(".ftd"))
+                .map(|name| root.join(name))
+                .find(|path| path.exists())
+            {
+                return Some(path);
+            }
+        }
+        None
+    }
+}
+
+/// Interprets the document the way `fastn build` does and returns the errors found, along with
+/// the warnings (unused imports and `private` definitions) if there are none.
+async fn interpret(
+    config: &fastn_core::Config,
+    document: &fastn_core::Document,
+) -> Vec<ftd::diagnostic::Diagnostic> {
+    let mut lib = fastn_core::Library2022 {
+        config: config.clone(),
+        markdown: None,
+        document_id: document.id.clone(),
+        translated_data: Default::default(),
+        base_url: "/".to_string(),
+        module_package_map: Default::default(),
+    };
+    let doc_content =
+        config
+            .package
+            .get_prefixed_body(document.content.as_str(), document.id.as_str(), true);
+    let line_number = doc_content.split('\n').count() - document.content.split('\n').count();
+    let doc_id = document.id_with_package();
+    match fastn_core::doc::interpret_helper(
+        doc_id.as_str(),
+        doc_content.as_str(),
+        &mut lib,
+        "/",
+        false,
+        line_number,
+    )
+    .await
+    {
+        Ok(_) => ftd::interpreter::ParsedDocument::parse_with_line_number(
+            doc_id.as_str(),
+            doc_content.as_str(),
+            line_number,
+        )
+        .map(|parsed| parsed.warnings)
+        .unwrap_or_default(),
+        Err(e) => e.to_diagnostics(),
+    }
+}
+
+/// The body of the next message, `None` once the input is closed. The body is not parsed here,
+/// so that a malformed message can be answered with an error.
+async fn read_message<R>(reader: &mut R) -> fastn_core::Result<Option<Vec<u8>>>
+where
+    R: tokio::io::AsyncBufRead + Unpin,
+{
+    use tokio::io::{AsyncBufReadExt, AsyncReadExt};
+
+    let mut content_length = None;
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header).await? == 0 {
+            return Ok(None);
+        }
+        let header = header.trim();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((key, value)) = header.split_once(':') {
+            if key.trim().eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse::<usize>().ok();
+            }
+        }
+    }
+
+    let mut body = vec![0; content_length.unwrap_or_default()];
+    reader.read_exact(&mut body).await?;
+    Ok(Some(body))
+}
+
+fn error_response(code: i32, message: &str) -> serde_json::Value {
+    serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": serde_json::Value::Null,
+        "error": {"code": code, "message": message},
+    })
+}
+
+async fn write_message<W>(writer: &mut W, message: serde_json::Value) -> fastn_core::Result<()>
+where
+    W: tokio::io::AsyncWrite + Unpin,
+{
+    use tokio::io::AsyncWriteExt;
+
+    let body = serde_json::to_string(&message)?;
+    writer
+        .write_all(format!("Content-Length: {}\r\n\r\n{}", body.len(), body).as_bytes())
+        .await?;
+    writer.flush().await?;
+    Ok(())
+}
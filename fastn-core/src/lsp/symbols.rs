@@ -0,0 +1,281 @@
+/// A thing (component, variable, record etc.) that can be referred to from a document, under
+/// the name it is referred to with: `card` if it is defined in the document itself, `lib.card`
+/// if it comes from the `lib` import, `ftd.text` for built-ins.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Symbol {
+    pub name: String,
+    pub kind: SymbolKind,
+    /// how the thing is defined, e.g. `component card`, `string list names`
+    pub detail: String,
+    /// the `;;` comment lines right above the definition
+    pub doc: Option<String>,
+    pub arguments: Vec<Argument>,
+    pub location: Option<Location>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SymbolKind {
+    Component,
+    WebComponent,
+    Variable,
+    Record,
+    OrType,
+    Function,
+}
+
+/// A header of a component, or a field of a record.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Argument {
+    pub name: String,
+    pub detail: String,
+    pub doc: Option<String>,
+    pub line_number: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Location {
+    pub path: camino::Utf8PathBuf,
+    /// 1 based, as in `ftd::p1`
+    pub line_number: usize,
+    /// the characters of the name on that line, 0 based
+    pub columns: std::ops::Range<usize>,
+}
+
+impl Symbol {
+    pub fn is_component(&self) -> bool {
+        matches!(self.kind, SymbolKind::Component | SymbolKind::WebComponent)
+    }
+
+    /// The hover text: the definition as ftd code followed by the doc comment.
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = format!("```ftd\n-- {}:\n", self.detail);
+        for argument in self.arguments.iter() {
+            markdown.push_str(format!("{}:\n", argument.detail).as_str());
+        }
+        markdown.push_str("```");
+        if let Some(ref doc) = self.doc {
+            markdown.push_str(format!("\n\n{}", doc).as_str());
+        }
+        markdown
+    }
+}
+
+/// All the things defined in `source`. `prefix` is the alias the document is imported with,
+/// `None` for the document being edited.
+///
+/// Sections that fail to parse are skipped, so a half written document still gives the
+/// symbols of the rest of it.
+pub fn from_source(
+    prefix: Option<&str>,
+    doc_id: &str,
+    source: &str,
+    path: &camino::Utf8Path,
+) -> Vec<Symbol> {
+    let (sections, _) = ftd::p1::parse_with_errors(source, doc_id, 0);
+    let (asts, _) = ftd::ast::AST::from_sections_with_errors(sections.as_slice(), doc_id);
+    let name = |n: &str| match prefix {
+        Some(prefix) => format!("{}.{}", prefix, n),
+        None => n.to_string(),
+    };
+
+    asts.into_iter()
+        .filter_map(|ast| {
+            let (kind, detail, arguments, line_number) = match ast {
+                ftd::ast::AST::ComponentDefinition(c) => (
+                    SymbolKind::Component,
+                    format!("component {}", c.name),
+                    c.arguments,
+                    c.line_number,
+                ),
+                ftd::ast::AST::WebComponentDefinition(w) => (
+                    SymbolKind::WebComponent,
+                    format!("web-component {}", w.name),
+                    w.arguments,
+                    w.line_number,
+                ),
+                ftd::ast::AST::Record(r) => (
+                    SymbolKind::Record,
+                    format!("record {}", r.name),
+                    r.fields,
+                    r.line_number,
+                ),
+                ftd::ast::AST::OrType(o) => (
+                    SymbolKind::OrType,
+                    format!("or-type {}", o.name),
+                    vec![],
+                    o.line_number,
+                ),
+                ftd::ast::AST::VariableDefinition(v) => (
+                    SymbolKind::Variable,
+                    format!("{} {}", ast_kind_to_string(&v.kind, v.mutable), v.name),
+                    vec![],
+                    v.line_number,
+                ),
+                ftd::ast::AST::FunctionDefinition(f) => (
+                    SymbolKind::Function,
+                    format!("{} {}", ast_kind_to_string(&f.kind, false), f.name),
+                    f.arguments,
+                    f.line_number,
+                ),
+                _ => return None,
+            };
+            let detail_name = detail.rsplit_once(' ').map(|v| v.1).unwrap_or_default();
+            let location = Location {
+                path: path.to_path_buf(),
+                line_number,
+                columns: name_columns(source, line_number, detail_name),
+            };
+            Some(Symbol {
+                name: name(detail_name),
+                kind,
+                arguments: arguments
+                    .into_iter()
+                    .map(|a| Argument {
+                        detail: format!("{} {}", ast_kind_to_string(&a.kind, a.mutable), a.name),
+                        doc: doc_comment(source, a.line_number),
+                        name: a.name,
+                        line_number: a.line_number,
+                    })
+                    .collect(),
+                detail,
+                doc: doc_comment(source, line_number),
+                location: Some(location),
+            })
+        })
+        .collect()
+}
+
+/// The `ftd` built-ins: kernel components, `ftd.*` variables, records, or-types and functions.
+pub fn built_ins() -> Vec<Symbol> {
+    let aliases = ftd::interpreter::default::default_aliases();
+    let bag = ftd::interpreter::default::default_bag();
+    let doc = ftd::interpreter::TDoc::new("ftd", &aliases, &bag);
+
+    bag.keys()
+        .filter_map(|full_name| full_name.strip_prefix("ftd#"))
+        .filter_map(|name| {
+            let name = format!("ftd.{}", name);
+            let (kind, detail, arguments) = match doc.get_thing(name.as_str(), 0).ok()? {
+                ftd::interpreter::Thing::Component(c) => (
+                    SymbolKind::Component,
+                    format!("component {}", name),
+                    c.arguments,
+                ),
+                ftd::interpreter::Thing::WebComponent(w) => (
+                    SymbolKind::WebComponent,
+                    format!("web-component {}", name),
+                    w.arguments,
+                ),
+                ftd::interpreter::Thing::Record(r) => {
+                    (SymbolKind::Record, format!("record {}", name), r.fields)
+                }
+                ftd::interpreter::Thing::OrType(_) => {
+                    (SymbolKind::OrType, format!("or-type {}", name), vec![])
+                }
+                ftd::interpreter::Thing::Variable(v) => (
+                    SymbolKind::Variable,
+                    format!("{} {}", kind_data_to_string(&v.kind, v.mutable), name),
+                    vec![],
+                ),
+                ftd::interpreter::Thing::Function(f) => (
+                    SymbolKind::Function,
+                    format!("{} {}", kind_data_to_string(&f.return_kind, false), name),
+                    f.arguments,
+                ),
+                _ => return None,
+            };
+            Some(Symbol {
+                name,
+                kind,
+                detail,
+                doc: None,
+                arguments: arguments
+                    .into_iter()
+                    .map(|a| Argument {
+                        detail: format!("{} {}", kind_data_to_string(&a.kind, a.mutable), a.name),
+                        name: a.name,
+                        doc: None,
+                        line_number: 0,
+                    })
+                    .collect(),
+                location: None,
+            })
+        })
+        .collect()
+}
+
+/// Where `name` is written on the `-- ` line (1 based `line_number`) that defines it: the last
+/// occurrence before the `:` (and the arguments of a function), `0..0` if it is not there.
+fn name_columns(source: &str, line_number: usize, name: &str) -> std::ops::Range<usize> {
+    let line = source
+        .split('\n')
+        .nth(line_number.saturating_sub(1))
+        .unwrap_or_default();
+    let head = line.split([':', '(']).next().unwrap_or_default();
+    match head.rfind(name).filter(|_| !name.is_empty()) {
+        Some(offset) => {
+            let start = head[..offset].chars().count();
+            start..start + name.chars().count()
+        }
+        None => 0..0,
+    }
+}
+
+/// The contiguous `;;` comment lines right above `line_number` (1 based).
+pub fn doc_comment(source: &str, line_number: usize) -> Option<String> {
+    use itertools::Itertools;
+
+    let lines = source.split('\n').collect_vec();
+    let comment = lines
+        .get(..line_number.checked_sub(1)?)?
+        .iter()
+        .rev()
+        .map(|l| l.trim())
+        .take_while(|l| l.starts_with(";;"))
+        .map(|l| l.trim_start_matches(";;").trim())
+        .collect_vec();
+    if comment.is_empty() {
+        return None;
+    }
+    Some(comment.into_iter().rev().join("\n"))
+}
+
+fn ast_kind_to_string(kind: &ftd::ast::VariableKind, mutable: bool) -> String {
+    let kind_string = match kind.modifier {
+        Some(ftd::ast::VariableModifier::List) => format!("{} list", kind.kind),
+        Some(ftd::ast::VariableModifier::Optional) => format!("optional {}", kind.kind),
+        None => kind.kind.to_string(),
+    };
+    if mutable {
+        format!("mutable {}", kind_string)
+    } else {
+        kind_string
+    }
+}
+
+fn kind_data_to_string(kind: &ftd::interpreter::KindData, mutable: bool) -> String {
+    let mut kind_string = kind_to_string(&kind.kind);
+    if kind.body {
+        kind_string = format!("body {}", kind_string);
+    }
+    if kind.caption {
+        kind_string = format!("caption {}", kind_string);
+    }
+    if mutable {
+        kind_string = format!("mutable {}", kind_string);
+    }
+    kind_string
+}
+
+fn kind_to_string(kind: &ftd::interpreter::Kind) -> String {
+    match kind {
+        ftd::interpreter::Kind::List { kind } => format!("{} list", kind_to_string(kind)),
+        ftd::interpreter::Kind::Optional { kind } => format!("optional {}", kind_to_string(kind)),
+        ftd::interpreter::Kind::Map { key, kind } => {
+            format!("map<{}, {}>", kind_to_string(key), kind_to_string(kind))
+        }
+        ftd::interpreter::Kind::Constant { kind } => kind_to_string(kind),
+        ftd::interpreter::Kind::UI { .. } => "ftd.ui".to_string(),
+        _ => kind.get_name().replace("ftd#", "ftd."),
+    }
+}
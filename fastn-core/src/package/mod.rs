@@ -31,6 +31,9 @@ pub struct Package {
     ///
     /// Note that this too is kind of bad design, we will move fonts to `fastn_core::Package` struct soon.
     pub fonts: Vec<fastn_core::Font>,
+    /// The `fastn.breakpoint`s of the package, from the narrowest to the widest. They are the
+    /// `ftd.breakpoints` of its pages, unless a page declares its own on `ftd.document`.
+    pub breakpoints: Vec<ftd::executor::Breakpoint>,
     pub import_auto_imports_from_original: bool,
 
     pub groups: std::collections::BTreeMap<String, crate::user_group::UserGroup>,
@@ -70,6 +73,11 @@ pub struct Package {
 
     /// Redirect URLs
     pub redirects: Option<ftd::Map<String>>,
+
+    /// Where `fastn status`, `fastn diff` and `fastn sync` keep the history of the package. By
+    /// default it is fastn's own `.remote-state`, `backing-store: git` uses the git repository
+    /// the package is in instead.
+    pub backing_store: Option<String>,
 }
 
 impl Package {
@@ -90,6 +98,7 @@ impl Package {
             fastn_path: None,
             ignored_paths: vec![],
             fonts: vec![],
+            breakpoints: vec![],
             import_auto_imports_from_original: true,
             groups: std::collections::BTreeMap::new(),
             sitemap_temp: None,
@@ -103,6 +112,7 @@ impl Package {
             apps: vec![],
             icon: None,
             redirects: None,
+            backing_store: None,
         }
     }
 
@@ -172,8 +182,16 @@ impl Package {
             .to_owned()
     }
 
-    pub fn get_font_html(&self) -> String {
+    /// The `@font-face` rules of the package fonts, a font found in `subsetted` (by its
+    /// `html_name`) is written in place of the one declared by the package.
+    pub fn get_font_html(
+        &self,
+        subsetted: &std::collections::HashMap<String, fastn_core::Font>,
+    ) -> String {
         self.fonts.iter().fold(String::new(), |accumulator, font| {
+            let font = subsetted
+                .get(&font.html_name(self.name.as_str()))
+                .unwrap_or(font);
             format!(
                 "{pre}{new}\n",
                 pre = accumulator,
@@ -527,6 +545,8 @@ impl Package {
             .map(|f| f.into_auto_import())
             .collect();
         package.fonts = fastn_document.get("fastn#font")?;
+        package.breakpoints = fastn_document.get("fastn#breakpoint")?;
+        check_breakpoints(package.breakpoints.as_slice())?;
         package.sitemap_temp = fastn_document.get("fastn#sitemap")?;
         *self = package;
         Ok(())
@@ -615,6 +635,8 @@ impl Package {
 
         package.ignored_paths = fastn_doc.get::<Vec<String>>("fastn#ignore")?;
         package.fonts = fastn_doc.get("fastn#font")?;
+        package.breakpoints = fastn_doc.get("fastn#breakpoint")?;
+        check_breakpoints(package.breakpoints.as_slice())?;
         package.sitemap_temp = fastn_doc.get("fastn#sitemap")?;
         package.dynamic_urls_temp = fastn_doc.get("fastn#dynamic-urls")?;
 
@@ -685,6 +707,18 @@ impl Package {
     }
 }
 
+/// A `fastn.breakpoint` is named after one of the values the `ftd.responsive-*` records have.
+fn check_breakpoints(breakpoints: &[ftd::executor::Breakpoint]) -> fastn_core::Result<()> {
+    let aliases = ftd::interpreter::default::default_aliases();
+    let bag = ftd::interpreter::default::default_bag();
+    let doc = ftd::interpreter::TDoc::new("FASTN", &aliases, &bag);
+    ftd::executor::Breakpoint::check_names(breakpoints, &doc, 0).map_err(|e| {
+        fastn_core::Error::PackageError {
+            message: format!("`fastn.breakpoint`: {}", e),
+        }
+    })
+}
+
 trait PackageTempIntoPackage {
     fn into_package(self) -> Package;
 }
@@ -721,6 +755,7 @@ impl PackageTempIntoPackage for fastn_package::old_fastn::PackageTemp {
             fastn_path: None,
             ignored_paths: vec![],
             fonts: vec![],
+            breakpoints: vec![],
             import_auto_imports_from_original: self.import_auto_imports_from_original,
             groups: std::collections::BTreeMap::new(),
             sitemap: None,
@@ -734,6 +769,7 @@ impl PackageTempIntoPackage for fastn_package::old_fastn::PackageTemp {
             apps: vec![],
             icon: self.icon,
             redirects: None,
+            backing_store: self.backing_store,
         }
     }
 }
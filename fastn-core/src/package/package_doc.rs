@@ -344,19 +344,32 @@ pub(crate) async fn read_ftd(
             unimplemented!()
         }
         fastn_core::FTDEdition::FTD2022 => {
-            read_ftd_2022(config, main, base_url, download_assets, test).await
+            read_ftd_2022(
+                config,
+                main,
+                base_url,
+                download_assets,
+                test,
+                Default::default(),
+                None,
+            )
+            .await
         }
     }
 }
 
 #[allow(clippy::await_holding_refcell_ref)]
 #[tracing::instrument(name = "read_ftd_2022", skip_all)]
+/// `translated_data` and `message` are set for the documents of a translation package, the
+/// `message` document is shown above `main`, see `fastn_core::TranslatedDocument::html`.
 pub(crate) async fn read_ftd_2022(
     config: &mut fastn_core::Config,
     main: &fastn_core::Document,
     base_url: &str,
     download_assets: bool,
     test: bool,
+    translated_data: fastn_core::TranslationData,
+    message: Option<&str>,
 ) -> fastn_core::Result<Vec<u8>> {
     let lib_config = config.clone();
     let mut all_packages = config.all_packages.borrow_mut();
@@ -368,19 +381,28 @@ pub(crate) async fn read_ftd_2022(
         config: lib_config,
         markdown: None,
         document_id: main.id.clone(),
-        translated_data: Default::default(),
+        translated_data,
         base_url: base_url.to_string(),
         module_package_map: Default::default(),
     };
 
-    // Get Prefix Body => [AutoImports + Actual Doc content]
+    // Get Prefix Body => [AutoImports + Message + Actual Doc content]
+    let content = match message {
+        Some(message) => format!(
+            "{}
+
+{}",
+            message, main.content
+        ),
+        None => main.content.to_string(),
+    };
     let mut doc_content =
-        current_package.get_prefixed_body(main.content.as_str(), main.id.as_str(), true);
+        current_package.get_prefixed_body(content.as_str(), main.id.as_str(), true);
     // Fix aliased imports to full path (if any)
     doc_content = current_package.fix_imports_in_body(doc_content.as_str(), main.id.as_str())?;
 
     let line_number = doc_content.split('\n').count() - main.content.split('\n').count();
-    let main_ftd_doc = match fastn_core::doc::interpret_helper(
+    let mut main_ftd_doc = match fastn_core::doc::interpret_helper(
         main.id_with_package().as_str(),
         doc_content.as_str(),
         &mut lib,
@@ -393,13 +415,33 @@ pub(crate) async fn read_ftd_2022(
         Ok(v) => v,
         Err(e) => {
             tracing::error!(msg = "failed to parse", doc = main.id.as_str());
+            let id = main.id_with_package();
             return Err(fastn_core::Error::PackageError {
-                message: format!("failed to parse {:?}", &e),
+                message: format!(
+                    "failed to parse {}\n{}",
+                    main.id,
+                    ftd::diagnostic::render(e.to_diagnostics().as_slice(), |doc_id| {
+                        doc_id.eq(id.as_str()).then_some(main.content.as_str())
+                    })
+                ),
             });
         }
     };
+    if !config.package.breakpoints.is_empty() {
+        ftd::executor::ExecuteDoc::set_breakpoints(
+            &mut main_ftd_doc.data,
+            config.package.breakpoints.as_slice(),
+            0,
+        );
+    }
     let executor = ftd::executor::ExecuteDoc::from_interpreter(main_ftd_doc)?;
     let node = ftd::node::NodeData::from_rt(executor);
+    // `-.ftd` is the generated package info page
+    let accessibility_issues = if test && main.id.ne("-.ftd") {
+        node.accessibility_issues()
+    } else {
+        vec![]
+    };
     let html_ui = ftd::html::HtmlUI::from_node_data(node, "main", test)?;
 
     all_packages.extend(lib.config.all_packages.into_inner());
@@ -408,8 +450,19 @@ pub(crate) async fn read_ftd_2022(
     config
         .downloaded_assets
         .extend(lib.config.downloaded_assets);
+    config.accessibility_issues.extend(
+        accessibility_issues
+            .into_iter()
+            .map(|issue| format!("{}:{}: {}", main.id, issue.line_number, issue.message)),
+    );
 
-    let font_style = config.get_font_style();
+    // when building with static assets the fonts are subsetted once all the pages are built, the
+    // `@font-face` rules are written then, by `fastn_core::web_font::optimise`
+    let font_style = if download_assets {
+        fastn_core::web_font::FONT_STYLE_MARKER.to_string()
+    } else {
+        config.get_font_style()
+    };
     let file_content = fastn_core::utils::replace_markers_2022(
         fastn_core::ftd_html(),
         html_ui,
@@ -454,24 +507,47 @@ pub(crate) async fn process_ftd(
         main
     };
 
-    let file_rel_path = if main.id.eq("404.ftd") {
-        "404.html".to_string()
-    } else if main.id.contains("index.ftd") {
-        main.id.replace("index.ftd", "index.html")
-    } else {
-        main.id.replace(
-            ".ftd",
-            format!("{}index.html", std::path::MAIN_SEPARATOR).as_str(),
-        )
-    };
-
     let response = read_ftd(config, &main, base_url, !no_static, test).await?;
     fastn_core::utils::write(
         &config.build_dir(),
-        file_rel_path.as_str(),
+        build_file_path(main.id.as_str()).as_str(),
         response.as_slice(),
     )
     .await?;
 
     Ok(response)
 }
+
+/// Builds the document `id` of a translation package, the translated document or the original
+/// one, with the message of its translation status.
+pub(crate) async fn process_translated_ftd(
+    config: &mut fastn_core::Config,
+    id: &str,
+    translated: &fastn_core::TranslatedDocument,
+    base_url: &str,
+    no_static: bool,
+) -> fastn_core::Result<Vec<u8>> {
+    let response = translated.html(config, base_url, !no_static).await?;
+    fastn_core::utils::write(
+        &config.build_dir(),
+        build_file_path(id).as_str(),
+        response.as_slice(),
+    )
+    .await?;
+
+    Ok(response)
+}
+
+/// The path in `.build` of the html of the document `id`.
+fn build_file_path(id: &str) -> String {
+    if id.eq("404.ftd") {
+        "404.html".to_string()
+    } else if id.contains("index.ftd") {
+        id.replace("index.ftd", "index.html")
+    } else {
+        id.replace(
+            ".ftd",
+            format!("{}index.html", std::path::MAIN_SEPARATOR).as_str(),
+        )
+    }
+}
@@ -1195,7 +1195,8 @@ impl Sitemap {
                         toc_item.writers.clone(),
                         toc_item.icon.clone(),
                         toc_item.bury,
-                    );
+                    )
+                    .add_modified(toc_item.file_location.as_ref());
                     current_toc.children = children;
                     if is_open {
                         found_here = true;
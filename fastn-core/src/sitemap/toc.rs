@@ -117,6 +117,8 @@ pub struct TocItemCompat {
     pub extra_data: std::collections::BTreeMap<String, String>,
     #[serde(rename = "nav-title")]
     pub nav_title: Option<String>,
+    /// when the file of the page was last modified, for a `datetime` field
+    pub modified: Option<String>,
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -150,6 +152,7 @@ impl TocItemCompat {
             document: None,
             extra_data: Default::default(),
             nav_title: None,
+            modified: None,
         }
     }
 
@@ -157,4 +160,9 @@ impl TocItemCompat {
         self.path = Some(path.to_string());
         self
     }
+
+    pub(crate) fn add_modified(mut self, file_location: Option<&camino::Utf8PathBuf>) -> Self {
+        self.modified = file_location.and_then(|v| fastn_core::utils::modified_rfc3339(v));
+        self
+    }
 }
@@ -1,13 +1,10 @@
-#![allow(dead_code, unused, unused_variables)]
-
 #[derive(Debug)]
 pub(crate) enum TranslatedDocument {
     Missing {
         original: fastn_core::File,
     },
     NeverMarked {
-        original: fastn_core::File,   // main
-        translated: fastn_core::File, // fallback
+        original: fastn_core::File,
     },
     Outdated {
         original: fastn_core::File,   // fallback
@@ -30,40 +27,31 @@ pub struct TranslationData {
     pub status: Option<String>,
 }
 
-impl TranslationData {
-    fn new(status: &str) -> TranslationData {
-        TranslationData {
-            diff: None,
-            last_marked_on: None,
-            original_latest: None,
-            translated_latest: None,
-            status: Some(status.to_string()),
-        }
-    }
-}
-
 impl TranslatedDocument {
+    /// Renders the translated document, or the original one while the translation is missing or
+    /// was never marked, with the message of the translation status above it.
     pub async fn html(
         &self,
-        config: &fastn_core::Config,
-        _base_url: &str,
-        _skip_failed: bool,
-        _asset_documents: &std::collections::HashMap<String, String>,
-    ) -> fastn_core::Result<()> {
-        // handle the message
-        // render with-fallback or with-message
-        let _message = fastn_core::get_messages(self, config)?;
-        let (_main, _fallback, _translated_data) = match self {
-            TranslatedDocument::Missing { original } => {
-                (original, None, TranslationData::new("Missing"))
-            }
-            TranslatedDocument::NeverMarked {
+        config: &mut fastn_core::Config,
+        base_url: &str,
+        download_assets: bool,
+    ) -> fastn_core::Result<Vec<u8>> {
+        let status = Some(self.status().as_str().to_string());
+        let (main, translated_data) = match self {
+            TranslatedDocument::Missing { original }
+            | TranslatedDocument::NeverMarked { original } => (
                 original,
+                TranslationData {
+                    status,
+                    ..Default::default()
+                },
+            ),
+            TranslatedDocument::UptoDate { translated } => (
                 translated,
-            } => (
-                original,
-                Some(translated),
-                TranslationData::new("NeverMarked"),
+                TranslationData {
+                    status,
+                    ..Default::default()
+                },
             ),
             TranslatedDocument::Outdated {
                 original,
@@ -72,63 +60,70 @@ impl TranslatedDocument {
                 original_latest,
                 translated_latest,
             } => {
-                // Gets the diff on original file between last_marked_on and original_latest timestamp
                 let diff = get_diff(config, original, last_marked_on, original_latest).await?;
                 let translated_data = TranslationData {
-                    diff: Some(diff),
+                    diff: Some(escape_diff(diff.as_str())),
                     last_marked_on: Some(*last_marked_on),
                     original_latest: Some(*original_latest),
                     translated_latest: Some(*translated_latest),
-                    status: Some("Outdated".to_string()),
+                    status,
                 };
-
-                (translated, Some(original), translated_data)
-            }
-            TranslatedDocument::UptoDate { translated, .. } => {
-                (translated, None, TranslationData::new("UptoDate"))
+                (translated, translated_data)
             }
         };
 
-        todo!();
-        // fastn_core::process_file(
-        //     config,
-        //     &config.package,
-        //     main,
-        //     fallback,
-        //     Some(message.as_str()),
-        //     translated_data,
-        //     base_url,
-        //     skip_failed,
-        //     asset_documents,
-        //     None,
-        //     false,
-        // )
-        // .await?;
-        // return Ok(());
-
-        /// Gets the diff on original file between last_marked_on and original_latest timestamp
-        async fn get_diff(
-            config: &fastn_core::Config,
-            original: &fastn_core::File,
-            last_marked_on: &u128,
-            original_latest: &u128,
-        ) -> fastn_core::Result<String> {
-            let last_marked_on_path = fastn_core::utils::history_path(
-                original.get_id().as_str(),
-                config.original_path()?.as_str(),
-                last_marked_on,
-            );
-            let last_marked_on_data = tokio::fs::read_to_string(last_marked_on_path).await?;
-            let original_latest_path = fastn_core::utils::history_path(
-                original.get_id().as_str(),
-                config.original_path()?.as_str(),
-                original_latest,
-            );
-            let original_latest_data = tokio::fs::read_to_string(original_latest_path).await?;
+        let main = match main {
+            fastn_core::File::Ftd(main) => main,
+            _ => {
+                return Err(fastn_core::Error::UsageError {
+                    message: format!("{} is not an ftd document", main.get_id()),
+                })
+            }
+        };
+        let message = self.message(config)?;
+        fastn_core::package::package_doc::read_ftd_2022(
+            config,
+            main,
+            base_url,
+            download_assets,
+            false,
+            translated_data,
+            Some(message.as_str()),
+        )
+        .await
+    }
 
-            let patch = diffy::create_patch(&last_marked_on_data, &original_latest_data);
-            Ok(patch.to_string().replace("---", "\\---"))
-        }
+    /// The message shown above the document for this translation status. A package changes it
+    /// with its own `fastn/translation/<status>.ftd`.
+    fn message(&self, config: &fastn_core::Config) -> fastn_core::Result<String> {
+        let (file_name, default) = match self {
+            TranslatedDocument::Missing { .. } => (
+                "missing.ftd",
+                include_str!("../ftd/translation/missing.ftd"),
+            ),
+            TranslatedDocument::NeverMarked { .. } => (
+                "never-marked.ftd",
+                include_str!("../ftd/translation/never-marked.ftd"),
+            ),
+            TranslatedDocument::Outdated { .. } => (
+                "out-of-date.ftd",
+                include_str!("../ftd/translation/out-of-date.ftd"),
+            ),
+            TranslatedDocument::UptoDate { .. } => (
+                "upto-date.ftd",
+                include_str!("../ftd/translation/upto-date.ftd"),
+            ),
+        };
+        let path = config
+            .root
+            .join("fastn")
+            .join("translation")
+            .join(file_name);
+        Ok(if path.is_file() {
+            std::fs::read_to_string(path)?
+        } else {
+            default.to_string()
+        })
     }
 
     pub async fn get_translated_document(
@@ -164,7 +159,6 @@ impl TranslatedDocument {
                     file,
                     TranslatedDocument::NeverMarked {
                         original: original_document.clone(),
-                        translated: translated_document.clone(),
                     },
                 );
                 continue;
@@ -200,7 +194,6 @@ impl TranslatedDocument {
                     file,
                     TranslatedDocument::NeverMarked {
                         original: original_document.clone(),
-                        translated: translated_document.clone(),
                     },
                 );
             }
@@ -209,45 +202,425 @@ impl TranslatedDocument {
     }
 }
 
-pub(crate) fn get_translation_status_counts(
-    snapshots: &std::collections::BTreeMap<String, u128>,
-    path: &camino::Utf8PathBuf,
-) -> fastn_core::Result<TranslationStatusSummary> {
-    let mut translation_status_count = TranslationStatusSummary {
-        never_marked: 0,
-        missing: 0,
-        out_dated: 0,
-        upto_date: 0,
-        last_modified_on: None,
+/// Returns the translation status of every document of the original package, with the files of
+/// the original and the current (translation) package already loaded.
+pub(crate) async fn get_translated_documents(
+    config: &fastn_core::Config,
+) -> fastn_core::Result<std::collections::BTreeMap<String, TranslatedDocument>> {
+    let original = original_package(config)?;
+    let original_documents = config
+        .get_files(original)
+        .await?
+        .into_iter()
+        .map(|v| (v.get_id(), v))
+        .collect();
+    let translated_documents = config
+        .get_files(&config.package)
+        .await?
+        .into_iter()
+        .map(|v| (v.get_id(), v))
+        .collect();
+    TranslatedDocument::get_translated_document(config, original_documents, translated_documents)
+        .await
+}
+
+fn original_package(config: &fastn_core::Config) -> fastn_core::Result<&fastn_core::Package> {
+    match config.package.translation_of.as_ref() {
+        Some(original) => Ok(original),
+        None => Err(fastn_core::Error::UsageError {
+            message: "This package is not a translation package, `translation-of` is not set in \
+                FASTN.ftd"
+                .to_string(),
+        }),
+    }
+}
+
+/// A machine translation service, used to pre-fill the documents of a translation package so
+/// that translators start with a draft instead of a copy of the original.
+///
+/// `translate` gets plain text (a paragraph of prose, never ftd syntax) and returns it in the
+/// target language.
+pub trait TranslationProvider {
+    fn name(&self) -> &str;
+
+    fn translate(&self, text: &str, from: Option<&str>, to: &str) -> fastn_core::Result<String>;
+}
+
+/// Does not translate anything, the documents are copied over as they are.
+pub struct CopyTranslationProvider;
+
+impl TranslationProvider for CopyTranslationProvider {
+    fn name(&self) -> &str {
+        "copy"
+    }
+
+    fn translate(&self, text: &str, _from: Option<&str>, _to: &str) -> fastn_core::Result<String> {
+        Ok(text.to_string())
+    }
+}
+
+/// Local, deterministic provider for tests: prefixes every translated paragraph with the target
+/// language, e.g. `Hello` becomes `[hi] Hello`.
+pub struct StubTranslationProvider;
+
+impl TranslationProvider for StubTranslationProvider {
+    fn name(&self) -> &str {
+        "stub"
+    }
+
+    fn translate(&self, text: &str, _from: Option<&str>, to: &str) -> fastn_core::Result<String> {
+        Ok(format!("[{}] {}", to, text))
+    }
+}
+
+/// Returns the translation provider with the given name, as passed to `--provider`.
+pub fn get_provider(name: &str) -> fastn_core::Result<Box<dyn TranslationProvider>> {
+    match name {
+        "copy" => Ok(Box::new(CopyTranslationProvider)),
+        "stub" => Ok(Box::new(StubTranslationProvider)),
+        _ => fastn_core::usage_error(format!(
+            "Unknown translation provider `{}`, available providers: copy, stub",
+            name
+        )),
+    }
+}
+
+/// Translates the prose of an ftd document: the captions, the header values of several words
+/// and the body of every section, paragraph by paragraph. References, numbers, urls etc, the
+/// imports, the definitions of components, records and functions, comments and the body of
+/// `ftd.code` sections are left untouched.
+pub(crate) fn translate_ftd(
+    provider: &dyn TranslationProvider,
+    content: &str,
+    from: Option<&str>,
+    to: &str,
+) -> fastn_core::Result<String> {
+    enum State {
+        Headers,
+        Body,
+        Verbatim,
+    }
+
+    let mut output: Vec<String> = vec![];
+    let mut paragraph: Vec<&str> = vec![];
+    let mut state = State::Verbatim;
+
+    for line in content.split('\n') {
+        if line.starts_with("-- ") || line.starts_with("/-- ") {
+            flush_paragraph(provider, &mut paragraph, &mut output, from, to)?;
+            let section = line.strip_prefix("-- ").map(|v| v.split_once(':'));
+            let (name, caption) = match section {
+                Some(Some((name, caption))) if !is_verbatim_section(name) => (name, caption),
+                _ => {
+                    state = State::Verbatim;
+                    output.push(line.to_string());
+                    continue;
+                }
+            };
+            state = State::Headers;
+            output.push(if is_prose(caption) {
+                format!(
+                    "-- {}: {}",
+                    name,
+                    translate_line(provider, caption, from, to)?
+                )
+            } else {
+                line.to_string()
+            });
+            continue;
+        }
+        match state {
+            State::Headers if line.trim().is_empty() => {
+                state = State::Body;
+                output.push(line.to_string());
+            }
+            State::Headers => {
+                output.push(match line.split_once(':') {
+                    Some((key, value))
+                        if !key.starts_with('$')
+                            && key.ne("if")
+                            && is_prose(value)
+                            && value.trim().contains(char::is_whitespace) =>
+                    {
+                        format!("{}: {}", key, translate_line(provider, value, from, to)?)
+                    }
+                    _ => line.to_string(),
+                });
+            }
+            State::Body if line.trim().is_empty() => {
+                flush_paragraph(provider, &mut paragraph, &mut output, from, to)?;
+                output.push(line.to_string());
+            }
+            State::Body if !line.starts_with(";;") => paragraph.push(line),
+            _ => {
+                flush_paragraph(provider, &mut paragraph, &mut output, from, to)?;
+                output.push(line.to_string());
+            }
+        }
+    }
+    flush_paragraph(provider, &mut paragraph, &mut output, from, to)?;
+
+    return Ok(output.join("\n"));
+
+    /// The sections with no prose: imports, definitions and code.
+    fn is_verbatim_section(name: &str) -> bool {
+        name.contains('(')
+            || matches!(
+                name.split_whitespace().next(),
+                Some(
+                    "import"
+                        | "component"
+                        | "web-component"
+                        | "record"
+                        | "or-type"
+                        | "end"
+                        | "ftd.code"
+                )
+            )
+    }
+
+    /// Whether a caption or a header value is text, and not a reference, an expression, a number,
+    /// a boolean, a url or a file name.
+    fn is_prose(value: &str) -> bool {
+        let value = value.trim();
+        !(value.is_empty()
+            || value.starts_with(['$', '{', '/', '#'])
+            || value.parse::<f64>().is_ok()
+            || value.eq("true")
+            || value.eq("false")
+            || (!value.contains(char::is_whitespace) && value.contains(['.', '/', ':'])))
+    }
+
+    fn translate_line(
+        provider: &dyn TranslationProvider,
+        text: &str,
+        from: Option<&str>,
+        to: &str,
+    ) -> fastn_core::Result<String> {
+        // a caption or a header is a single line
+        Ok(provider
+            .translate(text.trim(), from, to)?
+            .lines()
+            .map(str::trim)
+            .collect::<Vec<_>>()
+            .join(" "))
+    }
+
+    fn flush_paragraph(
+        provider: &dyn TranslationProvider,
+        paragraph: &mut Vec<&str>,
+        output: &mut Vec<String>,
+        from: Option<&str>,
+        to: &str,
+    ) -> fastn_core::Result<()> {
+        if paragraph.is_empty() {
+            return Ok(());
+        }
+        let translated = provider.translate(paragraph.join("\n").as_str(), from, to)?;
+        paragraph.clear();
+        // the translated text must not start new sections or comments
+        output.extend(translated.split('\n').map(|line| {
+            if line.starts_with("-- ") || line.starts_with(";;") {
+                format!("\\{}", line)
+            } else {
+                line.to_string()
+            }
+        }));
+        Ok(())
+    }
+}
+
+/// Creates the documents of the original package that the translation package does not have
+/// yet, pre-filled using `provider`. Static files are not copied, they are served from the
+/// original package. Returns the ids of the created documents.
+pub async fn create_skeleton(
+    config: &fastn_core::Config,
+    provider: &dyn TranslationProvider,
+) -> fastn_core::Result<Vec<String>> {
+    let original = original_package(config)?;
+    let language = match config.package.language.as_ref() {
+        Some(language) => language,
+        None => {
+            return fastn_core::usage_error(
+                "`language` is not set in FASTN.ftd, cannot translate documents".to_string(),
+            )
+        }
     };
-    for (file, timestamp) in snapshots {
-        if !path.join(file).exists() {
-            translation_status_count.missing += 1;
+
+    let mut created = vec![];
+    for file in config.get_files(original).await? {
+        let id = file.get_id();
+        if id.eq("FASTN.ftd") || config.root.join(id.as_str()).exists() {
             continue;
         }
-        let track_path = fastn_core::utils::track_path(file.as_str(), path.as_str());
-        if !track_path.exists() {
-            translation_status_count.never_marked += 1;
+        let content = match file {
+            fastn_core::File::Ftd(ref doc) => translate_ftd(
+                provider,
+                doc.content.as_str(),
+                original.language.as_deref(),
+                language,
+            )?,
+            fastn_core::File::Markdown(ref doc) => {
+                provider.translate(doc.content.as_str(), original.language.as_deref(), language)?
+            }
+            _ => continue,
+        };
+        fastn_core::utils::update(config.root.join(id.as_str()), content.as_bytes()).await?;
+        created.push(id);
+    }
+    Ok(created)
+}
+
+/// Returns the diff of the original document since the translation was last marked up to date,
+/// for every outdated document, or only for `file` if given.
+pub async fn get_outdated_diffs(
+    config: &fastn_core::Config,
+    file: Option<&str>,
+) -> fastn_core::Result<std::collections::BTreeMap<String, String>> {
+    let mut diffs = std::collections::BTreeMap::new();
+    for (id, document) in get_translated_documents(config).await? {
+        if file.map(|v| v != id).unwrap_or(false) {
             continue;
         }
-        let tracks = fastn_core::tracker::get_tracks(path.as_str(), &track_path)?;
-        if let Some(fastn_core::Track {
-            last_merged_version: Some(last_merged_version),
+        if let TranslatedDocument::Outdated {
+            original,
+            last_marked_on,
+            original_latest,
             ..
-        }) = tracks.get(file)
+        } = document
         {
-            if last_merged_version < timestamp {
-                translation_status_count.out_dated += 1;
-                continue;
+            let diff = get_diff(config, &original, &last_marked_on, &original_latest).await?;
+            diffs.insert(id, diff);
+        }
+    }
+    Ok(diffs)
+}
+
+/// The `/-/translation-status/` dashboard: counts per status, the outdated documents with the
+/// diff of the original since they were last marked, and the documents needing a translation.
+pub(crate) async fn dashboard(config: &fastn_core::Config) -> fastn_core::Result<String> {
+    let documents = get_translated_documents(config).await?;
+    let count = |status: &str| {
+        documents
+            .values()
+            .filter(|v| v.status().as_str() == status)
+            .count()
+    };
+
+    let mut content = indoc::formatdoc! {"
+        -- ftd.column:
+        padding.px: 40
+        spacing.fixed.px: 16
+        width: fill-container
+
+        -- ftd.text: Translation status of {package}
+        style: bold
+
+        -- ftd.text: Out-dated: {outdated}, Never marked: {never_marked}, Missing: {missing}, Up to date: {upto_date}
+        ",
+        package = config.package.name,
+        outdated = count("Out-dated"),
+        never_marked = count("Never marked"),
+        missing = count("Missing"),
+        upto_date = count("Up to date"),
+    };
+
+    for (id, document) in documents.iter() {
+        let status = document.status();
+        if let TranslatedDocument::UptoDate { .. } = document {
+            continue;
+        }
+        content.push_str(
+            indoc::formatdoc! {"
+
+                -- ftd.text: {status}: {id}
+                link: /{url}
+                style: bold
+                ",
+                status = status.as_str(),
+                id = id,
+                url = id_to_url(id),
             }
-            translation_status_count.upto_date += 1;
-        } else {
-            translation_status_count.never_marked += 1;
+            .as_str(),
+        );
+        if let TranslatedDocument::Outdated {
+            original,
+            last_marked_on,
+            original_latest,
+            ..
+        } = document
+        {
+            let diff = get_diff(config, original, last_marked_on, original_latest).await?;
+            content.push_str("\n-- ftd.code:\nlang: diff\n\n");
+            content.push_str(escape_diff(diff.as_str()).as_str());
+        }
+    }
+    content.push_str("\n-- end: ftd.column\n");
+    Ok(content)
+}
+
+fn id_to_url(id: &str) -> String {
+    let id = id
+        .strip_suffix(".ftd")
+        .or_else(|| id.strip_suffix(".md"))
+        .unwrap_or(id);
+    if id.eq("index") {
+        return String::new();
+    }
+    match id.strip_suffix("/index") {
+        Some(id) => format!("{}/", id),
+        None => format!("{}/", id),
+    }
+}
+
+impl TranslatedDocument {
+    pub(crate) fn status(&self) -> fastn_core::commands::translation_status::TranslationStatus {
+        use fastn_core::commands::translation_status::TranslationStatus;
+
+        match self {
+            TranslatedDocument::Missing { .. } => TranslationStatus::Missing,
+            TranslatedDocument::NeverMarked { .. } => TranslationStatus::NeverMarked,
+            TranslatedDocument::Outdated { .. } => TranslationStatus::Outdated,
+            TranslatedDocument::UptoDate { .. } => TranslationStatus::UptoDate,
         }
     }
-    translation_status_count.last_modified_on =
-        futures::executor::block_on(fastn_core::utils::get_last_modified_on(path));
-    Ok(translation_status_count)
+}
+
+/// Gets the diff on original file between last_marked_on and original_latest timestamp
+pub(crate) async fn get_diff(
+    config: &fastn_core::Config,
+    original: &fastn_core::File,
+    last_marked_on: &u128,
+    original_latest: &u128,
+) -> fastn_core::Result<String> {
+    let last_marked_on_path = fastn_core::utils::history_path(
+        original.get_id().as_str(),
+        config.original_path()?.as_str(),
+        last_marked_on,
+    );
+    let last_marked_on_data = tokio::fs::read_to_string(last_marked_on_path).await?;
+    let original_latest_path = fastn_core::utils::history_path(
+        original.get_id().as_str(),
+        config.original_path()?.as_str(),
+        original_latest,
+    );
+    let original_latest_data = tokio::fs::read_to_string(original_latest_path).await?;
+
+    Ok(diffy::create_patch(&last_marked_on_data, &original_latest_data).to_string())
+}
+
+/// Escapes a diff so that it can be the body of a section: the lines removed from the original
+/// that start with `-- ` would otherwise start a new section.
+fn escape_diff(diff: &str) -> String {
+    diff.lines()
+        .map(|line| {
+            if line.starts_with("-- ") {
+                format!("\\{}\n", line)
+            } else {
+                format!("{}\n", line)
+            }
+        })
+        .collect()
 }
 
 #[derive(serde::Deserialize, Debug, Clone)]
@@ -280,3 +653,93 @@ impl ToString for TranslationStatusSummary {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn stub_translates_only_prose() {
+        let original = indoc::indoc! {"
+            -- import: fastn
+
+            -- component card:
+            caption title: Untitled card
+
+            -- ftd.text: $card.title
+
+            -- end: card
+
+            -- card: Welcome
+            id: welcome-card
+
+            -- ftd.text: Heading
+            role: $inherited.types.heading-large
+            padding.px: 40
+            link: https://example.com/hello-world
+            alt: Hello world of fastn
+
+            Hello world.
+            Same paragraph.
+
+            ;; comment
+            Second paragraph.
+
+            -- ftd.image: /images/hello world.png
+
+            -- integer count: 5
+
+            -- ftd.code:
+            lang: rs
+
+            fn main() {}
+        "};
+        assert_eq!(
+            super::translate_ftd(&super::StubTranslationProvider, original, None, "hi").unwrap(),
+            indoc::indoc! {"
+                -- import: fastn
+
+                -- component card:
+                caption title: Untitled card
+
+                -- ftd.text: $card.title
+
+                -- end: card
+
+                -- card: [hi] Welcome
+                id: welcome-card
+
+                -- ftd.text: [hi] Heading
+                role: $inherited.types.heading-large
+                padding.px: 40
+                link: https://example.com/hello-world
+                alt: [hi] Hello world of fastn
+
+                [hi] Hello world.
+                Same paragraph.
+
+                ;; comment
+                [hi] Second paragraph.
+
+                -- ftd.image: /images/hello world.png
+
+                -- integer count: 5
+
+                -- ftd.code:
+                lang: rs
+
+                fn main() {}
+            "}
+        );
+    }
+
+    #[test]
+    fn diff_is_escaped_for_ftd_code() {
+        let diff = diffy::create_patch("-- ftd.text: a\n\n- one\n", "-- ftd.text: b\n\n- two\n")
+            .to_string();
+        let escaped = super::escape_diff(diff.as_str());
+        assert!(escaped.lines().all(|line| !line.starts_with("-- ")));
+        assert!(escaped.starts_with("--- original\n"));
+        assert!(escaped.contains("\n--- ftd.text: a\n"));
+        assert!(escaped.contains("\n\\-- one\n"));
+        assert!(escaped.contains("\n+-- ftd.text: b\n"));
+    }
+}
@@ -215,6 +215,16 @@ pub(crate) fn nanos_to_rfc3339(nanos: &u128) -> String {
     nanos.to_string() // TODO
 }
 
+/// The modification time of the file at `path` as an ISO-8601 date time in UTC, e.g.
+/// `2023-06-01T10:30:00Z`, for the `datetime` kind.
+pub(crate) fn modified_rfc3339(path: &camino::Utf8Path) -> Option<String> {
+    let modified = std::fs::metadata(path).ok()?.modified().ok()?;
+    Some(
+        chrono::DateTime::<chrono::Utc>::from(modified)
+            .to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+    )
+}
+
 pub(crate) fn history_path(id: &str, base_path: &str, timestamp: &u128) -> camino::Utf8PathBuf {
     let id_with_timestamp_extension = snapshot_id(id, timestamp);
     let base_path = camino::Utf8PathBuf::from(base_path);
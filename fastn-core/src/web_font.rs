@@ -0,0 +1,562 @@
+/// Pages built with static assets have this in place of the `@font-face` rules, it is replaced
+/// by `optimise()` once the fonts are subsetted.
+pub(crate) const FONT_STYLE_MARKER: &str = "__fastn_font_style__";
+
+/// Subsetting of self hosted fonts, done by `fastn build` once all the pages are generated.
+///
+/// Every truetype/opentype font the package (or any of its dependencies) lists is cut down to the
+/// glyphs of the characters used across the generated pages and saved as woff2 next to the
+/// original file. The `@font-face` rules are then written in the pages, the subsetted fonts with
+/// an `unicode-range` listing only the characters the font has, and the fonts are preloaded by
+/// the pages using them.
+pub(crate) async fn optimise(config: &mut fastn_core::Config) -> fastn_core::Result<()> {
+    use sha2::Digest;
+
+    let pages = built_pages(&config.build_dir());
+    if pages.is_empty() {
+        return Ok(());
+    }
+
+    let mut used = std::collections::BTreeSet::new();
+    // printable ascii is always kept, it is cheap and covers text added by scripts at runtime
+    used.extend((0x20u8..0x7f).map(char::from));
+    for page in pages.iter() {
+        used.extend(tokio::fs::read_to_string(page).await?.chars());
+    }
+
+    let mut preloads = vec![];
+    for (package_name, font) in config.all_fonts() {
+        let url = match font.sfnt_url() {
+            Some(url) if !fastn_core::config::utils::is_http_url(url) => url.to_string(),
+            _ => continue,
+        };
+        let start = std::time::Instant::now();
+        let content = tokio::fs::read(config.build_dir().join(url.as_str())).await?;
+        let (compressed, chars) = match subset(content.as_slice(), &used) {
+            Some(tables) => tables,
+            None => {
+                fastn_core::utils::print_end(
+                    format!("Skipped {}, not a truetype or opentype font", url).as_str(),
+                    start,
+                );
+                continue;
+            }
+        };
+        let woff2_url = format!(
+            "{}-{}.woff2",
+            url.rsplit_once('.').map(|(v, _)| v).unwrap_or(url.as_str()),
+            &format!("{:x}", sha2::Sha256::digest(&compressed))[..8]
+        );
+        fastn_core::utils::update(&config.build_dir().join(woff2_url.as_str()), &compressed)
+            .await?;
+        fastn_core::utils::print_end(
+            format!(
+                "Subsetted {} to {} glyph(s), {} -> {} bytes",
+                url,
+                chars.len(),
+                content.len(),
+                compressed.len()
+            )
+            .as_str(),
+            start,
+        );
+
+        let family = font.html_name(package_name.as_str());
+        // the fonts in normal style are the ones used by the text of the page, and so the ones
+        // needed for the first paint, italic and other variants can come in later
+        if font.is_normal_style() {
+            preloads.push((family.clone(), woff2_url.clone()));
+        }
+        config.subsetted_fonts.insert(
+            family,
+            font.subsetted(woff2_url.as_str(), unicode_range(&chars).as_str()),
+        );
+    }
+
+    let font_style = config.get_font_style();
+    for page in pages {
+        let html = tokio::fs::read_to_string(&page).await?;
+        if !html.contains(FONT_STYLE_MARKER) {
+            continue;
+        }
+        let links = preloads
+            .iter()
+            .filter(|(family, _)| uses_family(html.as_str(), family))
+            .map(|(_, woff2_url)| {
+                format!(
+                    "<link rel=\"preload\" href=\"/{}\" as=\"font\" type=\"font/woff2\" \
+                        crossorigin>\n",
+                    woff2_url.trim_start_matches('/')
+                )
+            })
+            .collect::<String>();
+        let html = html
+            .replacen(FONT_STYLE_MARKER, font_style.as_str(), 1)
+            .replacen("</head>", format!("{}</head>", links).as_str(), 1);
+        fastn_core::utils::update(&page, html.as_bytes()).await?;
+    }
+
+    Ok(())
+}
+
+fn built_pages(build_dir: &camino::Utf8Path) -> Vec<camino::Utf8PathBuf> {
+    ignore::WalkBuilder::new(build_dir)
+        .standard_filters(false)
+        .build()
+        .flatten()
+        .map(|x| camino::Utf8PathBuf::from_path_buf(x.into_path()).unwrap())
+        .filter(|x| x.is_file() && x.extension().map(|v| v.eq("html")).unwrap_or(false))
+        .collect()
+}
+
+fn uses_family(html: &str, family: &str) -> bool {
+    html.match_indices("font-family").any(|(i, _)| {
+        html[i + "font-family".len()..]
+            .trim_start_matches(|c: char| c == ':' || c == '"' || c == '\'' || c.is_whitespace())
+            .starts_with(family)
+    })
+}
+
+/// Formats the characters as a css `unicode-range`, consecutive characters are merged into a
+/// range, e.g. `U+20-7E, U+E9`.
+pub(crate) fn unicode_range(chars: &[char]) -> String {
+    let mut ranges: Vec<(u32, u32)> = vec![];
+    for c in chars.iter().map(|c| *c as u32) {
+        match ranges.last_mut() {
+            Some((_, end)) if *end + 1 == c => *end = c,
+            _ => ranges.push((c, c)),
+        }
+    }
+    ranges
+        .into_iter()
+        .map(|(start, end)| {
+            if start == end {
+                format!("U+{:X}", start)
+            } else {
+                format!("U+{:X}-{:X}", start, end)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Subsets the font to the glyphs of `used` characters and returns it as woff2, along with the
+/// characters out of `used` that the font has. Glyphs are not renumbered, the unused ones are
+/// emptied, so the layout tables (`GSUB`, `GPOS`, `kern`) stay valid as they are. Opentype
+/// fonts with `CFF` outlines can not be emptied this way and are only compressed.
+///
+/// Returns `None` if `data` is not a truetype or opentype font.
+pub(crate) fn subset(
+    data: &[u8],
+    used: &std::collections::BTreeSet<char>,
+) -> Option<(Vec<u8>, Vec<char>)> {
+    let face = ttf_parser::Face::parse(data, 0).ok()?;
+    let mut glyphs = std::collections::BTreeSet::from([0u16]);
+    let mut chars = vec![];
+    for c in used {
+        if let Some(glyph) = face.glyph_index(*c) {
+            glyphs.insert(glyph.0);
+            chars.push(*c);
+        }
+    }
+
+    let (flavor, mut tables) = read_tables(data)?;
+    // a signature is no longer valid once the font is modified
+    tables.retain(|(tag, _)| tag != b"DSIG");
+    if let Some((glyf, loca, head)) = empty_unused_glyphs(&tables, glyphs) {
+        for (tag, table) in tables.iter_mut() {
+            match &*tag {
+                b"glyf" => *table = glyf.clone(),
+                b"loca" => *table = loca.clone(),
+                b"head" => *table = head.clone(),
+                _ => {}
+            }
+        }
+    }
+
+    Some((woff2(flavor, tables).ok()?, chars))
+}
+
+type Tables = Vec<([u8; 4], Vec<u8>)>;
+
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_be_bytes(
+        data.get(offset..offset + 2)?.try_into().ok()?,
+    ))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_be_bytes(
+        data.get(offset..offset + 4)?.try_into().ok()?,
+    ))
+}
+
+fn read_tables(data: &[u8]) -> Option<(u32, Tables)> {
+    let flavor = read_u32(data, 0)?;
+    if ![
+        0x0001_0000,
+        u32::from_be_bytes(*b"true"),
+        u32::from_be_bytes(*b"OTTO"),
+    ]
+    .contains(&flavor)
+    {
+        return None;
+    }
+    let mut tables = vec![];
+    for i in 0..read_u16(data, 4)? as usize {
+        let record = 12 + 16 * i;
+        let tag: [u8; 4] = data.get(record..record + 4)?.try_into().ok()?;
+        let offset = read_u32(data, record + 8)? as usize;
+        let length = read_u32(data, record + 12)? as usize;
+        tables.push((tag, data.get(offset..offset + length)?.to_vec()));
+    }
+    tables.sort_by_key(|(tag, _)| *tag);
+    Some((flavor, tables))
+}
+
+/// Returns the new `glyf`, `loca` and `head` tables, where every glyph not in `glyphs` (or used
+/// by a composite glyph in `glyphs`) is empty. `loca` is always written in the long format.
+fn empty_unused_glyphs(
+    tables: &Tables,
+    mut glyphs: std::collections::BTreeSet<u16>,
+) -> Option<(Vec<u8>, Vec<u8>, Vec<u8>)> {
+    let table = |name: &[u8; 4]| tables.iter().find(|(tag, _)| tag == name).map(|(_, v)| v);
+    let (glyf, loca, head, maxp) = (
+        table(b"glyf")?,
+        table(b"loca")?,
+        table(b"head")?,
+        table(b"maxp")?,
+    );
+
+    let long = read_u16(head, 50)? == 1;
+    let offsets = (0..=read_u16(maxp, 4)? as usize)
+        .map(|i| {
+            if long {
+                read_u32(loca, i * 4).map(|v| v as usize)
+            } else {
+                read_u16(loca, i * 2).map(|v| v as usize * 2)
+            }
+        })
+        .collect::<Option<Vec<usize>>>()?;
+    let glyph = |id: usize| glyf.get(offsets[id]..offsets[id + 1]);
+
+    // composite glyphs are drawn using other glyphs, they have to be kept as well
+    let mut pending = glyphs.iter().copied().collect::<Vec<_>>();
+    while let Some(id) = pending.pop() {
+        let data = match glyph(id as usize) {
+            Some(data) if data.len() >= 10 && (read_u16(data, 0)? as i16) < 0 => data,
+            _ => continue,
+        };
+        let mut offset = 10;
+        loop {
+            let flags = read_u16(data, offset)?;
+            let component = read_u16(data, offset + 2)?;
+            if (component as usize) < offsets.len() - 1 && glyphs.insert(component) {
+                pending.push(component);
+            }
+            offset += 4 + if flags & 0x0001 != 0 { 4 } else { 2 };
+            offset += if flags & 0x0008 != 0 {
+                2
+            } else if flags & 0x0040 != 0 {
+                4
+            } else if flags & 0x0080 != 0 {
+                8
+            } else {
+                0
+            };
+            if flags & 0x0020 == 0 {
+                break;
+            }
+        }
+    }
+
+    let mut new_glyf = vec![];
+    let mut new_loca = vec![];
+    for id in 0..offsets.len() - 1 {
+        new_loca.extend((new_glyf.len() as u32).to_be_bytes());
+        if glyphs.contains(&(id as u16)) {
+            new_glyf.extend(glyph(id)?);
+            new_glyf.resize((new_glyf.len() + 3) & !3, 0);
+        }
+    }
+    new_loca.extend((new_glyf.len() as u32).to_be_bytes());
+
+    let mut new_head = head.clone();
+    // checkSumAdjustment is recomputed by the woff2 decoder
+    new_head[8..12].copy_from_slice(&[0; 4]);
+    new_head[50..52].copy_from_slice(&1u16.to_be_bytes());
+    Some((new_glyf, new_loca, new_head))
+}
+
+/// Tables with a known tag are referred by their index in this list in the woff2 table
+/// directory, as per https://www.w3.org/TR/WOFF2/#table_dir_format.
+const WOFF2_KNOWN_TAGS: [&[u8; 4]; 63] = [
+    b"cmap", b"head", b"hhea", b"hmtx", b"maxp", b"name", b"OS/2", b"post", b"cvt ", b"fpgm",
+    b"glyf", b"loca", b"prep", b"CFF ", b"VORG", b"EBDT", b"EBLC", b"gasp", b"hdmx", b"kern",
+    b"LTSH", b"PCLT", b"VDMX", b"vhea", b"vmtx", b"BASE", b"GDEF", b"GPOS", b"GSUB", b"EBSC",
+    b"JSTF", b"MATH", b"CBDT", b"CBLC", b"COLR", b"CPAL", b"SVG ", b"sbix", b"acnt", b"avar",
+    b"bdat", b"bloc", b"bsln", b"cvar", b"fdsc", b"feat", b"fmtx", b"fvar", b"gvar", b"hsty",
+    b"just", b"lcar", b"mort", b"morx", b"opbd", b"prop", b"trak", b"Zapf", b"Silf", b"Glat",
+    b"Gloc", b"Feat", b"Sill",
+];
+
+/// Encodes the tables as woff2. No table is transformed (`glyf` and `loca` use the null
+/// transform), all of them are compressed together using brotli.
+fn woff2(flavor: u32, tables: Tables) -> std::io::Result<Vec<u8>> {
+    use std::io::Write;
+
+    let mut directory = vec![];
+    let mut data = vec![];
+    let mut sfnt_size = 12 + 16 * tables.len();
+    for (tag, table) in tables.iter() {
+        let transform = if tag == b"glyf" || tag == b"loca" {
+            3 << 6
+        } else {
+            0
+        };
+        match WOFF2_KNOWN_TAGS.iter().position(|v| *v == tag) {
+            Some(index) => directory.push(index as u8 | transform),
+            None => {
+                directory.push(63 | transform);
+                directory.extend(tag);
+            }
+        }
+        push_base128(&mut directory, table.len() as u32);
+        data.extend(table);
+        sfnt_size += (table.len() + 3) & !3;
+    }
+
+    let mut compressor = brotli::CompressorWriter::new(vec![], 4096, 11, 22);
+    compressor.write_all(&data)?;
+    let compressed = compressor.into_inner();
+
+    let mut woff2 = Vec::with_capacity(48 + directory.len() + compressed.len() + 3);
+    woff2.extend(b"wOF2");
+    woff2.extend(flavor.to_be_bytes());
+    woff2.extend(0u32.to_be_bytes()); // length, set below
+    woff2.extend((tables.len() as u16).to_be_bytes());
+    woff2.extend(0u16.to_be_bytes()); // reserved
+    woff2.extend((sfnt_size as u32).to_be_bytes());
+    woff2.extend((compressed.len() as u32).to_be_bytes());
+    woff2.extend(1u16.to_be_bytes()); // major version
+    woff2.extend(0u16.to_be_bytes()); // minor version
+    woff2.extend([0; 20]); // no metadata and private data
+    woff2.extend(directory);
+    woff2.extend(compressed);
+    woff2.resize((woff2.len() + 3) & !3, 0);
+    let length = (woff2.len() as u32).to_be_bytes();
+    woff2[8..12].copy_from_slice(&length);
+    Ok(woff2)
+}
+
+fn push_base128(buffer: &mut Vec<u8>, value: u32) {
+    let mut bytes = vec![(value & 0x7f) as u8];
+    let mut value = value >> 7;
+    while value > 0 {
+        bytes.push((value & 0x7f) as u8 | 0x80);
+        value >>= 7;
+    }
+    buffer.extend(bytes.into_iter().rev());
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn unicode_range() {
+        assert_eq!(
+            super::unicode_range(&['a', 'b', 'c', 'e', 'é', '–', '—']),
+            "U+61-63, U+65, U+E9, U+2013-2014"
+        );
+    }
+
+    /// A truetype font with the glyphs `.notdef`, `a`, `b`, `c` and `d`, where `c` is a
+    /// composite glyph drawn using `b`.
+    fn font() -> Vec<u8> {
+        fn be(values: &[i32]) -> Vec<u8> {
+            values
+                .iter()
+                .flat_map(|v| (*v as u16).to_be_bytes())
+                .collect()
+        }
+        // a triangle, as a simple glyph with on curve points only
+        let simple = [
+            be(&[1, 0, 0, 100, 100, 2, 0]),
+            vec![1; 3],
+            be(&[0, 100, 0, 0, 0, 100]),
+        ]
+        .concat();
+        // ARG_1_AND_2_ARE_WORDS, glyph `b`, no offset
+        let composite = be(&[-1, 0, 0, 100, 100, 1, 2, 0, 0]);
+        let glyphs = [vec![], simple.clone(), simple.clone(), composite, simple];
+
+        let mut glyf = vec![];
+        let mut loca = vec![];
+        for glyph in glyphs.iter() {
+            loca.extend(be(&[glyf.len() as i32 / 2]));
+            glyf.extend(glyph);
+            glyf.resize((glyf.len() + 1) & !1, 0);
+        }
+        loca.extend(be(&[glyf.len() as i32 / 2]));
+
+        let mut head = be(&[1, 0, 1, 0, 0, 0, 0x5F0F, 0x3CF5, 0, 1000]);
+        head.extend([0; 16]);
+        head.extend(be(&[0, 0, 100, 100, 0, 0, 2, 0, 0]));
+        let mut hhea = be(&[1, 0, 800, -200, 0]);
+        hhea.extend([0; 24]);
+        hhea.extend(be(&[5]));
+        let maxp = be(&[0, 0x5000, 5]);
+        let hmtx = be(&[500, 0].repeat(5));
+        // a single format 6 subtable, mapping `a` to `d` to the glyphs 1 to 4
+        let cmap = be(&[0, 1, 0, 3, 0, 12, 6, 18, 0, 0x61, 4, 1, 2, 3, 4]);
+
+        let tables: [(&[u8; 4], Vec<u8>); 7] = [
+            (b"cmap", cmap),
+            (b"glyf", glyf),
+            (b"head", head),
+            (b"hhea", hhea),
+            (b"hmtx", hmtx),
+            (b"loca", loca),
+            (b"maxp", maxp),
+        ];
+        let mut font = be(&[1, 0, tables.len() as i32, 0, 0, 0]);
+        let mut offset = 12 + 16 * tables.len();
+        for (tag, table) in tables.iter() {
+            font.extend(*tag);
+            font.extend([0; 4]);
+            font.extend((offset as u32).to_be_bytes());
+            font.extend((table.len() as u32).to_be_bytes());
+            offset += (table.len() + 3) & !3;
+        }
+        for (_, table) in tables.iter() {
+            font.extend(table);
+            font.resize((font.len() + 3) & !3, 0);
+        }
+        font
+    }
+
+    /// Decodes a woff2 font, with no transformed table, back to a truetype/opentype font.
+    fn decode_woff2(woff2: &[u8]) -> Vec<u8> {
+        use std::io::Read;
+
+        fn base128(data: &[u8], offset: &mut usize) -> u32 {
+            let mut value = 0;
+            loop {
+                let byte = data[*offset];
+                *offset += 1;
+                value = value << 7 | (byte & 0x7f) as u32;
+                if byte & 0x80 == 0 {
+                    return value;
+                }
+            }
+        }
+
+        assert_eq!(&woff2[..4], b"wOF2");
+        assert_eq!(super::read_u32(woff2, 8).unwrap() as usize, woff2.len());
+        let count = super::read_u16(woff2, 12).unwrap() as usize;
+        let mut offset = 48;
+        let mut directory = vec![];
+        for _ in 0..count {
+            let flags = woff2[offset];
+            offset += 1;
+            assert!(
+                flags >> 6 == 0 || flags >> 6 == 3,
+                "no transform is expected"
+            );
+            let tag = match flags & 63 {
+                63 => {
+                    offset += 4;
+                    woff2[offset - 4..offset].try_into().unwrap()
+                }
+                index => *super::WOFF2_KNOWN_TAGS[index as usize],
+            };
+            directory.push((tag, base128(woff2, &mut offset) as usize));
+        }
+        let compressed = super::read_u32(woff2, 20).unwrap() as usize;
+        let mut data = vec![];
+        brotli::Decompressor::new(&woff2[offset..offset + compressed], 4096)
+            .read_to_end(&mut data)
+            .unwrap();
+
+        let mut font = woff2[4..8].to_vec();
+        font.extend((count as u16).to_be_bytes());
+        font.extend([0; 6]);
+        let mut table_offset = 12 + 16 * count;
+        for (tag, length) in directory.iter() {
+            font.extend(tag);
+            font.extend([0; 4]);
+            font.extend((table_offset as u32).to_be_bytes());
+            font.extend((*length as u32).to_be_bytes());
+            table_offset += (length + 3) & !3;
+        }
+        let mut start = 0;
+        for (_, length) in directory {
+            font.extend(&data[start..start + length]);
+            font.resize((font.len() + 3) & !3, 0);
+            start += length;
+        }
+        assert_eq!(start, data.len());
+        font
+    }
+
+    struct Points(usize);
+
+    impl ttf_parser::OutlineBuilder for Points {
+        fn move_to(&mut self, _: f32, _: f32) {
+            self.0 += 1;
+        }
+        fn line_to(&mut self, _: f32, _: f32) {
+            self.0 += 1;
+        }
+        fn quad_to(&mut self, _: f32, _: f32, _: f32, _: f32) {
+            self.0 += 1;
+        }
+        fn curve_to(&mut self, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32) {
+            self.0 += 1;
+        }
+        fn close(&mut self) {}
+    }
+
+    fn has_outline(face: &ttf_parser::Face, c: char) -> bool {
+        let glyph = face.glyph_index(c).unwrap();
+        face.outline_glyph(glyph, &mut Points(0)).is_some()
+    }
+
+    #[test]
+    fn woff2() {
+        let original = font();
+        let (flavor, tables) = super::read_tables(&original).unwrap();
+        let decoded = decode_woff2(&super::woff2(flavor, tables.clone()).unwrap());
+        assert_eq!(super::read_tables(&decoded).unwrap(), (flavor, tables));
+
+        let face = ttf_parser::Face::parse(&decoded, 0).unwrap();
+        assert_eq!(face.number_of_glyphs(), 5);
+        assert!("abcd".chars().all(|c| has_outline(&face, c)));
+    }
+
+    #[test]
+    fn subset() {
+        let used = std::collections::BTreeSet::from(['a', 'c', 'z']);
+        let (woff2, chars) = super::subset(&font(), &used).unwrap();
+        assert_eq!(chars, vec!['a', 'c']);
+
+        let decoded = decode_woff2(&woff2);
+        let face = ttf_parser::Face::parse(&decoded, 0).unwrap();
+        assert_eq!(face.number_of_glyphs(), 5);
+        assert!(has_outline(&face, 'a'));
+        // `b` is kept, the composite `c` is drawn using it
+        assert!(has_outline(&face, 'b'));
+        assert!(has_outline(&face, 'c'));
+        assert!(!has_outline(&face, 'd'));
+        assert_eq!(face.glyph_index('d'), Some(ttf_parser::GlyphId(4)));
+
+        assert_eq!(super::subset(b"not a font", &used), None);
+    }
+
+    #[test]
+    fn base128() {
+        let mut buffer = vec![];
+        super::push_base128(&mut buffer, 63);
+        super::push_base128(&mut buffer, 300);
+        assert_eq!(buffer, vec![63, 0x82, 0x2c]);
+    }
+}
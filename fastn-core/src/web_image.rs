@@ -0,0 +1,348 @@
+/// Widths of the resized variants, an image only gets the ones smaller than itself.
+const VARIANT_WIDTHS: [u32; 5] = [320, 640, 960, 1280, 1920];
+
+/// Width of the blurred placeholder, the browser stretches it to the size of the image.
+const PLACEHOLDER_WIDTH: u32 = 16;
+
+/// What `fastn build` knows about an image of the package, after generating its variants.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+pub(crate) struct ImageInfo {
+    pub width: u32,
+    pub height: u32,
+    /// size of the original image file, in bytes
+    pub size: usize,
+    /// tiny blurred version of the image, as a data url, shown while the image loads. Images
+    /// with transparency have none, the placeholder would show through them.
+    pub placeholder: Option<String>,
+    pub variants: Vec<Variant>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+pub(crate) struct Variant {
+    pub width: u32,
+    /// `png`, `jpeg`, `webp` or `avif`
+    pub format: String,
+    /// name of the variant file, it is saved next to the original image
+    pub file_name: String,
+    pub size: usize,
+}
+
+impl ImageInfo {
+    /// The `srcset` of the image shown from `src`: the smallest file for every width, in a format
+    /// all browsers support. `avif` is not in it, `<img srcset>` can not fall back to another
+    /// format if the browser lacks it.
+    fn srcset(&self, src: &str) -> String {
+        let mut candidates = self.candidates(src, |format| format.ne("avif"));
+        candidates.push((self.width, self.size, src.to_string()));
+        ImageInfo::smallest_by_width(candidates)
+    }
+
+    /// The `srcset` of the `avif` variants, `ftd.image` renders it as the `<source>` the
+    /// browsers supporting `avif` pick over the `<img>`.
+    fn avif_srcset(&self, src: &str) -> Option<String> {
+        let candidates = self.candidates(src, |format| format.eq("avif"));
+        if candidates.is_empty() {
+            return None;
+        }
+        Some(ImageInfo::smallest_by_width(candidates))
+    }
+
+    /// `(width, size, url)` of the variants in the formats `format` accepts.
+    fn candidates(&self, src: &str, format: impl Fn(&str) -> bool) -> Vec<(u32, usize, String)> {
+        let base = src.rsplit_once('/').map(|(v, _)| format!("{}/", v));
+        self.variants
+            .iter()
+            .filter(|v| format(v.format.as_str()))
+            .map(|v| {
+                (
+                    v.width,
+                    v.size,
+                    format!("{}{}", base.as_deref().unwrap_or_default(), v.file_name),
+                )
+            })
+            .collect()
+    }
+
+    fn smallest_by_width(candidates: Vec<(u32, usize, String)>) -> String {
+        use itertools::Itertools;
+
+        candidates
+            .into_iter()
+            .into_group_map_by(|(width, _, _)| *width)
+            .into_iter()
+            .sorted_by_key(|(width, _)| *width)
+            .filter_map(|(_, v)| v.into_iter().min_by_key(|(_, size, _)| *size))
+            .map(|(width, _, url)| format!("{} {}w", url, width))
+            .join(", ")
+    }
+
+    /// The `width`, `height`, `srcset`, `avif-srcset` and `placeholder` fields of the
+    /// `ftd#image-src` of the image shown from `src`, `ftd.image` uses them for the `<img>` it
+    /// renders.
+    pub(crate) fn image_src_fields(
+        &self,
+        src: &str,
+    ) -> Vec<(String, ftd::interpreter::PropertyValue)> {
+        let value = |value| ftd::interpreter::PropertyValue::Value {
+            value,
+            is_mutable: false,
+            line_number: 0,
+        };
+        let mut fields = vec![
+            (
+                "width".to_string(),
+                value(ftd::interpreter::Value::Integer {
+                    value: self.width as i64,
+                }),
+            ),
+            (
+                "height".to_string(),
+                value(ftd::interpreter::Value::Integer {
+                    value: self.height as i64,
+                }),
+            ),
+            (
+                "srcset".to_string(),
+                value(ftd::interpreter::Value::String {
+                    text: self.srcset(src),
+                }),
+            ),
+        ];
+        if let Some(avif_srcset) = self.avif_srcset(src) {
+            fields.push((
+                "avif-srcset".to_string(),
+                value(ftd::interpreter::Value::String { text: avif_srcset }),
+            ));
+        }
+        if let Some(placeholder) = self.placeholder.as_ref() {
+            fields.push((
+                "placeholder".to_string(),
+                value(ftd::interpreter::Value::String {
+                    text: placeholder.to_string(),
+                }),
+            ));
+        }
+        fields
+    }
+}
+
+/// Generates the resized, `webp` and `avif` variants and the blur placeholder of a package
+/// image, and saves the variants in `.build` next to the image. The variants are cached in
+/// `.fastn/image-cache` by the hash of the image content, so only new or changed images are
+/// processed again by the next build.
+///
+/// Returns `None` for images that are not png, jpeg or webp (svg, gif etc. are served as is).
+pub(crate) async fn optimise(
+    config: &fastn_core::Config,
+    image: &fastn_core::Static,
+) -> fastn_core::Result<Option<ImageInfo>> {
+    use sha2::Digest;
+
+    let format = match image.id.rsplit_once('.').map(|(_, v)| v.to_lowercase()) {
+        Some(ext) if ext.eq("png") => image::ImageFormat::Png,
+        Some(ext) if ext.eq("jpg") || ext.eq("jpeg") => image::ImageFormat::Jpeg,
+        Some(ext) if ext.eq("webp") => image::ImageFormat::WebP,
+        _ => return Ok(None),
+    };
+
+    let hash = format!("{:x}", sha2::Sha256::digest(&image.content));
+    let cache_dir = config.image_cache_dir().join(hash.as_str());
+    let info_path = cache_dir.join("info.json");
+    let info: ImageInfo = if info_path.exists() {
+        serde_json::from_str(tokio::fs::read_to_string(&info_path).await?.as_str())?
+    } else {
+        let stem = image
+            .id
+            .rsplit_once('/')
+            .map(|(_, v)| v)
+            .unwrap_or(image.id.as_str());
+        let stem = stem.rsplit_once('.').map(|(v, _)| v).unwrap_or(stem);
+        let (info, files) = match generate(
+            image.content.as_slice(),
+            format,
+            format!("{}-{}", stem, &hash[..8]).as_str(),
+        ) {
+            Ok(v) => v,
+            Err(e) => {
+                fastn_core::warning!("Skipped image optimisation of {}: {}", image.id, e);
+                return Ok(None);
+            }
+        };
+        for (file_name, content) in files {
+            fastn_core::utils::update(&cache_dir.join(file_name), content.as_slice()).await?;
+        }
+        fastn_core::utils::update(&info_path, serde_json::to_string(&info)?.as_bytes()).await?;
+        info
+    };
+
+    // same locations `process_static` copies the image to
+    let dir = image
+        .id
+        .rsplit_once('/')
+        .map(|(v, _)| v)
+        .unwrap_or_default();
+    for build_dir in [
+        config
+            .build_dir()
+            .join("-")
+            .join(config.package.name.as_str()),
+        config.build_dir(),
+    ] {
+        for variant in info.variants.iter() {
+            let content = tokio::fs::read(cache_dir.join(variant.file_name.as_str())).await?;
+            fastn_core::utils::update(
+                &build_dir.join(dir).join(variant.file_name.as_str()),
+                content.as_slice(),
+            )
+            .await?;
+        }
+    }
+
+    Ok(Some(info))
+}
+
+/// Variant files as `(file name, content)`
+type Files = Vec<(String, Vec<u8>)>;
+
+/// Returns the info of the image, and the variant files to save.
+fn generate(
+    content: &[u8],
+    format: image::ImageFormat,
+    prefix: &str,
+) -> image::ImageResult<(ImageInfo, Files)> {
+    use itertools::Itertools;
+
+    let original = image::load_from_memory_with_format(content, format)?;
+    let (width, height) = (original.width(), original.height());
+    let (extension, format) = match format {
+        image::ImageFormat::Jpeg => ("jpg", "jpeg"),
+        image::ImageFormat::WebP => ("webp", "webp"),
+        _ => ("png", "png"),
+    };
+
+    let mut widths = VARIANT_WIDTHS
+        .into_iter()
+        .filter(|v| *v < width)
+        .collect::<Vec<_>>();
+    widths.push(width);
+
+    let mut variants = vec![];
+    let mut files = vec![];
+    for variant_width in widths {
+        let resized = if variant_width == width {
+            original.clone()
+        } else {
+            original.resize(
+                variant_width,
+                u32::MAX,
+                image::imageops::FilterType::Lanczos3,
+            )
+        };
+        let mut own_format_size = if variant_width == width {
+            content.len()
+        } else {
+            usize::MAX
+        };
+        for (extension, variant_format) in [(extension, format), ("webp", "webp"), ("avif", "avif")]
+            .into_iter()
+            .unique_by(|(_, v)| *v)
+        {
+            // the original image is already there for the full width
+            if variant_width == width && variant_format.eq(format) {
+                continue;
+            }
+            let content = encode(&resized, variant_format)?;
+            if variant_format.eq(format) {
+                own_format_size = content.len();
+            } else if content.len() >= own_format_size {
+                // of no use if bigger, lossless webp of a photo is often bigger than the jpeg
+                continue;
+            }
+            let file_name = format!("{}-{}w.{}", prefix, variant_width, extension);
+            variants.push(Variant {
+                width: variant_width,
+                format: variant_format.to_string(),
+                file_name: file_name.clone(),
+                size: content.len(),
+            });
+            files.push((file_name, content));
+        }
+    }
+
+    let placeholder = if original.color().has_alpha() {
+        None
+    } else {
+        use base64::Engine;
+
+        let tiny = original
+            .thumbnail(PLACEHOLDER_WIDTH, PLACEHOLDER_WIDTH)
+            .blur(1.0);
+        Some(format!(
+            "data:image/png;base64,{}",
+            base64::engine::general_purpose::STANDARD.encode(encode(&tiny, "png")?)
+        ))
+    };
+
+    Ok((
+        ImageInfo {
+            width,
+            height,
+            size: content.len(),
+            placeholder,
+            variants,
+        },
+        files,
+    ))
+}
+
+fn encode(image: &image::DynamicImage, format: &str) -> image::ImageResult<Vec<u8>> {
+    let mut content = std::io::Cursor::new(vec![]);
+    match format {
+        "avif" => image::DynamicImage::ImageRgba8(image.to_rgba8()).write_with_encoder(
+            image::codecs::avif::AvifEncoder::new_with_speed_quality(&mut content, 8, 70),
+        )?,
+        "webp" => image::DynamicImage::ImageRgba8(image.to_rgba8())
+            .write_to(&mut content, image::ImageFormat::WebP)?,
+        "jpeg" => image::DynamicImage::ImageRgb8(image.to_rgb8())
+            .write_to(&mut content, image::ImageFormat::Jpeg)?,
+        _ => image.write_to(&mut content, image::ImageFormat::Png)?,
+    }
+    Ok(content.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn srcset() {
+        let info = super::ImageInfo {
+            width: 1000,
+            height: 500,
+            size: 30000,
+            placeholder: None,
+            variants: vec![
+                variant(320, "png", 9000),
+                variant(320, "webp", 7000),
+                variant(320, "avif", 5000),
+                variant(1000, "webp", 40000),
+                variant(1000, "avif", 20000),
+            ],
+        };
+        assert_eq!(
+            info.srcset("-/foo.com/images/a.png"),
+            "-/foo.com/images/a-320w.webp 320w, -/foo.com/images/a.png 1000w"
+        );
+        assert_eq!(
+            info.avif_srcset("-/foo.com/images/a.png").as_deref(),
+            Some("-/foo.com/images/a-320w.avif 320w, -/foo.com/images/a-1000w.avif 1000w")
+        );
+    }
+
+    fn variant(width: u32, format: &str, size: usize) -> super::Variant {
+        super::Variant {
+            width,
+            format: format.to_string(),
+            file_name: format!("a-{}w.{}", width, format),
+            size,
+        }
+    }
+}
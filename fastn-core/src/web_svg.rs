@@ -0,0 +1,220 @@
+/// Id prefix of the symbols the build adds to a page, for the svgs of `ftd.svg`.
+const SVG_SYMBOL_PREFIX: &str = "ftd-svg-";
+
+/// Id prefix of the symbols the build adds to a page, for the icons of `ftd.icon`.
+const ICON_SYMBOL_PREFIX: &str = "ftd-icon-";
+
+/// Inlines the svgs of every `ftd.svg` and the sprite symbols of every `ftd.icon` of the
+/// generated pages, so they show without a request per svg and take the `fill`, `stroke` and
+/// `color` of the page. Each svg file and each icon is added once to a hidden sprite at the top
+/// of the page, however many times the page shows it, and every use of it points to that one
+/// symbol.
+///
+/// Svgs that are not files of the build (e.g. on another site) are left to the page, which
+/// fetches them.
+pub(crate) async fn update_pages(config: &fastn_core::Config) -> fastn_core::Result<()> {
+    let build_dir = config.build_dir();
+    let pages = ignore::WalkBuilder::new(&build_dir)
+        .standard_filters(false)
+        .build()
+        .flatten()
+        .map(|x| camino::Utf8PathBuf::from_path_buf(x.into_path()).unwrap())
+        .filter(|x| x.is_file() && x.extension().map(|v| v.eq("html")).unwrap_or(false))
+        .collect::<Vec<_>>();
+    // the same svg files are used by most pages
+    let mut files: std::collections::HashMap<String, Option<String>> = Default::default();
+    for page in pages {
+        let html = tokio::fs::read_to_string(&page).await?;
+        let updated = inline_svgs(html.as_str(), |src| {
+            files
+                .entry(src.to_string())
+                .or_insert_with(|| {
+                    if src.contains("://") {
+                        return None;
+                    }
+                    std::fs::read_to_string(build_dir.join(src.trim_start_matches('/'))).ok()
+                })
+                .clone()
+        });
+        if updated == html {
+            continue;
+        }
+        fastn_core::utils::update(&page, updated.as_bytes()).await?;
+    }
+    Ok(())
+}
+
+/// `load` returns the content of an svg file of the page, by its url.
+fn inline_svgs(html: &str, mut load: impl FnMut(&str) -> Option<String>) -> String {
+    static EMPTY_WRAPPER: once_cell::sync::Lazy<regex::Regex> = once_cell::sync::Lazy::new(|| {
+        regex::Regex::new(r#"(<div\s[^>]*\sdata-svg-src="([^"]+)"[^>]*>)\s*</div>"#).unwrap()
+    });
+    static SPRITE_USE: once_cell::sync::Lazy<regex::Regex> = once_cell::sync::Lazy::new(|| {
+        regex::Regex::new(r##"<use href="([^"#]+)#([^"]+)""##).unwrap()
+    });
+    static BODY: once_cell::sync::Lazy<regex::Regex> =
+        once_cell::sync::Lazy::new(|| regex::Regex::new(r"<body[^>]*>").unwrap());
+
+    // symbol id by the svg url, or by the sprite url and the icon name
+    let mut ids: std::collections::HashMap<String, Option<(String, String)>> = Default::default();
+    let mut symbols = vec![];
+
+    let html = EMPTY_WRAPPER.replace_all(html, |captures: &regex::Captures| {
+        let src = captures[2].to_string();
+        let symbol = ids
+            .entry(src.clone())
+            .or_insert_with(|| {
+                let (view_box, content) = parse_svg(load(src.as_str())?.as_str())?;
+                let id = format!("{}{}", SVG_SYMBOL_PREFIX, symbols.len());
+                symbols.push(format!(
+                    "<symbol id=\"{}\" viewBox=\"{}\">{}</symbol>",
+                    id, view_box, content
+                ));
+                Some((id, view_box))
+            })
+            .clone();
+        match symbol {
+            Some((id, view_box)) => format!(
+                "{}<svg viewBox=\"{}\"><use href=\"#{}\"></use></svg></div>",
+                &captures[1], view_box, id
+            ),
+            None => captures[0].to_string(),
+        }
+    });
+
+    let html = SPRITE_USE.replace_all(html.as_ref(), |captures: &regex::Captures| {
+        let (sprite, name) = (&captures[1], &captures[2]);
+        let symbol = ids
+            .entry(format!("{}#{}", sprite, name))
+            .or_insert_with(|| {
+                let (view_box, content) = find_symbol(load(sprite)?.as_str(), name)?;
+                let id = format!("{}{}", ICON_SYMBOL_PREFIX, symbols.len());
+                symbols.push(format!(
+                    "<symbol id=\"{}\"{}>{}</symbol>",
+                    id,
+                    view_box
+                        .as_ref()
+                        .map(|v| format!(" viewBox=\"{}\"", v))
+                        .unwrap_or_default(),
+                    content
+                ));
+                Some((id, view_box.unwrap_or_default()))
+            })
+            .clone();
+        match symbol {
+            Some((id, _)) => format!("<use href=\"#{}\"", id),
+            None => captures[0].to_string(),
+        }
+    });
+
+    if symbols.is_empty() {
+        return html.to_string();
+    }
+    let sprite = format!(
+        "<svg style=\"display: none\" aria-hidden=\"true\">{}</svg>",
+        symbols.concat()
+    );
+    match BODY.find(html.as_ref()) {
+        Some(body) => format!("{}{}{}", &html[..body.end()], sprite, &html[body.end()..]),
+        None => html.to_string(),
+    }
+}
+
+/// The `viewBox` and the content of the `<svg>` of an svg file. A `viewBox` missing from the
+/// file comes from its `width` and `height`, so the svg still scales to the size of the
+/// element.
+fn parse_svg(svg: &str) -> Option<(String, String)> {
+    static SVG: once_cell::sync::Lazy<regex::Regex> =
+        once_cell::sync::Lazy::new(|| regex::Regex::new(r"(?s)<svg\b([^>]*)>(.*)</svg>").unwrap());
+
+    let captures = SVG.captures(svg)?;
+    let attributes = &captures[1];
+    let view_box = match attribute(attributes, "viewBox") {
+        Some(view_box) => view_box,
+        None => {
+            let length = |name: &str| {
+                attribute(attributes, name)?
+                    .trim_end_matches("px")
+                    .parse::<f64>()
+                    .ok()
+            };
+            format!("0 0 {} {}", length("width")?, length("height")?)
+        }
+    };
+    Some((view_box, captures[2].trim().to_string()))
+}
+
+/// The `viewBox` and the content of the `<symbol>` named `name` in a sprite file.
+fn find_symbol(sprite: &str, name: &str) -> Option<(Option<String>, String)> {
+    static SYMBOL: once_cell::sync::Lazy<regex::Regex> = once_cell::sync::Lazy::new(|| {
+        regex::Regex::new(r"(?s)<symbol\b([^>]*)>(.*?)</symbol>").unwrap()
+    });
+
+    SYMBOL.captures_iter(sprite).find_map(|captures| {
+        let attributes = &captures[1];
+        if attribute(attributes, "id")?.ne(name) {
+            return None;
+        }
+        Some((
+            attribute(attributes, "viewBox"),
+            captures[2].trim().to_string(),
+        ))
+    })
+}
+
+fn attribute(attributes: &str, name: &str) -> Option<String> {
+    static ATTRIBUTE: once_cell::sync::Lazy<regex::Regex> = once_cell::sync::Lazy::new(|| {
+        regex::Regex::new(r#"\s([\w:-]+)\s*=\s*["']([^"']*)["']"#).unwrap()
+    });
+    ATTRIBUTE
+        .captures_iter(attributes)
+        .find(|v| v[1].eq(name))
+        .map(|v| v[2].to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn inline_svgs() {
+        let html = r#"<html><body class="a"><div data-id="1" class="ft_svg" data-svg-src="/images/logo.svg"></div><div data-id="2" class="ft_svg" data-svg-src="/images/logo.svg"></div><div class="ft_svg" data-icon-sprite="/icons/sprite.svg" data-icon-name="home"><svg width="100%" height="100%"><use href="/icons/sprite.svg#home"></use></svg></div><div class="ft_svg" data-svg-src="https://example.com/a.svg"></div></body></html>"#;
+        let mut loaded = vec![];
+        let updated = super::inline_svgs(html, |src| {
+            loaded.push(src.to_string());
+            match src {
+                "/images/logo.svg" => Some(
+                    "<?xml version=\"1.0\"?>\n<svg xmlns=\"http://www.w3.org/2000/svg\" \
+                    width=\"120\" height=\"40px\">\n<path d=\"M0 0h120v40H0z\"/>\n</svg>\n"
+                        .to_string(),
+                ),
+                "/icons/sprite.svg" => Some(
+                    "<svg><symbol id=\"close\" viewBox=\"0 0 24 24\"><path d=\"M6 6l12 12\"/>\
+                    </symbol><symbol id=\"home\" viewBox=\"0 0 24 24\"><path d=\"M3 12l9-9\"/>\
+                    </symbol></svg>"
+                        .to_string(),
+                ),
+                _ => None,
+            }
+        });
+        assert_eq!(
+            loaded,
+            vec![
+                "/images/logo.svg",
+                "https://example.com/a.svg",
+                "/icons/sprite.svg"
+            ]
+        );
+        assert_eq!(
+            updated,
+            "<html><body class=\"a\"><svg style=\"display: none\" aria-hidden=\"true\">\
+            <symbol id=\"ftd-svg-0\" viewBox=\"0 0 120 40\"><path d=\"M0 0h120v40H0z\"/></symbol>\
+            <symbol id=\"ftd-icon-1\" viewBox=\"0 0 24 24\"><path d=\"M3 12l9-9\"/></symbol></svg>\
+            <div data-id=\"1\" class=\"ft_svg\" data-svg-src=\"/images/logo.svg\"><svg \
+            viewBox=\"0 0 120 40\"><use href=\"#ftd-svg-0\"></use></svg></div><div data-id=\"2\" \
+            class=\"ft_svg\" data-svg-src=\"/images/logo.svg\"><svg viewBox=\"0 0 120 40\"><use \
+            href=\"#ftd-svg-0\"></use></svg></div><div class=\"ft_svg\" \
+            data-icon-sprite=\"/icons/sprite.svg\" data-icon-name=\"home\"><svg width=\"100%\" \
+            height=\"100%\"><use href=\"#ftd-icon-1\"></use></svg></div><div class=\"ft_svg\" \
+            data-svg-src=\"https://example.com/a.svg\"></div></body></html>"
+        );
+    }
+}
@@ -2,8 +2,9 @@
 use regex::Regex;
 
 use fastn_grammar::evalexpr::{
+    function::{collections, datetime},
     value::{FloatType, IntType},
-    EvalexprError, Function, Value, ValueType,
+    EvalexprError, EvalexprResult, Function, Value, ValueType,
 };
 use std::ops::{BitAnd, BitOr, BitXor, Not, Shl, Shr};
 
@@ -45,6 +46,30 @@ macro_rules! int_function {
     };
 }
 
+fn as_datetime(value: &Value) -> EvalexprResult<chrono::DateTime<chrono::FixedOffset>> {
+    let s = value.as_string()?;
+    datetime::parse_datetime(s.as_str()).ok_or_else(|| {
+        EvalexprError::CustomMessage(format!("`{}` is not an ISO-8601 date time", s))
+    })
+}
+
+/// The arguments of a function taking `min` to `max` of them, the trailing ones being optional,
+/// e.g. `date::format(d, f)` and `date::format(d, f, locale)`.
+fn arguments_between(argument: &Value, min: usize, max: usize) -> EvalexprResult<Vec<Value>> {
+    let arguments = match argument {
+        Value::Tuple(arguments) => arguments.clone(),
+        argument => vec![argument.clone()],
+    };
+    if arguments.len() < min || arguments.len() > max {
+        return Err(EvalexprError::wrong_function_argument_amount(
+            arguments.len(),
+            if arguments.len() < min { min } else { max },
+        ));
+    }
+    Ok(arguments)
+}
+
+/// The built-in function named `identifier`, e.g. `math::ln` or `date::format`.
 pub fn builtin_function(identifier: &str) -> Option<Function> {
     match identifier {
         // Log
@@ -207,11 +232,151 @@ pub fn builtin_function(identifier: &str) -> Option<Function> {
         "str::from" => Some(Function::new(|argument| {
             Ok(Value::String(argument.to_string()))
         })),
+        "str::split" => Some(Function::new(|argument| {
+            let arguments = argument.as_fixed_len_tuple(2)?;
+            let (subject, separator) = (arguments[0].as_string()?, arguments[1].as_string()?);
+            let parts = if separator.is_empty() {
+                subject
+                    .chars()
+                    .map(|c| Value::String(c.to_string()))
+                    .collect()
+            } else {
+                subject.split(separator.as_str()).map(Value::from).collect()
+            };
+            Ok(Value::Tuple(parts))
+        })),
+        "str::join" => Some(Function::new(|argument| {
+            let arguments = argument.as_fixed_len_tuple(2)?;
+            let parts = collections::as_list(&arguments[0])?
+                .iter()
+                .map(|part| match part {
+                    Value::String(part) => part.clone(),
+                    part => part.to_string(),
+                })
+                .collect::<Vec<_>>();
+            Ok(Value::String(
+                parts.join(arguments[1].as_string()?.as_str()),
+            ))
+        })),
+        "str::replace" => Some(Function::new(|argument| {
+            let arguments = argument.as_fixed_len_tuple(3)?;
+            let subject = arguments[0].as_string()?;
+            Ok(Value::String(subject.replace(
+                arguments[1].as_string()?.as_str(),
+                arguments[2].as_string()?.as_str(),
+            )))
+        })),
+        "str::contains" => Some(Function::new(|argument| {
+            let arguments = argument.as_fixed_len_tuple(2)?;
+            match &arguments[0] {
+                Value::String(subject) => Ok(Value::Boolean(
+                    subject.contains(arguments[1].as_string()?.as_str()),
+                )),
+                subject => Ok(Value::Boolean(
+                    collections::index_of(&collections::as_list(subject)?, &arguments[1]) >= 0,
+                )),
+            }
+        })),
+        "str::starts_with" => Some(Function::new(|argument| {
+            let arguments = argument.as_fixed_len_tuple(2)?;
+            let subject = arguments[0].as_string()?;
+            Ok(Value::Boolean(
+                subject.starts_with(arguments[1].as_string()?.as_str()),
+            ))
+        })),
+        "str::slice" => Some(Function::new(|argument| {
+            let arguments = arguments_between(argument, 2, 3)?;
+            let end = match arguments.get(2) {
+                Some(Value::Empty) | None => None,
+                Some(end) => Some(end.as_int()?),
+            };
+            let chars = arguments[0].as_string()?.chars().collect::<Vec<_>>();
+            let range = collections::slice_range(chars.len(), arguments[1].as_int()?, end);
+            Ok(Value::String(chars[range].iter().collect()))
+        })),
+        // List functions
+        "list::reverse" => Some(Function::new(|argument| {
+            let mut items = collections::as_list(argument)?;
+            items.reverse();
+            Ok(Value::Tuple(items))
+        })),
+        "list::take" => Some(Function::new(|argument| {
+            let arguments = argument.as_fixed_len_tuple(2)?;
+            let items = collections::as_list(&arguments[0])?;
+            let range =
+                collections::slice_range(items.len(), 0, Some(arguments[1].as_int()?.max(0)));
+            Ok(Value::Tuple(items[range].to_vec()))
+        })),
+        "list::skip" => Some(Function::new(|argument| {
+            let arguments = argument.as_fixed_len_tuple(2)?;
+            let items = collections::as_list(&arguments[0])?;
+            let range = collections::slice_range(items.len(), arguments[1].as_int()?.max(0), None);
+            Ok(Value::Tuple(items[range].to_vec()))
+        })),
+        "list::sum" => Some(Function::new(|argument| {
+            collections::sum(&collections::as_list(argument)?)
+        })),
+        "list::unique" => Some(Function::new(|argument| {
+            Ok(Value::Tuple(collections::unique(collections::as_list(
+                argument,
+            )?)))
+        })),
+        "list::index_of" => Some(Function::new(|argument| {
+            let arguments = argument.as_fixed_len_tuple(2)?;
+            let items = collections::as_list(&arguments[0])?;
+            Ok(Value::Int(collections::index_of(&items, &arguments[1])))
+        })),
         #[cfg(feature = "rand")]
         "random" => Some(Function::new(|argument| {
             argument.as_empty()?;
             Ok(Value::Float(rand::random()))
         })),
+        // Date and time functions
+        "date::now" => Some(Function::new(|argument| {
+            argument.as_empty()?;
+            Ok(Value::String(
+                datetime::datetime_to_string(&datetime::now()),
+            ))
+        })),
+        "date::format" => Some(Function::new(|argument| {
+            let arguments = arguments_between(argument, 2, 3)?;
+            let locale = match arguments.get(2) {
+                Some(Value::Empty) | None => "en".to_string(),
+                Some(locale) => locale.as_string()?,
+            };
+            Ok(Value::String(datetime::format(
+                &as_datetime(&arguments[0])?,
+                arguments[1].as_string()?.as_str(),
+                locale.as_str(),
+            )))
+        })),
+        "date::relative" => Some(Function::new(|argument| {
+            let arguments = arguments_between(argument, 1, 2)?;
+            let now = match arguments.get(1) {
+                Some(Value::Empty) | None => datetime::now(),
+                Some(now) => as_datetime(now)?,
+            };
+            Ok(Value::String(datetime::relative(
+                &as_datetime(&arguments[0])?,
+                &now,
+            )))
+        })),
+        "date::compare" => Some(Function::new(|argument| {
+            let arguments = argument.as_fixed_len_tuple(2)?;
+            let (a, b) = (as_datetime(&arguments[0])?, as_datetime(&arguments[1])?);
+            Ok(Value::Int(a.cmp(&b) as IntType))
+        })),
+        "date::add" => Some(Function::new(|argument| {
+            let arguments = argument.as_fixed_len_tuple(2)?;
+            let datetime =
+                as_datetime(&arguments[0])? + chrono::Duration::seconds(arguments[1].as_int()?);
+            Ok(Value::String(datetime::datetime_to_string(&datetime)))
+        })),
+        "date::diff" => Some(Function::new(|argument| {
+            let arguments = argument.as_fixed_len_tuple(2)?;
+            let (a, b) = (as_datetime(&arguments[0])?, as_datetime(&arguments[1])?);
+            Ok(Value::Int(a.signed_duration_since(b).num_seconds()))
+        })),
         // Bitwise operators
         "bitand" => int_function!(bitand, 2),
         "bitor" => int_function!(bitor, 2),
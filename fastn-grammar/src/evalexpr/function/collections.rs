@@ -0,0 +1,87 @@
+use fastn_grammar::evalexpr::{
+    value::{FloatType, IntType},
+    EvalexprError, EvalexprResult, Value,
+};
+
+/// The items of a list argument, an empty value is an empty list.
+pub fn as_list(value: &Value) -> EvalexprResult<Vec<Value>> {
+    match value {
+        Value::Tuple(items) => Ok(items.clone()),
+        Value::Empty => Ok(vec![]),
+        value => Err(EvalexprError::expected_tuple(value.clone())),
+    }
+}
+
+/// The range `start..end` of a string or list of `len` items. Negative positions count from
+/// the end and positions past the end are clamped, like `slice` in JavaScript.
+pub fn slice_range(len: usize, start: IntType, end: Option<IntType>) -> std::ops::Range<usize> {
+    let position = |p: IntType| {
+        if p < 0 {
+            (len as IntType + p).max(0) as usize
+        } else {
+            (p as usize).min(len)
+        }
+    };
+    let start = position(start);
+    let end = end.map_or(len, position);
+    start..end.max(start)
+}
+
+/// Orders numbers by value, strings alphabetically and `false` before `true`, like `<` and
+/// `>` in JavaScript. Values that can't be compared are equal, so sorting keeps their order.
+pub fn compare(a: &Value, b: &Value) -> std::cmp::Ordering {
+    match (a, b) {
+        (Value::String(a), Value::String(b)) => a.cmp(b),
+        (Value::Boolean(a), Value::Boolean(b)) => a.cmp(b),
+        (a, b) if a.is_number() && b.is_number() => {
+            let (a, b) = (a.as_number().unwrap(), b.as_number().unwrap());
+            a.partial_cmp(&b).unwrap_or(std::cmp::Ordering::Equal)
+        }
+        _ => std::cmp::Ordering::Equal,
+    }
+}
+
+/// The sum of a list of numbers, an integer if all of them are.
+pub fn sum(items: &[Value]) -> EvalexprResult<Value> {
+    if items.iter().all(|item| matches!(item, Value::Int(_))) {
+        let mut sum: IntType = 0;
+        for item in items {
+            sum = sum
+                .checked_add(item.as_int()?)
+                .ok_or_else(|| EvalexprError::addition_error(Value::Int(sum), item.clone()))?;
+        }
+        return Ok(Value::Int(sum));
+    }
+    let mut sum: FloatType = 0.0;
+    for item in items {
+        sum += item.as_number()?;
+    }
+    Ok(Value::Float(sum))
+}
+
+/// The items without the ones equal to an item before them.
+pub fn unique(items: Vec<Value>) -> Vec<Value> {
+    let mut result: Vec<Value> = vec![];
+    for item in items {
+        if !result.iter().any(|r| equal(r, &item)) {
+            result.push(item);
+        }
+    }
+    result
+}
+
+/// The position of the first item equal to `value`, or `-1`.
+pub fn index_of(items: &[Value], value: &Value) -> IntType {
+    items
+        .iter()
+        .position(|item| equal(item, value))
+        .map_or(-1, |index| index as IntType)
+}
+
+/// Numbers are equal by value, `1` and `1.0` are the same number, as in JavaScript.
+fn equal(a: &Value, b: &Value) -> bool {
+    if a.is_number() && b.is_number() {
+        return a.as_number().ok() == b.as_number().ok();
+    }
+    a == b
+}
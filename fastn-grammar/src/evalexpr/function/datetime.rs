@@ -0,0 +1,444 @@
+use chrono::{Datelike, TimeZone, Timelike};
+
+// Expressions have no value type for dates and durations: a `datetime` or a `date` is passed
+// around as its ISO-8601 string and a `duration` as a number of seconds.
+
+/// Parses an ISO-8601 date time, e.g. `2023-06-01T10:30:00+05:30`. A date time without an offset
+/// and a plain date, e.g. `2023-06-01`, are taken to be in UTC, the latter at midnight.
+pub fn parse_datetime(s: &str) -> Option<chrono::DateTime<chrono::FixedOffset>> {
+    let s = s.trim();
+    if let Ok(datetime) = chrono::DateTime::parse_from_rfc3339(s) {
+        return Some(datetime);
+    }
+    let utc = chrono::FixedOffset::east_opt(0)?;
+    for format in [
+        "%Y-%m-%dT%H:%M:%S%.f",
+        "%Y-%m-%d %H:%M:%S%.f",
+        "%Y-%m-%dT%H:%M",
+    ] {
+        if let Ok(datetime) = chrono::NaiveDateTime::parse_from_str(s, format) {
+            return Some(utc.from_utc_datetime(&datetime));
+        }
+    }
+    Some(utc.from_utc_datetime(&parse_date(s)?.and_hms_opt(0, 0, 0)?))
+}
+
+/// Parses an ISO-8601 calendar date, e.g. `2023-06-01`.
+pub fn parse_date(s: &str) -> Option<chrono::NaiveDate> {
+    chrono::NaiveDate::parse_from_str(s.trim(), "%Y-%m-%d").ok()
+}
+
+/// The canonical form of a date time, `Z` is used for UTC.
+pub fn datetime_to_string(datetime: &chrono::DateTime<chrono::FixedOffset>) -> String {
+    datetime.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true)
+}
+
+/// The canonical form of a date, e.g. `2023-06-01`.
+pub fn date_to_string(date: &chrono::NaiveDate) -> String {
+    date.format("%Y-%m-%d").to_string()
+}
+
+/// Parses an ISO-8601 duration into seconds, e.g. `PT1H30M` is `5400`. Years and months are not
+/// supported as they do not have a fixed length, `P1W` and `P2DT3H` are.
+pub fn parse_duration(s: &str) -> Option<i64> {
+    let s = s.trim();
+    let (sign, s) = match s.strip_prefix('-') {
+        Some(s) => (-1, s),
+        None => (1, s),
+    };
+    let s = s.strip_prefix('P')?;
+    let (date, time) = match s.split_once('T') {
+        Some((_, "")) => return None,
+        Some((date, time)) => (date, Some(time)),
+        None => (s, None),
+    };
+    if date.is_empty() && time.is_none() {
+        return None;
+    }
+
+    let mut seconds = 0;
+    for (number, unit) in duration_parts(date)? {
+        seconds += number
+            * match unit {
+                'W' => 7 * 24 * 60 * 60,
+                'D' => 24 * 60 * 60,
+                _ => return None,
+            };
+    }
+    for (number, unit) in duration_parts(time.unwrap_or_default())? {
+        seconds += number
+            * match unit {
+                'H' => 60 * 60,
+                'M' => 60,
+                'S' => 1,
+                _ => return None,
+            };
+    }
+    Some(sign * seconds)
+}
+
+fn duration_parts(s: &str) -> Option<Vec<(i64, char)>> {
+    let mut parts = vec![];
+    let mut number = String::new();
+    for c in s.chars() {
+        if c.is_ascii_digit() {
+            number.push(c);
+        } else if number.is_empty() {
+            return None;
+        } else {
+            parts.push((number.parse().ok()?, c));
+            number.clear();
+        }
+    }
+    number.is_empty().then_some(parts)
+}
+
+/// The ISO-8601 form of a duration of `seconds`, e.g. `P1DT2H`, the inverse of `parse_duration`.
+pub fn duration_to_string(seconds: i64) -> String {
+    let sign = if seconds < 0 { "-" } else { "" };
+    let seconds = seconds.unsigned_abs();
+    let (days, hours, minutes, seconds) = (
+        seconds / 86400,
+        seconds % 86400 / 3600,
+        seconds % 3600 / 60,
+        seconds % 60,
+    );
+
+    let mut duration = format!("{}P", sign);
+    if days > 0 {
+        duration.push_str(format!("{}D", days).as_str());
+    }
+    if hours > 0 || minutes > 0 || seconds > 0 || days == 0 {
+        duration.push('T');
+        if hours > 0 {
+            duration.push_str(format!("{}H", hours).as_str());
+        }
+        if minutes > 0 {
+            duration.push_str(format!("{}M", minutes).as_str());
+        }
+        if seconds > 0 || (hours == 0 && minutes == 0) {
+            duration.push_str(format!("{}S", seconds).as_str());
+        }
+    }
+    duration
+}
+
+/// Formats `datetime`, in its own offset, using strftime style specifiers: `%Y`, `%y`, `%m`,
+/// `%-m`, `%d`, `%-d`, `%B`, `%b`, `%A`, `%a`, `%H`, `%-H`, `%I`, `%-I`, `%M`, `%S`, `%p`, `%j`
+/// and `%%`. Names of months and days are in `locale`, e.g. `fr` or `fr-FR`, english is used for
+/// locales we do not know. `fastn-js/js/utils.js` has the same implementation for the browser.
+pub fn format(
+    datetime: &chrono::DateTime<chrono::FixedOffset>,
+    format: &str,
+    locale: &str,
+) -> String {
+    let names = locale_names(locale);
+    let hour12 = match datetime.hour() % 12 {
+        0 => 12,
+        h => h,
+    };
+
+    let mut result = String::new();
+    let mut chars = format.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            result.push(c);
+            continue;
+        }
+        let (specifier, padded) = match chars.next() {
+            Some('-') => match chars.next() {
+                Some(specifier) => (specifier, false),
+                None => {
+                    result.push_str("%-");
+                    break;
+                }
+            },
+            Some(specifier) => (specifier, true),
+            None => {
+                result.push('%');
+                break;
+            }
+        };
+        let number = |n: u32| {
+            if padded {
+                format!("{:02}", n)
+            } else {
+                n.to_string()
+            }
+        };
+        let month = datetime.month0() as usize;
+        let weekday = datetime.weekday().num_days_from_monday() as usize;
+        match specifier {
+            'Y' => result.push_str(datetime.year().to_string().as_str()),
+            'y' => result.push_str(format!("{:02}", datetime.year().rem_euclid(100)).as_str()),
+            'm' => result.push_str(number(datetime.month()).as_str()),
+            'd' => result.push_str(number(datetime.day()).as_str()),
+            'B' => result.push_str(names.months[month]),
+            'b' => result.push_str(names.short_months[month]),
+            'A' => result.push_str(names.weekdays[weekday]),
+            'a' => result.push_str(names.short_weekdays[weekday]),
+            'H' => result.push_str(number(datetime.hour()).as_str()),
+            'I' => result.push_str(number(hour12).as_str()),
+            'M' => result.push_str(format!("{:02}", datetime.minute()).as_str()),
+            'S' => result.push_str(format!("{:02}", datetime.second()).as_str()),
+            'p' => result.push_str(if datetime.hour() < 12 { "AM" } else { "PM" }),
+            'j' => result.push_str(format!("{:03}", datetime.ordinal()).as_str()),
+            '%' => result.push('%'),
+            t => {
+                result.push('%');
+                if !padded {
+                    result.push('-');
+                }
+                result.push(t);
+            }
+        }
+    }
+    result
+}
+
+/// Describes `datetime` relative to `now`, e.g. `3 days ago` or `in 2 hours`.
+pub fn relative(
+    datetime: &chrono::DateTime<chrono::FixedOffset>,
+    now: &chrono::DateTime<chrono::FixedOffset>,
+) -> String {
+    let seconds = datetime.signed_duration_since(*now).num_seconds();
+    let elapsed = seconds.unsigned_abs();
+    let (count, unit) = if elapsed < 60 {
+        return "just now".to_string();
+    } else if elapsed < 60 * 60 {
+        (elapsed / 60, "minute")
+    } else if elapsed < 24 * 60 * 60 {
+        (elapsed / (60 * 60), "hour")
+    } else if elapsed < 30 * 24 * 60 * 60 {
+        (elapsed / (24 * 60 * 60), "day")
+    } else if elapsed < 365 * 24 * 60 * 60 {
+        (elapsed / (30 * 24 * 60 * 60), "month")
+    } else {
+        (elapsed / (365 * 24 * 60 * 60), "year")
+    };
+    let plural = if count == 1 { "" } else { "s" };
+    if seconds < 0 {
+        format!("{} {}{} ago", count, unit, plural)
+    } else {
+        format!("in {} {}{}", count, unit, plural)
+    }
+}
+
+/// The current date time, in UTC.
+pub fn now() -> chrono::DateTime<chrono::FixedOffset> {
+    chrono::Utc::now().into()
+}
+
+struct LocaleNames {
+    months: [&'static str; 12],
+    short_months: [&'static str; 12],
+    weekdays: [&'static str; 7],
+    short_weekdays: [&'static str; 7],
+}
+
+fn locale_names(locale: &str) -> LocaleNames {
+    let language = locale
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+    match language.as_str() {
+        "fr" => LocaleNames {
+            months: [
+                "janvier",
+                "février",
+                "mars",
+                "avril",
+                "mai",
+                "juin",
+                "juillet",
+                "août",
+                "septembre",
+                "octobre",
+                "novembre",
+                "décembre",
+            ],
+            short_months: [
+                "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.",
+                "nov.", "déc.",
+            ],
+            weekdays: [
+                "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche",
+            ],
+            short_weekdays: ["lun.", "mar.", "mer.", "jeu.", "ven.", "sam.", "dim."],
+        },
+        "de" => LocaleNames {
+            months: [
+                "Januar",
+                "Februar",
+                "März",
+                "April",
+                "Mai",
+                "Juni",
+                "Juli",
+                "August",
+                "September",
+                "Oktober",
+                "November",
+                "Dezember",
+            ],
+            short_months: [
+                "Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.",
+                "Nov.", "Dez.",
+            ],
+            weekdays: [
+                "Montag",
+                "Dienstag",
+                "Mittwoch",
+                "Donnerstag",
+                "Freitag",
+                "Samstag",
+                "Sonntag",
+            ],
+            short_weekdays: ["Mo.", "Di.", "Mi.", "Do.", "Fr.", "Sa.", "So."],
+        },
+        "es" => LocaleNames {
+            months: [
+                "enero",
+                "febrero",
+                "marzo",
+                "abril",
+                "mayo",
+                "junio",
+                "julio",
+                "agosto",
+                "septiembre",
+                "octubre",
+                "noviembre",
+                "diciembre",
+            ],
+            short_months: [
+                "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic",
+            ],
+            weekdays: [
+                "lunes",
+                "martes",
+                "miércoles",
+                "jueves",
+                "viernes",
+                "sábado",
+                "domingo",
+            ],
+            short_weekdays: ["lun", "mar", "mié", "jue", "vie", "sáb", "dom"],
+        },
+        "hi" => LocaleNames {
+            months: [
+                "जनवरी",
+                "फ़रवरी",
+                "मार्च",
+                "अप्रैल",
+                "मई",
+                "जून",
+                "जुलाई",
+                "अगस्त",
+                "सितंबर",
+                "अक्तूबर",
+                "नवंबर",
+                "दिसंबर",
+            ],
+            short_months: [
+                "जन॰",
+                "फ़र॰",
+                "मार्च",
+                "अप्रैल",
+                "मई",
+                "जून",
+                "जुल॰",
+                "अग॰",
+                "सित॰",
+                "अक्तू॰",
+                "नव॰",
+                "दिस॰",
+            ],
+            weekdays: [
+                "सोमवार",
+                "मंगलवार",
+                "बुधवार",
+                "गुरुवार",
+                "शुक्रवार",
+                "शनिवार",
+                "रविवार",
+            ],
+            short_weekdays: ["सोम", "मंगल", "बुध", "गुरु", "शुक्र", "शनि", "रवि"],
+        },
+        _ => LocaleNames {
+            months: [
+                "January",
+                "February",
+                "March",
+                "April",
+                "May",
+                "June",
+                "July",
+                "August",
+                "September",
+                "October",
+                "November",
+                "December",
+            ],
+            short_months: [
+                "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+            ],
+            weekdays: [
+                "Monday",
+                "Tuesday",
+                "Wednesday",
+                "Thursday",
+                "Friday",
+                "Saturday",
+                "Sunday",
+            ],
+            short_weekdays: ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fastn_grammar::evalexpr::function::datetime;
+
+    #[test]
+    fn parse_and_format() {
+        let datetime = datetime::parse_datetime("2023-06-01T18:05:09+05:30").unwrap();
+        assert_eq!(
+            datetime::format(&datetime, "%A, %-d %B %Y %I:%M %p", "en"),
+            "Thursday, 1 June 2023 06:05 PM"
+        );
+        assert_eq!(
+            datetime::format(&datetime, "%a %d %b", "fr-FR"),
+            "jeu. 01 juin"
+        );
+        assert_eq!(
+            datetime::datetime_to_string(&datetime::parse_datetime("2023-06-01").unwrap()),
+            "2023-06-01T00:00:00Z"
+        );
+        assert!(datetime::parse_datetime("2023-13-01").is_none());
+    }
+
+    #[test]
+    fn durations() {
+        assert_eq!(datetime::parse_duration("PT1H30M"), Some(5400));
+        assert_eq!(datetime::parse_duration("P1W"), Some(604800));
+        assert_eq!(datetime::parse_duration("-P1DT1S"), Some(-86401));
+        assert_eq!(datetime::parse_duration("P1M"), None);
+        assert_eq!(datetime::parse_duration("PT"), None);
+        assert_eq!(datetime::duration_to_string(93784), "P1DT2H3M4S");
+        assert_eq!(datetime::duration_to_string(0), "PT0S");
+        assert_eq!(datetime::duration_to_string(-86400), "-P1D");
+    }
+
+    #[test]
+    fn relative() {
+        let now = datetime::parse_datetime("2023-06-10T00:00:00Z").unwrap();
+        let relative = |s: &str| datetime::relative(&datetime::parse_datetime(s).unwrap(), &now);
+        assert_eq!(relative("2023-06-07"), "3 days ago");
+        assert_eq!(relative("2023-06-10T02:00:00Z"), "in 2 hours");
+        assert_eq!(relative("2023-06-09T23:59:30Z"), "just now");
+        assert_eq!(relative("2022-06-01"), "1 year ago");
+    }
+}
@@ -3,6 +3,13 @@ use std::fmt;
 use fastn_grammar::evalexpr::{error::EvalexprResult, value::Value};
 
 pub(crate) mod builtin;
+/// Helpers shared by the `str::` and `list::` functions, e.g. the ordering used to sort lists.
+pub mod collections;
+/// Parsing and formatting of the dates, date times and durations used by the `date::` functions.
+pub mod datetime;
+/// The regular expressions of `ftd.regex_match`, which match the same way in the browser.
+#[cfg(feature = "regex_support")]
+pub mod regex_subset;
 
 /// A helper trait to enable cloning through `Fn` trait objects.
 trait ClonableFn
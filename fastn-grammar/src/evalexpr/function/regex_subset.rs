@@ -0,0 +1,178 @@
+use regex::Regex;
+
+use fastn_grammar::evalexpr::{EvalexprError, EvalexprResult};
+
+/// The characters of `\s` in a JavaScript `RegExp`, which are not quite the Unicode
+/// `White_Space` characters of `\s` in the `regex` crate.
+const SPACE: &str =
+    r"\t\n\x0B\f\r \x{A0}\x{1680}\x{2000}-\x{200A}\x{2028}\x{2029}\x{202F}\x{205F}\x{3000}\x{FEFF}";
+
+/// Compiles `pattern`, written in the subset of regular expressions that matches the same way
+/// here and in the browser, where it is a JavaScript `RegExp` with the `u` flag.
+///
+/// The subset has literal characters, `.`, `^`, `$`, `|`, the `*`, `+`, `?` and `{n,m}`
+/// repetitions (lazy ones too), `(..)` and `(?:..)` groups, character classes like `[^a-z_]`,
+/// the `\d`, `\w`, `\s` classes and their negations, the `\n`, `\r`, `\t`, `\f`, `\v` and `\xHH`
+/// escapes, and escaped syntax characters like `\.`. The classes are ASCII only, and `.` does
+/// not match line terminators, as in JavaScript. Anything else, like `\b`, lookarounds, named
+/// groups or flags, is an error, as it either isn't supported by both or means different
+/// things in them.
+pub fn compile(pattern: &str) -> EvalexprResult<Regex> {
+    let error = |message: String| EvalexprError::invalid_regex(pattern.to_string(), message);
+    let mut translated = String::new();
+    let mut chars = pattern.chars().peekable();
+    let mut in_class = false;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                let escaped = chars
+                    .next()
+                    .ok_or_else(|| error("the pattern ends with `\\`".to_string()))?;
+                match escaped {
+                    'd' => translated.push_str("[0-9]"),
+                    'D' => translated.push_str("[^0-9]"),
+                    'w' => translated.push_str("[0-9A-Za-z_]"),
+                    'W' => translated.push_str("[^0-9A-Za-z_]"),
+                    's' => translated.push_str(format!("[{}]", SPACE).as_str()),
+                    'S' => translated.push_str(format!("[^{}]", SPACE).as_str()),
+                    'n' | 'r' | 't' | 'f' | 'v' => {
+                        translated.push('\\');
+                        translated.push(escaped);
+                    }
+                    'x' => {
+                        let digits = [chars.next(), chars.next()];
+                        if !digits
+                            .iter()
+                            .all(|d| d.map_or(false, |d| d.is_ascii_hexdigit()))
+                        {
+                            return Err(error("`\\x` takes two hex digits, e.g. `\\x41`".into()));
+                        }
+                        translated.push_str("\\x");
+                        translated.extend(digits.iter().flatten());
+                    }
+                    '/' => translated.push('/'),
+                    '-' if in_class => translated.push_str("\\-"),
+                    '^' | '$' | '\\' | '.' | '*' | '+' | '?' | '(' | ')' | '[' | ']' | '{'
+                    | '}' | '|' => {
+                        translated.push('\\');
+                        translated.push(escaped);
+                    }
+                    'b' | 'B' => {
+                        return Err(error(format!(
+                            "`\\{}` is not supported, word boundaries are not the same in the \
+                            browser",
+                            escaped
+                        )))
+                    }
+                    escaped => return Err(error(format!("`\\{}` is not supported", escaped))),
+                }
+            }
+            '[' if in_class => {
+                return Err(error(
+                    "a `[` in a character class must be escaped as `\\[`".to_string(),
+                ))
+            }
+            '[' => {
+                in_class = true;
+                translated.push('[');
+                if chars.peek() == Some(&'^') {
+                    translated.push(chars.next().unwrap_or_default());
+                }
+                if chars.peek() == Some(&']') {
+                    return Err(error(
+                        "empty character classes are not supported".to_string(),
+                    ));
+                }
+            }
+            ']' if in_class => {
+                in_class = false;
+                translated.push(']');
+            }
+            '&' | '~' | '-' if in_class && chars.peek() == Some(&c) => {
+                return Err(error(format!(
+                    "`{0}{0}` in a character class is not supported",
+                    c
+                )))
+            }
+            _ if in_class => translated.push(c),
+            '.' => translated.push_str(r"[^\n\r\x{2028}\x{2029}]"),
+            '(' if chars.peek() == Some(&'?') => {
+                chars.next();
+                if chars.next() != Some(':') {
+                    return Err(error(
+                        "only `(..)` and `(?:..)` groups are supported".to_string(),
+                    ));
+                }
+                translated.push_str("(?:");
+            }
+            '{' => {
+                let mut repetition = String::new();
+                for c in chars.by_ref() {
+                    if c == '}' {
+                        break;
+                    }
+                    repetition.push(c);
+                }
+                let mut bounds = repetition.splitn(2, ',');
+                let valid = bounds.next().map_or(false, |v| {
+                    !v.is_empty() && v.chars().all(|c| c.is_ascii_digit())
+                }) && bounds.all(|v| v.chars().all(|c| c.is_ascii_digit()));
+                if !valid {
+                    return Err(error(
+                        "a `{` must be escaped as `\\{` unless it is a repetition like `{2,3}`"
+                            .to_string(),
+                    ));
+                }
+                translated.push_str(format!("{{{}}}", repetition).as_str());
+            }
+            '}' | ']' => return Err(error(format!("a `{0}` must be escaped as `\\{0}`", c))),
+            c => translated.push(c),
+        }
+    }
+
+    if in_class {
+        return Err(error("the character class is not closed".to_string()));
+    }
+    Regex::new(translated.as_str()).map_err(|e| error(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use fastn_grammar::evalexpr::function::regex_subset::compile;
+
+    #[test]
+    fn matches_like_the_browser() {
+        let is_match = |pattern: &str, text: &str| compile(pattern).unwrap().is_match(text);
+
+        assert!(is_match(r"^\d{3}-\w+$", "123-abc_9"));
+        // the classes are ASCII only
+        assert!(!is_match(r"^\d$", "٣"));
+        assert!(!is_match(r"^\w$", "é"));
+        assert!(is_match(r"^[\W]$", "é"));
+        // `.` doesn't match line terminators, `\s` is the whitespace of JavaScript
+        assert!(!is_match(r"^a.b$", "a\rb"));
+        assert!(is_match(r"^\s$", "\u{FEFF}"));
+        assert!(!is_match(r"^\s$", "\u{85}"));
+        assert!(is_match(r"^(?:a|b)+?[^\-x]\.\/$", "abc./"));
+    }
+
+    #[test]
+    fn rejects_what_the_browser_does_differently() {
+        for pattern in [
+            r"\bword\b",
+            r"(?i)a",
+            r"a(?=b)",
+            r"(?P<name>a)",
+            r"\p{L}",
+            r"[[:alpha:]]",
+            r"[a&&b]",
+            r"[^]",
+            r"a{,2}",
+            r"a}",
+            r"\A",
+        ] {
+            assert!(compile(pattern).is_err(), "{}", pattern);
+        }
+    }
+}
@@ -369,6 +369,12 @@
 //! | `shl`                | 2               | Int                    | Computes the given integer bitwise shifted left by the other given integer |
 //! | `shr`                | 2               | Int                    | Computes the given integer bitwise shifted right by the other given integer |
 //! | `random`             | 0               | Empty                  | Return a random float between 0 and 1. Requires the `rand` feature flag. |
+//! | `date::now`          | 0               | Empty                  | Returns the current date time in UTC |
+//! | `date::format`       | 2, 3            | String, String, String | Formats the date time in the first argument with the strftime style format in the second argument, month and day names are in the locale in the optional third argument |
+//! | `date::relative`     | 1, 2            | String, String         | Describes the date time relative to now or to the optional second argument, e.g. "3 days ago" |
+//! | `date::compare`      | 2               | String, String         | Returns -1, 0 or 1 if the first date time is before, the same as or after the second one |
+//! | `date::add`          | 2               | String, Int            | Returns the date time moved by the number of seconds in the second argument |
+//! | `date::diff`         | 2               | String, String         | Returns the number of seconds from the second date time to the first one |
 //!
 //! The `min` and `max` functions can deal with a mixture of integer and floating point arguments.
 //! If the maximum or minimum is an integer, then an integer is returned.
@@ -376,6 +382,9 @@
 //!
 //! The regex functions require the feature flag `regex_support`.
 //!
+//! The date functions take dates and date times as ISO-8601 strings, e.g. `2023-06-01` or
+//! `2023-06-01T10:30:00+05:30`, and durations as a number of seconds.
+//!
 //! ### Values
 //!
 //! Operators take values as arguments and produce values as results.
@@ -533,13 +542,15 @@ extern crate serde;
 #[macro_use]
 extern crate serde_derive;
 
+#[cfg(feature = "regex_support")]
+pub use fastn_grammar::evalexpr::function::regex_subset;
 pub use fastn_grammar::evalexpr::{
     context::{
         Context, ContextWithMutableFunctions, ContextWithMutableVariables, EmptyContext,
         HashMapContext, IterateVariablesContext,
     },
     error::{EvalexprError, EvalexprResult},
-    function::Function,
+    function::{builtin::builtin_function, collections, datetime, Function},
     interface::*,
     operator::Operator,
     token::PartialToken,
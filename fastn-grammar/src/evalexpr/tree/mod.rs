@@ -172,10 +172,35 @@ impl ExprNode {
         })
     }
 
+    /// The condition and the branches of a call to the builtin `if`. Only the branch it returns
+    /// is evaluated, so the other one can't fail, e.g. by reading the value of another variant
+    /// in a `match` over an or-type.
+    fn if_arguments(&self) -> Option<&[ExprNode]> {
+        match self.operator() {
+            Operator::FunctionIdentifier { identifier } if identifier.eq("if") => {}
+            _ => return None,
+        }
+        let mut node = self.children().first()?;
+        while matches!(node.operator(), Operator::RootNode) && node.children().len() == 1 {
+            node = node.children().first()?;
+        }
+        match node.operator() {
+            Operator::Tuple if node.children().len() == 3 => Some(node.children()),
+            _ => None,
+        }
+    }
+
     /// Evaluates the operator tree rooted at this node with the given context.
     ///
     /// Fails, if one of the operators in the expression tree fails.
     pub fn eval_with_context<C: Context>(&self, context: &C) -> EvalexprResult<Value> {
+        if let Some([condition, then, otherwise]) = self.if_arguments() {
+            return if condition.eval_with_context(context)?.as_boolean()? {
+                then.eval_with_context(context)
+            } else {
+                otherwise.eval_with_context(context)
+            };
+        }
         let mut arguments = Vec::new();
         for child in self.children() {
             arguments.push(child.eval_with_context(context)?);
@@ -190,6 +215,13 @@ impl ExprNode {
         &self,
         context: &mut C,
     ) -> EvalexprResult<Value> {
+        if let Some([condition, then, otherwise]) = self.if_arguments() {
+            return if condition.eval_with_context_mut(context)?.as_boolean()? {
+                then.eval_with_context_mut(context)
+            } else {
+                otherwise.eval_with_context_mut(context)
+            };
+        }
         let mut arguments = Vec::new();
         for child in self.children() {
             arguments.push(child.eval_with_context_mut(context)?);
@@ -1,6 +1,8 @@
 let fastn_dom = {};
 
 fastn_dom.classes = {}
+// css rules that are not classes, e.g. the `@keyframes` of `animation`, by name
+fastn_dom.rules = {}
 fastn_dom.unsanitised_classes = {}
 fastn_dom.class_count = 0;
 fastn_dom.property_map = {
@@ -11,14 +13,92 @@ fastn_dom.property_map = {
     "height": "h",
     "border-width": "bw",
     "border-style": "bs",
-
+    "z-index": "z",
+    "left": "l",
+    "right": "r",
+    "top": "t",
+    "bottom": "b",
+    "position": "pos",
+    "cursor": "cur",
+    "font-size": "fs",
+    "line-height": "lh",
+    "letter-spacing": "ls",
+    "font-weight": "fwt",
+    "font-family": "ff",
+    "font-style": "fst",
+    "padding-left": "pl",
+    "padding-right": "pr",
+    "padding-top": "pt",
+    "padding-bottom": "pb",
+    "margin-left": "ml",
+    "margin-right": "mr",
+    "margin-top": "mt",
+    "margin-bottom": "mb",
+    "border-radius": "br",
+    "border-color": "bc",
+    "border-top-width": "btw",
+    "border-bottom-width": "bbw",
+    "border-left-width": "blw",
+    "border-right-width": "brw",
+    "border-top-color": "btc",
+    "border-bottom-color": "bbc",
+    "border-left-color": "blc",
+    "border-right-color": "brc",
+    "border-top-left-radius": "btlr",
+    "border-top-right-radius": "btrr",
+    "border-bottom-left-radius": "bblr",
+    "border-bottom-right-radius": "bbrr",
+    "border-top-style": "bts",
+    "border-bottom-style": "bbs",
+    "border-left-style": "bls",
+    "border-right-style": "brs",
+    "min-width": "mw",
+    "max-width": "mxw",
+    "min-height": "mh",
+    "max-height": "mxh",
+    "background-color": "bgc",
+    "background-image": "bgi",
+    "background-repeat": "bgr",
+    "background-size": "bgs",
+    "background-position": "bgp",
+    "align-self": "as",
+    "overflow": "o",
+    "overflow-x": "ox",
+    "overflow-y": "oy",
+    "opacity": "op",
+    "resize": "res",
+    "white-space": "ws",
+    "text-transform": "tt",
+    "text-decoration": "td",
+    "box-shadow": "bxs",
+    "display": "dp",
+    "text-align": "ta",
+    "-webkit-line-clamp": "lc",
+    "-webkit-box-orient": "wbo",
+    "text-indent": "ti",
+    "gap": "g",
+    "row-gap": "rg",
+    "column-gap": "cg",
+    "grid-template-columns": "gtc",
+    "grid-template-rows": "gtr",
+    "grid-template-areas": "gta",
+    "grid-area": "ga",
+    "grid-column-start": "gcs",
+    "grid-column-end": "gce",
+    "grid-row-start": "grs",
+    "grid-row-end": "gre",
+    "justify-content": "jc",
+    "align-items": "ai",
+    "flex-wrap": "fw",
+    "fill": "fl",
+    "stroke": "stk",
 };
 
 // dynamic-class-css.md
 fastn_dom.getClassesAsString = function() {
     let classes = Object.entries(fastn_dom.classes).map(entry => {
         return getClassAsString(entry[0], entry[1]);
-    });
+    }).concat(Object.values(fastn_dom.rules));
 
     return `<style id="styles">
     /*.ft_text {
@@ -29,7 +109,13 @@ fastn_dom.getClassesAsString = function() {
 }
 
 function getClassAsString(className, obj) {
-    return `.${className} { ${obj.property}: ${obj.value}; }`;
+    // `prefix` scopes the class, e.g. `body.dark` for the colours used in dark mode
+    let selector = obj.prefix ? `${obj.prefix} .${className}` : `.${className}`;
+    // `pseudo` is the state of `color.hover` etc, it shows over the inline style of the element
+    if (obj.pseudo) {
+        return `${selector}:${obj.pseudo} { ${obj.property}: ${obj.value} !important; }`;
+    }
+    return `${selector} { ${obj.property}: ${obj.value}; }`;
 }
 
 fastn_dom.ElementKind = {
@@ -43,6 +129,26 @@ fastn_dom.ElementKind = {
     IFrame: 7,
     // To create parent for dynamic DOM
     Div: 8,
+    Code: 9,
+    Container: 10,
+    TextInput: 11,
+    TextArea: 12,
+    CheckBox: 13,
+    Rive: 14,
+    Document: 15,
+    WebComponent: (name, args) => { return [16, {name, args}]; },
+    Grid: 17,
+    Select: 18,
+    RadioGroup: 19,
+    Form: 20,
+    Video: 21,
+    Audio: 22,
+    Svg: 23,
+    Icon: 24,
+    Table: 25,
+    Dialog: 26,
+    Popover: 27,
+    Tooltip: 28,
 };
 
 fastn_dom.PropertyKind = {
@@ -56,11 +162,203 @@ fastn_dom.PropertyKind = {
     BorderWidth: 7,
     BorderStyle: 8,
     Margin: 9,
+    DecimalValue: 10,
+    BooleanValue: 11,
+    ZIndex: 12,
+    Left: 13,
+    Right: 14,
+    Top: 15,
+    Bottom: 16,
+    Anchor: 17,
+    Role: 18,
+    Region: 19,
+    Cursor: 20,
+    Classes: 21,
+    PaddingHorizontal: 22,
+    PaddingVertical: 23,
+    PaddingLeft: 24,
+    PaddingRight: 25,
+    PaddingTop: 26,
+    PaddingBottom: 27,
+    MarginHorizontal: 28,
+    MarginVertical: 29,
+    MarginLeft: 30,
+    MarginRight: 31,
+    MarginTop: 32,
+    MarginBottom: 33,
+    BorderRadius: 34,
+    BorderColor: 35,
+    BorderTopWidth: 36,
+    BorderTopColor: 37,
+    BorderBottomWidth: 38,
+    BorderBottomColor: 39,
+    BorderLeftWidth: 40,
+    BorderLeftColor: 41,
+    BorderRightWidth: 42,
+    BorderRightColor: 43,
+    BorderTopLeftRadius: 44,
+    BorderTopRightRadius: 45,
+    BorderBottomLeftRadius: 46,
+    BorderBottomRightRadius: 47,
+    BorderStyleVertical: 48,
+    BorderStyleHorizontal: 49,
+    BorderStyleLeft: 50,
+    BorderStyleRight: 51,
+    BorderStyleTop: 52,
+    BorderStyleBottom: 53,
+    MinWidth: 54,
+    MaxWidth: 55,
+    MinHeight: 56,
+    MaxHeight: 57,
+    Link: 58,
+    OpenInNewTab: 59,
+    Background: 60,
+    AlignSelf: 61,
+    Overflow: 62,
+    OverflowX: 63,
+    OverflowY: 64,
+    Opacity: 65,
+    Resize: 66,
+    WhiteSpace: 67,
+    TextTransform: 68,
+    Sticky: 69,
+    Shadow: 70,
+    Display: 71,
+    TextAlign: 72,
+    LineClamp: 73,
+    TextIndent: 74,
+    TextStyle: 75,
+    Spacing: 76,
+    Wrap: 77,
+    AlignContent: 78,
+    ImageSrc: 79,
+    Alt: 80,
+    Code: 81,
+    CodeLanguage: 82,
+    CodeTheme: 83,
+    IFrameSrc: 84,
+    YoutubeSrc: 85,
+    IFrameSrcDoc: 86,
+    Loading: 87,
+    Placeholder: 88,
+    Value: 89,
+    DefaultValue: 90,
+    Enabled: 91,
+    InputType: 92,
+    Checked: 93,
+    RiveSrc: 94,
+    RiveCanvasWidth: 95,
+    RiveCanvasHeight: 96,
+    RiveStateMachine: 97,
+    RiveAutoplay: 98,
+    RiveArtboard: 99,
+    MetaTitle: 100,
+    MetaOgTitle: 101,
+    MetaTwitterTitle: 102,
+    MetaDescription: 103,
+    MetaOgDescription: 104,
+    MetaTwitterDescription: 105,
+    MetaOgImage: 106,
+    MetaTwitterImage: 107,
+    MetaThemeColor: 108,
+    GridTemplateColumns: 109,
+    GridTemplateRows: 110,
+    GridTemplateAreas: 111,
+    Gap: 112,
+    RowGap: 113,
+    ColumnGap: 114,
+    GridArea: 115,
+    GridColumnStart: 116,
+    GridColumnSpan: 117,
+    GridRowStart: 118,
+    GridRowSpan: 119,
+    ColorHover: 120,
+    BackgroundHover: 121,
+    BorderColorHover: 122,
+    ShadowHover: 123,
+    ColorFocus: 124,
+    BackgroundFocus: 125,
+    BorderColorFocus: 126,
+    ShadowFocus: 127,
+    ColorActive: 128,
+    BackgroundActive: 129,
+    BorderColorActive: 130,
+    ShadowActive: 131,
+    Transform: 132,
+    Transition: 133,
+    Animation: 134,
+    Options: 135,
+    FieldName: 136,
+    Required: 137,
+    Min: 138,
+    Max: 139,
+    Step: 140,
+    Pattern: 141,
+    FormAction: 142,
+    FormMethod: 143,
+    MediaSources: 144,
+    Captions: 145,
+    Poster: 146,
+    Controls: 147,
+    Autoplay: 148,
+    Muted: 149,
+    Loop: 150,
+    SvgSrc: 151,
+    IconName: 152,
+    IconSprite: 153,
+    Fill: 154,
+    Stroke: 155,
+    TableTitle: 156,
+    TableColumns: 157,
+    TableCells: 158,
+    TableFilterable: 159,
+    TablePageSize: 160,
+    TablePageParam: 161,
+    TablePage: 162,
+    TableTotal: 163,
+    TableData: 164,
+    AriaLabel: 165,
+    AriaDescribedBy: 166,
+    AriaHidden: 167,
+    AriaLive: 168,
+    Landmark: 169,
+    TabIndex: 170,
+    OverlayOpen: 171,
+    OverlayCloseOnEscape: 172,
+    OverlayCloseOnOutsideClick: 173,
+    OverlayTrigger: 174,
+    OverlayPlacement: 175,
+    Breakpoints: 176,
+}
+
+// the css property and the pseudo-class of `color.hover`, `background.focus` etc
+fastn_dom.statePropertyKinds = {
+    [fastn_dom.PropertyKind.ColorHover]: ["color", "hover"],
+    [fastn_dom.PropertyKind.BackgroundHover]: ["background", "hover"],
+    [fastn_dom.PropertyKind.BorderColorHover]: ["border-color", "hover"],
+    [fastn_dom.PropertyKind.ShadowHover]: ["shadow", "hover"],
+    [fastn_dom.PropertyKind.ColorFocus]: ["color", "focus"],
+    [fastn_dom.PropertyKind.BackgroundFocus]: ["background", "focus"],
+    [fastn_dom.PropertyKind.BorderColorFocus]: ["border-color", "focus"],
+    [fastn_dom.PropertyKind.ShadowFocus]: ["shadow", "focus"],
+    [fastn_dom.PropertyKind.ColorActive]: ["color", "active"],
+    [fastn_dom.PropertyKind.BackgroundActive]: ["background", "active"],
+    [fastn_dom.PropertyKind.BorderColorActive]: ["border-color", "active"],
+    [fastn_dom.PropertyKind.ShadowActive]: ["shadow", "active"],
+}
+
+fastn_dom.Easing = {
+    Linear: "linear",
+    Ease: "ease",
+    EaseIn: "ease-in",
+    EaseOut: "ease-out",
+    EaseInOut: "ease-in-out",
 }
 
 fastn_dom.Resizing = {
     FillContainer: "100%",
     HugContent: "fit-content",
+    Auto: "auto",
     Fixed: (value) => { return value; }
 }
 
@@ -118,34 +416,356 @@ fastn_dom.Length = {
         }
         return `${value}vw`;
     },
-    Responsive: (desktop, mobile) => {
-        if (ftd.device == "desktop") {
-            return desktop;
-        } else {
-            return mobile ? mobile: desktop;
-        }
+    // the length changes with `ftd.device`
+    Responsive: (length) => {
+        return fastn.formula([ftd.device], function () {
+            return fastn_utils.getDeviceValue(length);
+        });
     }
 }
 
+fastn_dom.FontSize = {
+    Px: fastn_dom.Length.Px,
+    Em: fastn_dom.Length.Em,
+    Rem: fastn_dom.Length.Rem,
+}
+
+fastn_dom.Anchor = {
+    Parent: "absolute",
+    Window: "fixed",
+    Id: (value) => { return ["id", value]; },
+}
+
+fastn_dom.Region = {
+    H1: "h1",
+    H2: "h2",
+    H3: "h3",
+    H4: "h4",
+    H5: "h5",
+    H6: "h6",
+}
+
+fastn_dom.Cursor = {
+    Default: "default",
+    None: "none",
+    ContextMenu: "context-menu",
+    Help: "help",
+    Pointer: "pointer",
+    Progress: "progress",
+    Wait: "wait",
+    Cell: "cell",
+    Crosshair: "crosshair",
+    Text: "text",
+    VerticalText: "vertical-text",
+    Alias: "alias",
+    Copy: "copy",
+    Move: "move",
+    NoDrop: "no-drop",
+    NotAllowed: "not-allowed",
+    Grab: "grab",
+    Grabbing: "grabbing",
+    EResize: "e-resize",
+    NResize: "n-resize",
+    NeResize: "ne-resize",
+    NwResize: "nw-resize",
+    SResize: "s-resize",
+    SeResize: "se-resize",
+    SwResize: "sw-resize",
+    WResize: "w-resize",
+    EwResize: "ew-resize",
+    NsResize: "ns-resize",
+    NeswResize: "nesw-resize",
+    NwseResize: "nwse-resize",
+    ColResize: "col-resize",
+    RowResize: "row-resize",
+    AllScroll: "all-scroll",
+    ZoomIn: "zoom-in",
+    ZoomOut: "zoom-out",
+}
+
+// [vertical, horizontal]
+fastn_dom.Align = {
+    TopLeft: ["start", "start"],
+    TopCenter: ["start", "center"],
+    TopRight: ["start", "end"],
+    Left: ["center", "start"],
+    Center: ["center", "center"],
+    Right: ["center", "end"],
+    BottomLeft: ["end", "start"],
+    BottomCenter: ["end", "center"],
+    BottomRight: ["end", "end"],
+}
+
+fastn_dom.AlignSelf = {
+    Start: "start",
+    Center: "center",
+    End: "end",
+}
+
+fastn_dom.Overflow = {
+    Scroll: "scroll",
+    Visible: "visible",
+    Hidden: "hidden",
+    Auto: "auto",
+}
+
+fastn_dom.Resize = {
+    Horizontal: "horizontal",
+    Vertical: "vertical",
+    Both: "both",
+}
+
+fastn_dom.WhiteSpace = {
+    Normal: "normal",
+    Nowrap: "nowrap",
+    Pre: "pre",
+    PreWrap: "pre-wrap",
+    PreLine: "pre-line",
+    BreakSpaces: "break-spaces",
+}
+
+fastn_dom.TextTransform = {
+    None: "none",
+    Capitalize: "capitalize",
+    Uppercase: "uppercase",
+    Lowercase: "lowercase",
+    Initial: "initial",
+    Inherit: "inherit",
+}
+
+// the tracks of a grid, `Fill` and `Fit` repeat columns of at least the given length as many
+// times as they fit
+fastn_dom.GridTemplate = {
+    Count: (value) => {
+        if (value instanceof fastn.mutableClass) {
+            return fastn.formula([value], function () { return `repeat(${value.get()}, minmax(0, 1fr))`})
+        }
+        return `repeat(${value}, minmax(0, 1fr))`;
+    },
+    Fill: (value) => {
+        if (value instanceof fastn.mutableClass) {
+            return fastn.formula([value], function () { return `repeat(auto-fill, minmax(${value.get()}, 1fr))`})
+        }
+        return `repeat(auto-fill, minmax(${value}, 1fr))`;
+    },
+    Fit: (value) => {
+        if (value instanceof fastn.mutableClass) {
+            return fastn.formula([value], function () { return `repeat(auto-fit, minmax(${value.get()}, 1fr))`})
+        }
+        return `repeat(auto-fit, minmax(${value}, 1fr))`;
+    },
+    Tracks: (value) => { return value; },
+    Responsive: fastn_dom.Length.Responsive,
+}
+
+fastn_dom.Display = {
+    Block: "block",
+    Inline: "inline",
+    InlineBlock: "inline-block",
+}
+
+fastn_dom.TextAlign = {
+    Start: "start",
+    Center: "center",
+    End: "end",
+    Justify: "justify",
+}
+
+// [css property, value]
+fastn_dom.TextStyle = {
+    Underline: ["text-decoration", "underline"],
+    Strike: ["text-decoration", "line-through"],
+    Italic: ["font-style", "italic"],
+    Heavy: ["font-weight", "900"],
+    ExtraBold: ["font-weight", "800"],
+    Bold: ["font-weight", "700"],
+    SemiBold: ["font-weight", "600"],
+    Medium: ["font-weight", "500"],
+    Regular: ["font-weight", "400"],
+    Light: ["font-weight", "300"],
+    ExtraLight: ["font-weight", "200"],
+    Hairline: ["font-weight", "100"],
+}
+
+// [css property, value]
+fastn_dom.Spacing = {
+    SpaceBetween: ["justify-content", "space-between"],
+    SpaceEvenly: ["justify-content", "space-evenly"],
+    SpaceAround: ["justify-content", "space-around"],
+    Fixed: (value) => {
+        if (value instanceof fastn.mutableClass) {
+            return fastn.formula([value], function () { return ["gap", value.get()]; })
+        }
+        return ["gap", value];
+    },
+}
+
+fastn_dom.Loading = {
+    Eager: "eager",
+    Lazy: "lazy",
+}
+
+fastn_dom.ColumnType = {
+    Text: "text",
+    Number: "number",
+    Date: "date",
+    Boolean: "boolean",
+}
+
+fastn_dom.Landmark = {
+    Banner: "banner",
+    Navigation: "navigation",
+    Main: "main",
+    Complementary: "complementary",
+    ContentInfo: "contentinfo",
+    Search: "search",
+    Form: "form",
+    Region: "region",
+}
+
+fastn_dom.AriaLive = {
+    Polite: "polite",
+    Assertive: "assertive",
+    Off: "off",
+}
+
+fastn_dom.Placement = {
+    Top: "top",
+    Bottom: "bottom",
+    Left: "left",
+    Right: "right",
+}
+
+fastn_dom.TextInputType = {
+    Text: "text",
+    Email: "email",
+    Password: "password",
+    Url: "url",
+    Datetime: "datetime-local",
+    Date: "date",
+    Time: "time",
+    Month: "month",
+    Week: "week",
+    Color: "color",
+    File: "file",
+    Number: "number",
+    Range: "range",
+    Submit: "submit",
+}
+
+fastn_dom.DeviceData = {
+    Mobile: "mobile",
+    Tablet: "tablet",
+    Desktop: "desktop",
+    Wide: "wide",
+}
+
+fastn_dom.Background = {
+    Solid: (value) => { return [1, value]; },
+    Image: (value) => { return [2, value]; },
+    LinearGradient: (value) => { return [3, value]; },
+}
 
+fastn_dom.BackgroundRepeat = {
+    Repeat: "repeat",
+    RepeatX: "repeat-x",
+    RepeatY: "repeat-y",
+    NoRepeat: "no-repeat",
+    Space: "space",
+    Round: "round",
+}
+
+fastn_dom.BackgroundSize = {
+    Auto: "auto",
+    Cover: "cover",
+    Contain: "contain",
+    Length: (value) => {
+        let x = fastn_utils.getStaticValue(value.get("x"));
+        let y = fastn_utils.getStaticValue(value.get("y"));
+        return `${x} ${y}`;
+    },
+}
+
+fastn_dom.BackgroundPosition = {
+    Left: "left",
+    Center: "center",
+    Right: "right",
+    LeftTop: "left top",
+    LeftCenter: "left center",
+    LeftBottom: "left bottom",
+    CenterTop: "center top",
+    CenterCenter: "center center",
+    CenterBottom: "center bottom",
+    RightTop: "right top",
+    RightCenter: "right center",
+    RightBottom: "right bottom",
+    Length: fastn_dom.BackgroundSize.Length,
+}
+
+fastn_dom.LinearGradientDirections = {
+    Angle: (value) => { return `${value}deg`; },
+    Turn: (value) => { return `${value}turn`; },
+    Left: "to left",
+    Right: "to right",
+    Top: "to top",
+    Bottom: "to bottom",
+    TopLeft: "to top left",
+    BottomLeft: "to bottom left",
+    TopRight: "to top right",
+    BottomRight: "to bottom right",
+}
+
+
+
+// the radio groups without a name, each gets a name of its own
+fastn_dom.radio_group_count = 0;
 
 fastn_dom.Event = {
-    Click: 0
+    Click: 0,
+    MouseEnter: 1,
+    MouseLeave: 2,
+    ClickOutside: 3,
+    GlobalKey: (keys) => { return [4, keys]; },
+    GlobalKeySeq: (keys) => { return [5, keys]; },
+    Input: 6,
+    Change: 7,
+    Blur: 8,
+    Focus: 9,
+    RivePlay: (timeline) => { return [10, timeline]; },
+    RiveStateChange: (state) => { return [11, state]; },
+    RivePause: (timeline) => { return [12, timeline]; },
+    Submit: 13,
+    Play: 14,
+    Pause: 15,
+    Ended: 16,
+    TimeUpdate: 17,
 }
 
 class Node2 {
     #node;
+    #kind;
     #parent;
     #mutables;
+    #extraData;
     constructor(parent, kind) {
-        let [node, classes] = fastn_utils.htmlNode(kind);
+        this.#kind = kind;
+        let [node, classes, attributes] = fastn_utils.htmlNode(kind);
         this.#node = fastn_virtual.document.createElement(node);
         for (let c in classes) {
             this.#node.classList.add(classes[c]);
         }
+        for (let key in attributes) {
+            this.#node.setAttribute(key, attributes[key]);
+        }
+        if (Array.isArray(kind) && !ssr) {
+            // web component, its arguments are read with `ftd.component_data`
+            this.#node.data = kind[1].args;
+        }
         this.#parent = parent;
         // this is where we store all the attached closures, so we can free them when we are done
         this.#mutables = [];
+        // data needed by some kernels, like rive, to build themselves
+        this.#extraData = {};
         /*if (!!parent.parent) {
             parent = parent.parent();
         }*/
@@ -153,19 +773,37 @@ class Node2 {
             this.#parent = this.#parent.getNode();
         }
         this.#parent.appendChild(this.#node);
+        if (kind === fastn_dom.ElementKind.Grid) {
+            this.attachCss("display", "grid");
+        }
     }
     parent() {
         return this.#parent;
     }
     // dynamic-class-css
-    attachCss(property, value) {
-        const propertyShort = fastn_dom.property_map[property] || property;
+    attachCss(property, value, prefix, pseudo) {
+        let propertyShort = fastn_dom.property_map[property] || property;
+        if (pseudo) {
+            propertyShort = `${pseudo}-${propertyShort}`;
+        }
+        if (prefix) {
+            propertyShort = `d${propertyShort}`;
+        }
+        if (value === null) {
+            value = undefined;
+        }
         let cls = `${propertyShort}-${value}`;
         if (!fastn_dom.unsanitised_classes[cls]) {
             fastn_dom.unsanitised_classes[cls] = ++fastn_dom.class_count;
         }
         cls = `${propertyShort}-${fastn_dom.unsanitised_classes[cls]}`;
         const obj = { property, value };
+        if (prefix) {
+            obj.prefix = prefix;
+        }
+        if (pseudo) {
+            obj.pseudo = pseudo;
+        }
 
         if (!ssr && !hydrating) {
             for (const className of this.#node.classList.values()) {
@@ -174,12 +812,19 @@ class Node2 {
                 }
             }
             if (value === undefined) {
+                if (!prefix && !pseudo) {
+                    this.#node.style[property] = null;
+                }
                 return;
             }
 
-            if (!fastn_dom.classes[cls]) {
+            if (!fastn_dom.classes[cls] && !prefix && !pseudo) {
                 this.#node.style[property] = value;
             } else {
+                if (!fastn_dom.classes[cls]) {
+                    fastn_dom.classes[cls] = obj;
+                    fastn_utils.addCssToDocument(getClassAsString(cls, obj));
+                }
                 this.#node.classList.add(cls);
             }
 
@@ -191,27 +836,921 @@ class Node2 {
             this.#node.classList.add(cls);
         }
     }
+    // `value` is a `ftd.color` record, the dark colour only applies when the body has the `dark`
+    // class
+    attachColorCss(property, value, pseudo) {
+        if (value === undefined || value === null) {
+            this.attachCss(property, value, undefined, pseudo);
+            this.removeDarkCss(property, pseudo);
+            return;
+        }
+        let lightValue = fastn_utils.getStaticValue(value.get("light"));
+        let darkValue = fastn_utils.getStaticValue(value.get("dark"));
+        this.attachCss(property, lightValue, undefined, pseudo);
+        if (lightValue !== darkValue) {
+            this.attachCss(property, darkValue, "body.dark", pseudo);
+        } else {
+            this.removeDarkCss(property, pseudo);
+        }
+    }
+    // a rendered element can have a dark colour from an earlier value
+    removeDarkCss(property, pseudo) {
+        if (!ssr && !hydrating) {
+            this.attachCss(property, undefined, "body.dark", pseudo);
+        }
+    }
+    attachAttribute(property, value) {
+        if (value === undefined || value === null) {
+            this.#node.removeAttribute(property);
+            return;
+        }
+        this.#node.setAttribute(property, value);
+    }
+    // `alt` on an element other than an image makes it an `img` named by the
+    // alt text, an `aria-label` or a `landmark` takes precedence over it
+    attachAccessibility(key, value) {
+        if (key === "alt" && this.#kind === fastn_dom.ElementKind.Image) {
+            this.attachAttribute("alt", value);
+            return;
+        }
+        this.#extraData[key] = value;
+        let alt = this.#extraData.alt ?? null;
+        let label = this.#extraData.aria_label ?? null;
+        let landmark = this.#extraData.landmark ?? null;
+        this.attachAttribute("aria-label", label ?? alt);
+        this.attachAttribute("role", landmark ?? (alt === null ? null : "img"));
+    }
+    // `ftd.dialog`, `ftd.popover` and `ftd.tooltip`, shown in the top layer of the page
+    attachOverlay(key, value) {
+        let overlay = this.#extraData.overlay || (this.#extraData.overlay = {});
+        overlay[key] = value;
+        if (ssr) {
+            if (key === "open" && this.#kind === fastn_dom.ElementKind.Dialog) {
+                // a dialog open when the page is rendered is shown before it is hydrated
+                this.attachAttribute("open", value ? "" : null);
+            }
+            return;
+        }
+        if (!overlay.close) {
+            overlay.close = () => {
+                if (!!overlay.mutable) {
+                    overlay.mutable.set(false);
+                } else {
+                    this.attachOverlay("open", false);
+                }
+            };
+            overlay.toggle = (open) => {
+                if (!!overlay.mutable) {
+                    overlay.mutable.set(open);
+                } else {
+                    this.attachOverlay("open", open);
+                }
+            };
+            if (this.#kind !== fastn_dom.ElementKind.Dialog) {
+                this.addEventHandler(fastn_dom.Event.ClickOutside, (e) => {
+                    if (overlay.open && overlay.close_on_outside_click !== false
+                        && e.timeStamp >= overlay.opened_at
+                        && !fastn_utils.isOverlayTrigger(overlay, e.target)) {
+                        overlay.close();
+                    }
+                });
+            }
+            fastn_utils.initialiseOverlay(this.#node, this.#kind, overlay);
+        }
+        if (key === "open" || overlay.open) {
+            fastn_utils.updateOverlay(this.#node, this.#kind, overlay);
+        }
+    }
+    // `ftd.link` and `ftd.region` change the tag of an already created element
+    updateTagName(name) {
+        if (ssr) {
+            this.#node.updateTagName(name);
+            return;
+        }
+        if (hydrating || this.#node.tagName.toLowerCase() === name) {
+            return;
+        }
+        let node = fastn_virtual.document.createElement(name);
+        for (const attribute of this.#node.attributes) {
+            node.setAttribute(attribute.name, attribute.value);
+        }
+        node.innerHTML = this.#node.innerHTML;
+        this.#node.replaceWith(node);
+        this.#node = node;
+    }
+    attachLength(property, value) {
+        if (Array.isArray(value)) {
+            // `ftd.spacing`
+            this.attachCss(value[0], value[1]);
+            return;
+        }
+        this.attachCss(property, value);
+    }
+    attachAlignContent(value) {
+        if (value === undefined || value === null) {
+            this.attachCss("justify-content", value);
+            this.attachCss("align-items", value);
+            return;
+        }
+        let [vertical, horizontal] = value;
+        if (this.#kind === fastn_dom.ElementKind.Column) {
+            this.attachCss("justify-content", vertical);
+            this.attachCss("align-items", horizontal);
+        } else {
+            this.attachCss("justify-content", horizontal);
+            this.attachCss("align-items", vertical);
+        }
+    }
+    attachRoleCss(value) {
+        if (value === undefined || value === null) {
+            return;
+        }
+        let role = fastn_utils.getDeviceValue(value);
+        this.attachCss("font-size", fastn_utils.getStaticValue(role.get("size")));
+        this.attachCss("line-height", fastn_utils.getStaticValue(role.get("line-height")));
+        this.attachCss("letter-spacing", fastn_utils.getStaticValue(role.get("letter-spacing")));
+        this.attachCss("font-weight", fastn_utils.getStaticValue(role.get("weight")));
+        this.attachCss("font-family", fastn_utils.getStaticValue(role.get("font-family")));
+    }
+    attachTextStyle(value) {
+        let styles = {"text-decoration": [], "font-style": [], "font-weight": []};
+        for (const style of fastn_utils.getFlattenStaticValue(value) || []) {
+            styles[style[0]].push(style[1]);
+        }
+        for (const property in styles) {
+            this.attachCss(property, styles[property].length ? styles[property].join(" ") : undefined);
+        }
+    }
+    attachShadow(value, pseudo) {
+        if (value === undefined || value === null) {
+            this.attachColorCss("box-shadow", value, pseudo);
+            return;
+        }
+        let shadow = ["x-offset", "y-offset", "blur", "spread"]
+            .map(key => fastn_utils.getStaticValue(value.get(key)))
+            .join(" ");
+        if (fastn_utils.getStaticValue(value.get("inset"))) {
+            shadow = `inset ${shadow}`;
+        }
+        let color = fastn_utils.getStaticValue(value.get("color"));
+        this.attachColorCss("box-shadow", fastn.recordInstance({
+            light: `${shadow} ${fastn_utils.getStaticValue(color.get("light"))}`,
+            dark: `${shadow} ${fastn_utils.getStaticValue(color.get("dark"))}`,
+        }), pseudo);
+    }
+    // a css rule that is not a class, it is added to the page once
+    attachRule(name, rule) {
+        if (fastn_dom.rules[name]) {
+            return;
+        }
+        fastn_dom.rules[name] = rule;
+        if (!ssr && !hydrating) {
+            fastn_utils.addCssToDocument(rule);
+        }
+    }
+    // `transition` and `animation`, they are turned off for `prefers-reduced-motion`
+    attachMotion(property, value) {
+        this.attachCss(property, value);
+        if (value === undefined || value === null) {
+            return;
+        }
+        this.attachRule("ftd-motion", "@media (prefers-reduced-motion: reduce) { .ftd-motion { animation: none !important; transition: none !important; } }");
+        this.#node.classList.add("ftd-motion");
+    }
+    attachTransition(value) {
+        if (value === undefined || value === null) {
+            this.attachMotion("transition", value);
+            return;
+        }
+        let get = (key) => fastn_utils.getStaticValue(value.get(key));
+        this.attachMotion("transition", `${get("property")} ${get("duration")}s ${get("easing")} ${get("delay")}s`);
+    }
+    attachAnimation(value) {
+        if (value === undefined || value === null) {
+            this.attachMotion("animation", value);
+            return;
+        }
+        let get = (key) => fastn_utils.getStaticValue(value.get(key));
+        let keyframes = get("keyframes").getList().map(v => {
+            let keyframe = fastn_utils.getStaticValue(v.item);
+            let declarations = [];
+            let opacity = fastn_utils.getStaticValue(keyframe.get("opacity"));
+            if (opacity !== null && opacity !== undefined) {
+                declarations.push(`opacity: ${opacity};`);
+            }
+            let transform = fastn_utils.getTransformCss(keyframe.get("transform"));
+            if (transform !== undefined) {
+                declarations.push(`transform: ${transform};`);
+            }
+            return `${fastn_utils.getStaticValue(keyframe.get("at"))}% { ${declarations.join(" ")} }`;
+        }).join(" ");
+        // FNV-1a of the keyframes, elements with the same keyframes share the rule
+        let hash = 0x811c9dc5;
+        for (let i = 0; i < keyframes.length; i++) {
+            hash = Math.imul(hash ^ keyframes.charCodeAt(i), 0x01000193);
+        }
+        let name = `ftd-keyframes-${(hash >>> 0).toString(16).padStart(8, "0")}`;
+        this.attachRule(name, `@keyframes ${name} { ${keyframes} }`);
+        let count = get("infinite") ? "infinite" : get("iteration-count");
+        let direction = get("alternate") ? "alternate" : "normal";
+        this.attachMotion("animation", `${name} ${get("duration")}s ${get("easing")} ${get("delay")}s ${count} ${direction}`);
+    }
+    attachBackground(value, pseudo) {
+        if (value === undefined || value === null) {
+            this.attachColorCss("background-color", value, pseudo);
+            this.attachColorCss("background-image", value, pseudo);
+            return;
+        }
+        let [kind, background] = value;
+        if (kind === 1) {
+            this.attachColorCss("background-color", background, pseudo);
+        } else if (kind === 2) {
+            let src = fastn_utils.getStaticValue(background.get("src"));
+            this.attachColorCss("background-image", fastn.recordInstance({
+                light: `url(${fastn_utils.getStaticValue(src.get("light"))})`,
+                dark: `url(${fastn_utils.getStaticValue(src.get("dark"))})`,
+            }), pseudo);
+            this.attachCss("background-repeat", fastn_utils.getStaticValue(background.get("repeat")), undefined, pseudo);
+            this.attachCss("background-size", fastn_utils.getStaticValue(background.get("size")), undefined, pseudo);
+            this.attachCss("background-position", fastn_utils.getStaticValue(background.get("position")), undefined, pseudo);
+        } else if (kind === 3) {
+            let direction = fastn_utils.getStaticValue(background.get("direction")) || "to bottom";
+            let colors = fastn_utils.getStaticValue(background.get("colors"));
+            let gradient = (mode) => {
+                let stops = colors.getList().map(v => {
+                    let color = fastn_utils.getStaticValue(v.item);
+                    let stop = [fastn_utils.getStaticValue(fastn_utils.getStaticValue(color.get("color")).get(mode))];
+                    for (const key of ["start", "end", "stop-position"]) {
+                        let position = fastn_utils.getStaticValue(color.get(key));
+                        if (position !== null && position !== undefined) {
+                            stop.push(position);
+                        }
+                    }
+                    return stop.join(" ");
+                });
+                return `linear-gradient(${direction}, ${stops.join(", ")})`;
+            };
+            this.attachColorCss("background-image", fastn.recordInstance({
+                light: gradient("light"),
+                dark: gradient("dark"),
+            }), pseudo);
+        }
+    }
+    attachImageSrc(value, attribute = "src") {
+        if (value === undefined || value === null) {
+            this.attachAttribute(attribute, value);
+            return;
+        }
+        let mode = !ssr && ftd.dark_mode.get() ? "dark" : "light";
+        this.attachAttribute(attribute, fastn_utils.getStaticValue(value.get(mode)));
+    }
+    // the `<source>`s and the caption `<track>`s of a video or an audio are rendered together,
+    // they are rendered again when either changes
+    attachMedia(key, value) {
+        let data = this.#extraData.media_data || (this.#extraData.media_data = {});
+        data[key] = value;
+        let attr = (name, value) => {
+            return value === null || value === undefined ? "" : ` ${name}="${fastn_utils.escapeHtml(value)}"`;
+        };
+        let sources = (data.sources || []).map((source) => {
+            let src = fastn_utils.getStaticValue(source.get("src"));
+            let type = fastn_utils.getStaticValue(source.get("type"));
+            return `<source${attr("src", src)}${attr("type", type)}>`;
+        });
+        let captions = (data.captions || []).map((track) => {
+            let src = fastn_utils.getStaticValue(track.get("src"));
+            let label = fastn_utils.getStaticValue(track.get("label"));
+            let lang = fastn_utils.getStaticValue(track.get("lang"));
+            let selected = fastn_utils.getStaticValue(track.get("default")) ? " default" : "";
+            return `<track kind="captions"${attr("src", src)}${attr("label", label)}${attr("srclang", lang)}${selected}>`;
+        });
+        this.#node.innerHTML = sources.concat(captions).join("");
+        if (!ssr) {
+            // the browser picks a source again only when it is asked to
+            this.#node.load();
+        }
+    }
+    attachMediaOption(attribute, value) {
+        if (ssr) {
+            this.attachAttribute(attribute, value ? "" : undefined);
+        } else {
+            this.#node[attribute] = !!value;
+        }
+    }
+    // the svg is inlined so that its shapes take the `fill` and the `stroke`, a server rendered
+    // page gets it inlined by the build
+    attachSvg(value) {
+        let src = value === undefined || value === null
+            ? value
+            : fastn_utils.getStaticValue(value.get(!ssr && ftd.dark_mode.get() ? "dark" : "light"));
+        if (hydrating && this.#node.firstElementChild
+            && this.#node.getAttribute("data-svg-src") === src) {
+            return;
+        }
+        this.attachAttribute("data-svg-src", src);
+        if (ssr) {
+            return;
+        }
+        this.#node.innerHTML = "";
+        if (src === undefined || src === null) {
+            return;
+        }
+        let node = this.#node;
+        // an svg that can't be fetched stays empty, as a broken image would
+        fetch(src)
+            .then((response) => response.ok ? response.text() : "")
+            .then((text) => {
+                // the src may have changed while the svg was being fetched
+                if (node.getAttribute("data-svg-src") === src) {
+                    node.innerHTML = text;
+                }
+            })
+            .catch(() => {});
+    }
+    // the icon is a `<use>` of a symbol in a sprite, it is rendered again when the name or the
+    // sprite changes
+    attachIcon(key, value) {
+        let data = this.#extraData.icon_data || (this.#extraData.icon_data = {});
+        if (key === "sprite" && value !== undefined && value !== null) {
+            value = fastn_utils.getStaticValue(value.get(!ssr && ftd.dark_mode.get() ? "dark" : "light"));
+        }
+        data[key] = value;
+        if (hydrating && this.#node.getAttribute(`data-icon-${key}`) === value) {
+            // the build can have pointed the icon to a symbol of the page
+            return;
+        }
+        this.attachAttribute(`data-icon-${key}`, value);
+        let href = fastn_utils.escapeHtml(`${data.sprite || ""}#${data.name || ""}`);
+        this.#node.innerHTML = `<svg width="100%" height="100%"><use href="${href}"></use></svg>`;
+    }
+    // the table is rendered once its rows are set, they are its last property, and again when a
+    // property changes; the rows are sorted, filtered and paginated in the page
+    attachTable(key, value) {
+        let data = this.#extraData.table_data || (this.#extraData.table_data = {});
+        data[key] = value;
+        if (data.data === undefined) {
+            return;
+        }
+        if (!ssr && !hydrating) {
+            this.#node.innerHTML = "";
+        }
+        let element = (tagName, parent, attributes = {}, text) => {
+            let node = fastn_virtual.document.createElement(tagName);
+            for (let key in attributes) {
+                if (attributes[key] !== null && attributes[key] !== undefined) {
+                    node.setAttribute(key, attributes[key]);
+                }
+            }
+            if (text !== undefined) {
+                node.innerHTML = fastn_utils.escapeHtml(text);
+            }
+            parent.appendChild(node);
+            return node;
+        };
+        let columns = (data.columns || []).map((column) => {
+            column = fastn_utils.getStaticValue(column);
+            let get = (field) => fastn_utils.getStaticValue(column.get(field));
+            let type = get("type") || fastn_dom.ColumnType.Text;
+            let align = get("align") ?? (type === fastn_dom.ColumnType.Number ? "end" : null);
+            return {
+                header: get("header"),
+                field: get("field"),
+                type,
+                width: get("width"),
+                style: align ? `text-align:${align}` : null,
+                sortable: get("sortable"),
+            };
+        });
+        let cells = (data.cells || []).map((cell) => fastn_utils.getStaticValue(cell));
 
+        if (data.filterable) {
+            element("input", this.#node, {
+                type: "search",
+                class: "ft_table_filter",
+                placeholder: "Filter",
+                "aria-label": "Filter rows",
+            });
+        }
+        let table = element("table", this.#node);
+        if (data.title !== null && data.title !== undefined) {
+            element("caption", table, {}, data.title);
+        }
+        let header = element("tr", element("thead", table));
+        columns.forEach((column) => {
+            let style = [column.width ? `width:${column.width}` : null, column.style]
+                .filter((s) => !!s).join(";");
+            let attributes = { scope: "col", "data-type": column.type, style: style || null };
+            if (column.sortable) {
+                attributes["aria-sort"] = "none";
+                element("button", element("th", header, attributes), { type: "button" }, column.header);
+            } else {
+                element("th", header, attributes, column.header);
+            }
+        });
+        let body = element("tbody", table);
+        data.data.forEach((row) => {
+            row = fastn_utils.getStaticValue(row);
+            let tr = element("tr", body);
+            columns.forEach((column, index) => {
+                let value = fastn_utils.getStaticValue(row.get(column.field));
+                let text = value === null || value === undefined ? "" : `${value}`;
+                let attributes = { "data-sort": text, style: column.style };
+                if (cells[index]) {
+                    cells[index](element("td", tr, attributes), value, row);
+                } else {
+                    element("td", tr, attributes, text);
+                }
+            });
+        });
+
+        let pages = data.page_size > 0 && data.total !== null && data.total !== undefined
+            ? Math.max(1, Math.ceil(data.total / data.page_size))
+            : null;
+        if (data.page_param && pages) {
+            // the server gives the rows of the page in the `page_param` query parameter
+            let page = Math.min(Math.max(data.page || 1, 1), pages);
+            let nav = element("nav", this.#node, { class: "ft_table_pages", "aria-label": "Pages" });
+            let link = (to, rel, label) => {
+                element("a", nav, { href: `?${data.page_param}=${to}`, rel }, label);
+            };
+            if (page > 1) {
+                link(page - 1, "prev", "Previous");
+            }
+            element("span", nav, {}, `Page ${page} of ${pages}`);
+            if (page < pages) {
+                link(page + 1, "next", "Next");
+            }
+        }
+        if (!ssr) {
+            fastn_utils.initialiseTable(this.#node, data.page_param ? 0 : data.page_size);
+        }
+    }
+    attachCode(value) {
+        let language = this.#extraData.code_language;
+        let code = fastn_utils.escapeHtml(value);
+        this.#node.innerHTML = language
+            ? `<code class="language-${language}">${code}</code>`
+            : `<code>${code}</code>`;
+    }
+    attachAnchor(value) {
+        if (Array.isArray(value)) {
+            this.attachCss("position", "absolute");
+            if (!ssr) {
+                let id = value[1];
+                queueMicrotask(() => {
+                    let anchor = window.document.getElementById(id);
+                    if (anchor) {
+                        anchor.style.position = "relative";
+                    }
+                });
+            }
+            return;
+        }
+        this.attachCss("position", value);
+    }
+    attachInputValue(value) {
+        if (this.#kind === fastn_dom.ElementKind.TextArea) {
+            this.#node.innerHTML = fastn_utils.escapeHtml(value);
+        } else if (ssr) {
+            this.attachAttribute("value", value);
+        } else {
+            this.#node.value = value;
+        }
+    }
+    hasOptions() {
+        return this.#kind === fastn_dom.ElementKind.Select ||
+            this.#kind === fastn_dom.ElementKind.RadioGroup;
+    }
+    // the `<option>`s of a select and the radio buttons of a radio group are rendered from the
+    // options and the selected value, they are rendered again when either changes
+    attachOptions(key, value) {
+        let data = this.#extraData.options_data || (this.#extraData.options_data = {});
+        data[key] = fastn_utils.getStaticValue(value);
+        let options = (data.options || []).map((option) => {
+            let label = fastn_utils.getStaticValue(option.get("label"));
+            let value = fastn_utils.getStaticValue(option.get("value"));
+            return [fastn_utils.escapeHtml(label), fastn_utils.escapeHtml(value ?? label)];
+        });
+        let selected = data.value === null || data.value === undefined
+            ? null
+            : fastn_utils.escapeHtml(data.value);
+        let html;
+        if (this.#kind === fastn_dom.ElementKind.Select) {
+            html = options.map(([label, value]) => {
+                let attr = value === selected ? " selected" : "";
+                return `<option value="${value}"${attr}>${label}</option>`;
+            }).join("");
+            if (data.placeholder !== null && data.placeholder !== undefined) {
+                let attr = selected === null ? " selected" : "";
+                let label = fastn_utils.escapeHtml(data.placeholder);
+                html = `<option value="" disabled${attr}>${label}</option>${html}`;
+            }
+        } else {
+            // the radio buttons of a group share a name, a group without one gets its own
+            if (!this.#extraData.radio_name) {
+                this.#extraData.radio_name = `ftd-radio-${++fastn_dom.radio_group_count}`;
+            }
+            let name = fastn_utils.escapeHtml(data.name ?? this.#extraData.radio_name);
+            let attrs = (data.enabled === false ? " disabled" : "") + (data.required ? " required" : "");
+            html = options.map(([label, value]) => {
+                let attr = (value === selected ? " checked" : "") + attrs;
+                return `<label style="display: block"><input type="radio" name="${name}" value="${value}"${attr}><span>${label}</span></label>`;
+            }).join("");
+        }
+        this.#node.innerHTML = html;
+    }
+    attachRive() {
+        if (ssr) {
+            return;
+        }
+        let node = this;
+        queueMicrotask(() => fastn_utils.createRive(node, node.#extraData));
+    }
     setStaticProperty(kind, value) {
         // value can be either static or mutable
         let staticValue = fastn_utils.getStaticValue(value);
         if (kind === fastn_dom.PropertyKind.Id) {
             this.#node.id = staticValue;
+            this.#extraData.id = staticValue;
         } else if (kind === fastn_dom.PropertyKind.Width) {
             this.attachCss("width", staticValue);
         } else if (kind === fastn_dom.PropertyKind.Height) {
             this.attachCss("height", staticValue);
         } else if (kind === fastn_dom.PropertyKind.Padding) {
             this.attachCss("padding", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.PaddingHorizontal) {
+            this.attachCss("padding-left", staticValue);
+            this.attachCss("padding-right", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.PaddingVertical) {
+            this.attachCss("padding-top", staticValue);
+            this.attachCss("padding-bottom", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.PaddingLeft) {
+            this.attachCss("padding-left", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.PaddingRight) {
+            this.attachCss("padding-right", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.PaddingTop) {
+            this.attachCss("padding-top", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.PaddingBottom) {
+            this.attachCss("padding-bottom", staticValue);
         } else if (kind === fastn_dom.PropertyKind.Margin) {
             this.attachCss("margin", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.MarginHorizontal) {
+            this.attachCss("margin-left", staticValue);
+            this.attachCss("margin-right", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.MarginVertical) {
+            this.attachCss("margin-top", staticValue);
+            this.attachCss("margin-bottom", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.MarginLeft) {
+            this.attachCss("margin-left", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.MarginRight) {
+            this.attachCss("margin-right", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.MarginTop) {
+            this.attachCss("margin-top", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.MarginBottom) {
+            this.attachCss("margin-bottom", staticValue);
         } else if (kind === fastn_dom.PropertyKind.BorderWidth) {
             this.attachCss("border-width", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.BorderTopWidth) {
+            this.attachCss("border-top-width", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.BorderBottomWidth) {
+            this.attachCss("border-bottom-width", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.BorderLeftWidth) {
+            this.attachCss("border-left-width", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.BorderRightWidth) {
+            this.attachCss("border-right-width", staticValue);
         } else if (kind === fastn_dom.PropertyKind.BorderStyle) {
             this.attachCss("border-style", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.BorderStyleVertical) {
+            this.attachCss("border-top-style", staticValue);
+            this.attachCss("border-bottom-style", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.BorderStyleHorizontal) {
+            this.attachCss("border-left-style", staticValue);
+            this.attachCss("border-right-style", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.BorderStyleLeft) {
+            this.attachCss("border-left-style", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.BorderStyleRight) {
+            this.attachCss("border-right-style", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.BorderStyleTop) {
+            this.attachCss("border-top-style", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.BorderStyleBottom) {
+            this.attachCss("border-bottom-style", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.BorderRadius) {
+            this.attachCss("border-radius", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.BorderTopLeftRadius) {
+            this.attachCss("border-top-left-radius", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.BorderTopRightRadius) {
+            this.attachCss("border-top-right-radius", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.BorderBottomLeftRadius) {
+            this.attachCss("border-bottom-left-radius", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.BorderBottomRightRadius) {
+            this.attachCss("border-bottom-right-radius", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.BorderColor) {
+            this.attachColorCss("border-color", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.BorderTopColor) {
+            this.attachColorCss("border-top-color", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.BorderBottomColor) {
+            this.attachColorCss("border-bottom-color", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.BorderLeftColor) {
+            this.attachColorCss("border-left-color", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.BorderRightColor) {
+            this.attachColorCss("border-right-color", staticValue);
         } else if (kind === fastn_dom.PropertyKind.Color) {
-            this.attachCss("color", staticValue);
+            if (staticValue instanceof RecordInstance) {
+                this.attachColorCss("color", staticValue);
+            } else {
+                this.attachCss("color", staticValue);
+            }
+        } else if (kind === fastn_dom.PropertyKind.MinWidth) {
+            this.attachCss("min-width", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.MaxWidth) {
+            this.attachCss("max-width", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.MinHeight) {
+            this.attachCss("min-height", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.MaxHeight) {
+            this.attachCss("max-height", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.ZIndex) {
+            this.attachCss("z-index", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Left) {
+            this.attachCss("left", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Right) {
+            this.attachCss("right", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Top) {
+            this.attachCss("top", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Bottom) {
+            this.attachCss("bottom", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Anchor) {
+            this.attachAnchor(staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Sticky) {
+            this.attachCss("position", staticValue ? "sticky" : undefined);
+        } else if (kind === fastn_dom.PropertyKind.Role) {
+            this.attachRoleCss(staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Region) {
+            if (staticValue) {
+                this.updateTagName(staticValue);
+            }
+        } else if (kind === fastn_dom.PropertyKind.Cursor) {
+            this.attachCss("cursor", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Classes) {
+            for (const cls of fastn_utils.getFlattenStaticValue(staticValue) || []) {
+                this.#node.classList.add(cls);
+            }
+        } else if (kind === fastn_dom.PropertyKind.Link) {
+            if (staticValue !== null && staticValue !== undefined) {
+                this.updateTagName("a");
+            }
+            this.attachAttribute("href", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.OpenInNewTab) {
+            this.attachAttribute("target", staticValue ? "_blank" : undefined);
+        } else if (kind === fastn_dom.PropertyKind.Background) {
+            this.attachBackground(staticValue);
+        } else if (kind === fastn_dom.PropertyKind.AlignSelf) {
+            this.attachCss("align-self", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Overflow) {
+            this.attachCss("overflow", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.OverflowX) {
+            this.attachCss("overflow-x", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.OverflowY) {
+            this.attachCss("overflow-y", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Opacity) {
+            this.attachCss("opacity", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Resize) {
+            this.attachCss("resize", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.WhiteSpace) {
+            this.attachCss("white-space", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.TextTransform) {
+            this.attachCss("text-transform", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Shadow) {
+            this.attachShadow(staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Transform) {
+            this.attachCss("transform", fastn_utils.getTransformCss(staticValue));
+        } else if (kind === fastn_dom.PropertyKind.Transition) {
+            this.attachTransition(staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Animation) {
+            this.attachAnimation(staticValue);
+        } else if (kind in fastn_dom.statePropertyKinds) {
+            let [property, state] = fastn_dom.statePropertyKinds[kind];
+            if (property === "background") {
+                this.attachBackground(staticValue, state);
+            } else if (property === "shadow") {
+                this.attachShadow(staticValue, state);
+            } else {
+                this.attachColorCss(property, staticValue, state);
+            }
+        } else if (kind === fastn_dom.PropertyKind.Display) {
+            this.attachCss("display", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.TextAlign) {
+            this.attachCss("text-align", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.LineClamp) {
+            let clamped = staticValue !== null && staticValue !== undefined;
+            this.attachCss("display", clamped ? "-webkit-box" : undefined);
+            this.attachCss("overflow", clamped ? "hidden" : undefined);
+            this.attachCss("-webkit-box-orient", clamped ? "vertical" : undefined);
+            this.attachCss("-webkit-line-clamp", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.TextIndent) {
+            this.attachCss("text-indent", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.TextStyle) {
+            this.attachTextStyle(staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Spacing) {
+            this.attachLength("gap", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Wrap) {
+            this.attachCss("flex-wrap", staticValue ? "wrap" : undefined);
+        } else if (kind === fastn_dom.PropertyKind.AlignContent) {
+            this.attachAlignContent(staticValue);
+        } else if (kind === fastn_dom.PropertyKind.ImageSrc) {
+            this.attachImageSrc(staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Alt) {
+            this.attachAccessibility("alt", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.AriaLabel) {
+            this.attachAccessibility("aria_label", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.OverlayOpen) {
+            this.attachOverlay("open", !!staticValue);
+        } else if (kind === fastn_dom.PropertyKind.OverlayCloseOnEscape) {
+            this.attachOverlay("close_on_escape", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.OverlayCloseOnOutsideClick) {
+            this.attachOverlay("close_on_outside_click", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.OverlayTrigger) {
+            this.attachOverlay("trigger", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.OverlayPlacement) {
+            this.attachOverlay("placement", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.AriaDescribedBy) {
+            this.attachAttribute("aria-describedby", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.AriaHidden) {
+            this.attachAttribute("aria-hidden", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.AriaLive) {
+            this.attachAttribute("aria-live", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Landmark) {
+            this.attachAccessibility("landmark", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.TabIndex) {
+            this.attachAttribute("tabindex", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.CodeLanguage) {
+            this.#extraData.code_language = staticValue;
+        } else if (kind === fastn_dom.PropertyKind.CodeTheme) {
+            if (staticValue) {
+                this.#node.classList.add(staticValue.replace(/[^a-zA-Z0-9-]/g, "-"));
+            }
+        } else if (kind === fastn_dom.PropertyKind.Code) {
+            this.attachCode(staticValue);
+        } else if (kind === fastn_dom.PropertyKind.IFrameSrc) {
+            this.attachAttribute("src", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.YoutubeSrc) {
+            this.attachAttribute("src", staticValue ? `https://youtube.com/embed/${staticValue}` : staticValue);
+        } else if (kind === fastn_dom.PropertyKind.IFrameSrcDoc) {
+            this.attachAttribute("srcdoc", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Loading) {
+            this.attachAttribute("loading", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Placeholder && this.#kind === fastn_dom.ElementKind.Select) {
+            this.attachOptions("placeholder", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Placeholder) {
+            this.attachAttribute("placeholder", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Value && this.hasOptions()) {
+            this.attachOptions("value", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Value) {
+            this.attachInputValue(staticValue);
+        } else if (kind === fastn_dom.PropertyKind.DefaultValue) {
+            if (!this.#extraData.default_value_set) {
+                this.#extraData.default_value_set = true;
+                this.attachInputValue(staticValue);
+            }
+        } else if (kind === fastn_dom.PropertyKind.Enabled && this.#kind === fastn_dom.ElementKind.RadioGroup) {
+            this.attachOptions("enabled", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Enabled) {
+            this.attachAttribute("disabled", staticValue === false ? "" : undefined);
+        } else if (kind === fastn_dom.PropertyKind.InputType) {
+            this.attachAttribute("type", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Checked) {
+            if (ssr) {
+                this.attachAttribute("checked", staticValue ? "" : undefined);
+            } else {
+                this.#node.checked = !!staticValue;
+            }
+        } else if (kind === fastn_dom.PropertyKind.Options) {
+            this.attachOptions("options", fastn_utils.getListItems(staticValue));
+        } else if (kind === fastn_dom.PropertyKind.FieldName && this.#kind === fastn_dom.ElementKind.RadioGroup) {
+            this.attachOptions("name", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.FieldName) {
+            this.attachAttribute("name", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Required && this.#kind === fastn_dom.ElementKind.RadioGroup) {
+            this.attachOptions("required", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Required) {
+            this.attachAttribute("required", staticValue ? "" : undefined);
+        } else if (kind === fastn_dom.PropertyKind.Min) {
+            this.attachAttribute("min", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Max) {
+            this.attachAttribute("max", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Step) {
+            this.attachAttribute("step", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Pattern) {
+            this.attachAttribute("pattern", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.FormAction) {
+            this.attachAttribute("action", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.FormMethod) {
+            this.attachAttribute("method", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.MediaSources) {
+            this.attachMedia("sources", fastn_utils.getListItems(staticValue));
+        } else if (kind === fastn_dom.PropertyKind.Captions) {
+            this.attachMedia("captions", fastn_utils.getListItems(staticValue));
+        } else if (kind === fastn_dom.PropertyKind.Poster) {
+            this.attachImageSrc(staticValue, "poster");
+        } else if (kind === fastn_dom.PropertyKind.Controls) {
+            this.attachAttribute("controls", staticValue === false ? undefined : "");
+        } else if (kind === fastn_dom.PropertyKind.Autoplay) {
+            this.attachMediaOption("autoplay", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Muted) {
+            this.attachMediaOption("muted", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Loop) {
+            this.attachMediaOption("loop", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.SvgSrc) {
+            this.attachSvg(staticValue);
+        } else if (kind === fastn_dom.PropertyKind.IconName) {
+            this.attachIcon("name", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.IconSprite) {
+            this.attachIcon("sprite", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Fill) {
+            this.attachColorCss("fill", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Stroke) {
+            this.attachColorCss("stroke", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.TableTitle) {
+            this.attachTable("title", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.TableColumns) {
+            this.attachTable("columns", fastn_utils.getListItems(staticValue));
+        } else if (kind === fastn_dom.PropertyKind.TableCells) {
+            this.attachTable("cells", fastn_utils.getListItems(staticValue));
+        } else if (kind === fastn_dom.PropertyKind.TableFilterable) {
+            this.attachTable("filterable", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.TablePageSize) {
+            this.attachTable("page_size", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.TablePageParam) {
+            this.attachTable("page_param", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.TablePage) {
+            this.attachTable("page", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.TableTotal) {
+            this.attachTable("total", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.TableData) {
+            this.attachTable("data", fastn_utils.getListItems(staticValue));
+        } else if (kind === fastn_dom.PropertyKind.RiveCanvasWidth) {
+            this.attachAttribute("width", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.RiveCanvasHeight) {
+            this.attachAttribute("height", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.RiveStateMachine) {
+            this.#extraData.state_machines = fastn_utils.getFlattenStaticValue(staticValue);
+        } else if (kind === fastn_dom.PropertyKind.RiveAutoplay) {
+            this.#extraData.autoplay = staticValue;
+        } else if (kind === fastn_dom.PropertyKind.RiveArtboard) {
+            this.#extraData.artboard = staticValue;
+        } else if (kind === fastn_dom.PropertyKind.RiveSrc) {
+            this.#extraData.src = staticValue;
+            this.attachRive();
+        } else if (kind === fastn_dom.PropertyKind.MetaTitle) {
+            fastn_utils.setTitle(staticValue);
+        } else if (kind === fastn_dom.PropertyKind.MetaOgTitle) {
+            fastn_utils.setMeta("property", "og:title", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.MetaTwitterTitle) {
+            fastn_utils.setMeta("name", "twitter:title", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.MetaDescription) {
+            fastn_utils.setMeta("name", "description", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.MetaOgDescription) {
+            fastn_utils.setMeta("property", "og:description", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.MetaTwitterDescription) {
+            fastn_utils.setMeta("name", "twitter:description", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.MetaOgImage) {
+            fastn_utils.setMeta("property", "og:image", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.MetaTwitterImage) {
+            fastn_utils.setMeta("name", "twitter:image", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.MetaThemeColor) {
+            fastn_utils.setMeta("name", "theme-color", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Breakpoints) {
+            ftd.set_breakpoints(staticValue);
+        } else if (kind === fastn_dom.PropertyKind.GridTemplateColumns) {
+            this.attachCss("grid-template-columns", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.GridTemplateRows) {
+            this.attachCss("grid-template-rows", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.GridTemplateAreas) {
+            let areas = fastn_utils.getFlattenStaticValue(staticValue) || [];
+            this.attachCss(
+                "grid-template-areas",
+                areas.length ? areas.map(row => `"${row}"`).join(" ") : undefined,
+            );
+        } else if (kind === fastn_dom.PropertyKind.Gap) {
+            this.attachCss("gap", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.RowGap) {
+            this.attachCss("row-gap", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.ColumnGap) {
+            this.attachCss("column-gap", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.GridArea) {
+            this.attachCss("grid-area", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.GridColumnStart) {
+            this.attachCss("grid-column-start", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.GridColumnSpan) {
+            let span = staticValue === null || staticValue === undefined ? undefined : `span ${staticValue}`;
+            this.attachCss("grid-column-end", span);
+        } else if (kind === fastn_dom.PropertyKind.GridRowStart) {
+            this.attachCss("grid-row-start", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.GridRowSpan) {
+            let span = staticValue === null || staticValue === undefined ? undefined : `span ${staticValue}`;
+            this.attachCss("grid-row-end", span);
         } else if (kind === fastn_dom.PropertyKind.IntegerValue ||
+            kind === fastn_dom.PropertyKind.DecimalValue ||
+            kind === fastn_dom.PropertyKind.BooleanValue ||
             kind === fastn_dom.PropertyKind.StringValue
         ) {
             this.#node.innerHTML = staticValue;
@@ -220,7 +1759,19 @@ class Node2 {
         }
     }
     setProperty(kind, value) {
-        if (value instanceof fastn.mutableClass) {
+        if (kind === fastn_dom.PropertyKind.ImageSrc || kind === fastn_dom.PropertyKind.Poster
+            || kind === fastn_dom.PropertyKind.SvgSrc || kind === fastn_dom.PropertyKind.IconSprite) {
+            // the image changes with the colour scheme
+            this.setDynamicProperty(kind, [ftd.dark_mode, value], () => { return value; });
+        } else if (kind === fastn_dom.PropertyKind.Role) {
+            // the type of the text changes with `ftd.device`
+            this.setDynamicProperty(kind, [ftd.device, value], () => { return value; });
+        } else if (value instanceof fastn.mutableClass) {
+            if (kind === fastn_dom.PropertyKind.OverlayOpen) {
+                // the overlay sets the variable to false when it is dismissed
+                let overlay = this.#extraData.overlay || (this.#extraData.overlay = {});
+                overlay.mutable = value;
+            }
             this.setDynamicProperty(kind, [value], () => { return value.get(); });
         } else {
             this.setStaticProperty(kind, value);
@@ -229,7 +1780,8 @@ class Node2 {
     setDynamicProperty(kind, deps, func) {
         let closure = fastn.closure(func).addNodeProperty(this, kind);
         for (let dep in deps) {
-            if (!deps[dep].addClosure) {
+            // an optional argument that is not set is `null`
+            if (!deps[dep] || !deps[dep].addClosure) {
                 continue;
             }
             deps[dep].addClosure(closure);
@@ -239,9 +1791,67 @@ class Node2 {
     getNode() {
         return this.#node;
     }
+    getExtraData() {
+        return this.#extraData;
+    }
     addEventHandler(event, func) {
         if (event === fastn_dom.Event.Click) {
             this.#node.onclick = func;
+            return;
+        }
+        if (ssr) {
+            return;
+        }
+        let [kind, data] = Array.isArray(event) ? event : [event, null];
+        if (kind === fastn_dom.Event.MouseEnter) {
+            this.#node.addEventListener("mouseenter", func);
+        } else if (kind === fastn_dom.Event.MouseLeave) {
+            this.#node.addEventListener("mouseleave", func);
+        } else if (kind === fastn_dom.Event.ClickOutside) {
+            window.document.addEventListener("click", (e) => {
+                if (this.#node && !this.#node.contains(e.target)) {
+                    func(e);
+                }
+            });
+        } else if (kind === 4) {
+            fastn_utils.onGlobalKey(data, func);
+        } else if (kind === 5) {
+            fastn_utils.onGlobalKeySeq(data, func);
+        } else if (kind === fastn_dom.Event.Input) {
+            this.#node.addEventListener("input", (e) => {
+                VALUE = e.target.value;
+                func();
+            });
+        } else if (kind === fastn_dom.Event.Change) {
+            this.#node.addEventListener("change", (e) => {
+                VALUE = e.target.value;
+                CHECKED = e.target.checked;
+                func();
+            });
+        } else if (kind === fastn_dom.Event.Submit) {
+            // the form is handled by the function instead of being sent to its `action`
+            this.#node.addEventListener("submit", (e) => {
+                e.preventDefault();
+                FORM = fastn_utils.getFormValues(e.target);
+                func();
+            });
+        } else if (kind === fastn_dom.Event.Play ||
+            kind === fastn_dom.Event.Pause ||
+            kind === fastn_dom.Event.Ended ||
+            kind === fastn_dom.Event.TimeUpdate
+        ) {
+            let name = ["play", "pause", "ended", "timeupdate"][kind - fastn_dom.Event.Play];
+            this.#node.addEventListener(name, (e) => {
+                TIME = e.target.currentTime;
+                func();
+            });
+        } else if (kind === fastn_dom.Event.Blur) {
+            this.#node.addEventListener("blur", func);
+        } else if (kind === fastn_dom.Event.Focus) {
+            this.#node.addEventListener("focus", func);
+        } else if (kind === 10 || kind === 11 || kind === 12) {
+            let events = this.#extraData.rive_events || (this.#extraData.rive_events = []);
+            events.push([kind, data, func]);
         }
     }
     destroy() {
@@ -277,7 +1887,11 @@ class ConditionalDom {
                 this.#conditionUI = null;
             }
         })
-        deps.forEach(dep => dep.addClosure(closure));
+        deps.forEach(dep => {
+            if (dep && dep.addClosure) {
+                dep.addClosure(closure);
+            }
+        });
 
 
         this.#parent = domNode;
@@ -315,7 +1929,7 @@ class ForLoop {
     }
     createNode(index) {
         let v = this.#list.get(index);
-        this.#node_constructor(this.#wrapper, v.item, v.index);
+        this.#node_constructor(this.#wrapper, v.item, v.index, v.key);
     }
 
     getParent() {
@@ -65,7 +65,8 @@ class Mutable {
             this.#value.removeClosure(this.#old_closure);
         }
 
-        if (this.#value instanceof RecordInstance) {
+        // the value of an or-type changes its fields along with its variant
+        if (this.#value instanceof RecordInstance && this.#value.hasSameKeys(value)) {
             this.#value.replace(value);
         } else {
             this.#value = value;
@@ -83,6 +84,10 @@ class Mutable {
 
         this.#closureInstance.update();
     }
+    // a list in a record field is a mutable of the list
+    forLoop(root, dom_constructor, integerKeys) {
+        return this.#value.forLoop(root, dom_constructor, integerKeys);
+    }
     // we have to unlink all nodes, else they will be kept in memory after the node is removed from DOM
     unlinkNode(node) {
         this.#closures = this.#closures.filter(closure => closure.getNode() !== node);
@@ -208,7 +213,7 @@ fastn.formula = function (deps, func) {
     let closure = fastn.closure(func);
     let mutable = new Mutable(closure.get());
     for (let idx in deps) {
-        if (!deps[idx].addClosure) {
+        if (!deps[idx] || !deps[idx].addClosure) {
             continue;
         }
         deps[idx].addClosure(new Closure(function () {
@@ -257,9 +262,31 @@ class RecordInstance {
     get(key) {
         return this.#fields[key];
     }
+    getAllFields() {
+        return this.#fields;
+    }
     set(key, value) {
         this.#fields[key].set(value);
     }
+    // a map is a record instance with its keys as fields, looping over it goes through the
+    // values, with `key` set to their key (a number for a map with integer keys)
+    forLoop(root, dom_constructor, integerKeys) {
+        let keys = Object.keys(this.#fields);
+        if (integerKeys) {
+            keys.sort((a, b) => Number(a) - Number(b));
+        }
+        let list = fastn.mutableList(keys.map((key) => this.#fields[key]));
+        keys.forEach((key, idx) => list.get(idx).key = fastn.mutable(integerKeys ? Number(key) : key));
+        return list.forLoop(root, dom_constructor);
+    }
+    hasSameKeys(obj) {
+        if (!(obj instanceof RecordInstance)) {
+            return false;
+        }
+        let keys = Object.keys(this.#fields);
+        let other = Object.keys(obj.#fields);
+        return keys.length === other.length && keys.every((key) => key in obj.#fields);
+    }
     replace(obj) {
         for (let key in this.#fields) {
             if (!(key in obj.#fields)) {
@@ -273,6 +300,30 @@ class RecordInstance {
 fastn.recordInstance = function (obj) {
     return new RecordInstance(obj);
 }
+fastn.recordInstanceClass = RecordInstance;
+
+// the values components read as `$inherited.<name>`, a component sees the values of its
+// ancestors, extended with its own arguments
+class Inherited {
+    #fields;
+    #defaults;
+    constructor(fields, defaults) {
+        this.#fields = fields;
+        this.#defaults = defaults;
+    }
+    get(key) {
+        return key in this.#fields ? this.#fields[key] : this.#defaults[key];
+    }
+    // the `values` override the values of the ancestors, which override the `defaults`
+    extend(values, defaults) {
+        return new Inherited(Object.assign({}, defaults, this.#fields, values), this.#defaults);
+    }
+}
+
+// `defaults` are read when no ancestor sets a value, `ftd.default-colors` for `colors`
+fastn.inherited = function (defaults) {
+    return new Inherited({}, defaults);
+}
 
 
 
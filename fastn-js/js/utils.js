@@ -2,6 +2,7 @@ let fastn_utils = {
     htmlNode(kind) {
         let node = "div";
         let css = [];
+        let attributes = {};
         if (kind === fastn_dom.ElementKind.Column) {
             css.push("ft_column");
         } else if (kind === fastn_dom.ElementKind.Row) {
@@ -15,8 +16,64 @@ let fastn_utils = {
             css = [];
         } else if (kind === fastn_dom.ElementKind.Text) {
             // css.push("ft_text");
+        } else if (kind === fastn_dom.ElementKind.Code) {
+            node = "pre";
+        } else if (kind === fastn_dom.ElementKind.TextInput) {
+            node = "input";
+        } else if (kind === fastn_dom.ElementKind.TextArea) {
+            node = "textarea";
+        } else if (kind === fastn_dom.ElementKind.CheckBox) {
+            node = "input";
+            attributes["type"] = "checkbox";
+        } else if (kind === fastn_dom.ElementKind.Select) {
+            node = "select";
+        } else if (kind === fastn_dom.ElementKind.RadioGroup) {
+            attributes["role"] = "radiogroup";
+        } else if (kind === fastn_dom.ElementKind.Form) {
+            node = "form";
+            css.push("ft_column");
+        } else if (kind === fastn_dom.ElementKind.Video || kind === fastn_dom.ElementKind.Audio) {
+            node = kind === fastn_dom.ElementKind.Video ? "video" : "audio";
+            // the controls are shown unless they are turned off
+            attributes["controls"] = "";
+        } else if (kind === fastn_dom.ElementKind.Svg || kind === fastn_dom.ElementKind.Icon) {
+            css.push("ft_svg");
+        } else if (kind === fastn_dom.ElementKind.Table) {
+            css.push("ft_table");
+        } else if (kind === fastn_dom.ElementKind.Dialog) {
+            node = "dialog";
+            css.push("ft_column", "ft_dialog");
+            attributes["aria-modal"] = "true";
+        } else if (kind === fastn_dom.ElementKind.Popover || kind === fastn_dom.ElementKind.Tooltip) {
+            css.push("ft_column", kind === fastn_dom.ElementKind.Popover ? "ft_popover" : "ft_tooltip");
+            attributes["popover"] = "manual";
+            if (kind === fastn_dom.ElementKind.Tooltip) {
+                attributes["role"] = "tooltip";
+            }
+        } else if (kind === fastn_dom.ElementKind.Rive) {
+            node = "canvas";
+        } else if (Array.isArray(kind)) {
+            // web component
+            node = kind[1].name;
+        }
+        return [node, css, attributes];
+    },
+    // the values of the named controls of a form, as the record passed for `$FORM`
+    getFormValues(form) {
+        let values = {};
+        for (let element of form.elements) {
+            if (!element.name || (element.type === "radio" && !element.checked)) {
+                continue;
+            }
+            if (element.type === "checkbox") {
+                values[element.name] = element.checked;
+            } else if (element.type === "number" || element.type === "range") {
+                values[element.name] = element.value === "" ? null : Number(element.value);
+            } else {
+                values[element.name] = element.value === "" ? null : element.value;
+            }
         }
-        return [node, css];
+        return fastn.recordInstance(values);
     },
     getStaticValue(obj) {
         if (obj instanceof fastn.mutableClass) {
@@ -25,6 +82,14 @@ let fastn_utils = {
            return obj;
         }
     },
+    // the function of a `ftd.ui` value or of a component of a `module` value, an item of a
+    // `ftd.ui list` is read as `{item, index}`
+    getComponent(value) {
+        if (value !== null && value !== undefined && value.item instanceof fastn.mutableClass) {
+            value = value.item;
+        }
+        return this.getStaticValue(value);
+    },
     deepEqual(obj1, obj2) {
         // Check for strict equality
         if (obj1 === obj2) {
@@ -109,7 +174,9 @@ let fastn_utils = {
     },
 
     getter(value) {
-        if (value.get) {
+        // an optional value that is not set is `null`, a record is read field by field
+        if (value !== null && value !== undefined && value.get
+            && !(value instanceof fastn.recordInstanceClass)) {
             return value.get();
         } else {
             return value;
@@ -126,5 +193,598 @@ let fastn_utils = {
 
     defaultPropertyValue(_propertyValue) {
         return null;
-    }
+    },
+
+    // Static value of a list, with the static value of each item
+    getFlattenStaticValue(obj) {
+        let value = this.getStaticValue(obj);
+        if (value instanceof MutableList) {
+            return value.getList().map(v => this.getFlattenStaticValue(v.item));
+        }
+        return value;
+    },
+
+    // the items of a list, a `MutableList` or a plain array, as they are stored in it
+    getListItems(list) {
+        let value = this.getStaticValue(list);
+        if (value === null || value === undefined) {
+            return [];
+        }
+        return Array.isArray(value) ? value : value.getList().map(v => v.item);
+    },
+
+    // `len` of an expression, the length of a string or of a list
+    len(value) {
+        let v = this.getStaticValue(value);
+        if (typeof v === "string") {
+            return v.length;
+        }
+        return this.getListItems(v).length;
+    },
+
+    // the value of a responsive record (`ftd.responsive-length`, `ftd.responsive-type` etc) for
+    // `ftd.device`, a device without a value of its own uses the desktop one
+    getDeviceValue(record) {
+        let value = this.getStaticValue(this.getStaticValue(record).get(ftd.device.get()));
+        if (value === null || value === undefined) {
+            return this.getStaticValue(this.getStaticValue(record).get("desktop"));
+        }
+        return value;
+    },
+
+    // a value as plain data, records are objects with their fields sorted by name, as serde_json
+    // writes them, so that `ftd.to_json` gives the same json in the browser and on the server
+    toJsonValue(obj) {
+        let value = this.getStaticValue(obj);
+        if (value instanceof MutableList) {
+            return value.getList().map(v => this.toJsonValue(v.item));
+        }
+        if (value instanceof RecordInstance) {
+            let fields = value.getAllFields();
+            let result = {};
+            for (const key of Object.keys(fields).sort()) {
+                result[key] = this.toJsonValue(fields[key]);
+            }
+            return result;
+        }
+        return value === undefined ? null : value;
+    },
+
+    fromJsonValue(value) {
+        if (Array.isArray(value)) {
+            return fastn.mutableList(value.map(v => this.fromJsonValue(v)));
+        }
+        if (value !== null && typeof value === "object") {
+            let fields = {};
+            for (const key in value) {
+                fields[key] = this.fromJsonValue(value[key]);
+            }
+            return fastn.recordInstance(fields);
+        }
+        return value;
+    },
+
+    // the ordering of `collections::compare` in `fastn-grammar`, used by `ftd.sort_by`
+    compare(a, b) {
+        if (typeof a !== typeof b || !["number", "string", "boolean"].includes(typeof a)) {
+            return 0;
+        }
+        return a < b ? -1 : a > b ? 1 : 0;
+    },
+
+    escapeHtml(s) {
+        if (s === null || s === undefined) {
+            return "";
+        }
+        return `${s}`.replace(/&/g, "&amp;")
+            .replace(/</g, "&lt;")
+            .replace(/>/g, "&gt;")
+            .replace(/"/g, "&quot;");
+    },
+
+    // classes created in the browser after the page is rendered
+    // `ftd.transform` as the value of the css `transform` property
+    getTransformCss(value) {
+        let transform = this.getStaticValue(value);
+        if (transform === undefined || transform === null) {
+            return undefined;
+        }
+        let get = (key) => this.getStaticValue(transform.get(key));
+        let isSet = (key) => get(key) !== null && get(key) !== undefined;
+        let functions = [];
+        if (isSet("translate-x") || isSet("translate-y")) {
+            functions.push(`translate(${get("translate-x") ?? "0px"}, ${get("translate-y") ?? "0px"})`);
+        }
+        if (isSet("rotate")) {
+            functions.push(`rotate(${get("rotate")}deg)`);
+        }
+        if (isSet("scale")) {
+            functions.push(`scale(${get("scale")})`);
+        }
+        if (isSet("skew-x") || isSet("skew-y")) {
+            functions.push(`skew(${get("skew-x") ?? 0}deg, ${get("skew-y") ?? 0}deg)`);
+        }
+        return functions.length ? functions.join(" ") : "none";
+    },
+
+    addCssToDocument(css) {
+        let styles = window.document.getElementById("styles");
+        if (!styles) {
+            styles = window.document.createElement("style");
+            styles.id = "styles";
+            window.document.head.appendChild(styles);
+        }
+        styles.innerHTML += `${css}\n`;
+    },
+
+    // `ftd.document` meta data is only applied in the browser, the server rendered page gets it
+    // from the page template
+    setTitle(title) {
+        if (ssr || title === null || title === undefined) {
+            return;
+        }
+        window.document.title = title;
+    },
+
+    setMeta(attribute, key, value) {
+        if (ssr || value === null || value === undefined) {
+            return;
+        }
+        let meta = window.document.querySelector(`meta[${attribute}="${key}"]`);
+        if (!meta) {
+            meta = window.document.createElement("meta");
+            meta.setAttribute(attribute, key);
+            window.document.head.appendChild(meta);
+        }
+        meta.setAttribute("content", value);
+    },
+
+    // Dates and date times are ISO-8601 strings, as in `ftd`. The date time is parsed into its
+    // instant, in milliseconds, and the offset it was written in, in minutes.
+    parseDateTime(s) {
+        let m = `${s}`.trim().match(
+            /^(\d{4})-(\d{2})-(\d{2})(?:[T ](\d{2}):(\d{2})(?::(\d{2})(\.\d+)?)?)?(Z|[+-]\d{2}:\d{2})?$/i
+        );
+        if (!m) {
+            throw new Error(`\`${s}\` is not an ISO-8601 date time`);
+        }
+        let offset = 0;
+        if (m[8] && m[8].toUpperCase() !== "Z") {
+            let sign = m[8][0] === "-" ? -1 : 1;
+            offset = sign * (parseInt(m[8].slice(1, 3)) * 60 + parseInt(m[8].slice(4, 6)));
+        }
+        let millis = m[7] ? Math.floor(parseFloat(m[7]) * 1000) : 0;
+        let time = Date.UTC(m[1], m[2] - 1, m[3], m[4] || 0, m[5] || 0, m[6] || 0, millis);
+        return {time: time - offset * 60000, offset};
+    },
+
+    // the canonical form, the same as `datetime_to_string` in `fastn-grammar`
+    dateTimeToString(datetime) {
+        let date = new Date(datetime.time + datetime.offset * 60000);
+        let pad = (n, width = 2) => `${n}`.padStart(width, "0");
+        let millis = date.getUTCMilliseconds();
+        let offset = "Z";
+        if (datetime.offset !== 0) {
+            let minutes = Math.abs(datetime.offset);
+            offset = `${datetime.offset < 0 ? "-" : "+"}${pad(Math.floor(minutes / 60))}:${pad(minutes % 60)}`;
+        }
+        return `${pad(date.getUTCFullYear(), 4)}-${pad(date.getUTCMonth() + 1)}-${pad(date.getUTCDate())}`
+            + `T${pad(date.getUTCHours())}:${pad(date.getUTCMinutes())}:${pad(date.getUTCSeconds())}`
+            + `${millis ? "." + pad(millis, 3) : ""}${offset}`;
+    },
+
+    // the same specifiers and locales as `format` in `fastn-grammar`, so that the server
+    // rendered and the hydrated text match
+    formatDateTime(datetime, format, locale) {
+        let names = fastn_utils.dateLocaleNames(locale);
+        let date = new Date(datetime.time + datetime.offset * 60000);
+        let pad = (n, width = 2) => `${n}`.padStart(width, "0");
+        let hours = date.getUTCHours();
+        let month = date.getUTCMonth();
+        let weekday = (date.getUTCDay() + 6) % 7;
+        let ordinal = Math.floor(
+            (Date.UTC(date.getUTCFullYear(), month, date.getUTCDate())
+                - Date.UTC(date.getUTCFullYear(), 0, 1)) / 86400000
+        ) + 1;
+        return format.replace(/%(-?)(.|$)/g, (match, unpadded, specifier) => {
+            let number = (n) => unpadded ? `${n}` : pad(n);
+            switch (specifier) {
+                case "Y": return `${date.getUTCFullYear()}`;
+                case "y": return pad(((date.getUTCFullYear() % 100) + 100) % 100);
+                case "m": return number(month + 1);
+                case "d": return number(date.getUTCDate());
+                case "B": return names.months[month];
+                case "b": return names.shortMonths[month];
+                case "A": return names.weekdays[weekday];
+                case "a": return names.shortWeekdays[weekday];
+                case "H": return number(hours);
+                case "I": return number(hours % 12 === 0 ? 12 : hours % 12);
+                case "M": return pad(date.getUTCMinutes());
+                case "S": return pad(date.getUTCSeconds());
+                case "p": return hours < 12 ? "AM" : "PM";
+                case "j": return pad(ordinal, 3);
+                case "%": return "%";
+                default: return match;
+            }
+        });
+    },
+
+    relativeTime(datetime, now) {
+        let seconds = Math.trunc((datetime.time - now.time) / 1000);
+        let elapsed = Math.abs(seconds);
+        let units = [
+            [365 * 24 * 60 * 60, "year"],
+            [30 * 24 * 60 * 60, "month"],
+            [24 * 60 * 60, "day"],
+            [60 * 60, "hour"],
+            [60, "minute"],
+        ];
+        let unit = units.find(u => elapsed >= u[0]);
+        if (!unit) {
+            return "just now";
+        }
+        let count = Math.floor(elapsed / unit[0]);
+        let name = `${unit[1]}${count === 1 ? "" : "s"}`;
+        return seconds < 0 ? `${count} ${name} ago` : `in ${count} ${name}`;
+    },
+
+    dateLocaleNames(locale) {
+        switch (`${locale || "en"}`.split(/[-_]/)[0].toLowerCase()) {
+            case "fr": return {
+                months: ["janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août",
+                    "septembre", "octobre", "novembre", "décembre"],
+                shortMonths: ["janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août",
+                    "sept.", "oct.", "nov.", "déc."],
+                weekdays: ["lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche"],
+                shortWeekdays: ["lun.", "mar.", "mer.", "jeu.", "ven.", "sam.", "dim."],
+            };
+            case "de": return {
+                months: ["Januar", "Februar", "März", "April", "Mai", "Juni", "Juli", "August",
+                    "September", "Oktober", "November", "Dezember"],
+                shortMonths: ["Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.",
+                    "Sept.", "Okt.", "Nov.", "Dez."],
+                weekdays: ["Montag", "Dienstag", "Mittwoch", "Donnerstag", "Freitag", "Samstag",
+                    "Sonntag"],
+                shortWeekdays: ["Mo.", "Di.", "Mi.", "Do.", "Fr.", "Sa.", "So."],
+            };
+            case "es": return {
+                months: ["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto",
+                    "septiembre", "octubre", "noviembre", "diciembre"],
+                shortMonths: ["ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept",
+                    "oct", "nov", "dic"],
+                weekdays: ["lunes", "martes", "miércoles", "jueves", "viernes", "sábado",
+                    "domingo"],
+                shortWeekdays: ["lun", "mar", "mié", "jue", "vie", "sáb", "dom"],
+            };
+            case "hi": return {
+                months: ["जनवरी", "फ़रवरी", "मार्च", "अप्रैल", "मई", "जून", "जुलाई", "अगस्त",
+                    "सितंबर", "अक्तूबर", "नवंबर", "दिसंबर"],
+                shortMonths: ["जन॰", "फ़र॰", "मार्च", "अप्रैल", "मई", "जून", "जुल॰", "अग॰",
+                    "सित॰", "अक्तू॰", "नव॰", "दिस॰"],
+                weekdays: ["सोमवार", "मंगलवार", "बुधवार", "गुरुवार", "शुक्रवार", "शनिवार",
+                    "रविवार"],
+                shortWeekdays: ["सोम", "मंगल", "बुध", "गुरु", "शुक्र", "शनि", "रवि"],
+            };
+            default: return {
+                months: ["January", "February", "March", "April", "May", "June", "July",
+                    "August", "September", "October", "November", "December"],
+                shortMonths: ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep",
+                    "Oct", "Nov", "Dec"],
+                weekdays: ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday",
+                    "Sunday"],
+                shortWeekdays: ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+            };
+        }
+    },
+
+    // `func` is called when all the `keys` are pressed together
+    onGlobalKey(keys, func) {
+        let pressed = new Set();
+        window.addEventListener("keydown", (e) => {
+            pressed.add(e.key);
+            if (keys.every(key => pressed.has(key))) {
+                func();
+            }
+        });
+        window.addEventListener("keyup", (e) => pressed.delete(e.key));
+    },
+
+    // `func` is called when the `keys` are pressed one after another
+    onGlobalKeySeq(keys, func) {
+        let sequence = [];
+        window.addEventListener("keydown", (e) => {
+            sequence.push(e.key);
+            if (sequence.length > keys.length) {
+                sequence.shift();
+            }
+            if (keys.every((key, idx) => key === sequence[idx])) {
+                sequence = [];
+                func();
+            }
+        });
+    },
+
+    // the rows of a table are sorted by the text of their cells, in their `data-sort`, and
+    // filtered by it; a table with a `page_size` is paginated in the page
+    initialiseTable(table, page_size) {
+        let tbody = table.querySelector("tbody");
+        if (!tbody) {
+            return;
+        }
+        let rows = Array.from(tbody.rows);
+        let text = (row, index) => (row.cells[index] && row.cells[index].getAttribute("data-sort")) || "";
+        let compare = (type, a, b) => {
+            if (type === fastn_dom.ColumnType.Number) {
+                return (parseFloat(a) || 0) - (parseFloat(b) || 0);
+            }
+            if (type === fastn_dom.ColumnType.Date) {
+                return (Date.parse(a) || 0) - (Date.parse(b) || 0);
+            }
+            return a.localeCompare(b);
+        };
+        let filter = table.querySelector(".ft_table_filter");
+        let page = 0;
+        let nav = null;
+        if (page_size > 0) {
+            nav = document.createElement("nav");
+            nav.className = "ft_table_pages";
+            nav.setAttribute("aria-label", "Pages");
+            table.appendChild(nav);
+        }
+        let update = () => {
+            let query = filter ? filter.value.trim().toLowerCase() : "";
+            let matching = rows.filter((row) => !query || Array.from(row.cells).some(
+                (_, index) => text(row, index).toLowerCase().includes(query)
+            ));
+            let pages = page_size > 0 ? Math.max(1, Math.ceil(matching.length / page_size)) : 1;
+            page = Math.min(page, pages - 1);
+            rows.forEach((row) => row.hidden = true);
+            matching.forEach((row, index) => {
+                row.hidden = page_size > 0 && Math.floor(index / page_size) !== page;
+            });
+            if (!nav) {
+                return;
+            }
+            let button = (label, to, enabled) => {
+                let button = document.createElement("button");
+                button.type = "button";
+                button.textContent = label;
+                button.disabled = !enabled;
+                button.onclick = () => {
+                    page = to;
+                    update();
+                };
+                return button;
+            };
+            let current = document.createElement("span");
+            current.textContent = `Page ${page + 1} of ${pages}`;
+            nav.innerHTML = "";
+            nav.append(button("Previous", page - 1, page > 0), current, button("Next", page + 1, page < pages - 1));
+        };
+        table.querySelectorAll("th[aria-sort]").forEach((th) => {
+            let button = th.querySelector("button");
+            if (!button) {
+                return;
+            }
+            button.onclick = () => {
+                let ascending = th.getAttribute("aria-sort") !== "ascending";
+                table.querySelectorAll("th[aria-sort]").forEach((other) => other.setAttribute("aria-sort", "none"));
+                th.setAttribute("aria-sort", ascending ? "ascending" : "descending");
+                let type = th.getAttribute("data-type");
+                rows.sort((a, b) => {
+                    let order = compare(type, text(a, th.cellIndex), text(b, th.cellIndex));
+                    return ascending ? order : -order;
+                });
+                rows.forEach((row) => tbody.appendChild(row));
+                page = 0;
+                update();
+            };
+        });
+        if (filter) {
+            filter.oninput = () => {
+                page = 0;
+                update();
+            };
+        }
+        update();
+    },
+    // the element with the id in `trigger`, or one inside it, is the trigger of the overlay
+    isOverlayTrigger(overlay, target) {
+        return !!overlay.trigger && !!target && !!target.closest
+            && !!target.closest(`#${CSS.escape(overlay.trigger)}`);
+    },
+    // Escape and the trigger of a popover or a tooltip open and close it, Tab does not leave an
+    // open dialog
+    initialiseOverlay(node, kind, overlay) {
+        window.document.addEventListener("keydown", (e) => {
+            if (!overlay.open) {
+                return;
+            }
+            if (e.key === "Escape" && overlay.close_on_escape !== false) {
+                e.preventDefault();
+                overlay.close();
+            } else if (e.key === "Tab" && kind === fastn_dom.ElementKind.Dialog) {
+                fastn_utils.trapFocus(node, e);
+            }
+        });
+        if (kind === fastn_dom.ElementKind.Dialog) {
+            // the browser closes a modal dialog on Escape by itself, `open` decides instead
+            node.addEventListener("cancel", (e) => e.preventDefault());
+            // a click on the backdrop of a modal dialog has the dialog as its target
+            node.addEventListener("click", (e) => {
+                let rect = node.getBoundingClientRect();
+                let inside = rect.top <= e.clientY && e.clientY <= rect.bottom
+                    && rect.left <= e.clientX && e.clientX <= rect.right;
+                if (e.target === node && !inside && overlay.close_on_outside_click !== false) {
+                    overlay.close();
+                }
+            });
+        } else if (kind === fastn_dom.ElementKind.Popover) {
+            window.document.addEventListener("click", (e) => {
+                if (fastn_utils.isOverlayTrigger(overlay, e.target)) {
+                    overlay.toggle(!overlay.open);
+                }
+            });
+        } else {
+            let hover = (open) => (e) => {
+                if (fastn_utils.isOverlayTrigger(overlay, e.target)
+                    && !fastn_utils.isOverlayTrigger(overlay, e.relatedTarget)) {
+                    overlay.toggle(open);
+                }
+            };
+            window.document.addEventListener("mouseover", hover(true));
+            window.document.addEventListener("mouseout", hover(false));
+            window.document.addEventListener("focusin", hover(true));
+            window.document.addEventListener("focusout", hover(false));
+        }
+        let position = () => {
+            if (overlay.open && kind !== fastn_dom.ElementKind.Dialog) {
+                fastn_utils.positionOverlay(node, overlay);
+            }
+        };
+        window.addEventListener("resize", position);
+        window.addEventListener("scroll", position, true);
+    },
+    // shows or hides the overlay as `open` says, focus moves into an opened dialog or popover
+    // and back to where it was when it is closed
+    updateOverlay(node, kind, overlay) {
+        if (!node.isConnected) {
+            // the element is not in the page yet
+            setTimeout(() => fastn_utils.updateOverlay(node, kind, overlay), 0);
+            return;
+        }
+        let trigger = !!overlay.trigger ? document.getElementById(overlay.trigger) : null;
+        if (kind === fastn_dom.ElementKind.Popover && !!trigger) {
+            trigger.setAttribute("aria-expanded", overlay.open ? "true" : "false");
+            if (!!node.id) {
+                trigger.setAttribute("aria-controls", node.id);
+            }
+        } else if (kind === fastn_dom.ElementKind.Tooltip && !!trigger && !!node.id) {
+            trigger.setAttribute("aria-describedby", node.id);
+        }
+        let shown = kind === fastn_dom.ElementKind.Dialog
+            ? node.matches(":modal")
+            : node.matches(":popover-open");
+        if (overlay.open) {
+            if (!shown) {
+                overlay.opened_at = performance.now();
+                if (kind !== fastn_dom.ElementKind.Tooltip) {
+                    overlay.restore_focus = document.activeElement;
+                }
+                if (kind === fastn_dom.ElementKind.Dialog) {
+                    // a dialog open in the rendered page is not modal yet
+                    if (node.open) {
+                        node.close();
+                    }
+                    node.showModal();
+                } else {
+                    node.showPopover();
+                }
+                if (kind === fastn_dom.ElementKind.Popover) {
+                    let first = fastn_utils.focusableElements(node)[0];
+                    if (!!first) {
+                        first.focus();
+                    }
+                }
+            }
+            if (kind !== fastn_dom.ElementKind.Dialog) {
+                fastn_utils.positionOverlay(node, overlay);
+            }
+            return;
+        }
+        if (kind === fastn_dom.ElementKind.Dialog) {
+            if (node.open) {
+                node.close();
+            }
+        } else if (shown) {
+            node.hidePopover();
+        }
+        let restore = overlay.restore_focus;
+        overlay.restore_focus = null;
+        if (shown && !!restore && restore.isConnected) {
+            restore.focus();
+        }
+    },
+    focusableElements(node) {
+        return Array.from(node.querySelectorAll(
+            "a[href], button:not([disabled]), input:not([disabled]), select:not([disabled]), "
+            + "textarea:not([disabled]), [tabindex]:not([tabindex='-1'])"
+        )).filter((element) => !element.closest("[hidden]"));
+    },
+    // Tab from the last focusable element of a dialog goes to its first, Shift+Tab the other way
+    trapFocus(node, e) {
+        let elements = fastn_utils.focusableElements(node);
+        if (elements.length === 0) {
+            e.preventDefault();
+            return;
+        }
+        let first = elements[0];
+        let last = elements[elements.length - 1];
+        let outside = !node.contains(document.activeElement);
+        if (e.shiftKey && (document.activeElement === first || outside)) {
+            e.preventDefault();
+            last.focus();
+        } else if (!e.shiftKey && (document.activeElement === last || outside)) {
+            e.preventDefault();
+            first.focus();
+        }
+    },
+    // places a popover or a tooltip on the `placement` side of its trigger, on the opposite side
+    // when there is no room there, and inside the window
+    positionOverlay(node, overlay) {
+        let trigger = !!overlay.trigger ? document.getElementById(overlay.trigger) : null;
+        if (!trigger) {
+            return;
+        }
+        let gap = 8;
+        let target = trigger.getBoundingClientRect();
+        let rect = node.getBoundingClientRect();
+        let placement = overlay.placement || (node.getAttribute("role") === "tooltip"
+            ? fastn_dom.Placement.Top
+            : fastn_dom.Placement.Bottom);
+        let opposite = {top: "bottom", bottom: "top", left: "right", right: "left"};
+        let place = (side) => {
+            if (side === "top") {
+                return [target.top - rect.height - gap, target.left + (target.width - rect.width) / 2];
+            } else if (side === "bottom") {
+                return [target.bottom + gap, target.left + (target.width - rect.width) / 2];
+            } else if (side === "left") {
+                return [target.top + (target.height - rect.height) / 2, target.left - rect.width - gap];
+            }
+            return [target.top + (target.height - rect.height) / 2, target.right + gap];
+        };
+        let fits = ([top, left]) => top >= 0 && left >= 0
+            && top + rect.height <= window.innerHeight && left + rect.width <= window.innerWidth;
+        let [top, left] = place(placement);
+        if (!fits([top, left]) && fits(place(opposite[placement]))) {
+            [top, left] = place(opposite[placement]);
+        }
+        node.style.top = `${Math.max(gap, Math.min(top, window.innerHeight - rect.height - gap))}px`;
+        node.style.left = `${Math.max(gap, Math.min(left, window.innerWidth - rect.width - gap))}px`;
+    },
+    createRive(node, data) {
+        if (!window.rive) {
+            console.error("rive runtime is not loaded");
+            return;
+        }
+        let events = data.rive_events || [];
+        let riveInstance = new window.rive.Rive({
+            src: data.src,
+            canvas: node.getNode(),
+            autoplay: data.autoplay,
+            stateMachines: data.state_machines,
+            artboard: data.artboard,
+            onLoad: () => riveInstance.resizeDrawingSurfaceToCanvas(),
+            onPlay: (event) => {
+                events.filter(e => e[0] === 10 && event.data.includes(e[1])).forEach(e => e[2]());
+            },
+            onStateChange: (event) => {
+                events.filter(e => e[0] === 11 && event.data.includes(e[1])).forEach(e => e[2]());
+            },
+            onPause: (event) => {
+                events.filter(e => e[0] === 12 && event.data.includes(e[1])).forEach(e => e[2]());
+            },
+        });
+        ftd.riveNodes[data.id] = riveInstance;
+    },
 }
@@ -1,7 +1,347 @@
 let ftd = {};
 
-ftd.device = "mobile";
+// rive instances by the `id` of their `ftd.rive`
+ftd.riveNodes = {};
+
+// set by the `input` and `change` events, `$VALUE` and `$CHECKED` in ftd
+let VALUE = null;
+let CHECKED = null;
+// set by the `submit` event of an `ftd.form`, `$FORM` in ftd
+let FORM = null;
+// set by the events of an `ftd.video` or `ftd.audio`, `$TIME` in ftd
+let TIME = null;
+
+// variables of the `ftd` module
+let ftd__dark_mode = fastn.mutable(false);
+let ftd__system_dark_mode = fastn.mutable(false);
+let ftd__follow_system_dark_mode = fastn.mutable(true);
+let ftd__device = fastn.mutable("mobile");
+let ftd__breakpoint_width = fastn.recordInstance({mobile: 768});
+// `ftd.breakpoints`, from the narrowest to the widest, the `breakpoints` of `ftd.document` replace
+// them
+let ftd__breakpoints = fastn.mutableList([
+    fastn.recordInstance({name: "mobile", "max-width": 768}),
+    fastn.recordInstance({name: "desktop", "max-width": null}),
+]);
+let ftd__font_display = fastn.mutable("sans-serif");
+let ftd__font_copy = fastn.mutable("sans-serif");
+let ftd__font_code = fastn.mutable("monospace");
+
+ftd.dark_mode = ftd__dark_mode;
+ftd.device = ftd__device;
 
 // ftd.append($a = $people, v = Tom)
 
 ftd.append = function (a, v) { a.push(v)}
+
+ftd.is_empty = function (value) {
+    value = fastn_utils.getFlattenStaticValue(value);
+    return value === null || value === undefined || value.length === 0;
+}
+
+// A value of an or-type defined in an ftd document is a record with its variant as the only
+// field, `match` is built on these two
+ftd.is_variant = function (value, variant) {
+    value = fastn_utils.getStaticValue(value);
+    return value.get(fastn_utils.getter(variant)) !== undefined;
+}
+
+ftd.variant_value = function (value, variant) {
+    value = fastn_utils.getStaticValue(value);
+    return fastn_utils.getStaticValue(value.get(fastn_utils.getter(variant)));
+}
+
+ftd.set_breakpoints = function (breakpoints) {
+    ftd__breakpoints = breakpoints;
+    ftd.detect_device();
+}
+
+// `ftd.device` is the name of the first breakpoint the window is not wider than, it stays
+// "mobile" on the server
+ftd.detect_device = function () {
+    if (ssr || typeof window === "undefined") {
+        return;
+    }
+    let device = "desktop";
+    for (let breakpoint of fastn_utils.getListItems(ftd__breakpoints)) {
+        breakpoint = fastn_utils.getStaticValue(breakpoint);
+        let max_width = fastn_utils.getStaticValue(breakpoint.get("max-width"));
+        if (max_width === null || max_width === undefined
+            || window.matchMedia(`(max-width: ${max_width}px)`).matches) {
+            device = fastn_utils.getStaticValue(breakpoint.get("name"));
+            break;
+        }
+    }
+    if (ftd__device.get() !== device) {
+        ftd__device.set(device);
+    }
+}
+
+ftd.toggle = function (a) {
+    fastn_utils.setter(a, !fastn_utils.getter(a));
+}
+
+ftd.increment = function (a) {
+    fastn_utils.setter(a, fastn_utils.getter(a) + 1);
+}
+
+ftd.increment_by = function (a, v) {
+    fastn_utils.setter(a, fastn_utils.getter(a) + fastn_utils.getter(v));
+}
+
+ftd.set_bool = function (a, v) {
+    fastn_utils.setter(a, fastn_utils.getter(v));
+}
+
+ftd.set_string = ftd.set_bool;
+ftd.set_integer = ftd.set_bool;
+
+ftd.setDarkMode = function (dark, followSystem) {
+    ftd__follow_system_dark_mode.set(followSystem);
+    ftd__dark_mode.set(dark);
+    if (ssr) {
+        return;
+    }
+    if (dark) {
+        window.document.body.classList.add("dark");
+    } else {
+        window.document.body.classList.remove("dark");
+    }
+}
+
+ftd.enable_dark_mode = function () {
+    ftd.setDarkMode(true, false);
+}
+
+ftd.enable_light_mode = function () {
+    ftd.setDarkMode(false, false);
+}
+
+ftd.enable_system_mode = function () {
+    let dark = !ssr && window.matchMedia
+        && window.matchMedia("(prefers-color-scheme: dark)").matches;
+    ftd__system_dark_mode.set(!!dark);
+    ftd.setDarkMode(!!dark, true);
+}
+
+ftd.clean_code = function (a, lang) {
+    // removes the `;; <hl>` markers used to highlight lines
+    let code = fastn_utils.getter(a);
+    if (lang !== undefined && fastn_utils.getter(lang) !== "ftd") {
+        return code;
+    }
+    return code.split("\n").map(line => line.replace(/(;;)( *)(<hl>)( *)$/, "")).join("\n");
+}
+
+// a date time is an ISO-8601 string and a duration a number of seconds, see `date::` functions
+// in `fastn-grammar`
+ftd.format_date = function (d, format, locale) {
+    let datetime = fastn_utils.parseDateTime(fastn_utils.getter(d));
+    locale = locale === undefined ? "en" : fastn_utils.getter(locale);
+    return fastn_utils.formatDateTime(datetime, fastn_utils.getter(format), locale);
+}
+
+ftd.relative_time = function (d, now) {
+    now = now === undefined ? null : fastn_utils.getter(now);
+    return fastn_utils.relativeTime(
+        fastn_utils.parseDateTime(fastn_utils.getter(d)),
+        now === null ? {time: Date.now(), offset: 0} : fastn_utils.parseDateTime(now),
+    );
+}
+
+ftd.compare_dates = function (a, b) {
+    let difference = fastn_utils.parseDateTime(fastn_utils.getter(a)).time
+        - fastn_utils.parseDateTime(fastn_utils.getter(b)).time;
+    return Math.sign(difference);
+}
+
+ftd.add_duration = function (d, by) {
+    let datetime = fastn_utils.parseDateTime(fastn_utils.getter(d));
+    datetime.time += fastn_utils.getter(by) * 1000;
+    return fastn_utils.dateTimeToString(datetime);
+}
+
+ftd.date_difference = function (a, b) {
+    let difference = fastn_utils.parseDateTime(fastn_utils.getter(a)).time
+        - fastn_utils.parseDateTime(fastn_utils.getter(b)).time;
+    return Math.trunc(difference / 1000);
+}
+
+// string and list functions, they follow the `str::` and `list::` functions of `fastn-grammar`
+ftd.split = function (text, separator) {
+    return fastn.mutableList(fastn_utils.getter(text).split(fastn_utils.getter(separator)));
+}
+
+ftd.join = function (list, separator) {
+    return fastn_utils.getListItems(list)
+        .map(v => fastn_utils.getStaticValue(v))
+        .join(fastn_utils.getter(separator));
+}
+
+ftd.replace = function (text, from, to) {
+    to = fastn_utils.getter(to);
+    return fastn_utils.getter(text).replaceAll(fastn_utils.getter(from), () => to);
+}
+
+ftd.trim = function (text) {
+    return fastn_utils.getter(text).trim();
+}
+
+ftd.upper = function (text) {
+    return fastn_utils.getter(text).toUpperCase();
+}
+
+ftd.lower = function (text) {
+    return fastn_utils.getter(text).toLowerCase();
+}
+
+ftd.contains = function (text, part) {
+    let value = fastn_utils.getStaticValue(text);
+    if (typeof value === "string") {
+        return value.includes(fastn_utils.getter(part));
+    }
+    return ftd.index_of(value, part) >= 0;
+}
+
+ftd.starts_with = function (text, prefix) {
+    return fastn_utils.getter(text).startsWith(fastn_utils.getter(prefix));
+}
+
+// by characters, not UTF-16 code units, like `str::slice`
+ftd.slice = function (text, start, end) {
+    end = end === undefined ? null : fastn_utils.getter(end);
+    return Array.from(fastn_utils.getter(text))
+        .slice(fastn_utils.getter(start), end === null ? undefined : end)
+        .join("");
+}
+
+// the pattern is one of `fastn_grammar::evalexpr::regex_subset`, which match the same way at
+// build time, the ones written in the document are checked when it is interpreted
+ftd.regex_match = function (text, pattern) {
+    return new RegExp(fastn_utils.getter(pattern), "u").test(fastn_utils.getter(text));
+}
+
+// `expression` is the item expression compiled to a function of the item, an expression only
+// known when the page runs can't be evaluated in the browser
+ftd.itemFunction = function (expression) {
+    if (typeof expression !== "function") {
+        throw new Error("The expression of ftd.map, ftd.filter and ftd.sort-by must be given "
+            + "as is, found: " + fastn_utils.getter(expression));
+    }
+    return (item) => fastn_utils.getStaticValue(expression(item));
+}
+
+ftd.map = function (list, expression) {
+    let f = ftd.itemFunction(expression);
+    return fastn.mutableList(fastn_utils.getListItems(list).map(f));
+}
+
+ftd.filter = function (list, condition) {
+    let f = ftd.itemFunction(condition);
+    return fastn.mutableList(fastn_utils.getListItems(list)
+        .filter(f)
+        .map(v => fastn_utils.getStaticValue(v)));
+}
+
+ftd.sort_by = function (list, key) {
+    let f = ftd.itemFunction(key);
+    return fastn.mutableList(fastn_utils.getListItems(list)
+        .map(v => ({key: f(v), item: fastn_utils.getStaticValue(v)}))
+        .sort((a, b) => fastn_utils.compare(a.key, b.key))
+        .map(v => v.item));
+}
+
+ftd.reverse = function (list) {
+    return fastn.mutableList(fastn_utils.getListItems(list)
+        .map(v => fastn_utils.getStaticValue(v))
+        .reverse());
+}
+
+ftd.take = function (list, count) {
+    return fastn.mutableList(fastn_utils.getListItems(list)
+        .slice(0, Math.max(0, fastn_utils.getter(count)))
+        .map(v => fastn_utils.getStaticValue(v)));
+}
+
+ftd.skip = function (list, count) {
+    return fastn.mutableList(fastn_utils.getListItems(list)
+        .slice(Math.max(0, fastn_utils.getter(count)))
+        .map(v => fastn_utils.getStaticValue(v)));
+}
+
+ftd.sum = function (list) {
+    return fastn_utils.getListItems(list)
+        .reduce((sum, v) => sum + fastn_utils.getStaticValue(v), 0);
+}
+
+// items are equal when their json is, so records are compared by their fields
+ftd.unique = function (list) {
+    let seen = new Set();
+    return fastn.mutableList(fastn_utils.getListItems(list)
+        .filter(v => {
+            let json = JSON.stringify(fastn_utils.toJsonValue(v));
+            return !seen.has(json) && seen.add(json);
+        })
+        .map(v => fastn_utils.getStaticValue(v)));
+}
+
+ftd.index_of = function (list, item) {
+    let json = JSON.stringify(fastn_utils.toJsonValue(item));
+    return fastn_utils.getListItems(list)
+        .findIndex(v => JSON.stringify(fastn_utils.toJsonValue(v)) === json);
+}
+
+ftd.to_json = function (value) {
+    return JSON.stringify(fastn_utils.toJsonValue(value));
+}
+
+ftd.from_json = function (json) {
+    return fastn_utils.fromJsonValue(JSON.parse(fastn_utils.getter(json)));
+}
+
+ftd.copy_to_clipboard = function (a) {
+    let text = fastn_utils.getter(a);
+    if (navigator.clipboard) {
+        navigator.clipboard.writeText(text);
+    }
+}
+
+ftd.component_data = function (component) {
+    return component.data;
+}
+
+ftd.riveStateMachineInput = function (rive, input) {
+    let instance = ftd.riveNodes[fastn_utils.getter(rive)];
+    const stateMachineName = instance.stateMachineNames[0];
+    const inputs = instance.stateMachineInputs(stateMachineName);
+    return inputs.find(i => i.name === fastn_utils.getter(input));
+}
+
+ftd.set_rive_boolean = function (rive, input, value) {
+    ftd.riveStateMachineInput(rive, input).value = fastn_utils.getter(value);
+}
+
+ftd.toggle_rive_boolean = function (rive, input) {
+    let trigger = ftd.riveStateMachineInput(rive, input);
+    trigger.value = !trigger.value;
+}
+
+ftd.set_rive_integer = ftd.set_rive_boolean;
+
+ftd.fire_rive = function (rive, input) {
+    ftd.riveStateMachineInput(rive, input).fire();
+}
+
+ftd.play_rive = function (rive, input) {
+    ftd.riveNodes[fastn_utils.getter(rive)].play(fastn_utils.getter(input));
+}
+
+ftd.pause_rive = function (rive, input) {
+    ftd.riveNodes[fastn_utils.getter(rive)].pause(fastn_utils.getter(input));
+}
+
+ftd.toggle_play_rive = function (rive, input) {
+    let instance = ftd.riveNodes[fastn_utils.getter(rive)];
+    let name = fastn_utils.getter(input);
+    instance.playingAnimationNames.includes(name) ? instance.pause(name) : instance.play(name);
+}
@@ -4,6 +4,9 @@ let id_counter = 0;
 let hydrating = false;
 let ssr = false;
 
+// elements that have no closing tag
+const voidElements = ["img", "input"];
+
 class ClassList {
     #classes = [];
     add(item) {
@@ -18,11 +21,13 @@ class Node {
     #id
     #tagName
     #children
+    #attributes
     constructor(id, tagName) {
         this.#tagName = tagName;
         this.#id = id;
         this.classList = new ClassList();
         this.#children = [];
+        this.#attributes = {};
         this.innerHTML = "";
         this.style = {};
         this.onclick = null;
@@ -30,8 +35,20 @@ class Node {
     appendChild(c) {
         this.#children.push(c);
     }
+    setAttribute(key, value) {
+        this.#attributes[key] = value;
+    }
+    removeAttribute(key) {
+        delete this.#attributes[key];
+    }
+    updateTagName(tagName) {
+        this.#tagName = tagName;
+    }
     toHtmlAsString() {
-        const openingTag = `<${this.#tagName}${this.getDataIdString()}${this.getClassString()}${this.getStyleString()}>`;
+        const openingTag = `<${this.#tagName}${this.getDataIdString()}${this.getClassString()}${this.getStyleString()}${this.getAttributesString()}>`;
+        if (voidElements.includes(this.#tagName)) {
+            return openingTag;
+        }
         const closingTag = `</${this.#tagName}>`;
         const innerHTML = this.innerHTML;
         const childNodes = this.#children.map(child => child.toHtmlAsString()).join('');
@@ -45,6 +62,11 @@ class Node {
         const classList = this.classList.toString();
         return classList ? ` class="${classList}"` : '';
     }
+    getAttributesString() {
+        return Object.entries(this.#attributes)
+            .map(([key, value]) => value === "" ? ` ${key}` : ` ${key}="${fastn_utils.escapeHtml(value)}"`)
+            .join('');
+    }
     getStyleString() {
         const styleProperties = Object.entries(this.style)
             .map(([prop, value]) => `${prop}:${value}`)
@@ -82,6 +104,9 @@ fastn_virtual.document = new Document2();
 
 fastn_virtual.hydrate = function(main) {
     hydrating = true;
+    // `ftd.device` follows the width of the window
+    ftd.detect_device();
+    window.addEventListener("resize", ftd.detect_device);
     let body = fastn_virtual.document.createElement("body");
     main(body);
     id_counter = 0;
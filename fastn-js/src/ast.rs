@@ -5,4 +5,7 @@ pub enum Ast {
     MutableVariable(fastn_js::MutableVariable),
     MutableList(fastn_js::MutableList),
     RecordInstance(fastn_js::RecordInstance),
+    /// `let inherited = ...`, the `$inherited` values no component sets, `ftd.default-colors`
+    /// and `ftd.default-types` for `colors` and `types`
+    DeclareInherited(Vec<(String, fastn_js::SetPropertyValue)>),
 }
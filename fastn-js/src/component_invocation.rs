@@ -14,18 +14,52 @@ impl Kernel {
             parent: parent.to_string(),
         }
     }
+
+    pub fn from_web_component(
+        web_component_name: &str,
+        arguments: Vec<(String, fastn_js::SetPropertyValue)>,
+        parent: &str,
+        index: usize,
+    ) -> Kernel {
+        Kernel {
+            element_kind: ElementKind::WebComponent(web_component_name.to_string(), arguments),
+            name: format!("{parent}i{index}"),
+            parent: parent.to_string(),
+        }
+    }
 }
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub enum ElementKind {
     Row,
     Column,
+    Container,
+    Grid,
     Integer,
     Decimal,
     Boolean,
     Text,
     Image,
     IFrame,
+    Code,
+    TextInput,
+    TextArea,
+    CheckBox,
+    Select,
+    RadioGroup,
+    Form,
+    Video,
+    Audio,
+    Svg,
+    Icon,
+    Table,
+    Dialog,
+    Popover,
+    Tooltip,
+    Rive,
+    Document,
+    /// custom element name and the arguments passed to it
+    WebComponent(String, Vec<(String, fastn_js::SetPropertyValue)>),
 }
 
 impl ElementKind {
@@ -33,17 +67,59 @@ impl ElementKind {
         match name {
             "ftd#text" => ElementKind::Text,
             "ftd#integer" => ElementKind::Text,
+            "ftd#decimal" => ElementKind::Text,
+            "ftd#boolean" => ElementKind::Text,
             "ftd#row" => ElementKind::Row,
             "ftd#column" => ElementKind::Column,
+            "ftd#container" => ElementKind::Container,
+            "ftd#grid" => ElementKind::Grid,
+            "ftd#image" => ElementKind::Image,
+            "ftd#iframe" => ElementKind::IFrame,
+            "ftd#code" => ElementKind::Code,
+            "ftd#text-input" => ElementKind::TextInput,
+            "ftd#checkbox" => ElementKind::CheckBox,
+            "ftd#select" => ElementKind::Select,
+            "ftd#radio-group" => ElementKind::RadioGroup,
+            "ftd#form" => ElementKind::Form,
+            "ftd#video" => ElementKind::Video,
+            "ftd#audio" => ElementKind::Audio,
+            "ftd#svg" => ElementKind::Svg,
+            "ftd#icon" => ElementKind::Icon,
+            "ftd#table" => ElementKind::Table,
+            "ftd#dialog" => ElementKind::Dialog,
+            "ftd#popover" => ElementKind::Popover,
+            "ftd#tooltip" => ElementKind::Tooltip,
+            "ftd#rive" => ElementKind::Rive,
+            "ftd#document" => ElementKind::Document,
             _ => todo!(),
         }
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct InstantiateComponent {
-    pub name: String,
+    pub component: InstantiateComponentData,
     pub arguments: Vec<fastn_js::SetPropertyValue>,
     pub parent: String,
+    /// the `$inherited` values the instance sees, `None` when the document reads none
+    pub inherited: Option<Inherited>,
     pub should_return: bool,
 }
+
+#[derive(Clone, Debug)]
+pub enum InstantiateComponentData {
+    /// a component definition
+    Name(String),
+    /// a `ftd.ui` value, or a component of a `module` value, `-- page.header:`
+    Definition(Box<fastn_js::SetPropertyValue>),
+}
+
+/// The values the components read as `$inherited.<name>`. A component instance, and the
+/// children of a container, see the values of their ancestors extended with these.
+#[derive(Clone, Debug, Default)]
+pub struct Inherited {
+    /// the arguments passed to the instance, these override the values of the ancestors
+    pub values: Vec<(String, fastn_js::SetPropertyValue)>,
+    /// the defaults of the other arguments, the values of the ancestors override these
+    pub defaults: Vec<(String, fastn_js::SetPropertyValue)>,
+}
@@ -1,3 +1,4 @@
+#[derive(Clone, Debug)]
 pub enum ComponentStatement {
     StaticVariable(fastn_js::StaticVariable),
     MutableVariable(fastn_js::MutableVariable),
@@ -5,11 +6,18 @@ pub enum ComponentStatement {
     SetProperty(fastn_js::SetProperty),
     InstantiateComponent(fastn_js::InstantiateComponent),
     AddEventHandler(fastn_js::EventHandler),
-    Return { component_name: String },
+    Return {
+        component_name: String,
+    },
     ConditionalComponent(fastn_js::ConditionalComponent),
     MutableList(fastn_js::MutableList),
     ForLoop(fastn_js::ForLoop),
     RecordInstance(fastn_js::RecordInstance),
+    /// the children of a container that sets `colors` or `types` for them
+    ExtendInherited {
+        inherited: fastn_js::Inherited,
+        statements: Vec<fastn_js::ComponentStatement>,
+    },
     // JSExpression(ExprNode),
     // RecordInstance(RecordInstance),
     // Formula(Formula),
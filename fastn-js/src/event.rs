@@ -1,13 +1,33 @@
+#[derive(Clone, Debug)]
 pub struct EventHandler {
     pub event: fastn_js::Event,
     pub action: fastn_js::Function,
     pub element_name: String,
 }
 
+#[derive(Clone, Debug)]
 pub enum Event {
     OnClick,
+    OnMouseEnter,
+    OnMouseLeave,
+    OnClickOutside,
+    OnGlobalKey(Vec<String>),
+    OnGlobalKeySeq(Vec<String>),
+    OnInput,
+    OnChange,
+    OnBlur,
+    OnFocus,
+    OnSubmit,
+    OnPlay,
+    OnPause,
+    OnEnded,
+    OnTimeUpdate,
+    OnRivePlay(String),
+    OnRiveStateChange(String),
+    OnRivePause(String),
 }
 
+#[derive(Clone, Debug)]
 pub struct Function {
     pub name: String,
     pub parameters: Vec<fastn_js::SetPropertyValue>,
@@ -1,6 +1,9 @@
+#[derive(Clone, Debug)]
 pub struct ForLoop {
     pub list_variable: fastn_js::SetPropertyValue,
     pub statements: Vec<fastn_js::ComponentStatement>,
     pub parent: String,
     pub should_return: bool,
+    /// the loop is over a map with `integer` keys, which are given to the loop as numbers
+    pub integer_keys: bool,
 }
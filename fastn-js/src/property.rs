@@ -1,10 +1,11 @@
+#[derive(Clone, Debug)]
 pub struct SetProperty {
     pub kind: PropertyKind,
     pub value: SetPropertyValue,
     pub element_name: String,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum SetPropertyValue {
     Reference(String),
     Value(Value),
@@ -25,7 +26,7 @@ impl SetPropertyValue {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Formula {
     pub deps: Vec<String>,
     pub conditional_values: Vec<ConditionalValue>,
@@ -39,7 +40,7 @@ impl Formula {
             "fastn.formula([{}], {})",
             self.deps
                 .iter()
-                .map(|v| fastn_js::utils::name_to_js(v))
+                .map(|v| fastn_js::utils::reference_to_js(v))
                 .collect_vec()
                 .join(", "),
             self.conditional_values_to_js()
@@ -79,7 +80,7 @@ impl Formula {
         }
 
         let default = match default {
-            Some(d) if conditions.is_empty() => d,
+            Some(d) if conditions.is_empty() => format!("return {};", d),
             Some(d) => format!("else {{ return {}; }}", d),
             None => "".to_string(),
         };
@@ -96,17 +97,18 @@ impl Formula {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct ConditionalValue {
     pub condition: Option<fastn_grammar::evalexpr::ExprNode>,
     pub expression: SetPropertyValue,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum Value {
     String(String),
     Integer(i64),
     Decimal(f64),
+    Boolean(bool),
     OrType {
         variant: String,
         value: Option<Box<SetPropertyValue>>,
@@ -117,15 +119,34 @@ pub enum Value {
     Record {
         fields: Vec<(String, SetPropertyValue)>,
     },
+    FunctionCall(fastn_js::Function),
+    // the expression given to `ftd#map`, `ftd#filter` or `ftd#sort-by`, a function of the item
+    ItemExpression(fastn_grammar::evalexpr::ExprNode),
+    // the `cell` component of a `ftd.table-column`, a function of the cell element, its value and
+    // the row it belongs to
+    CellComponent(fastn_js::InstantiateComponent),
+    // a `ftd.ui` value, a function of the element it is shown in, and of the `$inherited` values
+    // when the document reads any
+    UI {
+        statements: Vec<fastn_js::ComponentStatement>,
+        inherited: bool,
+    },
+    Null,
 }
 
 impl Value {
     pub(crate) fn to_js(&self) -> String {
         use itertools::Itertools;
         match self {
-            Value::String(s) => format!("\"{}\"", s.replace('\n', "\\n")),
+            Value::String(s) => format!(
+                "\"{}\"",
+                s.replace('\\', "\\\\")
+                    .replace('"', "\\\"")
+                    .replace('\n', "\\n")
+            ),
             Value::Integer(i) => i.to_string(),
             Value::Decimal(f) => f.to_string(),
+            Value::Boolean(b) => b.to_string(),
             Value::OrType { variant, value } => {
                 if let Some(value) = value {
                     format!("{}({})", variant, value.to_js())
@@ -137,26 +158,237 @@ impl Value {
                 "fastn.mutableList([{}])",
                 value.iter().map(|v| v.to_js()).join(", ")
             ),
-            Value::Record { fields } => format!(
-                "fastn.recordInstance({{{}}})",
-                fields
-                    .iter()
-                    .map(|(k, v)| format!("{}: {}", k, v.to_js()))
-                    .join(", ")
+            Value::Record { fields } => format!("fastn.recordInstance({})", fields_to_js(fields)),
+            Value::FunctionCall(function) => format!(
+                "{}({})",
+                fastn_js::utils::function_name_to_js(function.name.as_str()),
+                function.parameters.iter().map(|v| v.to_js()).join(", ")
+            ),
+            Value::ItemExpression(expression) => {
+                fastn_js::to_js::ExpressionGenerator.item_function(expression)
+            }
+            Value::CellComponent(component) => format!(
+                "function ({}, value, row) {{{}}}",
+                component.parent,
+                fastn_js::to_js::statements_to_js(&[
+                    fastn_js::ComponentStatement::InstantiateComponent(component.clone())
+                ])
+            ),
+            Value::UI {
+                statements,
+                inherited,
+            } => format!(
+                "function (root{}) {{{}}}",
+                if *inherited { ", inherited" } else { "" },
+                fastn_js::to_js::statements_to_js(statements)
             ),
+            Value::Null => "null".to_string(),
         }
     }
 }
 
+/// `{name: "Foo", "og-image": ...}`, the fields of a record instance.
+pub(crate) fn fields_to_js(fields: &[(String, SetPropertyValue)]) -> String {
+    use itertools::Itertools;
+
+    format!(
+        "{{{}}}",
+        fields
+            .iter()
+            .map(|(k, v)| format!("{}: {}", record_key_to_js(k), v.to_js()))
+            .join(", ")
+    )
+}
+
+/// Record keys are kept as is, so that `.get("<field>")` finds them, and are quoted when they are
+/// not valid identifiers, e.g. `og-image`.
+fn record_key_to_js(key: &str) -> String {
+    if key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        key.to_string()
+    } else {
+        format!("\"{}\"", key)
+    }
+}
+
+#[derive(Clone, Debug)]
 pub enum PropertyKind {
-    StringValue,
     Id,
+    StringValue,
     Width,
     Padding,
     Margin,
     Height,
     BorderWidth,
     BorderStyle,
+    Color,
+    IntegerValue,
+    DecimalValue,
+    BooleanValue,
+    ZIndex,
+    Left,
+    Right,
+    Top,
+    Bottom,
+    Anchor,
+    Role,
+    Region,
+    Cursor,
+    Classes,
+    PaddingHorizontal,
+    PaddingVertical,
+    PaddingLeft,
+    PaddingRight,
+    PaddingTop,
+    PaddingBottom,
+    MarginHorizontal,
+    MarginVertical,
+    MarginLeft,
+    MarginRight,
+    MarginTop,
+    MarginBottom,
+    BorderRadius,
+    BorderColor,
+    BorderTopWidth,
+    BorderTopColor,
+    BorderBottomWidth,
+    BorderBottomColor,
+    BorderLeftWidth,
+    BorderLeftColor,
+    BorderRightWidth,
+    BorderRightColor,
+    BorderTopLeftRadius,
+    BorderTopRightRadius,
+    BorderBottomLeftRadius,
+    BorderBottomRightRadius,
+    BorderStyleVertical,
+    BorderStyleHorizontal,
+    BorderStyleLeft,
+    BorderStyleRight,
+    BorderStyleTop,
+    BorderStyleBottom,
+    MinWidth,
+    MaxWidth,
+    MinHeight,
+    MaxHeight,
+    Link,
+    OpenInNewTab,
+    Background,
+    AlignSelf,
+    Overflow,
+    OverflowX,
+    OverflowY,
+    Opacity,
+    Resize,
+    WhiteSpace,
+    TextTransform,
+    Sticky,
+    Shadow,
+    Display,
+    TextAlign,
+    LineClamp,
+    TextIndent,
+    TextStyle,
+    Spacing,
+    Wrap,
+    AlignContent,
+    ImageSrc,
+    Alt,
+    Code,
+    CodeLanguage,
+    CodeTheme,
+    IFrameSrc,
+    YoutubeSrc,
+    IFrameSrcDoc,
+    Loading,
+    Placeholder,
+    Value,
+    DefaultValue,
+    Enabled,
+    InputType,
+    Checked,
+    RiveSrc,
+    RiveCanvasWidth,
+    RiveCanvasHeight,
+    RiveStateMachine,
+    RiveAutoplay,
+    RiveArtboard,
+    MetaTitle,
+    MetaOgTitle,
+    MetaTwitterTitle,
+    MetaDescription,
+    MetaOgDescription,
+    MetaTwitterDescription,
+    MetaOgImage,
+    MetaTwitterImage,
+    MetaThemeColor,
+    GridTemplateColumns,
+    GridTemplateRows,
+    GridTemplateAreas,
+    Gap,
+    RowGap,
+    ColumnGap,
+    GridArea,
+    GridColumnStart,
+    GridColumnSpan,
+    GridRowStart,
+    GridRowSpan,
+    ColorHover,
+    BackgroundHover,
+    BorderColorHover,
+    ShadowHover,
+    ColorFocus,
+    BackgroundFocus,
+    BorderColorFocus,
+    ShadowFocus,
+    ColorActive,
+    BackgroundActive,
+    BorderColorActive,
+    ShadowActive,
+    Transform,
+    Transition,
+    Animation,
+    Options,
+    FieldName,
+    Required,
+    Min,
+    Max,
+    Step,
+    Pattern,
+    FormAction,
+    FormMethod,
+    MediaSources,
+    Captions,
+    Poster,
+    Controls,
+    Autoplay,
+    Muted,
+    Loop,
+    SvgSrc,
+    IconName,
+    IconSprite,
+    Fill,
+    Stroke,
+    TableTitle,
+    TableColumns,
+    TableCells,
+    TableFilterable,
+    TablePageSize,
+    TablePageParam,
+    TablePage,
+    TableTotal,
+    TableData,
+    AriaLabel,
+    AriaDescribedBy,
+    AriaHidden,
+    AriaLive,
+    Landmark,
+    TabIndex,
+    OverlayOpen,
+    OverlayCloseOnEscape,
+    OverlayCloseOnOutsideClick,
+    OverlayTrigger,
+    OverlayPlacement,
+    Breakpoints,
 }
 
 impl PropertyKind {
@@ -170,6 +402,179 @@ impl PropertyKind {
             PropertyKind::Height => "fastn_dom.PropertyKind.Height",
             PropertyKind::BorderWidth => "fastn_dom.PropertyKind.BorderWidth",
             PropertyKind::BorderStyle => "fastn_dom.PropertyKind.BorderStyle",
+            PropertyKind::Color => "fastn_dom.PropertyKind.Color",
+            PropertyKind::IntegerValue => "fastn_dom.PropertyKind.IntegerValue",
+            PropertyKind::DecimalValue => "fastn_dom.PropertyKind.DecimalValue",
+            PropertyKind::BooleanValue => "fastn_dom.PropertyKind.BooleanValue",
+            PropertyKind::ZIndex => "fastn_dom.PropertyKind.ZIndex",
+            PropertyKind::Left => "fastn_dom.PropertyKind.Left",
+            PropertyKind::Right => "fastn_dom.PropertyKind.Right",
+            PropertyKind::Top => "fastn_dom.PropertyKind.Top",
+            PropertyKind::Bottom => "fastn_dom.PropertyKind.Bottom",
+            PropertyKind::Anchor => "fastn_dom.PropertyKind.Anchor",
+            PropertyKind::Role => "fastn_dom.PropertyKind.Role",
+            PropertyKind::Region => "fastn_dom.PropertyKind.Region",
+            PropertyKind::Cursor => "fastn_dom.PropertyKind.Cursor",
+            PropertyKind::Classes => "fastn_dom.PropertyKind.Classes",
+            PropertyKind::PaddingHorizontal => "fastn_dom.PropertyKind.PaddingHorizontal",
+            PropertyKind::PaddingVertical => "fastn_dom.PropertyKind.PaddingVertical",
+            PropertyKind::PaddingLeft => "fastn_dom.PropertyKind.PaddingLeft",
+            PropertyKind::PaddingRight => "fastn_dom.PropertyKind.PaddingRight",
+            PropertyKind::PaddingTop => "fastn_dom.PropertyKind.PaddingTop",
+            PropertyKind::PaddingBottom => "fastn_dom.PropertyKind.PaddingBottom",
+            PropertyKind::MarginHorizontal => "fastn_dom.PropertyKind.MarginHorizontal",
+            PropertyKind::MarginVertical => "fastn_dom.PropertyKind.MarginVertical",
+            PropertyKind::MarginLeft => "fastn_dom.PropertyKind.MarginLeft",
+            PropertyKind::MarginRight => "fastn_dom.PropertyKind.MarginRight",
+            PropertyKind::MarginTop => "fastn_dom.PropertyKind.MarginTop",
+            PropertyKind::MarginBottom => "fastn_dom.PropertyKind.MarginBottom",
+            PropertyKind::BorderRadius => "fastn_dom.PropertyKind.BorderRadius",
+            PropertyKind::BorderColor => "fastn_dom.PropertyKind.BorderColor",
+            PropertyKind::BorderTopWidth => "fastn_dom.PropertyKind.BorderTopWidth",
+            PropertyKind::BorderTopColor => "fastn_dom.PropertyKind.BorderTopColor",
+            PropertyKind::BorderBottomWidth => "fastn_dom.PropertyKind.BorderBottomWidth",
+            PropertyKind::BorderBottomColor => "fastn_dom.PropertyKind.BorderBottomColor",
+            PropertyKind::BorderLeftWidth => "fastn_dom.PropertyKind.BorderLeftWidth",
+            PropertyKind::BorderLeftColor => "fastn_dom.PropertyKind.BorderLeftColor",
+            PropertyKind::BorderRightWidth => "fastn_dom.PropertyKind.BorderRightWidth",
+            PropertyKind::BorderRightColor => "fastn_dom.PropertyKind.BorderRightColor",
+            PropertyKind::BorderTopLeftRadius => "fastn_dom.PropertyKind.BorderTopLeftRadius",
+            PropertyKind::BorderTopRightRadius => "fastn_dom.PropertyKind.BorderTopRightRadius",
+            PropertyKind::BorderBottomLeftRadius => "fastn_dom.PropertyKind.BorderBottomLeftRadius",
+            PropertyKind::BorderBottomRightRadius => {
+                "fastn_dom.PropertyKind.BorderBottomRightRadius"
+            }
+            PropertyKind::BorderStyleVertical => "fastn_dom.PropertyKind.BorderStyleVertical",
+            PropertyKind::BorderStyleHorizontal => "fastn_dom.PropertyKind.BorderStyleHorizontal",
+            PropertyKind::BorderStyleLeft => "fastn_dom.PropertyKind.BorderStyleLeft",
+            PropertyKind::BorderStyleRight => "fastn_dom.PropertyKind.BorderStyleRight",
+            PropertyKind::BorderStyleTop => "fastn_dom.PropertyKind.BorderStyleTop",
+            PropertyKind::BorderStyleBottom => "fastn_dom.PropertyKind.BorderStyleBottom",
+            PropertyKind::MinWidth => "fastn_dom.PropertyKind.MinWidth",
+            PropertyKind::MaxWidth => "fastn_dom.PropertyKind.MaxWidth",
+            PropertyKind::MinHeight => "fastn_dom.PropertyKind.MinHeight",
+            PropertyKind::MaxHeight => "fastn_dom.PropertyKind.MaxHeight",
+            PropertyKind::Link => "fastn_dom.PropertyKind.Link",
+            PropertyKind::OpenInNewTab => "fastn_dom.PropertyKind.OpenInNewTab",
+            PropertyKind::Background => "fastn_dom.PropertyKind.Background",
+            PropertyKind::AlignSelf => "fastn_dom.PropertyKind.AlignSelf",
+            PropertyKind::Overflow => "fastn_dom.PropertyKind.Overflow",
+            PropertyKind::OverflowX => "fastn_dom.PropertyKind.OverflowX",
+            PropertyKind::OverflowY => "fastn_dom.PropertyKind.OverflowY",
+            PropertyKind::Opacity => "fastn_dom.PropertyKind.Opacity",
+            PropertyKind::Resize => "fastn_dom.PropertyKind.Resize",
+            PropertyKind::WhiteSpace => "fastn_dom.PropertyKind.WhiteSpace",
+            PropertyKind::TextTransform => "fastn_dom.PropertyKind.TextTransform",
+            PropertyKind::Sticky => "fastn_dom.PropertyKind.Sticky",
+            PropertyKind::Shadow => "fastn_dom.PropertyKind.Shadow",
+            PropertyKind::Display => "fastn_dom.PropertyKind.Display",
+            PropertyKind::TextAlign => "fastn_dom.PropertyKind.TextAlign",
+            PropertyKind::LineClamp => "fastn_dom.PropertyKind.LineClamp",
+            PropertyKind::TextIndent => "fastn_dom.PropertyKind.TextIndent",
+            PropertyKind::TextStyle => "fastn_dom.PropertyKind.TextStyle",
+            PropertyKind::Spacing => "fastn_dom.PropertyKind.Spacing",
+            PropertyKind::Wrap => "fastn_dom.PropertyKind.Wrap",
+            PropertyKind::AlignContent => "fastn_dom.PropertyKind.AlignContent",
+            PropertyKind::ImageSrc => "fastn_dom.PropertyKind.ImageSrc",
+            PropertyKind::Alt => "fastn_dom.PropertyKind.Alt",
+            PropertyKind::Code => "fastn_dom.PropertyKind.Code",
+            PropertyKind::CodeLanguage => "fastn_dom.PropertyKind.CodeLanguage",
+            PropertyKind::CodeTheme => "fastn_dom.PropertyKind.CodeTheme",
+            PropertyKind::IFrameSrc => "fastn_dom.PropertyKind.IFrameSrc",
+            PropertyKind::YoutubeSrc => "fastn_dom.PropertyKind.YoutubeSrc",
+            PropertyKind::IFrameSrcDoc => "fastn_dom.PropertyKind.IFrameSrcDoc",
+            PropertyKind::Loading => "fastn_dom.PropertyKind.Loading",
+            PropertyKind::Placeholder => "fastn_dom.PropertyKind.Placeholder",
+            PropertyKind::Value => "fastn_dom.PropertyKind.Value",
+            PropertyKind::DefaultValue => "fastn_dom.PropertyKind.DefaultValue",
+            PropertyKind::Enabled => "fastn_dom.PropertyKind.Enabled",
+            PropertyKind::InputType => "fastn_dom.PropertyKind.InputType",
+            PropertyKind::Checked => "fastn_dom.PropertyKind.Checked",
+            PropertyKind::RiveSrc => "fastn_dom.PropertyKind.RiveSrc",
+            PropertyKind::RiveCanvasWidth => "fastn_dom.PropertyKind.RiveCanvasWidth",
+            PropertyKind::RiveCanvasHeight => "fastn_dom.PropertyKind.RiveCanvasHeight",
+            PropertyKind::RiveStateMachine => "fastn_dom.PropertyKind.RiveStateMachine",
+            PropertyKind::RiveAutoplay => "fastn_dom.PropertyKind.RiveAutoplay",
+            PropertyKind::RiveArtboard => "fastn_dom.PropertyKind.RiveArtboard",
+            PropertyKind::MetaTitle => "fastn_dom.PropertyKind.MetaTitle",
+            PropertyKind::MetaOgTitle => "fastn_dom.PropertyKind.MetaOgTitle",
+            PropertyKind::MetaTwitterTitle => "fastn_dom.PropertyKind.MetaTwitterTitle",
+            PropertyKind::MetaDescription => "fastn_dom.PropertyKind.MetaDescription",
+            PropertyKind::MetaOgDescription => "fastn_dom.PropertyKind.MetaOgDescription",
+            PropertyKind::MetaTwitterDescription => "fastn_dom.PropertyKind.MetaTwitterDescription",
+            PropertyKind::MetaOgImage => "fastn_dom.PropertyKind.MetaOgImage",
+            PropertyKind::MetaTwitterImage => "fastn_dom.PropertyKind.MetaTwitterImage",
+            PropertyKind::MetaThemeColor => "fastn_dom.PropertyKind.MetaThemeColor",
+            PropertyKind::GridTemplateColumns => "fastn_dom.PropertyKind.GridTemplateColumns",
+            PropertyKind::GridTemplateRows => "fastn_dom.PropertyKind.GridTemplateRows",
+            PropertyKind::GridTemplateAreas => "fastn_dom.PropertyKind.GridTemplateAreas",
+            PropertyKind::Gap => "fastn_dom.PropertyKind.Gap",
+            PropertyKind::RowGap => "fastn_dom.PropertyKind.RowGap",
+            PropertyKind::ColumnGap => "fastn_dom.PropertyKind.ColumnGap",
+            PropertyKind::GridArea => "fastn_dom.PropertyKind.GridArea",
+            PropertyKind::GridColumnStart => "fastn_dom.PropertyKind.GridColumnStart",
+            PropertyKind::GridColumnSpan => "fastn_dom.PropertyKind.GridColumnSpan",
+            PropertyKind::GridRowStart => "fastn_dom.PropertyKind.GridRowStart",
+            PropertyKind::GridRowSpan => "fastn_dom.PropertyKind.GridRowSpan",
+            PropertyKind::ColorHover => "fastn_dom.PropertyKind.ColorHover",
+            PropertyKind::BackgroundHover => "fastn_dom.PropertyKind.BackgroundHover",
+            PropertyKind::BorderColorHover => "fastn_dom.PropertyKind.BorderColorHover",
+            PropertyKind::ShadowHover => "fastn_dom.PropertyKind.ShadowHover",
+            PropertyKind::ColorFocus => "fastn_dom.PropertyKind.ColorFocus",
+            PropertyKind::BackgroundFocus => "fastn_dom.PropertyKind.BackgroundFocus",
+            PropertyKind::BorderColorFocus => "fastn_dom.PropertyKind.BorderColorFocus",
+            PropertyKind::ShadowFocus => "fastn_dom.PropertyKind.ShadowFocus",
+            PropertyKind::ColorActive => "fastn_dom.PropertyKind.ColorActive",
+            PropertyKind::BackgroundActive => "fastn_dom.PropertyKind.BackgroundActive",
+            PropertyKind::BorderColorActive => "fastn_dom.PropertyKind.BorderColorActive",
+            PropertyKind::ShadowActive => "fastn_dom.PropertyKind.ShadowActive",
+            PropertyKind::Transform => "fastn_dom.PropertyKind.Transform",
+            PropertyKind::Transition => "fastn_dom.PropertyKind.Transition",
+            PropertyKind::Animation => "fastn_dom.PropertyKind.Animation",
+            PropertyKind::Options => "fastn_dom.PropertyKind.Options",
+            PropertyKind::FieldName => "fastn_dom.PropertyKind.FieldName",
+            PropertyKind::Required => "fastn_dom.PropertyKind.Required",
+            PropertyKind::Min => "fastn_dom.PropertyKind.Min",
+            PropertyKind::Max => "fastn_dom.PropertyKind.Max",
+            PropertyKind::Step => "fastn_dom.PropertyKind.Step",
+            PropertyKind::Pattern => "fastn_dom.PropertyKind.Pattern",
+            PropertyKind::FormAction => "fastn_dom.PropertyKind.FormAction",
+            PropertyKind::FormMethod => "fastn_dom.PropertyKind.FormMethod",
+            PropertyKind::MediaSources => "fastn_dom.PropertyKind.MediaSources",
+            PropertyKind::Captions => "fastn_dom.PropertyKind.Captions",
+            PropertyKind::Poster => "fastn_dom.PropertyKind.Poster",
+            PropertyKind::Controls => "fastn_dom.PropertyKind.Controls",
+            PropertyKind::Autoplay => "fastn_dom.PropertyKind.Autoplay",
+            PropertyKind::Muted => "fastn_dom.PropertyKind.Muted",
+            PropertyKind::Loop => "fastn_dom.PropertyKind.Loop",
+            PropertyKind::SvgSrc => "fastn_dom.PropertyKind.SvgSrc",
+            PropertyKind::IconName => "fastn_dom.PropertyKind.IconName",
+            PropertyKind::IconSprite => "fastn_dom.PropertyKind.IconSprite",
+            PropertyKind::Fill => "fastn_dom.PropertyKind.Fill",
+            PropertyKind::Stroke => "fastn_dom.PropertyKind.Stroke",
+            PropertyKind::TableTitle => "fastn_dom.PropertyKind.TableTitle",
+            PropertyKind::TableColumns => "fastn_dom.PropertyKind.TableColumns",
+            PropertyKind::TableCells => "fastn_dom.PropertyKind.TableCells",
+            PropertyKind::TableFilterable => "fastn_dom.PropertyKind.TableFilterable",
+            PropertyKind::TablePageSize => "fastn_dom.PropertyKind.TablePageSize",
+            PropertyKind::TablePageParam => "fastn_dom.PropertyKind.TablePageParam",
+            PropertyKind::TablePage => "fastn_dom.PropertyKind.TablePage",
+            PropertyKind::TableTotal => "fastn_dom.PropertyKind.TableTotal",
+            PropertyKind::TableData => "fastn_dom.PropertyKind.TableData",
+            PropertyKind::AriaLabel => "fastn_dom.PropertyKind.AriaLabel",
+            PropertyKind::AriaDescribedBy => "fastn_dom.PropertyKind.AriaDescribedBy",
+            PropertyKind::AriaHidden => "fastn_dom.PropertyKind.AriaHidden",
+            PropertyKind::AriaLive => "fastn_dom.PropertyKind.AriaLive",
+            PropertyKind::Landmark => "fastn_dom.PropertyKind.Landmark",
+            PropertyKind::TabIndex => "fastn_dom.PropertyKind.TabIndex",
+            PropertyKind::OverlayOpen => "fastn_dom.PropertyKind.OverlayOpen",
+            PropertyKind::OverlayCloseOnEscape => "fastn_dom.PropertyKind.OverlayCloseOnEscape",
+            PropertyKind::OverlayCloseOnOutsideClick => {
+                "fastn_dom.PropertyKind.OverlayCloseOnOutsideClick"
+            }
+            PropertyKind::OverlayTrigger => "fastn_dom.PropertyKind.OverlayTrigger",
+            PropertyKind::OverlayPlacement => "fastn_dom.PropertyKind.OverlayPlacement",
+            PropertyKind::Breakpoints => "fastn_dom.PropertyKind.Breakpoints",
         }
     }
 }
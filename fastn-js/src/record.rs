@@ -1,3 +1,4 @@
+#[derive(Clone, Debug)]
 pub struct RecordInstance {
     pub name: String,
     pub fields: fastn_js::SetPropertyValue,
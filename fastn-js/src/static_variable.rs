@@ -1,3 +1,4 @@
+#[derive(Clone, Debug)]
 pub struct StaticVariable {
     pub name: String,
     pub value: fastn_js::SetPropertyValue,
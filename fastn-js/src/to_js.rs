@@ -17,6 +17,15 @@ pub fn to_js(ast: &[fastn_js::Ast]) -> String {
     String::from_utf8(w).unwrap()
 }
 
+/// The statements of a function given as a value, a `ftd.ui` value or the `cell` of a table
+/// column.
+pub(crate) fn statements_to_js(statements: &[fastn_js::ComponentStatement]) -> String {
+    let mut w = Vec::new();
+    let o = pretty::RcDoc::intersperse(statements.iter().map(|f| f.to_js()), space());
+    o.render(80, &mut w).unwrap();
+    String::from_utf8(w).unwrap()
+}
+
 impl fastn_js::Ast {
     pub fn to_js(&self) -> pretty::RcDoc<'static> {
         match self {
@@ -26,6 +35,13 @@ impl fastn_js::Ast {
             fastn_js::Ast::MutableVariable(m) => m.to_js(),
             fastn_js::Ast::MutableList(ml) => ml.to_js(),
             fastn_js::Ast::RecordInstance(ri) => ri.to_js(),
+            fastn_js::Ast::DeclareInherited(defaults) => text(
+                format!(
+                    "let inherited = fastn.inherited({});",
+                    fastn_js::property::fields_to_js(defaults)
+                )
+                .as_str(),
+            ),
         }
     }
 }
@@ -41,7 +57,7 @@ impl fastn_js::Kernel {
             .append(text("fastn_dom.createKernel("))
             .append(text(&format!("{},", self.parent.clone())))
             .append(space())
-            .append(text(self.element_kind.to_js()))
+            .append(text(self.element_kind.to_js().as_str()))
             .append(text(");"))
     }
 }
@@ -73,36 +89,109 @@ impl fastn_js::Event {
     pub fn to_js(&self) -> pretty::RcDoc<'static> {
         match self {
             fastn_js::Event::OnClick => text("fastn_dom.Event.Click"),
+            fastn_js::Event::OnMouseEnter => text("fastn_dom.Event.MouseEnter"),
+            fastn_js::Event::OnMouseLeave => text("fastn_dom.Event.MouseLeave"),
+            fastn_js::Event::OnClickOutside => text("fastn_dom.Event.ClickOutside"),
+            fastn_js::Event::OnGlobalKey(keys) => {
+                text(format!("fastn_dom.Event.GlobalKey({})", strings_to_js(keys)).as_str())
+            }
+            fastn_js::Event::OnGlobalKeySeq(keys) => {
+                text(format!("fastn_dom.Event.GlobalKeySeq({})", strings_to_js(keys)).as_str())
+            }
+            fastn_js::Event::OnInput => text("fastn_dom.Event.Input"),
+            fastn_js::Event::OnChange => text("fastn_dom.Event.Change"),
+            fastn_js::Event::OnBlur => text("fastn_dom.Event.Blur"),
+            fastn_js::Event::OnFocus => text("fastn_dom.Event.Focus"),
+            fastn_js::Event::OnSubmit => text("fastn_dom.Event.Submit"),
+            fastn_js::Event::OnPlay => text("fastn_dom.Event.Play"),
+            fastn_js::Event::OnPause => text("fastn_dom.Event.Pause"),
+            fastn_js::Event::OnEnded => text("fastn_dom.Event.Ended"),
+            fastn_js::Event::OnTimeUpdate => text("fastn_dom.Event.TimeUpdate"),
+            fastn_js::Event::OnRivePlay(timeline) => {
+                text(format!("fastn_dom.Event.RivePlay(\"{timeline}\")").as_str())
+            }
+            fastn_js::Event::OnRiveStateChange(state) => {
+                text(format!("fastn_dom.Event.RiveStateChange(\"{state}\")").as_str())
+            }
+            fastn_js::Event::OnRivePause(timeline) => {
+                text(format!("fastn_dom.Event.RivePause(\"{timeline}\")").as_str())
+            }
         }
     }
 }
 
 impl fastn_js::Function {
     pub fn to_js(&self) -> pretty::RcDoc<'static> {
-        text(format!("{}(", fastn_js::utils::name_to_js(self.name.as_str())).as_str())
-            .append(pretty::RcDoc::intersperse(
-                self.parameters.iter().map(|v| v.to_js()),
-                comma().append(space()),
-            ))
-            .append(text(");"))
+        text(
+            format!(
+                "{}(",
+                fastn_js::utils::function_name_to_js(self.name.as_str())
+            )
+            .as_str(),
+        )
+        .append(pretty::RcDoc::intersperse(
+            self.parameters.iter().map(|v| v.to_js()),
+            comma().append(space()),
+        ))
+        .append(text(");"))
     }
 }
 
 impl fastn_js::ElementKind {
-    pub fn to_js(&self) -> &'static str {
+    pub fn to_js(&self) -> String {
         match self {
-            fastn_js::ElementKind::Row => "fastn_dom.ElementKind.Row",
-            fastn_js::ElementKind::Column => "fastn_dom.ElementKind.Column",
-            fastn_js::ElementKind::Integer => "fastn_dom.ElementKind.Integer",
-            fastn_js::ElementKind::Decimal => "fastn_dom.ElementKind.Decimal",
-            fastn_js::ElementKind::Boolean => "fastn_dom.ElementKind.Boolean",
-            fastn_js::ElementKind::Text => "fastn_dom.ElementKind.Text",
-            fastn_js::ElementKind::Image => "fastn_dom.ElementKind.Image",
-            fastn_js::ElementKind::IFrame => "fastn_dom.ElementKind.IFrame",
+            fastn_js::ElementKind::Row => "fastn_dom.ElementKind.Row".to_string(),
+            fastn_js::ElementKind::Column => "fastn_dom.ElementKind.Column".to_string(),
+            fastn_js::ElementKind::Container => "fastn_dom.ElementKind.Container".to_string(),
+            fastn_js::ElementKind::Grid => "fastn_dom.ElementKind.Grid".to_string(),
+            fastn_js::ElementKind::Integer => "fastn_dom.ElementKind.Integer".to_string(),
+            fastn_js::ElementKind::Decimal => "fastn_dom.ElementKind.Decimal".to_string(),
+            fastn_js::ElementKind::Boolean => "fastn_dom.ElementKind.Boolean".to_string(),
+            fastn_js::ElementKind::Text => "fastn_dom.ElementKind.Text".to_string(),
+            fastn_js::ElementKind::Image => "fastn_dom.ElementKind.Image".to_string(),
+            fastn_js::ElementKind::IFrame => "fastn_dom.ElementKind.IFrame".to_string(),
+            fastn_js::ElementKind::Code => "fastn_dom.ElementKind.Code".to_string(),
+            fastn_js::ElementKind::TextInput => "fastn_dom.ElementKind.TextInput".to_string(),
+            fastn_js::ElementKind::TextArea => "fastn_dom.ElementKind.TextArea".to_string(),
+            fastn_js::ElementKind::CheckBox => "fastn_dom.ElementKind.CheckBox".to_string(),
+            fastn_js::ElementKind::Select => "fastn_dom.ElementKind.Select".to_string(),
+            fastn_js::ElementKind::RadioGroup => "fastn_dom.ElementKind.RadioGroup".to_string(),
+            fastn_js::ElementKind::Form => "fastn_dom.ElementKind.Form".to_string(),
+            fastn_js::ElementKind::Video => "fastn_dom.ElementKind.Video".to_string(),
+            fastn_js::ElementKind::Audio => "fastn_dom.ElementKind.Audio".to_string(),
+            fastn_js::ElementKind::Svg => "fastn_dom.ElementKind.Svg".to_string(),
+            fastn_js::ElementKind::Icon => "fastn_dom.ElementKind.Icon".to_string(),
+            fastn_js::ElementKind::Table => "fastn_dom.ElementKind.Table".to_string(),
+            fastn_js::ElementKind::Dialog => "fastn_dom.ElementKind.Dialog".to_string(),
+            fastn_js::ElementKind::Popover => "fastn_dom.ElementKind.Popover".to_string(),
+            fastn_js::ElementKind::Tooltip => "fastn_dom.ElementKind.Tooltip".to_string(),
+            fastn_js::ElementKind::Rive => "fastn_dom.ElementKind.Rive".to_string(),
+            fastn_js::ElementKind::Document => "fastn_dom.ElementKind.Document".to_string(),
+            fastn_js::ElementKind::WebComponent(name, arguments) => {
+                use itertools::Itertools;
+
+                format!(
+                    "fastn_dom.ElementKind.WebComponent(\"{}\", {{{}}})",
+                    name,
+                    arguments
+                        .iter()
+                        .map(|(k, v)| format!("\"{}\": {}", k, v.to_js()))
+                        .join(", ")
+                )
+            }
         }
     }
 }
 
+fn strings_to_js(strings: &[String]) -> String {
+    use itertools::Itertools;
+
+    format!(
+        "[{}]",
+        strings.iter().map(|v| format!("\"{}\"", v)).join(", ")
+    )
+}
+
 impl fastn_js::ComponentStatement {
     pub fn to_js(&self) -> pretty::RcDoc<'static> {
         match self {
@@ -119,22 +208,43 @@ impl fastn_js::ComponentStatement {
             fastn_js::ComponentStatement::MutableList(ml) => ml.to_js(),
             fastn_js::ComponentStatement::ForLoop(fl) => fl.to_js(),
             fastn_js::ComponentStatement::RecordInstance(ri) => ri.to_js(),
+            fastn_js::ComponentStatement::ExtendInherited {
+                inherited,
+                statements,
+            } => text("(function (inherited) {")
+                .append(
+                    pretty::RcDoc::intersperse(
+                        statements.iter().map(|v| v.to_js()),
+                        pretty::RcDoc::softline(),
+                    )
+                    .group(),
+                )
+                .append(text(format!("}})({});", inherited.to_js()).as_str())),
         }
     }
 }
 
 impl fastn_js::InstantiateComponent {
     pub fn to_js(&self) -> pretty::RcDoc<'static> {
+        let component = match &self.component {
+            fastn_js::InstantiateComponentData::Name(name) => fastn_js::utils::name_to_js(name),
+            fastn_js::InstantiateComponentData::Definition(value) => {
+                format!("fastn_utils.getComponent({})", value.to_js())
+            }
+        };
         pretty::RcDoc::text(format!(
             "{}{}(",
             if self.should_return { "return " } else { "" },
-            fastn_js::utils::name_to_js(self.name.as_str())
+            component
         ))
         .append(pretty::RcDoc::text(self.parent.clone()))
         .append(comma().append(space()))
         .append(
             pretty::RcDoc::intersperse(
-                self.arguments.iter().map(|v| v.to_js()),
+                self.inherited
+                    .iter()
+                    .map(|v| v.to_js())
+                    .chain(self.arguments.iter().map(|v| v.to_js())),
                 comma().append(space()),
             )
             .group(),
@@ -143,6 +253,20 @@ impl fastn_js::InstantiateComponent {
     }
 }
 
+impl fastn_js::Inherited {
+    /// `inherited`, extended with the values and defaults, if any.
+    pub fn to_js(&self) -> String {
+        if self.values.is_empty() && self.defaults.is_empty() {
+            return "inherited".to_string();
+        }
+        format!(
+            "inherited.extend({}, {})",
+            fastn_js::property::fields_to_js(&self.values),
+            fastn_js::property::fields_to_js(&self.defaults)
+        )
+    }
+}
+
 impl fastn_js::ConditionalComponent {
     pub fn to_js(&self) -> pretty::RcDoc<'static> {
         text(
@@ -160,7 +284,7 @@ impl fastn_js::ConditionalComponent {
             pretty::RcDoc::intersperse(
                 self.deps
                     .iter()
-                    .map(|v| text(fastn_js::utils::name_to_js(v).as_str())),
+                    .map(|v| text(fastn_js::utils::reference_to_js(v).as_str())),
                 comma().append(space()),
             )
             .group(),
@@ -208,7 +332,7 @@ impl fastn_js::ForLoop {
         .append(text(self.parent.as_str()))
         .append(comma())
         .append(space())
-        .append(text("function (root, item, index) {"))
+        .append(text("function (root, item, index, key) {"))
         .append(
             pretty::RcDoc::intersperse(
                 self.statements.iter().map(|v| v.to_js()),
@@ -218,7 +342,8 @@ impl fastn_js::ForLoop {
         )
         .append(text(
             format!(
-                "}}){};",
+                "}}{}){};",
+                if self.integer_keys { ", true" } else { "" },
                 if self.should_return {
                     ".getParent()"
                 } else {
@@ -680,6 +805,25 @@ impl ExpressionGenerator {
             return format!("[{}]", result.join(","));
         }
 
+        if let Some([condition, then, otherwise]) = self.if_arguments(node) {
+            // only the branch taken is evaluated, as in evalexpr
+            return format!(
+                "({} ? {} : {})",
+                self.to_js_(condition, false, arguments, false),
+                self.to_js_(then, false, arguments, false),
+                self.to_js_(otherwise, false, arguments, false)
+            );
+        }
+
+        if let Some((list, expression)) = self.item_expression_arguments(node) {
+            return format!(
+                "{}({},{})",
+                self.function_name(node.operator()).unwrap_or_default(),
+                self.to_js_(list, false, arguments, true),
+                self.item_function(&expression)
+            );
+        }
+
         if let Some(function_name) = self.function_name(node.operator()) {
             let mut result = vec![];
             if let Some(child) = node.children().first() {
@@ -766,6 +910,62 @@ impl ExpressionGenerator {
         }
     }
 
+    /// The list and the expression, given as a string, of a call to `ftd.map`, `ftd.filter` or
+    /// `ftd.sort_by`. The expression is evaluated for every item of the list.
+    pub fn item_expression_arguments<'a>(
+        &self,
+        node: &'a fastn_grammar::evalexpr::ExprNode,
+    ) -> Option<(
+        &'a fastn_grammar::evalexpr::ExprNode,
+        fastn_grammar::evalexpr::ExprNode,
+    )> {
+        let function_name = self.function_name(node.operator())?;
+        if !["ftd.map", "ftd.filter", "ftd.sort_by"].contains(&function_name.as_str()) {
+            return None;
+        }
+        let unwrap_root = |mut node: &'a fastn_grammar::evalexpr::ExprNode| {
+            while self.is_root(node.operator()) && node.children().len() == 1 {
+                node = node.children().first()?;
+            }
+            Some(node)
+        };
+        let child = unwrap_root(node.children().first()?)?;
+        let [list, expression] = child.children() else {
+            return None;
+        };
+        if !self.is_tuple(child.operator()) {
+            return None;
+        }
+        match unwrap_root(expression)?.operator() {
+            fastn_grammar::evalexpr::Operator::Const {
+                value: fastn_grammar::evalexpr::Value::String(expression),
+            } => Some((
+                list,
+                fastn_grammar::evalexpr::build_operator_tree(expression).ok()?,
+            )),
+            _ => None,
+        }
+    }
+
+    /// An item expression as a JavaScript function of the item, the fields of a record item are
+    /// read with `get`, so `item.name` is `item.get("name")`.
+    pub fn item_function(&self, expression: &fastn_grammar::evalexpr::ExprNode) -> String {
+        fn read_fields(node: &mut fastn_grammar::evalexpr::ExprNode) {
+            if let fastn_grammar::evalexpr::Operator::VariableIdentifierRead { identifier } =
+                node.operator_mut()
+            {
+                if identifier.starts_with("item.") {
+                    *identifier = fastn_js::utils::reference_to_js(identifier);
+                }
+            }
+            node.children_mut().iter_mut().for_each(read_fields);
+        }
+
+        let mut expression = expression.clone();
+        read_fields(&mut expression);
+        format!("function (item) {{{}}}", self.to_js(&expression).trim())
+    }
+
     pub fn has_value(&self, operator: &fastn_grammar::evalexpr::Operator) -> Option<String> {
         match operator {
             fastn_grammar::evalexpr::Operator::Const { .. }
@@ -786,6 +986,25 @@ impl ExpressionGenerator {
         }
     }
 
+    /// The condition and the branches of a call to the builtin `if(condition, a, b)`.
+    pub fn if_arguments<'a>(
+        &self,
+        node: &'a fastn_grammar::evalexpr::ExprNode,
+    ) -> Option<&'a [fastn_grammar::evalexpr::ExprNode]> {
+        if self.function_name(node.operator())?.ne("if") {
+            return None;
+        }
+        let mut child = node.children().first()?;
+        while self.is_root(child.operator()) && child.children().len() == 1 {
+            child = child.children().first()?;
+        }
+        if self.is_tuple(child.operator()) && child.children().len() == 3 {
+            Some(child.children())
+        } else {
+            None
+        }
+    }
+
     pub fn is_assignment(&self, operator: &fastn_grammar::evalexpr::Operator) -> bool {
         matches!(operator, fastn_grammar::evalexpr::Operator::Assign)
     }
@@ -809,7 +1028,11 @@ impl ExpressionGenerator {
 
     pub fn function_name(&self, operator: &fastn_grammar::evalexpr::Operator) -> Option<String> {
         if let fastn_grammar::evalexpr::Operator::FunctionIdentifier { identifier } = operator {
-            Some(identifier.to_string())
+            // the builtin functions of expressions are in `fastn_utils`
+            Some(match identifier.as_str() {
+                "len" => "fastn_utils.len".to_string(),
+                _ => identifier.to_string(),
+            })
         } else {
             None
         }
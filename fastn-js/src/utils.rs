@@ -1,5 +1,33 @@
 pub fn is_kernel(s: &str) -> bool {
-    ["ftd#text", "ftd#row", "ftd#column", "ftd#integer"].contains(&s)
+    [
+        "ftd#text",
+        "ftd#row",
+        "ftd#column",
+        "ftd#integer",
+        "ftd#decimal",
+        "ftd#boolean",
+        "ftd#container",
+        "ftd#grid",
+        "ftd#image",
+        "ftd#iframe",
+        "ftd#code",
+        "ftd#text-input",
+        "ftd#checkbox",
+        "ftd#select",
+        "ftd#radio-group",
+        "ftd#form",
+        "ftd#video",
+        "ftd#audio",
+        "ftd#svg",
+        "ftd#icon",
+        "ftd#table",
+        "ftd#dialog",
+        "ftd#popover",
+        "ftd#tooltip",
+        "ftd#rive",
+        "ftd#document",
+    ]
+    .contains(&s)
 }
 
 pub fn reference_to_js(s: &str) -> String {
@@ -27,6 +55,15 @@ pub(crate) fn get_doc_name_and_remaining(s: &str) -> (String, Option<String>) {
     }
 }
 
+/// The functions of the `ftd` module, like `ftd#toggle`, are defined on the `ftd` object of
+/// the runtime, so `ftd#toggle` becomes `ftd.toggle`.
+pub fn function_name_to_js(s: &str) -> String {
+    match s.strip_prefix("ftd#") {
+        Some(name) => format!("ftd.{}", name.replace('-', "_")),
+        None => name_to_js(s),
+    }
+}
+
 pub fn name_to_js(s: &str) -> String {
     let mut s = s.to_string();
     if s.as_bytes()[0].is_ascii_digit() {
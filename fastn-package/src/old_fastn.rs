@@ -95,4 +95,6 @@ pub struct PackageTemp {
     pub backend_headers: Option<Vec<BackendHeader>>,
     #[serde(rename = "icon")]
     pub icon: Option<ftd::ImageSrc>,
+    #[serde(rename = "backing-store")]
+    pub backing_store: Option<String>,
 }
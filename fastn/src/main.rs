@@ -78,6 +78,18 @@ async fn fastn_core_commands(matches: &clap::ArgMatches) -> fastn_core::Result<(
         Some((fastn_core::commands::sync_status::COMMAND, matches)) => {
             return fastn_core::commands::sync_status::handle_command(matches).await;
         }
+        Some((fastn_core::commands::git::COMMAND, matches)) => {
+            return fastn_core::commands::git::handle_command(matches).await;
+        }
+        Some((fastn_core::commands::translation::COMMAND, matches)) => {
+            return fastn_core::commands::translation::handle_command(matches).await;
+        }
+        Some((fastn_core::commands::lsp::COMMAND, matches)) => {
+            return fastn_core::commands::lsp::handle_command(matches).await;
+        }
+        Some((fastn_core::commands::fmt::COMMAND, matches)) => {
+            return fastn_core::commands::fmt::handle_command(matches).await;
+        }
         _ => {}
     }
 
@@ -250,8 +262,12 @@ async fn fastn_core_commands(matches: &clap::ArgMatches) -> fastn_core::Result<(
         let revive_it = resolve_conflict.get_flag("revive-it");
         let delete_it = resolve_conflict.get_flag("delete-it");
         let source = resolve_conflict.value_of_("file").unwrap(); // TODO: handle multiple files
+        let hunk = resolve_conflict
+            .value_of_("hunk")
+            .map(|v| v.parse::<usize>())
+            .transpose()?;
         return fastn_core::resolve_conflict(
-            &config, source, use_ours, use_theirs, print, revive_it, delete_it,
+            &config, source, use_ours, use_theirs, print, revive_it, delete_it, hunk,
         )
         .await;
     }
@@ -414,6 +430,7 @@ fn app(version: &'static str) -> clap::Command {
                 .arg(clap::arg!(--"revive-it" "Revive the file"))
                 .arg(clap::arg!(--"delete-it" "Delete the file"))
                 .arg(clap::arg!(--"print" "Print the file to stdout"))
+                .arg(clap::arg!(--hunk <HUNK> "Resolve only this conflicting hunk (starting from 1)"))
                 .arg(clap::arg!(file: <FILE> "The file to resolve the conflict for"))
                 .hide(true) // hidden since the feature is not being released yet.
         )
@@ -437,6 +454,10 @@ fn app(version: &'static str) -> clap::Command {
                 .hide(true) // hidden since the feature is not being released yet.
         )
         .subcommand(fastn_core::commands::stop_tracking::command())
+        .subcommand(fastn_core::commands::git::command())
+        .subcommand(fastn_core::commands::translation::command())
+        .subcommand(fastn_core::commands::lsp::command())
+        .subcommand(fastn_core::commands::fmt::command())
         .subcommand(sub_command::serve())
         .subcommand(sub_command::publish_static())
 }
@@ -37,7 +37,17 @@ window.ftd = (function () {
                             obj_value = null;
                             obj_checked = null;
                         }
-                        let value = resolve_reference(function_argument.reference, ftd_data[id], obj_value, obj_checked);
+                        if (obj_value === undefined && !!evt && !!evt.target) {
+                            // the radio button that changed in a radio group
+                            obj_value = evt.target.value;
+                        }
+                        let obj_form = null;
+                        if (function_argument.reference === "FORM") {
+                            obj_form = window.ftd.utils.form_values(obj);
+                        }
+                        // the playback position of a video or an audio
+                        let obj_time = function_argument.reference === "TIME" ? obj.currentTime : null;
+                        let value = resolve_reference(function_argument.reference, ftd_data[id], obj_value, obj_checked, obj_form, obj_time);
                         if (!!function_argument.mutable) {
                             function_argument.value = value;
                             function_arguments.push(function_argument);
@@ -65,6 +75,10 @@ window.ftd = (function () {
     exports.handle_event = function (evt, id, event, obj) {
         window.ftd.utils.reset_full_height();
         console_log(id, event);
+        if (!!evt && evt.type === "submit") {
+            // the form is handled by its `submit` event instead of being sent to its `action`
+            evt.preventDefault();
+        }
         let actions = JSON.parse(event);
         for (const action in actions) {
             handle_event(evt, id, actions[action], obj);
@@ -131,6 +145,14 @@ window.ftd = (function () {
     exports.is_empty = function (str) {
         return (!str || str.length === 0);
     };
+    // a value of an or-type defined in an ftd document is an object with its variant as the
+    // only key
+    exports.is_variant = function (value, variant) {
+        return !!value && variant in value;
+    };
+    exports.variant_value = function (value, variant) {
+        return value[variant];
+    };
     exports.set_list = function (array, value, args, data, id) {
         args["CHANGE_VALUE"] = false;
         window.ftd.clear(array, args, data, id);
@@ -466,10 +488,8 @@ window.ftd.post_init = function () {
     const COOKIE_DARK_MODE = "dark";
     const COOKIE_LIGHT_MODE = "light";
     const DARK_MODE_CLASS = "fpm-dark";
-    const MOBILE_CLASS = "ftd-mobile";
-    const XL_CLASS = "ftd-xl";
     const FTD_DEVICE = "ftd#device";
-    const FTD_BREAKPOINT_WIDTH = "ftd#breakpoint-width";
+    const FTD_BREAKPOINTS = "ftd#breakpoints";
     let last_device;
     function initialise_device() {
         last_device = get_device();
@@ -549,42 +569,22 @@ window.ftd.post_init = function () {
        document.getElementsByTagName('head')[0].appendChild(markdown_style_sheet);
    }*/
     function get_device() {
-        // not at all sure about this functions logic.
         let width = window.innerWidth;
-        // in future we may want to have more than one break points, and then
-        // we may also want the theme builders to decide where the breakpoints
-        // should go. we should be able to fetch fpm variables here, or maybe
-        // simply pass the width, user agent etc to fpm and let people put the
-        // checks on width user agent etc, but it would be good if we can
-        // standardize few breakpoints. or maybe we should do both, some
-        // standard breakpoints and pass the raw data.
-        // we would then rename this function to detect_device() which will
-        // return one of "desktop", "tablet", "mobile". and also maybe have
-        // another function detect_orientation(), "landscape" and "portrait" etc,
-        // and instead of setting `fpm#mobile: boolean` we set `fpm-ui#device`
-        // and `fpm#view-port-orientation` etc.
-        let mobile_breakpoint = window.ftd.get_value("main", FTD_BREAKPOINT_WIDTH + ".mobile");
-        if (width <= mobile_breakpoint) {
-            document.body.classList.add(MOBILE_CLASS);
-            if (document.body.classList.contains(XL_CLASS)) {
-                document.body.classList.remove(XL_CLASS);
+        // the breakpoints go from the narrowest to the widest, the device is the first one the
+        // window is not wider than, the body gets a `ftd-<device>` class for it
+        let breakpoints = window.ftd.get_value("main", FTD_BREAKPOINTS) || [];
+        let device = "desktop";
+        for (let breakpoint of breakpoints) {
+            let max_width = breakpoint["max-width"];
+            if (max_width === null || max_width === undefined || width <= max_width) {
+                device = breakpoint.name;
+                break;
             }
-            return "mobile";
         }
-        /*if (width > desktop_breakpoint) {
-            document.body.classList.add(XL_CLASS);
-            if (document.body.classList.contains(MOBILE_CLASS)) {
-                document.body.classList.remove(MOBILE_CLASS);
-            }
-            return "xl";
-        }*/
-        if (document.body.classList.contains(MOBILE_CLASS)) {
-            document.body.classList.remove(MOBILE_CLASS);
+        for (let breakpoint of breakpoints) {
+            document.body.classList.toggle("ftd-" + breakpoint.name, device === breakpoint.name);
         }
-        /*if (document.body.classList.contains(XL_CLASS)) {
-            document.body.classList.remove(XL_CLASS);
-        }*/
-        return "desktop";
+        return device;
     }
     /*
         ftd.dark-mode behaviour:
@@ -695,8 +695,319 @@ window.ftd.post_init = function () {
     function start_watching_dark_mode_system_preference() {
         window.matchMedia('(prefers-color-scheme: dark)').addEventListener("change", update_dark_mode);
     }
+    function inline_svg(wrapper) {
+        let src = wrapper.getAttribute("data-svg-src");
+        if (!src) {
+            return;
+        }
+        fetch(src)
+            .then(response => response.text())
+            .then(text => {
+            // the src may have changed while the svg was being fetched
+            if (wrapper.getAttribute("data-svg-src") === src) {
+                wrapper.innerHTML = text;
+            }
+        })
+            .catch(error => console_log("failed to fetch svg", src, error));
+    }
+    function update_icon(wrapper) {
+        let use = wrapper.querySelector("use");
+        if (!!use) {
+            use.setAttribute("href", `${wrapper.getAttribute("data-icon-sprite")}#${wrapper.getAttribute("data-icon-name")}`);
+        }
+    }
+    function initialise_svgs() {
+        // the build inlines the svgs into the page, only the ones it could not are fetched
+        document.querySelectorAll("[data-svg-src]").forEach(wrapper => {
+            if (!wrapper.firstElementChild) {
+                inline_svg(wrapper);
+            }
+        });
+        new MutationObserver(mutations => {
+            for (let mutation of mutations) {
+                let wrapper = mutation.target;
+                if (mutation.attributeName === "data-svg-src") {
+                    inline_svg(wrapper);
+                }
+                else {
+                    update_icon(wrapper);
+                }
+            }
+        }).observe(document.body, {
+            subtree: true,
+            attributeFilter: ["data-svg-src", "data-icon-name", "data-icon-sprite"],
+        });
+    }
+    function compare_cells(type, a, b) {
+        if (type === "number") {
+            return (parseFloat(a) || 0) - (parseFloat(b) || 0);
+        }
+        if (type === "date") {
+            return (Date.parse(a) || 0) - (Date.parse(b) || 0);
+        }
+        return a.localeCompare(b);
+    }
+    function initialise_table(table) {
+        let body = table.querySelector("tbody");
+        if (!body) {
+            return;
+        }
+        let tbody = body;
+        // the rows in their current order, the text of a cell is in its `data-sort`
+        let rows = Array.from(tbody.rows);
+        let text = (row, index) => (row.cells[index] && row.cells[index].getAttribute("data-sort")) || "";
+        let filter = table.querySelector(".ft_table_filter");
+        let page_size = parseInt(table.getAttribute("data-page-size") || "0") || 0;
+        let page = 0;
+        let nav = null;
+        if (page_size > 0) {
+            nav = document.createElement("nav");
+            nav.className = "ft_table_pages";
+            nav.setAttribute("aria-label", "Pages");
+            table.appendChild(nav);
+        }
+        let update = function () {
+            let query = !!filter ? filter.value.trim().toLowerCase() : "";
+            let matching = rows.filter(row => !query || Array.from(row.cells).some((_, index) => text(row, index).toLowerCase().includes(query)));
+            let pages = page_size > 0 ? Math.max(1, Math.ceil(matching.length / page_size)) : 1;
+            page = Math.min(page, pages - 1);
+            rows.forEach(row => row.hidden = true);
+            matching.forEach((row, index) => {
+                row.hidden = page_size > 0 && Math.floor(index / page_size) !== page;
+            });
+            if (!nav) {
+                return;
+            }
+            let button = (label, to, enabled) => {
+                let button = document.createElement("button");
+                button.type = "button";
+                button.textContent = label;
+                button.disabled = !enabled;
+                button.onclick = () => {
+                    page = to;
+                    update();
+                };
+                return button;
+            };
+            let current = document.createElement("span");
+            current.textContent = `Page ${page + 1} of ${pages}`;
+            nav.innerHTML = "";
+            nav.append(button("Previous", page - 1, page > 0), current, button("Next", page + 1, page < pages - 1));
+        };
+        table.querySelectorAll("th[aria-sort]").forEach(th => {
+            let button = th.querySelector("button");
+            if (!button) {
+                return;
+            }
+            button.addEventListener("click", () => {
+                let ascending = th.getAttribute("aria-sort") !== "ascending";
+                table.querySelectorAll("th[aria-sort]").forEach(other => other.setAttribute("aria-sort", "none"));
+                th.setAttribute("aria-sort", ascending ? "ascending" : "descending");
+                let type = th.getAttribute("data-type");
+                rows.sort((a, b) => {
+                    let order = compare_cells(type, text(a, th.cellIndex), text(b, th.cellIndex));
+                    return ascending ? order : -order;
+                });
+                rows.forEach(row => tbody.appendChild(row));
+                page = 0;
+                update();
+            });
+        });
+        if (!!filter) {
+            filter.addEventListener("input", () => {
+                page = 0;
+                update();
+            });
+        }
+        update();
+    }
+    function initialise_tables() {
+        document.querySelectorAll(".ft_table").forEach(initialise_table);
+    }
+    function overlay_focusable(overlay) {
+        return Array.from(overlay.querySelectorAll(
+            "a[href], button:not([disabled]), input:not([disabled]), select:not([disabled]), "
+            + "textarea:not([disabled]), [tabindex]:not([tabindex='-1'])"
+        ));
+    }
+    // places a popover or a tooltip on the `data-placement` side of its trigger, on the opposite
+    // side when there is no room there, and inside the window
+    function position_overlay(overlay, trigger) {
+        let gap = 8;
+        let target = trigger.getBoundingClientRect();
+        let rect = overlay.getBoundingClientRect();
+        let opposite = {top: "bottom", bottom: "top", left: "right", right: "left"};
+        let place = (side) => {
+            if (side === "top") {
+                return [target.top - rect.height - gap, target.left + (target.width - rect.width) / 2];
+            } else if (side === "bottom") {
+                return [target.bottom + gap, target.left + (target.width - rect.width) / 2];
+            } else if (side === "left") {
+                return [target.top + (target.height - rect.height) / 2, target.left - rect.width - gap];
+            }
+            return [target.top + (target.height - rect.height) / 2, target.right + gap];
+        };
+        let fits = (position) => position[0] >= 0 && position[1] >= 0
+            && position[0] + rect.height <= window.innerHeight
+            && position[1] + rect.width <= window.innerWidth;
+        let placement = overlay.getAttribute("data-placement") || "bottom";
+        let position = place(placement);
+        if (!fits(position) && !!opposite[placement] && fits(place(opposite[placement]))) {
+            position = place(opposite[placement]);
+        }
+        overlay.style.top = `${Math.max(gap, Math.min(position[0], window.innerHeight - rect.height - gap))}px`;
+        overlay.style.left = `${Math.max(gap, Math.min(position[1], window.innerWidth - rect.width - gap))}px`;
+    }
+    function initialise_overlay(overlay) {
+        let is_dialog = overlay.classList.contains("ft_dialog");
+        let is_tooltip = overlay.classList.contains("ft_tooltip");
+        let trigger_id = overlay.getAttribute("data-trigger");
+        let trigger = !!trigger_id ? document.getElementById(trigger_id) : null;
+        let is_trigger = (target) => !!trigger_id && target instanceof Element
+            && !!target.closest(`#${CSS.escape(trigger_id)}`);
+        let is_open = () => overlay.getAttribute("data-open") === "true";
+        let opened_at = 0;
+        let restore_focus = null;
+        // the `open` variable is the source of truth, the attribute follows it
+        let set_open = (open) => {
+            let variable = overlay.getAttribute("data-open-variable");
+            if (!!variable) {
+                window.ftd.set_bool_for_all(variable, open);
+            } else {
+                overlay.setAttribute("data-open", open ? "true" : "false");
+            }
+        };
+        let update = () => {
+            if (!!trigger && !is_dialog && !!overlay.id) {
+                if (is_tooltip) {
+                    trigger.setAttribute("aria-describedby", overlay.id);
+                } else {
+                    trigger.setAttribute("aria-expanded", is_open() ? "true" : "false");
+                    trigger.setAttribute("aria-controls", overlay.id);
+                }
+            }
+            let shown = overlay.matches(is_dialog ? ":modal" : ":popover-open");
+            if (is_open()) {
+                if (!shown) {
+                    opened_at = performance.now();
+                    if (!is_tooltip) {
+                        restore_focus = document.activeElement;
+                    }
+                    if (is_dialog) {
+                        let dialog = overlay;
+                        // a dialog open in the rendered page is not modal yet
+                        if (dialog.open) {
+                            dialog.close();
+                        }
+                        dialog.showModal();
+                    } else {
+                        overlay.showPopover();
+                        if (!is_tooltip) {
+                            let first = overlay_focusable(overlay)[0];
+                            if (!!first) {
+                                first.focus();
+                            }
+                        }
+                    }
+                }
+                if (!!trigger && !is_dialog) {
+                    position_overlay(overlay, trigger);
+                }
+                return;
+            }
+            if (is_dialog) {
+                let dialog = overlay;
+                if (dialog.open) {
+                    dialog.close();
+                }
+            } else if (shown) {
+                overlay.hidePopover();
+            }
+            let restore = restore_focus;
+            restore_focus = null;
+            if (shown && restore instanceof HTMLElement && restore.isConnected) {
+                restore.focus();
+            }
+        };
+        new MutationObserver(update).observe(overlay, {attributes: true, attributeFilter: ["data-open"]});
+        document.addEventListener("keydown", (e) => {
+            if (!is_open()) {
+                return;
+            }
+            if (e.key === "Escape" && overlay.getAttribute("data-close-on-escape") !== "false") {
+                e.preventDefault();
+                set_open(false);
+            } else if (e.key === "Tab" && is_dialog) {
+                // Tab does not leave an open dialog
+                let elements = overlay_focusable(overlay);
+                if (elements.length === 0) {
+                    e.preventDefault();
+                    return;
+                }
+                let first = elements[0];
+                let last = elements[elements.length - 1];
+                let outside = !overlay.contains(document.activeElement);
+                if (e.shiftKey && (document.activeElement === first || outside)) {
+                    e.preventDefault();
+                    last.focus();
+                } else if (!e.shiftKey && (document.activeElement === last || outside)) {
+                    e.preventDefault();
+                    first.focus();
+                }
+            }
+        });
+        let close_on_outside_click = overlay.getAttribute("data-close-on-outside-click") !== "false";
+        if (is_dialog) {
+            // the browser closes a modal dialog on Escape by itself, `open` decides instead
+            overlay.addEventListener("cancel", (e) => e.preventDefault());
+            // a click on the backdrop of a modal dialog has the dialog as its target
+            overlay.addEventListener("click", (e) => {
+                let rect = overlay.getBoundingClientRect();
+                let inside = rect.top <= e.clientY && e.clientY <= rect.bottom
+                    && rect.left <= e.clientX && e.clientX <= rect.right;
+                if (e.target === overlay && !inside && close_on_outside_click) {
+                    set_open(false);
+                }
+            });
+        } else if (is_tooltip) {
+            let hover = (open) => (e) => {
+                let related = e.relatedTarget;
+                if (is_trigger(e.target) && !is_trigger(related)) {
+                    set_open(open);
+                }
+            };
+            document.addEventListener("mouseover", hover(true));
+            document.addEventListener("mouseout", hover(false));
+            document.addEventListener("focusin", hover(true));
+            document.addEventListener("focusout", hover(false));
+        } else {
+            document.addEventListener("click", (e) => {
+                if (is_trigger(e.target)) {
+                    set_open(!is_open());
+                } else if (is_open() && close_on_outside_click && e.timeStamp >= opened_at
+                    && e.target instanceof Node && !overlay.contains(e.target)) {
+                    set_open(false);
+                }
+            });
+        }
+        let position = () => {
+            if (!!trigger && !is_dialog && is_open()) {
+                position_overlay(overlay, trigger);
+            }
+        };
+        window.addEventListener("resize", position);
+        window.addEventListener("scroll", position, true);
+        update();
+    }
+    function initialise_overlays() {
+        document.querySelectorAll(".ft_dialog, .ft_popover, .ft_tooltip")
+            .forEach(initialise_overlay);
+    }
     initialise_dark_mode();
     initialise_device();
+    initialise_svgs();
+    initialise_tables();
+    initialise_overlays();
     window.ftd.utils.set_full_height();
     // update_markdown_colors();
 };
@@ -814,13 +1125,19 @@ function set_data_value(data, name, value) {
         return initial_value;
     }
 }
-function resolve_reference(reference, data, value, checked) {
+function resolve_reference(reference, data, value, checked, form, time) {
     if (reference === "VALUE") {
         return value;
     }
     if (reference === "CHECKED") {
         return checked;
     }
+    if (reference === "FORM") {
+        return form;
+    }
+    if (reference === "TIME") {
+        return time;
+    }
     if (!!data[reference]) {
         return deepCopy(data[reference]);
     }
@@ -881,6 +1198,25 @@ window.ftd.utils.set_full_height = function () {
 window.ftd.utils.reset_full_height = function () {
     document.body.style.height = `100%`;
 };
+// the values of the named controls of a form, by name
+window.ftd.utils.form_values = function (form) {
+    let values = {};
+    for (let element of form.elements) {
+        if (!element.name || (element.type === "radio" && !element.checked)) {
+            continue;
+        }
+        if (element.type === "checkbox") {
+            values[element.name] = element.checked;
+        }
+        else if (element.type === "number" || element.type === "range") {
+            values[element.name] = element.value === "" ? null : Number(element.value);
+        }
+        else {
+            values[element.name] = element.value === "" ? null : element.value;
+        }
+    }
+    return values;
+};
 window.ftd.utils.get_event_key = function (event) {
     if (65 <= event.keyCode && event.keyCode <= 90) {
         return String.fromCharCode(event.keyCode).toLowerCase();
@@ -1050,6 +1386,69 @@ window.ftd.dependencies.eval_box_shadow = function (shadow, data) {
         return null;
     }
 };
+window.ftd.dependencies.eval_transform = function (transform, data) {
+    if (typeof transform === 'object' && !!transform) {
+        let functions = [];
+        if ("translate-x" in transform || "translate-y" in transform) {
+            let x = transform["translate-x"] || "0px";
+            let y = transform["translate-y"] || "0px";
+            functions.push(`translate(${x}, ${y})`);
+        }
+        if ("rotate" in transform)
+            functions.push(`rotate(${transform.rotate}deg)`);
+        if ("scale" in transform)
+            functions.push(`scale(${transform.scale})`);
+        if ("skew-x" in transform || "skew-y" in transform) {
+            let x = transform["skew-x"] || 0;
+            let y = transform["skew-y"] || 0;
+            functions.push(`skew(${x}deg, ${y}deg)`);
+        }
+        return functions.length === 0 ? "none" : functions.join(" ");
+    }
+    else {
+        return null;
+    }
+};
+window.ftd.dependencies.eval_transition = function (transition, data) {
+    if (typeof transition === 'object' && !!transition) {
+        return `${transition.property} ${transition.duration}s ${transition.easing} ${transition.delay}s`;
+    }
+    else {
+        return null;
+    }
+};
+window.ftd.dependencies.eval_animation = function (animation, data) {
+    if (typeof animation === 'object' && !!animation) {
+        let keyframes = animation.keyframes.map(function (keyframe) {
+            let declarations = [];
+            if ("opacity" in keyframe)
+                declarations.push(`opacity: ${keyframe.opacity};`);
+            if ("transform" in keyframe) {
+                let transform = window.ftd.dependencies.eval_transform(keyframe.transform, data);
+                declarations.push(`transform: ${transform};`);
+            }
+            return `${keyframe.at}% { ${declarations.join(" ")} }`;
+        }).join(" ");
+        // FNV-1a, the same name as the rule added when the page is built
+        let hash = 0x811c9dc5;
+        for (let i = 0; i < keyframes.length; i++) {
+            hash = Math.imul(hash ^ keyframes.charCodeAt(i), 0x01000193);
+        }
+        let name = `ftd-keyframes-${(hash >>> 0).toString(16).padStart(8, "0")}`;
+        if (!document.getElementById(name)) {
+            let style = document.createElement("style");
+            style.id = name;
+            style.innerHTML = `@keyframes ${name} { ${keyframes} }`;
+            document.head.appendChild(style);
+        }
+        let count = animation.infinite ? "infinite" : animation["iteration-count"];
+        let direction = animation.alternate ? "alternate" : "normal";
+        return `${name} ${animation.duration}s ${animation.easing} ${animation.delay}s ${count} ${direction}`;
+    }
+    else {
+        return null;
+    }
+};
 window.ftd.utils.add_extra_in_id = function (node_id) {
     let element = document.querySelector(`[data-id=\"${node_id}\"]`);
     if (element) {
@@ -7,6 +7,8 @@ pub struct ComponentDefinition {
     pub definition: Component,
     pub css: Option<String>,
     pub line_number: usize,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub type_parameters: Vec<String>,
 }
 
 pub const COMPONENT: &str = "component";
@@ -25,6 +27,7 @@ impl ComponentDefinition {
             definition,
             css,
             line_number,
+            type_parameters: vec![],
         }
     }
 
@@ -58,18 +61,26 @@ impl ComponentDefinition {
             );
         }
 
+        let (name, type_parameters) = ftd::ast::utils::get_name_and_type_parameters(
+            section.name.as_str(),
+            doc_id,
+            section.line_number,
+        )?;
+
         let (css, arguments) =
             ftd::ast::utils::get_css_and_fields_from_headers(&section.headers, doc_id)?;
 
         let definition = Component::from_p1(section.sub_sections.first().unwrap(), doc_id)?;
 
-        Ok(ComponentDefinition::new(
-            section.name.as_str(),
+        let mut component_definition = ComponentDefinition::new(
+            name.as_str(),
             arguments,
             definition,
             css,
             section.line_number,
-        ))
+        );
+        component_definition.type_parameters = type_parameters;
+        Ok(component_definition)
     }
 
     pub fn line_number(&self) -> usize {
@@ -87,6 +98,8 @@ pub struct Component {
     pub children: Vec<Component>,
     #[serde(rename = "line-number")]
     pub line_number: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub case: Option<ftd::ast::Case>,
 }
 
 impl Component {
@@ -107,6 +120,7 @@ impl Component {
             events,
             children,
             line_number,
+            case: None,
         }
     }
 
@@ -123,6 +137,14 @@ impl Component {
             );
         }
 
+        if ftd::ast::Match::is_match(section) {
+            return ftd::ast::parse_error(
+                "`match` can only be used at the top level or among the children of a component",
+                doc_id,
+                section.line_number,
+            );
+        }
+
         let properties = {
             let mut properties = vec![];
             for header in section.headers.0.iter() {
@@ -169,6 +191,10 @@ impl Component {
         let children = {
             let mut children = vec![];
             for subsection in section.sub_sections.iter() {
+                if ftd::ast::Match::is_match(subsection) {
+                    children.extend(ftd::ast::Match::components_from_p1(subsection, doc_id)?);
+                    continue;
+                }
                 children.push(Component::from_p1(subsection, doc_id)?);
             }
             children
@@ -206,6 +232,7 @@ impl Component {
                 events: vec![],
                 children: vec![],
                 line_number,
+                case: None,
             }),
             ftd::ast::VariableValue::List { value, line_number } => {
                 let mut children = vec![];
@@ -224,6 +251,7 @@ impl Component {
                     events: vec![],
                     children,
                     line_number,
+                    case: None,
                 })
             }
             ftd::ast::VariableValue::Record {
@@ -290,6 +318,7 @@ impl Component {
                     events,
                     children,
                     line_number,
+                    case: None,
                 })
             }
             ftd::ast::VariableValue::String {
@@ -308,6 +337,7 @@ impl Component {
                 events: vec![],
                 children: vec![],
                 line_number,
+                case: None,
             }),
         }
     }
@@ -498,6 +528,7 @@ impl Loop {
                 message: "Loop statement is blank".to_string(),
                 doc_id: doc_id.to_string(),
                 line_number: loop_header.get_line_number(),
+                span: None,
             })?;
 
         let (on, alias) = ftd::ast::utils::split_at(loop_statement.as_str(), ftd::ast::utils::AS);
@@ -625,6 +656,7 @@ impl Event {
             message: "Event cannot be empty".to_string(),
             doc_id: doc_id.to_string(),
             line_number: header.get_line_number(),
+            span: None,
         })?;
 
         Ok(Some(Event::new(
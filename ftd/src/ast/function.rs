@@ -54,6 +54,7 @@ impl Function {
             message: format!("Section is not function section, found `{:?}`", section),
             doc_id: doc_id.to_string(),
             line_number: section.line_number,
+            span: None,
         })?;
         let kind = ftd::ast::VariableKind::get_kind(
             section.kind.as_ref().unwrap().as_str(),
@@ -69,6 +70,7 @@ impl Function {
             ),
             doc_id: doc_id.to_string(),
             line_number: section.line_number,
+            span: None,
         })?;
         Ok(Function::new(
             function_name.as_str(),
@@ -116,6 +116,7 @@ impl Export {
                 message: "Expected the export thing name".to_string(),
                 doc_id: doc_id.to_string(),
                 line_number: header.get_line_number(),
+                span: None,
             })?;
             if value.eq(ftd::ast::constants::EVERYTHING) {
                 return Ok(Some(Export::All));
@@ -156,6 +157,7 @@ impl Exposing {
                 message: "Expected the exposing thing name".to_string(),
                 doc_id: doc_id.to_string(),
                 line_number: header.get_line_number(),
+                span: None,
             })?;
             if value.eq(ftd::ast::constants::EVERYTHING) {
                 return Ok(Some(Exposing::All));
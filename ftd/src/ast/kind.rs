@@ -703,6 +703,7 @@ impl Condition {
             message: "`if` condition must contain expression".to_string(),
             doc_id: doc_id.to_string(),
             line_number: condition.get_line_number(),
+            span: None,
         })?;
 
         Ok(Some(Condition::new(
@@ -36,11 +36,48 @@ impl AST {
     ) -> ftd::ast::Result<Vec<AST>> {
         let mut di_vec = vec![];
         for section in ignore_comments(sections) {
-            di_vec.push(AST::from_section(&section, doc_id)?);
+            let span = |line_number| section.span_at(line_number);
+            if ftd::ast::Match::is_match(&section) {
+                di_vec.extend(
+                    ftd::ast::Match::components_from_p1(&section, doc_id)
+                        .map_err(|e| e.or_span(span))?
+                        .into_iter()
+                        .map(AST::ComponentInvocation),
+                );
+                continue;
+            }
+            di_vec.push(AST::from_section(&section, doc_id).map_err(|e| e.or_span(span))?);
         }
         Ok(di_vec)
     }
 
+    /// Like `from_sections`, but converts every section it can and collects the errors of
+    /// the ones it can't instead of stopping at the first failure.
+    pub fn from_sections_with_errors(
+        sections: &[ftd::p1::Section],
+        doc_id: &str,
+    ) -> (Vec<AST>, Vec<ftd::ast::Error>) {
+        let mut di_vec = vec![];
+        let mut errors = vec![];
+        for section in ignore_comments(sections) {
+            let span = |line_number| section.span_at(line_number);
+            if ftd::ast::Match::is_match(&section) {
+                match ftd::ast::Match::components_from_p1(&section, doc_id) {
+                    Ok(components) => {
+                        di_vec.extend(components.into_iter().map(AST::ComponentInvocation))
+                    }
+                    Err(e) => errors.push(e.or_span(span)),
+                }
+                continue;
+            }
+            match AST::from_section(&section, doc_id) {
+                Ok(ast) => di_vec.push(ast),
+                Err(e) => errors.push(e.or_span(span)),
+            }
+        }
+        (di_vec, errors)
+    }
+
     pub fn name(&self) -> String {
         match self {
             AST::Import(i) => i.alias.clone(),
@@ -55,7 +92,71 @@ impl AST {
         }
     }
 
+    /// The type parameters of a generic record, or-type or component definition.
+    pub fn type_parameters(&self) -> &[String] {
+        match self {
+            AST::Record(r) => r.type_parameters.as_slice(),
+            AST::OrType(o) => o.type_parameters.as_slice(),
+            AST::ComponentDefinition(c) => c.type_parameters.as_slice(),
+            _ => &[],
+        }
+    }
+
+    /// The names of the definitions only the document that defines them can refer to: the ones
+    /// marked `private`, and if the document marks any definition `public`, every definition not
+    /// marked so, as the `public` definitions are then the export list of the document.
+    pub fn private_definitions(sections: &[ftd::p1::Section]) -> Vec<String> {
+        let definitions = ignore_comments(sections)
+            .into_iter()
+            .filter(|s| s.kind.is_some())
+            .map(|s| {
+                // the name of a function is written with its arguments: `-- integer sum(a, b):`
+                let name = s.name.split('(').next().unwrap_or_default().trim();
+                (name.to_string(), AST::access_modifier(&s))
+            })
+            .collect::<Vec<_>>();
+        let has_export_list = definitions
+            .iter()
+            .any(|(_, m)| matches!(m, Some(ftd::p1::header::AccessModifier::Public)));
+        definitions
+            .into_iter()
+            .filter(|(_, m)| match m {
+                Some(ftd::p1::header::AccessModifier::Public) => false,
+                Some(ftd::p1::header::AccessModifier::Private) => true,
+                None => has_export_list,
+            })
+            .map(|(name, _)| name)
+            .collect()
+    }
+
+    /// The access modifier written before the kind of a definition: `-- private component foo:`.
+    pub fn access_modifier(section: &ftd::p1::Section) -> Option<ftd::p1::header::AccessModifier> {
+        section
+            .kind
+            .as_ref()
+            .and_then(|k| ftd::p1::header::AccessModifier::get_kind_and_modifier(k).1)
+    }
+
     pub fn from_section(section: &ftd::p1::Section, doc_id: &str) -> ftd::ast::Result<AST> {
+        if AST::access_modifier(section).is_some() {
+            let mut section = section.to_owned();
+            section.kind = section
+                .kind
+                .map(|k| ftd::p1::header::AccessModifier::remove_modifiers(k.as_str()))
+                .filter(|k| !k.is_empty());
+            let ast = AST::from_section(&section, doc_id)?;
+            if !ast.is_definition() {
+                return ftd::ast::parse_error(
+                    format!(
+                        "`private` and `public` can only be used on definitions, found: `{}`",
+                        section.name
+                    ),
+                    doc_id,
+                    section.line_number,
+                );
+            }
+            return Ok(ast);
+        }
         Ok(if ftd::ast::Import::is_import(section) {
             AST::Import(ftd::ast::Import::from_p1(section, doc_id)?)
         } else if ftd::ast::Record::is_record(section) {
@@ -79,6 +180,7 @@ impl AST {
                 message: format!("Invalid AST, found: `{:?}`", section),
                 doc_id: doc_id.to_string(),
                 line_number: section.line_number,
+                span: None,
             });
         })
     }
@@ -232,6 +334,169 @@ impl AST {
     pub fn is_component(&self) -> bool {
         matches!(self, AST::ComponentInvocation(_))
     }
+
+    /// Records, or-types, functions, variables and (web) components, the things a document
+    /// defines for itself or for the documents importing it.
+    pub fn is_definition(&self) -> bool {
+        !matches!(
+            self,
+            AST::Import(_) | AST::VariableInvocation(_) | AST::ComponentInvocation(_)
+        )
+    }
+
+    /// The names this ast refers to, as written (`lib.card`, `lib.person` etc.): the components
+    /// and variables it invokes, the kinds it uses, the processors, and the names in its
+    /// references (`$lib.name`), conditions, loops, events and function bodies.
+    pub fn references(&self) -> std::collections::HashSet<String> {
+        let mut references = References::default();
+        match self {
+            AST::Import(_) => {}
+            AST::Record(r) => references.record(r),
+            AST::OrType(o) => {
+                for variant in o.variants.iter() {
+                    match variant {
+                        ftd::ast::OrTypeVariant::AnonymousRecord(r) => references.record(r),
+                        ftd::ast::OrTypeVariant::Regular(f)
+                        | ftd::ast::OrTypeVariant::Constant(f) => references.field(f),
+                    }
+                }
+            }
+            AST::VariableDefinition(v) => {
+                references.kind(&v.kind);
+                references.value(&v.value);
+                references.names_in(v.processor.as_deref());
+            }
+            AST::VariableInvocation(v) => {
+                references.name(v.name.as_str());
+                references.value(&v.value);
+                references.names_in(v.condition.as_ref().map(|c| c.expression.as_str()));
+                references.names_in(v.processor.as_deref());
+            }
+            AST::ComponentDefinition(c) => {
+                c.arguments.iter().for_each(|f| references.field(f));
+                references.component(&c.definition);
+            }
+            AST::ComponentInvocation(c) => references.component(c),
+            AST::FunctionDefinition(f) => {
+                references.kind(&f.kind);
+                f.arguments.iter().for_each(|a| references.field(a));
+                references.names_in(Some(f.definition.value.as_str()));
+            }
+            AST::WebComponentDefinition(w) => {
+                w.arguments.iter().for_each(|a| references.field(a));
+                references.reference(w.js.as_str());
+            }
+        }
+        references.0
+    }
+}
+
+/// The names collected by `AST::references`.
+#[derive(Default)]
+struct References(std::collections::HashSet<String>);
+
+impl References {
+    fn name(&mut self, name: &str) {
+        self.0.insert(name.to_string());
+    }
+
+    /// The names in an expression: `lib.count > 2`, `$ftd.toggle($a = $lib.flag)` etc.
+    fn names_in(&mut self, expression: Option<&str>) {
+        self.0.extend(
+            expression
+                .unwrap_or_default()
+                .split(|c: char| !(c.is_alphanumeric() || matches!(c, '-' | '_' | '.')))
+                .map(|n| n.trim_matches('.'))
+                .filter(|n| !n.is_empty())
+                .map(ToString::to_string),
+        );
+    }
+
+    /// A value written in a header, caption or body. Only references and function calls
+    /// (`$lib.name`, `$lib.f(a = 1)`) refer to names, anything else is text.
+    fn reference(&mut self, value: &str) {
+        let value = value.trim_start();
+        if value.starts_with(ftd::ast::utils::REFERENCE)
+            || value.starts_with(ftd::ast::utils::CLONE)
+        {
+            self.names_in(Some(value));
+        }
+    }
+
+    fn kind(&mut self, kind: &ftd::ast::VariableKind) {
+        self.names_in(Some(kind.kind.as_str()));
+    }
+
+    fn value(&mut self, value: &ftd::ast::VariableValue) {
+        match value {
+            ftd::ast::VariableValue::Optional { value, .. } => {
+                if let Some(value) = value.as_ref() {
+                    self.value(value);
+                }
+            }
+            ftd::ast::VariableValue::List { value, .. } => {
+                for v in value.iter() {
+                    self.name(v.key.as_str());
+                    self.value(&v.value);
+                }
+            }
+            ftd::ast::VariableValue::Record {
+                name,
+                caption,
+                headers,
+                body,
+                values,
+                ..
+            } => {
+                self.name(name.as_str());
+                if let Some(caption) = caption.as_ref() {
+                    self.value(caption);
+                }
+                for header in headers.0.iter() {
+                    self.value(&header.value);
+                    self.names_in(header.kind.as_deref());
+                    self.names_in(header.condition.as_deref());
+                }
+                if let Some(body) = body {
+                    self.reference(body.value.as_str());
+                }
+                for v in values.iter() {
+                    self.value(&v.value);
+                }
+            }
+            ftd::ast::VariableValue::String { value, .. } => self.reference(value.as_str()),
+        }
+    }
+
+    fn field(&mut self, field: &ftd::ast::Field) {
+        self.kind(&field.kind);
+        if let Some(ref value) = field.value {
+            self.value(value);
+        }
+    }
+
+    fn record(&mut self, record: &ftd::ast::Record) {
+        record.fields.iter().for_each(|f| self.field(f));
+    }
+
+    fn component(&mut self, component: &ftd::ast::Component) {
+        self.name(component.name.as_str());
+        for property in component.properties.iter() {
+            self.value(&property.value);
+            self.names_in(property.condition.as_deref());
+        }
+        if let Some(ref iteration) = component.iteration {
+            self.names_in(Some(iteration.on.as_str()));
+        }
+        self.names_in(component.condition.as_ref().map(|c| c.expression.as_str()));
+        for event in component.events.iter() {
+            self.names_in(Some(event.action.as_str()));
+        }
+        if let Some(ref case) = component.case {
+            self.names_in(Some(case.match_.subject.as_str()));
+        }
+        component.children.iter().for_each(|c| self.component(c));
+    }
 }
 
 /// Filters out commented parts from the parsed document.
@@ -9,6 +9,7 @@ mod import;
 mod kind;
 mod main;
 mod or_type;
+mod pattern;
 mod record;
 pub(crate) mod utils;
 mod variable;
@@ -24,6 +25,7 @@ pub use kind::{
 };
 pub use main::AST;
 pub use or_type::{OrType, OrTypeVariant};
+pub use pattern::{Case, Match, MatchArm};
 pub use record::{Field, Record};
 pub use variable::{VariableDefinition, VariableFlags, VariableInvocation};
 pub use web_component::WebComponentDefinition;
@@ -38,12 +40,38 @@ pub enum Error {
         message: String,
         doc_id: String,
         line_number: usize,
+        span: Option<ftd::diagnostic::Span>,
     },
 
     #[error("ParseBoolError: {}", _0)]
     ParseBool(#[from] std::str::ParseBoolError),
 }
 
+impl Error {
+    /// Sets the span of the error, unless it already has one, `f` is called with the line
+    /// number of the error.
+    pub(crate) fn or_span<F>(self, f: F) -> Error
+    where
+        F: FnOnce(usize) -> Option<ftd::diagnostic::Span>,
+    {
+        match self {
+            Error::P1(e) => Error::P1(e.or_span(f)),
+            Error::Parse {
+                message,
+                doc_id,
+                line_number,
+                span,
+            } => Error::Parse {
+                span: span.or_else(|| f(line_number)),
+                message,
+                doc_id,
+                line_number,
+            },
+            e => e,
+        }
+    }
+}
+
 pub type Result<T> = std::result::Result<T, Error>;
 
 pub fn parse_error<T, S1>(m: S1, doc_id: &str, line_number: usize) -> ftd::ast::Result<T>
@@ -54,5 +82,6 @@ where
         message: m.into(),
         doc_id: doc_id.to_string(),
         line_number,
+        span: None,
     })
 }
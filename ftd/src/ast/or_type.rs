@@ -3,6 +3,8 @@ pub struct OrType {
     pub name: String,
     pub variants: Vec<OrTypeVariant>,
     pub line_number: usize,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub type_parameters: Vec<String>,
 }
 
 pub const ORTYPE: &str = "or-type";
@@ -13,6 +15,7 @@ impl OrType {
             name: name.to_string(),
             variants,
             line_number,
+            type_parameters: vec![],
         }
     }
 
@@ -28,16 +31,19 @@ impl OrType {
                 section.line_number,
             );
         }
+        let (name, type_parameters) = ftd::ast::utils::get_name_and_type_parameters(
+            section.name.as_str(),
+            doc_id,
+            section.line_number,
+        )?;
         let mut variants = vec![];
         for section in section.sub_sections.iter() {
             variants.push(OrTypeVariant::from_p1(section, doc_id)?);
         }
 
-        Ok(OrType::new(
-            section.name.as_str(),
-            variants,
-            section.line_number,
-        ))
+        let mut or_type = OrType::new(name.as_str(), variants, section.line_number);
+        or_type.type_parameters = type_parameters;
+        Ok(or_type)
     }
 
     pub fn line_number(&self) -> usize {
@@ -118,9 +124,9 @@ impl OrTypeVariant {
     }
 
     pub(crate) fn is_constant(section: &ftd::p1::Section) -> bool {
-        section
-            .name
-            .starts_with(format!("{} ", ftd::ast::constants::CONSTANT).as_str())
+        section.kind.as_ref().map_or(false, |kind| {
+            kind.starts_with(format!("{} ", ftd::ast::constants::CONSTANT).as_str())
+        })
     }
 
     pub fn from_p1(section: &ftd::p1::Section, doc_id: &str) -> ftd::ast::Result<OrTypeVariant> {
@@ -130,16 +136,16 @@ impl OrTypeVariant {
             )?))
         } else if OrTypeVariant::is_constant(section) {
             let mut section = section.to_owned();
-            section.name = section
-                .name
-                .trim_start_matches(ftd::ast::constants::CONSTANT)
-                .trim()
-                .to_string();
+            section.kind = section.kind.map(|kind| {
+                kind.trim_start_matches(ftd::ast::constants::CONSTANT)
+                    .trim()
+                    .to_string()
+            });
             Ok(OrTypeVariant::new_constant(ftd::ast::Field::from_p1(
                 &section, doc_id,
             )?))
         } else {
-            Ok(OrTypeVariant::new_constant(ftd::ast::Field::from_p1(
+            Ok(OrTypeVariant::new_variant(ftd::ast::Field::from_p1(
                 section, doc_id,
             )?))
         }
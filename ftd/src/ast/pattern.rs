@@ -0,0 +1,354 @@
+/// A `match` over the variants of an or-type value, in a component:
+///
+/// ```ftd
+/// -- match: $shape
+///
+/// -- ftd.decimal: $c.radius
+/// case: circle(c)
+///
+/// -- ftd.text: Not a circle
+/// case: _
+///
+/// -- end: match
+/// ```
+///
+/// or as the body of a function:
+///
+/// ```ftd
+/// -- decimal area(s):
+/// shape s:
+///
+/// match s {
+///     circle(radius) => radius * radius * 3.14,
+///     square(side) => side * side,
+///     _ => 0.0,
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct Match {
+    pub subject: String,
+    pub arms: Vec<MatchArm>,
+    #[serde(rename = "line-number")]
+    pub line_number: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct MatchArm {
+    /// `None` for the wildcard `_`
+    pub variant: Option<String>,
+    pub binding: Option<String>,
+    #[serde(rename = "line-number")]
+    pub line_number: usize,
+}
+
+/// The children of `-- match:` are hoisted into its parent, each remembers the arm it belongs
+/// to.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct Case {
+    #[serde(rename = "match")]
+    pub match_: Match,
+    pub arm: usize,
+}
+
+pub const MATCH: &str = "match";
+pub const CASE: &str = "case";
+pub const WILDCARD: &str = "_";
+
+impl Match {
+    pub(crate) fn is_match(section: &ftd::p1::Section) -> bool {
+        section.kind.is_none() && section.name.eq(MATCH)
+    }
+
+    /// The children of `-- match:`, with the arm in their `case:` header. The binding of an arm
+    /// is renamed to the payload of its variant, `$c` in `case: circle(c)` of `-- match: $shape`
+    /// becomes `$shape.circle`.
+    pub(crate) fn components_from_p1(
+        section: &ftd::p1::Section,
+        doc_id: &str,
+    ) -> ftd::ast::Result<Vec<ftd::ast::Component>> {
+        let subject = match section.caption.as_ref() {
+            Some(caption) => caption.get_value(doc_id)?.unwrap_or_default(),
+            None => String::new(),
+        };
+        let subject = subject.trim().to_string();
+        if !subject.starts_with(ftd::ast::utils::REFERENCE) {
+            return ftd::ast::parse_error(
+                format!(
+                    "Expected a reference to an or-type value after `-- match:`, found: `{}`",
+                    subject
+                ),
+                doc_id,
+                section.line_number,
+            );
+        }
+        if let Some(header) = section.headers.0.first() {
+            return ftd::ast::parse_error(
+                format!("`match` takes no headers, found: `{}`", header.get_key()),
+                doc_id,
+                header.get_line_number(),
+            );
+        }
+
+        let mut arms = vec![];
+        let mut children = vec![];
+        for sub_section in section.sub_sections.iter() {
+            let mut sub_section = sub_section.to_owned();
+            let index = sub_section
+                .headers
+                .0
+                .iter()
+                .position(|h| h.get_key().eq(CASE) && h.get_kind().is_none())
+                .ok_or(ftd::ast::Error::Parse {
+                    message: format!(
+                        "Expected `case` header in `{}`, the children of `match` need one",
+                        sub_section.name
+                    ),
+                    doc_id: doc_id.to_string(),
+                    line_number: sub_section.line_number,
+                    span: None,
+                })?;
+            let header = sub_section.headers.0.remove(index);
+            let arm = MatchArm::from_pattern(
+                header.get_value(doc_id)?.unwrap_or_default().as_str(),
+                doc_id,
+                header.get_line_number(),
+            )?;
+            if let (Some(variant), Some(binding)) = (arm.variant.as_ref(), arm.binding.as_ref()) {
+                let payload = format!(
+                    "{}.{}",
+                    subject.trim_start_matches(ftd::ast::utils::REFERENCE),
+                    variant
+                );
+                rename_in_section(&mut sub_section, binding, payload.as_str());
+            }
+            arms.push(arm);
+            children.push(ftd::ast::Component::from_p1(&sub_section, doc_id)?);
+        }
+
+        let match_ = Match {
+            subject,
+            arms,
+            line_number: section.line_number,
+        };
+        Ok(children
+            .into_iter()
+            .enumerate()
+            .map(|(arm, mut child)| {
+                child.case = Some(Case {
+                    match_: match_.clone(),
+                    arm,
+                });
+                child
+            })
+            .collect())
+    }
+
+    /// The match and the expression of each arm, if the function body is a `match`.
+    pub(crate) fn from_function_body(
+        body: &str,
+        doc_id: &str,
+        line_number: usize,
+    ) -> ftd::ast::Result<Option<(Match, Vec<String>)>> {
+        let body = body.trim();
+        let rest = match body.strip_prefix(MATCH) {
+            Some(rest) if rest.starts_with(char::is_whitespace) => rest,
+            _ => return Ok(None),
+        };
+        let (subject, arms) = match rest.split_once('{') {
+            Some((subject, arms)) if arms.trim_end().ends_with('}') => {
+                (subject.trim(), arms.trim_end().trim_end_matches('}'))
+            }
+            _ => {
+                return ftd::ast::parse_error(
+                    "Expected `match <argument> { <pattern> => <expression>, .. }`",
+                    doc_id,
+                    line_number,
+                )
+            }
+        };
+
+        let mut match_arms = vec![];
+        let mut expressions = vec![];
+        for (index, arm) in split_arms(arms).into_iter().enumerate() {
+            let arm_line_number = line_number + index + 1;
+            let (pattern, expression) = arm.split_once("=>").ok_or(ftd::ast::Error::Parse {
+                message: format!("Expected `<pattern> => <expression>`, found: `{}`", arm),
+                doc_id: doc_id.to_string(),
+                line_number: arm_line_number,
+                span: None,
+            })?;
+            match_arms.push(MatchArm::from_pattern(pattern, doc_id, arm_line_number)?);
+            expressions.push(expression.trim().to_string());
+        }
+
+        Ok(Some((
+            Match {
+                subject: subject.to_string(),
+                arms: match_arms,
+                line_number,
+            },
+            expressions,
+        )))
+    }
+}
+
+impl MatchArm {
+    /// `circle(c)`, `circle` or `_`
+    pub(crate) fn from_pattern(
+        pattern: &str,
+        doc_id: &str,
+        line_number: usize,
+    ) -> ftd::ast::Result<MatchArm> {
+        let pattern = pattern.trim();
+        let (variant, binding) = match pattern.split_once('(') {
+            Some((variant, binding)) if binding.ends_with(')') => (
+                variant.trim(),
+                Some(binding.trim_end_matches(')').trim().to_string()),
+            ),
+            Some(_) => {
+                return ftd::ast::parse_error(
+                    format!("Expected `)` at the end of the pattern `{}`", pattern),
+                    doc_id,
+                    line_number,
+                )
+            }
+            None => (pattern, None),
+        };
+        let is_name = |s: &str| {
+            !s.is_empty()
+                && s.chars()
+                    .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+        };
+        if !is_name(variant) || !binding.as_deref().map_or(true, is_name) {
+            return ftd::ast::parse_error(
+                format!(
+                    "Expected a pattern like `circle(c)`, `circle` or `_`, found: `{}`",
+                    pattern
+                ),
+                doc_id,
+                line_number,
+            );
+        }
+        if variant.eq(WILDCARD) {
+            if binding.is_some() {
+                return ftd::ast::parse_error(
+                    format!("The wildcard `_` can't bind a value, found: `{}`", pattern),
+                    doc_id,
+                    line_number,
+                );
+            }
+            return Ok(MatchArm {
+                variant: None,
+                binding: None,
+                line_number,
+            });
+        }
+        Ok(MatchArm {
+            variant: Some(variant.to_string()),
+            binding,
+            line_number,
+        })
+    }
+
+    pub fn pattern(&self) -> String {
+        match (self.variant.as_ref(), self.binding.as_ref()) {
+            (Some(variant), Some(binding)) => format!("{}({})", variant, binding),
+            (Some(variant), None) => variant.to_string(),
+            (None, _) => WILDCARD.to_string(),
+        }
+    }
+}
+
+/// Splits the arms of a function body `match` at the commas and new lines outside strings
+/// and brackets.
+fn split_arms(arms: &str) -> Vec<String> {
+    let mut result = vec![];
+    let mut current = String::new();
+    let mut depth = 0;
+    let mut in_string = false;
+    for c in arms.chars() {
+        match c {
+            '"' => in_string = !in_string,
+            '(' | '[' | '{' if !in_string => depth += 1,
+            ')' | ']' | '}' if !in_string => depth -= 1,
+            ',' | '\n' if !in_string && depth == 0 => {
+                if !current.trim().is_empty() {
+                    result.push(current.trim().to_string());
+                }
+                current = String::new();
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    if !current.trim().is_empty() {
+        result.push(current.trim().to_string());
+    }
+    result
+}
+
+fn rename_in_section(section: &mut ftd::p1::Section, from: &str, to: &str) {
+    if let Some(caption) = section.caption.as_mut() {
+        rename_in_header(caption, from, to);
+    }
+    for header in section.headers.0.iter_mut() {
+        rename_in_header(header, from, to);
+    }
+    if let Some(body) = section.body.as_mut() {
+        body.value = rename_in_value(body.value.as_str(), from, to);
+    }
+    for sub_section in section.sub_sections.iter_mut() {
+        rename_in_section(sub_section, from, to);
+    }
+}
+
+fn rename_in_header(header: &mut ftd::p1::Header, from: &str, to: &str) {
+    let condition = match header {
+        ftd::p1::Header::KV(kv) => {
+            kv.value = kv
+                .value
+                .as_ref()
+                .map(|value| rename_in_value(value, from, to));
+            &mut kv.condition
+        }
+        ftd::p1::Header::Section(section) => {
+            for section in section.section.iter_mut() {
+                rename_in_section(section, from, to);
+            }
+            &mut section.condition
+        }
+        ftd::p1::Header::BlockRecordHeader(block) => {
+            block.caption = block
+                .caption
+                .as_ref()
+                .map(|value| rename_in_value(value, from, to));
+            block.body.0 = block
+                .body
+                .0
+                .as_ref()
+                .map(|value| rename_in_value(value, from, to));
+            for field in block.fields.iter_mut() {
+                rename_in_header(field, from, to);
+            }
+            &mut block.condition
+        }
+    };
+    *condition = condition
+        .as_ref()
+        .map(|condition| ftd::ast::utils::rename_reference(condition, from, to, true));
+}
+
+/// References start with `$`, expressions are in `{}`, other values are left as they are.
+fn rename_in_value(value: &str, from: &str, to: &str) -> String {
+    let trimmed = value.trim();
+    if trimmed.starts_with('{') && trimmed.ends_with('}') {
+        ftd::ast::utils::rename_reference(value, from, to, true)
+    } else if trimmed.starts_with(ftd::ast::utils::REFERENCE)
+        || trimmed.starts_with(ftd::ast::utils::CLONE)
+    {
+        ftd::ast::utils::rename_reference(value, from, to, false)
+    } else {
+        value.to_string()
+    }
+}
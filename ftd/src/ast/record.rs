@@ -3,6 +3,8 @@ pub struct Record {
     pub name: String,
     pub fields: Vec<Field>,
     pub line_number: usize,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub type_parameters: Vec<String>,
 }
 
 impl Record {
@@ -11,6 +13,7 @@ impl Record {
             name: name.to_string(),
             fields,
             line_number,
+            type_parameters: vec![],
         }
     }
 
@@ -30,12 +33,15 @@ impl Record {
             );
         }
 
-        let fields = get_fields_from_headers(&section.headers, doc_id)?;
-        Ok(Record::new(
+        let (name, type_parameters) = ftd::ast::utils::get_name_and_type_parameters(
             section.name.as_str(),
-            fields,
+            doc_id,
             section.line_number,
-        ))
+        )?;
+        let fields = get_fields_from_headers(&section.headers, doc_id)?;
+        let mut record = Record::new(name.as_str(), fields, section.line_number);
+        record.type_parameters = type_parameters;
+        Ok(record)
     }
 
     pub fn line_number(&self) -> usize {
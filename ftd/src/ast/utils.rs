@@ -24,6 +24,131 @@ pub fn get_import_alias(input: &str) -> (String, String) {
     (module.to_string(), module)
 }
 
+/// Splits `paginated<string, integer>` into `("paginated", ["string", "integer"], "")`.
+/// Commas inside nested angle brackets, as in `pair<map<string, integer>, string>`, don't
+/// split, and whatever follows the closing `>`, like `.ok` in `result<string>.ok`, is the third
+/// item. Returns `None` if `name` has no type arguments.
+pub fn split_type_arguments(name: &str) -> Option<(String, Vec<String>, String)> {
+    let (base, rest) = name.split_once('<')?;
+    let mut depth = 0;
+    let mut arguments = vec![];
+    let mut start = 0;
+    for (i, c) in rest.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' if depth > 0 => depth -= 1,
+            '>' => {
+                arguments.push(rest[start..i].trim().to_string());
+                if base.trim().is_empty() || arguments.iter().any(|v| v.is_empty()) {
+                    return None;
+                }
+                return Some((
+                    base.trim().to_string(),
+                    arguments,
+                    rest[i + 1..].to_string(),
+                ));
+            }
+            ',' if depth == 0 => {
+                arguments.push(rest[start..i].trim().to_string());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// The name and the type parameters of the generic definition `paginated<T>`, the type
+/// parameters have to be distinct identifiers.
+pub(crate) fn get_name_and_type_parameters(
+    name: &str,
+    doc_id: &str,
+    line_number: usize,
+) -> ftd::ast::Result<(String, Vec<String>)> {
+    let (name, type_parameters, remaining) = match split_type_arguments(name) {
+        Some(v) => v,
+        None if name.contains('<') => {
+            return ftd::ast::parse_error(
+                format!("Invalid type parameters in `{}`", name),
+                doc_id,
+                line_number,
+            )
+        }
+        None => return Ok((name.to_string(), vec![])),
+    };
+    if !remaining.trim().is_empty() {
+        return ftd::ast::parse_error(
+            format!(
+                "Unexpected `{}` after type parameters of `{}`",
+                remaining, name
+            ),
+            doc_id,
+            line_number,
+        );
+    }
+    for (i, type_parameter) in type_parameters.iter().enumerate() {
+        if !type_parameter
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+        {
+            return ftd::ast::parse_error(
+                format!(
+                    "Type parameter `{}` of `{}` is not a name",
+                    type_parameter, name
+                ),
+                doc_id,
+                line_number,
+            );
+        }
+        if type_parameters[..i].contains(type_parameter) {
+            return ftd::ast::parse_error(
+                format!(
+                    "Type parameter `{}` is repeated in `{}`",
+                    type_parameter, name
+                ),
+                doc_id,
+                line_number,
+            );
+        }
+    }
+    Ok((name, type_parameters))
+}
+
+/// Renames the reference `$<from>` in `text` to `$<to>`, keeping what follows it, so `$c.radius`
+/// becomes `$shape.circle.radius` for `from = "c"` and `to = "shape.circle"`. In an expression,
+/// like `{ c.radius > 1 }`, the names without `$` are renamed too, except inside strings.
+pub(crate) fn rename_reference(text: &str, from: &str, to: &str, is_expression: bool) -> String {
+    let is_name_char = |c: char| c.is_alphanumeric() || c == '-' || c == '_';
+    let mut result = String::new();
+    let mut rest = text;
+    let mut previous = None;
+    let mut in_string = false;
+    while let Some(c) = rest.chars().next() {
+        if c == '"' && is_expression {
+            in_string = !in_string;
+        }
+        let at_name_start = match previous {
+            Some('$') => true,
+            Some(p) => is_expression && !is_name_char(p) && p != '.',
+            None => is_expression,
+        };
+        if !in_string
+            && at_name_start
+            && rest.starts_with(from)
+            && !rest[from.len()..].starts_with(is_name_char)
+        {
+            result.push_str(to);
+            rest = &rest[from.len()..];
+            previous = to.chars().last();
+            continue;
+        }
+        result.push(c);
+        previous = Some(c);
+        rest = &rest[c.len_utf8()..];
+    }
+    result
+}
+
 pub(crate) fn is_variable_mutable(name: &str) -> bool {
     name.starts_with(REFERENCE)
 }
@@ -44,6 +169,7 @@ pub(crate) fn get_js_and_fields_from_headers(
                 message: "js statement is blank".to_string(),
                 doc_id: doc_id.to_string(),
                 line_number: header.get_line_number(),
+                span: None,
             })?);
             continue;
         }
@@ -64,6 +190,7 @@ pub(crate) fn get_css_and_fields_from_headers(
                 message: "css statement is blank".to_string(),
                 doc_id: doc_id.to_string(),
                 line_number: header.get_line_number(),
+                span: None,
             })?);
             continue;
         }
@@ -203,6 +203,7 @@ impl VariableFlags {
                             .to_string(),
                         doc_id: doc_id.to_string(),
                         line_number: kv.line_number,
+                        span: None,
                     })?
                     .parse::<bool>()?;
                 if value {
@@ -239,6 +240,7 @@ impl Processor {
                     message: "Processor statement is blank".to_string(),
                     doc_id: doc_id.to_string(),
                     line_number: processor_header.get_line_number(),
+                    span: None,
                 })?;
 
         Ok(Some(processor_statement))
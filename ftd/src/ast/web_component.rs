@@ -52,6 +52,7 @@ impl WebComponentDefinition {
                 message: "js statement not found".to_string(),
                 doc_id: doc_id.to_string(),
                 line_number: section.line_number,
+                span: None,
             })?,
             section.line_number,
         ))
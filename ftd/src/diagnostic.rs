@@ -0,0 +1,296 @@
+/// A location in a document: byte offsets into the source along with the (1 based) line and
+/// column of `start`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Deserialize, serde::Serialize)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+    pub line_number: usize,
+    pub column: usize,
+}
+
+impl Span {
+    /// The span of the whole of `line_number` of `source`, without leading or trailing
+    /// whitespace. This is used for the stages that only record the line of a problem.
+    pub fn line(source: &str, line_number: usize) -> Option<Span> {
+        let index = line_number.checked_sub(1)?;
+        let line_start = source
+            .split('\n')
+            .take(index)
+            .map(|v| v.len() + 1)
+            .sum::<usize>();
+        let line = source.split('\n').nth(index)?;
+        let trimmed = line.trim_start();
+        let offset = line.len() - trimmed.len();
+        Some(Span {
+            start: line_start + offset,
+            end: line_start + offset + trimmed.trim_end().len().max(1),
+            line_number,
+            column: line[..offset].chars().count() + 1,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+/// A single problem found in a document, independent of the stage (parser, ast or
+/// interpreter) that found it.
+///
+/// `span` is set when the stage that found the problem recorded where exactly it is (the p1
+/// parser does), else the problem is shown against the whole line.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub message: String,
+    pub doc_id: String,
+    pub line_number: usize,
+    pub span: Option<Span>,
+}
+
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.doc_id.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(
+                f,
+                "{}:{} -> {}",
+                self.doc_id, self.line_number, self.message
+            )
+        }
+    }
+}
+
+impl Diagnostic {
+    pub fn error<S1, S2>(message: S1, doc_id: S2, line_number: usize) -> Diagnostic
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        Diagnostic {
+            severity: Severity::Error,
+            message: message.into(),
+            doc_id: doc_id.into(),
+            line_number,
+            span: None,
+        }
+    }
+
+    pub fn warning<S1, S2>(message: S1, doc_id: S2, line_number: usize) -> Diagnostic
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        Diagnostic {
+            severity: Severity::Warning,
+            ..Diagnostic::error(message, doc_id, line_number)
+        }
+    }
+
+    pub fn with_span(mut self, span: Option<Span>) -> Diagnostic {
+        self.span = span;
+        self
+    }
+
+    /// The span of this diagnostic in `source`, the recorded one if it is in `source`, else
+    /// the whole line.
+    pub fn span(&self, source: &str) -> Option<Span> {
+        match self.span {
+            Some(span) if source.get(span.start..span.end).is_some() => Some(span),
+            _ => Span::line(source, self.line_number),
+        }
+    }
+
+    /// Renders the diagnostic the way rustc does: the message, the location, and when the
+    /// source of the document is available, the offending line with a caret under the span.
+    ///
+    /// ```text
+    /// error: key not found: `colr`
+    ///  --> foo:3:13
+    ///   |
+    /// 3 | -- ftd.text.colr: red
+    ///   |             ^^^^
+    /// ```
+    pub fn render(&self, source: Option<&str>) -> String {
+        let mut out = format!("{}: {}\n", self.severity, self.message);
+        let span = source.and_then(|s| self.span(s).map(|span| (s, span)));
+        let (source, span) = match span {
+            Some(v) => v,
+            None => {
+                if !self.doc_id.is_empty() {
+                    out.push_str(format!(" --> {}:{}\n", self.doc_id, self.line_number).as_str());
+                }
+                return out;
+            }
+        };
+
+        let gutter = " ".repeat(span.line_number.to_string().len());
+        let line = source
+            .split('\n')
+            .nth(span.line_number - 1)
+            .unwrap_or_default();
+        let width = source
+            .get(span.start..span.end)
+            .map(|v| v.chars().count())
+            .unwrap_or_default()
+            .max(1);
+        out.push_str(
+            format!(
+                "{gutter}--> {}:{}:{}\n{gutter} |\n{} | {}\n{gutter} | {}{}\n",
+                self.doc_id,
+                span.line_number,
+                span.column,
+                span.line_number,
+                line,
+                " ".repeat(span.column - 1),
+                "^".repeat(width),
+            )
+            .as_str(),
+        );
+        out
+    }
+}
+
+/// Renders all the `diagnostics`, `source` is called with the doc id of each diagnostic and
+/// returns the source of that document if it is available.
+pub fn render<'a, F>(diagnostics: &[Diagnostic], source: F) -> String
+where
+    F: Fn(&str) -> Option<&'a str>,
+{
+    use itertools::Itertools;
+
+    diagnostics
+        .iter()
+        .map(|d| d.render(source(d.doc_id.as_str())))
+        .join("\n")
+}
+
+impl ftd::p1::Error {
+    pub fn to_diagnostic(&self) -> Diagnostic {
+        match self {
+            ftd::p1::Error::SectionNotFound {
+                doc_id,
+                line_number,
+                span,
+            } => Diagnostic::error("expected a section: `-- <name>:`", doc_id, *line_number)
+                .with_span(*span),
+            ftd::p1::Error::MoreThanOneCaption {
+                doc_id,
+                line_number,
+                span,
+            } => Diagnostic::error("more than one caption", doc_id, *line_number).with_span(*span),
+            ftd::p1::Error::ParseError {
+                message,
+                doc_id,
+                line_number,
+                span,
+            } => Diagnostic::error(message, doc_id, *line_number).with_span(*span),
+            ftd::p1::Error::MoreThanOneHeader {
+                key,
+                doc_id,
+                line_number,
+                span,
+            } => Diagnostic::error(
+                format!("more than one header for key `{}`", key),
+                doc_id,
+                *line_number,
+            )
+            .with_span(*span),
+            ftd::p1::Error::HeaderNotFound {
+                key,
+                doc_id,
+                line_number,
+                span,
+            } => Diagnostic::error(
+                format!("header not found for key `{}`", key),
+                doc_id,
+                *line_number,
+            )
+            .with_span(*span),
+            ftd::p1::Error::ForbiddenUsage {
+                message,
+                doc_id,
+                line_number,
+            } => Diagnostic::error(
+                format!("forbidden usage: {}", message),
+                doc_id,
+                *line_number,
+            ),
+            ftd::p1::Error::NotFound {
+                doc_id,
+                line_number,
+                key,
+            } => Diagnostic::error(format!("key not found: `{}`", key), doc_id, *line_number),
+            ftd::p1::Error::MoreThanOneSubSections {
+                key,
+                doc_id,
+                line_number,
+            } => Diagnostic::error(
+                format!("got more than one sub-sections: `{}`", key),
+                doc_id,
+                *line_number,
+            ),
+            ftd::p1::Error::Serde { .. } | ftd::p1::Error::Syntect { .. } => {
+                Diagnostic::error(self.to_string(), "", 0)
+            }
+        }
+    }
+}
+
+impl ftd::ast::Error {
+    pub fn to_diagnostic(&self) -> Diagnostic {
+        match self {
+            ftd::ast::Error::P1(e) => e.to_diagnostic(),
+            ftd::ast::Error::Parse {
+                message,
+                doc_id,
+                line_number,
+                span,
+            } => Diagnostic::error(message, doc_id, *line_number).with_span(*span),
+            ftd::ast::Error::ParseBool(_) => Diagnostic::error(self.to_string(), "", 0),
+        }
+    }
+}
+
+impl ftd::interpreter::Error {
+    /// All the diagnostics this error carries, more than one if the interpreter recovered
+    /// from earlier errors.
+    pub fn to_diagnostics(&self) -> Vec<Diagnostic> {
+        match self {
+            ftd::interpreter::Error::P1Error(e) => vec![e.to_diagnostic()],
+            ftd::interpreter::Error::ASTError(e) => vec![e.to_diagnostic()],
+            ftd::interpreter::Error::InvalidKind {
+                doc_id,
+                line_number,
+                message,
+                span,
+            }
+            | ftd::interpreter::Error::ValueNotFound {
+                doc_id,
+                line_number,
+                message,
+                span,
+            }
+            | ftd::interpreter::Error::ParseError {
+                message,
+                doc_id,
+                line_number,
+                span,
+            } => vec![Diagnostic::error(message, doc_id, *line_number).with_span(*span)],
+            ftd::interpreter::Error::Diagnostics { diagnostics } => diagnostics.clone(),
+            _ => vec![Diagnostic::error(self.to_string(), "", 0)],
+        }
+    }
+}
@@ -123,8 +123,13 @@ impl DummyElement {
             })
         };
 
-        let children_elements = instruction
-            .get_children(&doc.itdoc())?
+        let children_instructions = ftd::executor::ExecuteDoc::get_children_instructions(
+            &instruction,
+            doc,
+            &component_definition,
+            inherited_variables,
+        )?;
+        let children_elements = children_instructions
             .into_iter()
             .enumerate()
             .map(|(idx, instruction)| {
@@ -3,6 +3,7 @@ pub enum Element {
     Row(Row),
     Column(Column),
     Container(ContainerElement),
+    Grid(Grid),
     Document(Document),
     Text(Text),
     Integer(Text),
@@ -11,10 +12,21 @@ pub enum Element {
     Image(Image),
     Code(Code),
     Iframe(Iframe),
-    TextInput(TextInput),
+    TextInput(Box<TextInput>),
     RawElement(RawElement),
     IterativeElement(IterativeElement),
     CheckBox(CheckBox),
+    Select(Select),
+    RadioGroup(RadioGroup),
+    Form(Form),
+    Video(Video),
+    Audio(Audio),
+    Svg(Svg),
+    Icon(Icon),
+    Table(Table),
+    Dialog(Box<Overlay>),
+    Popover(Box<Overlay>),
+    Tooltip(Box<Overlay>),
     WebComponent(WebComponent),
     Rive(Rive),
     Null { line_number: usize },
@@ -26,6 +38,7 @@ impl Element {
             Element::Row(r) => Some(&r.common),
             Element::Column(c) => Some(&c.common),
             Element::Container(e) => Some(&e.common),
+            Element::Grid(g) => Some(&g.common),
             Element::Text(t) => Some(&t.common),
             Element::Integer(i) => Some(&i.common),
             Element::Boolean(b) => Some(&b.common),
@@ -35,6 +48,15 @@ impl Element {
             Element::Iframe(i) => Some(&i.common),
             Element::TextInput(i) => Some(&i.common),
             Element::CheckBox(c) => Some(&c.common),
+            Element::Select(s) => Some(&s.common),
+            Element::RadioGroup(r) => Some(&r.common),
+            Element::Form(f) => Some(&f.common),
+            Element::Video(v) => Some(&v.common),
+            Element::Audio(a) => Some(&a.common),
+            Element::Svg(s) => Some(&s.common),
+            Element::Icon(i) => Some(&i.common),
+            Element::Table(t) => Some(&t.common),
+            Element::Dialog(o) | Element::Popover(o) | Element::Tooltip(o) => Some(&o.common),
             Element::Document(_) => None,
             Element::Null { .. } => None,
             Element::RawElement(_) => None,
@@ -48,6 +70,12 @@ impl Element {
         match self {
             Element::Row(r) => Some(&mut r.container.children),
             Element::Column(c) => Some(&mut c.container.children),
+            Element::Grid(g) => Some(&mut g.children),
+            Element::Form(f) => Some(&mut f.container.children),
+            Element::Table(t) => Some(&mut t.cells),
+            Element::Dialog(o) | Element::Popover(o) | Element::Tooltip(o) => {
+                Some(&mut o.container.children)
+            }
             Element::Document(d) => Some(&mut d.children),
             Element::RawElement(r) => Some(&mut r.children),
             _ => None,
@@ -63,6 +91,7 @@ impl Element {
             Element::Row(r) => r.common.line_number,
             Element::Column(c) => c.common.line_number,
             Element::Container(e) => e.common.line_number,
+            Element::Grid(g) => g.common.line_number,
             Element::Document(d) => d.line_number,
             Element::Text(t) => t.common.line_number,
             Element::Integer(i) => i.common.line_number,
@@ -75,6 +104,15 @@ impl Element {
             Element::RawElement(r) => r.line_number,
             Element::IterativeElement(i) => i.iteration.line_number,
             Element::CheckBox(c) => c.common.line_number,
+            Element::Select(s) => s.common.line_number,
+            Element::RadioGroup(r) => r.common.line_number,
+            Element::Form(f) => f.common.line_number,
+            Element::Video(v) => v.common.line_number,
+            Element::Audio(a) => a.common.line_number,
+            Element::Svg(s) => s.common.line_number,
+            Element::Icon(i) => i.common.line_number,
+            Element::Table(t) => t.common.line_number,
+            Element::Dialog(o) | Element::Popover(o) | Element::Tooltip(o) => o.common.line_number,
             Element::WebComponent(w) => w.line_number,
             Element::Rive(r) => r.common.line_number,
             Element::Null { line_number } => *line_number,
@@ -136,6 +174,18 @@ pub struct ContainerElement {
     pub display: ftd::executor::Value<Option<ftd::executor::Display>>,
 }
 
+#[derive(serde::Deserialize, Debug, Default, PartialEq, Clone, serde::Serialize)]
+pub struct Grid {
+    pub common: Common,
+    pub children: Vec<ftd::executor::Element>,
+    pub columns: ftd::executor::Value<Option<ftd::executor::GridTemplate>>,
+    pub rows: ftd::executor::Value<Option<ftd::executor::GridTemplate>>,
+    pub areas: ftd::executor::Value<Vec<String>>,
+    pub gap: ftd::executor::Value<Option<ftd::executor::Length>>,
+    pub row_gap: ftd::executor::Value<Option<ftd::executor::Length>>,
+    pub column_gap: ftd::executor::Value<Option<ftd::executor::Length>>,
+}
+
 #[derive(serde::Deserialize, Debug, Default, PartialEq, Clone, serde::Serialize)]
 pub struct HTMLData {
     pub title: ftd::executor::Value<Option<String>>,
@@ -153,6 +203,7 @@ pub struct HTMLData {
 pub struct Document {
     pub data: HTMLData,
     pub breakpoint_width: ftd::executor::Value<Option<ftd::executor::BreakpointWidth>>,
+    pub breakpoints: ftd::executor::Value<Vec<ftd::executor::Breakpoint>>,
     pub children: Vec<Element>,
     pub line_number: usize,
 }
@@ -205,6 +256,13 @@ pub struct Image {
 pub struct ImageSrc {
     pub light: ftd::executor::Value<String>,
     pub dark: ftd::executor::Value<String>,
+    /// `width`, `height`, `srcset`, `avif_srcset` and `placeholder` describe the image file,
+    /// `fastn build` sets them for the images of the package
+    pub width: Option<i64>,
+    pub height: Option<i64>,
+    pub srcset: Option<String>,
+    pub avif_srcset: Option<String>,
+    pub placeholder: Option<String>,
 }
 
 #[allow(dead_code)]
@@ -313,7 +371,35 @@ impl ImageSrc {
             }
         };
 
-        Ok(ImageSrc { light, dark })
+        let optional_integer = |name: &str| match values.get(name) {
+            Some(value) => value
+                .clone()
+                .resolve(&doc.itdoc(), line_number)?
+                .optional_integer(doc.name, line_number),
+            None => Ok(None),
+        };
+        let width = optional_integer("width")?;
+        let height = optional_integer("height")?;
+        let optional_string = |name: &str| match values.get(name) {
+            Some(value) => value
+                .clone()
+                .resolve(&doc.itdoc(), line_number)?
+                .optional_string(doc.name, line_number),
+            None => Ok(None),
+        };
+        let srcset = optional_string("srcset")?;
+        let avif_srcset = optional_string("avif-srcset")?;
+        let placeholder = optional_string("placeholder")?;
+
+        Ok(ImageSrc {
+            light,
+            dark,
+            width,
+            height,
+            srcset,
+            avif_srcset,
+            placeholder,
+        })
     }
 
     pub fn image_pattern() -> (String, bool) {
@@ -680,6 +766,11 @@ pub struct Common {
     pub event: Vec<Event>,
     pub is_dummy: bool,
     pub z_index: ftd::executor::Value<Option<i64>>,
+    pub grid_area: ftd::executor::Value<Option<String>>,
+    pub column_start: ftd::executor::Value<Option<i64>>,
+    pub column_span: ftd::executor::Value<Option<i64>>,
+    pub row_start: ftd::executor::Value<Option<i64>>,
+    pub row_span: ftd::executor::Value<Option<i64>>,
     pub left: ftd::executor::Value<Option<ftd::executor::Length>>,
     pub right: ftd::executor::Value<Option<ftd::executor::Length>>,
     pub top: ftd::executor::Value<Option<ftd::executor::Length>>,
@@ -748,9 +839,181 @@ pub struct Common {
     pub border_style_top: ftd::executor::Value<Option<ftd::executor::BorderStyle>>,
     pub border_style_bottom: ftd::executor::Value<Option<ftd::executor::BorderStyle>>,
     pub shadow: ftd::executor::Value<Option<ftd::executor::Shadow>>,
+    pub transform: ftd::executor::Value<Option<ftd::executor::Transform>>,
+    pub transition: ftd::executor::Value<Option<ftd::executor::Transition>>,
+    pub animation: ftd::executor::Value<Option<ftd::executor::Animation>>,
+    pub hover: Box<StateStyle>,
+    pub focus: Box<StateStyle>,
+    pub active: Box<StateStyle>,
+    pub accessibility: Box<Accessibility>,
     pub device: Option<ftd::executor::Device>,
 }
 
+impl Common {
+    /// The styles of the element for each of `ftd::interpreter::FTD_ELEMENT_STATES`.
+    pub fn state_styles(&self) -> [(&'static str, &StateStyle); 3] {
+        [
+            ("hover", &self.hover),
+            ("focus", &self.focus),
+            ("active", &self.active),
+        ]
+    }
+}
+
+/// The `color`, `background`, `border-color` and `shadow` of an element while it is hovered,
+/// focused or pressed, given as `color.hover`, `background.focus` etc.
+#[derive(serde::Deserialize, Debug, PartialEq, Default, Clone, serde::Serialize)]
+pub struct StateStyle {
+    pub color: ftd::executor::Value<Option<ftd::executor::Color>>,
+    pub background: ftd::executor::Value<Option<ftd::executor::Background>>,
+    pub border_color: ftd::executor::Value<Option<ftd::executor::Color>>,
+    pub shadow: ftd::executor::Value<Option<ftd::executor::Shadow>>,
+}
+
+impl StateStyle {
+    fn from_properties(
+        state: &str,
+        properties: &[ftd::interpreter::Property],
+        arguments: &[ftd::interpreter::Argument],
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+        inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
+        component_name: &str,
+    ) -> ftd::executor::Result<StateStyle> {
+        Ok(StateStyle {
+            color: ftd::executor::Color::optional_color(
+                properties,
+                arguments,
+                doc,
+                line_number,
+                format!("color.{}", state).as_str(),
+                inherited_variables,
+                component_name,
+            )?,
+            background: ftd::executor::Background::optional_background(
+                properties,
+                arguments,
+                doc,
+                line_number,
+                format!("background.{}", state).as_str(),
+                inherited_variables,
+                component_name,
+            )?,
+            border_color: ftd::executor::Color::optional_color(
+                properties,
+                arguments,
+                doc,
+                line_number,
+                format!("border-color.{}", state).as_str(),
+                inherited_variables,
+                component_name,
+            )?,
+            shadow: ftd::executor::Shadow::optional_shadow(
+                properties,
+                arguments,
+                doc,
+                line_number,
+                format!("shadow.{}", state).as_str(),
+                inherited_variables,
+                component_name,
+            )?,
+        })
+    }
+}
+
+/// The accessibility properties every component takes: `aria-label`, `aria-describedby`,
+/// `aria-hidden`, `aria-live`, `landmark`, `tab-index`, and `alt` for elements other than
+/// `ftd.image` (which renders `alt` itself).
+#[derive(serde::Deserialize, Debug, PartialEq, Default, Clone, serde::Serialize)]
+pub struct Accessibility {
+    pub alt: ftd::executor::Value<Option<String>>,
+    pub label: ftd::executor::Value<Option<String>>,
+    pub described_by: ftd::executor::Value<Option<String>>,
+    pub hidden: ftd::executor::Value<Option<bool>>,
+    pub live: ftd::executor::Value<Option<ftd::executor::AriaLive>>,
+    pub landmark: ftd::executor::Value<Option<ftd::executor::Landmark>>,
+    pub tab_index: ftd::executor::Value<Option<i64>>,
+}
+
+impl Accessibility {
+    fn from_properties(
+        properties: &[ftd::interpreter::Property],
+        arguments: &[ftd::interpreter::Argument],
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+        inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
+        component_name: &str,
+    ) -> ftd::executor::Result<Accessibility> {
+        let alt = if component_name.eq("ftd#image") {
+            Default::default()
+        } else {
+            ftd::executor::value::optional_string(
+                "alt",
+                component_name,
+                properties,
+                arguments,
+                doc,
+                line_number,
+            )?
+        };
+        Ok(Accessibility {
+            alt,
+            label: ftd::executor::value::optional_string(
+                "aria-label",
+                component_name,
+                properties,
+                arguments,
+                doc,
+                line_number,
+            )?,
+            described_by: ftd::executor::value::optional_string(
+                "aria-describedby",
+                component_name,
+                properties,
+                arguments,
+                doc,
+                line_number,
+            )?,
+            hidden: ftd::executor::value::optional_bool(
+                "aria-hidden",
+                component_name,
+                properties,
+                arguments,
+                doc,
+                line_number,
+                inherited_variables,
+            )?,
+            live: ftd::executor::AriaLive::optional_aria_live(
+                properties,
+                arguments,
+                doc,
+                line_number,
+                "aria-live",
+                inherited_variables,
+                component_name,
+            )?,
+            landmark: ftd::executor::Landmark::optional_landmark(
+                properties,
+                arguments,
+                doc,
+                line_number,
+                "landmark",
+                inherited_variables,
+                component_name,
+            )?,
+            tab_index: ftd::executor::value::optional_i64(
+                "tab-index",
+                component_name,
+                properties,
+                arguments,
+                doc,
+                line_number,
+                inherited_variables,
+            )?,
+        })
+    }
+}
+
 pub fn default_column() -> Column {
     ftd::executor::Column {
         container: Default::default(),
@@ -1316,6 +1579,92 @@ pub fn container_element_from_properties(
     })
 }
 
+#[allow(clippy::too_many_arguments)]
+pub fn grid_from_properties(
+    properties: &[ftd::interpreter::Property],
+    events: &[ftd::interpreter::Event],
+    arguments: &[ftd::interpreter::Argument],
+    condition: &Option<ftd::interpreter::Expression>,
+    doc: &mut ftd::executor::TDoc,
+    local_container: &[usize],
+    line_number: usize,
+    children: Vec<Element>,
+    inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
+    device: Option<ftd::executor::Device>,
+) -> ftd::executor::Result<Grid> {
+    let component_name = "ftd#grid";
+    let common = common_from_properties(
+        properties,
+        events,
+        arguments,
+        condition,
+        doc,
+        local_container,
+        line_number,
+        inherited_variables,
+        component_name,
+        device,
+    )?;
+    Ok(Grid {
+        common,
+        children,
+        columns: ftd::executor::GridTemplate::optional_grid_template(
+            properties,
+            arguments,
+            doc,
+            line_number,
+            "columns",
+            inherited_variables,
+            component_name,
+        )?,
+        rows: ftd::executor::GridTemplate::optional_grid_template(
+            properties,
+            arguments,
+            doc,
+            line_number,
+            "rows",
+            inherited_variables,
+            component_name,
+        )?,
+        areas: ftd::executor::value::string_list(
+            "areas",
+            component_name,
+            properties,
+            arguments,
+            doc,
+            line_number,
+            inherited_variables,
+        )?,
+        gap: ftd::executor::Length::optional_length(
+            properties,
+            arguments,
+            doc,
+            line_number,
+            "gap",
+            inherited_variables,
+            component_name,
+        )?,
+        row_gap: ftd::executor::Length::optional_length(
+            properties,
+            arguments,
+            doc,
+            line_number,
+            "row-gap",
+            inherited_variables,
+            component_name,
+        )?,
+        column_gap: ftd::executor::Length::optional_length(
+            properties,
+            arguments,
+            doc,
+            line_number,
+            "column-gap",
+            inherited_variables,
+            component_name,
+        )?,
+    })
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn rive_from_properties(
     properties: &[ftd::interpreter::Property],
@@ -1437,6 +1786,15 @@ pub fn document_from_properties(
             inherited_variables,
             "ftd#document",
         )?,
+        breakpoints: ftd::executor::Breakpoint::breakpoint_list(
+            properties,
+            arguments,
+            doc,
+            line_number,
+            "breakpoints",
+            inherited_variables,
+            "ftd#document",
+        )?,
         data: html_data_from_properties(properties, arguments, doc, line_number, "ftd#document")?,
         children,
         line_number,
@@ -1606,6 +1964,50 @@ pub fn common_from_properties(
             line_number,
             inherited_variables,
         )?,
+        grid_area: ftd::executor::value::optional_string(
+            "grid-area",
+            component_name,
+            properties,
+            arguments,
+            doc,
+            line_number,
+        )?,
+        column_start: ftd::executor::value::optional_i64(
+            "column-start",
+            component_name,
+            properties,
+            arguments,
+            doc,
+            line_number,
+            inherited_variables,
+        )?,
+        column_span: ftd::executor::value::optional_i64(
+            "column-span",
+            component_name,
+            properties,
+            arguments,
+            doc,
+            line_number,
+            inherited_variables,
+        )?,
+        row_start: ftd::executor::value::optional_i64(
+            "row-start",
+            component_name,
+            properties,
+            arguments,
+            doc,
+            line_number,
+            inherited_variables,
+        )?,
+        row_span: ftd::executor::value::optional_i64(
+            "row-span",
+            component_name,
+            properties,
+            arguments,
+            doc,
+            line_number,
+            inherited_variables,
+        )?,
         left: ftd::executor::Length::optional_length(
             properties,
             arguments,
@@ -2183,6 +2585,68 @@ pub fn common_from_properties(
             inherited_variables,
             component_name,
         )?,
+        transform: ftd::executor::Transform::optional_transform(
+            properties,
+            arguments,
+            doc,
+            line_number,
+            "transform",
+            inherited_variables,
+            component_name,
+        )?,
+        transition: ftd::executor::Transition::optional_transition(
+            properties,
+            arguments,
+            doc,
+            line_number,
+            "transition",
+            inherited_variables,
+            component_name,
+        )?,
+        animation: ftd::executor::Animation::optional_animation(
+            properties,
+            arguments,
+            doc,
+            line_number,
+            "animation",
+            inherited_variables,
+            component_name,
+        )?,
+        hover: Box::new(StateStyle::from_properties(
+            "hover",
+            properties,
+            arguments,
+            doc,
+            line_number,
+            inherited_variables,
+            component_name,
+        )?),
+        focus: Box::new(StateStyle::from_properties(
+            "focus",
+            properties,
+            arguments,
+            doc,
+            line_number,
+            inherited_variables,
+            component_name,
+        )?),
+        active: Box::new(StateStyle::from_properties(
+            "active",
+            properties,
+            arguments,
+            doc,
+            line_number,
+            inherited_variables,
+            component_name,
+        )?),
+        accessibility: Box::new(Accessibility::from_properties(
+            properties,
+            arguments,
+            doc,
+            line_number,
+            inherited_variables,
+            component_name,
+        )?),
     })
 }
 
@@ -2238,6 +2702,11 @@ pub struct TextInput {
     pub default_value: ftd::executor::Value<Option<String>>,
     pub type_: ftd::executor::Value<Option<ftd::executor::TextInputType>>,
     pub enabled: ftd::executor::Value<Option<bool>>,
+    pub min: ftd::executor::Value<Option<String>>,
+    pub max: ftd::executor::Value<Option<String>>,
+    pub step: ftd::executor::Value<Option<f64>>,
+    pub pattern: ftd::executor::Value<Option<String>>,
+    pub field: FormField,
     pub common: Common,
 }
 
@@ -2329,6 +2798,51 @@ pub fn text_input_from_properties(
         "ftd#text-input",
     )?;
 
+    let min = ftd::executor::value::optional_string(
+        "min",
+        "ftd#text-input",
+        properties,
+        arguments,
+        doc,
+        line_number,
+    )?;
+
+    let max = ftd::executor::value::optional_string(
+        "max",
+        "ftd#text-input",
+        properties,
+        arguments,
+        doc,
+        line_number,
+    )?;
+
+    let step = ftd::executor::value::optional_f64(
+        "step",
+        "ftd#text-input",
+        properties,
+        arguments,
+        doc,
+        line_number,
+    )?;
+
+    let pattern = ftd::executor::value::optional_string(
+        "pattern",
+        "ftd#text-input",
+        properties,
+        arguments,
+        doc,
+        line_number,
+    )?;
+
+    let field = form_field_from_properties(
+        properties,
+        arguments,
+        doc,
+        line_number,
+        inherited_variables,
+        "ftd#text-input",
+    )?;
+
     let common = common_from_properties(
         properties,
         events,
@@ -2350,6 +2864,11 @@ pub fn text_input_from_properties(
         common,
         type_,
         enabled,
+        min,
+        max,
+        step,
+        pattern,
+        field,
     })
 }
 
@@ -2357,6 +2876,7 @@ pub fn text_input_from_properties(
 pub struct CheckBox {
     pub checked: ftd::executor::Value<Option<bool>>,
     pub enabled: ftd::executor::Value<Option<bool>>,
+    pub field: FormField,
     pub common: Common,
 }
 
@@ -2426,6 +2946,15 @@ pub fn checkbox_from_properties(
         inherited_variables,
     )?;
 
+    let field = form_field_from_properties(
+        properties,
+        arguments,
+        doc,
+        line_number,
+        inherited_variables,
+        "ftd#checkbox",
+    )?;
+
     let common = common_from_properties(
         properties,
         events,
@@ -2442,6 +2971,1064 @@ pub fn checkbox_from_properties(
     Ok(CheckBox {
         checked,
         enabled,
+        field,
         common,
     })
 }
+
+/// The `name` and `required` of a control of an `ftd.form`.
+#[derive(serde::Deserialize, Debug, Default, PartialEq, Clone, serde::Serialize)]
+pub struct FormField {
+    pub name: ftd::executor::Value<Option<String>>,
+    pub required: ftd::executor::Value<Option<bool>>,
+}
+
+impl FormField {
+    pub fn required_pattern() -> (String, bool) {
+        ftd::executor::CheckBox::checked_pattern()
+    }
+}
+
+pub fn form_field_from_properties(
+    properties: &[ftd::interpreter::Property],
+    arguments: &[ftd::interpreter::Argument],
+    doc: &ftd::executor::TDoc,
+    line_number: usize,
+    inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
+    component_name: &str,
+) -> ftd::executor::Result<FormField> {
+    let name = ftd::executor::value::optional_string(
+        "name",
+        component_name,
+        properties,
+        arguments,
+        doc,
+        line_number,
+    )?;
+
+    let required = ftd::executor::value::optional_bool(
+        "required",
+        component_name,
+        properties,
+        arguments,
+        doc,
+        line_number,
+        inherited_variables,
+    )?;
+
+    Ok(FormField { name, required })
+}
+
+#[derive(serde::Deserialize, Debug, Default, PartialEq, Clone, serde::Serialize)]
+pub struct Select {
+    pub options: ftd::executor::Value<Vec<ftd::executor::SelectOption>>,
+    pub value: ftd::executor::Value<Option<String>>,
+    pub placeholder: ftd::executor::Value<Option<String>>,
+    pub enabled: ftd::executor::Value<Option<bool>>,
+    pub field: FormField,
+    pub common: Common,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn select_from_properties(
+    properties: &[ftd::interpreter::Property],
+    events: &[ftd::interpreter::Event],
+    arguments: &[ftd::interpreter::Argument],
+    condition: &Option<ftd::interpreter::Expression>,
+    doc: &mut ftd::executor::TDoc,
+    local_container: &[usize],
+    line_number: usize,
+    inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
+    device: Option<ftd::executor::Device>,
+) -> ftd::executor::Result<Select> {
+    let options = ftd::executor::SelectOption::option_list(
+        properties,
+        arguments,
+        doc,
+        line_number,
+        "options",
+        inherited_variables,
+        "ftd#select",
+    )?;
+
+    let value = ftd::executor::value::optional_string(
+        "value",
+        "ftd#select",
+        properties,
+        arguments,
+        doc,
+        line_number,
+    )?;
+
+    let placeholder = ftd::executor::value::optional_string(
+        "placeholder",
+        "ftd#select",
+        properties,
+        arguments,
+        doc,
+        line_number,
+    )?;
+
+    let enabled = ftd::executor::value::optional_bool(
+        "enabled",
+        "ftd#select",
+        properties,
+        arguments,
+        doc,
+        line_number,
+        inherited_variables,
+    )?;
+
+    let field = form_field_from_properties(
+        properties,
+        arguments,
+        doc,
+        line_number,
+        inherited_variables,
+        "ftd#select",
+    )?;
+
+    let common = common_from_properties(
+        properties,
+        events,
+        arguments,
+        condition,
+        doc,
+        local_container,
+        line_number,
+        inherited_variables,
+        "ftd#select",
+        device,
+    )?;
+
+    Ok(Select {
+        options,
+        value,
+        placeholder,
+        enabled,
+        field,
+        common,
+    })
+}
+
+#[derive(serde::Deserialize, Debug, Default, PartialEq, Clone, serde::Serialize)]
+pub struct RadioGroup {
+    pub options: ftd::executor::Value<Vec<ftd::executor::SelectOption>>,
+    pub value: ftd::executor::Value<Option<String>>,
+    pub enabled: ftd::executor::Value<Option<bool>>,
+    pub field: FormField,
+    pub common: Common,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn radio_group_from_properties(
+    properties: &[ftd::interpreter::Property],
+    events: &[ftd::interpreter::Event],
+    arguments: &[ftd::interpreter::Argument],
+    condition: &Option<ftd::interpreter::Expression>,
+    doc: &mut ftd::executor::TDoc,
+    local_container: &[usize],
+    line_number: usize,
+    inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
+    device: Option<ftd::executor::Device>,
+) -> ftd::executor::Result<RadioGroup> {
+    let options = ftd::executor::SelectOption::option_list(
+        properties,
+        arguments,
+        doc,
+        line_number,
+        "options",
+        inherited_variables,
+        "ftd#radio-group",
+    )?;
+
+    let value = ftd::executor::value::optional_string(
+        "value",
+        "ftd#radio-group",
+        properties,
+        arguments,
+        doc,
+        line_number,
+    )?;
+
+    let enabled = ftd::executor::value::optional_bool(
+        "enabled",
+        "ftd#radio-group",
+        properties,
+        arguments,
+        doc,
+        line_number,
+        inherited_variables,
+    )?;
+
+    let field = form_field_from_properties(
+        properties,
+        arguments,
+        doc,
+        line_number,
+        inherited_variables,
+        "ftd#radio-group",
+    )?;
+
+    let common = common_from_properties(
+        properties,
+        events,
+        arguments,
+        condition,
+        doc,
+        local_container,
+        line_number,
+        inherited_variables,
+        "ftd#radio-group",
+        device,
+    )?;
+
+    Ok(RadioGroup {
+        options,
+        value,
+        enabled,
+        field,
+        common,
+    })
+}
+
+#[derive(serde::Deserialize, Debug, Default, PartialEq, Clone, serde::Serialize)]
+pub struct Form {
+    pub action: ftd::executor::Value<Option<String>>,
+    pub method: ftd::executor::Value<Option<String>>,
+    pub container: Container,
+    pub common: Common,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn form_from_properties(
+    properties: &[ftd::interpreter::Property],
+    events: &[ftd::interpreter::Event],
+    arguments: &[ftd::interpreter::Argument],
+    condition: &Option<ftd::interpreter::Expression>,
+    doc: &mut ftd::executor::TDoc,
+    local_container: &[usize],
+    line_number: usize,
+    children: Vec<Element>,
+    inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
+    device: Option<ftd::executor::Device>,
+) -> ftd::executor::Result<Form> {
+    let action = ftd::executor::value::optional_string(
+        "action",
+        "ftd#form",
+        properties,
+        arguments,
+        doc,
+        line_number,
+    )?;
+
+    let method = ftd::executor::value::optional_string(
+        "method",
+        "ftd#form",
+        properties,
+        arguments,
+        doc,
+        line_number,
+    )?;
+
+    let common = common_from_properties(
+        properties,
+        events,
+        arguments,
+        condition,
+        doc,
+        local_container,
+        line_number,
+        inherited_variables,
+        "ftd#form",
+        device.clone(),
+    )?;
+
+    let container = container_from_properties(
+        properties,
+        arguments,
+        doc,
+        line_number,
+        children,
+        inherited_variables,
+        "ftd#form",
+        device,
+    )?;
+
+    Ok(Form {
+        action,
+        method,
+        container,
+        common,
+    })
+}
+
+/// An `ftd.dialog`, `ftd.popover` or `ftd.tooltip`. It is shown while `open` is true, a popover
+/// or tooltip next to the element with the id `trigger`.
+#[derive(serde::Deserialize, Debug, Default, PartialEq, Clone, serde::Serialize)]
+pub struct Overlay {
+    pub open: ftd::executor::Value<bool>,
+    pub close_on_escape: ftd::executor::Value<Option<bool>>,
+    pub close_on_outside_click: ftd::executor::Value<Option<bool>>,
+    pub trigger: ftd::executor::Value<Option<String>>,
+    pub placement: ftd::executor::Value<Option<ftd::executor::Placement>>,
+    pub container: Container,
+    pub common: Common,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn overlay_from_properties(
+    properties: &[ftd::interpreter::Property],
+    events: &[ftd::interpreter::Event],
+    arguments: &[ftd::interpreter::Argument],
+    condition: &Option<ftd::interpreter::Expression>,
+    doc: &mut ftd::executor::TDoc,
+    local_container: &[usize],
+    line_number: usize,
+    children: Vec<Element>,
+    inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
+    component_name: &str,
+    device: Option<ftd::executor::Device>,
+) -> ftd::executor::Result<Overlay> {
+    let open = ftd::executor::value::bool(
+        "open",
+        component_name,
+        properties,
+        arguments,
+        doc,
+        line_number,
+    )?;
+
+    let close_on_escape = ftd::executor::value::optional_bool(
+        "close-on-escape",
+        component_name,
+        properties,
+        arguments,
+        doc,
+        line_number,
+        inherited_variables,
+    )?;
+
+    let close_on_outside_click = if component_name.eq("ftd#tooltip") {
+        Default::default()
+    } else {
+        ftd::executor::value::optional_bool(
+            "close-on-outside-click",
+            component_name,
+            properties,
+            arguments,
+            doc,
+            line_number,
+            inherited_variables,
+        )?
+    };
+
+    let (trigger, placement) = if component_name.eq("ftd#dialog") {
+        Default::default()
+    } else {
+        (
+            ftd::executor::value::optional_string(
+                "trigger",
+                component_name,
+                properties,
+                arguments,
+                doc,
+                line_number,
+            )?,
+            ftd::executor::Placement::optional_placement(
+                properties,
+                arguments,
+                doc,
+                line_number,
+                "placement",
+                inherited_variables,
+                component_name,
+            )?,
+        )
+    };
+
+    let common = common_from_properties(
+        properties,
+        events,
+        arguments,
+        condition,
+        doc,
+        local_container,
+        line_number,
+        inherited_variables,
+        component_name,
+        device.clone(),
+    )?;
+
+    let container = container_from_properties(
+        properties,
+        arguments,
+        doc,
+        line_number,
+        children,
+        inherited_variables,
+        component_name,
+        device,
+    )?;
+
+    Ok(Overlay {
+        open,
+        close_on_escape,
+        close_on_outside_click,
+        trigger,
+        placement,
+        container,
+        common,
+    })
+}
+
+/// The sources and the playback options of an `ftd.video` or an `ftd.audio`.
+#[derive(serde::Deserialize, Debug, Default, PartialEq, Clone, serde::Serialize)]
+pub struct Media {
+    pub sources: ftd::executor::Value<Vec<ftd::executor::MediaSource>>,
+    pub controls: ftd::executor::Value<Option<bool>>,
+    pub autoplay: ftd::executor::Value<Option<bool>>,
+    pub muted: ftd::executor::Value<Option<bool>>,
+    pub loop_: ftd::executor::Value<Option<bool>>,
+}
+
+impl Media {
+    /// The controls are shown unless they are turned off.
+    pub fn controls_pattern() -> (String, bool) {
+        (
+            format!(
+                indoc::indoc! {"
+                    if ({{0}} === false) {{
+                        \"{remove_key}\"
+                    }} else {{
+                        \"\"
+                    }}
+                "},
+                remove_key = ftd::interpreter::FTD_REMOVE_KEY,
+            ),
+            true,
+        )
+    }
+
+    pub fn option_pattern() -> (String, bool) {
+        ftd::executor::CheckBox::checked_pattern()
+    }
+}
+
+pub fn media_from_properties(
+    properties: &[ftd::interpreter::Property],
+    arguments: &[ftd::interpreter::Argument],
+    doc: &ftd::executor::TDoc,
+    line_number: usize,
+    inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
+    component_name: &str,
+) -> ftd::executor::Result<Media> {
+    let sources = ftd::executor::MediaSource::source_list(
+        properties,
+        arguments,
+        doc,
+        line_number,
+        "sources",
+        inherited_variables,
+        component_name,
+    )?;
+
+    let controls = ftd::executor::value::optional_bool(
+        "controls",
+        component_name,
+        properties,
+        arguments,
+        doc,
+        line_number,
+        inherited_variables,
+    )?;
+
+    let autoplay = ftd::executor::value::optional_bool(
+        "autoplay",
+        component_name,
+        properties,
+        arguments,
+        doc,
+        line_number,
+        inherited_variables,
+    )?;
+
+    let muted = ftd::executor::value::optional_bool(
+        "muted",
+        component_name,
+        properties,
+        arguments,
+        doc,
+        line_number,
+        inherited_variables,
+    )?;
+
+    let loop_ = ftd::executor::value::optional_bool(
+        "loop",
+        component_name,
+        properties,
+        arguments,
+        doc,
+        line_number,
+        inherited_variables,
+    )?;
+
+    Ok(Media {
+        sources,
+        controls,
+        autoplay,
+        muted,
+        loop_,
+    })
+}
+
+#[derive(serde::Deserialize, Debug, Default, PartialEq, Clone, serde::Serialize)]
+pub struct Video {
+    pub poster: ftd::executor::Value<Option<ImageSrc>>,
+    pub captions: ftd::executor::Value<Vec<ftd::executor::Track>>,
+    pub media: Media,
+    pub common: Common,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn video_from_properties(
+    properties: &[ftd::interpreter::Property],
+    events: &[ftd::interpreter::Event],
+    arguments: &[ftd::interpreter::Argument],
+    condition: &Option<ftd::interpreter::Expression>,
+    doc: &mut ftd::executor::TDoc,
+    local_container: &[usize],
+    line_number: usize,
+    inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
+    device: Option<ftd::executor::Device>,
+) -> ftd::executor::Result<Video> {
+    let poster = ImageSrc::optional_image(
+        properties,
+        arguments,
+        doc,
+        line_number,
+        "poster",
+        inherited_variables,
+        "ftd#video",
+    )?;
+
+    let captions = ftd::executor::Track::track_list(
+        properties,
+        arguments,
+        doc,
+        line_number,
+        "captions",
+        inherited_variables,
+        "ftd#video",
+    )?;
+
+    let media = media_from_properties(
+        properties,
+        arguments,
+        doc,
+        line_number,
+        inherited_variables,
+        "ftd#video",
+    )?;
+
+    let common = common_from_properties(
+        properties,
+        events,
+        arguments,
+        condition,
+        doc,
+        local_container,
+        line_number,
+        inherited_variables,
+        "ftd#video",
+        device,
+    )?;
+
+    Ok(Video {
+        poster,
+        captions,
+        media,
+        common,
+    })
+}
+
+#[derive(serde::Deserialize, Debug, Default, PartialEq, Clone, serde::Serialize)]
+pub struct Audio {
+    pub media: Media,
+    pub common: Common,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn audio_from_properties(
+    properties: &[ftd::interpreter::Property],
+    events: &[ftd::interpreter::Event],
+    arguments: &[ftd::interpreter::Argument],
+    condition: &Option<ftd::interpreter::Expression>,
+    doc: &mut ftd::executor::TDoc,
+    local_container: &[usize],
+    line_number: usize,
+    inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
+    device: Option<ftd::executor::Device>,
+) -> ftd::executor::Result<Audio> {
+    let media = media_from_properties(
+        properties,
+        arguments,
+        doc,
+        line_number,
+        inherited_variables,
+        "ftd#audio",
+    )?;
+
+    let common = common_from_properties(
+        properties,
+        events,
+        arguments,
+        condition,
+        doc,
+        local_container,
+        line_number,
+        inherited_variables,
+        "ftd#audio",
+        device,
+    )?;
+
+    Ok(Audio { media, common })
+}
+
+/// The `fill` and `stroke` of the shapes of an `ftd.svg` or an `ftd.icon`.
+#[derive(serde::Deserialize, Debug, Default, PartialEq, Clone, serde::Serialize)]
+pub struct Paint {
+    pub fill: ftd::executor::Value<Option<ftd::executor::Color>>,
+    pub stroke: ftd::executor::Value<Option<ftd::executor::Color>>,
+}
+
+pub fn paint_from_properties(
+    properties: &[ftd::interpreter::Property],
+    arguments: &[ftd::interpreter::Argument],
+    doc: &ftd::executor::TDoc,
+    line_number: usize,
+    inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
+    component_name: &str,
+) -> ftd::executor::Result<Paint> {
+    let fill = ftd::executor::Color::optional_color(
+        properties,
+        arguments,
+        doc,
+        line_number,
+        "fill",
+        inherited_variables,
+        component_name,
+    )?;
+
+    let stroke = ftd::executor::Color::optional_color(
+        properties,
+        arguments,
+        doc,
+        line_number,
+        "stroke",
+        inherited_variables,
+        component_name,
+    )?;
+
+    Ok(Paint { fill, stroke })
+}
+
+#[derive(serde::Deserialize, Debug, Default, PartialEq, Clone, serde::Serialize)]
+pub struct Svg {
+    pub src: ftd::executor::Value<ImageSrc>,
+    pub paint: Paint,
+    pub common: Common,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn svg_from_properties(
+    properties: &[ftd::interpreter::Property],
+    events: &[ftd::interpreter::Event],
+    arguments: &[ftd::interpreter::Argument],
+    condition: &Option<ftd::interpreter::Expression>,
+    doc: &mut ftd::executor::TDoc,
+    local_container: &[usize],
+    line_number: usize,
+    inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
+    device: Option<ftd::executor::Device>,
+) -> ftd::executor::Result<Svg> {
+    let src = {
+        let src = ftd::executor::value::record(
+            "src",
+            "ftd#svg",
+            properties,
+            arguments,
+            doc,
+            line_number,
+            ftd::interpreter::FTD_IMAGE_SRC,
+        )?;
+        ftd::executor::Value::new(
+            ImageSrc::from_values(src.value, doc, line_number)?,
+            Some(line_number),
+            src.properties,
+        )
+    };
+
+    let paint = paint_from_properties(
+        properties,
+        arguments,
+        doc,
+        line_number,
+        inherited_variables,
+        "ftd#svg",
+    )?;
+
+    let common = common_from_properties(
+        properties,
+        events,
+        arguments,
+        condition,
+        doc,
+        local_container,
+        line_number,
+        inherited_variables,
+        "ftd#svg",
+        device,
+    )?;
+
+    Ok(Svg { src, paint, common })
+}
+
+#[derive(serde::Deserialize, Debug, Default, PartialEq, Clone, serde::Serialize)]
+pub struct Icon {
+    pub name: ftd::executor::Value<String>,
+    pub sprite: ftd::executor::Value<ImageSrc>,
+    pub paint: Paint,
+    pub common: Common,
+}
+
+impl Icon {
+    /// The reference to the symbol of the icon in its sprite.
+    pub fn href(&self) -> String {
+        format!("{}#{}", self.sprite.value.light.value, self.name.value)
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn icon_from_properties(
+    properties: &[ftd::interpreter::Property],
+    events: &[ftd::interpreter::Event],
+    arguments: &[ftd::interpreter::Argument],
+    condition: &Option<ftd::interpreter::Expression>,
+    doc: &mut ftd::executor::TDoc,
+    local_container: &[usize],
+    line_number: usize,
+    inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
+    device: Option<ftd::executor::Device>,
+) -> ftd::executor::Result<Icon> {
+    let name =
+        ftd::executor::value::string("name", "ftd#icon", properties, arguments, doc, line_number)?;
+
+    let sprite = {
+        let sprite = ftd::executor::value::record(
+            "sprite",
+            "ftd#icon",
+            properties,
+            arguments,
+            doc,
+            line_number,
+            ftd::interpreter::FTD_IMAGE_SRC,
+        )?;
+        ftd::executor::Value::new(
+            ImageSrc::from_values(sprite.value, doc, line_number)?,
+            Some(line_number),
+            sprite.properties,
+        )
+    };
+
+    let paint = paint_from_properties(
+        properties,
+        arguments,
+        doc,
+        line_number,
+        inherited_variables,
+        "ftd#icon",
+    )?;
+
+    let common = common_from_properties(
+        properties,
+        events,
+        arguments,
+        condition,
+        doc,
+        local_container,
+        line_number,
+        inherited_variables,
+        "ftd#icon",
+        device,
+    )?;
+
+    Ok(Icon {
+        name,
+        sprite,
+        paint,
+        common,
+    })
+}
+
+#[derive(serde::Deserialize, Debug, Default, PartialEq, Clone, serde::Serialize)]
+pub struct Table {
+    pub columns: ftd::executor::Value<Vec<ftd::executor::TableColumn>>,
+    /// The text of the cells, row by row, which the table is sorted and filtered by.
+    pub rows: Vec<Vec<String>>,
+    pub title: ftd::executor::Value<Option<String>>,
+    pub filterable: ftd::executor::Value<Option<bool>>,
+    pub page_size: ftd::executor::Value<Option<i64>>,
+    pub page_param: ftd::executor::Value<Option<String>>,
+    pub page: ftd::executor::Value<Option<i64>>,
+    pub total: ftd::executor::Value<Option<i64>>,
+    pub cells: Vec<Element>,
+    pub common: Common,
+}
+
+impl Table {
+    /// The number of pages of a table paginated by the server, which shows one page of `total`
+    /// rows at a time.
+    pub fn page_count(&self) -> Option<i64> {
+        let total = self.total.value?;
+        let page_size = self.page_size.value.filter(|v| v.gt(&0))?;
+        Some(((total + page_size - 1) / page_size).max(1))
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn table_from_properties(
+    properties: &[ftd::interpreter::Property],
+    events: &[ftd::interpreter::Event],
+    arguments: &[ftd::interpreter::Argument],
+    condition: &Option<ftd::interpreter::Expression>,
+    doc: &mut ftd::executor::TDoc,
+    local_container: &[usize],
+    line_number: usize,
+    inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
+    device: Option<ftd::executor::Device>,
+) -> ftd::executor::Result<Table> {
+    let columns = ftd::executor::TableColumn::column_list(
+        properties,
+        arguments,
+        doc,
+        line_number,
+        "columns",
+        inherited_variables,
+        "ftd#table",
+    )?;
+
+    let mut rows = vec![];
+    for row in table_rows(properties, arguments, doc, line_number, inherited_variables)? {
+        let mut cells = vec![];
+        for column in columns.value.iter() {
+            let value = table_field(&row, column.field.as_str(), doc, line_number)?;
+            cells.push(value.to_string().unwrap_or_default());
+        }
+        rows.push(cells);
+    }
+
+    let title = ftd::executor::value::optional_string(
+        "title",
+        "ftd#table",
+        properties,
+        arguments,
+        doc,
+        line_number,
+    )?;
+
+    let filterable = ftd::executor::value::optional_bool(
+        "filterable",
+        "ftd#table",
+        properties,
+        arguments,
+        doc,
+        line_number,
+        inherited_variables,
+    )?;
+
+    let page_size = ftd::executor::value::optional_i64(
+        "page-size",
+        "ftd#table",
+        properties,
+        arguments,
+        doc,
+        line_number,
+        inherited_variables,
+    )?;
+
+    let page_param = ftd::executor::value::optional_string(
+        "page-param",
+        "ftd#table",
+        properties,
+        arguments,
+        doc,
+        line_number,
+    )?;
+
+    let page = ftd::executor::value::optional_i64(
+        "page",
+        "ftd#table",
+        properties,
+        arguments,
+        doc,
+        line_number,
+        inherited_variables,
+    )?;
+
+    let total = ftd::executor::value::optional_i64(
+        "total",
+        "ftd#table",
+        properties,
+        arguments,
+        doc,
+        line_number,
+        inherited_variables,
+    )?;
+
+    let common = common_from_properties(
+        properties,
+        events,
+        arguments,
+        condition,
+        doc,
+        local_container,
+        line_number,
+        inherited_variables,
+        "ftd#table",
+        device,
+    )?;
+
+    Ok(Table {
+        columns,
+        rows,
+        title,
+        filterable,
+        page_size,
+        page_param,
+        page,
+        total,
+        cells: vec![],
+        common,
+    })
+}
+
+/// The instructions of the cells of an `ftd.table`, row by row. A cell is the `cell` component of
+/// its column, given the value of the field as `value` and the record as `row`, or else the value
+/// as text.
+pub(crate) fn table_cell_instructions(
+    properties: &[ftd::interpreter::Property],
+    arguments: &[ftd::interpreter::Argument],
+    doc: &ftd::executor::TDoc,
+    line_number: usize,
+    inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
+) -> ftd::executor::Result<Vec<ftd::interpreter::Component>> {
+    let columns = ftd::executor::TableColumn::column_list(
+        properties,
+        arguments,
+        doc,
+        line_number,
+        "columns",
+        inherited_variables,
+        "ftd#table",
+    )?
+    .value;
+
+    let property = |name: &str, value: ftd::interpreter::Value| ftd::interpreter::Property {
+        value: value.into_property_value(false, line_number),
+        source: ftd::interpreter::PropertySource::header(name),
+        condition: None,
+        line_number,
+    };
+
+    let mut cells = vec![];
+    for row in table_rows(properties, arguments, doc, line_number, inherited_variables)? {
+        for column in columns.iter() {
+            let value = table_field(&row, column.field.as_str(), doc, line_number)?;
+            let mut cell = match column.cell {
+                Some(ref cell) => {
+                    let definition = doc.itdoc().get_component(cell.as_str(), line_number)?;
+                    let mut component =
+                        ftd::interpreter::Component::from_name(definition.name.as_str());
+                    component.properties.push(property("value", value));
+                    if definition.arguments.iter().any(|v| v.name.eq("row")) {
+                        component.properties.push(property("row", row.to_owned()));
+                    }
+                    component
+                }
+                None => {
+                    let mut component = ftd::interpreter::Component::from_name("ftd#text");
+                    component.properties.push(property(
+                        "text",
+                        ftd::interpreter::Value::new_string(
+                            value.to_string().unwrap_or_default().as_str(),
+                        ),
+                    ));
+                    component
+                }
+            };
+            cell.line_number = line_number;
+            cells.push(cell);
+        }
+    }
+    Ok(cells)
+}
+
+/// The records of the `data` of an `ftd.table`.
+fn table_rows(
+    properties: &[ftd::interpreter::Property],
+    arguments: &[ftd::interpreter::Argument],
+    doc: &ftd::executor::TDoc,
+    line_number: usize,
+    inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
+) -> ftd::executor::Result<Vec<ftd::interpreter::Value>> {
+    let data = ftd::executor::value::get_value_from_properties_using_key_and_arguments_dummy(
+        "data",
+        "ftd#table",
+        properties,
+        arguments,
+        doc,
+        line_number,
+        false,
+        inherited_variables,
+    )?;
+
+    let mut rows = vec![];
+    match data.value.and_then(|v| v.inner()) {
+        Some(ftd::interpreter::Value::List { data, .. }) => {
+            for row in data {
+                let ln = row.line_number();
+                rows.push(row.resolve(&doc.itdoc(), ln)?);
+            }
+        }
+        None => {}
+        t => {
+            return ftd::executor::utils::parse_error(
+                format!(
+                    "Expected list value for the data of ftd.table, found: {:?}",
+                    t
+                ),
+                doc.name,
+                line_number,
+            )
+        }
+    }
+    Ok(rows)
+}
+
+/// The value of the `field` of a record of the `data` of an `ftd.table`.
+fn table_field(
+    row: &ftd::interpreter::Value,
+    field: &str,
+    doc: &ftd::executor::TDoc,
+    line_number: usize,
+) -> ftd::executor::Result<ftd::interpreter::Value> {
+    match row.record_fields(doc.name, line_number)?.get(field) {
+        Some(value) => Ok(value.clone().resolve(&doc.itdoc(), line_number)?),
+        None => ftd::executor::utils::parse_error(
+            format!("`{}` field not found in the data of ftd.table", field),
+            doc.name,
+            line_number,
+        ),
+    }
+}
@@ -76,6 +76,13 @@ impl<'a> ExecuteDoc<'a> {
 
                 if let ftd::executor::Element::Document(d) = first {
                     // setting document breakpoint here
+                    if !d.breakpoints.value.is_empty() {
+                        ExecuteDoc::set_breakpoints(
+                            &mut document.data,
+                            d.breakpoints.value.as_slice(),
+                            d.line_number,
+                        );
+                    }
                     if let Some(breakpoint) = d.breakpoint_width.value.as_ref() {
                         ExecuteDoc::set_document_breakpoint(
                             &mut document.data,
@@ -150,6 +157,45 @@ impl<'a> ExecuteDoc<'a> {
                 line_number,
             };
         }
+
+        // `breakpoint` is also the `max-width` of the `mobile` breakpoint in `ftd#breakpoints`
+        let breakpoints = ExecuteDoc::breakpoints_from_bag(bag)
+            .into_iter()
+            .map(|mut breakpoint| {
+                if breakpoint.name.eq("mobile") {
+                    breakpoint.max_width = Some(breakpoint_width);
+                }
+                breakpoint
+            })
+            .collect::<Vec<_>>();
+        ExecuteDoc::set_breakpoints(bag, breakpoints.as_slice(), line_number);
+    }
+
+    /// Sets `ftd#breakpoints`, the breakpoints declared by the package of a page or by its
+    /// `ftd.document`, from the narrowest to the widest.
+    pub fn set_breakpoints(
+        bag: &mut ftd::Map<ftd::interpreter::Thing>,
+        breakpoints: &[ftd::executor::Breakpoint],
+        line_number: usize,
+    ) {
+        let breakpoints = breakpoints
+            .iter()
+            .map(|breakpoint| (breakpoint.name.as_str(), breakpoint.max_width))
+            .collect::<Vec<_>>();
+        if let Some(ftd::interpreter::Thing::Variable(v)) =
+            bag.get_mut(ftd::interpreter::FTD_BREAKPOINTS)
+        {
+            v.value =
+                ftd::interpreter::default::breakpoints_value(breakpoints.as_slice(), line_number);
+        }
+    }
+
+    fn breakpoints_from_bag(
+        bag: &ftd::Map<ftd::interpreter::Thing>,
+    ) -> Vec<ftd::executor::Breakpoint> {
+        let aliases = ftd::interpreter::default::default_aliases();
+        let doc = ftd::interpreter::TDoc::new("ftd", &aliases, bag);
+        ftd::executor::Breakpoint::from_bag(&doc).unwrap_or_default()
     }
 
     #[allow(clippy::type_complexity)]
@@ -374,6 +420,7 @@ impl<'a> ExecuteDoc<'a> {
         .0;
 
         let children_length = iteration.children(&doc.itdoc())?.0.len();
+        let keys = iteration.keys(&doc.itdoc())?;
         let reference_name =
             iteration
                 .on
@@ -397,6 +444,7 @@ impl<'a> ExecuteDoc<'a> {
                 instruction,
                 doc,
                 index,
+                keys.as_ref().and_then(|v| v.get(index)),
                 iteration.alias.as_str(),
                 reference_name,
                 inherited_variables,
@@ -570,7 +618,13 @@ impl<'a> ExecuteDoc<'a> {
                         )?,
                     );
                     let children_instructions = ExecuteDoc::get_instructions_from_instructions(
-                        instruction.get_children(&doc.itdoc())?.as_slice(),
+                        ExecuteDoc::get_children_instructions(
+                            &instruction,
+                            doc,
+                            &component_definition,
+                            &inherited_variables,
+                        )?
+                        .as_slice(),
                         doc,
                         container.as_slice(),
                         &mut inherited_variables,
@@ -608,6 +662,12 @@ impl<'a> ExecuteDoc<'a> {
                     ftd::executor::Element::Row(r) => &mut r.container.children,
                     ftd::executor::Element::Column(r) => &mut r.container.children,
                     ftd::executor::Element::Container(e) => &mut e.children,
+                    ftd::executor::Element::Grid(g) => &mut g.children,
+                    ftd::executor::Element::Form(f) => &mut f.container.children,
+                    ftd::executor::Element::Table(t) => &mut t.cells,
+                    ftd::executor::Element::Dialog(o)
+                    | ftd::executor::Element::Popover(o)
+                    | ftd::executor::Element::Tooltip(o) => &mut o.container.children,
                     ftd::executor::Element::Document(r) => &mut r.children,
                     t => unreachable!("{:?}", t),
                 };
@@ -615,6 +675,26 @@ impl<'a> ExecuteDoc<'a> {
         }
     }
 
+    /// The children of a component. The cells of an `ftd.table` are made from its data and its
+    /// columns.
+    pub(crate) fn get_children_instructions(
+        instruction: &ftd::interpreter::Component,
+        doc: &ftd::executor::TDoc,
+        component_definition: &ftd::interpreter::ComponentDefinition,
+        inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
+    ) -> ftd::executor::Result<Vec<ftd::interpreter::Component>> {
+        if component_definition.name.eq("ftd#table") {
+            return ftd::executor::element::table_cell_instructions(
+                instruction.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+                doc,
+                instruction.line_number,
+                inherited_variables,
+            );
+        }
+        Ok(instruction.get_children(&doc.itdoc())?)
+    }
+
     /*    fn execute_from_instructions(
         instructions: &[ftd::interpreter::Component],
         doc: &mut ftd::executor::TDoc,
@@ -771,8 +851,8 @@ impl<'a> ExecuteDoc<'a> {
         use itertools::Itertools;
 
         match component_definition.name.as_str() {
-            "ftd#row" | "ftd#column" | "ftd#container" | "ftd#document" | "ftd#desktop"
-            | "ftd#mobile" => {
+            "ftd#row" | "ftd#column" | "ftd#container" | "ftd#grid" | "ftd#form" | "ftd#dialog"
+            | "ftd#popover" | "ftd#tooltip" | "ftd#document" | "ftd#desktop" | "ftd#mobile" => {
                 doc.insert_local_variables(
                     component_definition.name.as_str(),
                     instruction.properties.as_slice(),
@@ -920,6 +1000,34 @@ impl<'a> ExecuteDoc<'a> {
                     device,
                 )?,
             ),
+            "ftd#grid" => {
+                ftd::executor::Element::Grid(ftd::executor::element::grid_from_properties(
+                    instruction.properties.as_slice(),
+                    instruction.events.as_slice(),
+                    component_definition.arguments.as_slice(),
+                    instruction.condition.as_ref(),
+                    doc,
+                    local_container,
+                    instruction.line_number,
+                    vec![],
+                    inherited_variables,
+                    device,
+                )?)
+            }
+            "ftd#form" => {
+                ftd::executor::Element::Form(ftd::executor::element::form_from_properties(
+                    instruction.properties.as_slice(),
+                    instruction.events.as_slice(),
+                    component_definition.arguments.as_slice(),
+                    instruction.condition.as_ref(),
+                    doc,
+                    local_container,
+                    instruction.line_number,
+                    vec![],
+                    inherited_variables,
+                    device,
+                )?)
+            }
             "ftd#document" => {
                 if !instruction.events.is_empty() {
                     return ftd::executor::utils::parse_error(
@@ -993,7 +1101,7 @@ impl<'a> ExecuteDoc<'a> {
                     device,
                 )?)
             }
-            "ftd#text-input" => ftd::executor::Element::TextInput(
+            "ftd#text-input" => ftd::executor::Element::TextInput(Box::new(
                 ftd::executor::element::text_input_from_properties(
                     instruction.properties.as_slice(),
                     instruction.events.as_slice(),
@@ -1005,7 +1113,7 @@ impl<'a> ExecuteDoc<'a> {
                     inherited_variables,
                     device,
                 )?,
-            ),
+            )),
             "ftd#checkbox" => {
                 ftd::executor::Element::CheckBox(ftd::executor::element::checkbox_from_properties(
                     instruction.properties.as_slice(),
@@ -1019,6 +1127,115 @@ impl<'a> ExecuteDoc<'a> {
                     device,
                 )?)
             }
+            "ftd#select" => {
+                ftd::executor::Element::Select(ftd::executor::element::select_from_properties(
+                    instruction.properties.as_slice(),
+                    instruction.events.as_slice(),
+                    component_definition.arguments.as_slice(),
+                    instruction.condition.as_ref(),
+                    doc,
+                    local_container,
+                    instruction.line_number,
+                    inherited_variables,
+                    device,
+                )?)
+            }
+            "ftd#radio-group" => ftd::executor::Element::RadioGroup(
+                ftd::executor::element::radio_group_from_properties(
+                    instruction.properties.as_slice(),
+                    instruction.events.as_slice(),
+                    component_definition.arguments.as_slice(),
+                    instruction.condition.as_ref(),
+                    doc,
+                    local_container,
+                    instruction.line_number,
+                    inherited_variables,
+                    device,
+                )?,
+            ),
+            "ftd#video" => {
+                ftd::executor::Element::Video(ftd::executor::element::video_from_properties(
+                    instruction.properties.as_slice(),
+                    instruction.events.as_slice(),
+                    component_definition.arguments.as_slice(),
+                    instruction.condition.as_ref(),
+                    doc,
+                    local_container,
+                    instruction.line_number,
+                    inherited_variables,
+                    device,
+                )?)
+            }
+            "ftd#audio" => {
+                ftd::executor::Element::Audio(ftd::executor::element::audio_from_properties(
+                    instruction.properties.as_slice(),
+                    instruction.events.as_slice(),
+                    component_definition.arguments.as_slice(),
+                    instruction.condition.as_ref(),
+                    doc,
+                    local_container,
+                    instruction.line_number,
+                    inherited_variables,
+                    device,
+                )?)
+            }
+            "ftd#svg" => ftd::executor::Element::Svg(ftd::executor::element::svg_from_properties(
+                instruction.properties.as_slice(),
+                instruction.events.as_slice(),
+                component_definition.arguments.as_slice(),
+                instruction.condition.as_ref(),
+                doc,
+                local_container,
+                instruction.line_number,
+                inherited_variables,
+                device,
+            )?),
+            "ftd#icon" => {
+                ftd::executor::Element::Icon(ftd::executor::element::icon_from_properties(
+                    instruction.properties.as_slice(),
+                    instruction.events.as_slice(),
+                    component_definition.arguments.as_slice(),
+                    instruction.condition.as_ref(),
+                    doc,
+                    local_container,
+                    instruction.line_number,
+                    inherited_variables,
+                    device,
+                )?)
+            }
+            "ftd#table" => {
+                ftd::executor::Element::Table(ftd::executor::element::table_from_properties(
+                    instruction.properties.as_slice(),
+                    instruction.events.as_slice(),
+                    component_definition.arguments.as_slice(),
+                    instruction.condition.as_ref(),
+                    doc,
+                    local_container,
+                    instruction.line_number,
+                    inherited_variables,
+                    device,
+                )?)
+            }
+            "ftd#dialog" | "ftd#popover" | "ftd#tooltip" => {
+                let overlay = Box::new(ftd::executor::element::overlay_from_properties(
+                    instruction.properties.as_slice(),
+                    instruction.events.as_slice(),
+                    component_definition.arguments.as_slice(),
+                    instruction.condition.as_ref(),
+                    doc,
+                    local_container,
+                    instruction.line_number,
+                    vec![],
+                    inherited_variables,
+                    component_definition.name.as_str(),
+                    device,
+                )?);
+                match component_definition.name.as_str() {
+                    "ftd#dialog" => ftd::executor::Element::Dialog(overlay),
+                    "ftd#popover" => ftd::executor::Element::Popover(overlay),
+                    _ => ftd::executor::Element::Tooltip(overlay),
+                }
+            }
             _ => unimplemented!(),
         })
     }
@@ -1087,7 +1304,7 @@ impl Device {
                 "ftd.device".to_string(),
                 ftd::interpreter::PropertyValue::Reference {
                     name: "ftd#device".to_string(),
-                    kind: ftd::interpreter::Kind::record("ftd#device-data").into_kind_data(),
+                    kind: ftd::interpreter::Kind::string().into_kind_data(),
                     source: ftd::interpreter::PropertyValueSource::Global,
                     is_mutable: false,
                     line_number,
@@ -1104,7 +1321,7 @@ impl Device {
                     "ftd.device".to_string(),
                     ftd::interpreter::PropertyValue::Reference {
                         name: "ftd#device".to_string(),
-                        kind: ftd::interpreter::Kind::record("ftd#device-data").into_kind_data(),
+                        kind: ftd::interpreter::Kind::string().into_kind_data(),
                         source: ftd::interpreter::PropertyValueSource::Global,
                         is_mutable: false,
                         line_number,
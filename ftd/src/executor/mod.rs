@@ -18,18 +18,21 @@ pub type FieldWithValue = (ftd::interpreter::Field, Option<ftd::ast::VariableVal
 
 pub use dummy::{DummyElement, ElementConstructor};
 pub use element::{
-    CheckBox, Code, Column, Common, Container, ContainerElement, Document, Element, Event,
-    HTMLData, Iframe, Image, ImageSrc, IterativeElement, RawElement, RawImage, Rive, Row, Text,
-    TextInput, WebComponent,
+    Accessibility, Audio, CheckBox, Code, Column, Common, Container, ContainerElement, Document,
+    Element, Event, Form, FormField, Grid, HTMLData, Icon, Iframe, Image, ImageSrc,
+    IterativeElement, Media, Overlay, Paint, RadioGroup, RawElement, RawImage, Rive, Row, Select,
+    StateStyle, Svg, Table, Text, TextInput, Video, WebComponent,
 };
 pub use main::{Device, ExecuteDoc, RT};
 pub use rive::RiveData;
 pub use styles::{
-    AlignSelf, Alignment, Anchor, Background, BackgroundImage, BackgroundPosition,
-    BackgroundRepeat, BackgroundSize, BorderStyle, BreakpointWidth, Color, ColorValue, Cursor,
-    Display, FontSize, Length, LineClamp, LinearGradient, LinearGradientColor,
-    LinearGradientDirection, Loading, Overflow, Region, Resize, Resizing, ResponsiveType, Shadow,
-    Spacing, TextAlign, TextInputType, TextStyle, TextTransform, TextWeight, WhiteSpace,
+    AlignSelf, Alignment, Anchor, Animation, AriaLive, Background, BackgroundImage,
+    BackgroundPosition, BackgroundRepeat, BackgroundSize, BorderStyle, Breakpoint, BreakpointWidth,
+    Color, ColorValue, ColumnType, Cursor, Display, Easing, FontSize, GridTemplate, Keyframe,
+    Landmark, Length, LineClamp, LinearGradient, LinearGradientColor, LinearGradientDirection,
+    Loading, MediaSource, Overflow, Placement, Region, Resize, Resizing, ResponsiveType,
+    SelectOption, Shadow, Spacing, TableColumn, TextAlign, TextInputType, TextStyle, TextTransform,
+    TextWeight, Track, Transform, Transition, WhiteSpace,
 };
 pub(crate) use tdoc::TDoc;
 pub(crate) use value::Value;
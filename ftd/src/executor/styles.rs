@@ -366,6 +366,203 @@ impl ResponsiveLength {
     }
 }
 
+#[derive(serde::Deserialize, Debug, PartialEq, Clone, serde::Serialize)]
+pub enum GridTemplate {
+    Count(i64),
+    Fill(Length),
+    Fit(Length),
+    Tracks(String),
+    Responsive(Box<ResponsiveGridTemplate>),
+}
+
+impl GridTemplate {
+    fn from_optional_values(
+        or_type_value: Option<(String, ftd::interpreter::PropertyValue)>,
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<Option<GridTemplate>> {
+        if let Some(value) = or_type_value {
+            Ok(Some(GridTemplate::from_values(value, doc, line_number)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn from_value(
+        value: ftd::interpreter::PropertyValue,
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<GridTemplate> {
+        let binding = value.resolve(&doc.itdoc(), line_number)?;
+        let value = binding.get_or_type(doc.name, line_number)?;
+        let value = (value.1.to_owned(), value.2.to_owned());
+        GridTemplate::from_values(value, doc, line_number)
+    }
+
+    fn from_values(
+        or_type_value: (String, ftd::interpreter::PropertyValue),
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<GridTemplate> {
+        match or_type_value.0.as_str() {
+            ftd::interpreter::FTD_GRID_TEMPLATE_COUNT => Ok(GridTemplate::Count(
+                or_type_value
+                    .1
+                    .clone()
+                    .resolve(&doc.itdoc(), line_number)?
+                    .integer(doc.name, line_number)?,
+            )),
+            ftd::interpreter::FTD_GRID_TEMPLATE_FILL => Ok(GridTemplate::Fill(Length::from_value(
+                or_type_value.1,
+                doc,
+                line_number,
+            )?)),
+            ftd::interpreter::FTD_GRID_TEMPLATE_FIT => Ok(GridTemplate::Fit(Length::from_value(
+                or_type_value.1,
+                doc,
+                line_number,
+            )?)),
+            ftd::interpreter::FTD_GRID_TEMPLATE_TRACKS => Ok(GridTemplate::Tracks(
+                or_type_value
+                    .1
+                    .clone()
+                    .resolve(&doc.itdoc(), line_number)?
+                    .string(doc.name, line_number)?,
+            )),
+            ftd::interpreter::FTD_GRID_TEMPLATE_RESPONSIVE => {
+                Ok(GridTemplate::Responsive(Box::new(
+                    ResponsiveGridTemplate::from_value(or_type_value.1, doc, line_number)?,
+                )))
+            }
+            t => ftd::executor::utils::parse_error(
+                format!("Unknown variant `{}` for or-type `ftd.grid-template`", t),
+                doc.name,
+                line_number,
+            ),
+        }
+    }
+
+    pub(crate) fn optional_grid_template(
+        properties: &[ftd::interpreter::Property],
+        arguments: &[ftd::interpreter::Argument],
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+        key: &str,
+        inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
+        component_name: &str,
+    ) -> ftd::executor::Result<ftd::executor::Value<Option<GridTemplate>>> {
+        let or_type_value = ftd::executor::value::optional_or_type(
+            key,
+            component_name,
+            properties,
+            arguments,
+            doc,
+            line_number,
+            ftd::interpreter::FTD_GRID_TEMPLATE,
+            inherited_variables,
+        )?;
+
+        Ok(ftd::executor::Value::new(
+            GridTemplate::from_optional_values(or_type_value.value, doc, line_number)?,
+            or_type_value.line_number,
+            or_type_value.properties,
+        ))
+    }
+
+    pub fn to_css_string(&self, device: &Option<ftd::executor::Device>) -> String {
+        match self {
+            GridTemplate::Count(count) => format!("repeat({}, minmax(0, 1fr))", count),
+            GridTemplate::Fill(length) => format!(
+                "repeat(auto-fill, minmax({}, 1fr))",
+                length.to_css_string(device)
+            ),
+            GridTemplate::Fit(length) => format!(
+                "repeat(auto-fit, minmax({}, 1fr))",
+                length.to_css_string(device)
+            ),
+            GridTemplate::Tracks(tracks) => tracks.to_string(),
+            GridTemplate::Responsive(r) => match device {
+                Some(ftd::executor::Device::Mobile) => r.mobile.to_css_string(device),
+                _ => r.desktop.to_css_string(device),
+            },
+        }
+    }
+
+    pub fn set_pattern_from_variant_str(
+        variant: &str,
+        doc_id: &str,
+        line_number: usize,
+    ) -> ftd::executor::Result<&'static str> {
+        match variant {
+            ftd::interpreter::FTD_GRID_TEMPLATE_COUNT => Ok("repeat({0}, minmax(0, 1fr))"),
+            ftd::interpreter::FTD_GRID_TEMPLATE_FILL => Ok("repeat(auto-fill, minmax({0}, 1fr))"),
+            ftd::interpreter::FTD_GRID_TEMPLATE_FIT => Ok("repeat(auto-fit, minmax({0}, 1fr))"),
+            ftd::interpreter::FTD_GRID_TEMPLATE_TRACKS => Ok("{0}"),
+            t => ftd::executor::utils::parse_error(
+                format!("Unknown variant found for ftd.grid-template: `{}`", t),
+                doc_id,
+                line_number,
+            ),
+        }
+    }
+}
+
+#[derive(serde::Deserialize, Debug, PartialEq, Clone, serde::Serialize)]
+pub struct ResponsiveGridTemplate {
+    pub desktop: GridTemplate,
+    pub mobile: GridTemplate,
+}
+
+impl ResponsiveGridTemplate {
+    fn from_value(
+        value: ftd::interpreter::PropertyValue,
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<ResponsiveGridTemplate> {
+        let value = value.resolve(&doc.itdoc(), line_number)?;
+        let fields = match value.inner() {
+            Some(ftd::interpreter::Value::Record { name, fields })
+                if name.eq(ftd::interpreter::FTD_RESPONSIVE_GRID_TEMPLATE) =>
+            {
+                fields
+            }
+            t => {
+                return ftd::executor::utils::parse_error(
+                    format!(
+                        "Expected value of type record `{}`, found: {:?}",
+                        ftd::interpreter::FTD_RESPONSIVE_GRID_TEMPLATE,
+                        t
+                    ),
+                    doc.name,
+                    line_number,
+                )
+            }
+        };
+
+        let desktop = {
+            let value = fields
+                .get("desktop")
+                .ok_or(ftd::executor::Error::ParseError {
+                    message: "`desktop` field in ftd.responsive-grid-template not found"
+                        .to_string(),
+                    doc_id: doc.name.to_string(),
+                    line_number,
+                })?;
+            GridTemplate::from_value(value.to_owned(), doc, line_number)?
+        };
+
+        let mobile = {
+            if let Some(value) = fields.get("mobile") {
+                GridTemplate::from_value(value.to_owned(), doc, line_number)?
+            } else {
+                desktop.clone()
+            }
+        };
+
+        Ok(ResponsiveGridTemplate { desktop, mobile })
+    }
+}
+
 #[derive(serde::Deserialize, Debug, Default, PartialEq, Clone, serde::Serialize)]
 pub struct BreakpointWidth {
     pub mobile: ftd::executor::Value<i64>,
@@ -452,6 +649,166 @@ impl BreakpointWidth {
     }
 }
 
+/// A `ftd.breakpoint`, the screens up to `max_width` wide that are wider than the breakpoint
+/// before it are `name` devices, the widest breakpoint has no `max_width`. `ftd.device` is set
+/// to `name`, which has to be one of [`Breakpoint::names`] as the `ftd.responsive-*` values have
+/// no value for any other breakpoint.
+#[derive(serde::Deserialize, Debug, Default, PartialEq, Clone, serde::Serialize)]
+pub struct Breakpoint {
+    pub name: String,
+    #[serde(rename = "max-width")]
+    pub max_width: Option<i64>,
+}
+
+impl Breakpoint {
+    fn from_vec_values(
+        value: ftd::interpreter::Value,
+        doc: &ftd::interpreter::TDoc,
+        line_number: usize,
+    ) -> ftd::interpreter::Result<Vec<Breakpoint>> {
+        let mut result = vec![];
+        match value.inner() {
+            Some(ftd::interpreter::Value::List { data, kind })
+                if kind.kind.get_name().eq(ftd::interpreter::FTD_BREAKPOINT) =>
+            {
+                for element in data.iter() {
+                    let ln = element.line_number();
+                    result.push(Breakpoint::from_value(element.to_owned(), doc, ln)?)
+                }
+            }
+            None => {}
+            t => {
+                return ftd::interpreter::utils::e2(
+                    format!(
+                        "Expected list value of type `{}`, found: {:?}",
+                        ftd::interpreter::FTD_BREAKPOINT,
+                        t
+                    ),
+                    doc.name,
+                    line_number,
+                )
+            }
+        };
+        Breakpoint::check_names(result.as_slice(), doc, line_number)?;
+        Ok(result)
+    }
+
+    /// The names a breakpoint can have, the fields of the `ftd.responsive-*` records.
+    pub fn names(doc: &ftd::interpreter::TDoc) -> ftd::interpreter::Result<Vec<String>> {
+        Ok(doc
+            .get_record(ftd::interpreter::FTD_RESPONSIVE_LENGTH, 0)?
+            .fields
+            .into_iter()
+            .map(|field| field.name)
+            .collect())
+    }
+
+    pub fn check_names(
+        breakpoints: &[Breakpoint],
+        doc: &ftd::interpreter::TDoc,
+        line_number: usize,
+    ) -> ftd::interpreter::Result<()> {
+        let names = Breakpoint::names(doc)?;
+        match breakpoints.iter().find(|v| !names.contains(&v.name)) {
+            Some(breakpoint) => ftd::interpreter::utils::e2(
+                format!(
+                    "`{}` is not a breakpoint the `ftd.responsive-*` values have a value for, \
+                    the breakpoints are: {}",
+                    breakpoint.name,
+                    names.join(", ")
+                ),
+                doc.name,
+                line_number,
+            ),
+            None => Ok(()),
+        }
+    }
+
+    fn from_value(
+        value: ftd::interpreter::PropertyValue,
+        doc: &ftd::interpreter::TDoc,
+        line_number: usize,
+    ) -> ftd::interpreter::Result<Breakpoint> {
+        let value = value.resolve(doc, line_number)?;
+        let fields = match value.inner() {
+            Some(ftd::interpreter::Value::Record { name, fields })
+                if name.eq(ftd::interpreter::FTD_BREAKPOINT) =>
+            {
+                fields
+            }
+            t => {
+                return ftd::interpreter::utils::e2(
+                    format!(
+                        "Expected value of type record `{}`, found: {:?}",
+                        ftd::interpreter::FTD_BREAKPOINT,
+                        t
+                    ),
+                    doc.name,
+                    line_number,
+                )
+            }
+        };
+
+        let get_property_value = |field_name: &str| {
+            fields
+                .get(field_name)
+                .ok_or_else(|| ftd::interpreter::Error::ParseError {
+                    message: format!("`{}` field in ftd.breakpoint not found", field_name),
+                    doc_id: doc.name.to_string(),
+                    line_number,
+                    span: None,
+                })
+        };
+
+        Ok(Breakpoint {
+            name: get_property_value("name")?
+                .clone()
+                .resolve(doc, line_number)?
+                .string(doc.name, line_number)?,
+            max_width: get_property_value("max-width")?
+                .clone()
+                .resolve(doc, line_number)?
+                .optional_integer(doc.name, line_number)?,
+        })
+    }
+
+    pub(crate) fn breakpoint_list(
+        properties: &[ftd::interpreter::Property],
+        arguments: &[ftd::interpreter::Argument],
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+        key: &str,
+        inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
+        component_name: &str,
+    ) -> ftd::executor::Result<ftd::executor::Value<Vec<Breakpoint>>> {
+        let value = ftd::executor::value::get_value_from_properties_using_key_and_arguments_dummy(
+            key,
+            component_name,
+            properties,
+            arguments,
+            doc,
+            line_number,
+            false,
+            inherited_variables,
+        )?;
+
+        Ok(ftd::executor::Value::new(
+            match value.value {
+                Some(value) => Breakpoint::from_vec_values(value, &doc.itdoc(), line_number)?,
+                None => vec![],
+            },
+            value.line_number,
+            value.properties,
+        ))
+    }
+
+    /// The breakpoints in `ftd#breakpoints`.
+    pub fn from_bag(doc: &ftd::interpreter::TDoc) -> ftd::interpreter::Result<Vec<Breakpoint>> {
+        let value = doc.get_value(0, ftd::interpreter::FTD_BREAKPOINTS)?;
+        Breakpoint::from_vec_values(value, doc, 0)
+    }
+}
+
 #[derive(serde::Deserialize, Default, Debug, PartialEq, Clone, serde::Serialize)]
 pub enum Alignment {
     #[default]
@@ -1895,101 +2252,95 @@ impl Shadow {
 }
 
 #[derive(serde::Deserialize, Debug, Default, PartialEq, Clone, serde::Serialize)]
-pub struct Color {
-    pub light: ftd::executor::Value<ColorValue>,
-    pub dark: ftd::executor::Value<ColorValue>,
+pub struct Transform {
+    pub translate_x: Option<Length>,
+    pub translate_y: Option<Length>,
+    pub rotate: Option<f64>,
+    pub scale: Option<f64>,
+    pub skew_x: Option<f64>,
+    pub skew_y: Option<f64>,
 }
 
-impl Color {
+impl Transform {
     fn from_value(
         value: ftd::interpreter::PropertyValue,
         doc: &ftd::executor::TDoc,
         line_number: usize,
-    ) -> ftd::executor::Result<Color> {
+    ) -> ftd::executor::Result<Option<ftd::executor::Transform>> {
         let value = value.resolve(&doc.itdoc(), line_number)?;
-        let fields = match value.inner() {
+        match value.inner() {
             Some(ftd::interpreter::Value::Record { name, fields })
-                if name.eq(ftd::interpreter::FTD_COLOR) =>
+                if name.eq(ftd::interpreter::FTD_TRANSFORM) =>
             {
-                fields
-            }
-            t => {
-                return ftd::executor::utils::parse_error(
-                    format!(
-                        "Expected value of type record `{}`, found: {:?}",
-                        ftd::interpreter::FTD_COLOR,
-                        t
-                    ),
-                    doc.name,
+                Ok(Some(ftd::executor::Transform::from_values(
+                    fields,
+                    doc,
                     line_number,
-                )
+                )?))
             }
-        };
-        Color::from_values(fields, doc, line_number)
+            None => Ok(None),
+            t => ftd::executor::utils::parse_error(
+                format!(
+                    "Expected value of type record `{}`, found: {:?}",
+                    ftd::interpreter::FTD_TRANSFORM,
+                    t
+                ),
+                doc.name,
+                line_number,
+            ),
+        }
     }
 
     fn from_values(
         values: ftd::Map<ftd::interpreter::PropertyValue>,
         doc: &ftd::executor::TDoc,
         line_number: usize,
-    ) -> ftd::executor::Result<Color> {
-        let light = {
-            let value = values
-                .get("light")
-                .ok_or(ftd::executor::Error::ParseError {
-                    message: "`light` field in ftd.color not found".to_string(),
-                    doc_id: doc.name.to_string(),
-                    line_number,
-                })?;
-            ftd::executor::Value::new(
-                ColorValue::color_from(
-                    value
-                        .clone()
-                        .resolve(&doc.itdoc(), line_number)?
-                        .string(doc.name, line_number)?,
-                    doc.name,
-                    line_number,
-                )?,
-                Some(line_number),
-                vec![value.into_property(ftd::interpreter::PropertySource::header("light"))],
-            )
-        };
-
-        let dark = {
-            if let Some(value) = values.get("dark") {
-                ftd::executor::Value::new(
-                    ColorValue::color_from(
-                        value
-                            .clone()
-                            .resolve(&doc.itdoc(), line_number)?
-                            .string(doc.name, line_number)?,
-                        doc.name,
-                        line_number,
-                    )?,
-                    Some(line_number),
-                    vec![value.into_property(ftd::interpreter::PropertySource::header("dark"))],
-                )
-            } else {
-                light.clone()
+    ) -> ftd::executor::Result<ftd::executor::Transform> {
+        let get_decimal = |field_name: &str| -> ftd::executor::Result<Option<f64>> {
+            match values.get(field_name) {
+                Some(value) => Ok(value
+                    .clone()
+                    .resolve(&doc.itdoc(), line_number)?
+                    .optional_decimal(doc.name, line_number)?),
+                None => Ok(None),
             }
         };
 
-        Ok(Color { light, dark })
+        Ok(ftd::executor::Transform {
+            translate_x: Length::from_optional_value(
+                values.get("translate-x").cloned(),
+                doc,
+                line_number,
+            )?,
+            translate_y: Length::from_optional_value(
+                values.get("translate-y").cloned(),
+                doc,
+                line_number,
+            )?,
+            rotate: get_decimal("rotate")?,
+            scale: get_decimal("scale")?,
+            skew_x: get_decimal("skew-x")?,
+            skew_y: get_decimal("skew-y")?,
+        })
     }
 
     fn from_optional_values(
         or_type_value: Option<ftd::Map<ftd::interpreter::PropertyValue>>,
         doc: &ftd::executor::TDoc,
         line_number: usize,
-    ) -> ftd::executor::Result<Option<Color>> {
+    ) -> ftd::executor::Result<Option<ftd::executor::Transform>> {
         if let Some(value) = or_type_value {
-            Ok(Some(Color::from_values(value, doc, line_number)?))
+            Ok(Some(ftd::executor::Transform::from_values(
+                value,
+                doc,
+                line_number,
+            )?))
         } else {
             Ok(None)
         }
     }
 
-    pub(crate) fn optional_color(
+    pub(crate) fn optional_transform(
         properties: &[ftd::interpreter::Property],
         arguments: &[ftd::interpreter::Argument],
         doc: &ftd::executor::TDoc,
@@ -1997,7 +2348,7 @@ impl Color {
         key: &str,
         inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
         component_name: &str,
-    ) -> ftd::executor::Result<ftd::executor::Value<Option<Color>>> {
+    ) -> ftd::executor::Result<ftd::executor::Value<Option<ftd::executor::Transform>>> {
         let record_values = ftd::executor::value::optional_record_inherited(
             key,
             component_name,
@@ -2005,160 +2356,160 @@ impl Color {
             arguments,
             doc,
             line_number,
-            ftd::interpreter::FTD_COLOR,
+            ftd::interpreter::FTD_TRANSFORM,
             inherited_variables,
         )?;
 
         Ok(ftd::executor::Value::new(
-            Color::from_optional_values(record_values.value, doc, line_number)?,
+            ftd::executor::Transform::from_optional_values(record_values.value, doc, line_number)?,
             record_values.line_number,
             record_values.properties,
         ))
     }
 
-    pub fn to_css_string(&self) -> String {
-        self.light.value.to_css_string()
-    }
-
-    pub fn color_pattern() -> (String, bool) {
+    pub fn transform_pattern() -> (String, bool) {
         (
-            r#"
-                let c = {0};
-                if (typeof c === 'object' && !!c && "light" in c) {
-                    if (data["ftd#dark-mode"] && "dark" in c){ c.dark } else { c.light }
-                } else {
-                    c
-                }
-            "#
-            .to_string(),
+            "window.ftd.dependencies.eval_transform({0}, data)".to_string(),
             true,
         )
     }
+
+    pub fn to_css_string(&self, device: &Option<ftd::executor::Device>) -> String {
+        let mut functions = vec![];
+        if self.translate_x.is_some() || self.translate_y.is_some() {
+            let length = |length: &Option<Length>| {
+                length
+                    .as_ref()
+                    .map(|v| v.to_css_string(device))
+                    .unwrap_or_else(|| "0px".to_string())
+            };
+            functions.push(format!(
+                "translate({}, {})",
+                length(&self.translate_x),
+                length(&self.translate_y)
+            ));
+        }
+        if let Some(rotate) = self.rotate {
+            functions.push(format!("rotate({}deg)", rotate));
+        }
+        if let Some(scale) = self.scale {
+            functions.push(format!("scale({})", scale));
+        }
+        if self.skew_x.is_some() || self.skew_y.is_some() {
+            functions.push(format!(
+                "skew({}deg, {}deg)",
+                self.skew_x.unwrap_or_default(),
+                self.skew_y.unwrap_or_default()
+            ));
+        }
+        if functions.is_empty() {
+            return "none".to_string();
+        }
+        functions.join(" ")
+    }
 }
 
-#[derive(serde::Deserialize, Debug, PartialEq, Default, Clone, serde::Serialize)]
-pub struct ColorValue {
-    pub r: u8,
-    pub g: u8,
-    pub b: u8,
-    pub alpha: f32,
+#[derive(serde::Deserialize, Debug, Default, PartialEq, Clone, serde::Serialize)]
+pub enum Easing {
+    Linear,
+    #[default]
+    Ease,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
 }
 
-impl ColorValue {
-    pub fn color_from(
-        l: String,
-        doc_id: &str,
+impl Easing {
+    fn from_value(
+        value: ftd::interpreter::PropertyValue,
+        doc: &ftd::executor::TDoc,
         line_number: usize,
-    ) -> ftd::executor::Result<ColorValue> {
-        use std::str::FromStr;
-
-        let v = l.trim().to_string();
-
-        // Remove all whitespace, not compliant, but should just be more accepting.
-        let mut string = v.replace(' ', "");
-        string.make_ascii_lowercase();
-        if v.starts_with('#') && v.len() == 9 {
-            let (_, value_string) = string.split_at(1);
-
-            let iv = u64::from_str_radix(value_string, 16).map_err(|e| {
-                ftd::executor::Error::ParseError {
-                    message: e.to_string(),
-                    doc_id: doc_id.to_string(),
-                    line_number: 0,
-                }
-            })?;
-
-            // (7thSigil) unlike original js code, NaN is impossible
-            if iv > 0xffffffff {
-                return ftd::executor::utils::parse_error(
-                    format!("{} is not a valid color", v),
-                    doc_id,
-                    line_number,
-                );
-            }
-
-            //Code for accepting 6-digit hexa-color code
-            Ok(ColorValue {
-                r: ((iv & 0xff000000) >> 24) as u8,
-                g: ((iv & 0xff0000) >> 16) as u8,
-                b: ((iv & 0xff00) >> 8) as u8,
-                alpha: round_1p((iv & 0xff) as f32 / 255_f32),
-            })
-        } else {
-            match css_color_parser::Color::from_str(v.as_str()) {
-                Ok(v) => Ok(ColorValue {
-                    r: v.r,
-                    g: v.g,
-                    b: v.b,
-                    alpha: v.a,
-                }),
-                Err(e) => ftd::executor::utils::parse_error(
-                    format!("{} is not a valid color: {:?}", v, e),
-                    doc_id,
-                    line_number,
-                ),
-            }
+    ) -> ftd::executor::Result<Easing> {
+        let binding = value.resolve(&doc.itdoc(), line_number)?;
+        let value = binding.get_or_type(doc.name, line_number)?;
+        match value.1.as_str() {
+            ftd::interpreter::FTD_EASING_LINEAR => Ok(Easing::Linear),
+            ftd::interpreter::FTD_EASING_EASE => Ok(Easing::Ease),
+            ftd::interpreter::FTD_EASING_EASE_IN => Ok(Easing::EaseIn),
+            ftd::interpreter::FTD_EASING_EASE_OUT => Ok(Easing::EaseOut),
+            ftd::interpreter::FTD_EASING_EASE_IN_OUT => Ok(Easing::EaseInOut),
+            t => ftd::executor::utils::parse_error(
+                format!("Unknown variant `{}` for or-type `ftd.easing`", t),
+                doc.name,
+                line_number,
+            ),
         }
     }
 
     pub fn to_css_string(&self) -> String {
-        format!("rgba({},{},{},{})", self.r, self.g, self.b, self.alpha)
+        match self {
+            Easing::Linear => "linear".to_string(),
+            Easing::Ease => "ease".to_string(),
+            Easing::EaseIn => "ease-in".to_string(),
+            Easing::EaseOut => "ease-out".to_string(),
+            Easing::EaseInOut => "ease-in-out".to_string(),
+        }
     }
 }
 
-fn round_1p(n: f32) -> f32 {
-    // 1234.56
-    let temp = (n * 10_f32) as u32;
-    let last = (temp % 10) as f32;
-    let front = n as u32;
-
-    front as f32 + last / 10_f32
-}
-
-#[derive(serde::Deserialize, Debug, PartialEq, Clone, serde::Serialize)]
-pub enum Spacing {
-    Fixed(Length),
-    SpaceBetween,
-    SpaceEvenly,
-    SpaceAround,
+#[derive(serde::Deserialize, Debug, Default, PartialEq, Clone, serde::Serialize)]
+pub struct Transition {
+    pub property: String,
+    pub duration: f64,
+    pub easing: Easing,
+    pub delay: f64,
 }
 
-impl Spacing {
-    fn from_optional_values(
-        or_type_value: Option<(String, ftd::interpreter::PropertyValue)>,
+impl Transition {
+    fn from_values(
+        values: ftd::Map<ftd::interpreter::PropertyValue>,
         doc: &ftd::executor::TDoc,
         line_number: usize,
-    ) -> ftd::executor::Result<Option<Self>> {
-        if let Some(value) = or_type_value {
-            Ok(Some(Spacing::from_values(value, doc, line_number)?))
-        } else {
-            Ok(None)
-        }
+    ) -> ftd::executor::Result<ftd::executor::Transition> {
+        let get_property_value = |field_name: &str| {
+            values
+                .get(field_name)
+                .ok_or_else(|| ftd::executor::Error::ParseError {
+                    message: format!("`{}` field in ftd.transition not found", field_name),
+                    doc_id: doc.name.to_string(),
+                    line_number,
+                })
+        };
+
+        Ok(ftd::executor::Transition {
+            property: get_property_value("property")?
+                .clone()
+                .resolve(&doc.itdoc(), line_number)?
+                .string(doc.name, line_number)?,
+            duration: get_property_value("duration")?
+                .clone()
+                .resolve(&doc.itdoc(), line_number)?
+                .decimal(doc.name, line_number)?,
+            easing: Easing::from_value(get_property_value("easing")?.clone(), doc, line_number)?,
+            delay: get_property_value("delay")?
+                .clone()
+                .resolve(&doc.itdoc(), line_number)?
+                .decimal(doc.name, line_number)?,
+        })
     }
 
-    fn from_values(
-        or_type_value: (String, ftd::interpreter::PropertyValue),
+    fn from_optional_values(
+        or_type_value: Option<ftd::Map<ftd::interpreter::PropertyValue>>,
         doc: &ftd::executor::TDoc,
         line_number: usize,
-    ) -> ftd::executor::Result<Self> {
-        match or_type_value.0.as_str() {
-            ftd::interpreter::FTD_SPACING_SPACE_BETWEEN => Ok(Spacing::SpaceBetween),
-            ftd::interpreter::FTD_SPACING_SPACE_EVENLY => Ok(Spacing::SpaceEvenly),
-            ftd::interpreter::FTD_SPACING_SPACE_AROUND => Ok(Spacing::SpaceAround),
-            ftd::interpreter::FTD_SPACING_FIXED => Ok(Spacing::Fixed(Length::from_value(
-                or_type_value.1.to_owned(),
+    ) -> ftd::executor::Result<Option<ftd::executor::Transition>> {
+        if let Some(value) = or_type_value {
+            Ok(Some(ftd::executor::Transition::from_values(
+                value,
                 doc,
                 line_number,
-            )?)),
-            t => ftd::executor::utils::parse_error(
-                format!("Unknown variant `{}` for or-type `ftd.spacing`", t),
-                doc.name,
-                line_number,
-            ),
+            )?))
+        } else {
+            Ok(None)
         }
     }
 
-    pub(crate) fn optional_spacing_mode(
+    pub(crate) fn optional_transition(
         properties: &[ftd::interpreter::Property],
         arguments: &[ftd::interpreter::Argument],
         doc: &ftd::executor::TDoc,
@@ -2166,182 +2517,220 @@ impl Spacing {
         key: &str,
         inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
         component_name: &str,
-    ) -> ftd::executor::Result<ftd::executor::Value<Option<Spacing>>> {
-        let or_type_value = ftd::executor::value::optional_or_type(
+    ) -> ftd::executor::Result<ftd::executor::Value<Option<ftd::executor::Transition>>> {
+        let record_values = ftd::executor::value::optional_record_inherited(
             key,
             component_name,
             properties,
             arguments,
             doc,
             line_number,
-            ftd::interpreter::FTD_SPACING,
+            ftd::interpreter::FTD_TRANSITION,
             inherited_variables,
         )?;
 
         Ok(ftd::executor::Value::new(
-            Spacing::from_optional_values(or_type_value.value, doc, line_number)?,
-            or_type_value.line_number,
-            or_type_value.properties,
+            ftd::executor::Transition::from_optional_values(record_values.value, doc, line_number)?,
+            record_values.line_number,
+            record_values.properties,
         ))
     }
 
-    pub fn to_gap_css_string(&self, device: &Option<ftd::executor::Device>) -> String {
-        match self {
-            Spacing::Fixed(f) => f.to_css_string(device),
-            _ => "0".to_string(),
-        }
+    pub fn transition_pattern() -> (String, bool) {
+        (
+            "window.ftd.dependencies.eval_transition({0}, data)".to_string(),
+            true,
+        )
     }
 
-    pub fn to_justify_content_css_string(&self) -> String {
-        match self {
-            Spacing::SpaceBetween => "space-between".to_string(),
-            Spacing::SpaceEvenly => "space-evenly".to_string(),
-            Spacing::SpaceAround => "space-around".to_string(),
-            Spacing::Fixed(_) => "unset".to_string(),
-        }
-    }
-
-    pub fn justify_content_pattern() -> (String, bool) {
-        (
-            indoc::indoc! {"
-                if ({0} == \"space-between\" || {0} == \"space-around\" || {0} == \"space-evenly\") {
-                    {0}
-                } else {
-                    \"start\"
-                }
-            "}
-            .to_string(),
-            true,
-        )
-    }
-
-    pub fn fixed_content_pattern() -> (String, bool) {
-        (
-            indoc::indoc! {"
-                if ({0} != \"space-between\" && {0} != \"space_around\" && {0} != \"space-evenly\") {
-                    {0}
-                } else {
-                    null
-                }
-            "}.to_string(),
-            true,
+    pub fn to_css_string(&self) -> String {
+        format!(
+            "{} {}s {} {}s",
+            self.property,
+            self.duration,
+            self.easing.to_css_string(),
+            self.delay
         )
     }
 }
 
-#[derive(serde::Deserialize, Debug, PartialEq, Clone, serde::Serialize)]
-pub enum AlignSelf {
-    Start,
-    Center,
-    End,
+#[derive(serde::Deserialize, Debug, Default, PartialEq, Clone, serde::Serialize)]
+pub struct Keyframe {
+    pub at: i64,
+    pub opacity: Option<f64>,
+    pub transform: Option<Transform>,
 }
 
-impl AlignSelf {
-    fn from_optional_values(
-        or_type_value: Option<(String, ftd::interpreter::PropertyValue)>,
+impl Keyframe {
+    fn from_vec_values(
+        value: ftd::interpreter::PropertyValue,
         doc: &ftd::executor::TDoc,
         line_number: usize,
-    ) -> ftd::executor::Result<Option<Self>> {
-        if let Some(value) = or_type_value {
-            Ok(Some(AlignSelf::from_values(value, doc, line_number)?))
-        } else {
-            Ok(None)
-        }
+    ) -> ftd::executor::Result<Vec<Keyframe>> {
+        let mut result = vec![];
+        let value = value.resolve(&doc.itdoc(), line_number)?;
+        match value.inner() {
+            Some(ftd::interpreter::Value::List { data, kind })
+                if kind.kind.get_name().eq(ftd::interpreter::FTD_KEYFRAME) =>
+            {
+                for element in data.iter() {
+                    let ln = element.line_number();
+                    result.push(Keyframe::from_value(element.to_owned(), doc, ln)?)
+                }
+            }
+            t => {
+                return ftd::executor::utils::parse_error(
+                    format!(
+                        "Expected list value of type `{}`, found: {:?}",
+                        ftd::interpreter::FTD_KEYFRAME,
+                        t
+                    ),
+                    doc.name,
+                    line_number,
+                )
+            }
+        };
+        Ok(result)
     }
 
-    fn from_values(
-        or_type_value: (String, ftd::interpreter::PropertyValue),
+    fn from_value(
+        value: ftd::interpreter::PropertyValue,
         doc: &ftd::executor::TDoc,
         line_number: usize,
-    ) -> ftd::executor::Result<Self> {
-        match or_type_value.0.as_str() {
-            ftd::interpreter::FTD_ALIGN_SELF_START => Ok(AlignSelf::Start),
-            ftd::interpreter::FTD_ALIGN_SELF_CENTER => Ok(AlignSelf::Center),
-            ftd::interpreter::FTD_ALIGN_SELF_END => Ok(AlignSelf::End),
-            t => ftd::executor::utils::parse_error(
-                format!("Unknown variant `{}` for or-type `ftd.align-self`", t),
-                doc.name,
-                line_number,
-            ),
-        }
-    }
+    ) -> ftd::executor::Result<Keyframe> {
+        let value = value.resolve(&doc.itdoc(), line_number)?;
+        let fields = match value.inner() {
+            Some(ftd::interpreter::Value::Record { name, fields })
+                if name.eq(ftd::interpreter::FTD_KEYFRAME) =>
+            {
+                fields
+            }
+            t => {
+                return ftd::executor::utils::parse_error(
+                    format!(
+                        "Expected value of type record `{}`, found: {:?}",
+                        ftd::interpreter::FTD_KEYFRAME,
+                        t
+                    ),
+                    doc.name,
+                    line_number,
+                )
+            }
+        };
 
-    pub(crate) fn optional_align_self(
-        properties: &[ftd::interpreter::Property],
-        arguments: &[ftd::interpreter::Argument],
-        doc: &ftd::executor::TDoc,
-        line_number: usize,
-        key: &str,
-        inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
-        component_name: &str,
-    ) -> ftd::executor::Result<ftd::executor::Value<Option<AlignSelf>>> {
-        let or_type_value = ftd::executor::value::optional_or_type(
-            key,
-            component_name,
-            properties,
-            arguments,
-            doc,
-            line_number,
-            ftd::interpreter::FTD_ALIGN_SELF,
-            inherited_variables,
-        )?;
+        let get_property_value = |field_name: &str| {
+            fields
+                .get(field_name)
+                .ok_or_else(|| ftd::executor::Error::ParseError {
+                    message: format!("`{}` field in ftd.keyframe not found", field_name),
+                    doc_id: doc.name.to_string(),
+                    line_number,
+                })
+        };
 
-        Ok(ftd::executor::Value::new(
-            AlignSelf::from_optional_values(or_type_value.value, doc, line_number)?,
-            or_type_value.line_number,
-            or_type_value.properties,
-        ))
+        Ok(Keyframe {
+            at: get_property_value("at")?
+                .clone()
+                .resolve(&doc.itdoc(), line_number)?
+                .integer(doc.name, line_number)?,
+            opacity: get_property_value("opacity")?
+                .clone()
+                .resolve(&doc.itdoc(), line_number)?
+                .optional_decimal(doc.name, line_number)?,
+            transform: Transform::from_value(
+                get_property_value("transform")?.clone(),
+                doc,
+                line_number,
+            )?,
+        })
     }
 
-    pub fn to_css_string(&self) -> String {
-        match self {
-            AlignSelf::Start => "start".to_string(),
-            AlignSelf::Center => "center".to_string(),
-            AlignSelf::End => "end".to_string(),
+    pub fn to_css_string(&self, device: &Option<ftd::executor::Device>) -> String {
+        let mut declarations = vec![];
+        if let Some(opacity) = self.opacity {
+            declarations.push(format!("opacity: {};", opacity));
         }
+        if let Some(ref transform) = self.transform {
+            declarations.push(format!("transform: {};", transform.to_css_string(device)));
+        }
+        format!("{}% {{ {} }}", self.at, declarations.join(" "))
     }
 }
 
-#[derive(serde::Deserialize, Debug, PartialEq, Clone, serde::Serialize)]
-pub enum Overflow {
-    Scroll,
-    Visible,
-    Hidden,
-    Auto,
+#[derive(serde::Deserialize, Debug, Default, PartialEq, Clone, serde::Serialize)]
+pub struct Animation {
+    pub keyframes: Vec<Keyframe>,
+    pub duration: f64,
+    pub easing: Easing,
+    pub delay: f64,
+    pub iteration_count: i64,
+    pub infinite: bool,
+    pub alternate: bool,
 }
 
-impl Overflow {
-    fn from_optional_values(
-        or_type_value: Option<(String, ftd::interpreter::PropertyValue)>,
+impl Animation {
+    fn from_values(
+        values: ftd::Map<ftd::interpreter::PropertyValue>,
         doc: &ftd::executor::TDoc,
         line_number: usize,
-    ) -> ftd::executor::Result<Option<Self>> {
-        if let Some(value) = or_type_value {
-            Ok(Some(Overflow::from_values(value, doc, line_number)?))
-        } else {
-            Ok(None)
-        }
+    ) -> ftd::executor::Result<ftd::executor::Animation> {
+        let get_property_value = |field_name: &str| {
+            values
+                .get(field_name)
+                .ok_or_else(|| ftd::executor::Error::ParseError {
+                    message: format!("`{}` field in ftd.animation not found", field_name),
+                    doc_id: doc.name.to_string(),
+                    line_number,
+                })
+        };
+
+        Ok(ftd::executor::Animation {
+            keyframes: Keyframe::from_vec_values(
+                get_property_value("keyframes")?.clone(),
+                doc,
+                line_number,
+            )?,
+            duration: get_property_value("duration")?
+                .clone()
+                .resolve(&doc.itdoc(), line_number)?
+                .decimal(doc.name, line_number)?,
+            easing: Easing::from_value(get_property_value("easing")?.clone(), doc, line_number)?,
+            delay: get_property_value("delay")?
+                .clone()
+                .resolve(&doc.itdoc(), line_number)?
+                .decimal(doc.name, line_number)?,
+            iteration_count: get_property_value("iteration-count")?
+                .clone()
+                .resolve(&doc.itdoc(), line_number)?
+                .integer(doc.name, line_number)?,
+            infinite: get_property_value("infinite")?
+                .clone()
+                .resolve(&doc.itdoc(), line_number)?
+                .bool(doc.name, line_number)?,
+            alternate: get_property_value("alternate")?
+                .clone()
+                .resolve(&doc.itdoc(), line_number)?
+                .bool(doc.name, line_number)?,
+        })
     }
 
-    fn from_values(
-        or_type_value: (String, ftd::interpreter::PropertyValue),
+    fn from_optional_values(
+        or_type_value: Option<ftd::Map<ftd::interpreter::PropertyValue>>,
         doc: &ftd::executor::TDoc,
         line_number: usize,
-    ) -> ftd::executor::Result<Self> {
-        match or_type_value.0.as_str() {
-            ftd::interpreter::FTD_OVERFLOW_SCROLL => Ok(Overflow::Scroll),
-            ftd::interpreter::FTD_OVERFLOW_VISIBLE => Ok(Overflow::Visible),
-            ftd::interpreter::FTD_OVERFLOW_HIDDEN => Ok(Overflow::Hidden),
-            ftd::interpreter::FTD_OVERFLOW_AUTO => Ok(Overflow::Auto),
-            t => ftd::executor::utils::parse_error(
-                format!("Unknown variant `{}` for or-type `ftd.overflow`", t),
-                doc.name,
+    ) -> ftd::executor::Result<Option<ftd::executor::Animation>> {
+        if let Some(value) = or_type_value {
+            Ok(Some(ftd::executor::Animation::from_values(
+                value,
+                doc,
                 line_number,
-            ),
+            )?))
+        } else {
+            Ok(None)
         }
     }
 
-    pub(crate) fn optional_overflow(
+    pub(crate) fn optional_animation(
         properties: &[ftd::interpreter::Property],
         arguments: &[ftd::interpreter::Argument],
         doc: &ftd::executor::TDoc,
@@ -2349,73 +2738,177 @@ impl Overflow {
         key: &str,
         inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
         component_name: &str,
-    ) -> ftd::executor::Result<ftd::executor::Value<Option<Overflow>>> {
-        let or_type_value = ftd::executor::value::optional_or_type(
+    ) -> ftd::executor::Result<ftd::executor::Value<Option<ftd::executor::Animation>>> {
+        let record_values = ftd::executor::value::optional_record_inherited(
             key,
             component_name,
             properties,
             arguments,
             doc,
             line_number,
-            ftd::interpreter::FTD_OVERFLOW,
+            ftd::interpreter::FTD_ANIMATION,
             inherited_variables,
         )?;
 
         Ok(ftd::executor::Value::new(
-            Overflow::from_optional_values(or_type_value.value, doc, line_number)?,
-            or_type_value.line_number,
-            or_type_value.properties,
+            ftd::executor::Animation::from_optional_values(record_values.value, doc, line_number)?,
+            record_values.line_number,
+            record_values.properties,
         ))
     }
 
-    pub fn to_css_string(&self) -> String {
-        match self {
-            Overflow::Scroll => "scroll".to_string(),
-            Overflow::Visible => "visible".to_string(),
-            Overflow::Hidden => "hidden".to_string(),
-            Overflow::Auto => "auto".to_string(),
-        }
+    /// The name of the `@keyframes` rule of the animation. It is derived from the keyframes so
+    /// that elements sharing an animation share the rule.
+    pub fn name(&self, device: &Option<ftd::executor::Device>) -> String {
+        // FNV-1a, the name has to be the same in every build of the page
+        let hash = self
+            .keyframes_css_string(device)
+            .bytes()
+            .fold(0x811c9dc5_u32, |hash, byte| {
+                (hash ^ byte as u32).wrapping_mul(0x01000193)
+            });
+        format!("ftd-keyframes-{:08x}", hash)
     }
-}
 
-#[derive(serde::Deserialize, Debug, PartialEq, Clone, serde::Serialize)]
-pub enum Resize {
-    Both,
-    Horizontal,
-    Vertical,
-}
+    fn keyframes_css_string(&self, device: &Option<ftd::executor::Device>) -> String {
+        self.keyframes
+            .iter()
+            .map(|keyframe| keyframe.to_css_string(device))
+            .join(" ")
+    }
 
-impl Resize {
-    fn from_optional_values(
-        or_type_value: Option<(String, ftd::interpreter::PropertyValue)>,
-        doc: &ftd::executor::TDoc,
-        line_number: usize,
-    ) -> ftd::executor::Result<Option<Self>> {
-        if let Some(value) = or_type_value {
-            Ok(Some(Resize::from_values(value, doc, line_number)?))
-        } else {
-            Ok(None)
-        }
+    pub fn keyframes_rule(&self, device: &Option<ftd::executor::Device>) -> String {
+        format!(
+            "@keyframes {} {{ {} }}",
+            self.name(device),
+            self.keyframes_css_string(device)
+        )
     }
 
-    fn from_values(
-        or_type_value: (String, ftd::interpreter::PropertyValue),
-        doc: &ftd::executor::TDoc,
-        line_number: usize,
-    ) -> ftd::executor::Result<Self> {
-        match or_type_value.0.as_str() {
-            ftd::interpreter::FTD_RESIZE_HORIZONTAL => Ok(Resize::Horizontal),
-            ftd::interpreter::FTD_RESIZE_VERTICAL => Ok(Resize::Vertical),
-            ftd::interpreter::FTD_RESIZE_BOTH => Ok(Resize::Both),
-            t => ftd::executor::utils::parse_error(
-                format!("Unknown variant `{}` for or-type `ftd.resize`", t),
-                doc.name,
-                line_number,
-            ),
+    pub fn animation_pattern() -> (String, bool) {
+        (
+            "window.ftd.dependencies.eval_animation({0}, data)".to_string(),
+            true,
+        )
+    }
+
+    pub fn to_css_string(&self, device: &Option<ftd::executor::Device>) -> String {
+        format!(
+            "{} {}s {} {}s {} {}",
+            self.name(device),
+            self.duration,
+            self.easing.to_css_string(),
+            self.delay,
+            if self.infinite {
+                "infinite".to_string()
+            } else {
+                self.iteration_count.to_string()
+            },
+            if self.alternate {
+                "alternate"
+            } else {
+                "normal"
+            }
+        )
+    }
+}
+
+#[derive(serde::Deserialize, Debug, Default, PartialEq, Clone, serde::Serialize)]
+pub struct Color {
+    pub light: ftd::executor::Value<ColorValue>,
+    pub dark: ftd::executor::Value<ColorValue>,
+}
+
+impl Color {
+    fn from_value(
+        value: ftd::interpreter::PropertyValue,
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<Color> {
+        let value = value.resolve(&doc.itdoc(), line_number)?;
+        let fields = match value.inner() {
+            Some(ftd::interpreter::Value::Record { name, fields })
+                if name.eq(ftd::interpreter::FTD_COLOR) =>
+            {
+                fields
+            }
+            t => {
+                return ftd::executor::utils::parse_error(
+                    format!(
+                        "Expected value of type record `{}`, found: {:?}",
+                        ftd::interpreter::FTD_COLOR,
+                        t
+                    ),
+                    doc.name,
+                    line_number,
+                )
+            }
+        };
+        Color::from_values(fields, doc, line_number)
+    }
+
+    fn from_values(
+        values: ftd::Map<ftd::interpreter::PropertyValue>,
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<Color> {
+        let light = {
+            let value = values
+                .get("light")
+                .ok_or(ftd::executor::Error::ParseError {
+                    message: "`light` field in ftd.color not found".to_string(),
+                    doc_id: doc.name.to_string(),
+                    line_number,
+                })?;
+            ftd::executor::Value::new(
+                ColorValue::color_from(
+                    value
+                        .clone()
+                        .resolve(&doc.itdoc(), line_number)?
+                        .string(doc.name, line_number)?,
+                    doc.name,
+                    line_number,
+                )?,
+                Some(line_number),
+                vec![value.into_property(ftd::interpreter::PropertySource::header("light"))],
+            )
+        };
+
+        let dark = {
+            if let Some(value) = values.get("dark") {
+                ftd::executor::Value::new(
+                    ColorValue::color_from(
+                        value
+                            .clone()
+                            .resolve(&doc.itdoc(), line_number)?
+                            .string(doc.name, line_number)?,
+                        doc.name,
+                        line_number,
+                    )?,
+                    Some(line_number),
+                    vec![value.into_property(ftd::interpreter::PropertySource::header("dark"))],
+                )
+            } else {
+                light.clone()
+            }
+        };
+
+        Ok(Color { light, dark })
+    }
+
+    fn from_optional_values(
+        or_type_value: Option<ftd::Map<ftd::interpreter::PropertyValue>>,
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<Option<Color>> {
+        if let Some(value) = or_type_value {
+            Ok(Some(Color::from_values(value, doc, line_number)?))
+        } else {
+            Ok(None)
         }
     }
 
-    pub(crate) fn optional_resize(
+    pub(crate) fn optional_color(
         properties: &[ftd::interpreter::Property],
         arguments: &[ftd::interpreter::Argument],
         doc: &ftd::executor::TDoc,
@@ -2423,50 +2916,140 @@ impl Resize {
         key: &str,
         inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
         component_name: &str,
-    ) -> ftd::executor::Result<ftd::executor::Value<Option<Resize>>> {
-        let or_type_value = ftd::executor::value::optional_or_type(
+    ) -> ftd::executor::Result<ftd::executor::Value<Option<Color>>> {
+        let record_values = ftd::executor::value::optional_record_inherited(
             key,
             component_name,
             properties,
             arguments,
             doc,
             line_number,
-            ftd::interpreter::FTD_RESIZE,
+            ftd::interpreter::FTD_COLOR,
             inherited_variables,
         )?;
 
         Ok(ftd::executor::Value::new(
-            Resize::from_optional_values(or_type_value.value, doc, line_number)?,
-            or_type_value.line_number,
-            or_type_value.properties,
+            Color::from_optional_values(record_values.value, doc, line_number)?,
+            record_values.line_number,
+            record_values.properties,
         ))
     }
 
     pub fn to_css_string(&self) -> String {
-        match self {
-            Resize::Horizontal => "horizontal".to_string(),
-            Resize::Vertical => "vertical".to_string(),
-            Resize::Both => "both".to_string(),
+        self.light.value.to_css_string()
+    }
+
+    pub fn color_pattern() -> (String, bool) {
+        (
+            r#"
+                let c = {0};
+                if (typeof c === 'object' && !!c && "light" in c) {
+                    if (data["ftd#dark-mode"] && "dark" in c){ c.dark } else { c.light }
+                } else {
+                    c
+                }
+            "#
+            .to_string(),
+            true,
+        )
+    }
+}
+
+#[derive(serde::Deserialize, Debug, PartialEq, Default, Clone, serde::Serialize)]
+pub struct ColorValue {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub alpha: f32,
+}
+
+impl ColorValue {
+    pub fn color_from(
+        l: String,
+        doc_id: &str,
+        line_number: usize,
+    ) -> ftd::executor::Result<ColorValue> {
+        use std::str::FromStr;
+
+        let v = l.trim().to_string();
+
+        // Remove all whitespace, not compliant, but should just be more accepting.
+        let mut string = v.replace(' ', "");
+        string.make_ascii_lowercase();
+        if v.starts_with('#') && v.len() == 9 {
+            let (_, value_string) = string.split_at(1);
+
+            let iv = u64::from_str_radix(value_string, 16).map_err(|e| {
+                ftd::executor::Error::ParseError {
+                    message: e.to_string(),
+                    doc_id: doc_id.to_string(),
+                    line_number: 0,
+                }
+            })?;
+
+            // (7thSigil) unlike original js code, NaN is impossible
+            if iv > 0xffffffff {
+                return ftd::executor::utils::parse_error(
+                    format!("{} is not a valid color", v),
+                    doc_id,
+                    line_number,
+                );
+            }
+
+            //Code for accepting 6-digit hexa-color code
+            Ok(ColorValue {
+                r: ((iv & 0xff000000) >> 24) as u8,
+                g: ((iv & 0xff0000) >> 16) as u8,
+                b: ((iv & 0xff00) >> 8) as u8,
+                alpha: round_1p((iv & 0xff) as f32 / 255_f32),
+            })
+        } else {
+            match css_color_parser::Color::from_str(v.as_str()) {
+                Ok(v) => Ok(ColorValue {
+                    r: v.r,
+                    g: v.g,
+                    b: v.b,
+                    alpha: v.a,
+                }),
+                Err(e) => ftd::executor::utils::parse_error(
+                    format!("{} is not a valid color: {:?}", v, e),
+                    doc_id,
+                    line_number,
+                ),
+            }
         }
     }
+
+    pub fn to_css_string(&self) -> String {
+        format!("rgba({},{},{},{})", self.r, self.g, self.b, self.alpha)
+    }
+}
+
+fn round_1p(n: f32) -> f32 {
+    // 1234.56
+    let temp = (n * 10_f32) as u32;
+    let last = (temp % 10) as f32;
+    let front = n as u32;
+
+    front as f32 + last / 10_f32
 }
 
 #[derive(serde::Deserialize, Debug, PartialEq, Clone, serde::Serialize)]
-pub enum TextAlign {
-    Start,
-    Center,
-    End,
-    Justify,
+pub enum Spacing {
+    Fixed(Length),
+    SpaceBetween,
+    SpaceEvenly,
+    SpaceAround,
 }
 
-impl TextAlign {
+impl Spacing {
     fn from_optional_values(
         or_type_value: Option<(String, ftd::interpreter::PropertyValue)>,
         doc: &ftd::executor::TDoc,
         line_number: usize,
     ) -> ftd::executor::Result<Option<Self>> {
         if let Some(value) = or_type_value {
-            Ok(Some(TextAlign::from_values(value, doc, line_number)?))
+            Ok(Some(Spacing::from_values(value, doc, line_number)?))
         } else {
             Ok(None)
         }
@@ -2478,19 +3061,23 @@ impl TextAlign {
         line_number: usize,
     ) -> ftd::executor::Result<Self> {
         match or_type_value.0.as_str() {
-            ftd::interpreter::FTD_TEXT_ALIGN_START => Ok(TextAlign::Start),
-            ftd::interpreter::FTD_TEXT_ALIGN_CENTER => Ok(TextAlign::Center),
-            ftd::interpreter::FTD_TEXT_ALIGN_END => Ok(TextAlign::End),
-            ftd::interpreter::FTD_TEXT_ALIGN_JUSTIFY => Ok(TextAlign::Justify),
+            ftd::interpreter::FTD_SPACING_SPACE_BETWEEN => Ok(Spacing::SpaceBetween),
+            ftd::interpreter::FTD_SPACING_SPACE_EVENLY => Ok(Spacing::SpaceEvenly),
+            ftd::interpreter::FTD_SPACING_SPACE_AROUND => Ok(Spacing::SpaceAround),
+            ftd::interpreter::FTD_SPACING_FIXED => Ok(Spacing::Fixed(Length::from_value(
+                or_type_value.1.to_owned(),
+                doc,
+                line_number,
+            )?)),
             t => ftd::executor::utils::parse_error(
-                format!("Unknown variant `{}` for or-type `ftd.text-align`", t),
+                format!("Unknown variant `{}` for or-type `ftd.spacing`", t),
                 doc.name,
                 line_number,
             ),
         }
     }
 
-    pub(crate) fn optional_text_align(
+    pub(crate) fn optional_spacing_mode(
         properties: &[ftd::interpreter::Property],
         arguments: &[ftd::interpreter::Argument],
         doc: &ftd::executor::TDoc,
@@ -2498,7 +3085,7 @@ impl TextAlign {
         key: &str,
         inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
         component_name: &str,
-    ) -> ftd::executor::Result<ftd::executor::Value<Option<TextAlign>>> {
+    ) -> ftd::executor::Result<ftd::executor::Value<Option<Spacing>>> {
         let or_type_value = ftd::executor::value::optional_or_type(
             key,
             component_name,
@@ -2506,74 +3093,76 @@ impl TextAlign {
             arguments,
             doc,
             line_number,
-            ftd::interpreter::FTD_TEXT_ALIGN,
+            ftd::interpreter::FTD_SPACING,
             inherited_variables,
         )?;
 
         Ok(ftd::executor::Value::new(
-            TextAlign::from_optional_values(or_type_value.value, doc, line_number)?,
+            Spacing::from_optional_values(or_type_value.value, doc, line_number)?,
             or_type_value.line_number,
             or_type_value.properties,
         ))
     }
 
-    pub fn to_css_string(&self) -> String {
+    pub fn to_gap_css_string(&self, device: &Option<ftd::executor::Device>) -> String {
         match self {
-            TextAlign::Start => "start".to_string(),
-            TextAlign::Center => "center".to_string(),
-            TextAlign::End => "end".to_string(),
-            TextAlign::Justify => "justify".to_string(),
+            Spacing::Fixed(f) => f.to_css_string(device),
+            _ => "0".to_string(),
+        }
+    }
+
+    pub fn to_justify_content_css_string(&self) -> String {
+        match self {
+            Spacing::SpaceBetween => "space-between".to_string(),
+            Spacing::SpaceEvenly => "space-evenly".to_string(),
+            Spacing::SpaceAround => "space-around".to_string(),
+            Spacing::Fixed(_) => "unset".to_string(),
         }
     }
+
+    pub fn justify_content_pattern() -> (String, bool) {
+        (
+            indoc::indoc! {"
+                if ({0} == \"space-between\" || {0} == \"space-around\" || {0} == \"space-evenly\") {
+                    {0}
+                } else {
+                    \"start\"
+                }
+            "}
+            .to_string(),
+            true,
+        )
+    }
+
+    pub fn fixed_content_pattern() -> (String, bool) {
+        (
+            indoc::indoc! {"
+                if ({0} != \"space-between\" && {0} != \"space_around\" && {0} != \"space-evenly\") {
+                    {0}
+                } else {
+                    null
+                }
+            "}.to_string(),
+            true,
+        )
+    }
 }
 
 #[derive(serde::Deserialize, Debug, PartialEq, Clone, serde::Serialize)]
-pub enum Cursor {
-    Default,
-    None,
-    ContextMenu,
-    Help,
-    Pointer,
-    Progress,
-    Wait,
-    Cell,
-    CrossHair,
-    Text,
-    VerticalText,
-    Alias,
-    Copy,
-    Move,
-    NoDrop,
-    NotAllowed,
-    Grab,
-    Grabbing,
-    EResize,
-    NResize,
-    NeResize,
-    NwResize,
-    SResize,
-    SeResize,
-    SwResize,
-    WResize,
-    EwResize,
-    NsResize,
-    NeswResize,
-    NwseResize,
-    ColResize,
-    RowResize,
-    AllScroll,
-    ZoomIn,
-    ZoomOut,
+pub enum AlignSelf {
+    Start,
+    Center,
+    End,
 }
 
-impl Cursor {
+impl AlignSelf {
     fn from_optional_values(
         or_type_value: Option<(String, ftd::interpreter::PropertyValue)>,
         doc: &ftd::executor::TDoc,
         line_number: usize,
     ) -> ftd::executor::Result<Option<Self>> {
         if let Some(value) = or_type_value {
-            Ok(Some(Cursor::from_values(value, doc, line_number)?))
+            Ok(Some(AlignSelf::from_values(value, doc, line_number)?))
         } else {
             Ok(None)
         }
@@ -2585,50 +3174,18 @@ impl Cursor {
         line_number: usize,
     ) -> ftd::executor::Result<Self> {
         match or_type_value.0.as_str() {
-            ftd::interpreter::FTD_CURSOR_DEFAULT => Ok(Cursor::Default),
-            ftd::interpreter::FTD_CURSOR_NONE => Ok(Cursor::None),
-            ftd::interpreter::FTD_CURSOR_CONTEXT_MENU => Ok(Cursor::ContextMenu),
-            ftd::interpreter::FTD_CURSOR_HELP => Ok(Cursor::Help),
-            ftd::interpreter::FTD_CURSOR_POINTER => Ok(Cursor::Pointer),
-            ftd::interpreter::FTD_CURSOR_PROGRESS => Ok(Cursor::Progress),
-            ftd::interpreter::FTD_CURSOR_WAIT => Ok(Cursor::Wait),
-            ftd::interpreter::FTD_CURSOR_CELL => Ok(Cursor::Cell),
-            ftd::interpreter::FTD_CURSOR_CROSSHAIR => Ok(Cursor::CrossHair),
-            ftd::interpreter::FTD_CURSOR_TEXT => Ok(Cursor::Text),
-            ftd::interpreter::FTD_CURSOR_VERTICAL_TEXT => Ok(Cursor::VerticalText),
-            ftd::interpreter::FTD_CURSOR_ALIAS => Ok(Cursor::Alias),
-            ftd::interpreter::FTD_CURSOR_COPY => Ok(Cursor::Copy),
-            ftd::interpreter::FTD_CURSOR_MOVE => Ok(Cursor::Move),
-            ftd::interpreter::FTD_CURSOR_NO_DROP => Ok(Cursor::NoDrop),
-            ftd::interpreter::FTD_CURSOR_NOT_ALLOWED => Ok(Cursor::NotAllowed),
-            ftd::interpreter::FTD_CURSOR_GRAB => Ok(Cursor::Grab),
-            ftd::interpreter::FTD_CURSOR_GRABBING => Ok(Cursor::Grabbing),
-            ftd::interpreter::FTD_CURSOR_E_RESIZE => Ok(Cursor::EResize),
-            ftd::interpreter::FTD_CURSOR_N_RESIZE => Ok(Cursor::NResize),
-            ftd::interpreter::FTD_CURSOR_NE_RESIZE => Ok(Cursor::NeResize),
-            ftd::interpreter::FTD_CURSOR_NW_RESIZE => Ok(Cursor::NwResize),
-            ftd::interpreter::FTD_CURSOR_S_RESIZE => Ok(Cursor::SResize),
-            ftd::interpreter::FTD_CURSOR_SE_RESIZE => Ok(Cursor::SeResize),
-            ftd::interpreter::FTD_CURSOR_SW_RESIZE => Ok(Cursor::SwResize),
-            ftd::interpreter::FTD_CURSOR_W_RESIZE => Ok(Cursor::WResize),
-            ftd::interpreter::FTD_CURSOR_EW_RESIZE => Ok(Cursor::EwResize),
-            ftd::interpreter::FTD_CURSOR_NS_RESIZE => Ok(Cursor::NsResize),
-            ftd::interpreter::FTD_CURSOR_NESW_RESIZE => Ok(Cursor::NeswResize),
-            ftd::interpreter::FTD_CURSOR_NWSE_RESIZE => Ok(Cursor::NwseResize),
-            ftd::interpreter::FTD_CURSOR_COL_RESIZE => Ok(Cursor::ColResize),
-            ftd::interpreter::FTD_CURSOR_ROW_RESIZE => Ok(Cursor::RowResize),
-            ftd::interpreter::FTD_CURSOR_ALL_SCROLL => Ok(Cursor::AllScroll),
-            ftd::interpreter::FTD_CURSOR_ZOOM_IN => Ok(Cursor::ZoomIn),
-            ftd::interpreter::FTD_CURSOR_ZOOM_OUT => Ok(Cursor::ZoomOut),
+            ftd::interpreter::FTD_ALIGN_SELF_START => Ok(AlignSelf::Start),
+            ftd::interpreter::FTD_ALIGN_SELF_CENTER => Ok(AlignSelf::Center),
+            ftd::interpreter::FTD_ALIGN_SELF_END => Ok(AlignSelf::End),
             t => ftd::executor::utils::parse_error(
-                format!("Unknown variant `{}` for or-type `ftd.cursor`", t),
+                format!("Unknown variant `{}` for or-type `ftd.align-self`", t),
                 doc.name,
                 line_number,
             ),
         }
     }
 
-    pub(crate) fn optional_cursor(
+    pub(crate) fn optional_align_self(
         properties: &[ftd::interpreter::Property],
         arguments: &[ftd::interpreter::Argument],
         doc: &ftd::executor::TDoc,
@@ -2636,7 +3193,7 @@ impl Cursor {
         key: &str,
         inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
         component_name: &str,
-    ) -> ftd::executor::Result<ftd::executor::Value<Option<Cursor>>> {
+    ) -> ftd::executor::Result<ftd::executor::Value<Option<AlignSelf>>> {
         let or_type_value = ftd::executor::value::optional_or_type(
             key,
             component_name,
@@ -2644,12 +3201,12 @@ impl Cursor {
             arguments,
             doc,
             line_number,
-            ftd::interpreter::FTD_CURSOR,
+            ftd::interpreter::FTD_ALIGN_SELF,
             inherited_variables,
         )?;
 
         Ok(ftd::executor::Value::new(
-            Cursor::from_optional_values(or_type_value.value, doc, line_number)?,
+            AlignSelf::from_optional_values(or_type_value.value, doc, line_number)?,
             or_type_value.line_number,
             or_type_value.properties,
         ))
@@ -2657,118 +3214,1372 @@ impl Cursor {
 
     pub fn to_css_string(&self) -> String {
         match self {
-            Cursor::Default => "default".to_string(),
-            Cursor::None => "none".to_string(),
-            Cursor::ContextMenu => "context-menu".to_string(),
-            Cursor::Help => "help".to_string(),
-            Cursor::Pointer => "pointer".to_string(),
-            Cursor::Progress => "progress".to_string(),
-            Cursor::Wait => "wait".to_string(),
-            Cursor::Cell => "cell".to_string(),
-            Cursor::CrossHair => "crosshair".to_string(),
-            Cursor::Text => "text".to_string(),
-            Cursor::VerticalText => "vertical-text".to_string(),
-            Cursor::Alias => "alias".to_string(),
-            Cursor::Copy => "copy".to_string(),
-            Cursor::Move => "move".to_string(),
-            Cursor::NoDrop => "no-drop".to_string(),
-            Cursor::NotAllowed => "not-allowed".to_string(),
-            Cursor::Grab => "grab".to_string(),
-            Cursor::Grabbing => "grabbing".to_string(),
-            Cursor::EResize => "e-resize".to_string(),
-            Cursor::NResize => "n-resize".to_string(),
-            Cursor::NeResize => "ne-resize".to_string(),
-            Cursor::NwResize => "nw-resize".to_string(),
-            Cursor::SResize => "s-resize".to_string(),
-            Cursor::SeResize => "se-resize".to_string(),
-            Cursor::SwResize => "sw-resize".to_string(),
-            Cursor::WResize => "w-resize".to_string(),
-            Cursor::EwResize => "ew-resize".to_string(),
-            Cursor::NsResize => "ns-resize".to_string(),
-            Cursor::NeswResize => "nesw-resize".to_string(),
-            Cursor::NwseResize => "nwse-resize".to_string(),
-            Cursor::ColResize => "col-resize".to_string(),
-            Cursor::RowResize => "row-resize".to_string(),
-            Cursor::AllScroll => "all-scroll".to_string(),
-            Cursor::ZoomIn => "zoom-in".to_string(),
-            Cursor::ZoomOut => "zoom-out".to_string(),
+            AlignSelf::Start => "start".to_string(),
+            AlignSelf::Center => "center".to_string(),
+            AlignSelf::End => "end".to_string(),
         }
     }
 }
 
 #[derive(serde::Deserialize, Debug, PartialEq, Clone, serde::Serialize)]
-pub enum FontSize {
-    Px(i64),
-    Em(f64),
-    Rem(f64),
+pub enum Overflow {
+    Scroll,
+    Visible,
+    Hidden,
+    Auto,
 }
 
-impl Default for FontSize {
-    fn default() -> FontSize {
-        FontSize::Px(0)
+impl Overflow {
+    fn from_optional_values(
+        or_type_value: Option<(String, ftd::interpreter::PropertyValue)>,
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<Option<Self>> {
+        if let Some(value) = or_type_value {
+            Ok(Some(Overflow::from_values(value, doc, line_number)?))
+        } else {
+            Ok(None)
+        }
     }
-}
 
-impl FontSize {
-    fn from_optional_value(
-        value: ftd::interpreter::PropertyValue,
+    fn from_values(
+        or_type_value: (String, ftd::interpreter::PropertyValue),
         doc: &ftd::executor::TDoc,
         line_number: usize,
-    ) -> ftd::executor::Result<Option<FontSize>> {
-        let value = value.resolve(&doc.itdoc(), line_number)?;
-        match value.inner() {
-            Some(ftd::interpreter::Value::OrType {
-                name,
-                variant,
-                value,
-                ..
-            }) if name.eq(ftd::interpreter::FTD_FONT_SIZE) => Ok(Some(FontSize::from_values(
-                (variant, value.as_ref().to_owned()),
-                doc,
-                line_number,
-            )?)),
-            None => Ok(None),
+    ) -> ftd::executor::Result<Self> {
+        match or_type_value.0.as_str() {
+            ftd::interpreter::FTD_OVERFLOW_SCROLL => Ok(Overflow::Scroll),
+            ftd::interpreter::FTD_OVERFLOW_VISIBLE => Ok(Overflow::Visible),
+            ftd::interpreter::FTD_OVERFLOW_HIDDEN => Ok(Overflow::Hidden),
+            ftd::interpreter::FTD_OVERFLOW_AUTO => Ok(Overflow::Auto),
             t => ftd::executor::utils::parse_error(
-                format!(
-                    "Expected value of font-size or-type `{}`, found: {:?}",
-                    ftd::interpreter::FTD_FONT_SIZE,
-                    t
-                ),
+                format!("Unknown variant `{}` for or-type `ftd.overflow`", t),
                 doc.name,
                 line_number,
             ),
         }
     }
 
+    pub(crate) fn optional_overflow(
+        properties: &[ftd::interpreter::Property],
+        arguments: &[ftd::interpreter::Argument],
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+        key: &str,
+        inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
+        component_name: &str,
+    ) -> ftd::executor::Result<ftd::executor::Value<Option<Overflow>>> {
+        let or_type_value = ftd::executor::value::optional_or_type(
+            key,
+            component_name,
+            properties,
+            arguments,
+            doc,
+            line_number,
+            ftd::interpreter::FTD_OVERFLOW,
+            inherited_variables,
+        )?;
+
+        Ok(ftd::executor::Value::new(
+            Overflow::from_optional_values(or_type_value.value, doc, line_number)?,
+            or_type_value.line_number,
+            or_type_value.properties,
+        ))
+    }
+
+    pub fn to_css_string(&self) -> String {
+        match self {
+            Overflow::Scroll => "scroll".to_string(),
+            Overflow::Visible => "visible".to_string(),
+            Overflow::Hidden => "hidden".to_string(),
+            Overflow::Auto => "auto".to_string(),
+        }
+    }
+}
+
+#[derive(serde::Deserialize, Debug, PartialEq, Clone, serde::Serialize)]
+pub enum Resize {
+    Both,
+    Horizontal,
+    Vertical,
+}
+
+impl Resize {
+    fn from_optional_values(
+        or_type_value: Option<(String, ftd::interpreter::PropertyValue)>,
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<Option<Self>> {
+        if let Some(value) = or_type_value {
+            Ok(Some(Resize::from_values(value, doc, line_number)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn from_values(
+        or_type_value: (String, ftd::interpreter::PropertyValue),
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<Self> {
+        match or_type_value.0.as_str() {
+            ftd::interpreter::FTD_RESIZE_HORIZONTAL => Ok(Resize::Horizontal),
+            ftd::interpreter::FTD_RESIZE_VERTICAL => Ok(Resize::Vertical),
+            ftd::interpreter::FTD_RESIZE_BOTH => Ok(Resize::Both),
+            t => ftd::executor::utils::parse_error(
+                format!("Unknown variant `{}` for or-type `ftd.resize`", t),
+                doc.name,
+                line_number,
+            ),
+        }
+    }
+
+    pub(crate) fn optional_resize(
+        properties: &[ftd::interpreter::Property],
+        arguments: &[ftd::interpreter::Argument],
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+        key: &str,
+        inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
+        component_name: &str,
+    ) -> ftd::executor::Result<ftd::executor::Value<Option<Resize>>> {
+        let or_type_value = ftd::executor::value::optional_or_type(
+            key,
+            component_name,
+            properties,
+            arguments,
+            doc,
+            line_number,
+            ftd::interpreter::FTD_RESIZE,
+            inherited_variables,
+        )?;
+
+        Ok(ftd::executor::Value::new(
+            Resize::from_optional_values(or_type_value.value, doc, line_number)?,
+            or_type_value.line_number,
+            or_type_value.properties,
+        ))
+    }
+
+    pub fn to_css_string(&self) -> String {
+        match self {
+            Resize::Horizontal => "horizontal".to_string(),
+            Resize::Vertical => "vertical".to_string(),
+            Resize::Both => "both".to_string(),
+        }
+    }
+}
+
+#[derive(serde::Deserialize, Debug, PartialEq, Clone, serde::Serialize)]
+pub enum TextAlign {
+    Start,
+    Center,
+    End,
+    Justify,
+}
+
+impl TextAlign {
+    fn from_optional_values(
+        or_type_value: Option<(String, ftd::interpreter::PropertyValue)>,
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<Option<Self>> {
+        if let Some(value) = or_type_value {
+            Ok(Some(TextAlign::from_values(value, doc, line_number)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn from_values(
+        or_type_value: (String, ftd::interpreter::PropertyValue),
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<Self> {
+        match or_type_value.0.as_str() {
+            ftd::interpreter::FTD_TEXT_ALIGN_START => Ok(TextAlign::Start),
+            ftd::interpreter::FTD_TEXT_ALIGN_CENTER => Ok(TextAlign::Center),
+            ftd::interpreter::FTD_TEXT_ALIGN_END => Ok(TextAlign::End),
+            ftd::interpreter::FTD_TEXT_ALIGN_JUSTIFY => Ok(TextAlign::Justify),
+            t => ftd::executor::utils::parse_error(
+                format!("Unknown variant `{}` for or-type `ftd.text-align`", t),
+                doc.name,
+                line_number,
+            ),
+        }
+    }
+
+    pub(crate) fn optional_text_align(
+        properties: &[ftd::interpreter::Property],
+        arguments: &[ftd::interpreter::Argument],
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+        key: &str,
+        inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
+        component_name: &str,
+    ) -> ftd::executor::Result<ftd::executor::Value<Option<TextAlign>>> {
+        let or_type_value = ftd::executor::value::optional_or_type(
+            key,
+            component_name,
+            properties,
+            arguments,
+            doc,
+            line_number,
+            ftd::interpreter::FTD_TEXT_ALIGN,
+            inherited_variables,
+        )?;
+
+        Ok(ftd::executor::Value::new(
+            TextAlign::from_optional_values(or_type_value.value, doc, line_number)?,
+            or_type_value.line_number,
+            or_type_value.properties,
+        ))
+    }
+
+    pub fn to_css_string(&self) -> String {
+        match self {
+            TextAlign::Start => "start".to_string(),
+            TextAlign::Center => "center".to_string(),
+            TextAlign::End => "end".to_string(),
+            TextAlign::Justify => "justify".to_string(),
+        }
+    }
+}
+
+#[derive(serde::Deserialize, Debug, PartialEq, Clone, serde::Serialize)]
+pub enum Cursor {
+    Default,
+    None,
+    ContextMenu,
+    Help,
+    Pointer,
+    Progress,
+    Wait,
+    Cell,
+    CrossHair,
+    Text,
+    VerticalText,
+    Alias,
+    Copy,
+    Move,
+    NoDrop,
+    NotAllowed,
+    Grab,
+    Grabbing,
+    EResize,
+    NResize,
+    NeResize,
+    NwResize,
+    SResize,
+    SeResize,
+    SwResize,
+    WResize,
+    EwResize,
+    NsResize,
+    NeswResize,
+    NwseResize,
+    ColResize,
+    RowResize,
+    AllScroll,
+    ZoomIn,
+    ZoomOut,
+}
+
+impl Cursor {
+    fn from_optional_values(
+        or_type_value: Option<(String, ftd::interpreter::PropertyValue)>,
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<Option<Self>> {
+        if let Some(value) = or_type_value {
+            Ok(Some(Cursor::from_values(value, doc, line_number)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn from_values(
+        or_type_value: (String, ftd::interpreter::PropertyValue),
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<Self> {
+        match or_type_value.0.as_str() {
+            ftd::interpreter::FTD_CURSOR_DEFAULT => Ok(Cursor::Default),
+            ftd::interpreter::FTD_CURSOR_NONE => Ok(Cursor::None),
+            ftd::interpreter::FTD_CURSOR_CONTEXT_MENU => Ok(Cursor::ContextMenu),
+            ftd::interpreter::FTD_CURSOR_HELP => Ok(Cursor::Help),
+            ftd::interpreter::FTD_CURSOR_POINTER => Ok(Cursor::Pointer),
+            ftd::interpreter::FTD_CURSOR_PROGRESS => Ok(Cursor::Progress),
+            ftd::interpreter::FTD_CURSOR_WAIT => Ok(Cursor::Wait),
+            ftd::interpreter::FTD_CURSOR_CELL => Ok(Cursor::Cell),
+            ftd::interpreter::FTD_CURSOR_CROSSHAIR => Ok(Cursor::CrossHair),
+            ftd::interpreter::FTD_CURSOR_TEXT => Ok(Cursor::Text),
+            ftd::interpreter::FTD_CURSOR_VERTICAL_TEXT => Ok(Cursor::VerticalText),
+            ftd::interpreter::FTD_CURSOR_ALIAS => Ok(Cursor::Alias),
+            ftd::interpreter::FTD_CURSOR_COPY => Ok(Cursor::Copy),
+            ftd::interpreter::FTD_CURSOR_MOVE => Ok(Cursor::Move),
+            ftd::interpreter::FTD_CURSOR_NO_DROP => Ok(Cursor::NoDrop),
+            ftd::interpreter::FTD_CURSOR_NOT_ALLOWED => Ok(Cursor::NotAllowed),
+            ftd::interpreter::FTD_CURSOR_GRAB => Ok(Cursor::Grab),
+            ftd::interpreter::FTD_CURSOR_GRABBING => Ok(Cursor::Grabbing),
+            ftd::interpreter::FTD_CURSOR_E_RESIZE => Ok(Cursor::EResize),
+            ftd::interpreter::FTD_CURSOR_N_RESIZE => Ok(Cursor::NResize),
+            ftd::interpreter::FTD_CURSOR_NE_RESIZE => Ok(Cursor::NeResize),
+            ftd::interpreter::FTD_CURSOR_NW_RESIZE => Ok(Cursor::NwResize),
+            ftd::interpreter::FTD_CURSOR_S_RESIZE => Ok(Cursor::SResize),
+            ftd::interpreter::FTD_CURSOR_SE_RESIZE => Ok(Cursor::SeResize),
+            ftd::interpreter::FTD_CURSOR_SW_RESIZE => Ok(Cursor::SwResize),
+            ftd::interpreter::FTD_CURSOR_W_RESIZE => Ok(Cursor::WResize),
+            ftd::interpreter::FTD_CURSOR_EW_RESIZE => Ok(Cursor::EwResize),
+            ftd::interpreter::FTD_CURSOR_NS_RESIZE => Ok(Cursor::NsResize),
+            ftd::interpreter::FTD_CURSOR_NESW_RESIZE => Ok(Cursor::NeswResize),
+            ftd::interpreter::FTD_CURSOR_NWSE_RESIZE => Ok(Cursor::NwseResize),
+            ftd::interpreter::FTD_CURSOR_COL_RESIZE => Ok(Cursor::ColResize),
+            ftd::interpreter::FTD_CURSOR_ROW_RESIZE => Ok(Cursor::RowResize),
+            ftd::interpreter::FTD_CURSOR_ALL_SCROLL => Ok(Cursor::AllScroll),
+            ftd::interpreter::FTD_CURSOR_ZOOM_IN => Ok(Cursor::ZoomIn),
+            ftd::interpreter::FTD_CURSOR_ZOOM_OUT => Ok(Cursor::ZoomOut),
+            t => ftd::executor::utils::parse_error(
+                format!("Unknown variant `{}` for or-type `ftd.cursor`", t),
+                doc.name,
+                line_number,
+            ),
+        }
+    }
+
+    pub(crate) fn optional_cursor(
+        properties: &[ftd::interpreter::Property],
+        arguments: &[ftd::interpreter::Argument],
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+        key: &str,
+        inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
+        component_name: &str,
+    ) -> ftd::executor::Result<ftd::executor::Value<Option<Cursor>>> {
+        let or_type_value = ftd::executor::value::optional_or_type(
+            key,
+            component_name,
+            properties,
+            arguments,
+            doc,
+            line_number,
+            ftd::interpreter::FTD_CURSOR,
+            inherited_variables,
+        )?;
+
+        Ok(ftd::executor::Value::new(
+            Cursor::from_optional_values(or_type_value.value, doc, line_number)?,
+            or_type_value.line_number,
+            or_type_value.properties,
+        ))
+    }
+
+    pub fn to_css_string(&self) -> String {
+        match self {
+            Cursor::Default => "default".to_string(),
+            Cursor::None => "none".to_string(),
+            Cursor::ContextMenu => "context-menu".to_string(),
+            Cursor::Help => "help".to_string(),
+            Cursor::Pointer => "pointer".to_string(),
+            Cursor::Progress => "progress".to_string(),
+            Cursor::Wait => "wait".to_string(),
+            Cursor::Cell => "cell".to_string(),
+            Cursor::CrossHair => "crosshair".to_string(),
+            Cursor::Text => "text".to_string(),
+            Cursor::VerticalText => "vertical-text".to_string(),
+            Cursor::Alias => "alias".to_string(),
+            Cursor::Copy => "copy".to_string(),
+            Cursor::Move => "move".to_string(),
+            Cursor::NoDrop => "no-drop".to_string(),
+            Cursor::NotAllowed => "not-allowed".to_string(),
+            Cursor::Grab => "grab".to_string(),
+            Cursor::Grabbing => "grabbing".to_string(),
+            Cursor::EResize => "e-resize".to_string(),
+            Cursor::NResize => "n-resize".to_string(),
+            Cursor::NeResize => "ne-resize".to_string(),
+            Cursor::NwResize => "nw-resize".to_string(),
+            Cursor::SResize => "s-resize".to_string(),
+            Cursor::SeResize => "se-resize".to_string(),
+            Cursor::SwResize => "sw-resize".to_string(),
+            Cursor::WResize => "w-resize".to_string(),
+            Cursor::EwResize => "ew-resize".to_string(),
+            Cursor::NsResize => "ns-resize".to_string(),
+            Cursor::NeswResize => "nesw-resize".to_string(),
+            Cursor::NwseResize => "nwse-resize".to_string(),
+            Cursor::ColResize => "col-resize".to_string(),
+            Cursor::RowResize => "row-resize".to_string(),
+            Cursor::AllScroll => "all-scroll".to_string(),
+            Cursor::ZoomIn => "zoom-in".to_string(),
+            Cursor::ZoomOut => "zoom-out".to_string(),
+        }
+    }
+}
+
+#[derive(serde::Deserialize, Debug, PartialEq, Clone, serde::Serialize)]
+pub enum FontSize {
+    Px(i64),
+    Em(f64),
+    Rem(f64),
+}
+
+impl Default for FontSize {
+    fn default() -> FontSize {
+        FontSize::Px(0)
+    }
+}
+
+impl FontSize {
+    fn from_optional_value(
+        value: ftd::interpreter::PropertyValue,
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<Option<FontSize>> {
+        let value = value.resolve(&doc.itdoc(), line_number)?;
+        match value.inner() {
+            Some(ftd::interpreter::Value::OrType {
+                name,
+                variant,
+                value,
+                ..
+            }) if name.eq(ftd::interpreter::FTD_FONT_SIZE) => Ok(Some(FontSize::from_values(
+                (variant, value.as_ref().to_owned()),
+                doc,
+                line_number,
+            )?)),
+            None => Ok(None),
+            t => ftd::executor::utils::parse_error(
+                format!(
+                    "Expected value of font-size or-type `{}`, found: {:?}",
+                    ftd::interpreter::FTD_FONT_SIZE,
+                    t
+                ),
+                doc.name,
+                line_number,
+            ),
+        }
+    }
+
+    fn from_values(
+        or_type_value: (String, ftd::interpreter::PropertyValue),
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<Self> {
+        match or_type_value.0.as_str() {
+            ftd::interpreter::FTD_FONT_SIZE_PX => Ok(FontSize::Px(
+                or_type_value
+                    .1
+                    .clone()
+                    .resolve(&doc.itdoc(), line_number)?
+                    .integer(doc.name, line_number)?,
+            )),
+            ftd::interpreter::FTD_FONT_SIZE_EM => Ok(FontSize::Em(
+                or_type_value
+                    .1
+                    .clone()
+                    .resolve(&doc.itdoc(), line_number)?
+                    .decimal(doc.name, line_number)?,
+            )),
+            ftd::interpreter::FTD_FONT_SIZE_REM => Ok(FontSize::Rem(
+                or_type_value
+                    .1
+                    .clone()
+                    .resolve(&doc.itdoc(), line_number)?
+                    .decimal(doc.name, line_number)?,
+            )),
+            t => ftd::executor::utils::parse_error(
+                format!("Unknown variant `{}` for or-type `ftd.font-size`", t),
+                doc.name,
+                line_number,
+            ),
+        }
+    }
+
+    pub fn to_css_string(&self) -> String {
+        match self {
+            FontSize::Px(px) => format!("{}px", px),
+            FontSize::Em(em) => format!("{}em", em),
+            FontSize::Rem(rem) => format!("{}rem", rem),
+        }
+    }
+
+    pub fn get_pattern_from_variant_str(
+        variant: &str,
+        doc_id: &str,
+        line_number: usize,
+    ) -> ftd::executor::Result<&'static str> {
+        match variant {
+            ftd::interpreter::FTD_FONT_SIZE_PX
+            | ftd::interpreter::FTD_FONT_SIZE_EM
+            | ftd::interpreter::FTD_FONT_SIZE_REM => Ok("{0}"),
+            t => ftd::executor::utils::parse_error(
+                format!("Unknown variant found for ftd.font-size: `{}`", t),
+                doc_id,
+                line_number,
+            ),
+        }
+    }
+
+    pub fn set_pattern_from_variant_str(
+        variant: &str,
+        doc_id: &str,
+        line_number: usize,
+    ) -> ftd::executor::Result<&'static str> {
+        match variant {
+            ftd::interpreter::FTD_FONT_SIZE_PX => Ok("{0}px"),
+            ftd::interpreter::FTD_FONT_SIZE_EM => Ok("{0}em"),
+            ftd::interpreter::FTD_FONT_SIZE_REM => Ok("{0}rem"),
+            t => ftd::executor::utils::parse_error(
+                format!("Unknown variant found for ftd.font-size: `{}`", t),
+                doc_id,
+                line_number,
+            ),
+        }
+    }
+
+    pub fn set_value_from_variant(
+        variant: &str,
+        value: &str,
+        doc_id: &str,
+        line_number: usize,
+    ) -> ftd::executor::Result<String> {
+        match variant {
+            ftd::interpreter::FTD_FONT_SIZE_PX => Ok(format!("{}px", value)),
+            ftd::interpreter::FTD_FONT_SIZE_EM => Ok(format!("{}em", value)),
+            ftd::interpreter::FTD_FONT_SIZE_REM => Ok(format!("{}rem", value)),
+            t => ftd::executor::utils::parse_error(
+                format!("Unknown variant found for ftd.font-size: `{}`", t),
+                doc_id,
+                line_number,
+            ),
+        }
+    }
+}
+
+#[derive(serde::Deserialize, Debug, Default, PartialEq, Clone, serde::Serialize)]
+pub struct Type {
+    pub size: Option<FontSize>,
+    pub line_height: Option<FontSize>,
+    pub letter_spacing: Option<FontSize>,
+    pub weight: Option<i64>,
+    pub font_family: Option<String>,
+}
+
+impl Type {
+    fn new(
+        size: Option<FontSize>,
+        line_height: Option<FontSize>,
+        letter_spacing: Option<FontSize>,
+        weight: Option<i64>,
+        font_family: Option<String>,
+    ) -> Type {
+        Type {
+            size,
+            line_height,
+            letter_spacing,
+            weight,
+            font_family,
+        }
+    }
+
+    fn from_value(
+        value: ftd::interpreter::PropertyValue,
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<Type> {
+        let value = value.resolve(&doc.itdoc(), line_number)?;
+        let fields = match value.inner() {
+            Some(ftd::interpreter::Value::Record { name, fields })
+                if name.eq(ftd::interpreter::FTD_TYPE) =>
+            {
+                fields
+            }
+            t => {
+                return ftd::executor::utils::parse_error(
+                    format!(
+                        "Expected value of type record `{}`, found: {:?}",
+                        ftd::interpreter::FTD_COLOR,
+                        t
+                    ),
+                    doc.name,
+                    line_number,
+                )
+            }
+        };
+        Type::from_values(fields, doc, line_number)
+    }
+
+    fn from_values(
+        values: ftd::Map<ftd::interpreter::PropertyValue>,
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<Type> {
+        let size = {
+            if let Some(value) = values.get("size") {
+                FontSize::from_optional_value(value.to_owned(), doc, line_number)?
+            } else {
+                None
+            }
+        };
+
+        let line_height = {
+            if let Some(value) = values.get("line-height") {
+                FontSize::from_optional_value(value.to_owned(), doc, line_number)?
+            } else {
+                None
+            }
+        };
+
+        let letter_spacing = {
+            if let Some(value) = values.get("letter-spacing") {
+                FontSize::from_optional_value(value.to_owned(), doc, line_number)?
+            } else {
+                None
+            }
+        };
+
+        let weight = {
+            if let Some(value) = values.get("weight") {
+                value
+                    .clone()
+                    .resolve(&doc.itdoc(), line_number)?
+                    .optional_integer(doc.name, line_number)?
+            } else {
+                None
+            }
+        };
+
+        let font_family = {
+            if let Some(value) = values.get("font-family") {
+                value
+                    .clone()
+                    .resolve(&doc.itdoc(), line_number)?
+                    .optional_string(doc.name, line_number)?
+            } else {
+                None
+            }
+        };
+
+        Ok(Type::new(
+            size,
+            line_height,
+            letter_spacing,
+            weight,
+            font_family,
+        ))
+    }
+}
+
+#[derive(serde::Deserialize, Debug, Default, PartialEq, Clone, serde::Serialize)]
+pub struct ResponsiveType {
+    pub desktop: Type,
+    pub mobile: Type,
+}
+
+impl ResponsiveType {
+    fn from_values(
+        values: ftd::Map<ftd::interpreter::PropertyValue>,
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<ResponsiveType> {
+        let desktop = {
+            let value = values
+                .get("desktop")
+                .ok_or(ftd::executor::Error::ParseError {
+                    message: "`desktop` field in ftd.responsive-type not found".to_string(),
+                    doc_id: doc.name.to_string(),
+                    line_number,
+                })?;
+            Type::from_value(value.to_owned(), doc, line_number)?
+        };
+
+        let mobile = {
+            if let Some(value) = values.get("mobile") {
+                Type::from_value(value.to_owned(), doc, line_number)?
+            } else {
+                desktop.clone()
+            }
+        };
+
+        Ok(ResponsiveType { desktop, mobile })
+    }
+
+    fn from_optional_values(
+        or_type_value: Option<ftd::Map<ftd::interpreter::PropertyValue>>,
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<Option<Self>> {
+        if let Some(value) = or_type_value {
+            Ok(Some(ResponsiveType::from_values(value, doc, line_number)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub(crate) fn optional_responsive_type(
+        properties: &[ftd::interpreter::Property],
+        arguments: &[ftd::interpreter::Argument],
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+        key: &str,
+        inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
+        component_name: &str,
+    ) -> ftd::executor::Result<ftd::executor::Value<Option<ResponsiveType>>> {
+        let record_values = ftd::executor::value::optional_record_inherited(
+            key,
+            component_name,
+            properties,
+            arguments,
+            doc,
+            line_number,
+            ftd::interpreter::FTD_RESPONSIVE_TYPE,
+            inherited_variables,
+        )?;
+
+        Ok(ftd::executor::Value::new(
+            ResponsiveType::from_optional_values(record_values.value, doc, line_number)?,
+            record_values.line_number,
+            record_values.properties,
+        ))
+    }
+
+    pub fn to_css_font_size(&self, device: &Option<ftd::executor::Device>) -> Option<String> {
+        match device {
+            Some(ftd::executor::Device::Mobile) => {
+                self.mobile.size.as_ref().map(|v| v.to_css_string())
+            }
+            _ => self.desktop.size.as_ref().map(|v| v.to_css_string()),
+        }
+    }
+
+    pub fn font_size_pattern() -> (String, bool) {
+        ("({0})[\"size\"]".to_string(), true)
+    }
+
+    pub fn to_css_line_height(&self, device: &Option<ftd::executor::Device>) -> Option<String> {
+        match device {
+            Some(ftd::executor::Device::Mobile) => {
+                self.mobile.line_height.as_ref().map(|v| v.to_css_string())
+            }
+            _ => self.desktop.line_height.as_ref().map(|v| v.to_css_string()),
+        }
+    }
+
+    pub fn line_height_pattern() -> (String, bool) {
+        ("({0})[\"line-height\"]".to_string(), true)
+    }
+
+    pub fn to_css_letter_spacing(&self, device: &Option<ftd::executor::Device>) -> Option<String> {
+        match device {
+            Some(ftd::executor::Device::Mobile) => self
+                .mobile
+                .letter_spacing
+                .as_ref()
+                .map(|v| v.to_css_string()),
+            _ => self
+                .desktop
+                .letter_spacing
+                .as_ref()
+                .map(|v| v.to_css_string()),
+        }
+    }
+
+    pub fn letter_spacing_pattern() -> (String, bool) {
+        ("({0})[\"letter-spacing\"]".to_string(), true)
+    }
+
+    pub fn to_css_weight(&self, device: &Option<ftd::executor::Device>) -> Option<String> {
+        match device {
+            Some(ftd::executor::Device::Mobile) => {
+                self.mobile.weight.as_ref().map(|v| v.to_string())
+            }
+            _ => self.desktop.weight.as_ref().map(|v| v.to_string()),
+        }
+    }
+
+    pub fn weight_pattern() -> (String, bool) {
+        ("({0}).weight".to_string(), true)
+    }
+
+    pub fn to_css_font_family(&self) -> Option<String> {
+        self.desktop.font_family.to_owned()
+    }
+
+    pub fn font_family_pattern() -> (String, bool) {
+        ("({0})[\"font-family\"]".to_string(), true)
+    }
+}
+
+#[derive(serde::Deserialize, Debug, PartialEq, Clone, serde::Serialize)]
+pub enum Anchor {
+    Window,
+    Parent,
+    Id(String),
+}
+
+impl Anchor {
+    fn from_optional_values(
+        or_type_value: Option<(String, ftd::interpreter::PropertyValue)>,
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<Option<Self>> {
+        if let Some(value) = or_type_value {
+            Ok(Some(Anchor::from_values(value, doc, line_number)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn from_values(
+        or_type_value: (String, ftd::interpreter::PropertyValue),
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<Self> {
+        match or_type_value.0.as_str() {
+            ftd::interpreter::FTD_ANCHOR_WINDOW => Ok(Anchor::Window),
+            ftd::interpreter::FTD_ANCHOR_PARENT => Ok(Anchor::Parent),
+            ftd::interpreter::FTD_ANCHOR_ID => Ok(Anchor::Id(
+                or_type_value
+                    .1
+                    .clone()
+                    .resolve(&doc.itdoc(), line_number)?
+                    .string(doc.name, line_number)?,
+            )),
+            t => ftd::executor::utils::parse_error(
+                format!("Unknown variant `{}` for or-type `ftd.anchor`", t),
+                doc.name,
+                line_number,
+            ),
+        }
+    }
+
+    pub(crate) fn optional_anchor(
+        properties: &[ftd::interpreter::Property],
+        arguments: &[ftd::interpreter::Argument],
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+        key: &str,
+        inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
+        component_name: &str,
+    ) -> ftd::executor::Result<ftd::executor::Value<Option<Anchor>>> {
+        let or_type_value = ftd::executor::value::optional_or_type(
+            key,
+            component_name,
+            properties,
+            arguments,
+            doc,
+            line_number,
+            ftd::interpreter::FTD_ANCHOR,
+            inherited_variables,
+        )?;
+
+        Ok(ftd::executor::Value::new(
+            Anchor::from_optional_values(or_type_value.value, doc, line_number)?,
+            or_type_value.line_number,
+            or_type_value.properties,
+        ))
+    }
+
+    pub fn to_css_string(&self) -> String {
+        match self {
+            Anchor::Window => "fixed".to_string(),
+            Anchor::Parent => "absolute".to_string(),
+            Anchor::Id(_) => "absolute".to_string(),
+        }
+    }
+}
+
+#[derive(serde::Deserialize, Debug, PartialEq, Clone, serde::Serialize)]
+pub enum TextInputType {
+    TEXT,
+    EMAIL,
+    PASSWORD,
+    URL,
+    DATETIME,
+    DATE,
+    TIME,
+    MONTH,
+    WEEK,
+    COLOR,
+    FILE,
+    NUMBER,
+    RANGE,
+    SUBMIT,
+}
+
+impl TextInputType {
+    fn from_optional_values(
+        or_type_value: Option<(String, ftd::interpreter::PropertyValue)>,
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<Option<Self>> {
+        if let Some(value) = or_type_value {
+            Ok(Some(TextInputType::from_values(value, doc, line_number)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn from_values(
+        or_type_value: (String, ftd::interpreter::PropertyValue),
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<Self> {
+        match or_type_value.0.as_str() {
+            ftd::interpreter::FTD_TEXT_INPUT_TYPE_TEXT => Ok(TextInputType::TEXT),
+            ftd::interpreter::FTD_TEXT_INPUT_TYPE_EMAIL => Ok(TextInputType::EMAIL),
+            ftd::interpreter::FTD_TEXT_INPUT_TYPE_PASSWORD => Ok(TextInputType::PASSWORD),
+            ftd::interpreter::FTD_TEXT_INPUT_TYPE_URL => Ok(TextInputType::URL),
+            ftd::interpreter::FTD_TEXT_INPUT_TYPE_DATETIME => Ok(TextInputType::DATETIME),
+            ftd::interpreter::FTD_TEXT_INPUT_TYPE_DATE => Ok(TextInputType::DATE),
+            ftd::interpreter::FTD_TEXT_INPUT_TYPE_TIME => Ok(TextInputType::TIME),
+            ftd::interpreter::FTD_TEXT_INPUT_TYPE_MONTH => Ok(TextInputType::MONTH),
+            ftd::interpreter::FTD_TEXT_INPUT_TYPE_WEEK => Ok(TextInputType::WEEK),
+            ftd::interpreter::FTD_TEXT_INPUT_TYPE_COLOR => Ok(TextInputType::COLOR),
+            ftd::interpreter::FTD_TEXT_INPUT_TYPE_FILE => Ok(TextInputType::FILE),
+            ftd::interpreter::FTD_TEXT_INPUT_TYPE_NUMBER => Ok(TextInputType::NUMBER),
+            ftd::interpreter::FTD_TEXT_INPUT_TYPE_RANGE => Ok(TextInputType::RANGE),
+            ftd::interpreter::FTD_TEXT_INPUT_TYPE_SUBMIT => Ok(TextInputType::SUBMIT),
+            t => ftd::executor::utils::parse_error(
+                format!("Unknown variant `{}` for or-type `ftd.text-input-type`", t),
+                doc.name,
+                line_number,
+            ),
+        }
+    }
+
+    pub(crate) fn optional_text_input_type(
+        properties: &[ftd::interpreter::Property],
+        arguments: &[ftd::interpreter::Argument],
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+        key: &str,
+        inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
+        component_name: &str,
+    ) -> ftd::executor::Result<ftd::executor::Value<Option<TextInputType>>> {
+        let or_type_value = ftd::executor::value::optional_or_type(
+            key,
+            component_name,
+            properties,
+            arguments,
+            doc,
+            line_number,
+            ftd::interpreter::FTD_TEXT_INPUT_TYPE,
+            inherited_variables,
+        )?;
+
+        Ok(ftd::executor::Value::new(
+            TextInputType::from_optional_values(or_type_value.value, doc, line_number)?,
+            or_type_value.line_number,
+            or_type_value.properties,
+        ))
+    }
+
+    pub fn to_css_string(&self) -> String {
+        match self {
+            TextInputType::TEXT => "text".to_string(),
+            TextInputType::EMAIL => "email".to_string(),
+            TextInputType::PASSWORD => "password".to_string(),
+            TextInputType::URL => "url".to_string(),
+            TextInputType::DATETIME => "datetime-local".to_string(),
+            TextInputType::DATE => "date".to_string(),
+            TextInputType::TIME => "time".to_string(),
+            TextInputType::MONTH => "month".to_string(),
+            TextInputType::WEEK => "week".to_string(),
+            TextInputType::COLOR => "color".to_string(),
+            TextInputType::FILE => "file".to_string(),
+            TextInputType::NUMBER => "number".to_string(),
+            TextInputType::RANGE => "range".to_string(),
+            TextInputType::SUBMIT => "submit".to_string(),
+        }
+    }
+}
+
+#[derive(serde::Deserialize, Debug, Default, PartialEq, Clone, serde::Serialize)]
+pub struct SelectOption {
+    pub label: String,
+    pub value: Option<String>,
+}
+
+impl SelectOption {
+    fn from_vec_values(
+        value: ftd::interpreter::Value,
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<Vec<SelectOption>> {
+        let mut result = vec![];
+        match value.inner() {
+            Some(ftd::interpreter::Value::List { data, kind })
+                if kind.kind.get_name().eq(ftd::interpreter::FTD_OPTION) =>
+            {
+                for element in data.iter() {
+                    let ln = element.line_number();
+                    result.push(SelectOption::from_value(element.to_owned(), doc, ln)?)
+                }
+            }
+            None => {}
+            t => {
+                return ftd::executor::utils::parse_error(
+                    format!(
+                        "Expected list value of type `{}`, found: {:?}",
+                        ftd::interpreter::FTD_OPTION,
+                        t
+                    ),
+                    doc.name,
+                    line_number,
+                )
+            }
+        };
+        Ok(result)
+    }
+
+    fn from_value(
+        value: ftd::interpreter::PropertyValue,
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<SelectOption> {
+        let value = value.resolve(&doc.itdoc(), line_number)?;
+        let fields = match value.inner() {
+            Some(ftd::interpreter::Value::Record { name, fields })
+                if name.eq(ftd::interpreter::FTD_OPTION) =>
+            {
+                fields
+            }
+            t => {
+                return ftd::executor::utils::parse_error(
+                    format!(
+                        "Expected value of type record `{}`, found: {:?}",
+                        ftd::interpreter::FTD_OPTION,
+                        t
+                    ),
+                    doc.name,
+                    line_number,
+                )
+            }
+        };
+
+        let get_property_value = |field_name: &str| {
+            fields
+                .get(field_name)
+                .ok_or_else(|| ftd::executor::Error::ParseError {
+                    message: format!("`{}` field in ftd.option not found", field_name),
+                    doc_id: doc.name.to_string(),
+                    line_number,
+                })
+        };
+
+        Ok(SelectOption {
+            label: get_property_value("label")?
+                .clone()
+                .resolve(&doc.itdoc(), line_number)?
+                .string(doc.name, line_number)?,
+            value: get_property_value("value")?
+                .clone()
+                .resolve(&doc.itdoc(), line_number)?
+                .optional_string(doc.name, line_number)?,
+        })
+    }
+
+    pub(crate) fn option_list(
+        properties: &[ftd::interpreter::Property],
+        arguments: &[ftd::interpreter::Argument],
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+        key: &str,
+        inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
+        component_name: &str,
+    ) -> ftd::executor::Result<ftd::executor::Value<Vec<SelectOption>>> {
+        let value = ftd::executor::value::get_value_from_properties_using_key_and_arguments_dummy(
+            key,
+            component_name,
+            properties,
+            arguments,
+            doc,
+            line_number,
+            false,
+            inherited_variables,
+        )?;
+
+        Ok(ftd::executor::Value::new(
+            match value.value {
+                Some(value) => SelectOption::from_vec_values(value, doc, line_number)?,
+                None => vec![],
+            },
+            value.line_number,
+            value.properties,
+        ))
+    }
+
+    /// The submitted value of the option, the label when it has none.
+    pub fn value(&self) -> &str {
+        self.value.as_deref().unwrap_or(self.label.as_str())
+    }
+}
+
+#[derive(serde::Deserialize, Debug, Default, PartialEq, Clone, serde::Serialize)]
+pub struct MediaSource {
+    pub src: String,
+    pub type_: Option<String>,
+}
+
+impl MediaSource {
+    fn from_vec_values(
+        value: ftd::interpreter::Value,
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<Vec<MediaSource>> {
+        let mut result = vec![];
+        match value.inner() {
+            Some(ftd::interpreter::Value::List { data, kind })
+                if kind.kind.get_name().eq(ftd::interpreter::FTD_MEDIA_SOURCE) =>
+            {
+                for element in data.iter() {
+                    let ln = element.line_number();
+                    result.push(MediaSource::from_value(element.to_owned(), doc, ln)?)
+                }
+            }
+            None => {}
+            t => {
+                return ftd::executor::utils::parse_error(
+                    format!(
+                        "Expected list value of type `{}`, found: {:?}",
+                        ftd::interpreter::FTD_MEDIA_SOURCE,
+                        t
+                    ),
+                    doc.name,
+                    line_number,
+                )
+            }
+        };
+        Ok(result)
+    }
+
+    fn from_value(
+        value: ftd::interpreter::PropertyValue,
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<MediaSource> {
+        let value = value.resolve(&doc.itdoc(), line_number)?;
+        let fields = match value.inner() {
+            Some(ftd::interpreter::Value::Record { name, fields })
+                if name.eq(ftd::interpreter::FTD_MEDIA_SOURCE) =>
+            {
+                fields
+            }
+            t => {
+                return ftd::executor::utils::parse_error(
+                    format!(
+                        "Expected value of type record `{}`, found: {:?}",
+                        ftd::interpreter::FTD_MEDIA_SOURCE,
+                        t
+                    ),
+                    doc.name,
+                    line_number,
+                )
+            }
+        };
+
+        let get_property_value = |field_name: &str| {
+            fields
+                .get(field_name)
+                .ok_or_else(|| ftd::executor::Error::ParseError {
+                    message: format!("`{}` field in ftd.media-source not found", field_name),
+                    doc_id: doc.name.to_string(),
+                    line_number,
+                })
+        };
+
+        Ok(MediaSource {
+            src: get_property_value("src")?
+                .clone()
+                .resolve(&doc.itdoc(), line_number)?
+                .string(doc.name, line_number)?,
+            type_: get_property_value("type")?
+                .clone()
+                .resolve(&doc.itdoc(), line_number)?
+                .optional_string(doc.name, line_number)?,
+        })
+    }
+
+    pub(crate) fn source_list(
+        properties: &[ftd::interpreter::Property],
+        arguments: &[ftd::interpreter::Argument],
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+        key: &str,
+        inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
+        component_name: &str,
+    ) -> ftd::executor::Result<ftd::executor::Value<Vec<MediaSource>>> {
+        let value = ftd::executor::value::get_value_from_properties_using_key_and_arguments_dummy(
+            key,
+            component_name,
+            properties,
+            arguments,
+            doc,
+            line_number,
+            false,
+            inherited_variables,
+        )?;
+
+        Ok(ftd::executor::Value::new(
+            match value.value {
+                Some(value) => MediaSource::from_vec_values(value, doc, line_number)?,
+                None => vec![],
+            },
+            value.line_number,
+            value.properties,
+        ))
+    }
+}
+
+#[derive(serde::Deserialize, Debug, Default, PartialEq, Clone, serde::Serialize)]
+pub struct Track {
+    pub src: String,
+    pub label: Option<String>,
+    pub lang: Option<String>,
+    pub default: bool,
+}
+
+impl Track {
+    fn from_vec_values(
+        value: ftd::interpreter::Value,
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<Vec<Track>> {
+        let mut result = vec![];
+        match value.inner() {
+            Some(ftd::interpreter::Value::List { data, kind })
+                if kind.kind.get_name().eq(ftd::interpreter::FTD_TRACK) =>
+            {
+                for element in data.iter() {
+                    let ln = element.line_number();
+                    result.push(Track::from_value(element.to_owned(), doc, ln)?)
+                }
+            }
+            None => {}
+            t => {
+                return ftd::executor::utils::parse_error(
+                    format!(
+                        "Expected list value of type `{}`, found: {:?}",
+                        ftd::interpreter::FTD_TRACK,
+                        t
+                    ),
+                    doc.name,
+                    line_number,
+                )
+            }
+        };
+        Ok(result)
+    }
+
+    fn from_value(
+        value: ftd::interpreter::PropertyValue,
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<Track> {
+        let value = value.resolve(&doc.itdoc(), line_number)?;
+        let fields = match value.inner() {
+            Some(ftd::interpreter::Value::Record { name, fields })
+                if name.eq(ftd::interpreter::FTD_TRACK) =>
+            {
+                fields
+            }
+            t => {
+                return ftd::executor::utils::parse_error(
+                    format!(
+                        "Expected value of type record `{}`, found: {:?}",
+                        ftd::interpreter::FTD_TRACK,
+                        t
+                    ),
+                    doc.name,
+                    line_number,
+                )
+            }
+        };
+
+        let get_property_value = |field_name: &str| {
+            fields
+                .get(field_name)
+                .ok_or_else(|| ftd::executor::Error::ParseError {
+                    message: format!("`{}` field in ftd.track not found", field_name),
+                    doc_id: doc.name.to_string(),
+                    line_number,
+                })
+        };
+
+        Ok(Track {
+            src: get_property_value("src")?
+                .clone()
+                .resolve(&doc.itdoc(), line_number)?
+                .string(doc.name, line_number)?,
+            label: get_property_value("label")?
+                .clone()
+                .resolve(&doc.itdoc(), line_number)?
+                .optional_string(doc.name, line_number)?,
+            lang: get_property_value("lang")?
+                .clone()
+                .resolve(&doc.itdoc(), line_number)?
+                .optional_string(doc.name, line_number)?,
+            default: get_property_value("default")?
+                .clone()
+                .resolve(&doc.itdoc(), line_number)?
+                .bool(doc.name, line_number)?,
+        })
+    }
+
+    pub(crate) fn track_list(
+        properties: &[ftd::interpreter::Property],
+        arguments: &[ftd::interpreter::Argument],
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+        key: &str,
+        inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
+        component_name: &str,
+    ) -> ftd::executor::Result<ftd::executor::Value<Vec<Track>>> {
+        let value = ftd::executor::value::get_value_from_properties_using_key_and_arguments_dummy(
+            key,
+            component_name,
+            properties,
+            arguments,
+            doc,
+            line_number,
+            false,
+            inherited_variables,
+        )?;
+
+        Ok(ftd::executor::Value::new(
+            match value.value {
+                Some(value) => Track::from_vec_values(value, doc, line_number)?,
+                None => vec![],
+            },
+            value.line_number,
+            value.properties,
+        ))
+    }
+}
+
+/// How the values of a column of an `ftd.table` are compared when the table is sorted.
+#[derive(serde::Deserialize, Debug, Default, PartialEq, Clone, serde::Serialize)]
+pub enum ColumnType {
+    #[default]
+    Text,
+    Number,
+    Date,
+    Boolean,
+}
+
+impl ColumnType {
     fn from_values(
         or_type_value: (String, ftd::interpreter::PropertyValue),
         doc: &ftd::executor::TDoc,
         line_number: usize,
     ) -> ftd::executor::Result<Self> {
         match or_type_value.0.as_str() {
-            ftd::interpreter::FTD_FONT_SIZE_PX => Ok(FontSize::Px(
-                or_type_value
-                    .1
-                    .clone()
-                    .resolve(&doc.itdoc(), line_number)?
-                    .integer(doc.name, line_number)?,
-            )),
-            ftd::interpreter::FTD_FONT_SIZE_EM => Ok(FontSize::Em(
-                or_type_value
-                    .1
-                    .clone()
-                    .resolve(&doc.itdoc(), line_number)?
-                    .decimal(doc.name, line_number)?,
-            )),
-            ftd::interpreter::FTD_FONT_SIZE_REM => Ok(FontSize::Rem(
-                or_type_value
-                    .1
-                    .clone()
-                    .resolve(&doc.itdoc(), line_number)?
-                    .decimal(doc.name, line_number)?,
-            )),
+            ftd::interpreter::FTD_COLUMN_TYPE_TEXT => Ok(ColumnType::Text),
+            ftd::interpreter::FTD_COLUMN_TYPE_NUMBER => Ok(ColumnType::Number),
+            ftd::interpreter::FTD_COLUMN_TYPE_DATE => Ok(ColumnType::Date),
+            ftd::interpreter::FTD_COLUMN_TYPE_BOOLEAN => Ok(ColumnType::Boolean),
             t => ftd::executor::utils::parse_error(
-                format!("Unknown variant `{}` for or-type `ftd.font-size`", t),
+                format!("Unknown variant `{}` for or-type `ftd.column-type`", t),
                 doc.name,
                 line_number,
             ),
@@ -2777,100 +4588,66 @@ impl FontSize {
 
     pub fn to_css_string(&self) -> String {
         match self {
-            FontSize::Px(px) => format!("{}px", px),
-            FontSize::Em(em) => format!("{}em", em),
-            FontSize::Rem(rem) => format!("{}rem", rem),
-        }
-    }
-
-    pub fn get_pattern_from_variant_str(
-        variant: &str,
-        doc_id: &str,
-        line_number: usize,
-    ) -> ftd::executor::Result<&'static str> {
-        match variant {
-            ftd::interpreter::FTD_FONT_SIZE_PX
-            | ftd::interpreter::FTD_FONT_SIZE_EM
-            | ftd::interpreter::FTD_FONT_SIZE_REM => Ok("{0}"),
-            t => ftd::executor::utils::parse_error(
-                format!("Unknown variant found for ftd.font-size: `{}`", t),
-                doc_id,
-                line_number,
-            ),
-        }
-    }
-
-    pub fn set_pattern_from_variant_str(
-        variant: &str,
-        doc_id: &str,
-        line_number: usize,
-    ) -> ftd::executor::Result<&'static str> {
-        match variant {
-            ftd::interpreter::FTD_FONT_SIZE_PX => Ok("{0}px"),
-            ftd::interpreter::FTD_FONT_SIZE_EM => Ok("{0}em"),
-            ftd::interpreter::FTD_FONT_SIZE_REM => Ok("{0}rem"),
-            t => ftd::executor::utils::parse_error(
-                format!("Unknown variant found for ftd.font-size: `{}`", t),
-                doc_id,
-                line_number,
-            ),
-        }
-    }
-
-    pub fn set_value_from_variant(
-        variant: &str,
-        value: &str,
-        doc_id: &str,
-        line_number: usize,
-    ) -> ftd::executor::Result<String> {
-        match variant {
-            ftd::interpreter::FTD_FONT_SIZE_PX => Ok(format!("{}px", value)),
-            ftd::interpreter::FTD_FONT_SIZE_EM => Ok(format!("{}em", value)),
-            ftd::interpreter::FTD_FONT_SIZE_REM => Ok(format!("{}rem", value)),
-            t => ftd::executor::utils::parse_error(
-                format!("Unknown variant found for ftd.font-size: `{}`", t),
-                doc_id,
-                line_number,
-            ),
+            ColumnType::Text => "text".to_string(),
+            ColumnType::Number => "number".to_string(),
+            ColumnType::Date => "date".to_string(),
+            ColumnType::Boolean => "boolean".to_string(),
         }
     }
 }
 
 #[derive(serde::Deserialize, Debug, Default, PartialEq, Clone, serde::Serialize)]
-pub struct Type {
-    pub size: Option<FontSize>,
-    pub line_height: Option<FontSize>,
-    pub letter_spacing: Option<FontSize>,
-    pub weight: Option<i64>,
-    pub font_family: Option<String>,
+pub struct TableColumn {
+    pub header: String,
+    pub field: String,
+    pub type_: ColumnType,
+    pub width: Option<Resizing>,
+    pub align: Option<TextAlign>,
+    pub sortable: bool,
+    pub cell: Option<String>,
 }
 
-impl Type {
-    fn new(
-        size: Option<FontSize>,
-        line_height: Option<FontSize>,
-        letter_spacing: Option<FontSize>,
-        weight: Option<i64>,
-        font_family: Option<String>,
-    ) -> Type {
-        Type {
-            size,
-            line_height,
-            letter_spacing,
-            weight,
-            font_family,
-        }
+impl TableColumn {
+    fn from_vec_values(
+        value: ftd::interpreter::Value,
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<Vec<TableColumn>> {
+        let mut result = vec![];
+        match value.inner() {
+            Some(ftd::interpreter::Value::List { data, kind })
+                if kind.kind.get_name().eq(ftd::interpreter::FTD_TABLE_COLUMN) =>
+            {
+                for element in data.iter() {
+                    let ln = element.line_number();
+                    result.push(TableColumn::from_value(element.to_owned(), doc, ln)?)
+                }
+            }
+            None => {}
+            t => {
+                return ftd::executor::utils::parse_error(
+                    format!(
+                        "Expected list value of type `{}`, found: {:?}",
+                        ftd::interpreter::FTD_TABLE_COLUMN,
+                        t
+                    ),
+                    doc.name,
+                    line_number,
+                )
+            }
+        };
+        Ok(result)
     }
 
     fn from_value(
         value: ftd::interpreter::PropertyValue,
         doc: &ftd::executor::TDoc,
         line_number: usize,
-    ) -> ftd::executor::Result<Type> {
+    ) -> ftd::executor::Result<TableColumn> {
         let value = value.resolve(&doc.itdoc(), line_number)?;
         let fields = match value.inner() {
             Some(ftd::interpreter::Value::Record { name, fields })
-                if name.eq(ftd::interpreter::FTD_TYPE) =>
+                if name.eq(ftd::interpreter::FTD_TABLE_COLUMN) =>
             {
                 fields
             }
@@ -2878,7 +4655,7 @@ impl Type {
                 return ftd::executor::utils::parse_error(
                     format!(
                         "Expected value of type record `{}`, found: {:?}",
-                        ftd::interpreter::FTD_COLOR,
+                        ftd::interpreter::FTD_TABLE_COLUMN,
                         t
                     ),
                     doc.name,
@@ -2886,117 +4663,127 @@ impl Type {
                 )
             }
         };
-        Type::from_values(fields, doc, line_number)
-    }
 
-    fn from_values(
-        values: ftd::Map<ftd::interpreter::PropertyValue>,
-        doc: &ftd::executor::TDoc,
-        line_number: usize,
-    ) -> ftd::executor::Result<Type> {
-        let size = {
-            if let Some(value) = values.get("size") {
-                FontSize::from_optional_value(value.to_owned(), doc, line_number)?
-            } else {
-                None
-            }
+        let get_value = |field_name: &str| {
+            fields
+                .get(field_name)
+                .ok_or_else(|| ftd::executor::Error::ParseError {
+                    message: format!("`{}` field in ftd.table-column not found", field_name),
+                    doc_id: doc.name.to_string(),
+                    line_number,
+                })?
+                .clone()
+                .resolve(&doc.itdoc(), line_number)
+                .map_err(ftd::executor::Error::from)
         };
-
-        let line_height = {
-            if let Some(value) = values.get("line-height") {
-                FontSize::from_optional_value(value.to_owned(), doc, line_number)?
-            } else {
-                None
-            }
+        let get_or_type = |field_name: &str| {
+            Ok::<_, ftd::executor::Error>(match get_value(field_name)?.inner() {
+                Some(value) => {
+                    let (_, variant, value) = value.get_or_type(doc.name, line_number)?;
+                    Some((variant.to_owned(), value.to_owned()))
+                }
+                None => None,
+            })
         };
 
-        let letter_spacing = {
-            if let Some(value) = values.get("letter-spacing") {
-                FontSize::from_optional_value(value.to_owned(), doc, line_number)?
-            } else {
-                None
-            }
-        };
+        Ok(TableColumn {
+            header: get_value("header")?.string(doc.name, line_number)?,
+            field: get_value("field")?.string(doc.name, line_number)?,
+            type_: match get_or_type("type")? {
+                Some(value) => ColumnType::from_values(value, doc, line_number)?,
+                None => ColumnType::Text,
+            },
+            width: Resizing::from_optional_values(get_or_type("width")?, doc, line_number)?,
+            align: TextAlign::from_optional_values(get_or_type("align")?, doc, line_number)?,
+            sortable: get_value("sortable")?.bool(doc.name, line_number)?,
+            cell: get_value("cell")?.optional_string(doc.name, line_number)?,
+        })
+    }
 
-        let weight = {
-            if let Some(value) = values.get("weight") {
-                value
-                    .clone()
-                    .resolve(&doc.itdoc(), line_number)?
-                    .optional_integer(doc.name, line_number)?
-            } else {
-                None
-            }
-        };
+    /// Numbers are aligned to the end of their column, unless the column says otherwise.
+    pub fn text_align(&self) -> Option<TextAlign> {
+        match self.align {
+            Some(ref align) => Some(align.to_owned()),
+            None if self.type_.eq(&ColumnType::Number) => Some(TextAlign::End),
+            None => None,
+        }
+    }
 
-        let font_family = {
-            if let Some(value) = values.get("font-family") {
-                value
-                    .clone()
-                    .resolve(&doc.itdoc(), line_number)?
-                    .optional_string(doc.name, line_number)?
-            } else {
-                None
-            }
-        };
+    pub(crate) fn column_list(
+        properties: &[ftd::interpreter::Property],
+        arguments: &[ftd::interpreter::Argument],
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+        key: &str,
+        inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
+        component_name: &str,
+    ) -> ftd::executor::Result<ftd::executor::Value<Vec<TableColumn>>> {
+        let value = ftd::executor::value::get_value_from_properties_using_key_and_arguments_dummy(
+            key,
+            component_name,
+            properties,
+            arguments,
+            doc,
+            line_number,
+            false,
+            inherited_variables,
+        )?;
 
-        Ok(Type::new(
-            size,
-            line_height,
-            letter_spacing,
-            weight,
-            font_family,
+        Ok(ftd::executor::Value::new(
+            match value.value {
+                Some(value) => TableColumn::from_vec_values(value, doc, line_number)?,
+                None => vec![],
+            },
+            value.line_number,
+            value.properties,
         ))
     }
 }
 
-#[derive(serde::Deserialize, Debug, Default, PartialEq, Clone, serde::Serialize)]
-pub struct ResponsiveType {
-    pub desktop: Type,
-    pub mobile: Type,
+#[derive(serde::Deserialize, Debug, PartialEq, Clone, serde::Serialize)]
+pub enum Region {
+    H1,
+    H2,
+    H3,
+    H4,
+    H5,
+    H6,
 }
 
-impl ResponsiveType {
-    fn from_values(
-        values: ftd::Map<ftd::interpreter::PropertyValue>,
-        doc: &ftd::executor::TDoc,
-        line_number: usize,
-    ) -> ftd::executor::Result<ResponsiveType> {
-        let desktop = {
-            let value = values
-                .get("desktop")
-                .ok_or(ftd::executor::Error::ParseError {
-                    message: "`desktop` field in ftd.responsive-type not found".to_string(),
-                    doc_id: doc.name.to_string(),
-                    line_number,
-                })?;
-            Type::from_value(value.to_owned(), doc, line_number)?
-        };
-
-        let mobile = {
-            if let Some(value) = values.get("mobile") {
-                Type::from_value(value.to_owned(), doc, line_number)?
-            } else {
-                desktop.clone()
-            }
-        };
-
-        Ok(ResponsiveType { desktop, mobile })
-    }
-
+impl Region {
     fn from_optional_values(
-        or_type_value: Option<ftd::Map<ftd::interpreter::PropertyValue>>,
+        or_type_value: Option<(String, ftd::interpreter::PropertyValue)>,
         doc: &ftd::executor::TDoc,
         line_number: usize,
     ) -> ftd::executor::Result<Option<Self>> {
         if let Some(value) = or_type_value {
-            Ok(Some(ResponsiveType::from_values(value, doc, line_number)?))
+            Ok(Some(Region::from_values(value, doc, line_number)?))
         } else {
             Ok(None)
         }
     }
 
-    pub(crate) fn optional_responsive_type(
+    fn from_values(
+        or_type_value: (String, ftd::interpreter::PropertyValue),
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<Self> {
+        match or_type_value.0.as_str() {
+            ftd::interpreter::FTD_REGION_H1 => Ok(Region::H1),
+            ftd::interpreter::FTD_REGION_H2 => Ok(Region::H2),
+            ftd::interpreter::FTD_REGION_H3 => Ok(Region::H3),
+            ftd::interpreter::FTD_REGION_H4 => Ok(Region::H4),
+            ftd::interpreter::FTD_REGION_H5 => Ok(Region::H5),
+            ftd::interpreter::FTD_REGION_H6 => Ok(Region::H6),
+            t => ftd::executor::utils::parse_error(
+                format!("Unknown variant `{}` for or-type `ftd.region`", t),
+                doc.name,
+                line_number,
+            ),
+        }
+    }
+
+    pub(crate) fn optional_region(
         properties: &[ftd::interpreter::Property],
         arguments: &[ftd::interpreter::Argument],
         doc: &ftd::executor::TDoc,
@@ -3004,107 +4791,61 @@ impl ResponsiveType {
         key: &str,
         inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
         component_name: &str,
-    ) -> ftd::executor::Result<ftd::executor::Value<Option<ResponsiveType>>> {
-        let record_values = ftd::executor::value::optional_record_inherited(
+    ) -> ftd::executor::Result<ftd::executor::Value<Option<Region>>> {
+        let or_type_value = ftd::executor::value::optional_or_type(
             key,
             component_name,
             properties,
             arguments,
             doc,
             line_number,
-            ftd::interpreter::FTD_RESPONSIVE_TYPE,
+            ftd::interpreter::FTD_REGION,
             inherited_variables,
         )?;
 
         Ok(ftd::executor::Value::new(
-            ResponsiveType::from_optional_values(record_values.value, doc, line_number)?,
-            record_values.line_number,
-            record_values.properties,
+            Region::from_optional_values(or_type_value.value, doc, line_number)?,
+            or_type_value.line_number,
+            or_type_value.properties,
         ))
     }
 
-    pub fn to_css_font_size(&self, device: &Option<ftd::executor::Device>) -> Option<String> {
-        match device {
-            Some(ftd::executor::Device::Mobile) => {
-                self.mobile.size.as_ref().map(|v| v.to_css_string())
-            }
-            _ => self.desktop.size.as_ref().map(|v| v.to_css_string()),
-        }
-    }
-
-    pub fn font_size_pattern() -> (String, bool) {
-        ("({0})[\"size\"]".to_string(), true)
-    }
-
-    pub fn to_css_line_height(&self, device: &Option<ftd::executor::Device>) -> Option<String> {
-        match device {
-            Some(ftd::executor::Device::Mobile) => {
-                self.mobile.line_height.as_ref().map(|v| v.to_css_string())
-            }
-            _ => self.desktop.line_height.as_ref().map(|v| v.to_css_string()),
-        }
-    }
-
-    pub fn line_height_pattern() -> (String, bool) {
-        ("({0})[\"line-height\"]".to_string(), true)
-    }
-
-    pub fn to_css_letter_spacing(&self, device: &Option<ftd::executor::Device>) -> Option<String> {
-        match device {
-            Some(ftd::executor::Device::Mobile) => self
-                .mobile
-                .letter_spacing
-                .as_ref()
-                .map(|v| v.to_css_string()),
-            _ => self
-                .desktop
-                .letter_spacing
-                .as_ref()
-                .map(|v| v.to_css_string()),
-        }
-    }
-
-    pub fn letter_spacing_pattern() -> (String, bool) {
-        ("({0})[\"letter-spacing\"]".to_string(), true)
+    // For now, only components with region h1 and h2 will have auto generated ids
+    // if there is no user defined id
+    pub fn is_heading(&self) -> bool {
+        matches!(self, Region::H1 | Region::H2 | Region::H3 | Region::H4)
     }
 
-    pub fn to_css_weight(&self, device: &Option<ftd::executor::Device>) -> Option<String> {
-        match device {
-            Some(ftd::executor::Device::Mobile) => {
-                self.mobile.weight.as_ref().map(|v| v.to_string())
-            }
-            _ => self.desktop.weight.as_ref().map(|v| v.to_string()),
+    pub fn to_css_string(&self) -> String {
+        match self {
+            Region::H1 => "h1".to_string(),
+            Region::H2 => "h2".to_string(),
+            Region::H3 => "h3".to_string(),
+            Region::H4 => "h4".to_string(),
+            Region::H5 => "h5".to_string(),
+            Region::H6 => "h6".to_string(),
         }
     }
-
-    pub fn weight_pattern() -> (String, bool) {
-        ("({0}).weight".to_string(), true)
-    }
-
-    pub fn to_css_font_family(&self) -> Option<String> {
-        self.desktop.font_family.to_owned()
-    }
-
-    pub fn font_family_pattern() -> (String, bool) {
-        ("({0})[\"font-family\"]".to_string(), true)
-    }
 }
 
 #[derive(serde::Deserialize, Debug, PartialEq, Clone, serde::Serialize)]
-pub enum Anchor {
-    Window,
-    Parent,
-    Id(String),
+pub enum WhiteSpace {
+    NORMAL,
+    NOWRAP,
+    PRE,
+    PREWRAP,
+    PRELINE,
+    BREAKSPACES,
 }
 
-impl Anchor {
+impl WhiteSpace {
     fn from_optional_values(
         or_type_value: Option<(String, ftd::interpreter::PropertyValue)>,
         doc: &ftd::executor::TDoc,
         line_number: usize,
     ) -> ftd::executor::Result<Option<Self>> {
         if let Some(value) = or_type_value {
-            Ok(Some(Anchor::from_values(value, doc, line_number)?))
+            Ok(Some(WhiteSpace::from_values(value, doc, line_number)?))
         } else {
             Ok(None)
         }
@@ -3116,24 +4857,21 @@ impl Anchor {
         line_number: usize,
     ) -> ftd::executor::Result<Self> {
         match or_type_value.0.as_str() {
-            ftd::interpreter::FTD_ANCHOR_WINDOW => Ok(Anchor::Window),
-            ftd::interpreter::FTD_ANCHOR_PARENT => Ok(Anchor::Parent),
-            ftd::interpreter::FTD_ANCHOR_ID => Ok(Anchor::Id(
-                or_type_value
-                    .1
-                    .clone()
-                    .resolve(&doc.itdoc(), line_number)?
-                    .string(doc.name, line_number)?,
-            )),
+            ftd::interpreter::FTD_WHITESPACE_NORMAL => Ok(WhiteSpace::NORMAL),
+            ftd::interpreter::FTD_WHITESPACE_NOWRAP => Ok(WhiteSpace::NOWRAP),
+            ftd::interpreter::FTD_WHITESPACE_PRE => Ok(WhiteSpace::PRE),
+            ftd::interpreter::FTD_WHITESPACE_PREWRAP => Ok(WhiteSpace::PREWRAP),
+            ftd::interpreter::FTD_WHITESPACE_PRELINE => Ok(WhiteSpace::PRELINE),
+            ftd::interpreter::FTD_WHITESPACE_BREAKSPACES => Ok(WhiteSpace::BREAKSPACES),
             t => ftd::executor::utils::parse_error(
-                format!("Unknown variant `{}` for or-type `ftd.anchor`", t),
+                format!("Unknown variant `{}` for or-type `ftd.whitespace`", t),
                 doc.name,
                 line_number,
             ),
         }
     }
 
-    pub(crate) fn optional_anchor(
+    pub(crate) fn optional_whitespace(
         properties: &[ftd::interpreter::Property],
         arguments: &[ftd::interpreter::Argument],
         doc: &ftd::executor::TDoc,
@@ -3141,7 +4879,7 @@ impl Anchor {
         key: &str,
         inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
         component_name: &str,
-    ) -> ftd::executor::Result<ftd::executor::Value<Option<Anchor>>> {
+    ) -> ftd::executor::Result<ftd::executor::Value<Option<WhiteSpace>>> {
         let or_type_value = ftd::executor::value::optional_or_type(
             key,
             component_name,
@@ -3149,12 +4887,12 @@ impl Anchor {
             arguments,
             doc,
             line_number,
-            ftd::interpreter::FTD_ANCHOR,
+            ftd::interpreter::FTD_WHITESPACE,
             inherited_variables,
         )?;
 
         Ok(ftd::executor::Value::new(
-            Anchor::from_optional_values(or_type_value.value, doc, line_number)?,
+            WhiteSpace::from_optional_values(or_type_value.value, doc, line_number)?,
             or_type_value.line_number,
             or_type_value.properties,
         ))
@@ -3162,36 +4900,33 @@ impl Anchor {
 
     pub fn to_css_string(&self) -> String {
         match self {
-            Anchor::Window => "fixed".to_string(),
-            Anchor::Parent => "absolute".to_string(),
-            Anchor::Id(_) => "absolute".to_string(),
+            WhiteSpace::NORMAL => "normal".to_string(),
+            WhiteSpace::NOWRAP => "nowrap".to_string(),
+            WhiteSpace::PRE => "pre".to_string(),
+            WhiteSpace::PRELINE => "pre-line".to_string(),
+            WhiteSpace::PREWRAP => "pre-wrap".to_string(),
+            WhiteSpace::BREAKSPACES => "break-spaces".to_string(),
         }
     }
 }
 
+/// The side of its trigger a popover or a tooltip is shown on.
 #[derive(serde::Deserialize, Debug, PartialEq, Clone, serde::Serialize)]
-pub enum TextInputType {
-    TEXT,
-    EMAIL,
-    PASSWORD,
-    URL,
-    DATETIME,
-    DATE,
-    TIME,
-    MONTH,
-    WEEK,
-    COLOR,
-    FILE,
+pub enum Placement {
+    Top,
+    Bottom,
+    Left,
+    Right,
 }
 
-impl TextInputType {
+impl Placement {
     fn from_optional_values(
         or_type_value: Option<(String, ftd::interpreter::PropertyValue)>,
         doc: &ftd::executor::TDoc,
         line_number: usize,
     ) -> ftd::executor::Result<Option<Self>> {
         if let Some(value) = or_type_value {
-            Ok(Some(TextInputType::from_values(value, doc, line_number)?))
+            Ok(Some(Placement::from_values(value, doc, line_number)?))
         } else {
             Ok(None)
         }
@@ -3203,26 +4938,19 @@ impl TextInputType {
         line_number: usize,
     ) -> ftd::executor::Result<Self> {
         match or_type_value.0.as_str() {
-            ftd::interpreter::FTD_TEXT_INPUT_TYPE_TEXT => Ok(TextInputType::TEXT),
-            ftd::interpreter::FTD_TEXT_INPUT_TYPE_EMAIL => Ok(TextInputType::EMAIL),
-            ftd::interpreter::FTD_TEXT_INPUT_TYPE_PASSWORD => Ok(TextInputType::PASSWORD),
-            ftd::interpreter::FTD_TEXT_INPUT_TYPE_URL => Ok(TextInputType::URL),
-            ftd::interpreter::FTD_TEXT_INPUT_TYPE_DATETIME => Ok(TextInputType::DATETIME),
-            ftd::interpreter::FTD_TEXT_INPUT_TYPE_DATE => Ok(TextInputType::DATE),
-            ftd::interpreter::FTD_TEXT_INPUT_TYPE_TIME => Ok(TextInputType::TIME),
-            ftd::interpreter::FTD_TEXT_INPUT_TYPE_MONTH => Ok(TextInputType::MONTH),
-            ftd::interpreter::FTD_TEXT_INPUT_TYPE_WEEK => Ok(TextInputType::WEEK),
-            ftd::interpreter::FTD_TEXT_INPUT_TYPE_COLOR => Ok(TextInputType::COLOR),
-            ftd::interpreter::FTD_TEXT_INPUT_TYPE_FILE => Ok(TextInputType::FILE),
+            ftd::interpreter::FTD_PLACEMENT_TOP => Ok(Placement::Top),
+            ftd::interpreter::FTD_PLACEMENT_BOTTOM => Ok(Placement::Bottom),
+            ftd::interpreter::FTD_PLACEMENT_LEFT => Ok(Placement::Left),
+            ftd::interpreter::FTD_PLACEMENT_RIGHT => Ok(Placement::Right),
             t => ftd::executor::utils::parse_error(
-                format!("Unknown variant `{}` for or-type `ftd.text-input-type`", t),
+                format!("Unknown variant `{}` for or-type `ftd.placement`", t),
                 doc.name,
                 line_number,
             ),
         }
     }
 
-    pub(crate) fn optional_text_input_type(
+    pub(crate) fn optional_placement(
         properties: &[ftd::interpreter::Property],
         arguments: &[ftd::interpreter::Argument],
         doc: &ftd::executor::TDoc,
@@ -3230,7 +4958,7 @@ impl TextInputType {
         key: &str,
         inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
         component_name: &str,
-    ) -> ftd::executor::Result<ftd::executor::Value<Option<TextInputType>>> {
+    ) -> ftd::executor::Result<ftd::executor::Value<Option<Placement>>> {
         let or_type_value = ftd::executor::value::optional_or_type(
             key,
             component_name,
@@ -3238,12 +4966,12 @@ impl TextInputType {
             arguments,
             doc,
             line_number,
-            ftd::interpreter::FTD_TEXT_INPUT_TYPE,
+            ftd::interpreter::FTD_PLACEMENT,
             inherited_variables,
         )?;
 
         Ok(ftd::executor::Value::new(
-            TextInputType::from_optional_values(or_type_value.value, doc, line_number)?,
+            Placement::from_optional_values(or_type_value.value, doc, line_number)?,
             or_type_value.line_number,
             or_type_value.properties,
         ))
@@ -3251,39 +4979,35 @@ impl TextInputType {
 
     pub fn to_css_string(&self) -> String {
         match self {
-            TextInputType::TEXT => "text".to_string(),
-            TextInputType::EMAIL => "email".to_string(),
-            TextInputType::PASSWORD => "password".to_string(),
-            TextInputType::URL => "url".to_string(),
-            TextInputType::DATETIME => "datetime-local".to_string(),
-            TextInputType::DATE => "date".to_string(),
-            TextInputType::TIME => "time".to_string(),
-            TextInputType::MONTH => "month".to_string(),
-            TextInputType::WEEK => "week".to_string(),
-            TextInputType::COLOR => "color".to_string(),
-            TextInputType::FILE => "file".to_string(),
+            Placement::Top => "top".to_string(),
+            Placement::Bottom => "bottom".to_string(),
+            Placement::Left => "left".to_string(),
+            Placement::Right => "right".to_string(),
         }
     }
 }
 
+/// The ARIA landmark `role` of an element, set with the `landmark` property.
 #[derive(serde::Deserialize, Debug, PartialEq, Clone, serde::Serialize)]
-pub enum Region {
-    H1,
-    H2,
-    H3,
-    H4,
-    H5,
-    H6,
+pub enum Landmark {
+    Banner,
+    Navigation,
+    Main,
+    Complementary,
+    ContentInfo,
+    Search,
+    Form,
+    Region,
 }
 
-impl Region {
+impl Landmark {
     fn from_optional_values(
         or_type_value: Option<(String, ftd::interpreter::PropertyValue)>,
         doc: &ftd::executor::TDoc,
         line_number: usize,
     ) -> ftd::executor::Result<Option<Self>> {
         if let Some(value) = or_type_value {
-            Ok(Some(Region::from_values(value, doc, line_number)?))
+            Ok(Some(Landmark::from_values(value, doc, line_number)?))
         } else {
             Ok(None)
         }
@@ -3295,21 +5019,23 @@ impl Region {
         line_number: usize,
     ) -> ftd::executor::Result<Self> {
         match or_type_value.0.as_str() {
-            ftd::interpreter::FTD_REGION_H1 => Ok(Region::H1),
-            ftd::interpreter::FTD_REGION_H2 => Ok(Region::H2),
-            ftd::interpreter::FTD_REGION_H3 => Ok(Region::H3),
-            ftd::interpreter::FTD_REGION_H4 => Ok(Region::H4),
-            ftd::interpreter::FTD_REGION_H5 => Ok(Region::H5),
-            ftd::interpreter::FTD_REGION_H6 => Ok(Region::H6),
+            ftd::interpreter::FTD_LANDMARK_BANNER => Ok(Landmark::Banner),
+            ftd::interpreter::FTD_LANDMARK_NAVIGATION => Ok(Landmark::Navigation),
+            ftd::interpreter::FTD_LANDMARK_MAIN => Ok(Landmark::Main),
+            ftd::interpreter::FTD_LANDMARK_COMPLEMENTARY => Ok(Landmark::Complementary),
+            ftd::interpreter::FTD_LANDMARK_CONTENT_INFO => Ok(Landmark::ContentInfo),
+            ftd::interpreter::FTD_LANDMARK_SEARCH => Ok(Landmark::Search),
+            ftd::interpreter::FTD_LANDMARK_FORM => Ok(Landmark::Form),
+            ftd::interpreter::FTD_LANDMARK_REGION => Ok(Landmark::Region),
             t => ftd::executor::utils::parse_error(
-                format!("Unknown variant `{}` for or-type `ftd.region`", t),
+                format!("Unknown variant `{}` for or-type `ftd.landmark`", t),
                 doc.name,
                 line_number,
             ),
         }
     }
 
-    pub(crate) fn optional_region(
+    pub(crate) fn optional_landmark(
         properties: &[ftd::interpreter::Property],
         arguments: &[ftd::interpreter::Argument],
         doc: &ftd::executor::TDoc,
@@ -3317,7 +5043,7 @@ impl Region {
         key: &str,
         inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
         component_name: &str,
-    ) -> ftd::executor::Result<ftd::executor::Value<Option<Region>>> {
+    ) -> ftd::executor::Result<ftd::executor::Value<Option<Landmark>>> {
         let or_type_value = ftd::executor::value::optional_or_type(
             key,
             component_name,
@@ -3325,53 +5051,47 @@ impl Region {
             arguments,
             doc,
             line_number,
-            ftd::interpreter::FTD_REGION,
+            ftd::interpreter::FTD_LANDMARK,
             inherited_variables,
         )?;
 
         Ok(ftd::executor::Value::new(
-            Region::from_optional_values(or_type_value.value, doc, line_number)?,
+            Landmark::from_optional_values(or_type_value.value, doc, line_number)?,
             or_type_value.line_number,
             or_type_value.properties,
         ))
     }
 
-    // For now, only components with region h1 and h2 will have auto generated ids
-    // if there is no user defined id
-    pub fn is_heading(&self) -> bool {
-        matches!(self, Region::H1 | Region::H2 | Region::H3 | Region::H4)
-    }
-
     pub fn to_css_string(&self) -> String {
         match self {
-            Region::H1 => "h1".to_string(),
-            Region::H2 => "h2".to_string(),
-            Region::H3 => "h3".to_string(),
-            Region::H4 => "h4".to_string(),
-            Region::H5 => "h5".to_string(),
-            Region::H6 => "h6".to_string(),
+            Landmark::Banner => "banner".to_string(),
+            Landmark::Navigation => "navigation".to_string(),
+            Landmark::Main => "main".to_string(),
+            Landmark::Complementary => "complementary".to_string(),
+            Landmark::ContentInfo => "contentinfo".to_string(),
+            Landmark::Search => "search".to_string(),
+            Landmark::Form => "form".to_string(),
+            Landmark::Region => "region".to_string(),
         }
     }
 }
 
+/// How assistive technologies announce updates to a live region, set with `aria-live`.
 #[derive(serde::Deserialize, Debug, PartialEq, Clone, serde::Serialize)]
-pub enum WhiteSpace {
-    NORMAL,
-    NOWRAP,
-    PRE,
-    PREWRAP,
-    PRELINE,
-    BREAKSPACES,
+pub enum AriaLive {
+    Polite,
+    Assertive,
+    Off,
 }
 
-impl WhiteSpace {
+impl AriaLive {
     fn from_optional_values(
         or_type_value: Option<(String, ftd::interpreter::PropertyValue)>,
         doc: &ftd::executor::TDoc,
         line_number: usize,
     ) -> ftd::executor::Result<Option<Self>> {
         if let Some(value) = or_type_value {
-            Ok(Some(WhiteSpace::from_values(value, doc, line_number)?))
+            Ok(Some(AriaLive::from_values(value, doc, line_number)?))
         } else {
             Ok(None)
         }
@@ -3383,21 +5103,18 @@ impl WhiteSpace {
         line_number: usize,
     ) -> ftd::executor::Result<Self> {
         match or_type_value.0.as_str() {
-            ftd::interpreter::FTD_WHITESPACE_NORMAL => Ok(WhiteSpace::NORMAL),
-            ftd::interpreter::FTD_WHITESPACE_NOWRAP => Ok(WhiteSpace::NOWRAP),
-            ftd::interpreter::FTD_WHITESPACE_PRE => Ok(WhiteSpace::PRE),
-            ftd::interpreter::FTD_WHITESPACE_PREWRAP => Ok(WhiteSpace::PREWRAP),
-            ftd::interpreter::FTD_WHITESPACE_PRELINE => Ok(WhiteSpace::PRELINE),
-            ftd::interpreter::FTD_WHITESPACE_BREAKSPACES => Ok(WhiteSpace::BREAKSPACES),
+            ftd::interpreter::FTD_ARIA_LIVE_POLITE => Ok(AriaLive::Polite),
+            ftd::interpreter::FTD_ARIA_LIVE_ASSERTIVE => Ok(AriaLive::Assertive),
+            ftd::interpreter::FTD_ARIA_LIVE_OFF => Ok(AriaLive::Off),
             t => ftd::executor::utils::parse_error(
-                format!("Unknown variant `{}` for or-type `ftd.whitespace`", t),
+                format!("Unknown variant `{}` for or-type `ftd.aria-live`", t),
                 doc.name,
                 line_number,
             ),
         }
     }
 
-    pub(crate) fn optional_whitespace(
+    pub(crate) fn optional_aria_live(
         properties: &[ftd::interpreter::Property],
         arguments: &[ftd::interpreter::Argument],
         doc: &ftd::executor::TDoc,
@@ -3405,7 +5122,7 @@ impl WhiteSpace {
         key: &str,
         inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
         component_name: &str,
-    ) -> ftd::executor::Result<ftd::executor::Value<Option<WhiteSpace>>> {
+    ) -> ftd::executor::Result<ftd::executor::Value<Option<AriaLive>>> {
         let or_type_value = ftd::executor::value::optional_or_type(
             key,
             component_name,
@@ -3413,12 +5130,12 @@ impl WhiteSpace {
             arguments,
             doc,
             line_number,
-            ftd::interpreter::FTD_WHITESPACE,
+            ftd::interpreter::FTD_ARIA_LIVE,
             inherited_variables,
         )?;
 
         Ok(ftd::executor::Value::new(
-            WhiteSpace::from_optional_values(or_type_value.value, doc, line_number)?,
+            AriaLive::from_optional_values(or_type_value.value, doc, line_number)?,
             or_type_value.line_number,
             or_type_value.properties,
         ))
@@ -3426,12 +5143,9 @@ impl WhiteSpace {
 
     pub fn to_css_string(&self) -> String {
         match self {
-            WhiteSpace::NORMAL => "normal".to_string(),
-            WhiteSpace::NOWRAP => "nowrap".to_string(),
-            WhiteSpace::PRE => "pre".to_string(),
-            WhiteSpace::PRELINE => "pre-line".to_string(),
-            WhiteSpace::PREWRAP => "pre-wrap".to_string(),
-            WhiteSpace::BREAKSPACES => "break-spaces".to_string(),
+            AriaLive::Polite => "polite".to_string(),
+            AriaLive::Assertive => "assertive".to_string(),
+            AriaLive::Off => "off".to_string(),
         }
     }
 }
@@ -46,6 +46,7 @@ pub(crate) fn update_instruction_for_loop_element(
     instruction: &ftd::interpreter::Component,
     doc: &mut ftd::executor::TDoc,
     index_in_loop: usize,
+    key_in_loop: Option<&ftd::interpreter::Value>,
     alias: &str,
     reference_name: &str,
     inherited_variables: &mut ftd::VecMap<(String, Vec<usize>)>,
@@ -55,18 +56,35 @@ pub(crate) fn update_instruction_for_loop_element(
     let mut instruction = instruction.clone();
     let reference_replace_pattern = ftd::interpreter::PropertyValueSource::Loop(alias.to_string())
         .get_reference_name(alias, &doc.itdoc());
-    let replace_with = format!("{}.{}", reference_name, index_in_loop);
+    // the entries of a map are referred to by their key, the items of a list by their index
+    let replace_with = match key_in_loop {
+        Some(ftd::interpreter::Value::Integer { value }) => {
+            format!("{}.{}", reference_name, value)
+        }
+        Some(ftd::interpreter::Value::String { text }) => format!("{}.{}", reference_name, text),
+        _ => format!("{}.{}", reference_name, index_in_loop),
+    };
     let map =
         std::iter::IntoIterator::into_iter([(reference_replace_pattern, replace_with)]).collect();
-    let replace_property_value = std::iter::IntoIterator::into_iter([(
-        doc.itdoc()
-            .resolve_name(format!("{}#{}", doc_name, ftd::interpreter::FTD_LOOP_COUNTER).as_str()),
-        ftd::interpreter::Value::Integer {
-            value: index_in_loop as i64,
-        }
-        .into_property_value(false, instruction.line_number),
-    )])
-    .collect();
+    let mut replace_property_value: ftd::Map<ftd::interpreter::PropertyValue> =
+        std::iter::IntoIterator::into_iter([(
+            doc.itdoc().resolve_name(
+                format!("{}#{}", doc_name, ftd::interpreter::FTD_LOOP_COUNTER).as_str(),
+            ),
+            ftd::interpreter::Value::Integer {
+                value: index_in_loop as i64,
+            }
+            .into_property_value(false, instruction.line_number),
+        )])
+        .collect();
+    if let Some(key) = key_in_loop {
+        replace_property_value.insert(
+            doc.itdoc()
+                .resolve_name(format!("{}#{}", doc_name, ftd::interpreter::FTD_LOOP_KEY).as_str()),
+            key.clone()
+                .into_property_value(false, instruction.line_number),
+        );
+    }
 
     update_local_variable_references_in_component(
         &mut instruction,
@@ -356,6 +374,7 @@ fn update_local_variable_reference_in_property_value(
                     }
                 }
                 ftd::interpreter::Value::Record { fields, .. }
+                | ftd::interpreter::Value::Map { data: fields, .. }
                 | ftd::interpreter::Value::Object { values: fields } => {
                     for d in fields.values_mut() {
                         update_local_variable_reference_in_property_value(
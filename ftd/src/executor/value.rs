@@ -611,7 +611,6 @@ pub fn optional_bool(
     }
 }
 
-#[allow(dead_code)]
 pub fn optional_f64(
     key: &str,
     component_name: &str,
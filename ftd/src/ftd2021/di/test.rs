@@ -143,14 +143,17 @@ fn di_import() {
         "ASTParseError: foo:1 -> Subsection not expected for import statement \
         `Section { name: \"import\", kind: None, caption: Some(KV(KV { \
         line_number: 1, key: \"$caption$\", kind: None, value: Some(\"foo\"), \
-        condition: None, access_modifier: Public, source: Caption })), \
-        headers: Headers([]), body: None, sub_sections: [Section { name: \
-        \"ftd.text\", kind: None, caption: Some(KV(KV { line_number: 3, key: \
-        \"$caption$\", kind: None, value: Some(\"Hello\"), condition: None, \
-        access_modifier: Public, source: Caption })), headers: Headers([]), \
-        body: None, sub_sections: [], is_commented: false, line_number: 3, \
-        block_body: false }], is_commented: false, line_number: 1, \
-        block_body: false }`",
+        condition: None, access_modifier: Public, source: Caption, span: Some(Span { \
+        start: 11, end: 14, line_number: 1, column: 12 }) })), headers: Headers([]), \
+        body: None, sub_sections: [Section { name: \"ftd.text\", kind: None, \
+        caption: Some(KV(KV { line_number: 3, key: \"$caption$\", kind: None, \
+        value: Some(\"Hello\"), condition: None, access_modifier: Public, \
+        source: Caption, span: Some(Span { start: 29, end: 34, line_number: 3, \
+        column: 14 }) })), headers: Headers([]), body: None, sub_sections: [], \
+        is_commented: false, line_number: 3, block_body: false, span: Some(Span { \
+        start: 19, end: 34, line_number: 3, column: 4 }) }], is_commented: false, \
+        line_number: 1, block_body: false, span: Some(Span { start: 3, end: 14, \
+        line_number: 1, column: 4 }) }`",
     )
 }
 
@@ -55,6 +55,7 @@ impl Variable {
                 message: format!("Expected boolean found: {:?}", t),
                 doc_id: doc_id.to_string(),
                 line_number: s.line_number,
+                span: None,
             }),
         }
     }
@@ -267,33 +267,10 @@ impl<'a> DependencyGenerator<'a> {
                     if self.node.device.is_some() && just_one_property_without_condition {
                         continue;
                     }
-                    let mut desktop_value_string = "".to_string();
-                    if let Some(value_string) =
-                        ftd::html::utils::get_formatted_dep_string_from_property_value(
-                            self.id,
-                            self.doc,
-                            &property.value,
-                            &property_with_pattern.pattern_with_eval,
-                            Some("desktop".to_string()),
-                            false,
-                        )?
-                    {
-                        desktop_value_string = value_string;
-                    }
-
-                    let mut mobile_value_string = "".to_string();
-                    if let Some(value_string) =
-                        ftd::html::utils::get_formatted_dep_string_from_property_value(
-                            self.id,
-                            self.doc,
-                            &property.value,
-                            &property_with_pattern.pattern_with_eval,
-                            Some("mobile".to_string()),
-                            false,
-                        )?
-                    {
-                        mobile_value_string = value_string;
-                    }
+                    let device_value_strings =
+                        device_value_strings(self.id, self.doc, property_with_pattern)?;
+                    let desktop_value_string = device_value_strings.desktop.to_string();
+                    let mobile_value_string = device_value_strings.mobile.to_string();
 
                     if self
                         .node
@@ -313,23 +290,15 @@ impl<'a> DependencyGenerator<'a> {
                     {
                         expressions
                             .push((condition, format!("{} = {};", key, desktop_value_string)));
-                    } else if desktop_value_string.ne(&mobile_value_string) {
+                    } else if !device_value_strings.is_same_on_all_devices() {
                         is_static = false;
                         let value = ftd::html::utils::js_expression_from_list(
-                            std::iter::IntoIterator::into_iter([
-                                (
-                                    Some("data[\"ftd#device\"] == \"desktop\"".to_string()),
-                                    format!(
-                                        "document.querySelector(`[data-id=\"{}\"]`).setAttribute(\"{}\", {});",
-                                        node_data_id, key, desktop_value_string
-                                    )
-                                ),
-                                (None, format!(
+                            device_value_strings.expressions(|value_string| {
+                                format!(
                                     "document.querySelector(`[data-id=\"{}\"]`).setAttribute(\"{}\", {});",
-                                    node_data_id, key, mobile_value_string
-                                )),
-                            ])
-                                .collect(),
+                                    node_data_id, key, value_string
+                                )
+                            }),
                             Some(key.as_str()),attribute
                                 .default
                                 .as_ref()
@@ -577,33 +546,10 @@ impl<'a> DependencyGenerator<'a> {
                     if self.node.device.is_some() && just_one_property_without_condition {
                         continue;
                     }
-                    let mut desktop_value_string = "".to_string();
-                    if let Some(value_string) =
-                        ftd::html::utils::get_formatted_dep_string_from_property_value(
-                            self.id,
-                            self.doc,
-                            &property.value,
-                            &property_with_pattern.pattern_with_eval,
-                            Some("desktop".to_string()),
-                            false,
-                        )?
-                    {
-                        desktop_value_string = value_string;
-                    }
-
-                    let mut mobile_value_string = "".to_string();
-                    if let Some(value_string) =
-                        ftd::html::utils::get_formatted_dep_string_from_property_value(
-                            self.id,
-                            self.doc,
-                            &property.value,
-                            &property_with_pattern.pattern_with_eval,
-                            Some("mobile".to_string()),
-                            false,
-                        )?
-                    {
-                        mobile_value_string = value_string;
-                    }
+                    let device_value_strings =
+                        device_value_strings(self.id, self.doc, property_with_pattern)?;
+                    let desktop_value_string = device_value_strings.desktop.to_string();
+                    let mobile_value_string = device_value_strings.mobile.to_string();
 
                     if self
                         .node
@@ -623,17 +569,11 @@ impl<'a> DependencyGenerator<'a> {
                     {
                         expressions
                             .push((condition, format!("{} = {};", key, desktop_value_string)));
-                    } else if desktop_value_string.ne(&mobile_value_string) {
+                    } else if !device_value_strings.is_same_on_all_devices() {
                         is_static = false;
                         let value = ftd::html::utils::js_expression_from_list(
-                            std::iter::IntoIterator::into_iter([
-                                (
-                                    Some("data[\"ftd#device\"] == \"desktop\"".to_string()),
-                                    format!("{} = {};", key, desktop_value_string),
-                                ),
-                                (None, format!("{} = {};", key, mobile_value_string)),
-                            ])
-                            .collect(),
+                            device_value_strings
+                                .expressions(|value_string| format!("{} = {};", key, value_string)),
                             Some(key.as_str()),
                             format!(
                                 "{} = {}",
@@ -891,6 +831,70 @@ impl<'a> DependencyGenerator<'a> {
     }
 }
 
+/// The value of a device dependent property for every breakpoint in `ftd#breakpoints`, and for
+/// `mobile` and `desktop`, which `ftd.mobile` and `ftd.desktop` are rendered for.
+struct DeviceValueStrings {
+    mobile: String,
+    desktop: String,
+    breakpoints: Vec<(String, String)>,
+}
+
+impl DeviceValueStrings {
+    fn is_same_on_all_devices(&self) -> bool {
+        self.desktop.eq(&self.mobile) && self.breakpoints.iter().all(|(_, v)| v.eq(&self.mobile))
+    }
+
+    /// The expressions to run on each device, the mobile one is the fallback
+    fn expressions(&self, expression: impl Fn(&str) -> String) -> Vec<(Option<String>, String)> {
+        let mut expressions = vec![];
+        for (device, value_string) in self.breakpoints.iter() {
+            if value_string.ne(&self.mobile) {
+                expressions.push((
+                    Some(format!("data[\"ftd#device\"] == {:?}", device)),
+                    expression(value_string),
+                ));
+            }
+        }
+        expressions.push((None, expression(&self.mobile)));
+        expressions
+    }
+}
+
+fn device_value_strings(
+    id: &str,
+    doc: &ftd::interpreter::TDoc,
+    property_with_pattern: &ftd::node::PropertyWithPattern,
+) -> ftd::html::Result<DeviceValueStrings> {
+    let value_string = |device: &str| {
+        ftd::html::utils::get_formatted_dep_string_from_property_value(
+            id,
+            doc,
+            &property_with_pattern.property.value,
+            &property_with_pattern.pattern_with_eval,
+            Some(device.to_string()),
+            false,
+        )
+        .map(|v| v.unwrap_or_default())
+    };
+    let desktop = value_string("desktop")?;
+    let mut breakpoints = vec![];
+    for breakpoint in ftd::executor::Breakpoint::from_bag(doc)? {
+        if breakpoint.name.eq("mobile") {
+            continue;
+        }
+        let value = value_string(breakpoint.name.as_str())?;
+        breakpoints.push((breakpoint.name, value));
+    }
+    if !breakpoints.iter().any(|(name, _)| name.eq("desktop")) {
+        breakpoints.push(("desktop".to_string(), desktop.to_string()));
+    }
+    Ok(DeviceValueStrings {
+        mobile: value_string("mobile")?,
+        desktop,
+        breakpoints,
+    })
+}
+
 fn node_for_properties(
     value: &ftd::node::Value,
     var_dependencies: &mut ftd::VecMap<String>,
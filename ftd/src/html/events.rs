@@ -71,6 +71,11 @@ impl ftd::html::Action {
             ftd::interpreter::Value::Integer { value } => serde_json::json!(value),
             ftd::interpreter::Value::Decimal { value } => serde_json::json!(value),
             ftd::interpreter::Value::Boolean { value } => serde_json::json!(value),
+            ftd::interpreter::Value::DateTime { value }
+            | ftd::interpreter::Value::Date { value } => {
+                serde_json::json!(value)
+            }
+            ftd::interpreter::Value::Duration { seconds } => serde_json::json!(seconds),
             ftd::interpreter::Value::Optional { data, .. } => {
                 if let Some(data) = data.as_ref() {
                     ftd::html::Action::from_value(data)
@@ -142,6 +147,11 @@ fn to_event_name(event_name: &ftd::interpreter::EventName) -> String {
         ftd::interpreter::EventName::Change => "onchange".to_string(),
         ftd::interpreter::EventName::Blur => "onblur".to_string(),
         ftd::interpreter::EventName::Focus => "onfocus".to_string(),
+        ftd::interpreter::EventName::Submit => "onsubmit".to_string(),
+        ftd::interpreter::EventName::Play => "onplay".to_string(),
+        ftd::interpreter::EventName::Pause => "onpause".to_string(),
+        ftd::interpreter::EventName::Ended => "onended".to_string(),
+        ftd::interpreter::EventName::TimeUpdate => "ontimeupdate".to_string(),
         ftd::interpreter::EventName::RivePlay(timeline) => format!("onriveplay[{}]", timeline),
         ftd::interpreter::EventName::RiveStateChange(state_change) => {
             format!("onrivestatechange[{}]", state_change)
@@ -150,6 +150,16 @@ impl ExpressionGenerator {
             return format!("[{}]", result.join(","));
         }
 
+        if let Some([condition, then, otherwise]) = self.if_arguments(node) {
+            // only the branch taken is evaluated, as in evalexpr
+            return format!(
+                "({} ? {} : {})",
+                self.to_string_(condition, false, arguments, extra_args),
+                self.to_string_(then, false, arguments, extra_args),
+                self.to_string_(otherwise, false, arguments, extra_args)
+            );
+        }
+
         if let Some(function_name) = self.function_name(node.operator()) {
             let mut result = vec![];
             if let Some(child) = node.children().first() {
@@ -251,6 +261,25 @@ impl ExpressionGenerator {
         }
     }
 
+    /// The condition and the branches of a call to the builtin `if(condition, a, b)`.
+    pub fn if_arguments<'a>(
+        &self,
+        node: &'a fastn_grammar::evalexpr::ExprNode,
+    ) -> Option<&'a [fastn_grammar::evalexpr::ExprNode]> {
+        if self.function_name(node.operator())?.ne("if") {
+            return None;
+        }
+        let mut child = node.children().first()?;
+        while self.is_root(child.operator()) && child.children().len() == 1 {
+            child = child.children().first()?;
+        }
+        if self.is_tuple(child.operator()) && child.children().len() == 3 {
+            Some(child.children())
+        } else {
+            None
+        }
+    }
+
     pub fn is_assignment(&self, operator: &fastn_grammar::evalexpr::Operator) -> bool {
         matches!(operator, fastn_grammar::evalexpr::Operator::Assign)
     }
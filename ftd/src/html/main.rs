@@ -58,6 +58,19 @@ impl HtmlUI {
             &node_data.bag,
         );
 
+        let nodes = std::iter::once(&node_data.node)
+            .chain(
+                node_data
+                    .dummy_nodes
+                    .value
+                    .values()
+                    .flatten()
+                    .map(|v| &v.main),
+            )
+            .chain(node_data.raw_nodes.values().map(|v| &v.node))
+            .collect_vec();
+        let pseudo_class_css = ftd::html::utils::get_pseudo_class_css_html(id, nodes.as_slice());
+        let motion_css = ftd::html::utils::get_motion_css_html(nodes.as_slice());
         let functions = ftd::html::FunctionGenerator::new(id).get_functions(&node_data)?;
         let (dependencies, var_dependencies) = ftd::html::dependencies::DependencyGenerator::new(
             id,
@@ -96,7 +109,12 @@ impl HtmlUI {
             immutable_variable,
             html_data: node_data.html_data.to_html_data(),
             js: ftd::html::utils::get_js_html(node_data.js.into_iter().collect_vec().as_slice()),
-            css: ftd::html::utils::get_css_html(node_data.css.into_iter().collect_vec().as_slice()),
+            css: format!(
+                "{}{}{}",
+                ftd::html::utils::get_css_html(node_data.css.into_iter().collect_vec().as_slice()),
+                pseudo_class_css,
+                motion_css
+            ),
             rive_data: ftd::html::utils::get_rive_data_html(
                 node_data.rive_data.as_slice(),
                 id,
@@ -402,17 +420,17 @@ impl<'a> HtmlGenerator<'a> {
     }
 
     pub fn class_to_html(&self, node: &ftd::node::Node) -> String {
-        if node.classes.is_empty() {
+        let mut classes = node.classes.clone();
+        if !node.pseudo_class_styles.is_empty() {
+            classes.push(ftd::html::utils::pseudo_class_name(
+                self.id.as_str(),
+                node.data_id.as_str(),
+            ));
+        }
+        if classes.is_empty() {
             return "".to_string();
         }
-        format!(
-            "class=\"{}\"",
-            node.classes
-                .iter()
-                .map(|k| k.to_string())
-                .collect::<Vec<String>>()
-                .join(" ")
-        )
+        format!("class=\"{}\"", classes.join(" "))
     }
 
     fn attrs_to_html(&mut self, node: &ftd::node::Node) -> String {
@@ -347,6 +347,13 @@ impl ftd::interpreter::Value {
             ftd::interpreter::Value::Integer { value } => Some(value.to_string()),
             ftd::interpreter::Value::Decimal { value } => Some(value.to_string()),
             ftd::interpreter::Value::Boolean { value } => Some(value.to_string()),
+            ftd::interpreter::Value::DateTime { value }
+            | ftd::interpreter::Value::Date { value } => Some(if string_needs_no_quotes {
+                value.to_string()
+            } else {
+                format!("\"{}\"", value)
+            }),
+            ftd::interpreter::Value::Duration { seconds } => Some(seconds.to_string()),
             ftd::interpreter::Value::List { data, .. } => {
                 let mut values = vec![];
                 for value in data {
@@ -364,6 +371,7 @@ impl ftd::interpreter::Value {
                 Some(format!("{:?}", values.join(" ")))
             }
             ftd::interpreter::Value::Record { fields, .. }
+            | ftd::interpreter::Value::Map { data: fields, .. }
                 if field
                     .as_ref()
                     .map(|v| fields.contains_key(v))
@@ -376,6 +384,22 @@ impl ftd::interpreter::Value {
                     string_needs_no_quotes,
                 )?
             }
+            // or-types defined in ftd documents keep their variant, `{"circle": {"radius": 2.0}}`
+            ftd::interpreter::Value::OrType {
+                value,
+                variant,
+                name,
+                ..
+            } if !name.starts_with("ftd#") => {
+                let value = value
+                    .to_html_string(doc, field, id, string_needs_no_quotes)?
+                    .unwrap_or_else(|| "null".to_string());
+                Some(format!(
+                    "{{\"{}\": {}}}",
+                    ftd::interpreter::utils::variant_short_name(variant),
+                    value
+                ))
+            }
             ftd::interpreter::Value::OrType {
                 value,
                 variant,
@@ -396,6 +420,19 @@ impl ftd::interpreter::Value {
                             Some(value)
                         }
                     }
+                    Some(value) if name.eq(ftd::interpreter::FTD_GRID_TEMPLATE) => {
+                        if let Ok(pattern) =
+                            ftd::executor::GridTemplate::set_pattern_from_variant_str(
+                                variant,
+                                doc.name,
+                                line_number,
+                            )
+                        {
+                            Some(format!("`{}`.format({})", pattern, value))
+                        } else {
+                            Some(value)
+                        }
+                    }
                     Some(value)
                         if name.eq(ftd::interpreter::FTD_RESIZING)
                             && variant.ne(ftd::interpreter::FTD_RESIZING_FIXED) =>
@@ -415,7 +452,8 @@ impl ftd::interpreter::Value {
                     None => None,
                 }
             }
-            ftd::interpreter::Value::Record { fields, .. } => {
+            ftd::interpreter::Value::Record { fields, .. }
+            | ftd::interpreter::Value::Map { data: fields, .. } => {
                 let mut values = vec![];
                 for (k, v) in fields {
                     let value = if let Some(v) =
@@ -892,6 +930,77 @@ pub fn get_css_html(external_css: &[String]) -> String {
     result
 }
 
+/// The class of an element with `color.hover`, `background.focus` etc, its css rules are for it.
+pub(crate) fn pseudo_class_name(id: &str, data_id: &str) -> String {
+    format!(
+        "ftd-state-{}",
+        full_data_id(id, data_id).replace(|c: char| !c.is_ascii_alphanumeric(), "-")
+    )
+}
+
+/// The css rules of `color.hover`, `background.focus` etc of the nodes and their children. These
+/// are `!important` to show over the inline style of the element.
+pub fn get_pseudo_class_css_html(id: &str, nodes: &[&ftd::node::Node]) -> String {
+    fn rules(id: &str, node: &ftd::node::Node, result: &mut Vec<String>) {
+        let class = pseudo_class_name(id, node.data_id.as_str());
+        for style in node.pseudo_class_styles.iter() {
+            result.push(format!(
+                ".{}:{} {{ {}: {} !important; }}",
+                class, style.pseudo_class, style.property, style.light
+            ));
+            if let Some(ref dark) = style.dark {
+                result.push(format!(
+                    "body.fpm-dark .{}:{} {{ {}: {} !important; }}",
+                    class, style.pseudo_class, style.property, dark
+                ));
+            }
+        }
+        for child in node.children.iter() {
+            rules(id, child, result);
+        }
+    }
+
+    let mut result = vec![];
+    for node in nodes {
+        rules(id, node, &mut result);
+    }
+    if result.is_empty() {
+        return "".to_string();
+    }
+    format!("<style>{}</style>", result.join("\n"))
+}
+
+/// The `@keyframes` rules of the `animation` of the nodes and their children, and the rule that
+/// stops the transitions and animations of `ftd-motion` elements for `prefers-reduced-motion`.
+pub fn get_motion_css_html(nodes: &[&ftd::node::Node]) -> String {
+    fn rules(node: &ftd::node::Node, keyframes: &mut Vec<String>, has_motion: &mut bool) {
+        *has_motion |= node.classes.iter().any(|class| class.eq("ftd-motion"));
+        for rule in node.keyframes.iter() {
+            if !keyframes.contains(rule) {
+                keyframes.push(rule.to_string());
+            }
+        }
+        for child in node.children.iter() {
+            rules(child, keyframes, has_motion);
+        }
+    }
+
+    let mut keyframes = vec![];
+    let mut has_motion = false;
+    for node in nodes {
+        rules(node, &mut keyframes, &mut has_motion);
+    }
+    if !has_motion {
+        return "".to_string();
+    }
+    keyframes.push(
+        "@media (prefers-reduced-motion: reduce) { .ftd-motion { animation: none !important; \
+         transition: none !important; } }"
+            .to_string(),
+    );
+    format!("<style>{}</style>", keyframes.join("\n"))
+}
+
 pub fn get_meta_data(html_data: &ftd::html::HTMLData) -> String {
     let mut result = vec![];
     if let Some(ref title) = html_data.og_title {
@@ -3,11 +3,15 @@ pub static FTD_HIGHLIGHTER: once_cell::sync::Lazy<regex::Regex> =
 
 pub const FTD_BREAKPOINT_WIDTH: &str = "ftd#breakpoint-width";
 pub const FTD_BREAKPOINT_WIDTH_DATA: &str = "ftd#breakpoint-width-data";
+pub const FTD_BREAKPOINT: &str = "ftd#breakpoint";
+pub const FTD_BREAKPOINTS: &str = "ftd#breakpoints";
 
 pub const FTD_DEVICE: &str = "ftd#device";
 pub const FTD_DEVICE_DATA: &str = "ftd#device-data";
 pub const FTD_DEVICE_DATA_MOBILE: &str = "ftd#device-data.mobile";
+pub const FTD_DEVICE_DATA_TABLET: &str = "ftd#device-data.tablet";
 pub const FTD_DEVICE_DATA_DESKTOP: &str = "ftd#device-data.desktop";
+pub const FTD_DEVICE_DATA_WIDE: &str = "ftd#device-data.wide";
 
 pub const FTD_LENGTH: &str = "ftd#length";
 pub const FTD_LENGTH_PX: &str = "ftd#length.px";
@@ -135,6 +139,22 @@ pub const FTD_TEXT_ALIGN_JUSTIFY: &str = "ftd#text-align.justify";
 pub const FTD_SHADOW: &str = "ftd#shadow";
 pub const FTD_SHADOW_COLOR: &str = "ftd#shadow.color";
 
+// The states of `color.hover`, `background.focus` etc, the order is the order of their css rules
+// so that a pressed element shows `.active` over `.hover`
+pub const FTD_ELEMENT_STATES: [&str; 3] = ["hover", "focus", "active"];
+
+pub const FTD_TRANSFORM: &str = "ftd#transform";
+pub const FTD_TRANSITION: &str = "ftd#transition";
+pub const FTD_KEYFRAME: &str = "ftd#keyframe";
+pub const FTD_ANIMATION: &str = "ftd#animation";
+
+pub const FTD_EASING: &str = "ftd#easing";
+pub const FTD_EASING_LINEAR: &str = "ftd#easing.linear";
+pub const FTD_EASING_EASE: &str = "ftd#easing.ease";
+pub const FTD_EASING_EASE_IN: &str = "ftd#easing.ease-in";
+pub const FTD_EASING_EASE_OUT: &str = "ftd#easing.ease-out";
+pub const FTD_EASING_EASE_IN_OUT: &str = "ftd#easing.ease-in-out";
+
 // FTD overflow(todo docs link)
 pub const FTD_OVERFLOW: &str = "ftd#overflow";
 pub const FTD_OVERFLOW_SCROLL: &str = "ftd#overflow.scroll";
@@ -221,6 +241,21 @@ pub const FTD_TEXT_INPUT_TYPE_MONTH: &str = "ftd#text-input-type.month";
 pub const FTD_TEXT_INPUT_TYPE_WEEK: &str = "ftd#text-input-type.week";
 pub const FTD_TEXT_INPUT_TYPE_COLOR: &str = "ftd#text-input-type.color";
 pub const FTD_TEXT_INPUT_TYPE_FILE: &str = "ftd#text-input-type.file";
+pub const FTD_TEXT_INPUT_TYPE_NUMBER: &str = "ftd#text-input-type.number";
+pub const FTD_TEXT_INPUT_TYPE_RANGE: &str = "ftd#text-input-type.range";
+pub const FTD_TEXT_INPUT_TYPE_SUBMIT: &str = "ftd#text-input-type.submit";
+
+pub const FTD_OPTION: &str = "ftd#option";
+
+pub const FTD_MEDIA_SOURCE: &str = "ftd#media-source";
+pub const FTD_TRACK: &str = "ftd#track";
+
+pub const FTD_TABLE_COLUMN: &str = "ftd#table-column";
+pub const FTD_COLUMN_TYPE: &str = "ftd#column-type";
+pub const FTD_COLUMN_TYPE_TEXT: &str = "ftd#column-type.text";
+pub const FTD_COLUMN_TYPE_NUMBER: &str = "ftd#column-type.number";
+pub const FTD_COLUMN_TYPE_DATE: &str = "ftd#column-type.date";
+pub const FTD_COLUMN_TYPE_BOOLEAN: &str = "ftd#column-type.boolean";
 
 pub const FTD_REGION: &str = "ftd#region";
 pub const FTD_REGION_H1: &str = "ftd#region.h1";
@@ -230,11 +265,41 @@ pub const FTD_REGION_H4: &str = "ftd#region.h4";
 pub const FTD_REGION_H5: &str = "ftd#region.h5";
 pub const FTD_REGION_H6: &str = "ftd#region.h6";
 
+pub const FTD_LANDMARK: &str = "ftd#landmark";
+pub const FTD_LANDMARK_BANNER: &str = "ftd#landmark.banner";
+pub const FTD_LANDMARK_NAVIGATION: &str = "ftd#landmark.navigation";
+pub const FTD_LANDMARK_MAIN: &str = "ftd#landmark.main";
+pub const FTD_LANDMARK_COMPLEMENTARY: &str = "ftd#landmark.complementary";
+pub const FTD_LANDMARK_CONTENT_INFO: &str = "ftd#landmark.content-info";
+pub const FTD_LANDMARK_SEARCH: &str = "ftd#landmark.search";
+pub const FTD_LANDMARK_FORM: &str = "ftd#landmark.form";
+pub const FTD_LANDMARK_REGION: &str = "ftd#landmark.region";
+
+pub const FTD_ARIA_LIVE: &str = "ftd#aria-live";
+pub const FTD_ARIA_LIVE_POLITE: &str = "ftd#aria-live.polite";
+pub const FTD_ARIA_LIVE_ASSERTIVE: &str = "ftd#aria-live.assertive";
+pub const FTD_ARIA_LIVE_OFF: &str = "ftd#aria-live.off";
+
+pub const FTD_PLACEMENT: &str = "ftd#placement";
+pub const FTD_PLACEMENT_TOP: &str = "ftd#placement.top";
+pub const FTD_PLACEMENT_BOTTOM: &str = "ftd#placement.bottom";
+pub const FTD_PLACEMENT_LEFT: &str = "ftd#placement.left";
+pub const FTD_PLACEMENT_RIGHT: &str = "ftd#placement.right";
+
 pub const FTD_DISPLAY: &str = "ftd#display";
 pub const FTD_DISPLAY_BLOCK: &str = "ftd#display.block";
 pub const FTD_DISPLAY_INLINE: &str = "ftd#display.inline";
 pub const FTD_DISPLAY_INLINE_BLOCK: &str = "ftd#display.inline-block";
 
+pub const FTD_GRID_TEMPLATE: &str = "ftd#grid-template";
+pub const FTD_GRID_TEMPLATE_COUNT: &str = "ftd#grid-template.count";
+pub const FTD_GRID_TEMPLATE_FILL: &str = "ftd#grid-template.fill";
+pub const FTD_GRID_TEMPLATE_FIT: &str = "ftd#grid-template.fit";
+pub const FTD_GRID_TEMPLATE_TRACKS: &str = "ftd#grid-template.tracks";
+pub const FTD_GRID_TEMPLATE_RESPONSIVE: &str = "ftd#grid-template.responsive";
+pub const FTD_RESPONSIVE_GRID_TEMPLATE: &str = "ftd#responsive-grid-template";
+pub const FTD_RESPONSIVE_GRID_TEMPLATE_DESKTOP: &str = "ftd#responsive-grid-template.desktop";
+
 pub const FTD_WHITESPACE: &str = "ftd#white-space";
 pub const FTD_WHITESPACE_NORMAL: &str = "ftd#white-space.normal";
 pub const FTD_WHITESPACE_NOWRAP: &str = "ftd#white-space.nowrap";
@@ -257,8 +322,11 @@ pub const FTD_LOADING_LAZY: &str = "ftd#loading.lazy";
 
 pub const FTD_SPECIAL_VALUE: &str = "$VALUE";
 pub const FTD_SPECIAL_CHECKED: &str = "$CHECKED";
+pub const FTD_SPECIAL_FORM: &str = "$FORM";
+pub const FTD_SPECIAL_TIME: &str = "$TIME";
 pub const FTD_INHERITED: &str = "inherited";
 pub const FTD_LOOP_COUNTER: &str = "LOOP.COUNTER";
+pub const FTD_LOOP_KEY: &str = "LOOP.KEY";
 pub const FTD_DEFAULT_TYPES: &str = "default-types";
 pub const FTD_DEFAULT_COLORS: &str = "default-colors";
 pub const FTD_NONE: &str = "none";
@@ -20,6 +20,12 @@
 ///
 /// - `instructions`: a `Vec` of `ftd::interpreter::Component`s that represents the instructions
 /// that the interpreter has processed.
+///
+/// - `errors`: the errors the interpreter has recovered from, reported together once there is
+/// nothing left to process.
+///
+/// - `failed`: the things (`<document>#<name>`) that failed to be processed, along with the
+/// diagnostics of the failure, so the sections using them fail the same way.
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct InterpreterState {
     pub id: String,
@@ -30,6 +36,8 @@ pub struct InterpreterState {
     pub pending_imports: PendingImports,
     pub parsed_libs: ftd::Map<ParsedDocument>,
     pub instructions: Vec<ftd::interpreter::Component>,
+    pub errors: Vec<ftd::diagnostic::Diagnostic>,
+    pub failed: ftd::Map<Vec<ftd::diagnostic::Diagnostic>>,
 }
 
 #[derive(Debug, Clone, Default, PartialEq)]
@@ -112,6 +120,7 @@ impl InterpreterState {
                     message: format!("Cannot find this document: `{}`", doc_name),
                     doc_id: doc_name.to_string(),
                     line_number,
+                    span: None,
                 })?;
         Ok(ftd::interpreter::TDoc::new(
             &parsed_document.name,
@@ -137,277 +146,419 @@ impl InterpreterState {
     #[tracing::instrument(name = "continue_processing", skip_all)]
     pub fn continue_processing(mut self) -> ftd::interpreter::Result<Interpreter> {
         while let Some((doc_name, number_of_scan, ast, exports)) = self.get_next_ast() {
-            if let Some(interpreter) = self.resolve_pending_imports::<ftd::interpreter::Thing>()? {
-                match interpreter {
-                    ftd::interpreter::StateWithThing::State(s) => {
-                        return Ok(s.into_interpreter(self))
-                    }
-                    ftd::interpreter::StateWithThing::Thing(t) => {
-                        self.bag.insert(t.name(), t);
-                    }
-                    ftd::interpreter::StateWithThing::Continue => continue,
+            match self.resolve_pending_imports::<ftd::interpreter::Thing>() {
+                Ok(Some(ftd::interpreter::StateWithThing::State(s))) => {
+                    return Ok(s.into_interpreter(self))
+                }
+                Ok(Some(ftd::interpreter::StateWithThing::Thing(t))) => {
+                    self.bag.insert(t.name(), t);
+                }
+                Ok(Some(ftd::interpreter::StateWithThing::Continue)) => continue,
+                Ok(None) => {}
+                Err(e) => {
+                    let e = self.with_span(e);
+                    self.skip_pending_import(e)?;
+                    continue;
+                }
+            }
+            match self.process_ast(doc_name, number_of_scan, ast, exports) {
+                Ok(Some(s)) => return Ok(s.into_interpreter(self)),
+                Ok(None) => {}
+                Err(e) => {
+                    let e = self.with_span(e);
+                    self.skip_section(e)?;
                 }
             }
+        }
 
-            self.increase_scan_count();
-            let parsed_document = self.parsed_libs.get(doc_name.as_str()).unwrap();
-            let name = parsed_document.name.to_string();
-            let aliases = parsed_document.doc_aliases.clone();
+        if self.to_process.stack.is_empty() {
+            if !self.errors.is_empty() {
+                return Err(ftd::interpreter::Error::Diagnostics {
+                    diagnostics: self.errors,
+                });
+            }
+            let aliases = match self.parsed_libs.get(self.id.as_str()) {
+                Some(parsed_document) => parsed_document.doc_aliases.clone(),
+                None => {
+                    return ftd::interpreter::utils::e2(
+                        format!("`{}` is not parsed", self.id),
+                        self.id.as_str(),
+                        0,
+                    )
+                }
+            };
+            let document = Document {
+                data: self.bag,
+                aliases,
+                tree: self.instructions,
+                name: self.id,
+                js: self.js,
+                css: self.css,
+            };
 
-            let ast_full_name = ftd::interpreter::utils::resolve_name(
-                ast.name().as_str(),
-                &parsed_document.name,
-                &parsed_document.doc_aliases,
-            );
-            let is_in_bag = self.bag.contains_key(&ast_full_name);
+            Ok(Interpreter::Done { document })
+        } else {
+            self.continue_processing()
+        }
+    }
 
-            if is_in_bag {
-                let line_number = self.bag.get(&ast_full_name).unwrap().line_number();
-                ftd::interpreter::utils::insert_export_thing(
-                    exports.as_slice(),
-                    ast_full_name.as_str(),
-                    &mut self.bag,
+    /// Processes the next `ast` on the stack. Returns the state to hand over to the caller if
+    /// the interpreter is stuck (on an import, a processor etc.).
+    fn process_ast(
+        &mut self,
+        doc_name: String,
+        number_of_scan: usize,
+        ast: ftd::ast::AST,
+        exports: Vec<String>,
+    ) -> ftd::interpreter::Result<Option<InterpreterWithoutState>> {
+        self.increase_scan_count();
+        let parsed_document = match self.parsed_libs.get(doc_name.as_str()) {
+            Some(parsed_document) => parsed_document,
+            None => {
+                return ftd::interpreter::utils::e2(
+                    format!("`{}` is not parsed", doc_name),
                     doc_name.as_str(),
-                    line_number,
-                );
+                    ast.line_number(),
+                )
             }
-            let state = &mut self;
-
-            let mut doc = ftd::interpreter::TDoc::new_state(&name, &aliases, state);
-            if ast.is_record() {
-                if !is_in_bag {
-                    if number_of_scan.eq(&1) {
-                        ftd::interpreter::Record::scan_ast(ast, &mut doc)?;
-                        continue;
-                    } else {
-                        match ftd::interpreter::Record::from_ast(ast, &mut doc)? {
-                            ftd::interpreter::StateWithThing::State(s) => {
-                                return Ok(s.into_interpreter(self))
-                            }
-                            ftd::interpreter::StateWithThing::Thing(record) => {
-                                ftd::interpreter::utils::insert_export_thing(
-                                    exports.as_slice(),
-                                    record.name.as_str(),
-                                    &mut self.bag,
-                                    doc_name.as_str(),
-                                    record.line_number,
-                                );
-                                self.bag.insert(
-                                    record.name.to_string(),
-                                    ftd::interpreter::Thing::Record(record),
-                                );
-                            }
-                            ftd::interpreter::StateWithThing::Continue => continue,
-                        }
-                    }
-                }
-            } else if ast.is_or_type() {
-                if !is_in_bag {
-                    if number_of_scan.eq(&1) {
-                        ftd::interpreter::OrType::scan_ast(ast, &mut doc)?;
-                        continue;
-                    } else {
-                        match ftd::interpreter::OrType::from_ast(ast, &mut doc)? {
-                            ftd::interpreter::StateWithThing::State(s) => {
-                                return Ok(s.into_interpreter(self))
-                            }
-                            ftd::interpreter::StateWithThing::Thing(or_type) => {
-                                ftd::interpreter::utils::insert_export_thing(
-                                    exports.as_slice(),
-                                    or_type.name.as_str(),
-                                    &mut self.bag,
-                                    doc_name.as_str(),
-                                    or_type.line_number,
-                                );
-                                self.bag.insert(
-                                    or_type.name.to_string(),
-                                    ftd::interpreter::Thing::OrType(or_type),
-                                );
-                            }
-                            ftd::interpreter::StateWithThing::Continue => continue,
+        };
+        let name = parsed_document.name.to_string();
+        let aliases = parsed_document.doc_aliases.clone();
+
+        let ast_full_name = ftd::interpreter::utils::resolve_name(
+            ast.name().as_str(),
+            &parsed_document.name,
+            &parsed_document.doc_aliases,
+        );
+        let is_in_bag = self.bag.contains_key(&ast_full_name);
+
+        if is_in_bag {
+            let line_number = self.bag.get(&ast_full_name).unwrap().line_number();
+            ftd::interpreter::utils::insert_export_thing(
+                exports.as_slice(),
+                ast_full_name.as_str(),
+                &mut self.bag,
+                doc_name.as_str(),
+                line_number,
+            );
+        }
+        let state = &mut *self;
+
+        let mut doc = ftd::interpreter::TDoc::new_state(&name, &aliases, state);
+        if ast.is_record() {
+            if !is_in_bag {
+                if number_of_scan.eq(&1) {
+                    ftd::interpreter::Record::scan_ast(ast, &mut doc)?;
+                    return Ok(None);
+                } else {
+                    match ftd::interpreter::Record::from_ast(ast, &mut doc)? {
+                        ftd::interpreter::StateWithThing::State(s) => return Ok(Some(s)),
+                        ftd::interpreter::StateWithThing::Thing(record) => {
+                            ftd::interpreter::utils::insert_export_thing(
+                                exports.as_slice(),
+                                record.name.as_str(),
+                                &mut self.bag,
+                                doc_name.as_str(),
+                                record.line_number,
+                            );
+                            self.bag.insert(
+                                record.name.to_string(),
+                                ftd::interpreter::Thing::Record(record),
+                            );
                         }
+                        ftd::interpreter::StateWithThing::Continue => return Ok(None),
                     }
                 }
-            } else if ast.is_function() {
-                if !is_in_bag {
-                    if number_of_scan.eq(&1) {
-                        ftd::interpreter::Function::scan_ast(ast, &mut doc)?;
-                        continue;
-                    } else {
-                        match ftd::interpreter::Function::from_ast(ast, &mut doc)? {
-                            ftd::interpreter::StateWithThing::State(s) => {
-                                return Ok(s.into_interpreter(self))
-                            }
-                            ftd::interpreter::StateWithThing::Thing(function) => {
-                                if let Some(ref js) = function.js {
-                                    let js = js
-                                        .to_owned()
-                                        .resolve(&doc, function.line_number)?
-                                        .string(doc.name, function.line_number)?;
-                                    self.js.insert(js);
-                                }
-                                ftd::interpreter::utils::insert_export_thing(
-                                    exports.as_slice(),
-                                    function.name.as_str(),
-                                    &mut self.bag,
-                                    doc_name.as_str(),
-                                    function.line_number,
-                                );
-                                self.bag.insert(
-                                    function.name.to_string(),
-                                    ftd::interpreter::Thing::Function(function),
-                                );
-                            }
-                            ftd::interpreter::StateWithThing::Continue => continue,
+            }
+        } else if ast.is_or_type() {
+            if !is_in_bag {
+                if number_of_scan.eq(&1) {
+                    ftd::interpreter::OrType::scan_ast(ast, &mut doc)?;
+                    return Ok(None);
+                } else {
+                    match ftd::interpreter::OrType::from_ast(ast, &mut doc)? {
+                        ftd::interpreter::StateWithThing::State(s) => return Ok(Some(s)),
+                        ftd::interpreter::StateWithThing::Thing(or_type) => {
+                            ftd::interpreter::utils::insert_export_thing(
+                                exports.as_slice(),
+                                or_type.name.as_str(),
+                                &mut self.bag,
+                                doc_name.as_str(),
+                                or_type.line_number,
+                            );
+                            self.bag.insert(
+                                or_type.name.to_string(),
+                                ftd::interpreter::Thing::OrType(or_type),
+                            );
                         }
+                        ftd::interpreter::StateWithThing::Continue => return Ok(None),
                     }
                 }
-            } else if ast.is_variable_definition() {
-                if !is_in_bag {
-                    if number_of_scan.eq(&1) {
-                        ftd::interpreter::Variable::scan_ast(ast, &mut doc)?;
-                        continue;
-                    } else {
-                        match ftd::interpreter::Variable::from_ast(ast, &mut doc, number_of_scan)? {
-                            ftd::interpreter::StateWithThing::State(s) => {
-                                return Ok(s.into_interpreter(self))
-                            }
-                            ftd::interpreter::StateWithThing::Thing(variable) => {
-                                ftd::interpreter::utils::insert_export_thing(
-                                    exports.as_slice(),
-                                    variable.name.as_str(),
-                                    &mut self.bag,
-                                    doc_name.as_str(),
-                                    variable.line_number,
-                                );
-                                self.bag.insert(
-                                    variable.name.to_string(),
-                                    ftd::interpreter::Thing::Variable(variable),
-                                );
+            }
+        } else if ast.is_function() {
+            if !is_in_bag {
+                if number_of_scan.eq(&1) {
+                    ftd::interpreter::Function::scan_ast(ast, &mut doc)?;
+                    return Ok(None);
+                } else {
+                    match ftd::interpreter::Function::from_ast(ast, &mut doc)? {
+                        ftd::interpreter::StateWithThing::State(s) => return Ok(Some(s)),
+                        ftd::interpreter::StateWithThing::Thing(function) => {
+                            if let Some(ref js) = function.js {
+                                let js = js
+                                    .to_owned()
+                                    .resolve(&doc, function.line_number)?
+                                    .string(doc.name, function.line_number)?;
+                                self.js.insert(js);
                             }
-                            ftd::interpreter::StateWithThing::Continue => continue,
+                            ftd::interpreter::utils::insert_export_thing(
+                                exports.as_slice(),
+                                function.name.as_str(),
+                                &mut self.bag,
+                                doc_name.as_str(),
+                                function.line_number,
+                            );
+                            self.bag.insert(
+                                function.name.to_string(),
+                                ftd::interpreter::Thing::Function(function),
+                            );
                         }
+                        ftd::interpreter::StateWithThing::Continue => return Ok(None),
                     }
                 }
-            } else if ast.is_variable_invocation() {
+            }
+        } else if ast.is_variable_definition() {
+            if !is_in_bag {
                 if number_of_scan.eq(&1) {
-                    ftd::interpreter::Variable::scan_update_from_ast(ast, &mut doc)?;
-                    continue;
+                    ftd::interpreter::Variable::scan_ast(ast, &mut doc)?;
+                    return Ok(None);
                 } else {
-                    match ftd::interpreter::Variable::update_from_ast(ast, &mut doc)? {
-                        ftd::interpreter::StateWithThing::State(s) => {
-                            return Ok(s.into_interpreter(self))
-                        }
+                    match ftd::interpreter::Variable::from_ast(ast, &mut doc, number_of_scan)? {
+                        ftd::interpreter::StateWithThing::State(s) => return Ok(Some(s)),
                         ftd::interpreter::StateWithThing::Thing(variable) => {
+                            ftd::interpreter::utils::insert_export_thing(
+                                exports.as_slice(),
+                                variable.name.as_str(),
+                                &mut self.bag,
+                                doc_name.as_str(),
+                                variable.line_number,
+                            );
                             self.bag.insert(
                                 variable.name.to_string(),
                                 ftd::interpreter::Thing::Variable(variable),
                             );
                         }
-                        ftd::interpreter::StateWithThing::Continue => continue,
+                        ftd::interpreter::StateWithThing::Continue => return Ok(None),
                     }
                 }
-            } else if ast.is_component_definition() {
-                if !is_in_bag {
-                    if number_of_scan.eq(&1) {
-                        ftd::interpreter::ComponentDefinition::scan_ast(ast, &mut doc)?;
-                        continue;
-                    } else {
-                        match ftd::interpreter::ComponentDefinition::from_ast(ast, &mut doc)? {
-                            ftd::interpreter::StateWithThing::State(s) => {
-                                return Ok(s.into_interpreter(self))
-                            }
-                            ftd::interpreter::StateWithThing::Thing(component) => {
-                                if let Some(ref css) = component.css {
-                                    let css = css
-                                        .to_owned()
-                                        .resolve(&doc, component.line_number)?
-                                        .string(doc.name, component.line_number)?;
-                                    self.css.insert(css);
-                                }
-
-                                ftd::interpreter::utils::insert_export_thing(
-                                    exports.as_slice(),
-                                    component.name.as_str(),
-                                    &mut self.bag,
-                                    doc_name.as_str(),
-                                    component.line_number,
-                                );
-
-                                self.bag.insert(
-                                    component.name.to_string(),
-                                    ftd::interpreter::Thing::Component(component),
-                                );
-                            }
-                            ftd::interpreter::StateWithThing::Continue => continue,
-                        }
+            }
+        } else if ast.is_variable_invocation() {
+            if number_of_scan.eq(&1) {
+                ftd::interpreter::Variable::scan_update_from_ast(ast, &mut doc)?;
+                return Ok(None);
+            } else {
+                match ftd::interpreter::Variable::update_from_ast(ast, &mut doc)? {
+                    ftd::interpreter::StateWithThing::State(s) => return Ok(Some(s)),
+                    ftd::interpreter::StateWithThing::Thing(variable) => {
+                        self.bag.insert(
+                            variable.name.to_string(),
+                            ftd::interpreter::Thing::Variable(variable),
+                        );
                     }
+                    ftd::interpreter::StateWithThing::Continue => return Ok(None),
                 }
-            } else if ast.is_web_component_definition() {
-                if !is_in_bag {
-                    if number_of_scan.eq(&1) {
-                        ftd::interpreter::WebComponentDefinition::scan_ast(ast, &mut doc)?;
-                        continue;
-                    } else {
-                        match ftd::interpreter::WebComponentDefinition::from_ast(ast, &mut doc)? {
-                            ftd::interpreter::StateWithThing::State(s) => {
-                                return Ok(s.into_interpreter(self))
-                            }
-                            ftd::interpreter::StateWithThing::Thing(web_component) => {
-                                ftd::interpreter::utils::insert_export_thing(
-                                    exports.as_slice(),
-                                    web_component.name.as_str(),
-                                    &mut self.bag,
-                                    doc_name.as_str(),
-                                    web_component.line_number,
-                                );
-                                self.bag.insert(
-                                    web_component.name.to_string(),
-                                    ftd::interpreter::Thing::WebComponent(web_component),
-                                );
+            }
+        } else if ast.is_component_definition() {
+            if !is_in_bag {
+                if number_of_scan.eq(&1) {
+                    ftd::interpreter::ComponentDefinition::scan_ast(ast, &mut doc)?;
+                    return Ok(None);
+                } else {
+                    match ftd::interpreter::ComponentDefinition::from_ast(ast, &mut doc)? {
+                        ftd::interpreter::StateWithThing::State(s) => return Ok(Some(s)),
+                        ftd::interpreter::StateWithThing::Thing(component) => {
+                            if let Some(ref css) = component.css {
+                                let css = css
+                                    .to_owned()
+                                    .resolve(&doc, component.line_number)?
+                                    .string(doc.name, component.line_number)?;
+                                self.css.insert(css);
                             }
-                            ftd::interpreter::StateWithThing::Continue => continue,
+
+                            ftd::interpreter::utils::insert_export_thing(
+                                exports.as_slice(),
+                                component.name.as_str(),
+                                &mut self.bag,
+                                doc_name.as_str(),
+                                component.line_number,
+                            );
+
+                            self.bag.insert(
+                                component.name.to_string(),
+                                ftd::interpreter::Thing::Component(component),
+                            );
                         }
+                        ftd::interpreter::StateWithThing::Continue => return Ok(None),
                     }
                 }
-            } else if ast.is_component() {
+            }
+        } else if ast.is_web_component_definition() {
+            if !is_in_bag {
                 if number_of_scan.eq(&1) {
-                    ftd::interpreter::Component::scan_ast(ast, &mut doc)?;
-                    continue;
+                    ftd::interpreter::WebComponentDefinition::scan_ast(ast, &mut doc)?;
+                    return Ok(None);
                 } else {
-                    match ftd::interpreter::Component::from_ast(ast, &mut doc)? {
-                        ftd::interpreter::StateWithThing::State(s) => {
-                            return Ok(s.into_interpreter(self))
-                        }
-                        ftd::interpreter::StateWithThing::Thing(component) => {
-                            self.instructions.push(component);
+                    match ftd::interpreter::WebComponentDefinition::from_ast(ast, &mut doc)? {
+                        ftd::interpreter::StateWithThing::State(s) => return Ok(Some(s)),
+                        ftd::interpreter::StateWithThing::Thing(web_component) => {
+                            ftd::interpreter::utils::insert_export_thing(
+                                exports.as_slice(),
+                                web_component.name.as_str(),
+                                &mut self.bag,
+                                doc_name.as_str(),
+                                web_component.line_number,
+                            );
+                            self.bag.insert(
+                                web_component.name.to_string(),
+                                ftd::interpreter::Thing::WebComponent(web_component),
+                            );
                         }
-                        ftd::interpreter::StateWithThing::Continue => continue,
+                        ftd::interpreter::StateWithThing::Continue => return Ok(None),
                     }
                 }
             }
-            self.remove_last();
+        } else if ast.is_component() {
+            if number_of_scan.eq(&1) {
+                ftd::interpreter::Component::scan_ast(ast, &mut doc)?;
+                return Ok(None);
+            } else {
+                match ftd::interpreter::Component::from_ast(ast, &mut doc)? {
+                    ftd::interpreter::StateWithThing::State(s) => return Ok(Some(s)),
+                    ftd::interpreter::StateWithThing::Thing(component) => {
+                        self.instructions.push(component);
+                    }
+                    ftd::interpreter::StateWithThing::Continue => return Ok(None),
+                }
+            }
         }
+        self.remove_last();
+        Ok(None)
+    }
 
-        if self.to_process.stack.is_empty() {
-            let document = Document {
-                data: self.bag,
-                aliases: self
-                    .parsed_libs
-                    .get(self.id.as_str())
-                    .unwrap()
-                    .doc_aliases
-                    .clone(),
-                tree: self.instructions,
-                name: self.id,
-                js: self.js,
-                css: self.css,
-            };
+    /// Sets the span of `error` from the spans the parser recorded for the document and line
+    /// it is on.
+    fn with_span(&self, error: ftd::interpreter::Error) -> ftd::interpreter::Error {
+        error.or_span(|doc_id, line_number| {
+            self.parsed_libs
+                .get(doc_id)?
+                .spans
+                .get(&line_number)
+                .copied()
+        })
+    }
 
-            Ok(Interpreter::Done { document })
+    /// The interpreter recovers from errors while there are sections of the main document left
+    /// to process. Errors outside the main document can not be recovered from.
+    fn is_recoverable(&self) -> bool {
+        self.to_process
+            .stack
+            .first()
+            .map(|(doc_name, asts)| doc_name.eq(&self.id) && !asts.is_empty())
+            .unwrap_or(false)
+    }
+
+    /// Records the diagnostics of `error`, each only once, and returns them.
+    fn record_error(&mut self, error: ftd::interpreter::Error) -> Vec<ftd::diagnostic::Diagnostic> {
+        let diagnostics = error.to_diagnostics();
+        for diagnostic in diagnostics.iter() {
+            if !self.errors.contains(diagnostic) {
+                self.errors.push(diagnostic.clone());
+            }
+        }
+        diagnostics
+    }
+
+    /// Records `error` and skips the section it happened in, so the rest of the document still
+    /// gets interpreted and all independent errors are reported together.
+    ///
+    /// The section is the next one on the stack: a section of the main document (a component,
+    /// a definition, an update of a variable etc.), or a definition that was pulled in for one.
+    /// A definition that fails is marked as failed, so every section using it fails with the
+    /// same error (which is only recorded once) instead of processing it again.
+    fn skip_section(&mut self, error: ftd::interpreter::Error) -> ftd::interpreter::Result<()> {
+        if !self.is_recoverable() {
+            return Err(error);
+        }
+        let diagnostics = self.record_error(error);
+
+        let (doc_name, ast) = match self.to_process.stack.last() {
+            Some((doc_name, asts)) => match asts.first() {
+                Some(item) => (doc_name.to_string(), item.ast.clone()),
+                None => return Ok(()),
+            },
+            None => return Ok(()),
+        };
+        let thing_name = match self.parsed_libs.get(doc_name.as_str()) {
+            Some(document) => {
+                let ast_full_name = ftd::interpreter::utils::resolve_name(
+                    ast.name().as_str(),
+                    document.name.as_str(),
+                    &document.doc_aliases,
+                );
+                let (doc_name, thing_name, _remaining) =
+                    ftd::interpreter::utils::get_doc_name_and_thing_name_and_remaining(
+                        ast_full_name.as_str(),
+                        doc_name.as_str(),
+                        ast.line_number(),
+                    );
+                format!("{}#{}", doc_name, thing_name)
+            }
+            None => return Ok(()),
+        };
+
+        if self.to_process.stack.len() > 1 {
+            // the asts of a definition pulled in for a section, the rest of them are the same
+            // definition
+            self.to_process.stack.pop();
+            self.to_process
+                .contains
+                .remove(&(doc_name, thing_name.to_string()));
+            self.failed.insert(thing_name, diagnostics);
         } else {
-            self.continue_processing()
+            if ast.is_definition() {
+                self.failed.insert(thing_name, diagnostics);
+            }
+            self.remove_last();
+        }
+        Ok(())
+    }
+
+    /// Records `error`, raised while resolving the import on top of the pending imports, and
+    /// marks the imported thing as failed, so the section that needs it fails in turn and gets
+    /// skipped.
+    fn skip_pending_import(
+        &mut self,
+        error: ftd::interpreter::Error,
+    ) -> ftd::interpreter::Result<()> {
+        if !self.is_recoverable() {
+            return Err(error);
+        }
+        let diagnostics = self.record_error(error);
+
+        if let Some(item) = self.pending_imports.stack.pop() {
+            let (doc_name, thing_name, _remaining) =
+                ftd::interpreter::utils::get_doc_name_and_thing_name_and_remaining(
+                    item.thing_name.as_str(),
+                    item.module.as_str(),
+                    item.line_number,
+                );
+            let thing_name = format!("{}#{}", doc_name, thing_name);
+            self.pending_imports
+                .contains
+                .remove(&(doc_name, thing_name.to_string()));
+            self.failed.insert(thing_name, diagnostics);
         }
+        Ok(())
     }
 
     /// Returns (doc_name, number_of_scan, last_ast)
@@ -861,6 +1012,11 @@ pub struct ParsedDocument {
     pub exposings: ftd::Map<String>,
     pub foreign_variable: Vec<String>,
     pub foreign_function: Vec<String>,
+    pub private_things: Vec<String>,
+    pub warnings: Vec<ftd::diagnostic::Diagnostic>,
+    /// the span the parser recorded for each line with a section or a header on it
+    #[serde(default)]
+    pub spans: std::collections::BTreeMap<usize, ftd::diagnostic::Span>,
 }
 
 #[derive(Debug, Clone, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -880,10 +1036,21 @@ impl ParsedDocument {
         source: &str,
         line_number: usize,
     ) -> ftd::interpreter::Result<ParsedDocument> {
-        let ast = ftd::ast::AST::from_sections(
-            ftd::p1::parse_with_line_number(source, id, line_number)?.as_slice(),
-            id,
-        )?;
+        let (sections, p1_errors) = ftd::p1::parse_with_errors(source, id, line_number);
+        let (ast, ast_errors) = ftd::ast::AST::from_sections_with_errors(sections.as_slice(), id);
+        if let Some(e) = ftd::interpreter::Error::from_errors(
+            p1_errors
+                .into_iter()
+                .map(ftd::interpreter::Error::P1Error)
+                .chain(
+                    ast_errors
+                        .into_iter()
+                        .map(ftd::interpreter::Error::ASTError),
+                )
+                .collect(),
+        ) {
+            return Err(e);
+        }
         let (doc_aliases, re_exports, exposings) = {
             let mut doc_aliases = ftd::interpreter::default::default_aliases();
             let mut re_exports = ReExport {
@@ -924,6 +1091,13 @@ impl ParsedDocument {
             (doc_aliases, re_exports, exposings)
         };
 
+        let private_things = ftd::ast::AST::private_definitions(sections.as_slice());
+        let warnings = unused_warnings(id, ast.as_slice(), private_things.as_slice());
+        let mut spans = std::collections::BTreeMap::new();
+        for (line_number, span) in sections.iter().flat_map(|v| v.spans()) {
+            spans.entry(line_number).or_insert(span);
+        }
+
         Ok(ParsedDocument {
             name: id.to_string(),
             ast,
@@ -933,9 +1107,19 @@ impl ParsedDocument {
             exposings,
             foreign_variable: vec![],
             foreign_function: vec![],
+            private_things,
+            warnings,
+            spans,
         })
     }
 
+    /// Whether `thing_name` (or the thing `thing_name` is a field or variant of) is private to
+    /// this document, see `ftd::ast::AST::private_definitions`.
+    pub fn is_private(&self, thing_name: &str) -> bool {
+        let name = thing_name.split('.').next().unwrap_or(thing_name);
+        self.private_things.iter().any(|v| v.eq(name))
+    }
+
     pub fn get_doc_aliases(&self) -> ftd::Map<String> {
         self.doc_aliases.clone()
     }
@@ -949,6 +1133,59 @@ impl ParsedDocument {
     }
 }
 
+/// Warnings for the imports a document never refers to and for its private definitions nothing
+/// else in the document uses. The other definitions can be used by the documents importing this
+/// one, so they are never reported. Neither are imports that re-export or expose everything,
+/// nor those added before the document (`line_number` 0, like the auto imports of a package).
+fn unused_warnings(
+    id: &str,
+    ast: &[ftd::ast::AST],
+    private_things: &[String],
+) -> Vec<ftd::diagnostic::Diagnostic> {
+    let names = ast.iter().map(|v| v.references()).collect::<Vec<_>>();
+    let used_elsewhere = |index: usize, name: &str| {
+        let prefix = format!("{}.", name);
+        names.iter().enumerate().any(|(i, names)| {
+            i != index && names.iter().any(|n| n.eq(name) || n.starts_with(&prefix))
+        })
+    };
+
+    let mut warnings = vec![];
+    for (index, v) in ast.iter().enumerate() {
+        match v {
+            ftd::ast::AST::Import(import) if import.line_number > 0 && import.exports.is_none() => {
+                let exposed = match &import.exposing {
+                    Some(ftd::ast::Exposing::Things(things)) => things.clone(),
+                    Some(ftd::ast::Exposing::All) => continue,
+                    None => vec![],
+                };
+                if used_elsewhere(index, import.alias.as_str())
+                    || exposed.iter().any(|t| used_elsewhere(index, t))
+                {
+                    continue;
+                }
+                warnings.push(ftd::diagnostic::Diagnostic::warning(
+                    format!("unused import: `{}`", import.alias),
+                    id,
+                    import.line_number,
+                ));
+            }
+            v if v.is_definition()
+                && private_things.contains(&v.name())
+                && !used_elsewhere(index, v.name().as_str()) =>
+            {
+                warnings.push(ftd::diagnostic::Diagnostic::warning(
+                    format!("`{}` is private and never used", v.name()),
+                    id,
+                    v.line_number(),
+                ));
+            }
+            _ => {}
+        }
+    }
+    warnings
+}
+
 /// Interpreter enum that represents different states that an interpreter can be in during its
 /// execution. The states are:
 ///
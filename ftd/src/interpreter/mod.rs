@@ -58,6 +58,7 @@ pub enum Error {
         doc_id: String,
         line_number: usize,
         message: String,
+        span: Option<ftd::diagnostic::Span>,
     },
 
     #[error("ValueNotFound: {doc_id}:{line_number} -> {message}")]
@@ -65,6 +66,7 @@ pub enum Error {
         doc_id: String,
         line_number: usize,
         message: String,
+        span: Option<ftd::diagnostic::Span>,
     },
 
     #[error("ParseIntError: {}", _0)]
@@ -81,6 +83,7 @@ pub enum Error {
         message: String,
         doc_id: String,
         line_number: usize,
+        span: Option<ftd::diagnostic::Span>,
     },
 
     #[error("EvalexprError: {}", _0)]
@@ -94,6 +97,77 @@ pub enum Error {
 
     #[error("Invalid access: {message}, line_number: {line_number}")]
     InvalidAccessError { message: String, line_number: usize },
+
+    #[error("{}", ftd::interpreter::utils::diagnostics_to_string(.diagnostics))]
+    Diagnostics {
+        diagnostics: Vec<ftd::diagnostic::Diagnostic>,
+    },
+}
+
+impl Error {
+    /// Combines the errors collected while recovering: `None` if there are none, the error
+    /// itself if there is only one, else all of them as `Error::Diagnostics`.
+    pub fn from_errors(mut errors: Vec<Error>) -> Option<Error> {
+        if errors.len() > 1 {
+            return Some(Error::Diagnostics {
+                diagnostics: errors.iter().flat_map(|e| e.to_diagnostics()).collect(),
+            });
+        }
+        errors.pop()
+    }
+
+    /// Sets the span of the error (or of each of its diagnostics) that does not have one yet,
+    /// `f` is called with the doc id and the line number of the error.
+    pub fn or_span<F>(self, f: F) -> Error
+    where
+        F: Fn(&str, usize) -> Option<ftd::diagnostic::Span>,
+    {
+        match self {
+            Error::InvalidKind {
+                doc_id,
+                line_number,
+                message,
+                span,
+            } => Error::InvalidKind {
+                span: span.or_else(|| f(doc_id.as_str(), line_number)),
+                doc_id,
+                line_number,
+                message,
+            },
+            Error::ValueNotFound {
+                doc_id,
+                line_number,
+                message,
+                span,
+            } => Error::ValueNotFound {
+                span: span.or_else(|| f(doc_id.as_str(), line_number)),
+                doc_id,
+                line_number,
+                message,
+            },
+            Error::ParseError {
+                message,
+                doc_id,
+                line_number,
+                span,
+            } => Error::ParseError {
+                span: span.or_else(|| f(doc_id.as_str(), line_number)),
+                message,
+                doc_id,
+                line_number,
+            },
+            Error::Diagnostics { diagnostics } => Error::Diagnostics {
+                diagnostics: diagnostics
+                    .into_iter()
+                    .map(|d| {
+                        let span = d.span.or_else(|| f(d.doc_id.as_str(), d.line_number));
+                        d.with_span(span)
+                    })
+                    .collect(),
+            },
+            e => e,
+        }
+    }
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -54,6 +54,52 @@ impl<'a> TDoc<'a> {
         }
     }
 
+    /// Adds an interpreted thing to the bag, like the instance `foo#paginated<string>` of the
+    /// generic record `foo#paginated`.
+    pub(crate) fn insert_thing(
+        &mut self,
+        thing: ftd::interpreter::Thing,
+        line_number: usize,
+    ) -> ftd::interpreter::Result<()> {
+        match &mut self.bag {
+            BagOrState::State(s) => {
+                s.bag.insert(thing.name(), thing);
+                Ok(())
+            }
+            BagOrState::Bag(_) => self.err(
+                "can't add to the bag of an interpreted document",
+                thing.name(),
+                "insert_thing",
+                line_number,
+            ),
+        }
+    }
+
+    /// The type parameters of the generic record, or-type or component `name`, e.g. `["T"]`
+    /// for `-- record paginated<T>:`. They are read from the definition, the interpreted thing
+    /// only has its type parameters as `Kind::TypeParameter`s in the fields.
+    pub(crate) fn type_parameters(&self, name: &str) -> Vec<String> {
+        let (doc_name, thing_name) = match name.split_once('#') {
+            Some(v) => v,
+            None => (self.name, name),
+        };
+        let state = match &self.bag {
+            BagOrState::State(s) => s,
+            BagOrState::Bag(_) => return vec![],
+        };
+        state
+            .parsed_libs
+            .get(doc_name)
+            .and_then(|d| {
+                d.ast.iter().find(|ast| {
+                    ast.name().eq(thing_name)
+                        && (ast.is_record() || ast.is_or_type() || ast.is_component_definition())
+                })
+            })
+            .map(|ast| ast.type_parameters().to_vec())
+            .unwrap_or_default()
+    }
+
     pub fn get_record(
         &'a self,
         name: &'a str,
@@ -82,9 +128,13 @@ impl<'a> TDoc<'a> {
             ftd::interpreter::StateWithThing::Continue => {
                 Ok(ftd::interpreter::StateWithThing::new_continue())
             }
-            ftd::interpreter::StateWithThing::Thing(ftd::interpreter::Thing::Record(r)) => {
-                Ok(ftd::interpreter::StateWithThing::new_thing(r))
-            }
+            ftd::interpreter::StateWithThing::Thing(ftd::interpreter::Thing::Record(r))
+            | ftd::interpreter::StateWithThing::Thing(
+                ftd::interpreter::Thing::OrTypeWithVariant {
+                    variant: ftd::interpreter::OrTypeVariant::AnonymousRecord(r),
+                    ..
+                },
+            ) => Ok(ftd::interpreter::StateWithThing::new_thing(r)),
             ftd::interpreter::StateWithThing::Thing(t) => self.err(
                 format!("Expected Record, found: `{:?}`", t).as_str(),
                 name,
@@ -245,6 +295,7 @@ impl<'a> TDoc<'a> {
                             message: format!("Can't find field `{}` in record `{}`", p1, rec_name),
                             doc_id: doc.name.to_string(),
                             line_number,
+                            span: None,
                         })?
                         .clone()
                         .resolve_with_inherited(doc, line_number, inherited_variables)?;
@@ -270,6 +321,30 @@ impl<'a> TDoc<'a> {
                             ),
                             doc_id: doc.name.to_string(),
                             line_number,
+                            span: None,
+                        })?
+                        .clone()
+                        .resolve_with_inherited(doc, line_number, inherited_variables)?;
+                    if let Some(p2) = p2 {
+                        return resolve_(
+                            p2.as_str(),
+                            &value,
+                            line_number,
+                            doc,
+                            inherited_variables,
+                        );
+                    }
+                    Ok(value)
+                }
+                ftd::interpreter::Value::Map { data, key, kind } => {
+                    let value = key
+                        .map_key(p1.as_str())
+                        .and_then(|p1| data.get(p1.as_str()))
+                        .ok_or(ftd::interpreter::Error::ParseError {
+                            message: format!("Can't find key `{}` in map of kind `{:?}`", p1, kind),
+                            doc_id: doc.name.to_string(),
+                            line_number,
+                            span: None,
                         })?
                         .clone()
                         .resolve_with_inherited(doc, line_number, inherited_variables)?;
@@ -362,10 +437,23 @@ impl<'a> TDoc<'a> {
                         ),
                         doc_id: doc.name.to_string(),
                         line_number,
+                        span: None,
                     },
                 )?;
 
                 match value {
+                    ftd::interpreter::Value::Map { data, key, .. } => {
+                        if let Some(entry) = key
+                            .map_key(p1.as_str())
+                            .and_then(|p1| data.get(p1.as_str()))
+                        {
+                            if let Some(variable) =
+                                find_variable_reference(entry, p2, doc, line_number)?
+                            {
+                                return Ok(Some(variable));
+                            }
+                        }
+                    }
                     ftd::interpreter::Value::Record {
                         name: rec_name,
                         fields,
@@ -379,6 +467,7 @@ impl<'a> TDoc<'a> {
                                 ),
                                 doc_id: doc.name.to_string(),
                                 line_number,
+                                span: None,
                             })?
                             .to_owned();
                         if let Some(variable) =
@@ -435,10 +524,39 @@ impl<'a> TDoc<'a> {
                                     ),
                                     doc_id: doc.name.to_string(),
                                     line_number,
+                                    span: None,
                                 },
                             )?;
                             change_value(field, set, p2, doc, line_number)?;
                         }
+                        // setting a key that is not in the map adds it
+                        ftd::interpreter::Value::Map { data, key, .. } => {
+                            let p1 = key.map_key(p1.as_str()).ok_or_else(|| {
+                                ftd::interpreter::Error::ParseError {
+                                    message: format!(
+                                        "Expected `{}` key in map, found: `{}`",
+                                        key.get_name(),
+                                        p1
+                                    ),
+                                    doc_id: doc.name.to_string(),
+                                    line_number,
+                                    span: None,
+                                }
+                            })?;
+                            if p2.is_none() && !data.contains_key(p1.as_str()) {
+                                data.insert(p1, set);
+                                return Ok(());
+                            }
+                            let entry = data.get_mut(p1.as_str()).ok_or(
+                                ftd::interpreter::Error::ParseError {
+                                    message: format!("Can't find key `{}` in map", p1),
+                                    doc_id: doc.name.to_string(),
+                                    line_number,
+                                    span: None,
+                                },
+                            )?;
+                            change_value(entry, set, p2, doc, line_number)?;
+                        }
                         t => {
                             return ftd::interpreter::utils::e2(
                                 format!("Expected record, found `{:?}`", t).as_str(),
@@ -486,6 +604,7 @@ impl<'a> TDoc<'a> {
                                 ),
                                 doc_id: doc.name.to_string(),
                                 line_number,
+                                span: None,
                             })?;
                         *value = ftd::interpreter::PropertyValue::Value {
                             value: resolved_value,
@@ -649,6 +768,40 @@ impl<'a> TDoc<'a> {
                         ))
                     }
                 }
+                ftd::interpreter::Kind::Map { kind, .. } => {
+                    if let Some(remaining) = remaining {
+                        get_kind_(*kind, &remaining, doc, line_number)
+                    } else {
+                        Ok(ftd::interpreter::StateWithThing::new_thing(
+                            ftd::interpreter::KindData::new(*kind),
+                        ))
+                    }
+                }
+                // `$shape.circle` is the value held by the variant `circle` of `$shape`
+                ftd::interpreter::Kind::OrType {
+                    name: or_type_name, ..
+                } if !or_type_name.starts_with("ftd#") => {
+                    let or_type =
+                        try_ok_state!(doc.search_or_type(or_type_name.as_str(), line_number)?);
+                    let variant_kind = match or_type.variant(v.as_str()) {
+                        Some(ftd::interpreter::OrTypeVariant::AnonymousRecord(record)) => {
+                            ftd::interpreter::Kind::record(record.name.as_str()).into_kind_data()
+                        }
+                        Some(variant) => variant.fields()[0].kind.to_owned(),
+                        None => {
+                            return ftd::interpreter::utils::e2(
+                                format!("`{}` is not a variant of `{}`", v, or_type_name),
+                                doc.name,
+                                line_number,
+                            )
+                        }
+                    };
+                    if let Some(remaining) = remaining {
+                        get_kind_(variant_kind.kind, &remaining, doc, line_number)
+                    } else {
+                        Ok(ftd::interpreter::StateWithThing::new_thing(variant_kind))
+                    }
+                }
                 ftd::interpreter::Kind::Optional { kind } => {
                     let state_with_thing = get_kind_(*kind, name, doc, line_number)?;
                     if let ftd::interpreter::StateWithThing::Thing(ref t) = state_with_thing {
@@ -858,6 +1011,20 @@ impl<'a> TDoc<'a> {
                     let fields = match value.resolve(doc, line_number)?.inner() {
                         Some(ftd::interpreter::Value::Record { fields, .. }) => fields,
                         Some(ftd::interpreter::Value::Object { values }) => values,
+                        Some(ftd::interpreter::Value::Map { data, .. }) => data,
+                        // the value of an or-type defined in an ftd document has its variant
+                        // as the only field
+                        Some(ftd::interpreter::Value::OrType {
+                            name,
+                            variant,
+                            value,
+                            ..
+                        }) if !name.starts_with("ftd#") => std::iter::once((
+                            ftd::interpreter::utils::variant_short_name(variant.as_str())
+                                .to_string(),
+                            *value,
+                        ))
+                        .collect(),
                         Some(ftd::interpreter::Value::List { data, .. }) => data
                             .into_iter()
                             .enumerate()
@@ -1012,6 +1179,33 @@ impl<'a> TDoc<'a> {
         self.scan_initial_thing(name, line_number)
     }
 
+    /// Errors if `thing_name` is private to `doc_name` and is referred to from another
+    /// document, directly, through `exposing` or through a re-export.
+    fn check_access(
+        &self,
+        doc_name: &str,
+        thing_name: &str,
+        line_number: usize,
+    ) -> ftd::interpreter::Result<()> {
+        let state = match &self.bag {
+            BagOrState::State(s) if doc_name.ne(self.name) => s,
+            _ => return Ok(()),
+        };
+        match state.parsed_libs.get(doc_name) {
+            Some(parsed_document) if parsed_document.is_private(thing_name) => {
+                ftd::interpreter::utils::e2(
+                    format!(
+                        "`{}` is private to `{}` and can't be used here",
+                        thing_name, doc_name
+                    ),
+                    self.name,
+                    line_number,
+                )
+            }
+            _ => Ok(()),
+        }
+    }
+
     pub fn scan_initial_thing_from_doc_name(
         &mut self,
         doc_name: String,
@@ -1033,6 +1227,8 @@ impl<'a> TDoc<'a> {
                 .unwrap_or_default()
         );
 
+        self.check_access(doc_name.as_str(), thing_name.as_str(), line_number)?;
+
         let state = if let Some(state) = {
             match &mut self.bag {
                 BagOrState::Bag(_) => None,
@@ -1048,6 +1244,12 @@ impl<'a> TDoc<'a> {
             return Ok(());
         }
 
+        if let Some(diagnostics) = state.failed.get(&format!("{}#{}", doc_name, thing_name)) {
+            return Err(ftd::interpreter::Error::Diagnostics {
+                diagnostics: diagnostics.clone(),
+            });
+        }
+
         // let current_parsed_document = state.parsed_libs.get(self.name).unwrap();
 
         /*if doc_name.ne(self.name) {
@@ -1218,19 +1420,21 @@ impl<'a> TDoc<'a> {
             .or_else(|| name.strip_prefix(ftd::interpreter::utils::CLONE))
             .unwrap_or(name);
 
-        if self.get_initial_thing(name, line_number).is_ok() {
-            return Ok(());
-        }
-
-        let name = self.resolve_name(name);
+        let resolved_name = self.resolve_name(name);
 
         let (doc_name, thing_name, remaining) = // Todo: use remaining
             ftd::interpreter::utils::get_doc_name_and_thing_name_and_remaining(
-                name.as_str(),
+                resolved_name.as_str(),
                 self.name,
                 line_number,
             );
 
+        self.check_access(doc_name.as_str(), thing_name.as_str(), line_number)?;
+
+        if self.get_initial_thing(name, line_number).is_ok() {
+            return Ok(());
+        }
+
         self.scan_initial_thing_from_doc_name(
             doc_name,
             thing_name,
@@ -1279,6 +1483,7 @@ impl<'a> TDoc<'a> {
                     let fields = match value.resolve(doc, line_number)?.inner() {
                         Some(ftd::interpreter::Value::Record { fields, .. }) => fields,
                         Some(ftd::interpreter::Value::Object { values }) => values,
+                        Some(ftd::interpreter::Value::Map { data, .. }) => data,
                         Some(ftd::interpreter::Value::List { data, .. }) => data
                             .into_iter()
                             .enumerate()
@@ -1451,6 +1656,8 @@ impl<'a> TDoc<'a> {
                 .unwrap_or_default()
         );
 
+        self.check_access(doc_name.as_str(), thing_name.as_str(), line_number)?;
+
         let state = if let Some(state) = {
             match &mut self.bag {
                 BagOrState::Bag(_) => None,
@@ -1462,6 +1669,12 @@ impl<'a> TDoc<'a> {
             return self.err("not found", name, "search_thing", line_number);
         };
 
+        if let Some(diagnostics) = state.failed.get(&format!("{}#{}", doc_name, thing_name)) {
+            return Err(ftd::interpreter::Error::Diagnostics {
+                diagnostics: diagnostics.clone(),
+            });
+        }
+
         let current_parsed_document = state.parsed_libs.get(state.id.as_str()).unwrap();
 
         if doc_name.ne(state.id.as_str()) {
@@ -1647,19 +1860,21 @@ impl<'a> TDoc<'a> {
             .or_else(|| name.strip_prefix(ftd::interpreter::utils::CLONE))
             .unwrap_or(name);
 
-        if let Ok(thing) = self.get_initial_thing(name, line_number) {
-            return Ok(ftd::interpreter::StateWithThing::new_thing(thing));
-        }
-
-        let name = self.resolve_name(name);
+        let resolved_name = self.resolve_name(name);
 
         let (doc_name, thing_name, remaining) = // Todo: use remaining
             ftd::interpreter::utils::get_doc_name_and_thing_name_and_remaining(
-                name.as_str(),
+                resolved_name.as_str(),
                 self.name,
                 line_number,
             );
 
+        self.check_access(doc_name.as_str(), thing_name.as_str(), line_number)?;
+
+        if let Ok(thing) = self.get_initial_thing(name, line_number) {
+            return Ok(ftd::interpreter::StateWithThing::new_thing(thing));
+        }
+
         self.search_initial_thing_from_doc_name(
             doc_name,
             thing_name,
@@ -1811,6 +2026,7 @@ impl<'a> TDoc<'a> {
                             message: format!("Can't parse to string, found: {:?}", row),
                             doc_id: self.name.to_string(),
                             line_number,
+                            span: None,
                         })?,
                 }
             }
@@ -1821,6 +2037,7 @@ impl<'a> TDoc<'a> {
                             message: format!("Can't parse to integer, found: {:?}", row),
                             doc_id: self.name.to_string(),
                             line_number,
+                            span: None,
                         },
                     )?,
                 }
@@ -1832,6 +2049,7 @@ impl<'a> TDoc<'a> {
                             message: format!("Can't parse to decimal, found: {:?}", row),
                             doc_id: self.name.to_string(),
                             line_number,
+                            span: None,
                         },
                     )?,
                 }
@@ -1843,9 +2061,17 @@ impl<'a> TDoc<'a> {
                             message: format!("Can't parse to boolean,found: {:?}", row),
                             doc_id: self.name.to_string(),
                             line_number,
+                            span: None,
                         })?,
                 }
             }
+            ftd::interpreter::Kind::DateTime
+            | ftd::interpreter::Kind::Date
+            | ftd::interpreter::Kind::Duration
+                if row.first().is_some() =>
+            {
+                self.from_json(row.first().unwrap(), kind, line_number)?
+            }
             t => unimplemented!(
                 "{:?} not yet implemented, line number: {}, doc: {}",
                 t,
@@ -1868,6 +2094,7 @@ impl<'a> TDoc<'a> {
             message: format!("Can't serialize to json: {:?}, found: {:?}", e, json),
             doc_id: self.name.to_string(),
             line_number,
+            span: None,
         })?;
 
         self.as_json_(line_number, &json, kind.to_owned())
@@ -1886,6 +2113,7 @@ impl<'a> TDoc<'a> {
                         message: format!("Can't parse to string, found: {}", json),
                         doc_id: self.name.to_string(),
                         line_number,
+                        span: None,
                     }
                 })?,
             },
@@ -1895,6 +2123,7 @@ impl<'a> TDoc<'a> {
                         message: format!("Can't parse to integer, found: {}", json),
                         doc_id: self.name.to_string(),
                         line_number,
+                        span: None,
                     }
                 })?,
             },
@@ -1904,6 +2133,7 @@ impl<'a> TDoc<'a> {
                         message: format!("Can't parse to decimal, found: {}", json),
                         doc_id: self.name.to_string(),
                         line_number,
+                        span: None,
                     }
                 })?,
             },
@@ -1913,9 +2143,50 @@ impl<'a> TDoc<'a> {
                         message: format!("Can't parse to boolean,found: {}", json),
                         doc_id: self.name.to_string(),
                         line_number,
+                        span: None,
                     }
                 })?,
             },
+            ftd::interpreter::Kind::DateTime => match json {
+                serde_json::Value::String(s) => {
+                    ftd::interpreter::Value::new_datetime(s, self.name, line_number)?
+                }
+                _ => {
+                    return ftd::interpreter::utils::e2(
+                        format!("Can't parse to datetime, found: {}", json),
+                        self.name,
+                        line_number,
+                    )
+                }
+            },
+            ftd::interpreter::Kind::Date => match json {
+                serde_json::Value::String(s) => {
+                    ftd::interpreter::Value::new_date(s, self.name, line_number)?
+                }
+                _ => {
+                    return ftd::interpreter::utils::e2(
+                        format!("Can't parse to date, found: {}", json),
+                        self.name,
+                        line_number,
+                    )
+                }
+            },
+            // either an ISO-8601 duration or a number of seconds
+            ftd::interpreter::Kind::Duration => match json {
+                serde_json::Value::String(s) => {
+                    ftd::interpreter::Value::new_duration(s, self.name, line_number)?
+                }
+                serde_json::Value::Number(n) if n.is_i64() => ftd::interpreter::Value::Duration {
+                    seconds: n.as_i64().unwrap_or_default(),
+                },
+                _ => {
+                    return ftd::interpreter::utils::e2(
+                        format!("Can't parse to duration, found: {}", json),
+                        self.name,
+                        line_number,
+                    )
+                }
+            },
             ftd::interpreter::Kind::Record { name, .. } => {
                 let rec_fields = self.get_record(&name, line_number)?.fields;
                 let mut fields: ftd::Map<ftd::interpreter::PropertyValue> = Default::default();
@@ -1925,6 +2196,9 @@ impl<'a> TDoc<'a> {
                             Some(v) => v.to_owned(),
                             None if field.kind.is_optional() => serde_json::Value::Null,
                             None if field.kind.is_list() => serde_json::Value::Array(vec![]),
+                            None if field.kind.is_map() => {
+                                serde_json::Value::Object(Default::default())
+                            }
                             None => {
                                 return ftd::interpreter::utils::e2(
                                     format!("key not found: {}", field.name.as_str()),
@@ -1993,6 +2267,44 @@ impl<'a> TDoc<'a> {
                     kind: kind.to_owned().into_kind_data(),
                 }
             }
+            ftd::interpreter::Kind::Map { key, kind } => {
+                let kind = kind.as_ref();
+                let mut data: ftd::Map<ftd::interpreter::PropertyValue> = Default::default();
+                if let serde_json::Value::Object(o) = json {
+                    for (map_key, item) in o {
+                        data.insert(
+                            key.map_key(map_key).ok_or_else(|| {
+                                ftd::interpreter::Error::ParseError {
+                                    message: format!(
+                                        "Expected `{}` key in map, found: `{}`",
+                                        key.get_name(),
+                                        map_key
+                                    ),
+                                    doc_id: self.name.to_string(),
+                                    line_number,
+                                    span: None,
+                                }
+                            })?,
+                            ftd::interpreter::PropertyValue::Value {
+                                value: self.as_json_(line_number, item, kind.to_owned())?,
+                                is_mutable: false,
+                                line_number,
+                            },
+                        );
+                    }
+                } else {
+                    return ftd::interpreter::utils::e2(
+                        format!("expected object of map type, found: {}", json),
+                        self.name,
+                        line_number,
+                    );
+                }
+                ftd::interpreter::Value::Map {
+                    data,
+                    key: key.as_ref().clone(),
+                    kind: kind.to_owned().into_kind_data(),
+                }
+            }
             ftd::interpreter::Kind::Optional { kind, .. } => {
                 let kind = kind.as_ref().to_owned();
                 match json {
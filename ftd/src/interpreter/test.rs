@@ -3,6 +3,16 @@ use pretty_assertions::assert_eq; // macro
 pub fn interpret_helper(
     name: &str,
     source: &str,
+) -> ftd::interpreter::Result<ftd::interpreter::Document> {
+    interpret_helper_with_modules(name, source, &[])
+}
+
+/// Like `interpret_helper`, the imported modules found in `modules` get their source from it,
+/// the rest are empty.
+pub fn interpret_helper_with_modules(
+    name: &str,
+    source: &str,
+    modules: &[(&str, &str)],
 ) -> ftd::interpreter::Result<ftd::interpreter::Document> {
     let mut s = ftd::interpreter::interpret(name, source)?;
     let document;
@@ -15,7 +25,11 @@ pub fn interpret_helper(
             ftd::interpreter::Interpreter::StuckOnImport {
                 module, state: st, ..
             } => {
-                let source = "";
+                let source = modules
+                    .iter()
+                    .find(|(m, _)| module.eq(m))
+                    .map(|(_, source)| *source)
+                    .unwrap_or_default();
                 let mut foreign_variable = vec![];
                 let mut foreign_function = vec![];
                 if module.eq("test") {
@@ -275,3 +289,598 @@ fn evalexpr_test() {
         }
             */
 }
+
+#[test]
+fn recovers_from_errors() {
+    let source = indoc::indoc!(
+        "
+        -- ftd.text: $foo
+
+        -- ftd.text: hello
+
+        -- ftd.text: $bar
+        "
+    );
+    let error = interpret_helper("foo", source).unwrap_err();
+    let diagnostics = error.to_diagnostics();
+    assert_eq!(
+        diagnostics
+            .iter()
+            .map(|d| (d.doc_id.as_str(), d.line_number))
+            .collect::<Vec<_>>(),
+        vec![("foo", 1), ("foo", 5)]
+    );
+    assert_eq!(
+        error.to_string(),
+        indoc::indoc!(
+            r#"
+            foo:1 -> foo: not found ("foo#foo"), f: search_thing
+            foo:5 -> foo: not found ("foo#bar"), f: search_thing"#
+        )
+    );
+}
+
+#[test]
+fn recovers_from_errors_in_definitions() {
+    let source = indoc::indoc!(
+        "
+        -- integer x: $y
+
+        -- ftd.integer: $x
+
+        -- component foo:
+
+        -- ftd.text: $baz
+
+        -- end: foo
+
+        -- foo:
+
+        -- ftd.text: hello
+        "
+    );
+    let error = interpret_helper("foo", source).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        indoc::indoc!(
+            r#"
+            foo:1 -> foo: not found ("foo#y"), f: search_thing
+            foo:7 -> foo: not found ("foo#baz"), f: search_thing"#
+        )
+    );
+}
+
+#[test]
+fn recovers_from_errors_in_imported_definitions() {
+    let lib = indoc::indoc!(
+        "
+        -- integer a: $missing
+
+        -- integer b: 2
+        "
+    );
+    let source = indoc::indoc!(
+        "
+        -- import: lib
+
+        -- ftd.integer: $lib.a
+
+        -- ftd.integer: $lib.b
+
+        -- ftd.integer: $lib.a
+
+        -- ftd.text: $foo
+        "
+    );
+    let error = interpret_helper_with_modules("foo", source, &[("lib", lib)]).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        indoc::indoc!(
+            r#"
+            lib:1 -> lib: not found ("lib#missing"), f: search_thing
+            foo:9 -> foo: not found ("foo#foo"), f: search_thing"#
+        )
+    );
+}
+
+#[test]
+fn errors_have_spans() {
+    let source = indoc::indoc!(
+        "
+        -- ftd.text: hello
+        padding.px: $foo
+
+        -- ftd.integer: $bar
+        "
+    );
+    let error = interpret_helper("foo", source).unwrap_err();
+    assert_eq!(
+        error
+            .to_diagnostics()
+            .iter()
+            .map(|d| d.render(Some(source)))
+            .collect::<Vec<_>>(),
+        vec![
+            indoc::indoc!(
+                r#"
+                error: foo: not found ("foo#foo"), f: search_thing
+                 --> foo:2:13
+                  |
+                2 | padding.px: $foo
+                  |             ^^^^
+                "#
+            ),
+            indoc::indoc!(
+                r#"
+                error: foo: not found ("foo#bar"), f: search_thing
+                 --> foo:4:4
+                  |
+                4 | -- ftd.integer: $bar
+                  |    ^^^^^^^^^^^^^^^^^
+                "#
+            ),
+        ]
+    );
+}
+
+#[test]
+fn match_in_function_body() {
+    let source = indoc::indoc!(
+        "
+        -- or-type shape:
+
+        -- decimal circle:
+
+        -- integer square:
+
+        -- end: shape
+
+        -- shape.circle c: 2.0
+
+        -- shape.square s: 3
+
+        -- decimal area(s):
+        shape s:
+
+        match s {
+            circle(radius) => radius * radius * 3.0,
+            square(side) => side * side * 1.0,
+        }
+
+        -- decimal circle-area: $area(s = $c)
+
+        -- decimal square-area: $area(s = $s)
+
+        -- ftd.decimal: $circle-area
+
+        -- ftd.decimal: $square-area
+        "
+    );
+    let document = interpret_helper("foo", source).unwrap_or_else(|e| panic!("{:?}", e));
+    let doc = ftd::interpreter::TDoc::new(&document.name, &document.aliases, &document.data);
+    let value = |name: &str| {
+        doc.get_variable(name, 0)
+            .unwrap()
+            .value
+            .resolve(&doc, 0)
+            .unwrap()
+    };
+    assert_eq!(
+        value("foo#circle-area"),
+        ftd::interpreter::Value::Decimal { value: 12.0 }
+    );
+    assert_eq!(
+        value("foo#square-area"),
+        ftd::interpreter::Value::Decimal { value: 9.0 }
+    );
+}
+
+#[test]
+fn string_list_and_json_functions() {
+    use fastn_grammar::evalexpr::Value;
+
+    let source = indoc::indoc!(
+        "
+        -- record person:
+        caption name:
+        integer age:
+
+        -- person list people:
+
+        -- person: Alice
+        age: 34
+
+        -- person: Bob
+        age: 27
+
+        -- person: Carol
+        age: 41
+
+        -- end: people
+
+        -- string list tags: $ftd.split(text = rust;ftd;rust;web, separator = ;)
+
+        -- string list chars(word):
+        string word:
+
+        ftd.split(word, \"\")
+
+        -- string list letters: $chars(word = abc)
+
+        -- string list unique-tags: $ftd.unique(list = $tags)
+
+        -- string list names: $ftd.map(list = $people, expression = item.name)
+
+        -- person list over-30: $ftd.filter(list = $people, condition = item.age > 30)
+
+        -- person list by-age: $ftd.sort-by(list = $people, key = item.age)
+
+        -- person list last-two: $ftd.skip(list = $people, count = 1)
+
+        -- string json: $ftd.to-json(value = $people)
+
+        -- person list decoded: $ftd.from-json(json = $json)
+
+        -- string slice: $ftd.slice(text = fastn, start = -3, end = -1)
+
+        -- string bob: Bob
+
+        -- integer bob-index: $ftd.index-of(list = $names, item = $bob)
+
+        -- integer list ages: $ftd.map(list = $people, expression = item.age)
+
+        -- integer total-age: $ftd.sum(list = $ages)
+
+        -- integer doubled-total(values):
+        integer list values:
+
+        ftd.sum(ftd.map(values, \"item * 2\"))
+
+        -- integer doubled: $doubled-total(values = $ages)
+
+        -- ftd.text: $t
+        $loop$: $tags as $t
+
+        -- ftd.text: $t
+        $loop$: $letters as $t
+
+        -- ftd.text: $t
+        $loop$: $unique-tags as $t
+
+        -- ftd.text: $p.name
+        $loop$: $over-30 as $p
+
+        -- ftd.text: $p.name
+        $loop$: $by-age as $p
+
+        -- ftd.text: $p.name
+        $loop$: $last-two as $p
+
+        -- ftd.text: $p.name
+        $loop$: $decoded as $p
+
+        -- ftd.text: $slice
+
+        -- ftd.integer: $bob-index
+
+        -- ftd.integer: $total-age
+
+        -- ftd.integer: $doubled
+        "
+    );
+    let document = interpret_helper("foo", source).unwrap_or_else(|e| panic!("{:?}", e));
+    let doc = ftd::interpreter::TDoc::new(&document.name, &document.aliases, &document.data);
+    let value = |name: &str| {
+        doc.get_variable(format!("foo#{}", name).as_str(), 0)
+            .unwrap()
+            .value
+            .resolve(&doc, 0)
+            .unwrap()
+            .to_evalexpr_value(&doc, 0)
+            .unwrap()
+    };
+    let strings = |values: &[&str]| Value::Tuple(values.iter().map(|v| Value::from(*v)).collect());
+
+    assert_eq!(value("tags"), strings(&["rust", "ftd", "rust", "web"]));
+    assert_eq!(value("letters"), strings(&["a", "b", "c"]));
+    assert_eq!(value("unique-tags"), strings(&["rust", "ftd", "web"]));
+    assert_eq!(value("names"), strings(&["Alice", "Bob", "Carol"]));
+    assert_eq!(value("slice"), Value::from("st"));
+    assert_eq!(value("bob-index"), Value::Int(1));
+    assert_eq!(value("total-age"), Value::Int(102));
+    assert_eq!(value("doubled"), Value::Int(204));
+    assert_eq!(
+        value("json"),
+        Value::from(
+            r#"[{"age":34,"name":"Alice"},{"age":27,"name":"Bob"},{"age":41,"name":"Carol"}]"#
+        )
+    );
+    assert_eq!(value("decoded"), value("people"));
+    let names = |list: &str| {
+        let ftd::interpreter::Value::List { data, .. } =
+            doc.get_variable(format!("foo#{}", list).as_str(), 0)
+                .unwrap()
+                .value
+                .resolve(&doc, 0)
+                .unwrap()
+        else {
+            panic!("Expected a list: {}", list)
+        };
+        data.into_iter()
+            .map(|person| match person.resolve(&doc, 0).unwrap() {
+                ftd::interpreter::Value::Record { fields, .. } => fields["name"]
+                    .clone()
+                    .resolve(&doc, 0)
+                    .unwrap()
+                    .string(doc.name, 0)
+                    .unwrap(),
+                t => panic!("Expected a person: {:?}", t),
+            })
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(names("over-30"), vec!["Alice", "Carol"]);
+    assert_eq!(names("by-age"), vec!["Bob", "Alice", "Carol"]);
+    assert_eq!(names("last-two"), vec!["Bob", "Carol"]);
+}
+
+#[test]
+fn regex_patterns_are_checked() {
+    // the patterns only matched in the browser are checked too
+    let source = indoc::indoc!(
+        r#"
+        -- boolean has-word(text):
+        string text:
+
+        ftd.regex_match(text, "\\bword")
+
+        -- string list words(list):
+        string list list:
+
+        ftd.filter(list, "ftd.regex_match(item, \"(?i)a\")")
+
+        -- string list names:
+
+        -- end: names
+
+        -- ftd.boolean: $has-word(text = hello)
+
+        -- ftd.text: $name
+        $loop$: $words(list = $names) as $name
+        "#
+    );
+    let error = interpret_helper("foo", source).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        indoc::indoc!(
+            r#"
+            foo:1 -> Invalid regex `\bword`: `\b` is not supported, word boundaries are not the same in the browser
+            foo:6 -> Invalid regex `(?i)a`: only `(..)` and `(?:..)` groups are supported"#
+        )
+    );
+}
+
+#[test]
+fn match_checks_arms() {
+    let or_type = indoc::indoc!(
+        "
+        -- or-type shape:
+
+        -- decimal circle:
+
+        -- integer square:
+
+        -- constant string dot: dot
+
+        -- end: shape
+
+        -- shape.square s: 3
+        "
+    );
+    let error = |arms: &str| {
+        let source = format!("{}\n-- match: $s\n\n{}\n-- end: match\n", or_type, arms);
+        interpret_helper("foo", source.as_str())
+            .unwrap_err()
+            .to_string()
+    };
+
+    assert!(error("-- ftd.text: circle\ncase: circle\n")
+        .contains("Non-exhaustive `match` over `$s`, not handled: square, dot"));
+    assert!(
+        error("-- ftd.text: any\ncase: _\n\n-- ftd.text: dot\ncase: dot\n")
+            .contains("Unreachable arm `dot`, it comes after `_`")
+    );
+    assert!(
+        error("-- ftd.text: dot\ncase: dot\n\n-- ftd.text: dot\ncase: dot\n")
+            .contains("Unreachable arm `dot`, `dot` is already handled")
+    );
+    assert!(
+        error("-- ftd.text: dot\ncase: dot(d)\n\n-- ftd.text: any\ncase: _\n")
+            .contains("The constant variant `dot` has no value to bind")
+    );
+    assert!(
+        error("-- ftd.text: oval\ncase: oval\n\n-- ftd.text: any\ncase: _\n")
+            .contains("`oval` is not a variant of `foo#shape`")
+    );
+}
+
+#[test]
+fn private_definitions() {
+    let lib = indoc::indoc!(
+        "
+        -- private string greeting: hello
+
+        -- private component label:
+        caption title:
+
+        -- ftd.text: $label.title
+
+        -- end: label
+
+        -- component card:
+
+        -- label: $greeting
+
+        -- end: card
+
+        -- private integer unused: 1
+        "
+    );
+    let modules = [
+        ("lib", lib),
+        ("lib2", "-- import: lib\nexport: greeting, card"),
+    ];
+
+    let document = interpret_helper_with_modules("foo", "-- import: lib\n\n-- lib.card:", &modules);
+    assert!(document.is_ok(), "{:?}", document);
+
+    for source in [
+        "-- import: lib\n\n-- ftd.text: $lib.greeting",
+        "-- import: lib\n\n-- lib.label: hi",
+        "-- import: lib\nexposing: label\n\n-- label: hi",
+        "-- import: lib2\n\n-- ftd.text: $lib2.greeting",
+    ] {
+        let error = interpret_helper_with_modules("foo", source, &modules).unwrap_err();
+        assert!(
+            error.to_string().contains("is private to `lib`"),
+            "{}: {}",
+            source,
+            error
+        );
+    }
+
+    let parsed = ftd::interpreter::ParsedDocument::parse("lib", lib).unwrap();
+    assert_eq!(parsed.private_things, vec!["greeting", "label", "unused"]);
+    assert_eq!(
+        parsed
+            .warnings
+            .iter()
+            .map(|w| w.to_string())
+            .collect::<Vec<_>>(),
+        vec!["lib:16 -> `unused` is private and never used"]
+    );
+
+    let parsed = ftd::interpreter::ParsedDocument::parse(
+        "foo",
+        // a mention in text is not a use
+        "-- import: lib\n-- import: lib2\nexposing: card\n-- import: lib3\n\n-- card:\n\n\
+        -- ftd.text: lib3",
+    )
+    .unwrap();
+    assert_eq!(
+        parsed
+            .warnings
+            .iter()
+            .map(|w| (w.severity, w.to_string()))
+            .collect::<Vec<_>>(),
+        vec![
+            (
+                ftd::diagnostic::Severity::Warning,
+                "foo:1 -> unused import: `lib`".to_string()
+            ),
+            (
+                ftd::diagnostic::Severity::Warning,
+                "foo:4 -> unused import: `lib3`".to_string()
+            )
+        ]
+    );
+
+    let error =
+        ftd::interpreter::ParsedDocument::parse("foo", "-- private ftd.text: hi").unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("can only be used on definitions"));
+}
+
+#[test]
+fn public_definitions_are_the_export_list() {
+    let lib = indoc::indoc!(
+        "
+        -- public component card:
+
+        -- label: $greeting
+
+        -- end: card
+
+        -- string greeting: hello
+
+        -- component label:
+        caption title:
+
+        -- ftd.text: $label.title
+
+        -- end: label
+
+        -- integer sum(a,b):
+        integer a:
+        integer b:
+
+        a + b
+        "
+    );
+    let modules = [("lib", lib)];
+
+    let document = interpret_helper_with_modules("foo", "-- import: lib\n\n-- lib.card:", &modules);
+    assert!(document.is_ok(), "{:?}", document);
+
+    for source in [
+        "-- import: lib\n\n-- ftd.text: $lib.greeting",
+        "-- import: lib\n\n-- lib.label: hi",
+        "-- import: lib\n\n-- ftd.integer: $lib.sum(a = 1, b = 2)",
+    ] {
+        let error = interpret_helper_with_modules("foo", source, &modules).unwrap_err();
+        assert!(
+            error.to_string().contains("is private to `lib`"),
+            "{}: {}",
+            source,
+            error
+        );
+    }
+
+    let parsed = ftd::interpreter::ParsedDocument::parse("lib", lib).unwrap();
+    assert_eq!(parsed.private_things, vec!["greeting", "label", "sum"]);
+    assert_eq!(
+        parsed
+            .warnings
+            .iter()
+            .map(|w| w.to_string())
+            .collect::<Vec<_>>(),
+        vec!["lib:16 -> `sum` is private and never used"]
+    );
+}
+
+#[test]
+fn condition_on_valued_variant() {
+    let source = indoc::indoc!(
+        "
+        -- or-type shape:
+
+        -- decimal circle:
+
+        -- integer square:
+
+        -- end: shape
+
+        -- shape.circle c: 2.0
+
+        -- ftd.text: circle
+        if: { ftd.is_variant($c, \"circle\") }
+
+        -- ftd.text: square
+        if: { ftd.is_variant($c, \"square\") }
+
+        -- ftd.text: big circle
+        if: { ftd.variant_value($c, \"circle\") > 1.0 }
+        "
+    );
+    let document = interpret_helper("foo", source).unwrap_or_else(|e| panic!("{:?}", e));
+    let doc = ftd::interpreter::TDoc::new(&document.name, &document.aliases, &document.data);
+    let conditions = document
+        .tree
+        .iter()
+        .map(|component| {
+            let condition = component.condition.as_ref().as_ref().unwrap();
+            condition.eval(&doc).unwrap()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(conditions, vec![true, false, true]);
+}
@@ -46,7 +46,11 @@ impl ComponentDefinition {
             doc,
         )?;
 
-        Argument::scan_ast_fields(component_definition.arguments, doc, &Default::default())?;
+        let known_kinds = ftd::interpreter::Kind::type_parameters(
+            component_definition.type_parameters.as_slice(),
+        )
+        .collect();
+        Argument::scan_ast_fields(component_definition.arguments, doc, &known_kinds)?;
 
         Ok(())
     }
@@ -75,11 +79,15 @@ impl ComponentDefinition {
             None
         };
 
+        let known_kinds = ftd::interpreter::Kind::type_parameters(
+            component_definition.type_parameters.as_slice(),
+        )
+        .collect();
         let mut arguments = try_ok_state!(Argument::from_ast_fields(
             component_definition.name.as_str(),
             component_definition.arguments,
             doc,
-            &Default::default(),
+            &known_kinds,
         )?);
 
         let definition_name_with_arguments =
@@ -95,6 +103,7 @@ impl ComponentDefinition {
                     .to_string(),
                 doc_id: doc.name.to_string(),
                 line_number: iteration.line_number,
+                span: None,
             });
         }
         Ok(ftd::interpreter::StateWithThing::new_thing(
@@ -210,7 +219,25 @@ impl Component {
         Property::scan_ast_children(ast_component.children, definition_name_with_arguments, doc)?;
         match definition_name_with_arguments {
             Some((definition, _)) if ast_component.name.eq(definition) => {}
-            _ => doc.scan_thing(ast_component.name.as_str(), ast_component.line_number)?,
+            _ => match ftd::ast::utils::split_type_arguments(ast_component.name.as_str()) {
+                Some((name, arguments, _)) => {
+                    doc.scan_thing(name.as_str(), ast_component.line_number)?;
+                    for argument in arguments {
+                        let argument = ftd::ast::VariableKind::get_kind(
+                            argument.as_str(),
+                            doc.name,
+                            ast_component.line_number,
+                        )?;
+                        ftd::interpreter::KindData::scan_ast_kind(
+                            argument,
+                            &Default::default(),
+                            doc,
+                            ast_component.line_number,
+                        )?;
+                    }
+                }
+                None => doc.scan_thing(ast_component.name.as_str(), ast_component.line_number)?,
+            },
         }
 
         let mut loop_object_name_and_kind = None;
@@ -219,6 +246,16 @@ impl Component {
             Loop::scan_ast_loop(v, definition_name_with_arguments, doc)?;
         };
 
+        if let Some(case) = ast_component.case {
+            ftd::interpreter::PropertyValue::scan_string_with_argument(
+                case.match_.subject.as_str(),
+                doc,
+                case.match_.line_number,
+                definition_name_with_arguments,
+                &loop_object_name_and_kind,
+            )?;
+        }
+
         if let Some(v) = ast_component.condition {
             ftd::interpreter::Expression::scan_ast_condition(
                 v,
@@ -258,12 +295,17 @@ impl Component {
         definition_name_with_arguments: &mut Option<(&str, &mut [Argument])>,
         doc: &mut ftd::interpreter::TDoc,
     ) -> ftd::interpreter::Result<ftd::interpreter::StateWithThing<Component>> {
-        let name = doc.resolve_name(ast_component.name.as_str());
+        // `-- list-view<string>:` invokes the generic component `list-view`, the type arguments
+        // only change the kinds of its arguments
+        let component_name = ftd::ast::utils::split_type_arguments(ast_component.name.as_str())
+            .map(|(name, _, _)| name)
+            .unwrap_or_else(|| ast_component.name.to_string());
+        let name = doc.resolve_name(component_name.as_str());
 
         // If the component is from `module` type argument
         ftd::interpreter::utils::insert_module_thing(
             &ftd::interpreter::Kind::ui().into_kind_data(),
-            ast_component.name.as_str(),
+            component_name.as_str(),
             name.as_str(),
             definition_name_with_arguments,
             ast_component.line_number(),
@@ -284,7 +326,18 @@ impl Component {
             None
         };
 
-        let condition = if let Some(v) = ast_component.condition {
+        let ast_condition = match ast_component.case {
+            Some(case) => Some(try_ok_state!(Component::case_condition(
+                case,
+                ast_component.condition,
+                definition_name_with_arguments,
+                &loop_object_name_and_kind,
+                doc,
+            )?)),
+            None => ast_component.condition,
+        };
+
+        let condition = if let Some(v) = ast_condition {
             Some(try_ok_state!(
                 ftd::interpreter::Expression::from_ast_condition(
                     v,
@@ -305,7 +358,7 @@ impl Component {
         )?);
 
         if let Some(component) = try_ok_state!(Component::variable_component_from_ast(
-            ast_component.name.as_str(),
+            component_name.as_str(),
             definition_name_with_arguments,
             doc,
             &iteration,
@@ -349,6 +402,82 @@ impl Component {
         }))
     }
 
+    /// The condition under which a child of `-- match:` is shown: the subject holds the
+    /// variant of its arm, or none of the other variants for `_`, and its own `if` holds.
+    fn case_condition(
+        case: ftd::ast::Case,
+        condition: Option<ftd::ast::Condition>,
+        definition_name_with_arguments: &mut Option<(&str, &mut [Argument])>,
+        loop_object_name_and_kind: &Option<(String, Argument)>,
+        doc: &mut ftd::interpreter::TDoc,
+    ) -> ftd::interpreter::Result<ftd::interpreter::StateWithThing<ftd::ast::Condition>> {
+        let match_ = case.match_;
+        let subject = try_ok_state!(ftd::interpreter::PropertyValue::from_string_with_argument(
+            match_.subject.as_str(),
+            doc,
+            None,
+            false,
+            match_.line_number,
+            definition_name_with_arguments,
+            loop_object_name_and_kind,
+        )?);
+        let or_type_name = match subject.kind() {
+            ftd::interpreter::Kind::OrType { name, .. } => name,
+            kind => {
+                return ftd::interpreter::utils::e2(
+                    format!(
+                        "`match` expects a value of an or-type, `{}` is of kind `{:?}`",
+                        match_.subject, kind
+                    ),
+                    doc.name,
+                    match_.line_number,
+                )
+            }
+        };
+        let or_type = try_ok_state!(doc.search_or_type(or_type_name.as_str(), match_.line_number)?);
+        or_type.check_match(&match_, doc.name)?;
+
+        let subject = match_
+            .subject
+            .trim_start_matches(ftd::ast::utils::REFERENCE);
+        let is_variant = |arm: &ftd::ast::MatchArm| {
+            arm.variant
+                .as_ref()
+                .map(|variant| format!("ftd.is_variant({}, \"{}\")", subject, variant))
+        };
+        let arm = &match_.arms[case.arm];
+        let mut expressions = match is_variant(arm) {
+            Some(expression) => vec![expression],
+            // `_` matches the variants the other arms don't
+            None => match_
+                .arms
+                .iter()
+                .filter_map(is_variant)
+                .map(|expression| format!("!{}", expression))
+                .collect(),
+        };
+        if let Some(condition) = condition.as_ref() {
+            let expression = condition.expression.trim();
+            let expression = expression
+                .strip_prefix('{')
+                .and_then(|e| e.strip_suffix('}'))
+                .unwrap_or(expression);
+            expressions.push(format!("({})", expression.trim()));
+        }
+        let expression = if expressions.is_empty() {
+            "true".to_string()
+        } else {
+            expressions.join(" && ")
+        };
+
+        Ok(ftd::interpreter::StateWithThing::new_thing(
+            ftd::ast::Condition::new(
+                format!("{{ {} }}", expression).as_str(),
+                condition.map_or(arm.line_number, |c| c.line_number),
+            ),
+        ))
+    }
+
     pub fn assert_no_private_properties_while_invocation(
         properties: &[Property],
         arguments: &[Argument],
@@ -674,6 +803,7 @@ impl Property {
                 message: "Subsection is unexpected".to_string(),
                 doc_id: doc.name.to_string(),
                 line_number,
+                span: None,
             },
         )?;
 
@@ -811,6 +941,12 @@ impl Property {
             line_number,
         )?);
 
+        try_ok_state!(search_things_for_table_cells(
+            properties.as_slice(),
+            doc,
+            component_arguments.as_slice(),
+        )?);
+
         Ok(ftd::interpreter::StateWithThing::new_thing(properties))
     }
 
@@ -898,6 +1034,7 @@ impl Property {
                         ),
                         doc_id: doc.name.to_string(),
                         line_number: ast_property.line_number,
+                        span: None,
                     })
                     .map(ToOwned::to_owned)?,
             )),
@@ -912,11 +1049,12 @@ impl Property {
                         ),
                         doc_id: doc.name.to_string(),
                         line_number: ast_property.line_number,
+                        span: None,
                     })
                     .map(ToOwned::to_owned)?,
             )),
             ftd::ast::PropertySource::Header { name, mutable } => {
-                let (name, remaining) = ftd::interpreter::utils::split_at(name, ".");
+                let (name, remaining) = state_argument_name(name, component_argument);
                 let mut argument = component_argument
                     .iter()
                     .find(|v| v.name.eq(name.as_str()))
@@ -927,6 +1065,7 @@ impl Property {
                         ),
                         doc_id: doc.name.to_string(),
                         line_number: ast_property.line_number,
+                        span: None,
                     })?
                     .to_owned();
                 if !argument.mutable.eq(mutable) {
@@ -966,6 +1105,22 @@ impl Property {
     }
 }
 
+/// The argument name and the or-type variant of a header. `background.hover.solid` is the `solid`
+/// variant of the `background.hover` argument, the state is a part of the name.
+fn state_argument_name(name: &str, arguments: &[Argument]) -> (String, Option<String>) {
+    let (name, remaining) = ftd::interpreter::utils::split_at(name, ".");
+    if let Some(ref remaining) = remaining {
+        let (state, variant) = ftd::interpreter::utils::split_at(remaining, ".");
+        let state_name = format!("{}.{}", name, state);
+        if ftd::interpreter::FTD_ELEMENT_STATES.contains(&state.as_str())
+            && arguments.iter().any(|v| v.name.eq(&state_name))
+        {
+            return (state_name, variant);
+        }
+    }
+    (name, remaining)
+}
+
 fn search_things_for_module(
     component_name: &str,
     properties: &[ftd::interpreter::Property],
@@ -1084,6 +1239,54 @@ fn search_things_for_module(
     Ok(ftd::interpreter::StateWithThing::new_thing(()))
 }
 
+/// The `cell` of an `ftd.table-column` names the component that shows the cells of the column,
+/// nothing else refers to that component, so it is searched here.
+fn search_things_for_table_cells(
+    properties: &[ftd::interpreter::Property],
+    doc: &mut ftd::interpreter::TDoc,
+    arguments: &[ftd::interpreter::Argument],
+) -> ftd::interpreter::Result<ftd::interpreter::StateWithThing<()>> {
+    let columns_kind =
+        ftd::interpreter::Kind::record(ftd::interpreter::FTD_TABLE_COLUMN).into_list();
+    for argument in arguments.iter() {
+        if !argument.kind.kind.eq(&columns_kind) {
+            continue;
+        }
+        let sources = argument.to_sources();
+        let properties = ftd::interpreter::utils::find_properties_by_source(
+            sources.as_slice(),
+            properties,
+            doc.name,
+            argument,
+            argument.line_number,
+        )?;
+        for property in properties {
+            // the columns given by an argument of the enclosing component are not known yet
+            let columns = match property.resolve(doc, &Default::default()) {
+                Ok(Some(ftd::interpreter::Value::List { data, .. })) => data,
+                _ => continue,
+            };
+            for column in columns {
+                let line_number = column.line_number();
+                let cell = match column.resolve(doc, line_number)? {
+                    ftd::interpreter::Value::Record { fields, .. } => fields.get("cell").cloned(),
+                    _ => None,
+                };
+                let cell = match cell {
+                    Some(cell) => cell
+                        .resolve(doc, line_number)?
+                        .optional_string(doc.name, line_number)?,
+                    None => None,
+                };
+                if let Some(cell) = cell {
+                    try_ok_state!(doc.search_component(cell.as_str(), line_number)?);
+                }
+            }
+        }
+    }
+    Ok(ftd::interpreter::StateWithThing::new_thing(()))
+}
+
 #[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct Loop {
     pub on: ftd::interpreter::PropertyValue,
@@ -1121,9 +1324,11 @@ impl Loop {
     ) -> ftd::interpreter::Result<ftd::interpreter::Kind> {
         let kind = self.on.kind();
         match kind {
-            ftd::interpreter::Kind::List { kind } => Ok(kind.as_ref().to_owned()),
+            ftd::interpreter::Kind::List { kind } | ftd::interpreter::Kind::Map { kind, .. } => {
+                Ok(kind.as_ref().to_owned())
+            }
             t => ftd::interpreter::utils::e2(
-                format!("Expected list kind, found: {:?}", t),
+                format!("Expected list or map kind, found: {:?}", t),
                 doc_id,
                 self.line_number,
             ),
@@ -1191,14 +1396,35 @@ impl Loop {
         ftd::interpreter::KindData,
     )> {
         let value = self.on.clone().resolve(doc, self.line_number)?;
-        if let ftd::interpreter::Value::List { data, kind } = value {
-            Ok((data, kind))
-        } else {
-            ftd::interpreter::utils::e2(
-                format!("Expected list type data, found: {:?}", self.on),
+        match value {
+            ftd::interpreter::Value::List { data, kind } => Ok((data, kind)),
+            ftd::interpreter::Value::Map { data, key, kind } => Ok((
+                key.map_entries(data).into_iter().map(|v| v.1).collect(),
+                kind,
+            )),
+            _ => ftd::interpreter::utils::e2(
+                format!("Expected list or map type data, found: {:?}", self.on),
                 doc.name,
                 self.line_number,
-            )
+            ),
+        }
+    }
+
+    /// The keys of the entries when looping over a map, in the same order as `children`.
+    pub fn keys(
+        &self,
+        doc: &ftd::interpreter::TDoc,
+    ) -> ftd::interpreter::Result<Option<Vec<ftd::interpreter::Value>>> {
+        let value = self.on.clone().resolve(doc, self.line_number)?;
+        if let ftd::interpreter::Value::Map { data, key, .. } = value {
+            Ok(Some(
+                key.map_entries(data)
+                    .into_iter()
+                    .map(|(k, _)| key.map_key_value(k.as_str()))
+                    .collect(),
+            ))
+        } else {
+            Ok(None)
         }
     }
 }
@@ -1304,6 +1530,11 @@ pub enum EventName {
     Change,
     Blur,
     Focus,
+    Submit,
+    Play,
+    Pause,
+    Ended,
+    TimeUpdate,
     RivePlay(String),
     RiveStateChange(String),
     RivePause(String),
@@ -1326,6 +1557,11 @@ impl EventName {
             "change" => Ok(EventName::Change),
             "blur" => Ok(EventName::Blur),
             "focus" => Ok(EventName::Focus),
+            "submit" => Ok(EventName::Submit),
+            "play" => Ok(EventName::Play),
+            "pause" => Ok(EventName::Pause),
+            "ended" => Ok(EventName::Ended),
+            "time-update" => Ok(EventName::TimeUpdate),
             t if t.starts_with("global-key[") && t.ends_with(']') => {
                 let keys = t
                     .trim_start_matches("global-key[")
@@ -29,10 +29,37 @@ enable light mode in the application.
 4. `enable_system_mode` - This function takes no arguments and returns an empty value. It is used to
 enable system mode in the application, which means the application will use the system's default
 color scheme.
+
+5. `ftd.format_date`, `ftd.relative_time`, `ftd.compare_dates`, `ftd.add_duration` and
+`ftd.date_difference` - These are the `date::` built-in functions of evalexpr, used by the date
+functions of the `ftd` module, e.g. `ftd.format-date`.
+
+6. `ftd.is_variant` and `ftd.variant_value` - These take a value of an or-type defined in an ftd
+document and the name of a variant. The first tells if the value is of that variant, the second
+returns the value the variant holds. `match` over or-types is built on them.
+7. `ftd.split`, `ftd.join`, `ftd.replace`, `ftd.trim`, `ftd.upper`, `ftd.lower`, `ftd.contains`,
+`ftd.starts_with`, `ftd.slice` and `ftd.regex_match` - String functions. `split` with an empty
+separator splits into characters, `replace` replaces every occurrence and `slice` counts
+negative positions from the end, as in JavaScript. `contains` also looks for an item in a list.
+`regex_match` takes the patterns that match the same way in the browser, see
+`fastn_grammar::evalexpr::regex_subset`, patterns written in the document are checked when it is
+interpreted.
+
+8. `ftd.map`, `ftd.filter` and `ftd.sort_by` - These take a list and an expression, as a string,
+which is evaluated for every item with the item as `item`, e.g. `ftd.map(people, "item.name")`.
+In the browser the expression is compiled to a JavaScript function, see `fastn_js::Value`.
+
+9. `ftd.reverse`, `ftd.take`, `ftd.skip`, `ftd.sum`, `ftd.unique` and `ftd.index_of` - List
+functions, `index_of` returns -1 for an item not in the list.
+
+10. `ftd.to_json` and `ftd.from_json` - Encode a value as JSON and decode JSON, records are
+objects with their fields sorted by name.
 **/
 pub fn default_functions() -> ftd::Map<fastn_grammar::evalexpr::Function> {
     use fastn_grammar::evalexpr::*;
 
+    let builtin = |name: &str| builtin_function(name).expect("function is built in");
+
     std::iter::IntoIterator::into_iter([
         (
             "ftd.clean_code".to_string(),
@@ -114,6 +141,113 @@ pub fn default_functions() -> ftd::Map<fastn_grammar::evalexpr::Function> {
                 }
             }),
         ),
+        (
+            "ftd.is_variant".to_string(),
+            Function::new(|argument| {
+                let (variant_value, variant) = or_type_arguments(argument)?;
+                Ok(Value::Boolean(variant_value.contains_key(variant.as_str())))
+            }),
+        ),
+        (
+            "ftd.variant_value".to_string(),
+            Function::new(|argument| {
+                let (mut variant_value, variant) = or_type_arguments(argument)?;
+                Ok(json_to_evalexpr_value(
+                    variant_value
+                        .remove(variant.as_str())
+                        .unwrap_or(serde_json::Value::Null),
+                ))
+            }),
+        ),
+        ("ftd.split".to_string(), builtin("str::split")),
+        ("ftd.join".to_string(), builtin("str::join")),
+        ("ftd.replace".to_string(), builtin("str::replace")),
+        ("ftd.trim".to_string(), builtin("str::trim")),
+        ("ftd.upper".to_string(), builtin("str::to_uppercase")),
+        ("ftd.lower".to_string(), builtin("str::to_lowercase")),
+        ("ftd.contains".to_string(), builtin("str::contains")),
+        ("ftd.starts_with".to_string(), builtin("str::starts_with")),
+        ("ftd.slice".to_string(), builtin("str::slice")),
+        (
+            "ftd.regex_match".to_string(),
+            Function::new(|argument| {
+                let arguments = argument.as_fixed_len_tuple(2)?;
+                let pattern = regex_subset::compile(arguments[1].as_string()?.as_str())?;
+                Ok(Value::Boolean(
+                    pattern.is_match(arguments[0].as_string()?.as_str()),
+                ))
+            }),
+        ),
+        (
+            "ftd.map".to_string(),
+            Function::new(|argument| {
+                let (items, expression) = item_expression_arguments(argument)?;
+                let mut result = vec![];
+                for item in items {
+                    result.push(eval_for_item(&expression, &item)?);
+                }
+                Ok(Value::Tuple(result))
+            }),
+        ),
+        (
+            "ftd.filter".to_string(),
+            Function::new(|argument| {
+                let (items, condition) = item_expression_arguments(argument)?;
+                let mut result = vec![];
+                for item in items {
+                    if eval_for_item(&condition, &item)?.as_boolean()? {
+                        result.push(item);
+                    }
+                }
+                Ok(Value::Tuple(result))
+            }),
+        ),
+        (
+            "ftd.sort_by".to_string(),
+            Function::new(|argument| {
+                let (items, key) = item_expression_arguments(argument)?;
+                let mut keyed = vec![];
+                for item in items {
+                    keyed.push((eval_for_item(&key, &item)?, item));
+                }
+                keyed.sort_by(|(a, _), (b, _)| collections::compare(a, b));
+                Ok(Value::Tuple(
+                    keyed.into_iter().map(|(_, item)| item).collect(),
+                ))
+            }),
+        ),
+        ("ftd.reverse".to_string(), builtin("list::reverse")),
+        ("ftd.take".to_string(), builtin("list::take")),
+        ("ftd.skip".to_string(), builtin("list::skip")),
+        ("ftd.sum".to_string(), builtin("list::sum")),
+        ("ftd.unique".to_string(), builtin("list::unique")),
+        ("ftd.index_of".to_string(), builtin("list::index_of")),
+        (
+            "ftd.to_json".to_string(),
+            Function::new(|argument| {
+                Ok(Value::String(
+                    evalexpr_value_to_json(argument.clone()).to_string(),
+                ))
+            }),
+        ),
+        (
+            "ftd.from_json".to_string(),
+            Function::new(|argument| {
+                let json = argument.as_string()?;
+                match serde_json::from_str(json.as_str()) {
+                    Ok(value) => Ok(json_to_evalexpr_value(value)),
+                    Err(err) => Err(EvalexprError::CustomMessage(format!(
+                        "Invalid json `{}`: {}",
+                        json, err
+                    ))),
+                }
+            }),
+        ),
+        ("ftd.format_date".to_string(), builtin("date::format")),
+        ("ftd.relative_time".to_string(), builtin("date::relative")),
+        ("ftd.compare_dates".to_string(), builtin("date::compare")),
+        ("ftd.add_duration".to_string(), builtin("date::add")),
+        ("ftd.date_difference".to_string(), builtin("date::diff")),
         (
             "enable_dark_mode".to_string(),
             Function::new(|_| Ok(Value::Empty)),
@@ -130,6 +264,111 @@ pub fn default_functions() -> ftd::Map<fastn_grammar::evalexpr::Function> {
     .collect()
 }
 
+/// The value of an or-type, `{"circle": 2.0}`, and the name of a variant.
+fn or_type_arguments(
+    argument: &fastn_grammar::evalexpr::Value,
+) -> fastn_grammar::evalexpr::EvalexprResult<(serde_json::Map<String, serde_json::Value>, String)> {
+    let arguments = argument.as_fixed_len_tuple(2)?;
+    let value = arguments[0].as_string()?;
+    let variant = arguments[1].as_string()?;
+    match serde_json::from_str(value.as_str()) {
+        Ok(serde_json::Value::Object(variant_value)) => Ok((variant_value, variant)),
+        _ => Err(fastn_grammar::evalexpr::EvalexprError::CustomMessage(
+            format!("Expected a value of an or-type, found: `{}`", value),
+        )),
+    }
+}
+
+/// The list and the parsed expression given to `ftd.map`, `ftd.filter` and `ftd.sort_by`.
+fn item_expression_arguments(
+    argument: &fastn_grammar::evalexpr::Value,
+) -> fastn_grammar::evalexpr::EvalexprResult<(
+    Vec<fastn_grammar::evalexpr::Value>,
+    fastn_grammar::evalexpr::ExprNode,
+)> {
+    let arguments = argument.as_fixed_len_tuple(2)?;
+    Ok((
+        fastn_grammar::evalexpr::collections::as_list(&arguments[0])?,
+        fastn_grammar::evalexpr::build_operator_tree(arguments[1].as_string()?.as_str())?,
+    ))
+}
+
+/// Evaluates `expression` with `item` set to an item of the list. The fields of a record item
+/// are set too, so that `item.name` reads the `name` field.
+fn eval_for_item(
+    expression: &fastn_grammar::evalexpr::ExprNode,
+    item: &fastn_grammar::evalexpr::Value,
+) -> fastn_grammar::evalexpr::EvalexprResult<fastn_grammar::evalexpr::Value> {
+    fn set_item(
+        context: &mut fastn_grammar::evalexpr::HashMapContext,
+        name: &str,
+        value: &fastn_grammar::evalexpr::Value,
+    ) -> fastn_grammar::evalexpr::EvalexprResult<()> {
+        use fastn_grammar::evalexpr::ContextWithMutableVariables;
+
+        context.set_value(name.to_string(), value.clone())?;
+        if let fastn_grammar::evalexpr::Value::String(value) = value {
+            if let Ok(serde_json::Value::Object(fields)) = serde_json::from_str(value.as_str()) {
+                for (field, value) in fields {
+                    set_item(
+                        context,
+                        format!("{}.{}", name, field).as_str(),
+                        &json_to_evalexpr_value(value),
+                    )?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    let mut context = default_context()
+        .map_err(|e| fastn_grammar::evalexpr::EvalexprError::CustomMessage(e.to_string()))?;
+    set_item(&mut context, "item", item)?;
+    expression.eval_with_context(&context)
+}
+
+/// The inverse of `json_to_evalexpr_value`: strings holding a JSON object, i.e. records, are
+/// embedded as objects. Whole decimals are written as integers, as JavaScript does.
+pub(crate) fn evalexpr_value_to_json(value: fastn_grammar::evalexpr::Value) -> serde_json::Value {
+    use fastn_grammar::evalexpr::Value;
+
+    match value {
+        Value::Empty => serde_json::Value::Null,
+        Value::Boolean(value) => serde_json::Value::Bool(value),
+        Value::Int(value) => serde_json::Value::from(value),
+        Value::Float(value) if value.fract() == 0.0 && value.abs() < i64::MAX as f64 => {
+            serde_json::Value::from(value as i64)
+        }
+        Value::Float(value) => serde_json::Value::from(value),
+        Value::String(value) => match serde_json::from_str(value.as_str()) {
+            Ok(object @ serde_json::Value::Object(_)) => object,
+            _ => serde_json::Value::String(value),
+        },
+        Value::Tuple(values) => {
+            serde_json::Value::Array(values.into_iter().map(evalexpr_value_to_json).collect())
+        }
+    }
+}
+
+/// Records stay JSON strings, as in `Value::into_evalexpr_value`.
+pub(crate) fn json_to_evalexpr_value(value: serde_json::Value) -> fastn_grammar::evalexpr::Value {
+    use fastn_grammar::evalexpr::Value;
+
+    match value {
+        serde_json::Value::Null => Value::Empty,
+        serde_json::Value::Bool(value) => Value::Boolean(value),
+        serde_json::Value::Number(value) => match value.as_i64() {
+            Some(value) => Value::Int(value),
+            None => Value::Float(value.as_f64().unwrap_or_default()),
+        },
+        serde_json::Value::String(value) => Value::String(value),
+        serde_json::Value::Array(values) => {
+            Value::Tuple(values.into_iter().map(json_to_evalexpr_value).collect())
+        }
+        value @ serde_json::Value::Object(_) => Value::String(value.to_string()),
+    }
+}
+
 pub fn default_context() -> ftd::interpreter::Result<fastn_grammar::evalexpr::HashMapContext> {
     let mut context = fastn_grammar::evalexpr::HashMapContext::new();
     for (key, function) in default_functions() {
@@ -138,6 +377,61 @@ pub fn default_context() -> ftd::interpreter::Result<fastn_grammar::evalexpr::Ha
     Ok(context)
 }
 
+/// The `ftd.responsive-type` values of `ftd#default-types` are written for desktop and mobile,
+/// tablet and wide screens use the desktop value.
+fn with_tablet_and_wide(
+    mut fields: ftd::Map<ftd::interpreter::PropertyValue>,
+) -> ftd::Map<ftd::interpreter::PropertyValue> {
+    if let Some(desktop) = fields.get("desktop").cloned() {
+        for device in ["tablet", "wide"] {
+            fields
+                .entry(device.to_string())
+                .or_insert_with(|| desktop.clone());
+        }
+    }
+    fields
+}
+
+/// The value of `ftd#breakpoints`, the breakpoints are `(name, max-width)` from the narrowest to
+/// the widest.
+pub fn breakpoints_value(
+    breakpoints: &[(&str, Option<i64>)],
+    line_number: usize,
+) -> ftd::interpreter::PropertyValue {
+    ftd::interpreter::Value::List {
+        data: breakpoints
+            .iter()
+            .map(|(name, max_width)| {
+                ftd::interpreter::Value::Record {
+                    name: ftd::interpreter::FTD_BREAKPOINT.to_string(),
+                    fields: std::iter::IntoIterator::into_iter([
+                        (
+                            "name".to_string(),
+                            ftd::interpreter::Value::new_string(name)
+                                .into_property_value(false, line_number),
+                        ),
+                        (
+                            "max-width".to_string(),
+                            ftd::interpreter::Value::Optional {
+                                data: Box::new(
+                                    max_width
+                                        .map(|value| ftd::interpreter::Value::Integer { value }),
+                                ),
+                                kind: ftd::interpreter::Kind::integer().into_kind_data(),
+                            }
+                            .into_property_value(false, line_number),
+                        ),
+                    ])
+                    .collect(),
+                }
+                .into_property_value(false, line_number)
+            })
+            .collect(),
+        kind: ftd::interpreter::Kind::record(ftd::interpreter::FTD_BREAKPOINT).into_kind_data(),
+    }
+    .into_property_value(true, line_number)
+}
+
 /**
 The `default_bag` function is a public function that returns a `Map` of `Thing`s.
 
@@ -148,7 +442,7 @@ themselves.
 pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
     let record = |n: &str, r: &str| (n.to_string(), ftd::interpreter::Kind::record(r));
     let _color = |n: &str| record(n, "ftd#color");
-    std::iter::IntoIterator::into_iter([
+    let mut bag: ftd::Map<ftd::interpreter::Thing> = std::iter::IntoIterator::into_iter([
         (
             "ftd#row".to_string(),
             ftd::interpreter::Thing::Component(row_function()),
@@ -904,6 +1198,56 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
                         access_modifier: Default::default(),
                         line_number: 0,
                     },
+                    ftd::interpreter::Field {
+                        name: "width".to_string(),
+                        kind: ftd::interpreter::Kind::integer()
+                            .into_optional()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                    ftd::interpreter::Field {
+                        name: "height".to_string(),
+                        kind: ftd::interpreter::Kind::integer()
+                            .into_optional()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                    ftd::interpreter::Field {
+                        name: "srcset".to_string(),
+                        kind: ftd::interpreter::Kind::string()
+                            .into_optional()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                    ftd::interpreter::Field {
+                        name: "avif-srcset".to_string(),
+                        kind: ftd::interpreter::Kind::string()
+                            .into_optional()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                    ftd::interpreter::Field {
+                        name: "placeholder".to_string(),
+                        kind: ftd::interpreter::Kind::string()
+                            .into_optional()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
                 ])
                 .collect(),
                 line_number: 0,
@@ -2707,6 +3051,40 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
                         }),
                         line_number: 0,
                     },
+                    ftd::interpreter::Field {
+                        name: "tablet".to_string(),
+                        kind: ftd::interpreter::Kind::or_type(ftd::interpreter::FTD_LENGTH)
+                            .into_kind_data(),
+                        mutable: false,
+                        access_modifier: Default::default(),
+                        value: Some(ftd::interpreter::PropertyValue::Reference {
+                            name: ftd::interpreter::FTD_RESPONSIVE_LENGTH_DESKTOP.to_string(),
+                            kind: ftd::interpreter::Kind::string().into_kind_data(),
+                            source: ftd::interpreter::PropertyValueSource::Local(
+                                ftd::interpreter::FTD_RESPONSIVE_LENGTH.to_string(),
+                            ),
+                            is_mutable: false,
+                            line_number: 0,
+                        }),
+                        line_number: 0,
+                    },
+                    ftd::interpreter::Field {
+                        name: "wide".to_string(),
+                        kind: ftd::interpreter::Kind::or_type(ftd::interpreter::FTD_LENGTH)
+                            .into_kind_data(),
+                        mutable: false,
+                        access_modifier: Default::default(),
+                        value: Some(ftd::interpreter::PropertyValue::Reference {
+                            name: ftd::interpreter::FTD_RESPONSIVE_LENGTH_DESKTOP.to_string(),
+                            kind: ftd::interpreter::Kind::string().into_kind_data(),
+                            source: ftd::interpreter::PropertyValueSource::Local(
+                                ftd::interpreter::FTD_RESPONSIVE_LENGTH.to_string(),
+                            ),
+                            is_mutable: false,
+                            line_number: 0,
+                        }),
+                        line_number: 0,
+                    },
                 ])
                 .collect(),
                 line_number: 0,
@@ -2818,118 +3196,117 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
             }),
         ),
         (
-            ftd::interpreter::FTD_TEXT_INPUT_TYPE.to_string(),
+            ftd::interpreter::FTD_LOADING.to_string(),
             ftd::interpreter::Thing::OrType(ftd::interpreter::OrType {
-                name: ftd::interpreter::FTD_TEXT_INPUT_TYPE.to_string(),
+                name: ftd::interpreter::FTD_LOADING.to_string(),
                 variants: vec![
                     ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
-                        ftd::interpreter::FTD_TEXT_INPUT_TYPE_TEXT,
+                        ftd::interpreter::FTD_LOADING_EAGER,
                         ftd::interpreter::Kind::string()
                             .into_kind_data()
                             .caption(),
                         false,
-                        Some(   ftd::interpreter::Value::new_string("text")
+                        Some(   ftd::interpreter::Value::new_string("eager")
                                     .into_property_value(false, 0),),
                         0,
                     )),
                     ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
-                        ftd::interpreter::FTD_TEXT_INPUT_TYPE_EMAIL,
-                        ftd::interpreter::Kind::string()
-                            .into_kind_data()
-                            .caption(),
-                        false,
-                        Some(ftd::interpreter::Value::new_string("email")
-                            .into_property_value(false, 0)),
-                        0,
-                    )),
-                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
-                        ftd::interpreter::FTD_TEXT_INPUT_TYPE_PASSWORD,
+                        ftd::interpreter::FTD_LOADING_LAZY,
                         ftd::interpreter::Kind::string()
                             .into_kind_data()
                             .caption(),
                         false,
-                        Some(ftd::interpreter::Value::new_string("password")
+                        Some(ftd::interpreter::Value::new_string("lazy")
                             .into_property_value(false, 0)),
                         0,
                     )),
+                ],
+                line_number: 0,
+            }),
+        ),
+        (
+            ftd::interpreter::FTD_BORDER_STYLE.to_string(),
+            ftd::interpreter::Thing::OrType(ftd::interpreter::OrType {
+                name: ftd::interpreter::FTD_BORDER_STYLE.to_string(),
+                variants: vec![
                     ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
-                        ftd::interpreter::FTD_TEXT_INPUT_TYPE_URL,
+                        ftd::interpreter::FTD_BORDER_STYLE_DASHED,
                         ftd::interpreter::Kind::string()
                             .into_kind_data()
                             .caption(),
                         false,
-                        Some(ftd::interpreter::Value::new_string("url")
-                            .into_property_value(false, 0)),
+                        Some(   ftd::interpreter::Value::new_string("dashed")
+                                    .into_property_value(false, 0),),
                         0,
                     )),
                     ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
-                        ftd::interpreter::FTD_TEXT_INPUT_TYPE_DATETIME,
+                        ftd::interpreter::FTD_BORDER_STYLE_DOTTED,
                         ftd::interpreter::Kind::string()
                             .into_kind_data()
                             .caption(),
                         false,
-                        Some(ftd::interpreter::Value::new_string("datetime-local")
-                            .into_property_value(false, 0)),
+                        Some(   ftd::interpreter::Value::new_string("dotted")
+                                    .into_property_value(false, 0),),
                         0,
                     )),
                     ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
-                        ftd::interpreter::FTD_TEXT_INPUT_TYPE_DATE,
+                        ftd::interpreter::FTD_BORDER_STYLE_DOUBLE,
                         ftd::interpreter::Kind::string()
                             .into_kind_data()
                             .caption(),
                         false,
-                        Some(ftd::interpreter::Value::new_string("date")
-                            .into_property_value(false, 0)),
+                        Some(   ftd::interpreter::Value::new_string("double")
+                                    .into_property_value(false, 0),),
                         0,
                     )),
                     ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
-                        ftd::interpreter::FTD_TEXT_INPUT_TYPE_TIME,
+                        ftd::interpreter::FTD_BORDER_STYLE_GROOVE,
                         ftd::interpreter::Kind::string()
                             .into_kind_data()
                             .caption(),
                         false,
-                        Some(ftd::interpreter::Value::new_string("time")
-                            .into_property_value(false, 0)),
+                        Some(   ftd::interpreter::Value::new_string("groove")
+                                    .into_property_value(false, 0),),
                         0,
                     )),
                     ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
-                        ftd::interpreter::FTD_TEXT_INPUT_TYPE_MONTH,
+                        ftd::interpreter::FTD_BORDER_STYLE_INSET,
                         ftd::interpreter::Kind::string()
                             .into_kind_data()
                             .caption(),
                         false,
-                        Some(ftd::interpreter::Value::new_string("month")
-                            .into_property_value(false, 0)),
+                        Some(   ftd::interpreter::Value::new_string("inset")
+                                    .into_property_value(false, 0),),
                         0,
                     )),
                     ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
-                        ftd::interpreter::FTD_TEXT_INPUT_TYPE_WEEK,
+                        ftd::interpreter::FTD_BORDER_STYLE_OUTSET,
                         ftd::interpreter::Kind::string()
                             .into_kind_data()
                             .caption(),
                         false,
-                        Some(ftd::interpreter::Value::new_string("week")
-                            .into_property_value(false, 0)),
+                        Some(   ftd::interpreter::Value::new_string("outset")
+                                    .into_property_value(false, 0),),
                         0,
                     )),
                     ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
-                        ftd::interpreter::FTD_TEXT_INPUT_TYPE_COLOR,
+                        ftd::interpreter::FTD_BORDER_STYLE_RIDGE,
                         ftd::interpreter::Kind::string()
                             .into_kind_data()
                             .caption(),
                         false,
-                        Some(ftd::interpreter::Value::new_string("color")
-                            .into_property_value(false, 0)),
+                        Some(   ftd::interpreter::Value::new_string("ridge")
+                                    .into_property_value(false, 0),),
                         0,
                     )),
                     ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
-                        ftd::interpreter::FTD_TEXT_INPUT_TYPE_FILE,
+                        ftd::interpreter::FTD_BORDER_STYLE_SOLID,
                         ftd::interpreter::Kind::string()
                             .into_kind_data()
                             .caption(),
                         false,
-                        Some(ftd::interpreter::Value::new_string("file")
-                            .into_property_value(false, 0)),
+                        Some(   ftd::interpreter::Value::new_string("solid")
+                                    .into_property_value(false, 0),),
                         0,
                     )),
                 ],
@@ -2937,162 +3314,44 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
             }),
         ),
         (
-            ftd::interpreter::FTD_LOADING.to_string(),
+            ftd::interpreter::FTD_TEXT_STYLE.to_string(),
             ftd::interpreter::Thing::OrType(ftd::interpreter::OrType {
-                name: ftd::interpreter::FTD_LOADING.to_string(),
+                name: ftd::interpreter::FTD_TEXT_STYLE.to_string(),
                 variants: vec![
                     ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
-                        ftd::interpreter::FTD_LOADING_EAGER,
+                        ftd::interpreter::FTD_TEXT_STYLE_UNDERLINE,
                         ftd::interpreter::Kind::string()
                             .into_kind_data()
                             .caption(),
                         false,
-                        Some(   ftd::interpreter::Value::new_string("eager")
-                                    .into_property_value(false, 0),),
+                        Some(ftd::interpreter::Value::new_string("underline").into_property_value(false, 0),),
                         0,
                     )),
                     ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
-                        ftd::interpreter::FTD_LOADING_LAZY,
+                        ftd::interpreter::FTD_TEXT_STYLE_STRIKE,
                         ftd::interpreter::Kind::string()
                             .into_kind_data()
                             .caption(),
                         false,
-                        Some(ftd::interpreter::Value::new_string("lazy")
-                            .into_property_value(false, 0)),
+                        Some(ftd::interpreter::Value::new_string("strike").into_property_value(false, 0),),
                         0,
                     )),
-                ],
-                line_number: 0,
-            }),
-        ),
-        (
-            ftd::interpreter::FTD_BORDER_STYLE.to_string(),
-            ftd::interpreter::Thing::OrType(ftd::interpreter::OrType {
-                name: ftd::interpreter::FTD_BORDER_STYLE.to_string(),
-                variants: vec![
                     ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
-                        ftd::interpreter::FTD_BORDER_STYLE_DASHED,
+                        ftd::interpreter::FTD_TEXT_STYLE_ITALIC,
                         ftd::interpreter::Kind::string()
                             .into_kind_data()
                             .caption(),
                         false,
-                        Some(   ftd::interpreter::Value::new_string("dashed")
-                                    .into_property_value(false, 0),),
+                        Some(ftd::interpreter::Value::new_string("italic").into_property_value(false, 0),),
                         0,
                     )),
                     ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
-                        ftd::interpreter::FTD_BORDER_STYLE_DOTTED,
+                        ftd::interpreter::FTD_TEXT_STYLE_WEIGHT_HEAVY,
                         ftd::interpreter::Kind::string()
                             .into_kind_data()
                             .caption(),
                         false,
-                        Some(   ftd::interpreter::Value::new_string("dotted")
-                                    .into_property_value(false, 0),),
-                        0,
-                    )),
-                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
-                        ftd::interpreter::FTD_BORDER_STYLE_DOUBLE,
-                        ftd::interpreter::Kind::string()
-                            .into_kind_data()
-                            .caption(),
-                        false,
-                        Some(   ftd::interpreter::Value::new_string("double")
-                                    .into_property_value(false, 0),),
-                        0,
-                    )),
-                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
-                        ftd::interpreter::FTD_BORDER_STYLE_GROOVE,
-                        ftd::interpreter::Kind::string()
-                            .into_kind_data()
-                            .caption(),
-                        false,
-                        Some(   ftd::interpreter::Value::new_string("groove")
-                                    .into_property_value(false, 0),),
-                        0,
-                    )),
-                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
-                        ftd::interpreter::FTD_BORDER_STYLE_INSET,
-                        ftd::interpreter::Kind::string()
-                            .into_kind_data()
-                            .caption(),
-                        false,
-                        Some(   ftd::interpreter::Value::new_string("inset")
-                                    .into_property_value(false, 0),),
-                        0,
-                    )),
-                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
-                        ftd::interpreter::FTD_BORDER_STYLE_OUTSET,
-                        ftd::interpreter::Kind::string()
-                            .into_kind_data()
-                            .caption(),
-                        false,
-                        Some(   ftd::interpreter::Value::new_string("outset")
-                                    .into_property_value(false, 0),),
-                        0,
-                    )),
-                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
-                        ftd::interpreter::FTD_BORDER_STYLE_RIDGE,
-                        ftd::interpreter::Kind::string()
-                            .into_kind_data()
-                            .caption(),
-                        false,
-                        Some(   ftd::interpreter::Value::new_string("ridge")
-                                    .into_property_value(false, 0),),
-                        0,
-                    )),
-                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
-                        ftd::interpreter::FTD_BORDER_STYLE_SOLID,
-                        ftd::interpreter::Kind::string()
-                            .into_kind_data()
-                            .caption(),
-                        false,
-                        Some(   ftd::interpreter::Value::new_string("solid")
-                                    .into_property_value(false, 0),),
-                        0,
-                    )),
-                ],
-                line_number: 0,
-            }),
-        ),
-        (
-            ftd::interpreter::FTD_TEXT_STYLE.to_string(),
-            ftd::interpreter::Thing::OrType(ftd::interpreter::OrType {
-                name: ftd::interpreter::FTD_TEXT_STYLE.to_string(),
-                variants: vec![
-                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
-                        ftd::interpreter::FTD_TEXT_STYLE_UNDERLINE,
-                        ftd::interpreter::Kind::string()
-                            .into_kind_data()
-                            .caption(),
-                        false,
-                        Some(ftd::interpreter::Value::new_string("underline").into_property_value(false, 0),),
-                        0,
-                    )),
-                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
-                        ftd::interpreter::FTD_TEXT_STYLE_STRIKE,
-                        ftd::interpreter::Kind::string()
-                            .into_kind_data()
-                            .caption(),
-                        false,
-                        Some(ftd::interpreter::Value::new_string("strike").into_property_value(false, 0),),
-                        0,
-                    )),
-                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
-                        ftd::interpreter::FTD_TEXT_STYLE_ITALIC,
-                        ftd::interpreter::Kind::string()
-                            .into_kind_data()
-                            .caption(),
-                        false,
-                        Some(ftd::interpreter::Value::new_string("italic").into_property_value(false, 0),),
-                        0,
-                    )),
-                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
-                        ftd::interpreter::FTD_TEXT_STYLE_WEIGHT_HEAVY,
-                        ftd::interpreter::Kind::string()
-                            .into_kind_data()
-                            .caption(),
-                        false,
-                        Some(ftd::interpreter::Value::new_string("heavy").into_property_value(false, 0),),
+                        Some(ftd::interpreter::Value::new_string("heavy").into_property_value(false, 0),),
                         0,
                     )),
                     ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
@@ -3332,6 +3591,42 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
                         }),
                         line_number: 0,
                     },
+                    ftd::interpreter::Field {
+                        name: "tablet".to_string(),
+                        kind: ftd::interpreter::Kind::record(ftd::interpreter::FTD_TYPE)
+                            .into_kind_data(),
+                        mutable: false,
+                        access_modifier: Default::default(),
+                        value: Some(ftd::interpreter::PropertyValue::Reference {
+                            name: ftd::interpreter::FTD_RESPONSIVE_TYPE_DESKTOP.to_string(),
+                            kind: ftd::interpreter::Kind::record(ftd::interpreter::FTD_TYPE)
+                                .into_kind_data(),
+                            source: ftd::interpreter::PropertyValueSource::Local(
+                                ftd::interpreter::FTD_RESPONSIVE_TYPE.to_string(),
+                            ),
+                            is_mutable: false,
+                            line_number: 0,
+                        }),
+                        line_number: 0,
+                    },
+                    ftd::interpreter::Field {
+                        name: "wide".to_string(),
+                        kind: ftd::interpreter::Kind::record(ftd::interpreter::FTD_TYPE)
+                            .into_kind_data(),
+                        mutable: false,
+                        access_modifier: Default::default(),
+                        value: Some(ftd::interpreter::PropertyValue::Reference {
+                            name: ftd::interpreter::FTD_RESPONSIVE_TYPE_DESKTOP.to_string(),
+                            kind: ftd::interpreter::Kind::record(ftd::interpreter::FTD_TYPE)
+                                .into_kind_data(),
+                            source: ftd::interpreter::PropertyValueSource::Local(
+                                ftd::interpreter::FTD_RESPONSIVE_TYPE.to_string(),
+                            ),
+                            is_mutable: false,
+                            line_number: 0,
+                        }),
+                        line_number: 0,
+                    },
                 ])
                 .collect(),
                 line_number: 0,
@@ -4060,7 +4355,7 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
                                 ftd::interpreter::PropertyValue::Value {
                                     value: ftd::interpreter::Value::Record {
                                         name: ftd::interpreter::FTD_RESPONSIVE_TYPE.to_string(),
-                                        fields: std::iter::IntoIterator::into_iter([
+                                        fields: with_tablet_and_wide(std::iter::IntoIterator::into_iter([
                                             (
                                                 "desktop".to_string(),
                                                 ftd::interpreter::PropertyValue::Value {
@@ -4210,7 +4505,7 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
                                                     line_number: 0
                                                 }
                                             )
-                                        ]).collect()
+                                        ]).collect())
                                     },
                                     is_mutable: false,
                                     line_number: 0
@@ -4221,7 +4516,7 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
                                 ftd::interpreter::PropertyValue::Value {
                                     value: ftd::interpreter::Value::Record {
                                         name: ftd::interpreter::FTD_RESPONSIVE_TYPE.to_string(),
-                                        fields: std::iter::IntoIterator::into_iter([
+                                        fields: with_tablet_and_wide(std::iter::IntoIterator::into_iter([
                                             (
                                                 "desktop".to_string(),
                                                 ftd::interpreter::PropertyValue::Value {
@@ -4371,7 +4666,7 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
                                                     line_number: 0
                                                 }
                                             )
-                                        ]).collect()
+                                        ]).collect())
                                     },
                                     is_mutable: false,
                                     line_number: 0
@@ -4382,7 +4677,7 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
                                 ftd::interpreter::PropertyValue::Value {
                                     value: ftd::interpreter::Value::Record {
                                         name: ftd::interpreter::FTD_RESPONSIVE_TYPE.to_string(),
-                                        fields: std::iter::IntoIterator::into_iter([
+                                        fields: with_tablet_and_wide(std::iter::IntoIterator::into_iter([
                                             (
                                                 "desktop".to_string(),
                                                 ftd::interpreter::PropertyValue::Value {
@@ -4532,7 +4827,7 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
                                                     line_number: 0
                                                 }
                                             )
-                                        ]).collect()
+                                        ]).collect())
                                     },
                                     is_mutable: false,
                                     line_number: 0
@@ -4543,7 +4838,7 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
                                 ftd::interpreter::PropertyValue::Value {
                                     value: ftd::interpreter::Value::Record {
                                         name: ftd::interpreter::FTD_RESPONSIVE_TYPE.to_string(),
-                                        fields: std::iter::IntoIterator::into_iter([
+                                        fields: with_tablet_and_wide(std::iter::IntoIterator::into_iter([
                                             (
                                                 "desktop".to_string(),
                                                 ftd::interpreter::PropertyValue::Value {
@@ -4693,7 +4988,7 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
                                                     line_number: 0
                                                 }
                                             )
-                                        ]).collect()
+                                        ]).collect())
                                     },
                                     is_mutable: false,
                                     line_number: 0
@@ -4704,7 +4999,7 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
                                 ftd::interpreter::PropertyValue::Value {
                                     value: ftd::interpreter::Value::Record {
                                         name: ftd::interpreter::FTD_RESPONSIVE_TYPE.to_string(),
-                                        fields: std::iter::IntoIterator::into_iter([
+                                        fields: with_tablet_and_wide(std::iter::IntoIterator::into_iter([
                                             (
                                                 "desktop".to_string(),
                                                 ftd::interpreter::PropertyValue::Value {
@@ -4854,7 +5149,7 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
                                                     line_number: 0
                                                 }
                                             )
-                                        ]).collect()
+                                        ]).collect())
                                     },
                                     is_mutable: false,
                                     line_number: 0
@@ -4866,7 +5161,7 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
                                 ftd::interpreter::PropertyValue::Value {
                                     value: ftd::interpreter::Value::Record {
                                         name: ftd::interpreter::FTD_RESPONSIVE_TYPE.to_string(),
-                                        fields: std::iter::IntoIterator::into_iter([
+                                        fields: with_tablet_and_wide(std::iter::IntoIterator::into_iter([
                                             (
                                                 "desktop".to_string(),
                                                 ftd::interpreter::PropertyValue::Value {
@@ -5016,7 +5311,7 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
                                                     line_number: 0
                                                 }
                                             )
-                                        ]).collect()
+                                        ]).collect())
                                     },
                                     is_mutable: false,
                                     line_number: 0
@@ -5027,7 +5322,7 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
                                 ftd::interpreter::PropertyValue::Value {
                                     value: ftd::interpreter::Value::Record {
                                         name: ftd::interpreter::FTD_RESPONSIVE_TYPE.to_string(),
-                                        fields: std::iter::IntoIterator::into_iter([
+                                        fields: with_tablet_and_wide(std::iter::IntoIterator::into_iter([
                                             (
                                                 "desktop".to_string(),
                                                 ftd::interpreter::PropertyValue::Value {
@@ -5177,7 +5472,7 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
                                                     line_number: 0
                                                 }
                                             )
-                                        ]).collect()
+                                        ]).collect())
                                     },
                                     is_mutable: false,
                                     line_number: 0
@@ -5188,7 +5483,7 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
                                 ftd::interpreter::PropertyValue::Value {
                                     value: ftd::interpreter::Value::Record {
                                         name: ftd::interpreter::FTD_RESPONSIVE_TYPE.to_string(),
-                                        fields: std::iter::IntoIterator::into_iter([
+                                        fields: with_tablet_and_wide(std::iter::IntoIterator::into_iter([
                                             (
                                                 "desktop".to_string(),
                                                 ftd::interpreter::PropertyValue::Value {
@@ -5338,7 +5633,7 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
                                                     line_number: 0
                                                 }
                                             )
-                                        ]).collect()
+                                        ]).collect())
                                     },
                                     is_mutable: false,
                                     line_number: 0
@@ -5350,7 +5645,7 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
                                 ftd::interpreter::PropertyValue::Value {
                                     value: ftd::interpreter::Value::Record {
                                         name: ftd::interpreter::FTD_RESPONSIVE_TYPE.to_string(),
-                                        fields: std::iter::IntoIterator::into_iter([
+                                        fields: with_tablet_and_wide(std::iter::IntoIterator::into_iter([
                                             (
                                                 "desktop".to_string(),
                                                 ftd::interpreter::PropertyValue::Value {
@@ -5500,7 +5795,7 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
                                                     line_number: 0
                                                 }
                                             )
-                                        ]).collect()
+                                        ]).collect())
                                     },
                                     is_mutable: false,
                                     line_number: 0
@@ -5511,7 +5806,7 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
                                 ftd::interpreter::PropertyValue::Value {
                                     value: ftd::interpreter::Value::Record {
                                         name: ftd::interpreter::FTD_RESPONSIVE_TYPE.to_string(),
-                                        fields: std::iter::IntoIterator::into_iter([
+                                        fields: with_tablet_and_wide(std::iter::IntoIterator::into_iter([
                                             (
                                                 "desktop".to_string(),
                                                 ftd::interpreter::PropertyValue::Value {
@@ -5661,7 +5956,7 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
                                                     line_number: 0
                                                 }
                                             )
-                                        ]).collect()
+                                        ]).collect())
                                     },
                                     is_mutable: false,
                                     line_number: 0
@@ -5672,7 +5967,7 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
                                 ftd::interpreter::PropertyValue::Value {
                                     value: ftd::interpreter::Value::Record {
                                         name: ftd::interpreter::FTD_RESPONSIVE_TYPE.to_string(),
-                                        fields: std::iter::IntoIterator::into_iter([
+                                        fields: with_tablet_and_wide(std::iter::IntoIterator::into_iter([
                                             (
                                                 "desktop".to_string(),
                                                 ftd::interpreter::PropertyValue::Value {
@@ -5822,7 +6117,7 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
                                                     line_number: 0
                                                 }
                                             )
-                                        ]).collect()
+                                        ]).collect())
                                     },
                                     is_mutable: false,
                                     line_number: 0
@@ -5834,7 +6129,7 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
                                 ftd::interpreter::PropertyValue::Value {
                                     value: ftd::interpreter::Value::Record {
                                         name: ftd::interpreter::FTD_RESPONSIVE_TYPE.to_string(),
-                                        fields: std::iter::IntoIterator::into_iter([
+                                        fields: with_tablet_and_wide(std::iter::IntoIterator::into_iter([
                                             (
                                                 "desktop".to_string(),
                                                 ftd::interpreter::PropertyValue::Value {
@@ -5984,7 +6279,7 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
                                                     line_number: 0
                                                 }
                                             )
-                                        ]).collect()
+                                        ]).collect())
                                     },
                                     is_mutable: false,
                                     line_number: 0
@@ -5995,7 +6290,7 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
                                 ftd::interpreter::PropertyValue::Value {
                                     value: ftd::interpreter::Value::Record {
                                         name: ftd::interpreter::FTD_RESPONSIVE_TYPE.to_string(),
-                                        fields: std::iter::IntoIterator::into_iter([
+                                        fields: with_tablet_and_wide(std::iter::IntoIterator::into_iter([
                                             (
                                                 "desktop".to_string(),
                                                 ftd::interpreter::PropertyValue::Value {
@@ -6145,7 +6440,7 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
                                                     line_number: 0
                                                 }
                                             )
-                                        ]).collect()
+                                        ]).collect())
                                     },
                                     is_mutable: false,
                                     line_number: 0
@@ -6157,7 +6452,7 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
                                 ftd::interpreter::PropertyValue::Value {
                                     value: ftd::interpreter::Value::Record {
                                         name: ftd::interpreter::FTD_RESPONSIVE_TYPE.to_string(),
-                                        fields: std::iter::IntoIterator::into_iter([
+                                        fields: with_tablet_and_wide(std::iter::IntoIterator::into_iter([
                                             (
                                                 "desktop".to_string(),
                                                 ftd::interpreter::PropertyValue::Value {
@@ -6307,7 +6602,7 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
                                                     line_number: 0
                                                 }
                                             )
-                                        ]).collect()
+                                        ]).collect())
                                     },
                                     is_mutable: false,
                                     line_number: 0
@@ -6318,7 +6613,7 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
                                 ftd::interpreter::PropertyValue::Value {
                                     value: ftd::interpreter::Value::Record {
                                         name: ftd::interpreter::FTD_RESPONSIVE_TYPE.to_string(),
-                                        fields: std::iter::IntoIterator::into_iter([
+                                        fields: with_tablet_and_wide(std::iter::IntoIterator::into_iter([
                                             (
                                                 "desktop".to_string(),
                                                 ftd::interpreter::PropertyValue::Value {
@@ -6468,7 +6763,7 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
                                                     line_number: 0
                                                 }
                                             )
-                                        ]).collect()
+                                        ]).collect())
                                     },
                                     is_mutable: false,
                                     line_number: 0
@@ -6479,7 +6774,7 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
                                 ftd::interpreter::PropertyValue::Value {
                                     value: ftd::interpreter::Value::Record {
                                         name: ftd::interpreter::FTD_RESPONSIVE_TYPE.to_string(),
-                                        fields: std::iter::IntoIterator::into_iter([
+                                        fields: with_tablet_and_wide(std::iter::IntoIterator::into_iter([
                                             (
                                                 "desktop".to_string(),
                                                 ftd::interpreter::PropertyValue::Value {
@@ -6629,7 +6924,7 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
                                                     line_number: 0
                                                 }
                                             )
-                                        ]).collect()
+                                        ]).collect())
                                     },
                                     is_mutable: false,
                                     line_number: 0
@@ -6640,7 +6935,7 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
                                 ftd::interpreter::PropertyValue::Value {
                                     value: ftd::interpreter::Value::Record {
                                         name: ftd::interpreter::FTD_RESPONSIVE_TYPE.to_string(),
-                                        fields: std::iter::IntoIterator::into_iter([
+                                        fields: with_tablet_and_wide(std::iter::IntoIterator::into_iter([
                                             (
                                                 "desktop".to_string(),
                                                 ftd::interpreter::PropertyValue::Value {
@@ -6790,7 +7085,7 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
                                                     line_number: 0
                                                 }
                                             )
-                                        ]).collect()
+                                        ]).collect())
                                     },
                                     is_mutable: false,
                                     line_number: 0
@@ -9182,6 +9477,45 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
                 is_static: false
             })
         ),
+        (
+            ftd::interpreter::FTD_BREAKPOINT.to_string(),
+            ftd::interpreter::Thing::Record(ftd::interpreter::Record {
+                name: ftd::interpreter::FTD_BREAKPOINT.to_string(),
+                fields: vec![
+                    ftd::interpreter::Field {
+                        name: "name".to_string(),
+                        kind: ftd::interpreter::Kind::string().into_kind_data().caption(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0
+                    },
+                    ftd::interpreter::Field {
+                        name: "max-width".to_string(),
+                        kind: ftd::interpreter::Kind::integer().into_optional().into_kind_data(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0
+                    },
+                ],
+                line_number: 0
+            })
+        ),
+        (
+            ftd::interpreter::FTD_BREAKPOINTS.to_string(),
+            ftd::interpreter::Thing::Variable(ftd::interpreter::Variable {
+                name: ftd::interpreter::FTD_BREAKPOINTS.to_string(),
+                kind: ftd::interpreter::Kind::record(ftd::interpreter::FTD_BREAKPOINT)
+                    .into_list()
+                    .into_kind_data(),
+                mutable: true,
+                value: breakpoints_value(&[("mobile", Some(768)), ("desktop", None)], 0),
+                conditional_value: vec![],
+                line_number: 0,
+                is_static: false
+            })
+        ),
         (
             ftd::interpreter::FTD_DEVICE_DATA.to_string(),
             ftd::interpreter::Thing::OrType(ftd::interpreter::OrType {
@@ -9197,6 +9531,16 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
                                     .into_property_value(false, 0),),
                         0,
                     )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_DEVICE_DATA_TABLET,
+                        ftd::interpreter::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(ftd::interpreter::Value::new_string("tablet")
+                                 .into_property_value(false, 0),),
+                        0,
+                    )),
                     ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
                         ftd::interpreter::FTD_DEVICE_DATA_DESKTOP,
                         ftd::interpreter::Kind::string()
@@ -9207,6 +9551,16 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
                                  .into_property_value(false, 0),),
                         0,
                     )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_DEVICE_DATA_WIDE,
+                        ftd::interpreter::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(ftd::interpreter::Value::new_string("wide")
+                                 .into_property_value(false, 0),),
+                        0,
+                    )),
                 ],
                 line_number: 0
             })
@@ -9215,84 +9569,1903 @@ pub fn default_bag() -> ftd::Map<ftd::interpreter::Thing> {
             ftd::interpreter::FTD_DEVICE.to_string(),
             ftd::interpreter::Thing::Variable(ftd::interpreter::Variable {
                 name: ftd::interpreter::FTD_DEVICE.to_string(),
-                kind: ftd::interpreter::Kind::record(ftd::interpreter::FTD_DEVICE_DATA).into_kind_data(),
+                kind: ftd::interpreter::Kind::string().into_kind_data(),
                 mutable: true,
-                value: ftd::interpreter::PropertyValue::Value {
-                    value: ftd::interpreter::Value::OrType {
-                        name: ftd::interpreter::FTD_DEVICE_DATA.to_string(),
-                        variant: ftd::interpreter::FTD_DEVICE_DATA_DESKTOP.to_string(),
-                        full_variant: ftd::interpreter::FTD_DEVICE_DATA_DESKTOP.to_string(),
-                        value: Box::new(ftd::interpreter::Value::new_string("desktop")
-                            .into_property_value(false, 0))
-                    },
-                    is_mutable: true,
-                    line_number: 0
-                },
+                value: ftd::interpreter::Value::new_string("desktop")
+                    .into_property_value(true, 0),
                 conditional_value: vec![],
                 line_number: 0,
                 is_static: false
             })
         )
     ])
-    .collect()
-}
-
-pub fn image_function() -> ftd::interpreter::ComponentDefinition {
-    ftd::interpreter::ComponentDefinition {
-        name: "ftd#image".to_string(),
-        arguments: [
-            common_arguments(),
-            vec![
-                ftd::interpreter::Argument::default(
-                    "src",
-                    ftd::interpreter::Kind::record(ftd::interpreter::FTD_IMAGE_SRC)
-                        .into_kind_data()
-                        .caption(),
-                ),
-                ftd::interpreter::Argument::default(
-                    "alt",
-                    ftd::interpreter::Kind::string()
-                        .into_kind_data()
-                        .into_optional(),
-                ),
-            ],
-        ]
-        .concat()
-        .into_iter()
-        .collect(),
-        definition: ftd::interpreter::Component::from_name("ftd.kernel"),
-        css: None,
-        line_number: 0,
-    }
+    .collect();
+    bag.extend(form_things());
+    bag.extend(media_things());
+    bag.extend(svg_things());
+    bag.extend(table_things());
+    bag.extend(accessibility_things());
+    bag.extend(overlay_things());
+    bag.extend(motion_things());
+    bag.extend(grid_things());
+    bag.extend(string_and_list_things());
+    bag.extend(date_things());
+    bag
 }
 
-pub fn boolean_function() -> ftd::interpreter::ComponentDefinition {
-    ftd::interpreter::ComponentDefinition {
-        name: "ftd#boolean".to_string(),
-        arguments: [
-            text_arguments(),
-            common_arguments(),
+/// Form related records and components. These are kept out of the main
+/// `default_bag` array so that its stack frame stays small.
+fn form_things() -> Vec<(String, ftd::interpreter::Thing)> {
+    vec![
+        (
+            ftd::interpreter::FTD_TEXT_INPUT_TYPE.to_string(),
+            ftd::interpreter::Thing::OrType(ftd::interpreter::OrType {
+                name: ftd::interpreter::FTD_TEXT_INPUT_TYPE.to_string(),
+                variants: vec![
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_TEXT_INPUT_TYPE_TEXT,
+                        ftd::interpreter::Kind::string().into_kind_data().caption(),
+                        false,
+                        Some(
+                            ftd::interpreter::Value::new_string("text")
+                                .into_property_value(false, 0),
+                        ),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_TEXT_INPUT_TYPE_EMAIL,
+                        ftd::interpreter::Kind::string().into_kind_data().caption(),
+                        false,
+                        Some(
+                            ftd::interpreter::Value::new_string("email")
+                                .into_property_value(false, 0),
+                        ),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_TEXT_INPUT_TYPE_PASSWORD,
+                        ftd::interpreter::Kind::string().into_kind_data().caption(),
+                        false,
+                        Some(
+                            ftd::interpreter::Value::new_string("password")
+                                .into_property_value(false, 0),
+                        ),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_TEXT_INPUT_TYPE_URL,
+                        ftd::interpreter::Kind::string().into_kind_data().caption(),
+                        false,
+                        Some(
+                            ftd::interpreter::Value::new_string("url")
+                                .into_property_value(false, 0),
+                        ),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_TEXT_INPUT_TYPE_DATETIME,
+                        ftd::interpreter::Kind::string().into_kind_data().caption(),
+                        false,
+                        Some(
+                            ftd::interpreter::Value::new_string("datetime-local")
+                                .into_property_value(false, 0),
+                        ),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_TEXT_INPUT_TYPE_DATE,
+                        ftd::interpreter::Kind::string().into_kind_data().caption(),
+                        false,
+                        Some(
+                            ftd::interpreter::Value::new_string("date")
+                                .into_property_value(false, 0),
+                        ),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_TEXT_INPUT_TYPE_TIME,
+                        ftd::interpreter::Kind::string().into_kind_data().caption(),
+                        false,
+                        Some(
+                            ftd::interpreter::Value::new_string("time")
+                                .into_property_value(false, 0),
+                        ),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_TEXT_INPUT_TYPE_MONTH,
+                        ftd::interpreter::Kind::string().into_kind_data().caption(),
+                        false,
+                        Some(
+                            ftd::interpreter::Value::new_string("month")
+                                .into_property_value(false, 0),
+                        ),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_TEXT_INPUT_TYPE_WEEK,
+                        ftd::interpreter::Kind::string().into_kind_data().caption(),
+                        false,
+                        Some(
+                            ftd::interpreter::Value::new_string("week")
+                                .into_property_value(false, 0),
+                        ),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_TEXT_INPUT_TYPE_COLOR,
+                        ftd::interpreter::Kind::string().into_kind_data().caption(),
+                        false,
+                        Some(
+                            ftd::interpreter::Value::new_string("color")
+                                .into_property_value(false, 0),
+                        ),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_TEXT_INPUT_TYPE_FILE,
+                        ftd::interpreter::Kind::string().into_kind_data().caption(),
+                        false,
+                        Some(
+                            ftd::interpreter::Value::new_string("file")
+                                .into_property_value(false, 0),
+                        ),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_TEXT_INPUT_TYPE_NUMBER,
+                        ftd::interpreter::Kind::string().into_kind_data().caption(),
+                        false,
+                        Some(
+                            ftd::interpreter::Value::new_string("number")
+                                .into_property_value(false, 0),
+                        ),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_TEXT_INPUT_TYPE_RANGE,
+                        ftd::interpreter::Kind::string().into_kind_data().caption(),
+                        false,
+                        Some(
+                            ftd::interpreter::Value::new_string("range")
+                                .into_property_value(false, 0),
+                        ),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_TEXT_INPUT_TYPE_SUBMIT,
+                        ftd::interpreter::Kind::string().into_kind_data().caption(),
+                        false,
+                        Some(
+                            ftd::interpreter::Value::new_string("submit")
+                                .into_property_value(false, 0),
+                        ),
+                        0,
+                    )),
+                ],
+                line_number: 0,
+            }),
+        ),
+        (
+            ftd::interpreter::FTD_OPTION.to_string(),
+            ftd::interpreter::Thing::Record(ftd::interpreter::Record {
+                name: ftd::interpreter::FTD_OPTION.to_string(),
+                fields: std::iter::IntoIterator::into_iter([
+                    ftd::interpreter::Field {
+                        name: "label".to_string(),
+                        kind: ftd::interpreter::Kind::string().into_kind_data().caption(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                    ftd::interpreter::Field {
+                        name: "value".to_string(),
+                        kind: ftd::interpreter::Kind::string()
+                            .into_optional()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                ])
+                .collect(),
+                line_number: 0,
+            }),
+        ),
+        (
+            "ftd#select".to_string(),
+            ftd::interpreter::Thing::Component(select_function()),
+        ),
+        (
+            "ftd#radio-group".to_string(),
+            ftd::interpreter::Thing::Component(radio_group_function()),
+        ),
+        (
+            "ftd#form".to_string(),
+            ftd::interpreter::Thing::Component(form_function()),
+        ),
+    ]
+}
+
+/// The records and components of native video and audio.
+fn media_things() -> Vec<(String, ftd::interpreter::Thing)> {
+    vec![
+        (
+            ftd::interpreter::FTD_MEDIA_SOURCE.to_string(),
+            ftd::interpreter::Thing::Record(ftd::interpreter::Record {
+                name: ftd::interpreter::FTD_MEDIA_SOURCE.to_string(),
+                fields: std::iter::IntoIterator::into_iter([
+                    ftd::interpreter::Field {
+                        name: "src".to_string(),
+                        kind: ftd::interpreter::Kind::string().into_kind_data().caption(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                    ftd::interpreter::Field {
+                        name: "type".to_string(),
+                        kind: ftd::interpreter::Kind::string()
+                            .into_optional()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                ])
+                .collect(),
+                line_number: 0,
+            }),
+        ),
+        (
+            ftd::interpreter::FTD_TRACK.to_string(),
+            ftd::interpreter::Thing::Record(ftd::interpreter::Record {
+                name: ftd::interpreter::FTD_TRACK.to_string(),
+                fields: std::iter::IntoIterator::into_iter([
+                    ftd::interpreter::Field {
+                        name: "src".to_string(),
+                        kind: ftd::interpreter::Kind::string().into_kind_data().caption(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                    ftd::interpreter::Field {
+                        name: "label".to_string(),
+                        kind: ftd::interpreter::Kind::string()
+                            .into_optional()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                    ftd::interpreter::Field {
+                        name: "lang".to_string(),
+                        kind: ftd::interpreter::Kind::string()
+                            .into_optional()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                    ftd::interpreter::Field {
+                        name: "default".to_string(),
+                        kind: ftd::interpreter::Kind::boolean().into_kind_data(),
+                        mutable: false,
+                        value: Some(ftd::interpreter::PropertyValue::Value {
+                            value: ftd::interpreter::Value::Boolean { value: false },
+                            is_mutable: false,
+                            line_number: 0,
+                        }),
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                ])
+                .collect(),
+                line_number: 0,
+            }),
+        ),
+        (
+            "ftd#video".to_string(),
+            ftd::interpreter::Thing::Component(video_function()),
+        ),
+        (
+            "ftd#audio".to_string(),
+            ftd::interpreter::Thing::Component(audio_function()),
+        ),
+    ]
+}
+
+/// The components that inline an svg file or a symbol of an svg sprite.
+fn svg_things() -> Vec<(String, ftd::interpreter::Thing)> {
+    vec![
+        (
+            "ftd#svg".to_string(),
+            ftd::interpreter::Thing::Component(svg_function()),
+        ),
+        (
+            "ftd#icon".to_string(),
+            ftd::interpreter::Thing::Component(icon_function()),
+        ),
+    ]
+}
+
+/// The table, its columns and how the values of a column are compared and aligned.
+fn table_things() -> Vec<(String, ftd::interpreter::Thing)> {
+    vec![
+        (
+            ftd::interpreter::FTD_COLUMN_TYPE.to_string(),
+            ftd::interpreter::Thing::OrType(ftd::interpreter::OrType {
+                name: ftd::interpreter::FTD_COLUMN_TYPE.to_string(),
+                variants: vec![
+                    ftd::interpreter::OrTypeVariant::new_constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_COLUMN_TYPE_TEXT,
+                        ftd::interpreter::Kind::string().into_kind_data(),
+                        false,
+                        Some(
+                            ftd::interpreter::Value::new_string("text")
+                                .into_property_value(false, 0),
+                        ),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::new_constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_COLUMN_TYPE_NUMBER,
+                        ftd::interpreter::Kind::string().into_kind_data(),
+                        false,
+                        Some(
+                            ftd::interpreter::Value::new_string("number")
+                                .into_property_value(false, 0),
+                        ),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::new_constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_COLUMN_TYPE_DATE,
+                        ftd::interpreter::Kind::string().into_kind_data(),
+                        false,
+                        Some(
+                            ftd::interpreter::Value::new_string("date")
+                                .into_property_value(false, 0),
+                        ),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::new_constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_COLUMN_TYPE_BOOLEAN,
+                        ftd::interpreter::Kind::string().into_kind_data(),
+                        false,
+                        Some(
+                            ftd::interpreter::Value::new_string("boolean")
+                                .into_property_value(false, 0),
+                        ),
+                        0,
+                    )),
+                ],
+                line_number: 0,
+            }),
+        ),
+        (
+            ftd::interpreter::FTD_TABLE_COLUMN.to_string(),
+            ftd::interpreter::Thing::Record(ftd::interpreter::Record {
+                name: ftd::interpreter::FTD_TABLE_COLUMN.to_string(),
+                fields: std::iter::IntoIterator::into_iter([
+                    ftd::interpreter::Field {
+                        name: "header".to_string(),
+                        kind: ftd::interpreter::Kind::string().into_kind_data().caption(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                    ftd::interpreter::Field {
+                        name: "field".to_string(),
+                        kind: ftd::interpreter::Kind::string().into_kind_data(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                    ftd::interpreter::Field {
+                        name: "type".to_string(),
+                        kind: ftd::interpreter::Kind::or_type(ftd::interpreter::FTD_COLUMN_TYPE)
+                            .into_kind_data(),
+                        mutable: false,
+                        value: Some(ftd::interpreter::PropertyValue::Value {
+                            value: ftd::interpreter::Value::OrType {
+                                name: ftd::interpreter::FTD_COLUMN_TYPE.to_string(),
+                                variant: ftd::interpreter::FTD_COLUMN_TYPE_TEXT.to_string(),
+                                full_variant: ftd::interpreter::FTD_COLUMN_TYPE_TEXT.to_string(),
+                                value: Box::new(
+                                    ftd::interpreter::Value::new_string("text")
+                                        .into_property_value(false, 0),
+                                ),
+                            },
+                            is_mutable: false,
+                            line_number: 0,
+                        }),
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                    ftd::interpreter::Field {
+                        name: "width".to_string(),
+                        kind: ftd::interpreter::Kind::or_type(ftd::interpreter::FTD_RESIZING)
+                            .into_optional()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                    ftd::interpreter::Field {
+                        name: "align".to_string(),
+                        kind: ftd::interpreter::Kind::or_type(ftd::interpreter::FTD_TEXT_ALIGN)
+                            .into_optional()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                    ftd::interpreter::Field {
+                        name: "sortable".to_string(),
+                        kind: ftd::interpreter::Kind::boolean().into_kind_data(),
+                        mutable: false,
+                        value: Some(ftd::interpreter::PropertyValue::Value {
+                            value: ftd::interpreter::Value::Boolean { value: false },
+                            is_mutable: false,
+                            line_number: 0,
+                        }),
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                    ftd::interpreter::Field {
+                        name: "cell".to_string(),
+                        kind: ftd::interpreter::Kind::string()
+                            .into_optional()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                ])
+                .collect(),
+                line_number: 0,
+            }),
+        ),
+        (
+            "ftd#table".to_string(),
+            ftd::interpreter::Thing::Component(table_function()),
+        ),
+    ]
+}
+
+/// The overlay components, `ftd.dialog`, `ftd.popover` and `ftd.tooltip`, and the
+/// `ftd#placement` of a popover or tooltip around its trigger.
+fn overlay_things() -> Vec<(String, ftd::interpreter::Thing)> {
+    vec![
+        (
+            ftd::interpreter::FTD_PLACEMENT.to_string(),
+            ftd::interpreter::Thing::OrType(ftd::interpreter::OrType {
+                name: ftd::interpreter::FTD_PLACEMENT.to_string(),
+                variants: vec![
+                    ftd::interpreter::OrTypeVariant::new_constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_PLACEMENT_TOP,
+                        ftd::interpreter::Kind::string().into_kind_data(),
+                        false,
+                        Some(
+                            ftd::interpreter::Value::new_string("top")
+                                .into_property_value(false, 0),
+                        ),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::new_constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_PLACEMENT_BOTTOM,
+                        ftd::interpreter::Kind::string().into_kind_data(),
+                        false,
+                        Some(
+                            ftd::interpreter::Value::new_string("bottom")
+                                .into_property_value(false, 0),
+                        ),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::new_constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_PLACEMENT_LEFT,
+                        ftd::interpreter::Kind::string().into_kind_data(),
+                        false,
+                        Some(
+                            ftd::interpreter::Value::new_string("left")
+                                .into_property_value(false, 0),
+                        ),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::new_constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_PLACEMENT_RIGHT,
+                        ftd::interpreter::Kind::string().into_kind_data(),
+                        false,
+                        Some(
+                            ftd::interpreter::Value::new_string("right")
+                                .into_property_value(false, 0),
+                        ),
+                        0,
+                    )),
+                ],
+                line_number: 0,
+            }),
+        ),
+        (
+            "ftd#dialog".to_string(),
+            ftd::interpreter::Thing::Component(dialog_function()),
+        ),
+        (
+            "ftd#popover".to_string(),
+            ftd::interpreter::Thing::Component(popover_function()),
+        ),
+        (
+            "ftd#tooltip".to_string(),
+            ftd::interpreter::Thing::Component(tooltip_function()),
+        ),
+    ]
+}
+
+/// `ftd#landmark` and `ftd#aria-live`, the or-types taken by the
+/// accessibility properties every component accepts.
+fn accessibility_things() -> Vec<(String, ftd::interpreter::Thing)> {
+    vec![
+        (
+            ftd::interpreter::FTD_LANDMARK.to_string(),
+            ftd::interpreter::Thing::OrType(ftd::interpreter::OrType {
+                name: ftd::interpreter::FTD_LANDMARK.to_string(),
+                variants: vec![
+                    ftd::interpreter::OrTypeVariant::new_constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_LANDMARK_BANNER,
+                        ftd::interpreter::Kind::string().into_kind_data(),
+                        false,
+                        Some(
+                            ftd::interpreter::Value::new_string("banner")
+                                .into_property_value(false, 0),
+                        ),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::new_constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_LANDMARK_NAVIGATION,
+                        ftd::interpreter::Kind::string().into_kind_data(),
+                        false,
+                        Some(
+                            ftd::interpreter::Value::new_string("navigation")
+                                .into_property_value(false, 0),
+                        ),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::new_constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_LANDMARK_MAIN,
+                        ftd::interpreter::Kind::string().into_kind_data(),
+                        false,
+                        Some(
+                            ftd::interpreter::Value::new_string("main")
+                                .into_property_value(false, 0),
+                        ),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::new_constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_LANDMARK_COMPLEMENTARY,
+                        ftd::interpreter::Kind::string().into_kind_data(),
+                        false,
+                        Some(
+                            ftd::interpreter::Value::new_string("complementary")
+                                .into_property_value(false, 0),
+                        ),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::new_constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_LANDMARK_CONTENT_INFO,
+                        ftd::interpreter::Kind::string().into_kind_data(),
+                        false,
+                        Some(
+                            ftd::interpreter::Value::new_string("contentinfo")
+                                .into_property_value(false, 0),
+                        ),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::new_constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_LANDMARK_SEARCH,
+                        ftd::interpreter::Kind::string().into_kind_data(),
+                        false,
+                        Some(
+                            ftd::interpreter::Value::new_string("search")
+                                .into_property_value(false, 0),
+                        ),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::new_constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_LANDMARK_FORM,
+                        ftd::interpreter::Kind::string().into_kind_data(),
+                        false,
+                        Some(
+                            ftd::interpreter::Value::new_string("form")
+                                .into_property_value(false, 0),
+                        ),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::new_constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_LANDMARK_REGION,
+                        ftd::interpreter::Kind::string().into_kind_data(),
+                        false,
+                        Some(
+                            ftd::interpreter::Value::new_string("region")
+                                .into_property_value(false, 0),
+                        ),
+                        0,
+                    )),
+                ],
+                line_number: 0,
+            }),
+        ),
+        (
+            ftd::interpreter::FTD_ARIA_LIVE.to_string(),
+            ftd::interpreter::Thing::OrType(ftd::interpreter::OrType {
+                name: ftd::interpreter::FTD_ARIA_LIVE.to_string(),
+                variants: vec![
+                    ftd::interpreter::OrTypeVariant::new_constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_ARIA_LIVE_POLITE,
+                        ftd::interpreter::Kind::string().into_kind_data(),
+                        false,
+                        Some(
+                            ftd::interpreter::Value::new_string("polite")
+                                .into_property_value(false, 0),
+                        ),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::new_constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_ARIA_LIVE_ASSERTIVE,
+                        ftd::interpreter::Kind::string().into_kind_data(),
+                        false,
+                        Some(
+                            ftd::interpreter::Value::new_string("assertive")
+                                .into_property_value(false, 0),
+                        ),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::new_constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_ARIA_LIVE_OFF,
+                        ftd::interpreter::Kind::string().into_kind_data(),
+                        false,
+                        Some(
+                            ftd::interpreter::Value::new_string("off")
+                                .into_property_value(false, 0),
+                        ),
+                        0,
+                    )),
+                ],
+                line_number: 0,
+            }),
+        ),
+    ]
+}
+
+/// The transform, transition and animation records.
+fn motion_things() -> Vec<(String, ftd::interpreter::Thing)> {
+    vec![
+        (
+            ftd::interpreter::FTD_TRANSFORM.to_string(),
+            ftd::interpreter::Thing::Record(ftd::interpreter::Record {
+                name: ftd::interpreter::FTD_TRANSFORM.to_string(),
+                fields: std::iter::IntoIterator::into_iter([
+                    ftd::interpreter::Field {
+                        name: "translate-x".to_string(),
+                        kind: ftd::interpreter::Kind::or_type(ftd::interpreter::FTD_LENGTH)
+                            .into_optional()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                    ftd::interpreter::Field {
+                        name: "translate-y".to_string(),
+                        kind: ftd::interpreter::Kind::or_type(ftd::interpreter::FTD_LENGTH)
+                            .into_optional()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                    ftd::interpreter::Field {
+                        name: "rotate".to_string(),
+                        kind: ftd::interpreter::Kind::decimal()
+                            .into_optional()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                    ftd::interpreter::Field {
+                        name: "scale".to_string(),
+                        kind: ftd::interpreter::Kind::decimal()
+                            .into_optional()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                    ftd::interpreter::Field {
+                        name: "skew-x".to_string(),
+                        kind: ftd::interpreter::Kind::decimal()
+                            .into_optional()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                    ftd::interpreter::Field {
+                        name: "skew-y".to_string(),
+                        kind: ftd::interpreter::Kind::decimal()
+                            .into_optional()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                ])
+                .collect(),
+                line_number: 0,
+            }),
+        ),
+        (
+            ftd::interpreter::FTD_EASING.to_string(),
+            ftd::interpreter::Thing::OrType(ftd::interpreter::OrType {
+                name: ftd::interpreter::FTD_EASING.to_string(),
+                variants: vec![
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_EASING_LINEAR,
+                        ftd::interpreter::Kind::string().into_kind_data().caption(),
+                        false,
+                        Some(
+                            ftd::interpreter::Value::new_string("linear")
+                                .into_property_value(false, 0),
+                        ),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_EASING_EASE,
+                        ftd::interpreter::Kind::string().into_kind_data().caption(),
+                        false,
+                        Some(
+                            ftd::interpreter::Value::new_string("ease")
+                                .into_property_value(false, 0),
+                        ),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_EASING_EASE_IN,
+                        ftd::interpreter::Kind::string().into_kind_data().caption(),
+                        false,
+                        Some(
+                            ftd::interpreter::Value::new_string("ease-in")
+                                .into_property_value(false, 0),
+                        ),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_EASING_EASE_OUT,
+                        ftd::interpreter::Kind::string().into_kind_data().caption(),
+                        false,
+                        Some(
+                            ftd::interpreter::Value::new_string("ease-out")
+                                .into_property_value(false, 0),
+                        ),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_EASING_EASE_IN_OUT,
+                        ftd::interpreter::Kind::string().into_kind_data().caption(),
+                        false,
+                        Some(
+                            ftd::interpreter::Value::new_string("ease-in-out")
+                                .into_property_value(false, 0),
+                        ),
+                        0,
+                    )),
+                ],
+                line_number: 0,
+            }),
+        ),
+        (
+            ftd::interpreter::FTD_TRANSITION.to_string(),
+            ftd::interpreter::Thing::Record(ftd::interpreter::Record {
+                name: ftd::interpreter::FTD_TRANSITION.to_string(),
+                fields: std::iter::IntoIterator::into_iter([
+                    ftd::interpreter::Field {
+                        name: "property".to_string(),
+                        kind: ftd::interpreter::Kind::string().into_kind_data().caption(),
+                        mutable: false,
+                        value: Some(
+                            ftd::interpreter::Value::new_string("all")
+                                .into_property_value(false, 0),
+                        ),
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                    ftd::interpreter::Field {
+                        name: "duration".to_string(),
+                        kind: ftd::interpreter::Kind::decimal().into_kind_data(),
+                        mutable: false,
+                        value: Some(ftd::interpreter::PropertyValue::Value {
+                            value: ftd::interpreter::Value::Decimal { value: 0.3 },
+                            is_mutable: false,
+                            line_number: 0,
+                        }),
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                    ftd::interpreter::Field {
+                        name: "easing".to_string(),
+                        kind: ftd::interpreter::Kind::or_type(ftd::interpreter::FTD_EASING)
+                            .into_kind_data(),
+                        mutable: false,
+                        value: Some(ftd::interpreter::PropertyValue::Value {
+                            value: ftd::interpreter::Value::OrType {
+                                name: ftd::interpreter::FTD_EASING.to_string(),
+                                variant: ftd::interpreter::FTD_EASING_EASE.to_string(),
+                                full_variant: ftd::interpreter::FTD_EASING_EASE.to_string(),
+                                value: Box::new(
+                                    ftd::interpreter::Value::new_string("ease")
+                                        .into_property_value(false, 0),
+                                ),
+                            },
+                            is_mutable: false,
+                            line_number: 0,
+                        }),
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                    ftd::interpreter::Field {
+                        name: "delay".to_string(),
+                        kind: ftd::interpreter::Kind::decimal().into_kind_data(),
+                        mutable: false,
+                        value: Some(ftd::interpreter::PropertyValue::Value {
+                            value: ftd::interpreter::Value::Decimal { value: 0.0 },
+                            is_mutable: false,
+                            line_number: 0,
+                        }),
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                ])
+                .collect(),
+                line_number: 0,
+            }),
+        ),
+        (
+            ftd::interpreter::FTD_KEYFRAME.to_string(),
+            ftd::interpreter::Thing::Record(ftd::interpreter::Record {
+                name: ftd::interpreter::FTD_KEYFRAME.to_string(),
+                fields: std::iter::IntoIterator::into_iter([
+                    ftd::interpreter::Field {
+                        name: "at".to_string(),
+                        kind: ftd::interpreter::Kind::integer().into_kind_data().caption(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                    ftd::interpreter::Field {
+                        name: "opacity".to_string(),
+                        kind: ftd::interpreter::Kind::decimal()
+                            .into_optional()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                    ftd::interpreter::Field {
+                        name: "transform".to_string(),
+                        kind: ftd::interpreter::Kind::record(ftd::interpreter::FTD_TRANSFORM)
+                            .into_optional()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                ])
+                .collect(),
+                line_number: 0,
+            }),
+        ),
+        (
+            ftd::interpreter::FTD_ANIMATION.to_string(),
+            ftd::interpreter::Thing::Record(ftd::interpreter::Record {
+                name: ftd::interpreter::FTD_ANIMATION.to_string(),
+                fields: std::iter::IntoIterator::into_iter([
+                    ftd::interpreter::Field {
+                        name: "keyframes".to_string(),
+                        kind: ftd::interpreter::Kind::record(ftd::interpreter::FTD_KEYFRAME)
+                            .into_list()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                    ftd::interpreter::Field {
+                        name: "duration".to_string(),
+                        kind: ftd::interpreter::Kind::decimal().into_kind_data(),
+                        mutable: false,
+                        value: Some(ftd::interpreter::PropertyValue::Value {
+                            value: ftd::interpreter::Value::Decimal { value: 1.0 },
+                            is_mutable: false,
+                            line_number: 0,
+                        }),
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                    ftd::interpreter::Field {
+                        name: "easing".to_string(),
+                        kind: ftd::interpreter::Kind::or_type(ftd::interpreter::FTD_EASING)
+                            .into_kind_data(),
+                        mutable: false,
+                        value: Some(ftd::interpreter::PropertyValue::Value {
+                            value: ftd::interpreter::Value::OrType {
+                                name: ftd::interpreter::FTD_EASING.to_string(),
+                                variant: ftd::interpreter::FTD_EASING_EASE.to_string(),
+                                full_variant: ftd::interpreter::FTD_EASING_EASE.to_string(),
+                                value: Box::new(
+                                    ftd::interpreter::Value::new_string("ease")
+                                        .into_property_value(false, 0),
+                                ),
+                            },
+                            is_mutable: false,
+                            line_number: 0,
+                        }),
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                    ftd::interpreter::Field {
+                        name: "delay".to_string(),
+                        kind: ftd::interpreter::Kind::decimal().into_kind_data(),
+                        mutable: false,
+                        value: Some(ftd::interpreter::PropertyValue::Value {
+                            value: ftd::interpreter::Value::Decimal { value: 0.0 },
+                            is_mutable: false,
+                            line_number: 0,
+                        }),
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                    ftd::interpreter::Field {
+                        name: "iteration-count".to_string(),
+                        kind: ftd::interpreter::Kind::integer().into_kind_data(),
+                        mutable: false,
+                        value: Some(ftd::interpreter::PropertyValue::Value {
+                            value: ftd::interpreter::Value::Integer { value: 1 },
+                            is_mutable: false,
+                            line_number: 0,
+                        }),
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                    ftd::interpreter::Field {
+                        name: "infinite".to_string(),
+                        kind: ftd::interpreter::Kind::boolean().into_kind_data(),
+                        mutable: false,
+                        value: Some(ftd::interpreter::PropertyValue::Value {
+                            value: ftd::interpreter::Value::Boolean { value: false },
+                            is_mutable: false,
+                            line_number: 0,
+                        }),
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                    ftd::interpreter::Field {
+                        name: "alternate".to_string(),
+                        kind: ftd::interpreter::Kind::boolean().into_kind_data(),
+                        mutable: false,
+                        value: Some(ftd::interpreter::PropertyValue::Value {
+                            value: ftd::interpreter::Value::Boolean { value: false },
+                            is_mutable: false,
+                            line_number: 0,
+                        }),
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                ])
+                .collect(),
+                line_number: 0,
+            }),
+        ),
+    ]
+}
+
+/// The `ftd.grid` component and its template records.
+fn grid_things() -> Vec<(String, ftd::interpreter::Thing)> {
+    vec![
+        (
+            "ftd#grid".to_string(),
+            ftd::interpreter::Thing::Component(grid_function()),
+        ),
+        (
+            ftd::interpreter::FTD_GRID_TEMPLATE.to_string(),
+            ftd::interpreter::Thing::OrType(ftd::interpreter::OrType {
+                name: ftd::interpreter::FTD_GRID_TEMPLATE.to_string(),
+                variants: vec![
+                    ftd::interpreter::OrTypeVariant::Regular(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_GRID_TEMPLATE_COUNT,
+                        ftd::interpreter::Kind::integer().into_kind_data().caption(),
+                        false,
+                        None,
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Regular(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_GRID_TEMPLATE_FILL,
+                        ftd::interpreter::Kind::or_type(ftd::interpreter::FTD_LENGTH)
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        None,
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Regular(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_GRID_TEMPLATE_FIT,
+                        ftd::interpreter::Kind::or_type(ftd::interpreter::FTD_LENGTH)
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        None,
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Regular(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_GRID_TEMPLATE_TRACKS,
+                        ftd::interpreter::Kind::string().into_kind_data().caption(),
+                        false,
+                        None,
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Regular(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_GRID_TEMPLATE_RESPONSIVE,
+                        ftd::interpreter::Kind::record(
+                            ftd::interpreter::FTD_RESPONSIVE_GRID_TEMPLATE,
+                        )
+                        .into_kind_data()
+                        .caption(),
+                        false,
+                        None,
+                        0,
+                    )),
+                ],
+                line_number: 0,
+            }),
+        ),
+        (
+            ftd::interpreter::FTD_RESPONSIVE_GRID_TEMPLATE.to_string(),
+            ftd::interpreter::Thing::Record(ftd::interpreter::Record {
+                name: ftd::interpreter::FTD_RESPONSIVE_GRID_TEMPLATE.to_string(),
+                fields: std::iter::IntoIterator::into_iter([
+                    ftd::interpreter::Field {
+                        name: "desktop".to_string(),
+                        kind: ftd::interpreter::Kind::or_type(ftd::interpreter::FTD_GRID_TEMPLATE)
+                            .into_kind_data()
+                            .caption(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                    ftd::interpreter::Field {
+                        name: "mobile".to_string(),
+                        kind: ftd::interpreter::Kind::or_type(ftd::interpreter::FTD_GRID_TEMPLATE)
+                            .into_kind_data(),
+                        mutable: false,
+                        access_modifier: Default::default(),
+                        value: Some(ftd::interpreter::PropertyValue::Reference {
+                            name: ftd::interpreter::FTD_RESPONSIVE_GRID_TEMPLATE_DESKTOP
+                                .to_string(),
+                            kind: ftd::interpreter::Kind::or_type(
+                                ftd::interpreter::FTD_GRID_TEMPLATE,
+                            )
+                            .into_kind_data(),
+                            source: ftd::interpreter::PropertyValueSource::Local(
+                                ftd::interpreter::FTD_RESPONSIVE_GRID_TEMPLATE.to_string(),
+                            ),
+                            is_mutable: false,
+                            line_number: 0,
+                        }),
+                        line_number: 0,
+                    },
+                    ftd::interpreter::Field {
+                        name: "tablet".to_string(),
+                        kind: ftd::interpreter::Kind::or_type(ftd::interpreter::FTD_GRID_TEMPLATE)
+                            .into_kind_data(),
+                        mutable: false,
+                        access_modifier: Default::default(),
+                        value: Some(ftd::interpreter::PropertyValue::Reference {
+                            name: ftd::interpreter::FTD_RESPONSIVE_GRID_TEMPLATE_DESKTOP
+                                .to_string(),
+                            kind: ftd::interpreter::Kind::or_type(
+                                ftd::interpreter::FTD_GRID_TEMPLATE,
+                            )
+                            .into_kind_data(),
+                            source: ftd::interpreter::PropertyValueSource::Local(
+                                ftd::interpreter::FTD_RESPONSIVE_GRID_TEMPLATE.to_string(),
+                            ),
+                            is_mutable: false,
+                            line_number: 0,
+                        }),
+                        line_number: 0,
+                    },
+                    ftd::interpreter::Field {
+                        name: "wide".to_string(),
+                        kind: ftd::interpreter::Kind::or_type(ftd::interpreter::FTD_GRID_TEMPLATE)
+                            .into_kind_data(),
+                        mutable: false,
+                        access_modifier: Default::default(),
+                        value: Some(ftd::interpreter::PropertyValue::Reference {
+                            name: ftd::interpreter::FTD_RESPONSIVE_GRID_TEMPLATE_DESKTOP
+                                .to_string(),
+                            kind: ftd::interpreter::Kind::or_type(
+                                ftd::interpreter::FTD_GRID_TEMPLATE,
+                            )
+                            .into_kind_data(),
+                            source: ftd::interpreter::PropertyValueSource::Local(
+                                ftd::interpreter::FTD_RESPONSIVE_GRID_TEMPLATE.to_string(),
+                            ),
+                            is_mutable: false,
+                            line_number: 0,
+                        }),
+                        line_number: 0,
+                    },
+                ])
+                .collect(),
+                line_number: 0,
+            }),
+        ),
+    ]
+}
+
+/// The string, list and JSON functions of the `ftd` module.
+fn string_and_list_things() -> Vec<(String, ftd::interpreter::Thing)> {
+    vec![
+        (
+            "ftd#split".to_string(),
+            kernel_function(
+                "ftd#split",
+                vec![
+                    ftd::interpreter::Argument::default(
+                        "text",
+                        ftd::interpreter::Kind::string().into_kind_data(),
+                    ),
+                    ftd::interpreter::Argument::default(
+                        "separator",
+                        ftd::interpreter::Kind::string().into_kind_data(),
+                    ),
+                ],
+                ftd::interpreter::Kind::string().into_list(),
+            ),
+        ),
+        (
+            "ftd#join".to_string(),
+            kernel_function(
+                "ftd#join",
+                vec![
+                    ftd::interpreter::Argument::default(
+                        "list",
+                        ftd::interpreter::Kind::type_parameter("T")
+                            .into_list()
+                            .into_kind_data(),
+                    ),
+                    ftd::interpreter::Argument::default(
+                        "separator",
+                        ftd::interpreter::Kind::string().into_kind_data(),
+                    ),
+                ],
+                ftd::interpreter::Kind::string(),
+            ),
+        ),
+        (
+            "ftd#replace".to_string(),
+            kernel_function(
+                "ftd#replace",
+                vec![
+                    ftd::interpreter::Argument::default(
+                        "text",
+                        ftd::interpreter::Kind::string().into_kind_data(),
+                    ),
+                    ftd::interpreter::Argument::default(
+                        "from",
+                        ftd::interpreter::Kind::string().into_kind_data(),
+                    ),
+                    ftd::interpreter::Argument::default(
+                        "to",
+                        ftd::interpreter::Kind::string().into_kind_data(),
+                    ),
+                ],
+                ftd::interpreter::Kind::string(),
+            ),
+        ),
+        (
+            "ftd#trim".to_string(),
+            kernel_function(
+                "ftd#trim",
+                vec![ftd::interpreter::Argument::default(
+                    "text",
+                    ftd::interpreter::Kind::string().into_kind_data(),
+                )],
+                ftd::interpreter::Kind::string(),
+            ),
+        ),
+        (
+            "ftd#upper".to_string(),
+            kernel_function(
+                "ftd#upper",
+                vec![ftd::interpreter::Argument::default(
+                    "text",
+                    ftd::interpreter::Kind::string().into_kind_data(),
+                )],
+                ftd::interpreter::Kind::string(),
+            ),
+        ),
+        (
+            "ftd#lower".to_string(),
+            kernel_function(
+                "ftd#lower",
+                vec![ftd::interpreter::Argument::default(
+                    "text",
+                    ftd::interpreter::Kind::string().into_kind_data(),
+                )],
+                ftd::interpreter::Kind::string(),
+            ),
+        ),
+        (
+            "ftd#contains".to_string(),
+            kernel_function(
+                "ftd#contains",
+                vec![
+                    ftd::interpreter::Argument::default(
+                        "text",
+                        ftd::interpreter::Kind::string().into_kind_data(),
+                    ),
+                    ftd::interpreter::Argument::default(
+                        "part",
+                        ftd::interpreter::Kind::string().into_kind_data(),
+                    ),
+                ],
+                ftd::interpreter::Kind::boolean(),
+            ),
+        ),
+        (
+            "ftd#starts-with".to_string(),
+            kernel_function(
+                "ftd#starts-with",
+                vec![
+                    ftd::interpreter::Argument::default(
+                        "text",
+                        ftd::interpreter::Kind::string().into_kind_data(),
+                    ),
+                    ftd::interpreter::Argument::default(
+                        "prefix",
+                        ftd::interpreter::Kind::string().into_kind_data(),
+                    ),
+                ],
+                ftd::interpreter::Kind::boolean(),
+            ),
+        ),
+        (
+            "ftd#slice".to_string(),
+            kernel_function(
+                "ftd#slice",
+                vec![
+                    ftd::interpreter::Argument::default(
+                        "text",
+                        ftd::interpreter::Kind::string().into_kind_data(),
+                    ),
+                    ftd::interpreter::Argument::default(
+                        "start",
+                        ftd::interpreter::Kind::integer().into_kind_data(),
+                    ),
+                    ftd::interpreter::Argument {
+                        value: Some(ftd::interpreter::PropertyValue::Value {
+                            value: ftd::interpreter::Value::new_none(
+                                ftd::interpreter::Kind::integer().into_kind_data(),
+                            ),
+                            is_mutable: false,
+                            line_number: 0,
+                        }),
+                        ..ftd::interpreter::Argument::default(
+                            "end",
+                            ftd::interpreter::Kind::integer()
+                                .into_optional()
+                                .into_kind_data(),
+                        )
+                    },
+                ],
+                ftd::interpreter::Kind::string(),
+            ),
+        ),
+        (
+            "ftd#regex-match".to_string(),
+            kernel_function(
+                "ftd#regex-match",
+                vec![
+                    ftd::interpreter::Argument::default(
+                        "text",
+                        ftd::interpreter::Kind::string().into_kind_data(),
+                    ),
+                    ftd::interpreter::Argument::default(
+                        "pattern",
+                        ftd::interpreter::Kind::string().into_kind_data(),
+                    ),
+                ],
+                ftd::interpreter::Kind::boolean(),
+            ),
+        ),
+        (
+            "ftd#map".to_string(),
+            kernel_function(
+                "ftd#map",
+                vec![
+                    ftd::interpreter::Argument::default(
+                        "list",
+                        ftd::interpreter::Kind::type_parameter("T")
+                            .into_list()
+                            .into_kind_data(),
+                    ),
+                    ftd::interpreter::Argument::default(
+                        "expression",
+                        ftd::interpreter::Kind::string().into_kind_data(),
+                    ),
+                ],
+                ftd::interpreter::Kind::type_parameter("U").into_list(),
+            ),
+        ),
+        (
+            "ftd#filter".to_string(),
+            kernel_function(
+                "ftd#filter",
+                vec![
+                    ftd::interpreter::Argument::default(
+                        "list",
+                        ftd::interpreter::Kind::type_parameter("T")
+                            .into_list()
+                            .into_kind_data(),
+                    ),
+                    ftd::interpreter::Argument::default(
+                        "condition",
+                        ftd::interpreter::Kind::string().into_kind_data(),
+                    ),
+                ],
+                ftd::interpreter::Kind::type_parameter("T").into_list(),
+            ),
+        ),
+        (
+            "ftd#sort-by".to_string(),
+            kernel_function(
+                "ftd#sort-by",
+                vec![
+                    ftd::interpreter::Argument::default(
+                        "list",
+                        ftd::interpreter::Kind::type_parameter("T")
+                            .into_list()
+                            .into_kind_data(),
+                    ),
+                    ftd::interpreter::Argument::default(
+                        "key",
+                        ftd::interpreter::Kind::string().into_kind_data(),
+                    ),
+                ],
+                ftd::interpreter::Kind::type_parameter("T").into_list(),
+            ),
+        ),
+        (
+            "ftd#reverse".to_string(),
+            kernel_function(
+                "ftd#reverse",
+                vec![ftd::interpreter::Argument::default(
+                    "list",
+                    ftd::interpreter::Kind::type_parameter("T")
+                        .into_list()
+                        .into_kind_data(),
+                )],
+                ftd::interpreter::Kind::type_parameter("T").into_list(),
+            ),
+        ),
+        (
+            "ftd#take".to_string(),
+            kernel_function(
+                "ftd#take",
+                vec![
+                    ftd::interpreter::Argument::default(
+                        "list",
+                        ftd::interpreter::Kind::type_parameter("T")
+                            .into_list()
+                            .into_kind_data(),
+                    ),
+                    ftd::interpreter::Argument::default(
+                        "count",
+                        ftd::interpreter::Kind::integer().into_kind_data(),
+                    ),
+                ],
+                ftd::interpreter::Kind::type_parameter("T").into_list(),
+            ),
+        ),
+        (
+            "ftd#skip".to_string(),
+            kernel_function(
+                "ftd#skip",
+                vec![
+                    ftd::interpreter::Argument::default(
+                        "list",
+                        ftd::interpreter::Kind::type_parameter("T")
+                            .into_list()
+                            .into_kind_data(),
+                    ),
+                    ftd::interpreter::Argument::default(
+                        "count",
+                        ftd::interpreter::Kind::integer().into_kind_data(),
+                    ),
+                ],
+                ftd::interpreter::Kind::type_parameter("T").into_list(),
+            ),
+        ),
+        (
+            "ftd#sum".to_string(),
+            kernel_function(
+                "ftd#sum",
+                vec![ftd::interpreter::Argument::default(
+                    "list",
+                    ftd::interpreter::Kind::type_parameter("T")
+                        .into_list()
+                        .into_kind_data(),
+                )],
+                ftd::interpreter::Kind::type_parameter("T"),
+            ),
+        ),
+        (
+            "ftd#unique".to_string(),
+            kernel_function(
+                "ftd#unique",
+                vec![ftd::interpreter::Argument::default(
+                    "list",
+                    ftd::interpreter::Kind::type_parameter("T")
+                        .into_list()
+                        .into_kind_data(),
+                )],
+                ftd::interpreter::Kind::type_parameter("T").into_list(),
+            ),
+        ),
+        (
+            "ftd#index-of".to_string(),
+            kernel_function(
+                "ftd#index-of",
+                vec![
+                    ftd::interpreter::Argument::default(
+                        "list",
+                        ftd::interpreter::Kind::type_parameter("T")
+                            .into_list()
+                            .into_kind_data(),
+                    ),
+                    ftd::interpreter::Argument::default(
+                        "item",
+                        ftd::interpreter::Kind::type_parameter("T").into_kind_data(),
+                    ),
+                ],
+                ftd::interpreter::Kind::integer(),
+            ),
+        ),
+        (
+            "ftd#to-json".to_string(),
+            kernel_function(
+                "ftd#to-json",
+                vec![ftd::interpreter::Argument::default(
+                    "value",
+                    ftd::interpreter::Kind::type_parameter("T").into_kind_data(),
+                )],
+                ftd::interpreter::Kind::string(),
+            ),
+        ),
+        (
+            "ftd#from-json".to_string(),
+            kernel_function(
+                "ftd#from-json",
+                vec![ftd::interpreter::Argument::default(
+                    "json",
+                    ftd::interpreter::Kind::string().into_kind_data(),
+                )],
+                ftd::interpreter::Kind::type_parameter("T"),
+            ),
+        ),
+    ]
+}
+
+/// The functions of the `ftd` module that format and compare dates.
+fn date_things() -> Vec<(String, ftd::interpreter::Thing)> {
+    vec![
+        (
+            "ftd#format-date".to_string(),
+            kernel_function(
+                "ftd#format-date",
+                vec![
+                    ftd::interpreter::Argument::default(
+                        "d",
+                        ftd::interpreter::Kind::datetime().into_kind_data(),
+                    ),
+                    ftd::interpreter::Argument::default(
+                        "format",
+                        ftd::interpreter::Kind::string().into_kind_data(),
+                    ),
+                    ftd::interpreter::Argument {
+                        value: Some(ftd::interpreter::PropertyValue::Value {
+                            value: ftd::interpreter::Value::new_string("en"),
+                            is_mutable: false,
+                            line_number: 0,
+                        }),
+                        ..ftd::interpreter::Argument::default(
+                            "locale",
+                            ftd::interpreter::Kind::string().into_kind_data(),
+                        )
+                    },
+                ],
+                ftd::interpreter::Kind::string(),
+            ),
+        ),
+        (
+            "ftd#relative-time".to_string(),
+            kernel_function(
+                "ftd#relative-time",
+                vec![
+                    ftd::interpreter::Argument::default(
+                        "d",
+                        ftd::interpreter::Kind::datetime().into_kind_data(),
+                    ),
+                    ftd::interpreter::Argument {
+                        value: Some(ftd::interpreter::PropertyValue::Value {
+                            value: ftd::interpreter::Value::new_none(
+                                ftd::interpreter::Kind::datetime().into_kind_data(),
+                            ),
+                            is_mutable: false,
+                            line_number: 0,
+                        }),
+                        ..ftd::interpreter::Argument::default(
+                            "now",
+                            ftd::interpreter::Kind::datetime()
+                                .into_optional()
+                                .into_kind_data(),
+                        )
+                    },
+                ],
+                ftd::interpreter::Kind::string(),
+            ),
+        ),
+        (
+            "ftd#compare-dates".to_string(),
+            kernel_function(
+                "ftd#compare-dates",
+                vec![
+                    ftd::interpreter::Argument::default(
+                        "a",
+                        ftd::interpreter::Kind::datetime().into_kind_data(),
+                    ),
+                    ftd::interpreter::Argument::default(
+                        "b",
+                        ftd::interpreter::Kind::datetime().into_kind_data(),
+                    ),
+                ],
+                ftd::interpreter::Kind::integer(),
+            ),
+        ),
+        (
+            "ftd#add-duration".to_string(),
+            kernel_function(
+                "ftd#add-duration",
+                vec![
+                    ftd::interpreter::Argument::default(
+                        "d",
+                        ftd::interpreter::Kind::datetime().into_kind_data(),
+                    ),
+                    ftd::interpreter::Argument::default(
+                        "by",
+                        ftd::interpreter::Kind::duration().into_kind_data(),
+                    ),
+                ],
+                ftd::interpreter::Kind::datetime(),
+            ),
+        ),
+        (
+            "ftd#date-difference".to_string(),
+            kernel_function(
+                "ftd#date-difference",
+                vec![
+                    ftd::interpreter::Argument::default(
+                        "a",
+                        ftd::interpreter::Kind::datetime().into_kind_data(),
+                    ),
+                    ftd::interpreter::Argument::default(
+                        "b",
+                        ftd::interpreter::Kind::datetime().into_kind_data(),
+                    ),
+                ],
+                ftd::interpreter::Kind::duration(),
+            ),
+        ),
+    ]
+}
+
+pub fn image_function() -> ftd::interpreter::ComponentDefinition {
+    ftd::interpreter::ComponentDefinition {
+        name: "ftd#image".to_string(),
+        arguments: [
+            common_arguments(),
+            vec![ftd::interpreter::Argument::default(
+                "src",
+                ftd::interpreter::Kind::record(ftd::interpreter::FTD_IMAGE_SRC)
+                    .into_kind_data()
+                    .caption(),
+            )],
+        ]
+        .concat()
+        .into_iter()
+        .collect(),
+        definition: ftd::interpreter::Component::from_name("ftd.kernel"),
+        css: None,
+        line_number: 0,
+    }
+}
+
+pub fn boolean_function() -> ftd::interpreter::ComponentDefinition {
+    ftd::interpreter::ComponentDefinition {
+        name: "ftd#boolean".to_string(),
+        arguments: [
+            text_arguments(),
+            common_arguments(),
+            vec![
+                ftd::interpreter::Argument::default(
+                    "value",
+                    ftd::interpreter::Kind::boolean()
+                        .into_kind_data()
+                        .caption_or_body(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "style",
+                    ftd::interpreter::Kind::string()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "format",
+                    ftd::interpreter::Kind::string()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "text-align",
+                    ftd::interpreter::Kind::string()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+            ],
+        ]
+        .concat()
+        .into_iter()
+        .collect(),
+        definition: ftd::interpreter::Component::from_name("ftd.kernel"),
+        css: None,
+        line_number: 0,
+    }
+}
+
+pub fn checkbox_function() -> ftd::interpreter::ComponentDefinition {
+    ftd::interpreter::ComponentDefinition {
+        name: "ftd#checkbox".to_string(),
+        arguments: [
+            common_arguments(),
+            field_arguments(),
+            vec![
+                ftd::interpreter::Argument::default(
+                    "checked",
+                    ftd::interpreter::Kind::boolean()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "enabled",
+                    ftd::interpreter::Kind::boolean()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+            ],
+        ]
+        .concat()
+        .into_iter()
+        .collect(),
+        definition: ftd::interpreter::Component::from_name("ftd.kernel"),
+        css: None,
+        line_number: 0,
+    }
+}
+
+pub fn text_input_function() -> ftd::interpreter::ComponentDefinition {
+    ftd::interpreter::ComponentDefinition {
+        name: "ftd#text-input".to_string(),
+        arguments: [
+            text_arguments(),
+            common_arguments(),
+            field_arguments(),
             vec![
+                ftd::interpreter::Argument::default(
+                    "placeholder",
+                    ftd::interpreter::Kind::string()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
                 ftd::interpreter::Argument::default(
                     "value",
+                    ftd::interpreter::Kind::string()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "default-value",
+                    ftd::interpreter::Kind::string()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "multiline",
                     ftd::interpreter::Kind::boolean()
-                        .into_kind_data()
-                        .caption_or_body(),
+                        .into_optional()
+                        .into_kind_data(),
                 ),
                 ftd::interpreter::Argument::default(
-                    "style",
+                    "enabled",
+                    ftd::interpreter::Kind::boolean()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "type",
+                    ftd::interpreter::Kind::or_type(ftd::interpreter::FTD_TEXT_INPUT_TYPE)
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "min",
                     ftd::interpreter::Kind::string()
                         .into_optional()
                         .into_kind_data(),
                 ),
                 ftd::interpreter::Argument::default(
-                    "format",
+                    "max",
                     ftd::interpreter::Kind::string()
                         .into_optional()
                         .into_kind_data(),
                 ),
                 ftd::interpreter::Argument::default(
-                    "text-align",
+                    "step",
+                    ftd::interpreter::Kind::decimal()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "pattern",
+                    ftd::interpreter::Kind::string()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+            ],
+        ]
+        .concat()
+        .into_iter()
+        .collect(),
+        definition: ftd::interpreter::Component::from_name("ftd.kernel"),
+        css: None,
+        line_number: 0,
+    }
+}
+
+pub fn select_function() -> ftd::interpreter::ComponentDefinition {
+    ftd::interpreter::ComponentDefinition {
+        name: "ftd#select".to_string(),
+        arguments: [
+            text_arguments(),
+            common_arguments(),
+            field_arguments(),
+            vec![
+                ftd::interpreter::Argument::default(
+                    "options",
+                    ftd::interpreter::Kind::record(ftd::interpreter::FTD_OPTION)
+                        .into_list()
+                        .into_kind_data(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "value",
+                    ftd::interpreter::Kind::string()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "placeholder",
+                    ftd::interpreter::Kind::string()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "enabled",
+                    ftd::interpreter::Kind::boolean()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+            ],
+        ]
+        .concat()
+        .into_iter()
+        .collect(),
+        definition: ftd::interpreter::Component::from_name("ftd.kernel"),
+        css: None,
+        line_number: 0,
+    }
+}
+
+pub fn radio_group_function() -> ftd::interpreter::ComponentDefinition {
+    ftd::interpreter::ComponentDefinition {
+        name: "ftd#radio-group".to_string(),
+        arguments: [
+            text_arguments(),
+            common_arguments(),
+            field_arguments(),
+            vec![
+                ftd::interpreter::Argument::default(
+                    "options",
+                    ftd::interpreter::Kind::record(ftd::interpreter::FTD_OPTION)
+                        .into_list()
+                        .into_kind_data(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "value",
+                    ftd::interpreter::Kind::string()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "enabled",
+                    ftd::interpreter::Kind::boolean()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+            ],
+        ]
+        .concat()
+        .into_iter()
+        .collect(),
+        definition: ftd::interpreter::Component::from_name("ftd.kernel"),
+        css: None,
+        line_number: 0,
+    }
+}
+
+pub fn form_function() -> ftd::interpreter::ComponentDefinition {
+    ftd::interpreter::ComponentDefinition {
+        name: "ftd#form".to_string(),
+        arguments: [
+            container_root_arguments(),
+            container_arguments(),
+            common_arguments(),
+            vec![
+                ftd::interpreter::Argument::default(
+                    "action",
+                    ftd::interpreter::Kind::string()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "method",
                     ftd::interpreter::Kind::string()
                         .into_optional()
                         .into_kind_data(),
@@ -9308,22 +11481,189 @@ pub fn boolean_function() -> ftd::interpreter::ComponentDefinition {
     }
 }
 
-pub fn checkbox_function() -> ftd::interpreter::ComponentDefinition {
+pub fn dialog_function() -> ftd::interpreter::ComponentDefinition {
+    ftd::interpreter::ComponentDefinition {
+        name: "ftd#dialog".to_string(),
+        arguments: [
+            container_root_arguments(),
+            container_arguments(),
+            common_arguments(),
+            overlay_arguments(),
+            vec![ftd::interpreter::Argument::default(
+                "close-on-outside-click",
+                ftd::interpreter::Kind::boolean()
+                    .into_optional()
+                    .into_kind_data(),
+            )],
+        ]
+        .concat()
+        .into_iter()
+        .collect(),
+        definition: ftd::interpreter::Component::from_name("ftd.kernel"),
+        css: None,
+        line_number: 0,
+    }
+}
+
+pub fn popover_function() -> ftd::interpreter::ComponentDefinition {
+    ftd::interpreter::ComponentDefinition {
+        name: "ftd#popover".to_string(),
+        arguments: [
+            container_root_arguments(),
+            container_arguments(),
+            common_arguments(),
+            overlay_arguments(),
+            anchored_overlay_arguments(),
+            vec![ftd::interpreter::Argument::default(
+                "close-on-outside-click",
+                ftd::interpreter::Kind::boolean()
+                    .into_optional()
+                    .into_kind_data(),
+            )],
+        ]
+        .concat()
+        .into_iter()
+        .collect(),
+        definition: ftd::interpreter::Component::from_name("ftd.kernel"),
+        css: None,
+        line_number: 0,
+    }
+}
+
+pub fn tooltip_function() -> ftd::interpreter::ComponentDefinition {
+    ftd::interpreter::ComponentDefinition {
+        name: "ftd#tooltip".to_string(),
+        arguments: [
+            container_root_arguments(),
+            container_arguments(),
+            common_arguments(),
+            overlay_arguments(),
+            anchored_overlay_arguments(),
+        ]
+        .concat()
+        .into_iter()
+        .collect(),
+        definition: ftd::interpreter::Component::from_name("ftd.kernel"),
+        css: None,
+        line_number: 0,
+    }
+}
+
+/// `open` is bound to a mutable boolean, the overlay sets it to `false` when it is closed with
+/// Escape or an outside click.
+fn overlay_arguments() -> Vec<ftd::interpreter::Argument> {
+    vec![
+        ftd::interpreter::Argument {
+            mutable: true,
+            ..ftd::interpreter::Argument::default(
+                "open",
+                ftd::interpreter::Kind::boolean().into_kind_data(),
+            )
+        },
+        ftd::interpreter::Argument::default(
+            "close-on-escape",
+            ftd::interpreter::Kind::boolean()
+                .into_optional()
+                .into_kind_data(),
+        ),
+    ]
+}
+
+/// The `id` of the element a popover or tooltip is shown next to, and on which side.
+fn anchored_overlay_arguments() -> Vec<ftd::interpreter::Argument> {
+    vec![
+        ftd::interpreter::Argument::default(
+            "trigger",
+            ftd::interpreter::Kind::string().into_kind_data(),
+        ),
+        ftd::interpreter::Argument::default(
+            "placement",
+            ftd::interpreter::Kind::or_type(ftd::interpreter::FTD_PLACEMENT)
+                .into_optional()
+                .into_kind_data(),
+        ),
+    ]
+}
+
+pub fn video_function() -> ftd::interpreter::ComponentDefinition {
+    ftd::interpreter::ComponentDefinition {
+        name: "ftd#video".to_string(),
+        arguments: [
+            common_arguments(),
+            media_arguments(),
+            vec![
+                ftd::interpreter::Argument::default(
+                    "poster",
+                    ftd::interpreter::Kind::record(ftd::interpreter::FTD_IMAGE_SRC)
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "captions",
+                    ftd::interpreter::Kind::record(ftd::interpreter::FTD_TRACK)
+                        .into_list()
+                        .into_kind_data(),
+                ),
+            ],
+        ]
+        .concat()
+        .into_iter()
+        .collect(),
+        definition: ftd::interpreter::Component::from_name("ftd.kernel"),
+        css: None,
+        line_number: 0,
+    }
+}
+
+pub fn audio_function() -> ftd::interpreter::ComponentDefinition {
+    ftd::interpreter::ComponentDefinition {
+        name: "ftd#audio".to_string(),
+        arguments: [common_arguments(), media_arguments()]
+            .concat()
+            .into_iter()
+            .collect(),
+        definition: ftd::interpreter::Component::from_name("ftd.kernel"),
+        css: None,
+        line_number: 0,
+    }
+}
+
+pub fn svg_function() -> ftd::interpreter::ComponentDefinition {
+    ftd::interpreter::ComponentDefinition {
+        name: "ftd#svg".to_string(),
+        arguments: [
+            common_arguments(),
+            paint_arguments(),
+            vec![ftd::interpreter::Argument::default(
+                "src",
+                ftd::interpreter::Kind::record(ftd::interpreter::FTD_IMAGE_SRC)
+                    .into_kind_data()
+                    .caption(),
+            )],
+        ]
+        .concat()
+        .into_iter()
+        .collect(),
+        definition: ftd::interpreter::Component::from_name("ftd.kernel"),
+        css: None,
+        line_number: 0,
+    }
+}
+
+pub fn icon_function() -> ftd::interpreter::ComponentDefinition {
     ftd::interpreter::ComponentDefinition {
-        name: "ftd#checkbox".to_string(),
+        name: "ftd#icon".to_string(),
         arguments: [
             common_arguments(),
+            paint_arguments(),
             vec![
                 ftd::interpreter::Argument::default(
-                    "checked",
-                    ftd::interpreter::Kind::boolean()
-                        .into_optional()
-                        .into_kind_data(),
+                    "name",
+                    ftd::interpreter::Kind::string().into_kind_data().caption(),
                 ),
                 ftd::interpreter::Argument::default(
-                    "enabled",
-                    ftd::interpreter::Kind::boolean()
-                        .into_optional()
+                    "sprite",
+                    ftd::interpreter::Kind::record(ftd::interpreter::FTD_IMAGE_SRC)
                         .into_kind_data(),
                 ),
             ],
@@ -9337,46 +11677,58 @@ pub fn checkbox_function() -> ftd::interpreter::ComponentDefinition {
     }
 }
 
-pub fn text_input_function() -> ftd::interpreter::ComponentDefinition {
+pub fn table_function() -> ftd::interpreter::ComponentDefinition {
     ftd::interpreter::ComponentDefinition {
-        name: "ftd#text-input".to_string(),
+        name: "ftd#table".to_string(),
         arguments: [
-            text_arguments(),
             common_arguments(),
             vec![
                 ftd::interpreter::Argument::default(
-                    "placeholder",
-                    ftd::interpreter::Kind::string()
-                        .into_optional()
+                    "data",
+                    ftd::interpreter::Kind::type_parameter("T")
+                        .into_list()
                         .into_kind_data(),
                 ),
                 ftd::interpreter::Argument::default(
-                    "value",
+                    "columns",
+                    ftd::interpreter::Kind::record(ftd::interpreter::FTD_TABLE_COLUMN)
+                        .into_list()
+                        .into_kind_data(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "title",
                     ftd::interpreter::Kind::string()
+                        .into_optional()
+                        .into_kind_data()
+                        .caption(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "filterable",
+                    ftd::interpreter::Kind::boolean()
                         .into_optional()
                         .into_kind_data(),
                 ),
                 ftd::interpreter::Argument::default(
-                    "default-value",
-                    ftd::interpreter::Kind::string()
+                    "page-size",
+                    ftd::interpreter::Kind::integer()
                         .into_optional()
                         .into_kind_data(),
                 ),
                 ftd::interpreter::Argument::default(
-                    "multiline",
-                    ftd::interpreter::Kind::boolean()
+                    "page-param",
+                    ftd::interpreter::Kind::string()
                         .into_optional()
                         .into_kind_data(),
                 ),
                 ftd::interpreter::Argument::default(
-                    "enabled",
-                    ftd::interpreter::Kind::boolean()
+                    "page",
+                    ftd::interpreter::Kind::integer()
                         .into_optional()
                         .into_kind_data(),
                 ),
                 ftd::interpreter::Argument::default(
-                    "type",
-                    ftd::interpreter::Kind::or_type(ftd::interpreter::FTD_TEXT_INPUT_TYPE)
+                    "total",
+                    ftd::interpreter::Kind::integer()
                         .into_optional()
                         .into_kind_data(),
                 ),
@@ -9596,6 +11948,60 @@ pub fn container_function() -> ftd::interpreter::ComponentDefinition {
     }
 }
 
+pub fn grid_function() -> ftd::interpreter::ComponentDefinition {
+    ftd::interpreter::ComponentDefinition {
+        name: "ftd#grid".to_string(),
+        arguments: [
+            container_root_arguments(),
+            common_arguments(),
+            vec![
+                ftd::interpreter::Argument::default(
+                    "columns",
+                    ftd::interpreter::Kind::or_type(ftd::interpreter::FTD_GRID_TEMPLATE)
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "rows",
+                    ftd::interpreter::Kind::or_type(ftd::interpreter::FTD_GRID_TEMPLATE)
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "areas",
+                    ftd::interpreter::Kind::string()
+                        .into_list()
+                        .into_kind_data(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "gap",
+                    ftd::interpreter::Kind::or_type(ftd::interpreter::FTD_LENGTH)
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "row-gap",
+                    ftd::interpreter::Kind::or_type(ftd::interpreter::FTD_LENGTH)
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "column-gap",
+                    ftd::interpreter::Kind::or_type(ftd::interpreter::FTD_LENGTH)
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+            ],
+        ]
+        .concat()
+        .into_iter()
+        .collect(),
+        definition: ftd::interpreter::Component::from_name("ftd.kernel"),
+        css: None,
+        line_number: 0,
+    }
+}
+
 pub fn desktop_function() -> ftd::interpreter::ComponentDefinition {
     ftd::interpreter::ComponentDefinition {
         name: "ftd#desktop".to_string(),
@@ -9722,6 +12128,12 @@ pub fn document_function() -> ftd::interpreter::ComponentDefinition {
                     .into_optional()
                     .into_kind_data(),
             ),
+            ftd::interpreter::Argument::default(
+                "breakpoints",
+                ftd::interpreter::Kind::record(ftd::interpreter::FTD_BREAKPOINT)
+                    .into_list()
+                    .into_kind_data(),
+            ),
             ftd::interpreter::Argument::default(
                 "title",
                 ftd::interpreter::Kind::string()
@@ -9910,7 +12322,37 @@ fn container_arguments() -> Vec<ftd::interpreter::Argument> {
 }
 
 fn common_arguments() -> Vec<ftd::interpreter::Argument> {
-    vec![
+    let mut arguments = vec![
+        ftd::interpreter::Argument::default(
+            "grid-area",
+            ftd::interpreter::Kind::string()
+                .into_optional()
+                .into_kind_data(),
+        ),
+        ftd::interpreter::Argument::default(
+            "column-start",
+            ftd::interpreter::Kind::integer()
+                .into_optional()
+                .into_kind_data(),
+        ),
+        ftd::interpreter::Argument::default(
+            "column-span",
+            ftd::interpreter::Kind::integer()
+                .into_optional()
+                .into_kind_data(),
+        ),
+        ftd::interpreter::Argument::default(
+            "row-start",
+            ftd::interpreter::Kind::integer()
+                .into_optional()
+                .into_kind_data(),
+        ),
+        ftd::interpreter::Argument::default(
+            "row-span",
+            ftd::interpreter::Kind::integer()
+                .into_optional()
+                .into_kind_data(),
+        ),
         ftd::interpreter::Argument::default(
             "opacity",
             ftd::interpreter::Kind::decimal()
@@ -9923,6 +12365,24 @@ fn common_arguments() -> Vec<ftd::interpreter::Argument> {
                 .into_optional()
                 .into_kind_data(),
         ),
+        ftd::interpreter::Argument::default(
+            "transform",
+            ftd::interpreter::Kind::record(ftd::interpreter::FTD_TRANSFORM)
+                .into_optional()
+                .into_kind_data(),
+        ),
+        ftd::interpreter::Argument::default(
+            "transition",
+            ftd::interpreter::Kind::record(ftd::interpreter::FTD_TRANSITION)
+                .into_optional()
+                .into_kind_data(),
+        ),
+        ftd::interpreter::Argument::default(
+            "animation",
+            ftd::interpreter::Kind::record(ftd::interpreter::FTD_ANIMATION)
+                .into_optional()
+                .into_kind_data(),
+        ),
         ftd::interpreter::Argument::default(
             "sticky",
             ftd::interpreter::Kind::boolean()
@@ -10325,6 +12785,159 @@ fn common_arguments() -> Vec<ftd::interpreter::Argument> {
                 .into_optional()
                 .into_kind_data(),
         ),
+        ftd::interpreter::Argument::default(
+            "alt",
+            ftd::interpreter::Kind::string()
+                .into_optional()
+                .into_kind_data(),
+        ),
+        ftd::interpreter::Argument::default(
+            "aria-label",
+            ftd::interpreter::Kind::string()
+                .into_optional()
+                .into_kind_data(),
+        ),
+        ftd::interpreter::Argument::default(
+            "aria-describedby",
+            ftd::interpreter::Kind::string()
+                .into_optional()
+                .into_kind_data(),
+        ),
+        ftd::interpreter::Argument::default(
+            "aria-hidden",
+            ftd::interpreter::Kind::boolean()
+                .into_optional()
+                .into_kind_data(),
+        ),
+        ftd::interpreter::Argument::default(
+            "aria-live",
+            ftd::interpreter::Kind::or_type(ftd::interpreter::FTD_ARIA_LIVE)
+                .into_optional()
+                .into_kind_data(),
+        ),
+        ftd::interpreter::Argument::default(
+            "landmark",
+            ftd::interpreter::Kind::or_type(ftd::interpreter::FTD_LANDMARK)
+                .into_optional()
+                .into_kind_data(),
+        ),
+        ftd::interpreter::Argument::default(
+            "tab-index",
+            ftd::interpreter::Kind::integer()
+                .into_optional()
+                .into_kind_data(),
+        ),
+    ];
+    arguments.extend(state_arguments());
+    arguments
+}
+
+/// `color.hover`, `background.focus`, `border-color.active` etc, the values these properties
+/// take while the element is hovered, focused or pressed.
+fn state_arguments() -> Vec<ftd::interpreter::Argument> {
+    let mut arguments = vec![];
+    for state in ftd::interpreter::FTD_ELEMENT_STATES {
+        arguments.extend([
+            ftd::interpreter::Argument::default(
+                format!("color.{}", state).as_str(),
+                ftd::interpreter::Kind::record(ftd::interpreter::FTD_COLOR)
+                    .into_optional()
+                    .into_kind_data(),
+            ),
+            ftd::interpreter::Argument::default(
+                format!("background.{}", state).as_str(),
+                ftd::interpreter::Kind::or_type(ftd::interpreter::FTD_BACKGROUND)
+                    .into_optional()
+                    .into_kind_data(),
+            ),
+            ftd::interpreter::Argument::default(
+                format!("border-color.{}", state).as_str(),
+                ftd::interpreter::Kind::record(ftd::interpreter::FTD_COLOR)
+                    .into_optional()
+                    .into_kind_data(),
+            ),
+            ftd::interpreter::Argument::default(
+                format!("shadow.{}", state).as_str(),
+                ftd::interpreter::Kind::record(ftd::interpreter::FTD_SHADOW)
+                    .into_optional()
+                    .into_kind_data(),
+            ),
+        ]);
+    }
+    arguments
+}
+
+/// The arguments of the controls of an `ftd.form`, `name` is the field of the submitted record
+/// that gets the value of the control.
+fn field_arguments() -> Vec<ftd::interpreter::Argument> {
+    vec![
+        ftd::interpreter::Argument::default(
+            "name",
+            ftd::interpreter::Kind::string()
+                .into_optional()
+                .into_kind_data(),
+        ),
+        ftd::interpreter::Argument::default(
+            "required",
+            ftd::interpreter::Kind::boolean()
+                .into_optional()
+                .into_kind_data(),
+        ),
+    ]
+}
+
+/// The sources and the playback options shared by `ftd.video` and `ftd.audio`.
+fn media_arguments() -> Vec<ftd::interpreter::Argument> {
+    vec![
+        ftd::interpreter::Argument::default(
+            "sources",
+            ftd::interpreter::Kind::record(ftd::interpreter::FTD_MEDIA_SOURCE)
+                .into_list()
+                .into_kind_data(),
+        ),
+        ftd::interpreter::Argument::default(
+            "controls",
+            ftd::interpreter::Kind::boolean()
+                .into_optional()
+                .into_kind_data(),
+        ),
+        ftd::interpreter::Argument::default(
+            "autoplay",
+            ftd::interpreter::Kind::boolean()
+                .into_optional()
+                .into_kind_data(),
+        ),
+        ftd::interpreter::Argument::default(
+            "muted",
+            ftd::interpreter::Kind::boolean()
+                .into_optional()
+                .into_kind_data(),
+        ),
+        ftd::interpreter::Argument::default(
+            "loop",
+            ftd::interpreter::Kind::boolean()
+                .into_optional()
+                .into_kind_data(),
+        ),
+    ]
+}
+
+/// The `fill` and `stroke` of the shapes of an `ftd.svg` or an `ftd.icon`, shapes that set their
+/// own keep it.
+fn paint_arguments() -> Vec<ftd::interpreter::Argument> {
+    vec![
+        ftd::interpreter::Argument::default(
+            "fill",
+            ftd::interpreter::Kind::record(ftd::interpreter::FTD_COLOR)
+                .into_optional()
+                .into_kind_data(),
+        ),
+        ftd::interpreter::Argument::default(
+            "stroke",
+            ftd::interpreter::Kind::record(ftd::interpreter::FTD_COLOR)
+                .into_optional()
+                .into_kind_data(),
+        ),
     ]
 }
 
@@ -10364,6 +12977,34 @@ fn text_arguments() -> Vec<ftd::interpreter::Argument> {
     ]
 }
 
+/// A function of the `ftd` module that calls the function of the same name in the default
+/// context, e.g. `ftd#format-date` calls `ftd.format_date`, which is a built-in function of evalexpr
+/// on the server and is in `variables.js` in the browser.
+fn kernel_function(
+    name: &str,
+    arguments: Vec<ftd::interpreter::Argument>,
+    return_kind: ftd::interpreter::Kind,
+) -> ftd::interpreter::Thing {
+    use itertools::Itertools;
+
+    let function_name = name.replace('#', ".").replace('-', "_");
+    ftd::interpreter::Thing::Function(ftd::interpreter::Function {
+        name: name.to_string(),
+        return_kind: return_kind.into_kind_data(),
+        expression: vec![ftd::interpreter::things::function::Expression {
+            expression: format!(
+                "{}({})",
+                function_name,
+                arguments.iter().map(|v| v.name.as_str()).join(", ")
+            ),
+            line_number: 0,
+        }],
+        arguments,
+        js: None,
+        line_number: 0,
+    })
+}
+
 /*fn kernel_component() -> ftd::interpreter::ComponentDefinition {
     ftd::interpreter::ComponentDefinition {
         name: "ftd.kernel".to_string(),
@@ -226,6 +226,26 @@ impl Expression {
                             identifier: value.to_string(),
                         }
                     }
+                } else if format!("${}", ftd::interpreter::FTD_LOOP_KEY).eq(identifier) {
+                    match references.get(identifier) {
+                        Some(ftd::interpreter::PropertyValue::Value {
+                            value: ftd::interpreter::Value::String { text },
+                            ..
+                        }) => {
+                            operator = fastn_grammar::evalexpr::Operator::VariableIdentifierRead {
+                                identifier: format!("{:?}", text),
+                            }
+                        }
+                        Some(ftd::interpreter::PropertyValue::Value {
+                            value: ftd::interpreter::Value::Integer { value },
+                            ..
+                        }) => {
+                            operator = fastn_grammar::evalexpr::Operator::VariableIdentifierRead {
+                                identifier: value.to_string(),
+                            }
+                        }
+                        _ => {}
+                    }
                 } else if let Some(ftd::interpreter::PropertyValue::Reference { name, .. }) =
                     references.get(identifier)
                 {
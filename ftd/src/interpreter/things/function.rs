@@ -83,8 +83,26 @@ impl Function {
             function.line_number,
         )?);
 
+        let body = match ftd::ast::Match::from_function_body(
+            function.definition.value.as_str(),
+            doc.name,
+            function.definition.line_number,
+        )? {
+            Some((match_, expressions)) => try_ok_state!(Function::match_to_expression(
+                match_,
+                expressions,
+                arguments.as_slice(),
+                doc
+            )?),
+            None => function.definition.value.to_string(),
+        };
+
+        if let Ok(body) = fastn_grammar::evalexpr::build_operator_tree(body.as_str()) {
+            check_regex_patterns(&body, doc.name, function.line_number)?;
+        }
+
         let expression = vec![Expression {
-            expression: function.definition.value.to_string(),
+            expression: body,
             line_number: function.definition.line_number,
         }];
 
@@ -98,9 +116,67 @@ impl Function {
         )))
     }
 
+    /// A `match` over an or-type argument as nested `if`s, the last arm is the `else` of the
+    /// others. `circle(c) => c * 2.0` becomes
+    /// `if(ftd.is_variant(s, "circle"), ftd.variant_value(s, "circle") * 2.0, ..)`.
+    fn match_to_expression(
+        match_: ftd::ast::Match,
+        expressions: Vec<String>,
+        arguments: &[ftd::interpreter::Argument],
+        doc: &mut ftd::interpreter::TDoc,
+    ) -> ftd::interpreter::Result<ftd::interpreter::StateWithThing<String>> {
+        let subject = match_.subject.as_str();
+        let or_type_name = match arguments.iter().find(|a| a.name.eq(subject)) {
+            Some(ftd::interpreter::Argument {
+                kind:
+                    ftd::interpreter::KindData {
+                        kind: ftd::interpreter::Kind::OrType { name, .. },
+                        ..
+                    },
+                ..
+            }) => name.to_string(),
+            _ => {
+                return ftd::interpreter::utils::e2(
+                    format!(
+                        "`match` in a function expects an argument of an or-type, found: `{}`",
+                        subject
+                    ),
+                    doc.name,
+                    match_.line_number,
+                )
+            }
+        };
+        let or_type = try_ok_state!(doc.search_or_type(or_type_name.as_str(), match_.line_number)?);
+        or_type.check_match(&match_, doc.name)?;
+
+        let mut expression: Option<String> = None;
+        for (arm, arm_expression) in match_.arms.iter().zip(expressions).rev() {
+            let arm_expression = match (arm.variant.as_ref(), arm.binding.as_ref()) {
+                (Some(variant), Some(binding)) => ftd::ast::utils::rename_reference(
+                    arm_expression.as_str(),
+                    binding,
+                    format!("ftd.variant_value({}, \"{}\")", subject, variant).as_str(),
+                    true,
+                ),
+                _ => arm_expression,
+            };
+            expression = Some(match (expression, arm.variant.as_ref()) {
+                (Some(otherwise), Some(variant)) => format!(
+                    "if(ftd.is_variant({}, \"{}\"), {}, {})",
+                    subject, variant, arm_expression, otherwise
+                ),
+                _ => arm_expression,
+            });
+        }
+
+        Ok(ftd::interpreter::StateWithThing::new_thing(
+            expression.unwrap_or_default(),
+        ))
+    }
+
     pub(crate) fn resolve(
         &self,
-        _kind: &ftd::interpreter::KindData,
+        kind: &ftd::interpreter::KindData,
         values: &ftd::Map<ftd::interpreter::PropertyValue>,
         doc: &ftd::interpreter::TDoc,
         line_number: usize,
@@ -126,6 +202,7 @@ impl Function {
                         ),
                         doc_id: doc.name.to_string(),
                         line_number,
+                        span: None,
                     })?;
             if !argument.mutable.eq(&function_value.is_mutable()) {
                 return ftd::interpreter::utils::e2(
@@ -197,6 +274,9 @@ impl Function {
             }
         }
 
+        if self.return_kind.kind.has_type_parameter() {
+            return Ok(Some(generic_value(eval, &kind.kind, doc, line_number)?));
+        }
         if !self.return_kind.is_void() {
             return Ok(Some(ftd::interpreter::Value::from_evalexpr_value(
                 eval,
@@ -385,6 +465,8 @@ impl FunctionCall {
             order.push(argument.name.to_string());
         }
 
+        check_regex_patterns_of_call(function.name.as_str(), &values, doc.name, line_number)?;
+
         let reference_full_name = ftd::interpreter::PropertyValueSource::Global
             .get_reference_name(function_name.as_str(), doc);
 
@@ -400,3 +482,157 @@ impl FunctionCall {
         ))
     }
 }
+
+/// The value returned by a generic function, like `ftd#map`, of the kind expected at the call
+/// site. It goes through JSON, as records are JSON strings in evalexpr. Without an expected kind
+/// the kind is taken from the value, which works for everything but records.
+fn generic_value(
+    value: fastn_grammar::evalexpr::Value,
+    kind: &ftd::interpreter::Kind,
+    doc: &ftd::interpreter::TDoc,
+    line_number: usize,
+) -> ftd::interpreter::Result<ftd::interpreter::Value> {
+    fn kind_of(json: &serde_json::Value) -> Option<ftd::interpreter::Kind> {
+        Some(match json {
+            serde_json::Value::Bool(_) => ftd::interpreter::Kind::boolean(),
+            serde_json::Value::Number(n) if n.is_i64() => ftd::interpreter::Kind::integer(),
+            serde_json::Value::Number(_) => ftd::interpreter::Kind::decimal(),
+            serde_json::Value::String(_) => ftd::interpreter::Kind::string(),
+            serde_json::Value::Array(items) => match items.first() {
+                Some(item) => kind_of(item)?.into_list(),
+                None => ftd::interpreter::Kind::string().into_list(),
+            },
+            serde_json::Value::Null => ftd::interpreter::Kind::string().into_optional(),
+            serde_json::Value::Object(_) => return None,
+        })
+    }
+
+    let json = ftd::interpreter::default::evalexpr_value_to_json(value);
+    let kind = if kind.has_type_parameter() {
+        match kind_of(&json) {
+            Some(kind) => kind,
+            None => {
+                return ftd::interpreter::utils::e2(
+                    format!(
+                        "Can't tell the kind of `{}`, found: `{:?}`. Help: use a variable of \
+                        the kind expected",
+                        json, kind
+                    ),
+                    doc.name,
+                    line_number,
+                )
+            }
+        }
+    } else {
+        kind.to_owned()
+    };
+    doc.from_json(&json, &kind, line_number)
+}
+
+/// Checks the patterns written in the document for `ftd.regex_match` in `expression`, and in the
+/// item expressions of `ftd.map`, `ftd.filter` and `ftd.sort_by` in it. A pattern that is only
+/// ever matched in the browser is checked here too, as it must be one that matches the same way
+/// there.
+fn check_regex_patterns(
+    expression: &fastn_grammar::evalexpr::ExprNode,
+    doc_name: &str,
+    line_number: usize,
+) -> ftd::interpreter::Result<()> {
+    fn unwrap_root(
+        mut node: &fastn_grammar::evalexpr::ExprNode,
+    ) -> &fastn_grammar::evalexpr::ExprNode {
+        while matches!(node.operator(), fastn_grammar::evalexpr::Operator::RootNode)
+            && node.children().len() == 1
+        {
+            node = &node.children()[0];
+        }
+        node
+    }
+
+    if let fastn_grammar::evalexpr::Operator::FunctionIdentifier { identifier } =
+        expression.operator()
+    {
+        let second_argument = expression
+            .children()
+            .first()
+            .map(unwrap_root)
+            .and_then(|v| match (v.operator(), v.children()) {
+                (fastn_grammar::evalexpr::Operator::Tuple, [_, argument]) => {
+                    match unwrap_root(argument).operator() {
+                        fastn_grammar::evalexpr::Operator::Const {
+                            value: fastn_grammar::evalexpr::Value::String(argument),
+                        } => Some(argument.as_str()),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            });
+        if let Some(argument) = second_argument {
+            check_regex_argument(identifier.as_str(), argument, doc_name, line_number)?;
+        }
+    }
+
+    for child in expression.children() {
+        check_regex_patterns(child, doc_name, line_number)?;
+    }
+    Ok(())
+}
+
+/// `check_regex_patterns` for a call of `ftd#regex-match`, `ftd#map`, `ftd#filter` or
+/// `ftd#sort-by` written as `$ftd.regex-match(text = $t, pattern = ^a)`.
+fn check_regex_patterns_of_call(
+    function_name: &str,
+    values: &ftd::Map<ftd::interpreter::PropertyValue>,
+    doc_name: &str,
+    line_number: usize,
+) -> ftd::interpreter::Result<()> {
+    let argument = match function_name {
+        "ftd#regex-match" => "pattern",
+        "ftd#map" => "expression",
+        "ftd#filter" => "condition",
+        "ftd#sort-by" => "key",
+        _ => return Ok(()),
+    };
+    match values.get(argument) {
+        Some(ftd::interpreter::PropertyValue::Value {
+            value: ftd::interpreter::Value::String { text },
+            ..
+        }) => check_regex_argument(
+            function_name.replace('#', ".").replace('-', "_").as_str(),
+            text,
+            doc_name,
+            line_number,
+        ),
+        _ => Ok(()),
+    }
+}
+
+/// `argument` is the second argument of a call to `function_name`, the pattern of
+/// `ftd.regex_match` or the item expression of the others.
+fn check_regex_argument(
+    function_name: &str,
+    argument: &str,
+    doc_name: &str,
+    line_number: usize,
+) -> ftd::interpreter::Result<()> {
+    match function_name {
+        "ftd.regex_match" => match fastn_grammar::evalexpr::regex_subset::compile(argument) {
+            Ok(_) => Ok(()),
+            Err(fastn_grammar::evalexpr::EvalexprError::InvalidRegex { message, .. }) => {
+                ftd::interpreter::utils::e2(
+                    format!("Invalid regex `{}`: {}", argument, message),
+                    doc_name,
+                    line_number,
+                )
+            }
+            Err(e) => ftd::interpreter::utils::e2(e.to_string(), doc_name, line_number),
+        },
+        "ftd.map" | "ftd.filter" | "ftd.sort_by" => {
+            match fastn_grammar::evalexpr::build_operator_tree(argument) {
+                Ok(expression) => check_regex_patterns(&expression, doc_name, line_number),
+                Err(_) => Ok(()),
+            }
+        }
+        _ => Ok(()),
+    }
+}
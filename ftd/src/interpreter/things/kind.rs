@@ -5,6 +5,9 @@ pub enum Kind {
     Integer,
     Decimal,
     Boolean,
+    DateTime,
+    Date,
+    Duration,
     Record {
         name: String,
     }, // the full name of the record (full document name.record name)
@@ -19,6 +22,10 @@ pub enum Kind {
     Optional {
         kind: Box<Kind>,
     },
+    Map {
+        key: Box<Kind>,
+        kind: Box<Kind>,
+    }, // the keys are strings or integers, `kind` is the kind of the values
     UI {
         name: Option<String>,
         subsection_source: bool,
@@ -29,6 +36,9 @@ pub enum Kind {
     },
     Void,
     Module,
+    TypeParameter {
+        name: String,
+    }, // a type parameter of a generic definition, like `T` in `record paginated<T>`
 }
 
 impl Kind {
@@ -38,8 +48,12 @@ impl Kind {
             Kind::Integer { .. } => "integer".to_string(),
             Kind::Boolean { .. } => "boolean".to_string(),
             Kind::Decimal { .. } => "decimal".to_string(),
+            Kind::DateTime => "datetime".to_string(),
+            Kind::Date => "date".to_string(),
+            Kind::Duration => "duration".to_string(),
             Kind::Constant { .. } => "constant".to_string(),
             Kind::List { .. } => "list".to_string(),
+            Kind::Map { .. } => "map".to_string(),
             Kind::Object { .. } => "object".to_string(),
             Kind::OrType { name, .. } => name.clone(),
             Kind::Optional { .. } => "optional".to_string(),
@@ -47,6 +61,23 @@ impl Kind {
             Kind::Module => "module".to_string(),
             Kind::UI { name, .. } => name.clone().unwrap_or("record".to_string()),
             Kind::Record { name } => name.clone(),
+            Kind::TypeParameter { name } => name.clone(),
+        }
+    }
+
+    /// The name of this kind as a type argument, used to name the instances of generic records
+    /// and or-types, e.g. `foo#paginated<string list>`.
+    pub(crate) fn type_argument_name(&self) -> String {
+        match self {
+            Kind::List { kind } => format!("{} list", kind.type_argument_name()),
+            Kind::Optional { kind } => format!("optional {}", kind.type_argument_name()),
+            Kind::Map { key, kind } => format!(
+                "map<{}, {}>",
+                key.type_argument_name(),
+                kind.type_argument_name()
+            ),
+            Kind::UI { .. } => "ftd.ui".to_string(),
+            t => t.get_name(),
         }
     }
 
@@ -54,13 +85,92 @@ impl Kind {
         KindData::new(self)
     }
 
+    /// This kind with its type parameters replaced by `type_arguments`, e.g. `T list` is
+    /// `string list` for `T: string`. Instances of generic records and or-types with type
+    /// parameters as type arguments, like `paginated<T>`, are instantiated again.
+    pub(crate) fn instantiate(
+        self,
+        type_arguments: &ftd::Map<Kind>,
+        doc: &mut ftd::interpreter::TDoc,
+        line_number: usize,
+    ) -> ftd::interpreter::Result<ftd::interpreter::StateWithThing<Kind>> {
+        Ok(ftd::interpreter::StateWithThing::new_thing(match self {
+            Kind::TypeParameter { name } => match type_arguments.get(name.as_str()) {
+                Some(kind) => kind.clone(),
+                None => Kind::TypeParameter { name },
+            },
+            Kind::List { kind } => {
+                try_ok_state!((*kind).instantiate(type_arguments, doc, line_number)?).into_list()
+            }
+            Kind::Optional { kind } => {
+                try_ok_state!((*kind).instantiate(type_arguments, doc, line_number)?)
+                    .into_optional()
+            }
+            Kind::Map { key, kind } => {
+                try_ok_state!((*kind).instantiate(type_arguments, doc, line_number)?).into_map(*key)
+            }
+            Kind::Constant { kind } => Kind::Constant {
+                kind: Box::new(try_ok_state!((*kind).instantiate(
+                    type_arguments,
+                    doc,
+                    line_number
+                )?)),
+            },
+            Kind::Record { name } if is_generic(name.as_str()) => try_ok_state!(generic_kind(
+                substitute_type_arguments(name.as_str(), type_arguments).as_str(),
+                &Default::default(),
+                doc,
+                line_number
+            )?),
+            Kind::OrType {
+                name,
+                variant,
+                full_variant,
+            } if is_generic(name.as_str()) => {
+                let instance = try_ok_state!(generic_kind(
+                    substitute_type_arguments(name.as_str(), type_arguments).as_str(),
+                    &Default::default(),
+                    doc,
+                    line_number
+                )?)
+                .get_name();
+                let rename = |v: Option<String>| {
+                    v.map(|v| format!("{}{}", instance, v.trim_start_matches(name.as_str())))
+                };
+                Kind::OrType {
+                    variant: rename(variant),
+                    full_variant: rename(full_variant),
+                    name: instance,
+                }
+            }
+            k => k,
+        }))
+    }
+
     pub fn is_same_as(&self, other: &Self) -> bool {
         match (self, other) {
             (Self::UI { .. }, Self::UI { .. }) => true,
-            (Self::OrType { name: n1, .. }, Self::OrType { name: n2, .. }) => n1.eq(n2),
+            // type parameters that are not given a type argument are erased
+            (Self::TypeParameter { .. }, _) | (_, Self::TypeParameter { .. }) => true,
+            (Self::Record { name: n1 }, Self::Record { name: n2 })
+            | (Self::OrType { name: n1, .. }, Self::OrType { name: n2, .. }) => {
+                n1.eq(n2) || is_same_instance(n1, n2)
+            }
             (Self::Optional { kind, .. }, _) => kind.is_same_as(other),
             (_, Self::Optional { kind: other, .. }) => self.is_same_as(other),
             (Self::List { kind: k1 }, Self::List { kind: k2 }) => k1.is_same_as(k2),
+            (
+                Self::Map {
+                    key: key1,
+                    kind: k1,
+                },
+                Self::Map {
+                    key: key2,
+                    kind: k2,
+                },
+            ) => key1.eq(key2) && k1.is_same_as(k2),
+            // a date is a date time at midnight, in UTC
+            (Self::DateTime, Self::Date) | (Self::Date, Self::DateTime) => true,
             _ => self.eq(other),
         }
     }
@@ -81,6 +191,18 @@ impl Kind {
         Kind::Boolean
     }
 
+    pub fn datetime() -> Kind {
+        Kind::DateTime
+    }
+
+    pub fn date() -> Kind {
+        Kind::Date
+    }
+
+    pub fn duration() -> Kind {
+        Kind::Duration
+    }
+
     pub fn module() -> Kind {
         Kind::Module
     }
@@ -125,6 +247,40 @@ impl Kind {
         Kind::Void
     }
 
+    pub fn type_parameter(name: &str) -> Kind {
+        Kind::TypeParameter {
+            name: name.to_string(),
+        }
+    }
+
+    /// The kinds of the type arguments of `<arguments>` in an invocation of the generic
+    /// component `name`, keyed by its type parameters.
+    pub(crate) fn type_arguments(
+        name: &str,
+        arguments: Vec<String>,
+        doc: &mut ftd::interpreter::TDoc,
+        line_number: usize,
+    ) -> ftd::interpreter::Result<ftd::interpreter::StateWithThing<ftd::Map<Kind>>> {
+        let type_arguments = try_ok_state!(type_argument_kinds(
+            arguments,
+            &Default::default(),
+            doc,
+            line_number
+        )?);
+        Ok(ftd::interpreter::StateWithThing::new_thing(
+            type_arguments_by_parameter(name, type_arguments, doc, line_number)?,
+        ))
+    }
+
+    /// The known kinds for the type parameters of a generic definition.
+    pub(crate) fn type_parameters(
+        type_parameters: &[String],
+    ) -> impl Iterator<Item = (String, Kind)> + '_ {
+        type_parameters
+            .iter()
+            .map(|name| (name.to_string(), Kind::type_parameter(name)))
+    }
+
     pub fn record(name: &str) -> Kind {
         Kind::Record {
             name: name.to_string(),
@@ -153,6 +309,43 @@ impl Kind {
         }
     }
 
+    /// A map with `key` keys and values of this kind.
+    pub fn into_map(self, key: Kind) -> Kind {
+        Kind::Map {
+            key: Box::new(key),
+            kind: Box::new(self),
+        }
+    }
+
+    /// `key` as it is stored in a map with keys of this kind, `None` if it is not such a key.
+    /// Integer keys are stored the way the integer is written out, e.g. `7` for `07`.
+    pub fn map_key(&self, key: &str) -> Option<String> {
+        match self {
+            Kind::Integer => key.trim().parse::<i64>().ok().map(|v| v.to_string()),
+            _ => Some(key.to_string()),
+        }
+    }
+
+    /// The value of `key`, a key stored in a map with keys of this kind.
+    pub fn map_key_value(&self, key: &str) -> ftd::interpreter::Value {
+        match self {
+            Kind::Integer => ftd::interpreter::Value::Integer {
+                value: key.parse().unwrap_or_default(),
+            },
+            _ => ftd::interpreter::Value::new_string(key),
+        }
+    }
+
+    /// The entries of a map with keys of this kind, in the order of their keys: by value for
+    /// integer keys, alphabetically otherwise.
+    pub fn map_entries<T>(&self, data: ftd::Map<T>) -> Vec<(String, T)> {
+        let mut entries = data.into_iter().collect::<Vec<_>>();
+        if self.is_integer() {
+            entries.sort_by_key(|(key, _)| key.parse::<i64>().unwrap_or_default());
+        }
+        entries
+    }
+
     pub fn into_optional(self) -> Kind {
         Kind::Optional {
             kind: Box::new(self),
@@ -198,6 +391,10 @@ impl Kind {
         matches!(self, Kind::List { .. })
     }
 
+    pub fn is_map(&self) -> bool {
+        matches!(self, Kind::Map { .. })
+    }
+
     pub fn is_subsection_ui(&self) -> bool {
         matches!(
             self,
@@ -276,10 +473,38 @@ impl Kind {
         matches!(self, Kind::Decimal { .. })
     }
 
+    pub fn is_datetime(&self) -> bool {
+        matches!(self, Kind::DateTime)
+    }
+
+    pub fn is_date(&self) -> bool {
+        matches!(self, Kind::Date)
+    }
+
+    pub fn is_duration(&self) -> bool {
+        matches!(self, Kind::Duration)
+    }
+
     pub fn is_void(&self) -> bool {
         matches!(self, Kind::Void { .. })
     }
 
+    pub fn is_type_parameter(&self) -> bool {
+        matches!(self, Kind::TypeParameter { .. })
+    }
+
+    /// Whether this kind is or holds a type parameter, e.g. `T list`.
+    pub fn has_type_parameter(&self) -> bool {
+        match self {
+            Kind::TypeParameter { .. } => true,
+            Kind::List { kind }
+            | Kind::Optional { kind }
+            | Kind::Map { kind, .. }
+            | Kind::Constant { kind } => kind.has_type_parameter(),
+            _ => false,
+        }
+    }
+
     pub(crate) fn list_type(
         &self,
         doc_name: &str,
@@ -364,9 +589,23 @@ impl KindData {
     ) -> ftd::interpreter::Result<()> {
         let ast_kind = var_kind.kind;
         match ast_kind.as_ref() {
-            "string" | "object" | "integer" | "decimal" | "boolean" | "void" | "ftd.ui"
-            | "children" => Ok(()),
+            "string" | "object" | "integer" | "decimal" | "boolean" | "datetime" | "date"
+            | "duration" | "void" | "ftd.ui" | "children" => Ok(()),
             k if known_kinds.contains_key(k) => Ok(()),
+            k if is_map(k) => {
+                let (_, value_kind) = map_key_and_value_kind(k, doc.name, line_number)?;
+                KindData::scan_ast_kind(value_kind, known_kinds, doc, line_number)
+            }
+            k if is_generic(k) => {
+                let (name, arguments, _) = ftd::ast::utils::split_type_arguments(k).unwrap();
+                doc.scan_thing(name.as_str(), line_number)?;
+                for argument in arguments {
+                    let argument =
+                        ftd::ast::VariableKind::get_kind(argument.as_str(), doc.name, line_number)?;
+                    KindData::scan_ast_kind(argument, known_kinds, doc, line_number)?;
+                }
+                Ok(())
+            }
             k => doc.scan_thing(k, line_number),
         }
     }
@@ -408,6 +647,9 @@ impl KindData {
             "integer" => Kind::integer(),
             "decimal" => Kind::decimal(),
             "boolean" => Kind::boolean(),
+            "datetime" => Kind::datetime(),
+            "date" => Kind::date(),
+            "duration" => Kind::duration(),
             "void" => Kind::void(),
             "ftd.ui" => Kind::ui(),
             "module" => Kind::module(),
@@ -424,6 +666,18 @@ impl KindData {
                 }
             }
             k if known_kinds.contains_key(k) => known_kinds.get(k).unwrap().to_owned(),
+            k if is_map(k) => {
+                let (key_kind, value_kind) = map_key_and_value_kind(k, doc.name, line_number)?;
+                try_ok_state!(KindData::from_ast_kind(
+                    value_kind,
+                    known_kinds,
+                    doc,
+                    line_number
+                )?)
+                .kind
+                .into_map(key_kind)
+            }
+            k if is_generic(k) => try_ok_state!(generic_kind(k, known_kinds, doc, line_number)?),
             k => match try_ok_state!(doc.search_thing(k, line_number)?) {
                 ftd::interpreter::Thing::Record(r) => Kind::record(r.name.as_str()),
                 ftd::interpreter::Thing::Component(_) => Kind::ui(),
@@ -483,6 +737,10 @@ impl KindData {
         self.kind.is_list()
     }
 
+    pub fn is_map(&self) -> bool {
+        self.kind.is_map()
+    }
+
     pub fn is_or_type(&self) -> bool {
         self.kind.is_or_type()
     }
@@ -547,6 +805,18 @@ impl KindData {
         }
     }
 
+    pub fn inner_map(self) -> KindData {
+        let kind = match self.kind {
+            Kind::Map { kind, .. } => kind.as_ref().to_owned(),
+            t => t,
+        };
+        KindData {
+            kind,
+            caption: self.caption,
+            body: self.body,
+        }
+    }
+
     pub fn inner(self) -> KindData {
         let kind = match self.kind {
             Kind::Optional { kind } => kind.as_ref().to_owned(),
@@ -560,6 +830,254 @@ impl KindData {
     }
 }
 
+fn is_map(kind: &str) -> bool {
+    kind.starts_with("map<") && kind.ends_with('>')
+}
+
+fn is_generic(kind: &str) -> bool {
+    !is_map(kind) && ftd::ast::utils::split_type_arguments(kind).is_some()
+}
+
+/// The kind of `paginated<string>`, an instance of a generic record or or-type. Generic
+/// definitions are monomorphised: the instance is added to the bag as a record or or-type of
+/// its own, named `foo#paginated<string>`, with its type parameters replaced by the type
+/// arguments, so the executor and the js output only see regular records and or-types.
+fn generic_kind(
+    kind: &str,
+    known_kinds: &ftd::Map<Kind>,
+    doc: &mut ftd::interpreter::TDoc,
+    line_number: usize,
+) -> ftd::interpreter::Result<ftd::interpreter::StateWithThing<Kind>> {
+    use itertools::Itertools;
+
+    let (name, arguments, variant) = ftd::ast::utils::split_type_arguments(kind)
+        .ok_or_else(|| ftd::interpreter::utils::invalid_kind_error(kind, doc.name, line_number))?;
+    if let Some(kind) = known_kinds.get(name.as_str()) {
+        // a generic record referring to itself, like `tree<T> list children` in `tree<T>`
+        return Ok(ftd::interpreter::StateWithThing::new_thing(kind.clone()));
+    }
+    let type_arguments = try_ok_state!(type_argument_kinds(
+        arguments,
+        known_kinds,
+        doc,
+        line_number
+    )?);
+
+    let generic = try_ok_state!(doc.search_thing(name.as_str(), line_number)?);
+    let instance_name = format!(
+        "{}<{}>",
+        generic.name(),
+        type_arguments
+            .iter()
+            .map(Kind::type_argument_name)
+            .join(", ")
+    );
+    if !doc.bag().contains_key(instance_name.as_str()) {
+        let type_arguments =
+            type_arguments_by_parameter(generic.name().as_str(), type_arguments, doc, line_number)?;
+        let instance = match generic {
+            ftd::interpreter::Thing::Record(r) => ftd::interpreter::Thing::Record(try_ok_state!(
+                r.instantiate(instance_name.as_str(), &type_arguments, doc)?
+            )),
+            ftd::interpreter::Thing::OrType(o) => ftd::interpreter::Thing::OrType(try_ok_state!(
+                o.instantiate(instance_name.as_str(), &type_arguments, doc)?
+            )),
+            t => {
+                return ftd::interpreter::utils::e2(
+                    format!("`{}` is not a generic record or or-type", t.name()),
+                    doc.name,
+                    line_number,
+                )
+            }
+        };
+        doc.insert_thing(instance, line_number)?;
+    }
+
+    let variant = variant.trim_start_matches('.');
+    Ok(ftd::interpreter::StateWithThing::new_thing(
+        match doc.bag().get(instance_name.as_str()) {
+            Some(ftd::interpreter::Thing::Record(_)) if variant.is_empty() => {
+                Kind::record(instance_name.as_str())
+            }
+            Some(ftd::interpreter::Thing::OrType(_)) if variant.is_empty() => {
+                Kind::or_type(instance_name.as_str())
+            }
+            Some(ftd::interpreter::Thing::OrType(o)) => {
+                let variant = format!("{}.{}", instance_name, variant);
+                if !o.variants.iter().any(|v| v.name().eq(&variant)) {
+                    return ftd::interpreter::utils::e2(
+                        format!("Cannot find variant `{}`", variant),
+                        doc.name,
+                        line_number,
+                    );
+                }
+                Kind::or_type_with_variant(
+                    instance_name.as_str(),
+                    variant.as_str(),
+                    variant.as_str(),
+                )
+            }
+            _ => {
+                return Err(ftd::interpreter::utils::invalid_kind_error(
+                    kind,
+                    doc.name,
+                    line_number,
+                ))
+            }
+        },
+    ))
+}
+
+fn type_argument_kinds(
+    arguments: Vec<String>,
+    known_kinds: &ftd::Map<Kind>,
+    doc: &mut ftd::interpreter::TDoc,
+    line_number: usize,
+) -> ftd::interpreter::Result<ftd::interpreter::StateWithThing<Vec<Kind>>> {
+    let mut kinds = vec![];
+    for argument in arguments {
+        let argument = ftd::ast::VariableKind::get_kind(argument.as_str(), doc.name, line_number)?;
+        kinds.push(
+            try_ok_state!(KindData::from_ast_kind(
+                argument,
+                known_kinds,
+                doc,
+                line_number
+            )?)
+            .kind,
+        );
+    }
+    Ok(ftd::interpreter::StateWithThing::new_thing(kinds))
+}
+
+/// The kinds of the type arguments keyed by the type parameters of the generic definition
+/// `name`, there has to be one type argument for each type parameter.
+fn type_arguments_by_parameter(
+    name: &str,
+    type_arguments: Vec<Kind>,
+    doc: &ftd::interpreter::TDoc,
+    line_number: usize,
+) -> ftd::interpreter::Result<ftd::Map<Kind>> {
+    let type_parameters = doc.type_parameters(name);
+    if type_parameters.len() != type_arguments.len() {
+        return ftd::interpreter::utils::e2(
+            format!(
+                "`{}` takes {} type arguments, found {}",
+                name,
+                type_parameters.len(),
+                type_arguments.len()
+            ),
+            doc.name,
+            line_number,
+        );
+    }
+    Ok(type_parameters.into_iter().zip(type_arguments).collect())
+}
+
+/// Whether `n1` and `n2` are instances of the same generic record or or-type, where a type
+/// parameter, like `T` in `foo#paginated<T>`, matches any type argument.
+fn is_same_instance(n1: &str, n2: &str) -> bool {
+    let is_type_parameter = |argument: &str| {
+        !argument.contains(|c: char| c == '#' || c == '<' || c.is_whitespace())
+            && !matches!(
+                argument,
+                "string"
+                    | "object"
+                    | "integer"
+                    | "decimal"
+                    | "boolean"
+                    | "datetime"
+                    | "date"
+                    | "duration"
+                    | "ftd.ui"
+            )
+    };
+    match (
+        ftd::ast::utils::split_type_arguments(n1),
+        ftd::ast::utils::split_type_arguments(n2),
+    ) {
+        (Some((b1, a1, r1)), Some((b2, a2, r2))) => {
+            b1.eq(&b2)
+                && r1.eq(&r2)
+                && a1.len() == a2.len()
+                && a1.iter().zip(a2.iter()).all(|(a1, a2)| {
+                    a1.eq(a2)
+                        || is_type_parameter(a1)
+                        || is_type_parameter(a2)
+                        || is_same_instance(a1, a2)
+                })
+        }
+        _ => false,
+    }
+}
+
+/// `foo#paginated<T>` with the type parameters in `type_arguments` replaced by the names of
+/// their kinds, e.g. `foo#paginated<string>` for `T: string`.
+fn substitute_type_arguments(name: &str, type_arguments: &ftd::Map<Kind>) -> String {
+    use itertools::Itertools;
+
+    let (base, arguments, remaining) = match ftd::ast::utils::split_type_arguments(name) {
+        Some(v) => v,
+        None => return name.to_string(),
+    };
+    let arguments = arguments.iter().map(|argument| {
+        if argument.contains('<') {
+            return substitute_type_arguments(argument, type_arguments);
+        }
+        argument
+            .split_whitespace()
+            .map(|word| match type_arguments.get(word) {
+                Some(kind) => kind.type_argument_name(),
+                None => word.to_string(),
+            })
+            .join(" ")
+    });
+    format!(
+        "{}<{}>{}",
+        base,
+        arguments.collect_vec().join(", "),
+        remaining
+    )
+}
+
+/// The kind of the keys and of the values of `map<<key kind>, <value kind>>`, the keys are
+/// `string` or `integer`.
+fn map_key_and_value_kind(
+    kind: &str,
+    doc_id: &str,
+    line_number: usize,
+) -> ftd::interpreter::Result<(Kind, ftd::ast::VariableKind)> {
+    let (key, value) = kind
+        .strip_prefix("map<")
+        .and_then(|v| v.strip_suffix('>'))
+        .and_then(|v| v.split_once(','))
+        .ok_or_else(|| {
+            ftd::interpreter::utils::invalid_kind_error(
+                format!("Expected `map<<key kind>, <kind>>`, found: `{}`", kind),
+                doc_id,
+                line_number,
+            )
+        })?;
+    let key = match key.trim() {
+        "string" => Kind::string(),
+        "integer" => Kind::integer(),
+        t => {
+            return Err(ftd::interpreter::utils::invalid_kind_error(
+                format!(
+                    "Only `string` and `integer` keys are supported in a map, found: `{}`",
+                    t
+                ),
+                doc_id,
+                line_number,
+            ))
+        }
+    };
+    Ok((
+        key,
+        ftd::ast::VariableKind::get_kind(value.trim(), doc_id, line_number)?,
+    ))
+}
+
 pub fn check_for_caption_and_body(s: &mut String) -> (bool, bool) {
     use itertools::Itertools;
 
@@ -142,7 +142,11 @@ impl Thing {
         line_number: usize,
     ) -> ftd::interpreter::Result<ftd::interpreter::Record> {
         match self {
-            ftd::interpreter::Thing::Record(v) => Ok(v),
+            ftd::interpreter::Thing::Record(v)
+            | ftd::interpreter::Thing::OrTypeWithVariant {
+                variant: ftd::interpreter::OrTypeVariant::AnonymousRecord(v),
+                ..
+            } => Ok(v),
             t => ftd::interpreter::utils::e2(
                 format!("Expected Record, found: `{:?}`", t),
                 doc_id,
@@ -25,7 +25,11 @@ impl OrType {
         let or_type = ast.get_or_type(doc.name)?;
         for mut variant in or_type.variants {
             variant.set_name(format!("{}.{}", or_type.name, variant.name()).as_str());
-            ftd::interpreter::OrTypeVariant::scan_ast(variant, doc)?;
+            ftd::interpreter::OrTypeVariant::scan_ast(
+                variant,
+                or_type.type_parameters.as_slice(),
+                doc,
+            )?;
         }
         Ok(())
     }
@@ -39,9 +43,11 @@ impl OrType {
         let line_number = or_type.line_number();
         let mut variants = vec![];
         for mut variant in or_type.variants {
-            variant.set_name(format!("{}.{}", or_type.name, variant.name()).as_str());
+            variant.set_name(format!("{}.{}", name, variant.name()).as_str());
             variants.push(try_ok_state!(ftd::interpreter::OrTypeVariant::from_ast(
-                variant, doc
+                variant,
+                or_type.type_parameters.as_slice(),
+                doc
             )?))
         }
         Ok(ftd::interpreter::StateWithThing::new_thing(OrType::new(
@@ -50,6 +56,157 @@ impl OrType {
             line_number,
         )))
     }
+
+    /// The instance `name` of this generic or-type, with the type parameters in its variants
+    /// replaced by `type_arguments`.
+    pub(crate) fn instantiate(
+        self,
+        name: &str,
+        type_arguments: &ftd::Map<ftd::interpreter::Kind>,
+        doc: &mut ftd::interpreter::TDoc,
+    ) -> ftd::interpreter::Result<ftd::interpreter::StateWithThing<OrType>> {
+        let variant_name =
+            |variant: &str| format!("{}{}", name, variant.trim_start_matches(self.name.as_str()));
+        let mut variants = vec![];
+        for variant in self.variants.iter().cloned() {
+            variants.push(match variant {
+                OrTypeVariant::AnonymousRecord(record) => {
+                    let record_name = variant_name(record.name.as_str());
+                    OrTypeVariant::new_record(try_ok_state!(record.instantiate(
+                        record_name.as_str(),
+                        type_arguments,
+                        doc
+                    )?))
+                }
+                OrTypeVariant::Regular(mut field) => {
+                    field.name = variant_name(field.name.as_str());
+                    let mut fields = try_ok_state!(ftd::interpreter::Field::instantiate_fields(
+                        vec![field],
+                        type_arguments,
+                        doc
+                    )?);
+                    OrTypeVariant::new_regular(fields.remove(0))
+                }
+                OrTypeVariant::Constant(mut field) => {
+                    field.name = variant_name(field.name.as_str());
+                    let mut fields = try_ok_state!(ftd::interpreter::Field::instantiate_fields(
+                        vec![field],
+                        type_arguments,
+                        doc
+                    )?);
+                    OrTypeVariant::new_constant(fields.remove(0))
+                }
+            });
+        }
+        Ok(ftd::interpreter::StateWithThing::new_thing(OrType::new(
+            name,
+            variants,
+            self.line_number,
+        )))
+    }
+
+    /// Checks the arms of a `match` over a value of this or-type: every arm names one of its
+    /// variants, only variants with a value bind it, and every variant is handled by exactly
+    /// one arm.
+    pub(crate) fn check_match(
+        &self,
+        match_: &ftd::ast::Match,
+        doc_id: &str,
+    ) -> ftd::interpreter::Result<()> {
+        if self.name.starts_with("ftd#") {
+            return ftd::interpreter::utils::e2(
+                format!(
+                    "`match` only supports or-types defined in ftd documents, `{}` is of the \
+                    kernel or-type `{}`",
+                    match_.subject, self.name
+                ),
+                doc_id,
+                match_.line_number,
+            );
+        }
+
+        let mut handled: Vec<&str> = vec![];
+        let mut has_wildcard = false;
+        for arm in match_.arms.iter() {
+            if has_wildcard {
+                return ftd::interpreter::utils::e2(
+                    format!("Unreachable arm `{}`, it comes after `_`", arm.pattern()),
+                    doc_id,
+                    arm.line_number,
+                );
+            }
+            let variant_name = match arm.variant.as_ref() {
+                Some(variant_name) => variant_name,
+                None => {
+                    has_wildcard = true;
+                    continue;
+                }
+            };
+            let variant =
+                self.variant(variant_name)
+                    .ok_or(ftd::interpreter::Error::ParseError {
+                        message: format!(
+                            "`{}` is not a variant of `{}`, expected one of: {}",
+                            variant_name,
+                            self.name,
+                            self.variant_names().join(", ")
+                        ),
+                        doc_id: doc_id.to_string(),
+                        line_number: arm.line_number,
+                        span: None,
+                    })?;
+            if arm.binding.is_some() && variant.is_constant() {
+                return ftd::interpreter::utils::e2(
+                    format!(
+                        "The constant variant `{}` has no value to bind, use `{}` instead",
+                        variant_name, variant_name
+                    ),
+                    doc_id,
+                    arm.line_number,
+                );
+            }
+            if handled.contains(&variant_name.as_str()) {
+                return ftd::interpreter::utils::e2(
+                    format!(
+                        "Unreachable arm `{}`, `{}` is already handled",
+                        arm.pattern(),
+                        variant_name
+                    ),
+                    doc_id,
+                    arm.line_number,
+                );
+            }
+            handled.push(variant_name.as_str());
+        }
+
+        let missing = self
+            .variant_names()
+            .into_iter()
+            .filter(|v| !handled.contains(&v.as_str()))
+            .collect::<Vec<_>>();
+        if !has_wildcard && !missing.is_empty() {
+            return ftd::interpreter::utils::e2(
+                format!(
+                    "Non-exhaustive `match` over `{}`, not handled: {}. Add the missing \
+                    variants or a `_` arm",
+                    match_.subject,
+                    missing.join(", ")
+                ),
+                doc_id,
+                match_.line_number,
+            );
+        }
+        Ok(())
+    }
+
+    /// The variant by its name without the or-type prefix, `circle` for `foo#shape.circle`.
+    pub fn variant(&self, name: &str) -> Option<&OrTypeVariant> {
+        self.variants.iter().find(|v| v.short_name().eq(name))
+    }
+
+    fn variant_names(&self) -> Vec<String> {
+        self.variants.iter().map(|v| v.short_name()).collect()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -84,6 +241,11 @@ impl OrTypeVariant {
         }
     }
 
+    /// The name without the or-type prefix, `circle` for `foo#shape.circle`.
+    pub fn short_name(&self) -> String {
+        ftd::interpreter::utils::variant_short_name(self.name().as_str()).to_string()
+    }
+
     pub fn ok_constant(&self, doc_id: &str) -> ftd::interpreter::Result<&ftd::interpreter::Field> {
         match self {
             ftd::interpreter::OrTypeVariant::Constant(c) => Ok(c),
@@ -105,27 +267,35 @@ impl OrTypeVariant {
 
     pub fn scan_ast(
         ast_variant: ftd::ast::OrTypeVariant,
+        type_parameters: &[String],
         doc: &mut ftd::interpreter::TDoc,
     ) -> ftd::interpreter::Result<()> {
+        let known_kinds: ftd::Map<ftd::interpreter::Kind> =
+            ftd::interpreter::Kind::type_parameters(type_parameters).collect();
         match ast_variant {
-            ftd::ast::OrTypeVariant::AnonymousRecord(record) => {
+            ftd::ast::OrTypeVariant::AnonymousRecord(mut record) => {
+                record.type_parameters = type_parameters.to_vec();
                 ftd::interpreter::Record::scan_record(record, doc)
             }
             ftd::ast::OrTypeVariant::Regular(variant) => {
-                ftd::interpreter::Field::scan_ast_field(variant, doc, &Default::default())
+                ftd::interpreter::Field::scan_ast_field(variant, doc, &known_kinds)
             }
             ftd::ast::OrTypeVariant::Constant(variant) => {
-                ftd::interpreter::Field::scan_ast_field(variant, doc, &Default::default())
+                ftd::interpreter::Field::scan_ast_field(variant, doc, &known_kinds)
             }
         }
     }
 
     pub fn from_ast(
         ast_variant: ftd::ast::OrTypeVariant,
+        type_parameters: &[String],
         doc: &mut ftd::interpreter::TDoc,
     ) -> ftd::interpreter::Result<ftd::interpreter::StateWithThing<OrTypeVariant>> {
+        let known_kinds: ftd::Map<ftd::interpreter::Kind> =
+            ftd::interpreter::Kind::type_parameters(type_parameters).collect();
         match ast_variant {
-            ftd::ast::OrTypeVariant::AnonymousRecord(record) => {
+            ftd::ast::OrTypeVariant::AnonymousRecord(mut record) => {
+                record.type_parameters = type_parameters.to_vec();
                 Ok(ftd::interpreter::StateWithThing::new_thing(
                     ftd::interpreter::OrTypeVariant::new_record(try_ok_state!(
                         ftd::interpreter::Record::from_record(record, doc)?
@@ -135,7 +305,7 @@ impl OrTypeVariant {
             ftd::ast::OrTypeVariant::Regular(variant) => {
                 Ok(ftd::interpreter::StateWithThing::new_thing(
                     ftd::interpreter::OrTypeVariant::new_regular(try_ok_state!(
-                        ftd::interpreter::Field::from_ast_field(variant, doc, &Default::default())?
+                        ftd::interpreter::Field::from_ast_field(variant, doc, &known_kinds)?
                     )),
                 ))
             }
@@ -143,11 +313,11 @@ impl OrTypeVariant {
                 let variant = try_ok_state!(ftd::interpreter::Field::from_ast_field(
                     variant,
                     doc,
-                    &Default::default()
+                    &known_kinds
                 )?);
                 validate_constant_variant(&variant, doc)?;
                 Ok(ftd::interpreter::StateWithThing::new_thing(
-                    ftd::interpreter::OrTypeVariant::new_regular(variant),
+                    ftd::interpreter::OrTypeVariant::new_constant(variant),
                 ))
             }
         }
@@ -31,6 +31,9 @@ impl Record {
             record.name.to_string(),
             ftd::interpreter::Kind::record(name.as_str()),
         )])
+        .chain(ftd::interpreter::Kind::type_parameters(
+            record.type_parameters.as_slice(),
+        ))
         .collect::<ftd::Map<ftd::interpreter::Kind>>();
         Field::scan_ast_fields(record.fields, doc, &known_kinds)
     }
@@ -54,6 +57,9 @@ impl Record {
                 name: name.to_string(),
             },
         )])
+        .chain(ftd::interpreter::Kind::type_parameters(
+            record.type_parameters.as_slice(),
+        ))
         .collect::<ftd::Map<ftd::interpreter::Kind>>();
         let fields = try_ok_state!(Field::from_ast_fields(
             record.name.as_str(),
@@ -69,6 +75,22 @@ impl Record {
         )))
     }
 
+    /// The instance `name` of this generic record, with the type parameters in its fields
+    /// replaced by `type_arguments`.
+    pub(crate) fn instantiate(
+        self,
+        name: &str,
+        type_arguments: &ftd::Map<ftd::interpreter::Kind>,
+        doc: &mut ftd::interpreter::TDoc,
+    ) -> ftd::interpreter::Result<ftd::interpreter::StateWithThing<ftd::interpreter::Record>> {
+        let fields = try_ok_state!(Field::instantiate_fields(self.fields, type_arguments, doc)?);
+        Ok(ftd::interpreter::StateWithThing::new_thing(Record::new(
+            name,
+            fields,
+            self.line_number,
+        )))
+    }
+
     pub(crate) fn get_field(
         &self,
         name: &str,
@@ -165,6 +187,25 @@ impl Field {
         }
     }
 
+    /// The fields of an instance of a generic record or component, with their type parameters
+    /// replaced by `type_arguments`.
+    pub(crate) fn instantiate_fields(
+        fields: Vec<Field>,
+        type_arguments: &ftd::Map<ftd::interpreter::Kind>,
+        doc: &mut ftd::interpreter::TDoc,
+    ) -> ftd::interpreter::Result<ftd::interpreter::StateWithThing<Vec<Field>>> {
+        let mut instantiated = vec![];
+        for mut field in fields {
+            field.kind.kind = try_ok_state!(field.kind.kind.instantiate(
+                type_arguments,
+                doc,
+                field.line_number
+            )?);
+            instantiated.push(field);
+        }
+        Ok(ftd::interpreter::StateWithThing::new_thing(instantiated))
+    }
+
     pub(crate) fn scan_ast_fields(
         fields: Vec<ftd::ast::Field>,
         doc: &mut ftd::interpreter::TDoc,
@@ -373,6 +414,16 @@ impl Field {
         doc: &mut ftd::interpreter::TDoc,
         line_number: usize,
     ) -> ftd::interpreter::Result<ftd::interpreter::StateWithThing<Vec<Field>>> {
+        if let Some((name, arguments, _)) = ftd::ast::utils::split_type_arguments(component_name) {
+            let component = try_ok_state!(doc.search_component(name.as_str(), line_number)?);
+            let type_arguments = try_ok_state!(ftd::interpreter::Kind::type_arguments(
+                component.name.as_str(),
+                arguments,
+                doc,
+                line_number
+            )?);
+            return Field::instantiate_fields(component.arguments, &type_arguments, doc);
+        }
         Ok(ftd::interpreter::StateWithThing::new_thing(
             match definition_name_with_arguments {
                 Some((name, arg)) if name.eq(&component_name) => arg.to_vec(),
@@ -427,6 +478,7 @@ impl Field {
                         ),
                         doc_id: doc.name.to_string(),
                         line_number,
+                        span: None,
                     })?;
 
                 check_variant_if_constant(or_variant, remaining, doc)?;
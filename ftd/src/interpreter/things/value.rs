@@ -116,6 +116,7 @@ impl PropertyValue {
                         ),
                         doc_id: doc.name.to_string(),
                         line_number,
+                        span: None,
                     },
                 )
             }
@@ -544,6 +545,7 @@ impl PropertyValue {
                                     ),
                                     doc_id: doc.name.to_string(),
                                     line_number,
+                                    span: None,
                                 })?
                                 .clone();
                             result_field.insert(field.name.to_string(), property_value);
@@ -708,6 +710,7 @@ impl PropertyValue {
             message: "Need expected kind".to_string(),
             doc_id: doc.name.to_string(),
             line_number: value.line_number(),
+            span: None,
         })?;
         return get_property_value(
             value,
@@ -787,6 +790,39 @@ impl PropertyValue {
                         line_number: value.line_number(),
                     })
                 }
+                ftd::interpreter::Kind::DateTime => {
+                    ftd::interpreter::StateWithThing::new_thing(PropertyValue::Value {
+                        value: Value::new_datetime(
+                            value.string(doc.name)?.as_str(),
+                            doc.name,
+                            value.line_number(),
+                        )?,
+                        is_mutable,
+                        line_number: value.line_number(),
+                    })
+                }
+                ftd::interpreter::Kind::Date => {
+                    ftd::interpreter::StateWithThing::new_thing(PropertyValue::Value {
+                        value: Value::new_date(
+                            value.string(doc.name)?.as_str(),
+                            doc.name,
+                            value.line_number(),
+                        )?,
+                        is_mutable,
+                        line_number: value.line_number(),
+                    })
+                }
+                ftd::interpreter::Kind::Duration => {
+                    ftd::interpreter::StateWithThing::new_thing(PropertyValue::Value {
+                        value: Value::new_duration(
+                            value.string(doc.name)?.as_str(),
+                            doc.name,
+                            value.line_number(),
+                        )?,
+                        is_mutable,
+                        line_number: value.line_number(),
+                    })
+                }
                 ftd::interpreter::Kind::List { kind } => {
                     let line_number = value.line_number();
                     let value_list = value.into_list(doc.name, kind)?;
@@ -834,6 +870,72 @@ impl PropertyValue {
                         line_number,
                     })
                 }
+                ftd::interpreter::Kind::Map { key, kind } => {
+                    let line_number = value.line_number();
+                    let mut data: ftd::Map<PropertyValue> = Default::default();
+                    if !value.is_null() {
+                        let (_, caption, headers, body, values, _) = value
+                            .get_record(doc.name)
+                            .map_err(|_| ftd::interpreter::Error::ParseError {
+                                message: format!(
+                                    "Expected `key: value` entries for map, found: `{:?}`",
+                                    value
+                                ),
+                                doc_id: doc.name.to_string(),
+                                line_number,
+                                span: None,
+                            })?;
+                        if caption.is_some() || body.is_some() || !values.is_empty() {
+                            return ftd::interpreter::utils::e2(
+                                "Map can only have `key: value` entries, found caption, body or \
+                                children",
+                                doc.name,
+                                line_number,
+                            );
+                        }
+                        for header in headers.0.iter() {
+                            let map_key = match key.map_key(header.key.as_str()) {
+                                Some(map_key) => map_key,
+                                None => {
+                                    return ftd::interpreter::utils::e2(
+                                        format!(
+                                            "Expected `{}` key in map, found: `{}`",
+                                            key.get_name(),
+                                            header.key
+                                        ),
+                                        doc.name,
+                                        header.line_number,
+                                    )
+                                }
+                            };
+                            if data.contains_key(map_key.as_str()) {
+                                return ftd::interpreter::utils::e2(
+                                    format!("Duplicate key `{}` in map", header.key),
+                                    doc.name,
+                                    header.line_number,
+                                );
+                            }
+                            let entry = try_ok_state!(PropertyValue::from_ast_value_with_argument(
+                                header.value.clone(),
+                                doc,
+                                is_mutable,
+                                Some(&kind.as_ref().clone().into_kind_data()),
+                                definition_name_with_arguments,
+                                loop_object_name_and_kind,
+                            )?);
+                            data.insert(map_key, entry);
+                        }
+                    }
+                    ftd::interpreter::StateWithThing::new_thing(PropertyValue::Value {
+                        value: ftd::interpreter::Value::Map {
+                            data,
+                            key: key.as_ref().clone(),
+                            kind: expected_kind.clone().inner_map(),
+                        },
+                        is_mutable,
+                        line_number,
+                    })
+                }
                 ftd::interpreter::Kind::Record { name }
                     if value.is_record() || value.is_string() =>
                 {
@@ -866,6 +968,7 @@ impl PropertyValue {
                                 ),
                                 doc_id: doc.name.to_string(),
                                 line_number: value.line_number(),
+                                span: None,
                             })?;
                         let value = match &variant {
                         ftd::interpreter::OrTypeVariant::Constant(c) => return ftd::interpreter::utils::e2(format!("Cannot pass constant variant as property, variant: `{}`. Help: Pass variant as value instead", c.name), doc.name, c.line_number),
@@ -951,6 +1054,7 @@ impl PropertyValue {
                                     ),
                                     doc_id: doc.name.to_string(),
                                     line_number: constant.line_number,
+                                    span: None,
                                 })?;
 
                         ftd::interpreter::StateWithThing::new_thing(
@@ -974,6 +1078,17 @@ impl PropertyValue {
                         line_number: value.line_number(),
                     })
                 }
+                ftd::interpreter::Kind::TypeParameter { name } => {
+                    return ftd::interpreter::utils::e2(
+                        format!(
+                            "Expected a reference for type parameter `{}`, found: `{:?}`. Help: \
+                            give the type arguments",
+                            name, value
+                        ),
+                        doc.name,
+                        value.line_number(),
+                    );
+                }
                 t => {
                     unimplemented!("t::{:?}  {:?}", t, value)
                 }
@@ -1036,6 +1151,37 @@ impl PropertyValue {
                     },
                 )))
             }
+            Ok(expression) if expression.eq(ftd::interpreter::FTD_SPECIAL_TIME) => {
+                // the playback position of an `ftd.video` or `ftd.audio`, in seconds
+                Ok(ftd::interpreter::StateWithThing::new_thing(Some(
+                    ftd::interpreter::PropertyValue::Reference {
+                        name: "TIME".to_string(),
+                        kind: ftd::interpreter::Kind::decimal()
+                            .into_optional()
+                            .into_kind_data(),
+                        source: PropertyValueSource::Global,
+                        is_mutable: false,
+                        line_number: 0,
+                    },
+                )))
+            }
+            Ok(expression) if expression.eq(ftd::interpreter::FTD_SPECIAL_FORM) => {
+                // the values of the named controls of the submitted form, as the record the
+                // function expects
+                if let Some(kind) = expected_kind {
+                    Ok(ftd::interpreter::StateWithThing::new_thing(Some(
+                        ftd::interpreter::PropertyValue::Reference {
+                            name: "FORM".to_string(),
+                            kind: kind.to_owned(),
+                            source: PropertyValueSource::Global,
+                            is_mutable: false,
+                            line_number: 0,
+                        },
+                    )))
+                } else {
+                    ftd::interpreter::utils::e2("Kind not found", doc.name, value.line_number())
+                }
+            }
             Ok(expression)
                 if expression.starts_with(ftd::interpreter::utils::REFERENCE)
                     && ftd::interpreter::utils::get_function_name(
@@ -1318,6 +1464,15 @@ pub enum Value {
     Boolean {
         value: bool,
     },
+    DateTime {
+        value: String,
+    }, // ISO-8601, e.g. `2023-06-01T10:30:00+05:30`
+    Date {
+        value: String,
+    }, // ISO-8601, e.g. `2023-06-01`
+    Duration {
+        seconds: i64,
+    },
     Object {
         values: ftd::Map<PropertyValue>,
     },
@@ -1335,6 +1490,11 @@ pub enum Value {
         data: Vec<PropertyValue>,
         kind: ftd::interpreter::KindData,
     },
+    Map {
+        data: ftd::Map<PropertyValue>,
+        key: ftd::interpreter::Kind,
+        kind: ftd::interpreter::KindData,
+    },
     Optional {
         data: Box<Option<Value>>,
         kind: ftd::interpreter::KindData,
@@ -1369,12 +1529,11 @@ impl Value {
     }
 
     pub fn is_empty(&self) -> bool {
-        if let Self::List { data, .. } = self {
-            if data.is_empty() {
-                return true;
-            }
+        match self {
+            Self::List { data, .. } => data.is_empty(),
+            Self::Map { data, .. } => data.is_empty(),
+            _ => false,
         }
-        false
     }
 
     pub fn is_record(&self, rec_name: &str) -> bool {
@@ -1452,6 +1611,11 @@ impl Value {
             (Some(Value::String { text: ref a, .. }), Some(Value::String { text: ref b, .. })) => {
                 a == b
             }
+            // the same instant can be written with different offsets
+            (Some(Value::DateTime { value: ref a }), Some(Value::DateTime { value: ref b })) => {
+                fastn_grammar::evalexpr::datetime::parse_datetime(a)
+                    == fastn_grammar::evalexpr::datetime::parse_datetime(b)
+            }
             (a, b) => a == b,
         }
     }
@@ -1486,6 +1650,7 @@ impl Value {
                 is_static
             }
             ftd::interpreter::Value::Record { fields, .. }
+            | ftd::interpreter::Value::Map { data: fields, .. }
             | ftd::interpreter::Value::Object { values: fields, .. } => {
                 let mut is_static = true;
                 for d in fields.values() {
@@ -1506,9 +1671,13 @@ impl Value {
             Value::Integer { .. } => ftd::interpreter::Kind::integer(),
             Value::Decimal { .. } => ftd::interpreter::Kind::decimal(),
             Value::Boolean { .. } => ftd::interpreter::Kind::boolean(),
+            Value::DateTime { .. } => ftd::interpreter::Kind::datetime(),
+            Value::Date { .. } => ftd::interpreter::Kind::date(),
+            Value::Duration { .. } => ftd::interpreter::Kind::duration(),
             Value::Object { .. } => ftd::interpreter::Kind::object(),
             Value::Record { name, .. } => ftd::interpreter::Kind::record(name),
             Value::List { kind, .. } => kind.kind.clone().into_list(),
+            Value::Map { key, kind, .. } => kind.kind.clone().into_map(key.clone()),
             Value::Optional { kind, .. } => ftd::interpreter::Kind::Optional {
                 kind: Box::new(kind.kind.clone()),
             },
@@ -1533,6 +1702,10 @@ impl Value {
             Value::Integer { value } => fastn_grammar::evalexpr::Value::Int(*value),
             Value::Decimal { value } => fastn_grammar::evalexpr::Value::Float(*value),
             Value::Boolean { value } => fastn_grammar::evalexpr::Value::Boolean(*value),
+            Value::DateTime { value } | Value::Date { value } => {
+                fastn_grammar::evalexpr::Value::String(value.to_string())
+            }
+            Value::Duration { seconds } => fastn_grammar::evalexpr::Value::Int(*seconds),
             Value::List { data, .. } => {
                 let mut values = vec![];
                 for value in data {
@@ -1544,6 +1717,18 @@ impl Value {
                 }
                 fastn_grammar::evalexpr::Value::Tuple(values)
             }
+            // expressions see a map as the tuple of its values, ordered by key
+            Value::Map { data, key, .. } => {
+                let mut values = vec![];
+                for (_, value) in key.map_entries(data.clone()) {
+                    let v = value
+                        .clone()
+                        .resolve(doc, line_number)?
+                        .to_evalexpr_value(doc, value.line_number())?;
+                    values.push(v);
+                }
+                fastn_grammar::evalexpr::Value::Tuple(values)
+            }
             Value::Optional { data, .. } => {
                 if let Some(data) = data.as_ref() {
                     data.to_evalexpr_value(doc, line_number)?
@@ -1551,6 +1736,23 @@ impl Value {
                     fastn_grammar::evalexpr::Value::Empty
                 }
             }
+            // or-types defined in ftd documents are the JSON of their variant and its value,
+            // read with `ftd.is_variant` and `ftd.variant_value`
+            Value::OrType { name, .. } if !name.starts_with("ftd#") => {
+                match ftd::interpreter::utils::get_value(doc, self)? {
+                    Some(value) => fastn_grammar::evalexpr::Value::String(value.to_string()),
+                    None => fastn_grammar::evalexpr::Value::Empty,
+                }
+            }
+            Value::OrType { value, .. } => value
+                .clone()
+                .resolve(doc, line_number)?
+                .to_evalexpr_value(doc, line_number)?,
+            // records are their JSON, as in `into_evalexpr_value`
+            Value::Record { .. } => match ftd::interpreter::utils::get_value(doc, self)? {
+                Some(value) => fastn_grammar::evalexpr::Value::String(value.to_string()),
+                None => fastn_grammar::evalexpr::Value::Empty,
+            },
             t => unimplemented!("{:?}", t),
         })
     }
@@ -1574,6 +1776,15 @@ impl Value {
             fastn_grammar::evalexpr::Value::Boolean(value) if expected_kind.is_boolean() => {
                 Value::Boolean { value }
             }
+            fastn_grammar::evalexpr::Value::String(text) if expected_kind.is_datetime() => {
+                Value::new_datetime(text.as_str(), doc_name, line_number)?
+            }
+            fastn_grammar::evalexpr::Value::String(text) if expected_kind.is_date() => {
+                Value::new_date(text.as_str(), doc_name, line_number)?
+            }
+            fastn_grammar::evalexpr::Value::Int(seconds) if expected_kind.is_duration() => {
+                Value::Duration { seconds }
+            }
             fastn_grammar::evalexpr::Value::Tuple(data) if expected_kind.is_list() => {
                 let mut values = vec![];
                 let val_kind = expected_kind.list_type(doc_name, line_number)?;
@@ -1618,6 +1829,71 @@ impl Value {
         }
     }
 
+    /// A date time from its ISO-8601 form, a plain date is taken to be midnight in UTC.
+    pub(crate) fn new_datetime(
+        text: &str,
+        doc_id: &str,
+        line_number: usize,
+    ) -> ftd::interpreter::Result<ftd::interpreter::Value> {
+        match fastn_grammar::evalexpr::datetime::parse_datetime(text) {
+            Some(datetime) => Ok(ftd::interpreter::Value::DateTime {
+                value: fastn_grammar::evalexpr::datetime::datetime_to_string(&datetime),
+            }),
+            None => ftd::interpreter::utils::e2(
+                format!(
+                    "Expected an ISO-8601 date time, e.g. `2023-06-01T10:30:00Z`, found: `{}`",
+                    text
+                ),
+                doc_id,
+                line_number,
+            ),
+        }
+    }
+
+    /// A date from its ISO-8601 form, the date of a date time is taken as is.
+    pub(crate) fn new_date(
+        text: &str,
+        doc_id: &str,
+        line_number: usize,
+    ) -> ftd::interpreter::Result<ftd::interpreter::Value> {
+        let date = fastn_grammar::evalexpr::datetime::parse_date(text).or_else(|| {
+            fastn_grammar::evalexpr::datetime::parse_datetime(text).map(|v| v.date_naive())
+        });
+        match date {
+            Some(date) => Ok(ftd::interpreter::Value::Date {
+                value: fastn_grammar::evalexpr::datetime::date_to_string(&date),
+            }),
+            None => ftd::interpreter::utils::e2(
+                format!(
+                    "Expected an ISO-8601 date, e.g. `2023-06-01`, found: `{}`",
+                    text
+                ),
+                doc_id,
+                line_number,
+            ),
+        }
+    }
+
+    /// A duration from its ISO-8601 form, e.g. `PT1H30M`.
+    pub(crate) fn new_duration(
+        text: &str,
+        doc_id: &str,
+        line_number: usize,
+    ) -> ftd::interpreter::Result<ftd::interpreter::Value> {
+        match fastn_grammar::evalexpr::datetime::parse_duration(text) {
+            Some(seconds) => Ok(ftd::interpreter::Value::Duration { seconds }),
+            None => ftd::interpreter::utils::e2(
+                format!(
+                    "Expected an ISO-8601 duration without years or months, e.g. `PT1H30M`, \
+                    found: `{}`",
+                    text
+                ),
+                doc_id,
+                line_number,
+            ),
+        }
+    }
+
     pub(crate) fn new_or_type(
         name: &str,
         variant: &str,
@@ -1649,6 +1925,13 @@ impl Value {
             ftd::interpreter::Value::Boolean { value } => {
                 Ok(fastn_grammar::evalexpr::Value::Boolean(value))
             }
+            ftd::interpreter::Value::DateTime { value }
+            | ftd::interpreter::Value::Date { value } => {
+                Ok(fastn_grammar::evalexpr::Value::String(value))
+            }
+            ftd::interpreter::Value::Duration { seconds } => {
+                Ok(fastn_grammar::evalexpr::Value::Int(seconds))
+            }
             ftd::interpreter::Value::Optional { data, .. } => {
                 if let Some(data) = data.as_ref() {
                     data.clone().into_evalexpr_value(doc)
@@ -1656,6 +1939,12 @@ impl Value {
                     Ok(fastn_grammar::evalexpr::Value::Empty)
                 }
             }
+            ftd::interpreter::Value::OrType { ref name, .. } if !name.starts_with("ftd#") => {
+                match ftd::interpreter::utils::get_value(doc, &self)? {
+                    Some(value) => Ok(fastn_grammar::evalexpr::Value::String(value.to_string())),
+                    None => Ok(fastn_grammar::evalexpr::Value::Empty),
+                }
+            }
             ftd::interpreter::Value::OrType { value, .. } => {
                 let line_number = value.line_number();
                 value.resolve(doc, line_number)?.into_evalexpr_value(doc)
@@ -1675,6 +1964,14 @@ impl Value {
                 }
                 Ok(fastn_grammar::evalexpr::Value::Tuple(values))
             }
+            ftd::interpreter::Value::Map { data, key, .. } => {
+                let mut values = vec![];
+                for (_, item) in key.map_entries(data) {
+                    let line_number = item.line_number();
+                    values.push(item.resolve(doc, line_number)?.into_evalexpr_value(doc)?);
+                }
+                Ok(fastn_grammar::evalexpr::Value::Tuple(values))
+            }
             t => unimplemented!("{:?}", t),
         }
     }
@@ -1808,6 +2105,28 @@ impl Value {
         }
     }
 
+    pub fn optional_decimal(
+        &self,
+        doc_id: &str,
+        line_number: usize,
+    ) -> ftd::interpreter::Result<Option<f64>> {
+        match self {
+            ftd::interpreter::Value::Optional { data, kind } if kind.is_decimal() => {
+                if let Some(data) = data.as_ref() {
+                    data.optional_decimal(doc_id, line_number)
+                } else {
+                    Ok(None)
+                }
+            }
+            ftd::interpreter::Value::Decimal { value } => Ok(Some(*value)),
+            t => ftd::interpreter::utils::e2(
+                format!("Expected Optional Decimal, found: `{:?}`", t),
+                doc_id,
+                line_number,
+            ),
+        }
+    }
+
     pub fn into_property_value(self, is_mutable: bool, line_number: usize) -> PropertyValue {
         PropertyValue::Value {
             value: self,
@@ -1822,6 +2141,12 @@ impl Value {
             Value::Integer { value } => Some(serde_json::json!(value)),
             Value::Decimal { value } => Some(serde_json::json!(value)),
             Value::Boolean { value } => Some(serde_json::Value::Bool(value.to_owned())),
+            Value::DateTime { value } | Value::Date { value } => {
+                Some(serde_json::Value::String(value.to_string()))
+            }
+            Value::Duration { seconds } => Some(serde_json::Value::String(
+                fastn_grammar::evalexpr::datetime::duration_to_string(*seconds),
+            )),
             Value::Optional { data, .. } => {
                 if let Some(data) = data.as_ref() {
                     data.to_serde_value()
@@ -1840,7 +2165,7 @@ impl Value {
                 }
                 serde_json::to_value(&new_values).ok()
             }
-            Value::Record { fields, .. } => {
+            Value::Record { fields, .. } | Value::Map { data: fields, .. } => {
                 let mut new_values: ftd::Map<serde_json::Value> = Default::default();
                 for (k, v) in fields {
                     if let ftd::interpreter::PropertyValue::Value { value, .. } = v {
@@ -1872,6 +2197,10 @@ impl Value {
             Value::Integer { value } => Some(value.to_string()),
             Value::Decimal { value } => Some(value.to_string()),
             Value::Boolean { value } => Some(value.to_string()),
+            Value::DateTime { value } | Value::Date { value } => Some(value.to_string()),
+            Value::Duration { seconds } => Some(
+                fastn_grammar::evalexpr::datetime::duration_to_string(*seconds),
+            ),
             Value::Optional { data, .. } => {
                 if let Some(data) = data.as_ref() {
                     data.to_string()
@@ -1879,9 +2208,10 @@ impl Value {
                     Some("".to_string())
                 }
             }
-            Value::Object { .. } | Value::Record { .. } | Value::List { .. } => {
-                serde_json::to_string(&self.to_serde_value()).ok()
-            }
+            Value::Object { .. }
+            | Value::Record { .. }
+            | Value::List { .. }
+            | Value::Map { .. } => serde_json::to_string(&self.to_serde_value()).ok(),
             _ => None,
         }
     }
@@ -1896,7 +2226,9 @@ fn get_kind(
             expected_kind.clone()
         } else {
             let mut expected_kind = expected_kind.clone();
-            if !found_kind.is_module() {
+            // a generic kind, like the `T list` returned by `ftd#filter`, is decided by the
+            // kind expected at the call site
+            if !found_kind.is_module() && !found_kind.kind.has_type_parameter() {
                 expected_kind.kind = found_kind.kind.clone();
             }
             expected_kind
@@ -39,9 +39,16 @@ where
         message: m.into(),
         doc_id: doc_id.to_string(),
         line_number,
+        span: None,
     })
 }
 
+pub(crate) fn diagnostics_to_string(diagnostics: &[ftd::diagnostic::Diagnostic]) -> String {
+    use itertools::Itertools;
+
+    diagnostics.iter().map(|d| d.to_string()).join("\n")
+}
+
 pub(crate) fn invalid_kind_error<S>(
     message: S,
     doc_id: &str,
@@ -54,6 +61,7 @@ where
         message: message.into(),
         doc_id: doc_id.to_string(),
         line_number,
+        span: None,
     }
 }
 
@@ -308,6 +316,21 @@ pub fn get_argument_for_reference_and_remaining(
                 ftd::interpreter::PropertyValueSource::Loop(loop_name.to_string()),
             )));
         }
+        if name.starts_with(format!("{}#{}", doc.name, ftd::interpreter::FTD_LOOP_KEY).as_str()) {
+            // the key has the kind of the keys of the map looped over
+            let key = match loop_argument.value.as_ref().map(|v| v.kind()) {
+                Some(ftd::interpreter::Kind::Map { key, .. }) => *key,
+                _ => ftd::interpreter::Kind::string(),
+            };
+            return Ok(Some((
+                ftd::interpreter::Field::default(
+                    ftd::interpreter::FTD_LOOP_KEY,
+                    key.into_optional().into_kind_data(),
+                ),
+                None,
+                ftd::interpreter::PropertyValueSource::Loop(loop_name.to_string()),
+            )));
+        }
     }
 
     Ok(None)
@@ -359,7 +382,8 @@ pub fn validate_record_value(
 
             if let ftd::interpreter::PropertyValue::Value { value, .. } = value {
                 match value.ref_inner() {
-                    Some(ftd::interpreter::Value::Record { fields, .. }) => {
+                    Some(ftd::interpreter::Value::Record { fields, .. })
+                    | Some(ftd::interpreter::Value::Map { data: fields, .. }) => {
                         validate_fields(fields.values().collect(), doc)?;
                     }
                     Some(ftd::interpreter::Value::OrType { value, .. }) => {
@@ -422,6 +446,13 @@ pub fn validate_property_value_for_mutable(
     }
 }
 
+/// The name of an or-type variant without the or-type, `circle` for `foo#shape.circle`.
+pub fn variant_short_name(variant: &str) -> &str {
+    variant
+        .rsplit_once('.')
+        .map_or(variant, |(_, short_name)| short_name)
+}
+
 pub(crate) fn get_value(
     doc: &ftd::interpreter::TDoc,
     value: &ftd::interpreter::Value,
@@ -449,7 +480,11 @@ pub(crate) fn get_value(
             serde_json::to_value(value).ok()
         }
         Some(ftd::interpreter::Value::Decimal { value, .. }) => serde_json::to_value(value).ok(),
-        Some(ftd::interpreter::Value::Record { fields, .. }) => {
+        Some(ftd::interpreter::Value::DateTime { value })
+        | Some(ftd::interpreter::Value::Date { value }) => serde_json::to_value(value).ok(),
+        Some(ftd::interpreter::Value::Duration { seconds }) => serde_json::to_value(seconds).ok(),
+        Some(ftd::interpreter::Value::Record { fields, .. })
+        | Some(ftd::interpreter::Value::Map { data: fields, .. }) => {
             let mut value_fields = ftd::Map::new();
             for (k, v) in fields {
                 if let Some(value) = get_value(doc, &v.clone().resolve(doc, v.line_number())?)? {
@@ -458,6 +493,21 @@ pub(crate) fn get_value(
             }
             serde_json::to_value(value_fields).ok()
         }
+        // or-types defined in ftd documents keep their variant, `{"circle": {"radius": 2.0}}`
+        Some(ftd::interpreter::Value::OrType {
+            value,
+            variant,
+            name,
+            ..
+        }) if !name.starts_with("ftd#") => {
+            let value = get_value(doc, &value.clone().resolve(doc, value.line_number())?)?;
+            let mut variant_value = ftd::Map::new();
+            variant_value.insert(
+                variant_short_name(variant.as_str()).to_string(),
+                value.unwrap_or(serde_json::Value::Null),
+            );
+            serde_json::to_value(variant_value).ok()
+        }
         Some(ftd::interpreter::Value::OrType {
             value,
             variant,
@@ -614,6 +664,7 @@ pub(crate) fn insert_module_thing(
         doc_id: doc.name.to_string(),
         line_number,
         message: format!("{} not found in component arguments.", reference,),
+        span: None,
     })?;
     if let ftd::interpreter::Value::Module {
         things,
@@ -625,6 +676,7 @@ pub(crate) fn insert_module_thing(
             doc_id: doc.name.to_string(),
             line_number,
             message: format!("{} not found in component arguments.", reference),
+            span: None,
         })?
         .value_mut(doc.name, line_number)?
     {
@@ -2,22 +2,118 @@
 pub enum Element {
     Text(Text),
     Integer(Integer),
+    Decimal(Decimal),
+    Boolean(Boolean),
     Column(Column),
+    Row(Row),
+    ContainerElement(ContainerElement),
+    Grid(Grid),
+    Image(Image),
+    Code(Code),
+    Iframe(Iframe),
+    TextInput(TextInput),
+    CheckBox(CheckBox),
+    Select(Select),
+    RadioGroup(RadioGroup),
+    Form(Form),
+    Video(Video),
+    Audio(Audio),
+    Svg(Svg),
+    Icon(Icon),
+    Table(Table),
+    Dialog(Overlay),
+    Popover(Overlay),
+    Tooltip(Overlay),
+    Rive(Rive),
+    WebComponent(WebComponent),
+    Document(Document),
 }
 
 impl Element {
     pub fn from_interpreter_component(
         component: &ftd::interpreter::Component,
         doc: &ftd::interpreter::TDoc,
-    ) -> Element {
+    ) -> ftd::interpreter::Result<Element> {
+        // every arm of these matches holds an element on the stack, each group is built in its
+        // own function so that only the frame of one group is on the stack at a time
         match component.name.as_str() {
+            "ftd#select" | "ftd#radio-group" | "ftd#form" | "ftd#video" | "ftd#audio"
+            | "ftd#svg" | "ftd#icon" | "ftd#table" => {
+                Element::from_form_or_media_component(component, doc)
+            }
+            "ftd#dialog" | "ftd#popover" | "ftd#tooltip" => {
+                Ok(Element::from_overlay_component(component, doc))
+            }
+            _ => Element::from_basic_component(component, doc),
+        }
+    }
+
+    fn from_basic_component(
+        component: &ftd::interpreter::Component,
+        doc: &ftd::interpreter::TDoc,
+    ) -> ftd::interpreter::Result<Element> {
+        Ok(match component.name.as_str() {
             "ftd#text" => Element::Text(Text::from(component)),
-            "ftd#column" => Element::Column(Column::from(component, doc)),
             "ftd#integer" => Element::Integer(Integer::from(component)),
-            _ => todo!(),
+            "ftd#decimal" => Element::Decimal(Decimal::from(component)),
+            "ftd#boolean" => Element::Boolean(Boolean::from(component)),
+            "ftd#column" => Element::Column(Column::from(component, doc)),
+            "ftd#row" => Element::Row(Row::from(component, doc)),
+            "ftd#container" => Element::ContainerElement(ContainerElement::from(component, doc)),
+            "ftd#grid" => Element::Grid(Grid::from(component, doc)),
+            "ftd#image" => Element::Image(Image::from(component)),
+            "ftd#code" => Element::Code(Code::from(component)),
+            "ftd#iframe" => Element::Iframe(Iframe::from(component)),
+            "ftd#text-input" => Element::TextInput(TextInput::from(component)),
+            "ftd#checkbox" => Element::CheckBox(CheckBox::from(component)),
+            "ftd#rive" => Element::Rive(Rive::from(component)),
+            "ftd#document" => Element::Document(Document::from(component, doc)),
+            _ => {
+                let web_component =
+                    doc.get_web_component(component.name.as_str(), component.line_number)?;
+                Element::WebComponent(WebComponent::from(component, web_component))
+            }
+        })
+    }
+
+    /// The form, media, svg and table elements.
+    fn from_form_or_media_component(
+        component: &ftd::interpreter::Component,
+        doc: &ftd::interpreter::TDoc,
+    ) -> ftd::interpreter::Result<Element> {
+        Ok(match component.name.as_str() {
+            "ftd#select" => Element::Select(Select::from(component)),
+            "ftd#radio-group" => Element::RadioGroup(RadioGroup::from(component)),
+            "ftd#form" => Element::Form(Form::from(component, doc)),
+            "ftd#video" => Element::Video(Video::from(component)),
+            "ftd#audio" => Element::Audio(Audio::from(component)),
+            "ftd#svg" => Element::Svg(Svg::from(component)),
+            "ftd#icon" => Element::Icon(Icon::from(component)),
+            "ftd#table" => Element::Table(Table::from(component, doc)),
+            t => {
+                return ftd::interpreter::utils::e2(
+                    format!("`{}` is not a form or media component", t),
+                    doc.name,
+                    component.line_number,
+                )
+            }
+        })
+    }
+
+    /// The dialog, popover and tooltip elements.
+    fn from_overlay_component(
+        component: &ftd::interpreter::Component,
+        doc: &ftd::interpreter::TDoc,
+    ) -> Element {
+        let overlay = Overlay::from(component, doc);
+        match component.name.as_str() {
+            "ftd#dialog" => Element::Dialog(overlay),
+            "ftd#popover" => Element::Popover(overlay),
+            _ => Element::Tooltip(overlay),
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn to_component_statements(
         &self,
         parent: &str,
@@ -26,15 +122,15 @@ impl Element {
         component_definition_name: Option<String>,
         loop_alias: Option<String>,
         should_return: bool,
-    ) -> Vec<fastn_js::ComponentStatement> {
-        match self {
+        inherited: &[String],
+    ) -> ftd::interpreter::Result<Vec<fastn_js::ComponentStatement>> {
+        let (kernel, mut component_statements) = match self {
             Element::Text(text) => text.to_component_statements(
                 parent,
                 index,
                 doc,
                 component_definition_name,
                 loop_alias,
-                should_return,
             ),
             Element::Integer(integer) => integer.to_component_statements(
                 parent,
@@ -42,7 +138,20 @@ impl Element {
                 doc,
                 component_definition_name,
                 loop_alias,
-                should_return,
+            ),
+            Element::Decimal(decimal) => decimal.to_component_statements(
+                parent,
+                index,
+                doc,
+                component_definition_name,
+                loop_alias,
+            ),
+            Element::Boolean(boolean) => boolean.to_component_statements(
+                parent,
+                index,
+                doc,
+                component_definition_name,
+                loop_alias,
             ),
             Element::Column(column) => column.to_component_statements(
                 parent,
@@ -50,53 +159,1900 @@ impl Element {
                 doc,
                 component_definition_name,
                 loop_alias,
-                should_return,
+                inherited,
+            )?,
+            Element::Row(row) => row.to_component_statements(
+                parent,
+                index,
+                doc,
+                component_definition_name,
+                loop_alias,
+                inherited,
+            )?,
+            Element::ContainerElement(container) => container.to_component_statements(
+                parent,
+                index,
+                doc,
+                component_definition_name,
+                loop_alias,
+                inherited,
+            )?,
+            Element::Grid(grid) => grid.to_component_statements(
+                parent,
+                index,
+                doc,
+                component_definition_name,
+                loop_alias,
+                inherited,
+            )?,
+            Element::Image(image) => image.to_component_statements(
+                parent,
+                index,
+                doc,
+                component_definition_name,
+                loop_alias,
+            ),
+            Element::Code(code) => code.to_component_statements(
+                parent,
+                index,
+                doc,
+                component_definition_name,
+                loop_alias,
+            ),
+            Element::Iframe(iframe) => iframe.to_component_statements(
+                parent,
+                index,
+                doc,
+                component_definition_name,
+                loop_alias,
+            ),
+            Element::TextInput(text_input) => text_input.to_component_statements(
+                parent,
+                index,
+                doc,
+                component_definition_name,
+                loop_alias,
+            ),
+            Element::CheckBox(checkbox) => checkbox.to_component_statements(
+                parent,
+                index,
+                doc,
+                component_definition_name,
+                loop_alias,
+            ),
+            Element::Select(select) => select.to_component_statements(
+                parent,
+                index,
+                doc,
+                component_definition_name,
+                loop_alias,
+            ),
+            Element::RadioGroup(radio_group) => radio_group.to_component_statements(
+                parent,
+                index,
+                doc,
+                component_definition_name,
+                loop_alias,
+            ),
+            Element::Form(form) => form.to_component_statements(
+                parent,
+                index,
+                doc,
+                component_definition_name,
+                loop_alias,
+                inherited,
+            )?,
+            Element::Video(video) => video.to_component_statements(
+                parent,
+                index,
+                doc,
+                component_definition_name,
+                loop_alias,
+            ),
+            Element::Audio(audio) => audio.to_component_statements(
+                parent,
+                index,
+                doc,
+                component_definition_name,
+                loop_alias,
+            ),
+            Element::Svg(svg) => svg.to_component_statements(
+                parent,
+                index,
+                doc,
+                component_definition_name,
+                loop_alias,
+            ),
+            Element::Icon(icon) => icon.to_component_statements(
+                parent,
+                index,
+                doc,
+                component_definition_name,
+                loop_alias,
+            ),
+            Element::Table(table) => table.to_component_statements(
+                parent,
+                index,
+                doc,
+                component_definition_name,
+                loop_alias,
+                inherited,
+            ),
+            Element::Dialog(overlay) | Element::Popover(overlay) | Element::Tooltip(overlay) => {
+                overlay.to_component_statements(
+                    parent,
+                    index,
+                    doc,
+                    component_definition_name,
+                    loop_alias,
+                    inherited,
+                )?
+            }
+            Element::Rive(rive) => rive.to_component_statements(
+                parent,
+                index,
+                doc,
+                component_definition_name,
+                loop_alias,
+            ),
+            Element::WebComponent(web_component) => web_component.to_component_statements(
+                parent,
+                index,
+                doc,
+                component_definition_name,
+                loop_alias,
             ),
+            Element::Document(document) => document.to_component_statements(
+                parent,
+                index,
+                doc,
+                component_definition_name,
+                loop_alias,
+                inherited,
+            )?,
+        };
+        if should_return {
+            component_statements.push(fastn_js::ComponentStatement::Return {
+                component_name: kernel.name,
+            });
         }
+        Ok(component_statements)
     }
 }
 
 #[derive(Debug)]
 pub struct Text {
     pub text: ftd::js::Value,
-    pub common: Common,
+    pub text_common: TextCommon,
+    pub common: Box<Common>,
 }
 
 #[derive(Debug)]
 pub struct Integer {
     pub value: ftd::js::Value,
-    pub common: Common,
+    pub text_common: TextCommon,
+    pub common: Box<Common>,
+}
+
+#[derive(Debug)]
+pub struct Decimal {
+    pub value: ftd::js::Value,
+    pub text_common: TextCommon,
+    pub common: Box<Common>,
+}
+
+#[derive(Debug)]
+pub struct Boolean {
+    pub value: ftd::js::Value,
+    pub text_common: TextCommon,
+    pub common: Box<Common>,
 }
 
 #[derive(Debug)]
 pub struct Column {
-    pub children: Vec<ftd::interpreter::Component>,
-    pub common: Common,
+    pub container: Container,
+    pub common: Box<Common>,
 }
 
-impl Text {
-    pub fn from(component: &ftd::interpreter::Component) -> Text {
-        let component_definition = ftd::interpreter::default::default_bag()
-            .get("ftd#text")
-            .unwrap()
-            .clone()
-            .component()
-            .unwrap();
-        Text {
-            text: ftd::js::value::get_properties(
-                "text",
-                component.properties.as_slice(),
-                component_definition.arguments.as_slice(),
-            )
-            .unwrap(),
-            common: Common::from(
-                component.properties.as_slice(),
-                component_definition.arguments.as_slice(),
-                component.events.as_slice(),
-            ),
+#[derive(Debug)]
+pub struct Row {
+    pub container: Container,
+    pub common: Box<Common>,
+}
+
+#[derive(Debug)]
+pub struct ContainerElement {
+    pub children: Children,
+    pub display: Option<ftd::js::Value>,
+    pub common: Box<Common>,
+}
+
+#[derive(Debug)]
+pub struct Grid {
+    pub children: Children,
+    pub columns: Option<ftd::js::Value>,
+    pub rows: Option<ftd::js::Value>,
+    pub areas: Option<ftd::js::Value>,
+    pub gap: Option<ftd::js::Value>,
+    pub row_gap: Option<ftd::js::Value>,
+    pub column_gap: Option<ftd::js::Value>,
+    pub common: Box<Common>,
+}
+
+#[derive(Debug)]
+pub struct Image {
+    pub src: ftd::js::Value,
+    pub common: Box<Common>,
+}
+
+#[derive(Debug)]
+pub struct Code {
+    pub text: ftd::js::Value,
+    pub lang: Option<ftd::js::Value>,
+    pub theme: Option<ftd::js::Value>,
+    pub text_common: TextCommon,
+    pub common: Box<Common>,
+}
+
+#[derive(Debug)]
+pub struct Iframe {
+    pub src: Option<ftd::js::Value>,
+    pub youtube: Option<ftd::js::Value>,
+    pub srcdoc: Option<ftd::js::Value>,
+    pub loading: Option<ftd::js::Value>,
+    pub common: Box<Common>,
+}
+
+#[derive(Debug)]
+pub struct TextInput {
+    pub placeholder: Option<ftd::js::Value>,
+    pub value: Option<ftd::js::Value>,
+    pub default_value: Option<ftd::js::Value>,
+    pub multiline: Option<ftd::js::Value>,
+    pub enabled: Option<ftd::js::Value>,
+    pub type_: Option<ftd::js::Value>,
+    pub min: Option<ftd::js::Value>,
+    pub max: Option<ftd::js::Value>,
+    pub step: Option<ftd::js::Value>,
+    pub pattern: Option<ftd::js::Value>,
+    pub name: Option<ftd::js::Value>,
+    pub required: Option<ftd::js::Value>,
+    pub text_common: TextCommon,
+    pub common: Box<Common>,
+}
+
+#[derive(Debug)]
+pub struct CheckBox {
+    pub checked: Option<ftd::js::Value>,
+    pub enabled: Option<ftd::js::Value>,
+    pub name: Option<ftd::js::Value>,
+    pub required: Option<ftd::js::Value>,
+    pub common: Box<Common>,
+}
+
+#[derive(Debug)]
+pub struct Select {
+    pub options: Option<ftd::js::Value>,
+    pub value: Option<ftd::js::Value>,
+    pub placeholder: Option<ftd::js::Value>,
+    pub enabled: Option<ftd::js::Value>,
+    pub name: Option<ftd::js::Value>,
+    pub required: Option<ftd::js::Value>,
+    pub text_common: TextCommon,
+    pub common: Box<Common>,
+}
+
+#[derive(Debug)]
+pub struct RadioGroup {
+    pub options: Option<ftd::js::Value>,
+    pub value: Option<ftd::js::Value>,
+    pub enabled: Option<ftd::js::Value>,
+    pub name: Option<ftd::js::Value>,
+    pub required: Option<ftd::js::Value>,
+    pub text_common: TextCommon,
+    pub common: Box<Common>,
+}
+
+#[derive(Debug)]
+pub struct Form {
+    pub container: Container,
+    pub action: Option<ftd::js::Value>,
+    pub method: Option<ftd::js::Value>,
+    pub common: Box<Common>,
+}
+
+#[derive(Debug)]
+pub struct Video {
+    pub poster: Option<ftd::js::Value>,
+    pub captions: Option<ftd::js::Value>,
+    pub media: Media,
+    pub common: Box<Common>,
+}
+
+#[derive(Debug)]
+pub struct Audio {
+    pub media: Media,
+    pub common: Box<Common>,
+}
+
+#[derive(Debug)]
+pub struct Svg {
+    pub src: ftd::js::Value,
+    pub fill: Option<ftd::js::Value>,
+    pub stroke: Option<ftd::js::Value>,
+    pub common: Box<Common>,
+}
+
+#[derive(Debug)]
+pub struct Icon {
+    pub name: ftd::js::Value,
+    pub sprite: ftd::js::Value,
+    pub fill: Option<ftd::js::Value>,
+    pub stroke: Option<ftd::js::Value>,
+    pub common: Box<Common>,
+}
+
+/// An `ftd.dialog`, `ftd.popover` or `ftd.tooltip`, `name` is the name of the component.
+#[derive(Debug)]
+pub struct Overlay {
+    pub name: String,
+    pub container: Container,
+    pub open: ftd::js::Value,
+    pub close_on_escape: Option<ftd::js::Value>,
+    pub close_on_outside_click: Option<ftd::js::Value>,
+    pub trigger: Option<ftd::js::Value>,
+    pub placement: Option<ftd::js::Value>,
+    pub common: Box<Common>,
+}
+
+#[derive(Debug)]
+pub struct Table {
+    pub data: ftd::js::Value,
+    pub columns: ftd::js::Value,
+    pub cells: Option<fastn_js::SetPropertyValue>,
+    pub title: Option<ftd::js::Value>,
+    pub filterable: Option<ftd::js::Value>,
+    pub page_size: Option<ftd::js::Value>,
+    pub page_param: Option<ftd::js::Value>,
+    pub page: Option<ftd::js::Value>,
+    pub total: Option<ftd::js::Value>,
+    pub common: Box<Common>,
+}
+
+#[derive(Debug)]
+pub struct Rive {
+    pub src: Option<ftd::js::Value>,
+    pub canvas_width: Option<ftd::js::Value>,
+    pub canvas_height: Option<ftd::js::Value>,
+    pub state_machine: Option<ftd::js::Value>,
+    pub autoplay: Option<ftd::js::Value>,
+    pub artboard: Option<ftd::js::Value>,
+    pub common: Box<Common>,
+}
+
+#[derive(Debug)]
+pub struct WebComponent {
+    pub name: String,
+    pub arguments: Vec<(String, ftd::js::Value)>,
+}
+
+#[derive(Debug)]
+pub struct Document {
+    pub children: Children,
+    pub title: Option<ftd::js::Value>,
+    pub og_title: Option<ftd::js::Value>,
+    pub twitter_title: Option<ftd::js::Value>,
+    pub description: Option<ftd::js::Value>,
+    pub og_description: Option<ftd::js::Value>,
+    pub twitter_description: Option<ftd::js::Value>,
+    pub og_image: Option<ftd::js::Value>,
+    pub twitter_image: Option<ftd::js::Value>,
+    pub theme_color: Option<ftd::js::Value>,
+    pub breakpoints: Option<ftd::js::Value>,
+}
+
+fn component_definition(name: &str) -> ftd::interpreter::ComponentDefinition {
+    ftd::interpreter::default::default_bag()
+        .get(name)
+        .unwrap()
+        .clone()
+        .component()
+        .unwrap()
+}
+
+/// `SetProperty` statements for the properties that have a value, in the given order.
+fn set_properties(
+    element_name: &str,
+    properties: Vec<(&Option<ftd::js::Value>, fastn_js::PropertyKind)>,
+    component_definition_name: &Option<String>,
+    loop_alias: &Option<String>,
+) -> Vec<fastn_js::ComponentStatement> {
+    properties
+        .into_iter()
+        .filter_map(|(value, kind)| {
+            value.as_ref().map(|value| {
+                fastn_js::ComponentStatement::SetProperty(value.to_set_property(
+                    kind,
+                    element_name,
+                    component_definition_name.clone(),
+                    loop_alias.clone(),
+                ))
+            })
+        })
+        .collect()
+}
+
+/// The children of a container, the components under it or the `ftd.ui list` it is given,
+/// `children: $page.body`.
+#[derive(Debug)]
+pub struct Children {
+    pub components: Vec<ftd::interpreter::Component>,
+    /// the reference to the `ftd.ui` or `ftd.ui list` the children are read from
+    pub reference: Option<ftd::interpreter::PropertyValue>,
+    /// the `colors` and `types` given to the container, its children read them as
+    /// `$inherited.colors` and `$inherited.types`
+    pub inherited: Vec<(String, ftd::js::Value)>,
+}
+
+impl Children {
+    pub fn from(
+        component: &ftd::interpreter::Component,
+        doc: &ftd::interpreter::TDoc,
+        arguments: &[ftd::interpreter::Argument],
+    ) -> Children {
+        let reference = component
+            .properties
+            .iter()
+            .find(|v| {
+                v.value.kind().inner_list().is_subsection_ui() && v.value.reference_name().is_some()
+            })
+            .map(|v| v.value.clone());
+        let inherited = ["colors", "types"]
+            .into_iter()
+            .filter(|key| arguments.iter().any(|v| v.name.eq(key)))
+            .filter_map(|key| {
+                ftd::js::value::get_properties(key, component.properties.as_slice(), arguments)
+                    .map(|value| (key.to_string(), value))
+            })
+            .collect();
+        Children {
+            components: match reference {
+                Some(_) => vec![],
+                None => component.get_children(doc).unwrap(),
+            },
+            reference,
+            inherited,
+        }
+    }
+
+    pub fn to_component_statements(
+        &self,
+        parent: &str,
+        doc: &ftd::interpreter::TDoc,
+        component_definition_name: Option<String>,
+        loop_alias: Option<String>,
+        inherited: &[String],
+    ) -> ftd::interpreter::Result<Vec<fastn_js::ComponentStatement>> {
+        let mut statements = vec![];
+        for (index, child) in self.components.iter().enumerate() {
+            statements.extend(child.to_component_statements(
+                parent,
+                index,
+                doc,
+                component_definition_name.clone(),
+                false,
+                inherited,
+            )?);
+        }
+        if let Some(reference) = self.reference.as_ref() {
+            let value = reference.to_fastn_js_value_with_reference(
+                component_definition_name.clone(),
+                loop_alias.clone(),
+            );
+            let instance = |component, parent: &str, should_return| {
+                fastn_js::ComponentStatement::InstantiateComponent(fastn_js::InstantiateComponent {
+                    component: fastn_js::InstantiateComponentData::Definition(Box::new(component)),
+                    arguments: vec![],
+                    parent: parent.to_string(),
+                    inherited: (!inherited.is_empty()).then(Default::default),
+                    should_return,
+                })
+            };
+            statements.push(if reference.kind().is_list() {
+                fastn_js::ComponentStatement::ForLoop(fastn_js::ForLoop {
+                    list_variable: value,
+                    statements: vec![instance(
+                        fastn_js::SetPropertyValue::Reference("item".to_string()),
+                        "root",
+                        true,
+                    )],
+                    parent: parent.to_string(),
+                    should_return: false,
+                    integer_keys: false,
+                })
+            } else {
+                instance(value, parent, false)
+            });
+        }
+
+        let values = self
+            .inherited
+            .iter()
+            .filter(|(key, _)| inherited.contains(key))
+            .map(|(key, value)| {
+                (
+                    key.to_string(),
+                    value.to_set_property_value(
+                        component_definition_name.clone(),
+                        loop_alias.clone(),
+                    ),
+                )
+            })
+            .collect::<Vec<_>>();
+        if values.is_empty() {
+            return Ok(statements);
+        }
+        Ok(vec![fastn_js::ComponentStatement::ExtendInherited {
+            inherited: fastn_js::Inherited {
+                values,
+                defaults: vec![],
+            },
+            statements,
+        }])
+    }
+}
+
+/// The device `ftd.desktop` and `ftd.mobile` show their children on.
+pub(crate) fn device(s: &str) -> Option<&'static str> {
+    match s {
+        "ftd#desktop" => Some("desktop"),
+        "ftd#mobile" => Some("mobile"),
+        _ => None,
+    }
+}
+
+/// The children of `ftd.desktop` or `ftd.mobile`, each is shown while `ftd.device` is `device`.
+pub(crate) fn device_statements(
+    device: &str,
+    children: &[ftd::interpreter::Component],
+    parent: &str,
+    doc: &ftd::interpreter::TDoc,
+    component_definition_name: Option<String>,
+    should_return: bool,
+    inherited: &[String],
+) -> ftd::interpreter::Result<Vec<fastn_js::ComponentStatement>> {
+    let mut statements = vec![];
+    for (index, child) in children.iter().enumerate() {
+        statements.push(fastn_js::ComponentStatement::ConditionalComponent(
+            fastn_js::ConditionalComponent {
+                deps: vec!["ftd#device".to_string()],
+                condition: fastn_grammar::evalexpr::build_operator_tree(
+                    format!("ftd__device == \"{}\"", device).as_str(),
+                )
+                .unwrap(),
+                statements: child.to_component_statements(
+                    "root",
+                    0,
+                    doc,
+                    component_definition_name.clone(),
+                    true,
+                    inherited,
+                )?,
+                parent: parent.to_string(),
+                // the last child is returned, the way a component with a single child is
+                should_return: should_return && index + 1 == children.len(),
+            },
+        ));
+    }
+    Ok(statements)
+}
+
+impl Text {
+    pub fn from(component: &ftd::interpreter::Component) -> Text {
+        let component_definition = component_definition("ftd#text");
+        Text {
+            text: ftd::js::value::get_properties(
+                "text",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            )
+            .unwrap(),
+            text_common: TextCommon::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            common: Box::new(Common::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+                component.events.as_slice(),
+            )),
+        }
+    }
+
+    pub fn to_component_statements(
+        &self,
+        parent: &str,
+        index: usize,
+        doc: &ftd::interpreter::TDoc,
+        component_definition_name: Option<String>,
+        loop_alias: Option<String>,
+    ) -> (fastn_js::Kernel, Vec<fastn_js::ComponentStatement>) {
+        let mut component_statements = vec![];
+        let kernel = fastn_js::Kernel::from_component("ftd#text", parent, index);
+        component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
+        component_statements.push(fastn_js::ComponentStatement::SetProperty(
+            self.text.to_set_property(
+                fastn_js::PropertyKind::StringValue,
+                kernel.name.as_str(),
+                component_definition_name.clone(),
+                loop_alias.clone(),
+            ),
+        ));
+        component_statements.extend(self.text_common.to_set_properties(
+            kernel.name.as_str(),
+            &component_definition_name,
+            &loop_alias,
+        ));
+        component_statements.extend(self.common.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            component_definition_name,
+            loop_alias,
+        ));
+        (kernel, component_statements)
+    }
+}
+
+impl Integer {
+    pub fn from(component: &ftd::interpreter::Component) -> Integer {
+        let component_definition = component_definition("ftd#integer");
+        Integer {
+            value: ftd::js::value::get_properties(
+                "value",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            )
+            .unwrap(),
+            text_common: TextCommon::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            common: Box::new(Common::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+                component.events.as_slice(),
+            )),
+        }
+    }
+
+    pub fn to_component_statements(
+        &self,
+        parent: &str,
+        index: usize,
+        doc: &ftd::interpreter::TDoc,
+        component_definition_name: Option<String>,
+        loop_alias: Option<String>,
+    ) -> (fastn_js::Kernel, Vec<fastn_js::ComponentStatement>) {
+        let mut component_statements = vec![];
+        let kernel = fastn_js::Kernel::from_component("ftd#integer", parent, index);
+        component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
+        component_statements.push(fastn_js::ComponentStatement::SetProperty(
+            self.value.to_set_property(
+                fastn_js::PropertyKind::StringValue,
+                kernel.name.as_str(),
+                component_definition_name.clone(),
+                loop_alias.clone(),
+            ),
+        ));
+        component_statements.extend(self.text_common.to_set_properties(
+            kernel.name.as_str(),
+            &component_definition_name,
+            &loop_alias,
+        ));
+        component_statements.extend(self.common.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            component_definition_name,
+            loop_alias,
+        ));
+        (kernel, component_statements)
+    }
+}
+
+impl Decimal {
+    pub fn from(component: &ftd::interpreter::Component) -> Decimal {
+        let component_definition = component_definition("ftd#decimal");
+        Decimal {
+            value: ftd::js::value::get_properties(
+                "value",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            )
+            .unwrap(),
+            text_common: TextCommon::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            common: Box::new(Common::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+                component.events.as_slice(),
+            )),
+        }
+    }
+
+    pub fn to_component_statements(
+        &self,
+        parent: &str,
+        index: usize,
+        doc: &ftd::interpreter::TDoc,
+        component_definition_name: Option<String>,
+        loop_alias: Option<String>,
+    ) -> (fastn_js::Kernel, Vec<fastn_js::ComponentStatement>) {
+        let mut component_statements = vec![];
+        let kernel = fastn_js::Kernel::from_component("ftd#decimal", parent, index);
+        component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
+        component_statements.push(fastn_js::ComponentStatement::SetProperty(
+            self.value.to_set_property(
+                fastn_js::PropertyKind::StringValue,
+                kernel.name.as_str(),
+                component_definition_name.clone(),
+                loop_alias.clone(),
+            ),
+        ));
+        component_statements.extend(self.text_common.to_set_properties(
+            kernel.name.as_str(),
+            &component_definition_name,
+            &loop_alias,
+        ));
+        component_statements.extend(self.common.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            component_definition_name,
+            loop_alias,
+        ));
+        (kernel, component_statements)
+    }
+}
+
+impl Boolean {
+    pub fn from(component: &ftd::interpreter::Component) -> Boolean {
+        let component_definition = component_definition("ftd#boolean");
+        Boolean {
+            value: ftd::js::value::get_properties(
+                "value",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            )
+            .unwrap(),
+            text_common: TextCommon::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            common: Box::new(Common::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+                component.events.as_slice(),
+            )),
+        }
+    }
+
+    pub fn to_component_statements(
+        &self,
+        parent: &str,
+        index: usize,
+        doc: &ftd::interpreter::TDoc,
+        component_definition_name: Option<String>,
+        loop_alias: Option<String>,
+    ) -> (fastn_js::Kernel, Vec<fastn_js::ComponentStatement>) {
+        let mut component_statements = vec![];
+        let kernel = fastn_js::Kernel::from_component("ftd#boolean", parent, index);
+        component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
+        component_statements.push(fastn_js::ComponentStatement::SetProperty(
+            self.value.to_set_property(
+                fastn_js::PropertyKind::StringValue,
+                kernel.name.as_str(),
+                component_definition_name.clone(),
+                loop_alias.clone(),
+            ),
+        ));
+        component_statements.extend(self.text_common.to_set_properties(
+            kernel.name.as_str(),
+            &component_definition_name,
+            &loop_alias,
+        ));
+        component_statements.extend(self.common.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            component_definition_name,
+            loop_alias,
+        ));
+        (kernel, component_statements)
+    }
+}
+
+impl Column {
+    pub fn from(component: &ftd::interpreter::Component, doc: &ftd::interpreter::TDoc) -> Column {
+        let component_definition = component_definition("ftd#column");
+        Column {
+            container: Container::from(component, doc, component_definition.arguments.as_slice()),
+            common: Box::new(Common::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+                component.events.as_slice(),
+            )),
+        }
+    }
+
+    pub fn to_component_statements(
+        &self,
+        parent: &str,
+        index: usize,
+        doc: &ftd::interpreter::TDoc,
+        component_definition_name: Option<String>,
+        loop_alias: Option<String>,
+        inherited: &[String],
+    ) -> ftd::interpreter::Result<(fastn_js::Kernel, Vec<fastn_js::ComponentStatement>)> {
+        let mut component_statements = vec![];
+        let kernel = fastn_js::Kernel::from_component("ftd#column", parent, index);
+        component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
+        component_statements.extend(self.common.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            component_definition_name.clone(),
+            loop_alias.clone(),
+        ));
+        component_statements.extend(self.container.to_set_properties(
+            kernel.name.as_str(),
+            &component_definition_name,
+            &loop_alias,
+        ));
+        component_statements.extend(self.container.children.to_component_statements(
+            kernel.name.as_str(),
+            doc,
+            component_definition_name,
+            loop_alias,
+            inherited,
+        )?);
+        Ok((kernel, component_statements))
+    }
+}
+
+impl Row {
+    pub fn from(component: &ftd::interpreter::Component, doc: &ftd::interpreter::TDoc) -> Row {
+        let component_definition = component_definition("ftd#row");
+        Row {
+            container: Container::from(component, doc, component_definition.arguments.as_slice()),
+            common: Box::new(Common::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+                component.events.as_slice(),
+            )),
+        }
+    }
+
+    pub fn to_component_statements(
+        &self,
+        parent: &str,
+        index: usize,
+        doc: &ftd::interpreter::TDoc,
+        component_definition_name: Option<String>,
+        loop_alias: Option<String>,
+        inherited: &[String],
+    ) -> ftd::interpreter::Result<(fastn_js::Kernel, Vec<fastn_js::ComponentStatement>)> {
+        let mut component_statements = vec![];
+        let kernel = fastn_js::Kernel::from_component("ftd#row", parent, index);
+        component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
+        component_statements.extend(self.common.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            component_definition_name.clone(),
+            loop_alias.clone(),
+        ));
+        component_statements.extend(self.container.to_set_properties(
+            kernel.name.as_str(),
+            &component_definition_name,
+            &loop_alias,
+        ));
+        component_statements.extend(self.container.children.to_component_statements(
+            kernel.name.as_str(),
+            doc,
+            component_definition_name,
+            loop_alias,
+            inherited,
+        )?);
+        Ok((kernel, component_statements))
+    }
+}
+
+impl ContainerElement {
+    pub fn from(
+        component: &ftd::interpreter::Component,
+        doc: &ftd::interpreter::TDoc,
+    ) -> ContainerElement {
+        let component_definition = component_definition("ftd#container");
+        ContainerElement {
+            children: Children::from(component, doc, component_definition.arguments.as_slice()),
+            display: ftd::js::value::get_properties(
+                "display",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            common: Box::new(Common::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+                component.events.as_slice(),
+            )),
+        }
+    }
+
+    pub fn to_component_statements(
+        &self,
+        parent: &str,
+        index: usize,
+        doc: &ftd::interpreter::TDoc,
+        component_definition_name: Option<String>,
+        loop_alias: Option<String>,
+        inherited: &[String],
+    ) -> ftd::interpreter::Result<(fastn_js::Kernel, Vec<fastn_js::ComponentStatement>)> {
+        let mut component_statements = vec![];
+        let kernel = fastn_js::Kernel::from_component("ftd#container", parent, index);
+        component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
+        component_statements.extend(self.common.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            component_definition_name.clone(),
+            loop_alias.clone(),
+        ));
+        component_statements.extend(set_properties(
+            kernel.name.as_str(),
+            vec![(&self.display, fastn_js::PropertyKind::Display)],
+            &component_definition_name,
+            &loop_alias,
+        ));
+        component_statements.extend(self.children.to_component_statements(
+            kernel.name.as_str(),
+            doc,
+            component_definition_name,
+            loop_alias,
+            inherited,
+        )?);
+        Ok((kernel, component_statements))
+    }
+}
+
+impl Grid {
+    pub fn from(component: &ftd::interpreter::Component, doc: &ftd::interpreter::TDoc) -> Grid {
+        let component_definition = component_definition("ftd#grid");
+        let properties = component.properties.as_slice();
+        let arguments = component_definition.arguments.as_slice();
+        Grid {
+            children: Children::from(component, doc, arguments),
+            columns: ftd::js::value::get_properties("columns", properties, arguments),
+            rows: ftd::js::value::get_properties("rows", properties, arguments),
+            areas: ftd::js::value::get_properties("areas", properties, arguments),
+            gap: ftd::js::value::get_properties("gap", properties, arguments),
+            row_gap: ftd::js::value::get_properties("row-gap", properties, arguments),
+            column_gap: ftd::js::value::get_properties("column-gap", properties, arguments),
+            common: Box::new(Common::from(
+                properties,
+                arguments,
+                component.events.as_slice(),
+            )),
+        }
+    }
+
+    pub fn to_component_statements(
+        &self,
+        parent: &str,
+        index: usize,
+        doc: &ftd::interpreter::TDoc,
+        component_definition_name: Option<String>,
+        loop_alias: Option<String>,
+        inherited: &[String],
+    ) -> ftd::interpreter::Result<(fastn_js::Kernel, Vec<fastn_js::ComponentStatement>)> {
+        let mut component_statements = vec![];
+        let kernel = fastn_js::Kernel::from_component("ftd#grid", parent, index);
+        component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
+        component_statements.extend(self.common.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            component_definition_name.clone(),
+            loop_alias.clone(),
+        ));
+        component_statements.extend(set_properties(
+            kernel.name.as_str(),
+            vec![
+                (&self.columns, fastn_js::PropertyKind::GridTemplateColumns),
+                (&self.rows, fastn_js::PropertyKind::GridTemplateRows),
+                (&self.areas, fastn_js::PropertyKind::GridTemplateAreas),
+                (&self.gap, fastn_js::PropertyKind::Gap),
+                (&self.row_gap, fastn_js::PropertyKind::RowGap),
+                (&self.column_gap, fastn_js::PropertyKind::ColumnGap),
+            ],
+            &component_definition_name,
+            &loop_alias,
+        ));
+        component_statements.extend(self.children.to_component_statements(
+            kernel.name.as_str(),
+            doc,
+            component_definition_name,
+            loop_alias,
+            inherited,
+        )?);
+        Ok((kernel, component_statements))
+    }
+}
+
+impl Image {
+    pub fn from(component: &ftd::interpreter::Component) -> Image {
+        let component_definition = component_definition("ftd#image");
+        let properties = component.properties.as_slice();
+        let arguments = component_definition.arguments.as_slice();
+        Image {
+            src: ftd::js::value::get_properties("src", properties, arguments).unwrap(),
+            common: Box::new(Common::from(
+                properties,
+                arguments,
+                component.events.as_slice(),
+            )),
+        }
+    }
+
+    pub fn to_component_statements(
+        &self,
+        parent: &str,
+        index: usize,
+        doc: &ftd::interpreter::TDoc,
+        component_definition_name: Option<String>,
+        loop_alias: Option<String>,
+    ) -> (fastn_js::Kernel, Vec<fastn_js::ComponentStatement>) {
+        let mut component_statements = vec![];
+        let kernel = fastn_js::Kernel::from_component("ftd#image", parent, index);
+        component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
+        component_statements.push(fastn_js::ComponentStatement::SetProperty(
+            self.src.to_set_property(
+                fastn_js::PropertyKind::ImageSrc,
+                kernel.name.as_str(),
+                component_definition_name.clone(),
+                loop_alias.clone(),
+            ),
+        ));
+        component_statements.extend(self.common.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            component_definition_name,
+            loop_alias,
+        ));
+        (kernel, component_statements)
+    }
+}
+
+impl Code {
+    pub fn from(component: &ftd::interpreter::Component) -> Code {
+        let component_definition = component_definition("ftd#code");
+        let properties = component.properties.as_slice();
+        let arguments = component_definition.arguments.as_slice();
+        Code {
+            text: ftd::js::value::get_properties("text", properties, arguments).unwrap(),
+            lang: ftd::js::value::get_properties("lang", properties, arguments),
+            theme: ftd::js::value::get_properties("theme", properties, arguments),
+            text_common: TextCommon::from(properties, arguments),
+            common: Box::new(Common::from(
+                properties,
+                arguments,
+                component.events.as_slice(),
+            )),
+        }
+    }
+
+    pub fn to_component_statements(
+        &self,
+        parent: &str,
+        index: usize,
+        doc: &ftd::interpreter::TDoc,
+        component_definition_name: Option<String>,
+        loop_alias: Option<String>,
+    ) -> (fastn_js::Kernel, Vec<fastn_js::ComponentStatement>) {
+        let mut component_statements = vec![];
+        let kernel = fastn_js::Kernel::from_component("ftd#code", parent, index);
+        component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
+        // language and theme are needed to highlight the code, so they are set before it
+        component_statements.extend(set_properties(
+            kernel.name.as_str(),
+            vec![
+                (&self.lang, fastn_js::PropertyKind::CodeLanguage),
+                (&self.theme, fastn_js::PropertyKind::CodeTheme),
+            ],
+            &component_definition_name,
+            &loop_alias,
+        ));
+        component_statements.push(fastn_js::ComponentStatement::SetProperty(
+            self.text.to_set_property(
+                fastn_js::PropertyKind::Code,
+                kernel.name.as_str(),
+                component_definition_name.clone(),
+                loop_alias.clone(),
+            ),
+        ));
+        component_statements.extend(self.text_common.to_set_properties(
+            kernel.name.as_str(),
+            &component_definition_name,
+            &loop_alias,
+        ));
+        component_statements.extend(self.common.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            component_definition_name,
+            loop_alias,
+        ));
+        (kernel, component_statements)
+    }
+}
+
+impl Iframe {
+    pub fn from(component: &ftd::interpreter::Component) -> Iframe {
+        let component_definition = component_definition("ftd#iframe");
+        let properties = component.properties.as_slice();
+        let arguments = component_definition.arguments.as_slice();
+        Iframe {
+            src: ftd::js::value::get_properties("src", properties, arguments),
+            youtube: ftd::js::value::get_properties("youtube", properties, arguments),
+            srcdoc: ftd::js::value::get_properties("srcdoc", properties, arguments),
+            loading: ftd::js::value::get_properties("loading", properties, arguments),
+            common: Box::new(Common::from(
+                properties,
+                arguments,
+                component.events.as_slice(),
+            )),
+        }
+    }
+
+    pub fn to_component_statements(
+        &self,
+        parent: &str,
+        index: usize,
+        doc: &ftd::interpreter::TDoc,
+        component_definition_name: Option<String>,
+        loop_alias: Option<String>,
+    ) -> (fastn_js::Kernel, Vec<fastn_js::ComponentStatement>) {
+        let mut component_statements = vec![];
+        let kernel = fastn_js::Kernel::from_component("ftd#iframe", parent, index);
+        component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
+        component_statements.extend(set_properties(
+            kernel.name.as_str(),
+            vec![
+                (&self.src, fastn_js::PropertyKind::IFrameSrc),
+                (&self.youtube, fastn_js::PropertyKind::YoutubeSrc),
+                (&self.srcdoc, fastn_js::PropertyKind::IFrameSrcDoc),
+                (&self.loading, fastn_js::PropertyKind::Loading),
+            ],
+            &component_definition_name,
+            &loop_alias,
+        ));
+        component_statements.extend(self.common.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            component_definition_name,
+            loop_alias,
+        ));
+        (kernel, component_statements)
+    }
+}
+
+impl TextInput {
+    pub fn from(component: &ftd::interpreter::Component) -> TextInput {
+        let component_definition = component_definition("ftd#text-input");
+        let properties = component.properties.as_slice();
+        let arguments = component_definition.arguments.as_slice();
+        TextInput {
+            placeholder: ftd::js::value::get_properties("placeholder", properties, arguments),
+            value: ftd::js::value::get_properties("value", properties, arguments),
+            default_value: ftd::js::value::get_properties("default-value", properties, arguments),
+            multiline: ftd::js::value::get_properties("multiline", properties, arguments),
+            enabled: ftd::js::value::get_properties("enabled", properties, arguments),
+            type_: ftd::js::value::get_properties("type", properties, arguments),
+            min: ftd::js::value::get_properties("min", properties, arguments),
+            max: ftd::js::value::get_properties("max", properties, arguments),
+            step: ftd::js::value::get_properties("step", properties, arguments),
+            pattern: ftd::js::value::get_properties("pattern", properties, arguments),
+            name: ftd::js::value::get_properties("name", properties, arguments),
+            required: ftd::js::value::get_properties("required", properties, arguments),
+            text_common: TextCommon::from(properties, arguments),
+            common: Box::new(Common::from(
+                properties,
+                arguments,
+                component.events.as_slice(),
+            )),
+        }
+    }
+
+    /// `multiline` decides the tag, `<textarea>` or `<input>`, so only a literal `true` is
+    /// supported, a `multiline` that changes at runtime is ignored.
+    fn is_multiline(&self) -> bool {
+        matches!(
+            self.multiline,
+            Some(ftd::js::Value::Data(ftd::interpreter::Value::Boolean {
+                value: true
+            }))
+        )
+    }
+
+    pub fn to_component_statements(
+        &self,
+        parent: &str,
+        index: usize,
+        doc: &ftd::interpreter::TDoc,
+        component_definition_name: Option<String>,
+        loop_alias: Option<String>,
+    ) -> (fastn_js::Kernel, Vec<fastn_js::ComponentStatement>) {
+        let mut component_statements = vec![];
+        let mut kernel = fastn_js::Kernel::from_component("ftd#text-input", parent, index);
+        if self.is_multiline() {
+            kernel.element_kind = fastn_js::ElementKind::TextArea;
+        }
+        component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
+        component_statements.extend(set_properties(
+            kernel.name.as_str(),
+            vec![
+                (&self.type_, fastn_js::PropertyKind::InputType),
+                (&self.placeholder, fastn_js::PropertyKind::Placeholder),
+                (&self.default_value, fastn_js::PropertyKind::DefaultValue),
+                (&self.value, fastn_js::PropertyKind::Value),
+                (&self.enabled, fastn_js::PropertyKind::Enabled),
+                (&self.min, fastn_js::PropertyKind::Min),
+                (&self.max, fastn_js::PropertyKind::Max),
+                (&self.step, fastn_js::PropertyKind::Step),
+                (&self.pattern, fastn_js::PropertyKind::Pattern),
+                (&self.name, fastn_js::PropertyKind::FieldName),
+                (&self.required, fastn_js::PropertyKind::Required),
+            ],
+            &component_definition_name,
+            &loop_alias,
+        ));
+        component_statements.extend(self.text_common.to_set_properties(
+            kernel.name.as_str(),
+            &component_definition_name,
+            &loop_alias,
+        ));
+        component_statements.extend(self.common.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            component_definition_name,
+            loop_alias,
+        ));
+        (kernel, component_statements)
+    }
+}
+
+impl CheckBox {
+    pub fn from(component: &ftd::interpreter::Component) -> CheckBox {
+        let component_definition = component_definition("ftd#checkbox");
+        let properties = component.properties.as_slice();
+        let arguments = component_definition.arguments.as_slice();
+        CheckBox {
+            checked: ftd::js::value::get_properties("checked", properties, arguments),
+            enabled: ftd::js::value::get_properties("enabled", properties, arguments),
+            name: ftd::js::value::get_properties("name", properties, arguments),
+            required: ftd::js::value::get_properties("required", properties, arguments),
+            common: Box::new(Common::from(
+                properties,
+                arguments,
+                component.events.as_slice(),
+            )),
+        }
+    }
+
+    pub fn to_component_statements(
+        &self,
+        parent: &str,
+        index: usize,
+        doc: &ftd::interpreter::TDoc,
+        component_definition_name: Option<String>,
+        loop_alias: Option<String>,
+    ) -> (fastn_js::Kernel, Vec<fastn_js::ComponentStatement>) {
+        let mut component_statements = vec![];
+        let kernel = fastn_js::Kernel::from_component("ftd#checkbox", parent, index);
+        component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
+        component_statements.extend(set_properties(
+            kernel.name.as_str(),
+            vec![
+                (&self.checked, fastn_js::PropertyKind::Checked),
+                (&self.enabled, fastn_js::PropertyKind::Enabled),
+                (&self.name, fastn_js::PropertyKind::FieldName),
+                (&self.required, fastn_js::PropertyKind::Required),
+            ],
+            &component_definition_name,
+            &loop_alias,
+        ));
+        component_statements.extend(self.common.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            component_definition_name,
+            loop_alias,
+        ));
+        (kernel, component_statements)
+    }
+}
+
+impl Select {
+    pub fn from(component: &ftd::interpreter::Component) -> Select {
+        let component_definition = component_definition("ftd#select");
+        let properties = component.properties.as_slice();
+        let arguments = component_definition.arguments.as_slice();
+        Select {
+            options: ftd::js::value::get_properties("options", properties, arguments),
+            value: ftd::js::value::get_properties("value", properties, arguments),
+            placeholder: ftd::js::value::get_properties("placeholder", properties, arguments),
+            enabled: ftd::js::value::get_properties("enabled", properties, arguments),
+            name: ftd::js::value::get_properties("name", properties, arguments),
+            required: ftd::js::value::get_properties("required", properties, arguments),
+            text_common: TextCommon::from(properties, arguments),
+            common: Box::new(Common::from(
+                properties,
+                arguments,
+                component.events.as_slice(),
+            )),
+        }
+    }
+
+    pub fn to_component_statements(
+        &self,
+        parent: &str,
+        index: usize,
+        doc: &ftd::interpreter::TDoc,
+        component_definition_name: Option<String>,
+        loop_alias: Option<String>,
+    ) -> (fastn_js::Kernel, Vec<fastn_js::ComponentStatement>) {
+        let mut component_statements = vec![];
+        let kernel = fastn_js::Kernel::from_component("ftd#select", parent, index);
+        component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
+        // the options are rendered with the placeholder and the selected value
+        component_statements.extend(set_properties(
+            kernel.name.as_str(),
+            vec![
+                (&self.placeholder, fastn_js::PropertyKind::Placeholder),
+                (&self.value, fastn_js::PropertyKind::Value),
+                (&self.options, fastn_js::PropertyKind::Options),
+                (&self.enabled, fastn_js::PropertyKind::Enabled),
+                (&self.name, fastn_js::PropertyKind::FieldName),
+                (&self.required, fastn_js::PropertyKind::Required),
+            ],
+            &component_definition_name,
+            &loop_alias,
+        ));
+        component_statements.extend(self.text_common.to_set_properties(
+            kernel.name.as_str(),
+            &component_definition_name,
+            &loop_alias,
+        ));
+        component_statements.extend(self.common.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            component_definition_name,
+            loop_alias,
+        ));
+        (kernel, component_statements)
+    }
+}
+
+impl RadioGroup {
+    pub fn from(component: &ftd::interpreter::Component) -> RadioGroup {
+        let component_definition = component_definition("ftd#radio-group");
+        let properties = component.properties.as_slice();
+        let arguments = component_definition.arguments.as_slice();
+        RadioGroup {
+            options: ftd::js::value::get_properties("options", properties, arguments),
+            value: ftd::js::value::get_properties("value", properties, arguments),
+            enabled: ftd::js::value::get_properties("enabled", properties, arguments),
+            name: ftd::js::value::get_properties("name", properties, arguments),
+            required: ftd::js::value::get_properties("required", properties, arguments),
+            text_common: TextCommon::from(properties, arguments),
+            common: Box::new(Common::from(
+                properties,
+                arguments,
+                component.events.as_slice(),
+            )),
+        }
+    }
+
+    pub fn to_component_statements(
+        &self,
+        parent: &str,
+        index: usize,
+        doc: &ftd::interpreter::TDoc,
+        component_definition_name: Option<String>,
+        loop_alias: Option<String>,
+    ) -> (fastn_js::Kernel, Vec<fastn_js::ComponentStatement>) {
+        let mut component_statements = vec![];
+        let kernel = fastn_js::Kernel::from_component("ftd#radio-group", parent, index);
+        component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
+        // the radio buttons are rendered with the name, state and value of the group
+        component_statements.extend(set_properties(
+            kernel.name.as_str(),
+            vec![
+                (&self.name, fastn_js::PropertyKind::FieldName),
+                (&self.enabled, fastn_js::PropertyKind::Enabled),
+                (&self.required, fastn_js::PropertyKind::Required),
+                (&self.value, fastn_js::PropertyKind::Value),
+                (&self.options, fastn_js::PropertyKind::Options),
+            ],
+            &component_definition_name,
+            &loop_alias,
+        ));
+        component_statements.extend(self.text_common.to_set_properties(
+            kernel.name.as_str(),
+            &component_definition_name,
+            &loop_alias,
+        ));
+        component_statements.extend(self.common.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            component_definition_name,
+            loop_alias,
+        ));
+        (kernel, component_statements)
+    }
+}
+
+impl Form {
+    pub fn from(component: &ftd::interpreter::Component, doc: &ftd::interpreter::TDoc) -> Form {
+        let component_definition = component_definition("ftd#form");
+        let properties = component.properties.as_slice();
+        let arguments = component_definition.arguments.as_slice();
+        Form {
+            container: Container::from(component, doc, arguments),
+            action: ftd::js::value::get_properties("action", properties, arguments),
+            method: ftd::js::value::get_properties("method", properties, arguments),
+            common: Box::new(Common::from(
+                properties,
+                arguments,
+                component.events.as_slice(),
+            )),
+        }
+    }
+
+    pub fn to_component_statements(
+        &self,
+        parent: &str,
+        index: usize,
+        doc: &ftd::interpreter::TDoc,
+        component_definition_name: Option<String>,
+        loop_alias: Option<String>,
+        inherited: &[String],
+    ) -> ftd::interpreter::Result<(fastn_js::Kernel, Vec<fastn_js::ComponentStatement>)> {
+        let mut component_statements = vec![];
+        let kernel = fastn_js::Kernel::from_component("ftd#form", parent, index);
+        component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
+        component_statements.extend(set_properties(
+            kernel.name.as_str(),
+            vec![
+                (&self.action, fastn_js::PropertyKind::FormAction),
+                (&self.method, fastn_js::PropertyKind::FormMethod),
+            ],
+            &component_definition_name,
+            &loop_alias,
+        ));
+        component_statements.extend(self.common.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            component_definition_name.clone(),
+            loop_alias.clone(),
+        ));
+        component_statements.extend(self.container.to_set_properties(
+            kernel.name.as_str(),
+            &component_definition_name,
+            &loop_alias,
+        ));
+        component_statements.extend(self.container.children.to_component_statements(
+            kernel.name.as_str(),
+            doc,
+            component_definition_name,
+            loop_alias,
+            inherited,
+        )?);
+        Ok((kernel, component_statements))
+    }
+}
+
+impl Video {
+    pub fn from(component: &ftd::interpreter::Component) -> Video {
+        let component_definition = component_definition("ftd#video");
+        let properties = component.properties.as_slice();
+        let arguments = component_definition.arguments.as_slice();
+        Video {
+            poster: ftd::js::value::get_properties("poster", properties, arguments),
+            captions: ftd::js::value::get_properties("captions", properties, arguments),
+            media: Media::from(properties, arguments),
+            common: Box::new(Common::from(
+                properties,
+                arguments,
+                component.events.as_slice(),
+            )),
+        }
+    }
+
+    pub fn to_component_statements(
+        &self,
+        parent: &str,
+        index: usize,
+        doc: &ftd::interpreter::TDoc,
+        component_definition_name: Option<String>,
+        loop_alias: Option<String>,
+    ) -> (fastn_js::Kernel, Vec<fastn_js::ComponentStatement>) {
+        let mut component_statements = vec![];
+        let kernel = fastn_js::Kernel::from_component("ftd#video", parent, index);
+        component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
+        component_statements.extend(self.media.to_set_properties(
+            kernel.name.as_str(),
+            &component_definition_name,
+            &loop_alias,
+        ));
+        component_statements.extend(set_properties(
+            kernel.name.as_str(),
+            vec![
+                (&self.poster, fastn_js::PropertyKind::Poster),
+                (&self.captions, fastn_js::PropertyKind::Captions),
+            ],
+            &component_definition_name,
+            &loop_alias,
+        ));
+        component_statements.extend(self.common.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            component_definition_name,
+            loop_alias,
+        ));
+        (kernel, component_statements)
+    }
+}
+
+impl Audio {
+    pub fn from(component: &ftd::interpreter::Component) -> Audio {
+        let component_definition = component_definition("ftd#audio");
+        let properties = component.properties.as_slice();
+        let arguments = component_definition.arguments.as_slice();
+        Audio {
+            media: Media::from(properties, arguments),
+            common: Box::new(Common::from(
+                properties,
+                arguments,
+                component.events.as_slice(),
+            )),
+        }
+    }
+
+    pub fn to_component_statements(
+        &self,
+        parent: &str,
+        index: usize,
+        doc: &ftd::interpreter::TDoc,
+        component_definition_name: Option<String>,
+        loop_alias: Option<String>,
+    ) -> (fastn_js::Kernel, Vec<fastn_js::ComponentStatement>) {
+        let mut component_statements = vec![];
+        let kernel = fastn_js::Kernel::from_component("ftd#audio", parent, index);
+        component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
+        component_statements.extend(self.media.to_set_properties(
+            kernel.name.as_str(),
+            &component_definition_name,
+            &loop_alias,
+        ));
+        component_statements.extend(self.common.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            component_definition_name,
+            loop_alias,
+        ));
+        (kernel, component_statements)
+    }
+}
+
+impl Svg {
+    pub fn from(component: &ftd::interpreter::Component) -> Svg {
+        let component_definition = component_definition("ftd#svg");
+        let properties = component.properties.as_slice();
+        let arguments = component_definition.arguments.as_slice();
+        Svg {
+            src: ftd::js::value::get_properties("src", properties, arguments).unwrap(),
+            fill: ftd::js::value::get_properties("fill", properties, arguments),
+            stroke: ftd::js::value::get_properties("stroke", properties, arguments),
+            common: Box::new(Common::from(
+                properties,
+                arguments,
+                component.events.as_slice(),
+            )),
+        }
+    }
+
+    pub fn to_component_statements(
+        &self,
+        parent: &str,
+        index: usize,
+        doc: &ftd::interpreter::TDoc,
+        component_definition_name: Option<String>,
+        loop_alias: Option<String>,
+    ) -> (fastn_js::Kernel, Vec<fastn_js::ComponentStatement>) {
+        let mut component_statements = vec![];
+        let kernel = fastn_js::Kernel::from_component("ftd#svg", parent, index);
+        component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
+        component_statements.push(fastn_js::ComponentStatement::SetProperty(
+            self.src.to_set_property(
+                fastn_js::PropertyKind::SvgSrc,
+                kernel.name.as_str(),
+                component_definition_name.clone(),
+                loop_alias.clone(),
+            ),
+        ));
+        component_statements.extend(set_properties(
+            kernel.name.as_str(),
+            vec![
+                (&self.fill, fastn_js::PropertyKind::Fill),
+                (&self.stroke, fastn_js::PropertyKind::Stroke),
+            ],
+            &component_definition_name,
+            &loop_alias,
+        ));
+        component_statements.extend(self.common.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            component_definition_name,
+            loop_alias,
+        ));
+        (kernel, component_statements)
+    }
+}
+
+impl Icon {
+    pub fn from(component: &ftd::interpreter::Component) -> Icon {
+        let component_definition = component_definition("ftd#icon");
+        let properties = component.properties.as_slice();
+        let arguments = component_definition.arguments.as_slice();
+        Icon {
+            name: ftd::js::value::get_properties("name", properties, arguments).unwrap(),
+            sprite: ftd::js::value::get_properties("sprite", properties, arguments).unwrap(),
+            fill: ftd::js::value::get_properties("fill", properties, arguments),
+            stroke: ftd::js::value::get_properties("stroke", properties, arguments),
+            common: Box::new(Common::from(
+                properties,
+                arguments,
+                component.events.as_slice(),
+            )),
+        }
+    }
+
+    pub fn to_component_statements(
+        &self,
+        parent: &str,
+        index: usize,
+        doc: &ftd::interpreter::TDoc,
+        component_definition_name: Option<String>,
+        loop_alias: Option<String>,
+    ) -> (fastn_js::Kernel, Vec<fastn_js::ComponentStatement>) {
+        let mut component_statements = vec![];
+        let kernel = fastn_js::Kernel::from_component("ftd#icon", parent, index);
+        component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
+        for (value, kind) in [
+            (&self.sprite, fastn_js::PropertyKind::IconSprite),
+            (&self.name, fastn_js::PropertyKind::IconName),
+        ] {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                value.to_set_property(
+                    kind,
+                    kernel.name.as_str(),
+                    component_definition_name.clone(),
+                    loop_alias.clone(),
+                ),
+            ));
+        }
+        component_statements.extend(set_properties(
+            kernel.name.as_str(),
+            vec![
+                (&self.fill, fastn_js::PropertyKind::Fill),
+                (&self.stroke, fastn_js::PropertyKind::Stroke),
+            ],
+            &component_definition_name,
+            &loop_alias,
+        ));
+        component_statements.extend(self.common.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            component_definition_name,
+            loop_alias,
+        ));
+        (kernel, component_statements)
+    }
+}
+
+impl Overlay {
+    pub fn from(component: &ftd::interpreter::Component, doc: &ftd::interpreter::TDoc) -> Overlay {
+        let component_definition = component_definition(component.name.as_str());
+        let properties = component.properties.as_slice();
+        let arguments = component_definition.arguments.as_slice();
+        // a dialog has no trigger and a tooltip does not close on an outside click
+        let get_properties = |key: &str| {
+            arguments
+                .iter()
+                .any(|v| v.name.eq(key))
+                .then(|| ftd::js::value::get_properties(key, properties, arguments))
+                .flatten()
+        };
+        Overlay {
+            name: component.name.to_string(),
+            container: Container::from(component, doc, arguments),
+            open: get_properties("open").unwrap(),
+            close_on_escape: get_properties("close-on-escape"),
+            close_on_outside_click: get_properties("close-on-outside-click"),
+            trigger: get_properties("trigger"),
+            placement: get_properties("placement"),
+            common: Box::new(Common::from(
+                properties,
+                arguments,
+                component.events.as_slice(),
+            )),
+        }
+    }
+
+    pub fn to_component_statements(
+        &self,
+        parent: &str,
+        index: usize,
+        doc: &ftd::interpreter::TDoc,
+        component_definition_name: Option<String>,
+        loop_alias: Option<String>,
+        inherited: &[String],
+    ) -> ftd::interpreter::Result<(fastn_js::Kernel, Vec<fastn_js::ComponentStatement>)> {
+        let mut component_statements = vec![];
+        let kernel = fastn_js::Kernel::from_component(self.name.as_str(), parent, index);
+        component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
+        component_statements.extend(set_properties(
+            kernel.name.as_str(),
+            vec![
+                (
+                    &self.close_on_escape,
+                    fastn_js::PropertyKind::OverlayCloseOnEscape,
+                ),
+                (
+                    &self.close_on_outside_click,
+                    fastn_js::PropertyKind::OverlayCloseOnOutsideClick,
+                ),
+                (&self.trigger, fastn_js::PropertyKind::OverlayTrigger),
+                (&self.placement, fastn_js::PropertyKind::OverlayPlacement),
+            ],
+            &component_definition_name,
+            &loop_alias,
+        ));
+        component_statements.extend(self.common.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            component_definition_name.clone(),
+            loop_alias.clone(),
+        ));
+        component_statements.extend(self.container.to_set_properties(
+            kernel.name.as_str(),
+            &component_definition_name,
+            &loop_alias,
+        ));
+        component_statements.extend(self.container.children.to_component_statements(
+            kernel.name.as_str(),
+            doc,
+            component_definition_name.clone(),
+            loop_alias.clone(),
+            inherited,
+        )?);
+        // opened once its content is created, so that focus can move into it
+        component_statements.push(fastn_js::ComponentStatement::SetProperty(
+            self.open.to_set_property(
+                fastn_js::PropertyKind::OverlayOpen,
+                kernel.name.as_str(),
+                component_definition_name,
+                loop_alias,
+            ),
+        ));
+        Ok((kernel, component_statements))
+    }
+}
+
+impl Table {
+    pub fn from(component: &ftd::interpreter::Component, doc: &ftd::interpreter::TDoc) -> Table {
+        let component_definition = component_definition("ftd#table");
+        let properties = component.properties.as_slice();
+        let arguments = component_definition.arguments.as_slice();
+        Table {
+            data: ftd::js::value::get_properties("data", properties, arguments).unwrap(),
+            columns: ftd::js::value::get_properties("columns", properties, arguments).unwrap(),
+            cells: Table::cells(component, arguments, doc),
+            title: ftd::js::value::get_properties("title", properties, arguments),
+            filterable: ftd::js::value::get_properties("filterable", properties, arguments),
+            page_size: ftd::js::value::get_properties("page-size", properties, arguments),
+            page_param: ftd::js::value::get_properties("page-param", properties, arguments),
+            page: ftd::js::value::get_properties("page", properties, arguments),
+            total: ftd::js::value::get_properties("total", properties, arguments),
+            common: Box::new(Common::from(
+                properties,
+                arguments,
+                component.events.as_slice(),
+            )),
         }
     }
 
+    /// The `cell` components of the columns, as functions of the cell, its value and its row,
+    /// `null` for a column without one. The columns have to be known when the page is built, the
+    /// columns given by an argument of the enclosing component show the values as text.
+    fn cells(
+        component: &ftd::interpreter::Component,
+        arguments: &[ftd::interpreter::Argument],
+        doc: &ftd::interpreter::TDoc,
+    ) -> Option<fastn_js::SetPropertyValue> {
+        use itertools::Itertools;
+
+        let argument = arguments.iter().find(|v| v.name.eq("columns"))?;
+        let property = ftd::interpreter::utils::find_properties_by_source(
+            argument.to_sources().as_slice(),
+            component.properties.as_slice(),
+            doc.name,
+            argument,
+            component.line_number,
+        )
+        .ok()?
+        .into_iter()
+        .next()?;
+        let columns = match property.resolve(doc, &Default::default()).ok()?? {
+            ftd::interpreter::Value::List { data, .. } => data,
+            _ => return None,
+        };
+
+        let mut cells = vec![];
+        for column in columns {
+            let line_number = column.line_number();
+            let cell = match column.resolve(doc, line_number).ok()? {
+                ftd::interpreter::Value::Record { fields, .. } => fields.get("cell").cloned(),
+                _ => None,
+            };
+            let cell = match cell {
+                Some(cell) => cell
+                    .resolve(doc, line_number)
+                    .ok()?
+                    .optional_string(doc.name, line_number)
+                    .ok()?,
+                None => None,
+            };
+            let cell = match cell {
+                Some(cell) => {
+                    let definition = doc.get_component(cell.as_str(), line_number).ok()?;
+                    let arguments = definition
+                        .arguments
+                        .iter()
+                        .map(|v| match v.name.as_str() {
+                            "value" | "row" => {
+                                fastn_js::SetPropertyValue::Reference(v.name.clone())
+                            }
+                            _ => v
+                                .get_optional_value(&[])
+                                .or_else(|| v.get_default_value())
+                                .map(|v| v.to_set_property_value_with_none())
+                                .unwrap_or(fastn_js::SetPropertyValue::Value(
+                                    fastn_js::Value::Null,
+                                )),
+                        })
+                        .collect_vec();
+                    fastn_js::Value::CellComponent(fastn_js::InstantiateComponent {
+                        component: fastn_js::InstantiateComponentData::Name(definition.name),
+                        arguments,
+                        parent: "root".to_string(),
+                        inherited: None,
+                        should_return: true,
+                    })
+                }
+                None => fastn_js::Value::Null,
+            };
+            cells.push(fastn_js::SetPropertyValue::Value(cell));
+        }
+        Some(fastn_js::SetPropertyValue::Value(fastn_js::Value::List {
+            value: cells,
+        }))
+    }
+
     pub fn to_component_statements(
         &self,
         parent: &str,
@@ -104,19 +2060,66 @@ impl Text {
         doc: &ftd::interpreter::TDoc,
         component_definition_name: Option<String>,
         loop_alias: Option<String>,
-        should_return: bool,
-    ) -> Vec<fastn_js::ComponentStatement> {
+        inherited: &[String],
+    ) -> (fastn_js::Kernel, Vec<fastn_js::ComponentStatement>) {
         let mut component_statements = vec![];
-        let kernel = fastn_js::Kernel::from_component("ftd#text", parent, index);
+        let kernel = fastn_js::Kernel::from_component("ftd#table", parent, index);
         component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
+        component_statements.extend(set_properties(
+            kernel.name.as_str(),
+            vec![(&self.title, fastn_js::PropertyKind::TableTitle)],
+            &component_definition_name,
+            &loop_alias,
+        ));
         component_statements.push(fastn_js::ComponentStatement::SetProperty(
-            fastn_js::SetProperty {
-                kind: fastn_js::PropertyKind::StringValue,
-                value: self
-                    .text
-                    .to_set_property_value(component_definition_name.clone(), loop_alias.clone()),
-                element_name: kernel.name.to_string(),
-            },
+            self.columns.to_set_property(
+                fastn_js::PropertyKind::TableColumns,
+                kernel.name.as_str(),
+                component_definition_name.clone(),
+                loop_alias.clone(),
+            ),
+        ));
+        if let Some(ref cells) = self.cells {
+            let mut cells = cells.clone();
+            // the cell components read the `$inherited` values of the table
+            if let fastn_js::SetPropertyValue::Value(fastn_js::Value::List { value }) = &mut cells {
+                for cell in value.iter_mut() {
+                    if let fastn_js::SetPropertyValue::Value(fastn_js::Value::CellComponent(
+                        component,
+                    )) = cell
+                    {
+                        component.inherited = (!inherited.is_empty()).then(Default::default);
+                    }
+                }
+            }
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                fastn_js::SetProperty {
+                    kind: fastn_js::PropertyKind::TableCells,
+                    value: cells,
+                    element_name: kernel.name.to_string(),
+                },
+            ));
+        }
+        component_statements.extend(set_properties(
+            kernel.name.as_str(),
+            vec![
+                (&self.filterable, fastn_js::PropertyKind::TableFilterable),
+                (&self.page_size, fastn_js::PropertyKind::TablePageSize),
+                (&self.page_param, fastn_js::PropertyKind::TablePageParam),
+                (&self.page, fastn_js::PropertyKind::TablePage),
+                (&self.total, fastn_js::PropertyKind::TableTotal),
+            ],
+            &component_definition_name,
+            &loop_alias,
+        ));
+        // the table is rendered once its rows are set
+        component_statements.push(fastn_js::ComponentStatement::SetProperty(
+            self.data.to_set_property(
+                fastn_js::PropertyKind::TableData,
+                kernel.name.as_str(),
+                component_definition_name.clone(),
+                loop_alias.clone(),
+            ),
         ));
         component_statements.extend(self.common.to_set_properties(
             kernel.name.as_str(),
@@ -124,35 +2127,27 @@ impl Text {
             component_definition_name,
             loop_alias,
         ));
-        if should_return {
-            component_statements.push(fastn_js::ComponentStatement::Return {
-                component_name: kernel.name,
-            });
-        }
-        component_statements
+        (kernel, component_statements)
     }
 }
 
-impl Integer {
-    pub fn from(component: &ftd::interpreter::Component) -> Integer {
-        let component_definition = ftd::interpreter::default::default_bag()
-            .get("ftd#integer")
-            .unwrap()
-            .clone()
-            .component()
-            .unwrap();
-        Integer {
-            value: ftd::js::value::get_properties(
-                "value",
-                component.properties.as_slice(),
-                component_definition.arguments.as_slice(),
-            )
-            .unwrap(),
-            common: Common::from(
-                component.properties.as_slice(),
-                component_definition.arguments.as_slice(),
+impl Rive {
+    pub fn from(component: &ftd::interpreter::Component) -> Rive {
+        let component_definition = component_definition("ftd#rive");
+        let properties = component.properties.as_slice();
+        let arguments = component_definition.arguments.as_slice();
+        Rive {
+            src: ftd::js::value::get_properties("src", properties, arguments),
+            canvas_width: ftd::js::value::get_properties("canvas-width", properties, arguments),
+            canvas_height: ftd::js::value::get_properties("canvas-height", properties, arguments),
+            state_machine: ftd::js::value::get_properties("state-machine", properties, arguments),
+            autoplay: ftd::js::value::get_properties("autoplay", properties, arguments),
+            artboard: ftd::js::value::get_properties("artboard", properties, arguments),
+            common: Box::new(Common::from(
+                properties,
+                arguments,
                 component.events.as_slice(),
-            ),
+            )),
         }
     }
 
@@ -163,19 +2158,30 @@ impl Integer {
         doc: &ftd::interpreter::TDoc,
         component_definition_name: Option<String>,
         loop_alias: Option<String>,
-        should_return: bool,
-    ) -> Vec<fastn_js::ComponentStatement> {
+    ) -> (fastn_js::Kernel, Vec<fastn_js::ComponentStatement>) {
         let mut component_statements = vec![];
-        let kernel = fastn_js::Kernel::from_component("ftd#integer", parent, index);
+        let kernel = fastn_js::Kernel::from_component("ftd#rive", parent, index);
         component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
-        component_statements.push(fastn_js::ComponentStatement::SetProperty(
-            fastn_js::SetProperty {
-                kind: fastn_js::PropertyKind::StringValue,
-                value: self
-                    .value
-                    .to_set_property_value(component_definition_name.clone(), loop_alias.clone()),
-                element_name: kernel.name.to_string(),
-            },
+        // `src` is set last, the animation is loaded with the rest of the configuration once it
+        // is known
+        component_statements.extend(set_properties(
+            kernel.name.as_str(),
+            vec![
+                (&self.canvas_width, fastn_js::PropertyKind::RiveCanvasWidth),
+                (
+                    &self.canvas_height,
+                    fastn_js::PropertyKind::RiveCanvasHeight,
+                ),
+                (
+                    &self.state_machine,
+                    fastn_js::PropertyKind::RiveStateMachine,
+                ),
+                (&self.autoplay, fastn_js::PropertyKind::RiveAutoplay),
+                (&self.artboard, fastn_js::PropertyKind::RiveArtboard),
+                (&self.src, fastn_js::PropertyKind::RiveSrc),
+            ],
+            &component_definition_name,
+            &loop_alias,
         ));
         component_statements.extend(self.common.to_set_properties(
             kernel.name.as_str(),
@@ -183,30 +2189,83 @@ impl Integer {
             component_definition_name,
             loop_alias,
         ));
-        if should_return {
-            component_statements.push(fastn_js::ComponentStatement::Return {
-                component_name: kernel.name,
-            });
+        (kernel, component_statements)
+    }
+}
+
+impl WebComponent {
+    pub fn from(
+        component: &ftd::interpreter::Component,
+        web_component_definition: ftd::interpreter::WebComponentDefinition,
+    ) -> WebComponent {
+        // The custom element is registered under the name used in its definition, without the
+        // module prefix
+        let name = match web_component_definition.name.split_once('#') {
+            Some((_, name)) => name.to_string(),
+            None => web_component_definition.name.to_string(),
+        };
+        WebComponent {
+            name,
+            arguments: web_component_definition
+                .arguments
+                .iter()
+                .map(|v| {
+                    (
+                        v.name.to_string(),
+                        v.get_value(component.properties.as_slice()),
+                    )
+                })
+                .collect(),
         }
-        component_statements
+    }
+
+    pub fn to_component_statements(
+        &self,
+        parent: &str,
+        index: usize,
+        _doc: &ftd::interpreter::TDoc,
+        component_definition_name: Option<String>,
+        loop_alias: Option<String>,
+    ) -> (fastn_js::Kernel, Vec<fastn_js::ComponentStatement>) {
+        let arguments = self
+            .arguments
+            .iter()
+            .map(|(name, value)| {
+                (
+                    name.to_string(),
+                    value.to_set_property_value(
+                        component_definition_name.clone(),
+                        loop_alias.clone(),
+                    ),
+                )
+            })
+            .collect();
+        let kernel =
+            fastn_js::Kernel::from_web_component(self.name.as_str(), arguments, parent, index);
+        (
+            kernel.clone(),
+            vec![fastn_js::ComponentStatement::CreateKernel(kernel)],
+        )
     }
 }
 
-impl Column {
-    pub fn from(component: &ftd::interpreter::Component, doc: &ftd::interpreter::TDoc) -> Column {
-        let component_definition = ftd::interpreter::default::default_bag()
-            .get("ftd#column")
-            .unwrap()
-            .clone()
-            .component()
-            .unwrap();
-        Column {
-            children: component.get_children(doc).unwrap(),
-            common: Common::from(
-                component.properties.as_slice(),
-                component_definition.arguments.as_slice(),
-                component.events.as_slice(),
-            ),
+impl Document {
+    pub fn from(component: &ftd::interpreter::Component, doc: &ftd::interpreter::TDoc) -> Document {
+        let component_definition = component_definition("ftd#document");
+        let properties = component.properties.as_slice();
+        let arguments = component_definition.arguments.as_slice();
+        Document {
+            children: Children::from(component, doc, arguments),
+            title: document_property("title", properties, arguments),
+            og_title: document_property("og-title", properties, arguments),
+            twitter_title: document_property("twitter-title", properties, arguments),
+            description: document_property("description", properties, arguments),
+            og_description: document_property("og-description", properties, arguments),
+            twitter_description: document_property("twitter-description", properties, arguments),
+            og_image: document_property("og-image", properties, arguments),
+            twitter_image: document_property("twitter-image", properties, arguments),
+            theme_color: document_property("theme-color", properties, arguments),
+            breakpoints: document_property("breakpoints", properties, arguments),
         }
     }
 
@@ -217,36 +2276,203 @@ impl Column {
         doc: &ftd::interpreter::TDoc,
         component_definition_name: Option<String>,
         loop_alias: Option<String>,
-        should_return: bool,
-    ) -> Vec<fastn_js::ComponentStatement> {
+        inherited: &[String],
+    ) -> ftd::interpreter::Result<(fastn_js::Kernel, Vec<fastn_js::ComponentStatement>)> {
         let mut component_statements = vec![];
-        let kernel = fastn_js::Kernel::from_component("ftd#column", parent, index);
+        let kernel = fastn_js::Kernel::from_component("ftd#document", parent, index);
         component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
-        component_statements.extend(self.common.to_set_properties(
+        component_statements.extend(set_properties(
+            kernel.name.as_str(),
+            vec![
+                (&self.title, fastn_js::PropertyKind::MetaTitle),
+                (&self.og_title, fastn_js::PropertyKind::MetaOgTitle),
+                (
+                    &self.twitter_title,
+                    fastn_js::PropertyKind::MetaTwitterTitle,
+                ),
+                (&self.description, fastn_js::PropertyKind::MetaDescription),
+                (
+                    &self.og_description,
+                    fastn_js::PropertyKind::MetaOgDescription,
+                ),
+                (
+                    &self.twitter_description,
+                    fastn_js::PropertyKind::MetaTwitterDescription,
+                ),
+                (&self.og_image, fastn_js::PropertyKind::MetaOgImage),
+                (
+                    &self.twitter_image,
+                    fastn_js::PropertyKind::MetaTwitterImage,
+                ),
+                (&self.theme_color, fastn_js::PropertyKind::MetaThemeColor),
+                (&self.breakpoints, fastn_js::PropertyKind::Breakpoints),
+            ],
+            &component_definition_name,
+            &loop_alias,
+        ));
+        component_statements.extend(self.children.to_component_statements(
             kernel.name.as_str(),
             doc,
-            component_definition_name.clone(),
+            component_definition_name,
             loop_alias,
-        ));
+            inherited,
+        )?);
+        Ok((kernel, component_statements))
+    }
+}
 
-        component_statements.extend(self.children.iter().enumerate().flat_map(|(index, v)| {
-            v.to_component_statements(
-                kernel.name.as_str(),
-                index,
-                doc,
-                component_definition_name.clone(),
-                false,
+/// An argument of `ftd.document`, the default of `og-title` and the like is another argument.
+fn document_property(
+    key: &str,
+    properties: &[ftd::interpreter::Property],
+    arguments: &[ftd::interpreter::Argument],
+) -> Option<ftd::js::Value> {
+    // a default that is another argument the document does not give has no value
+    ftd::js::value::get_properties_with_arguments(key, "ftd#document", properties, arguments)
+        .filter(|value| {
+            !matches!(
+                value,
+                ftd::js::Value::Data(ftd::interpreter::Value::Optional { data, .. })
+                    if data.is_none()
             )
-        }));
-        if should_return {
-            component_statements.push(fastn_js::ComponentStatement::Return {
-                component_name: kernel.name,
-            });
+        })
+}
+
+/// Arguments shared by `ftd.text` and the other text-like kernels.
+#[derive(Debug)]
+pub struct TextCommon {
+    pub display: Option<ftd::js::Value>,
+    pub text_align: Option<ftd::js::Value>,
+    pub line_clamp: Option<ftd::js::Value>,
+    pub text_indent: Option<ftd::js::Value>,
+    pub style: Option<ftd::js::Value>,
+}
+
+impl TextCommon {
+    pub fn from(
+        properties: &[ftd::interpreter::Property],
+        arguments: &[ftd::interpreter::Argument],
+    ) -> TextCommon {
+        TextCommon {
+            display: ftd::js::value::get_properties("display", properties, arguments),
+            text_align: ftd::js::value::get_properties("text-align", properties, arguments),
+            line_clamp: ftd::js::value::get_properties("line-clamp", properties, arguments),
+            text_indent: ftd::js::value::get_properties("text-indent", properties, arguments),
+            style: ftd::js::value::get_properties("style", properties, arguments),
         }
-        component_statements
+    }
+
+    pub fn to_set_properties(
+        &self,
+        element_name: &str,
+        component_definition_name: &Option<String>,
+        loop_alias: &Option<String>,
+    ) -> Vec<fastn_js::ComponentStatement> {
+        set_properties(
+            element_name,
+            vec![
+                (&self.display, fastn_js::PropertyKind::Display),
+                (&self.text_align, fastn_js::PropertyKind::TextAlign),
+                (&self.line_clamp, fastn_js::PropertyKind::LineClamp),
+                (&self.text_indent, fastn_js::PropertyKind::TextIndent),
+                (&self.style, fastn_js::PropertyKind::TextStyle),
+            ],
+            component_definition_name,
+            loop_alias,
+        )
+    }
+}
+
+/// Arguments shared by `ftd.row` and `ftd.column`.
+#[derive(Debug)]
+pub struct Container {
+    pub children: Children,
+    pub spacing: Option<ftd::js::Value>,
+    pub wrap: Option<ftd::js::Value>,
+    pub align_content: Option<ftd::js::Value>,
+}
+
+impl Container {
+    pub fn from(
+        component: &ftd::interpreter::Component,
+        doc: &ftd::interpreter::TDoc,
+        arguments: &[ftd::interpreter::Argument],
+    ) -> Container {
+        let properties = component.properties.as_slice();
+        Container {
+            children: Children::from(component, doc, arguments),
+            spacing: ftd::js::value::get_properties("spacing", properties, arguments),
+            wrap: ftd::js::value::get_properties("wrap", properties, arguments),
+            align_content: ftd::js::value::get_properties("align-content", properties, arguments),
+        }
+    }
+
+    pub fn to_set_properties(
+        &self,
+        element_name: &str,
+        component_definition_name: &Option<String>,
+        loop_alias: &Option<String>,
+    ) -> Vec<fastn_js::ComponentStatement> {
+        set_properties(
+            element_name,
+            vec![
+                (&self.spacing, fastn_js::PropertyKind::Spacing),
+                (&self.wrap, fastn_js::PropertyKind::Wrap),
+                (&self.align_content, fastn_js::PropertyKind::AlignContent),
+            ],
+            component_definition_name,
+            loop_alias,
+        )
+    }
+}
+
+/// Arguments shared by `ftd.video` and `ftd.audio`.
+#[derive(Debug)]
+pub struct Media {
+    pub sources: Option<ftd::js::Value>,
+    pub controls: Option<ftd::js::Value>,
+    pub autoplay: Option<ftd::js::Value>,
+    pub muted: Option<ftd::js::Value>,
+    pub loop_: Option<ftd::js::Value>,
+}
+
+impl Media {
+    pub fn from(
+        properties: &[ftd::interpreter::Property],
+        arguments: &[ftd::interpreter::Argument],
+    ) -> Media {
+        Media {
+            sources: ftd::js::value::get_properties("sources", properties, arguments),
+            controls: ftd::js::value::get_properties("controls", properties, arguments),
+            autoplay: ftd::js::value::get_properties("autoplay", properties, arguments),
+            muted: ftd::js::value::get_properties("muted", properties, arguments),
+            loop_: ftd::js::value::get_properties("loop", properties, arguments),
+        }
+    }
+
+    pub fn to_set_properties(
+        &self,
+        element_name: &str,
+        component_definition_name: &Option<String>,
+        loop_alias: &Option<String>,
+    ) -> Vec<fastn_js::ComponentStatement> {
+        set_properties(
+            element_name,
+            vec![
+                (&self.sources, fastn_js::PropertyKind::MediaSources),
+                (&self.controls, fastn_js::PropertyKind::Controls),
+                (&self.autoplay, fastn_js::PropertyKind::Autoplay),
+                (&self.muted, fastn_js::PropertyKind::Muted),
+                (&self.loop_, fastn_js::PropertyKind::Loop),
+            ],
+            component_definition_name,
+            loop_alias,
+        )
     }
 }
 
+/// Boxed in every element: it has a field for each common property, and an element is on the
+/// stack in every frame of the recursion over its children.
 #[derive(Debug)]
 pub struct Common {
     pub id: Option<ftd::js::Value>,
@@ -256,6 +2482,93 @@ pub struct Common {
     pub margin: Option<ftd::js::Value>,
     pub border_width: Option<ftd::js::Value>,
     pub border_style: Option<ftd::js::Value>,
+    pub z_index: Option<ftd::js::Value>,
+    pub grid_area: Option<ftd::js::Value>,
+    pub column_start: Option<ftd::js::Value>,
+    pub column_span: Option<ftd::js::Value>,
+    pub row_start: Option<ftd::js::Value>,
+    pub row_span: Option<ftd::js::Value>,
+    pub left: Option<ftd::js::Value>,
+    pub right: Option<ftd::js::Value>,
+    pub top: Option<ftd::js::Value>,
+    pub bottom: Option<ftd::js::Value>,
+    pub anchor: Option<ftd::js::Value>,
+    pub role: Option<ftd::js::Value>,
+    pub region: Option<ftd::js::Value>,
+    pub cursor: Option<ftd::js::Value>,
+    pub classes: Option<ftd::js::Value>,
+    pub padding_horizontal: Option<ftd::js::Value>,
+    pub padding_vertical: Option<ftd::js::Value>,
+    pub padding_left: Option<ftd::js::Value>,
+    pub padding_right: Option<ftd::js::Value>,
+    pub padding_top: Option<ftd::js::Value>,
+    pub padding_bottom: Option<ftd::js::Value>,
+    pub margin_horizontal: Option<ftd::js::Value>,
+    pub margin_vertical: Option<ftd::js::Value>,
+    pub margin_left: Option<ftd::js::Value>,
+    pub margin_right: Option<ftd::js::Value>,
+    pub margin_top: Option<ftd::js::Value>,
+    pub margin_bottom: Option<ftd::js::Value>,
+    pub border_radius: Option<ftd::js::Value>,
+    pub border_color: Option<ftd::js::Value>,
+    pub border_top_width: Option<ftd::js::Value>,
+    pub border_top_color: Option<ftd::js::Value>,
+    pub border_bottom_width: Option<ftd::js::Value>,
+    pub border_bottom_color: Option<ftd::js::Value>,
+    pub border_left_width: Option<ftd::js::Value>,
+    pub border_left_color: Option<ftd::js::Value>,
+    pub border_right_width: Option<ftd::js::Value>,
+    pub border_right_color: Option<ftd::js::Value>,
+    pub border_top_left_radius: Option<ftd::js::Value>,
+    pub border_top_right_radius: Option<ftd::js::Value>,
+    pub border_bottom_left_radius: Option<ftd::js::Value>,
+    pub border_bottom_right_radius: Option<ftd::js::Value>,
+    pub border_style_vertical: Option<ftd::js::Value>,
+    pub border_style_horizontal: Option<ftd::js::Value>,
+    pub border_style_left: Option<ftd::js::Value>,
+    pub border_style_right: Option<ftd::js::Value>,
+    pub border_style_top: Option<ftd::js::Value>,
+    pub border_style_bottom: Option<ftd::js::Value>,
+    pub min_width: Option<ftd::js::Value>,
+    pub max_width: Option<ftd::js::Value>,
+    pub min_height: Option<ftd::js::Value>,
+    pub max_height: Option<ftd::js::Value>,
+    pub link: Option<ftd::js::Value>,
+    pub open_in_new_tab: Option<ftd::js::Value>,
+    pub background: Option<ftd::js::Value>,
+    pub color: Option<ftd::js::Value>,
+    pub align_self: Option<ftd::js::Value>,
+    pub overflow: Option<ftd::js::Value>,
+    pub overflow_x: Option<ftd::js::Value>,
+    pub overflow_y: Option<ftd::js::Value>,
+    pub opacity: Option<ftd::js::Value>,
+    pub resize: Option<ftd::js::Value>,
+    pub white_space: Option<ftd::js::Value>,
+    pub text_transform: Option<ftd::js::Value>,
+    pub sticky: Option<ftd::js::Value>,
+    pub shadow: Option<ftd::js::Value>,
+    pub color_hover: Option<ftd::js::Value>,
+    pub background_hover: Option<ftd::js::Value>,
+    pub border_color_hover: Option<ftd::js::Value>,
+    pub shadow_hover: Option<ftd::js::Value>,
+    pub color_focus: Option<ftd::js::Value>,
+    pub background_focus: Option<ftd::js::Value>,
+    pub border_color_focus: Option<ftd::js::Value>,
+    pub shadow_focus: Option<ftd::js::Value>,
+    pub color_active: Option<ftd::js::Value>,
+    pub background_active: Option<ftd::js::Value>,
+    pub border_color_active: Option<ftd::js::Value>,
+    pub shadow_active: Option<ftd::js::Value>,
+    pub transform: Option<ftd::js::Value>,
+    pub transition: Option<ftd::js::Value>,
+    pub animation: Option<ftd::js::Value>,
+    pub alt: Option<ftd::js::Value>,
+    pub aria_label: Option<ftd::js::Value>,
+    pub aria_described_by: Option<ftd::js::Value>,
+    pub aria_hidden: Option<ftd::js::Value>,
+    pub aria_live: Option<ftd::js::Value>,
+    pub landmark: Option<ftd::js::Value>,
+    pub tab_index: Option<ftd::js::Value>,
     pub events: Vec<ftd::interpreter::Event>,
 }
 
@@ -273,6 +2586,213 @@ impl Common {
             margin: ftd::js::value::get_properties("margin", properties, arguments),
             border_width: ftd::js::value::get_properties("border-width", properties, arguments),
             border_style: ftd::js::value::get_properties("border-style", properties, arguments),
+            z_index: ftd::js::value::get_properties("z-index", properties, arguments),
+            grid_area: ftd::js::value::get_properties("grid-area", properties, arguments),
+            column_start: ftd::js::value::get_properties("column-start", properties, arguments),
+            column_span: ftd::js::value::get_properties("column-span", properties, arguments),
+            row_start: ftd::js::value::get_properties("row-start", properties, arguments),
+            row_span: ftd::js::value::get_properties("row-span", properties, arguments),
+            left: ftd::js::value::get_properties("left", properties, arguments),
+            right: ftd::js::value::get_properties("right", properties, arguments),
+            top: ftd::js::value::get_properties("top", properties, arguments),
+            bottom: ftd::js::value::get_properties("bottom", properties, arguments),
+            anchor: ftd::js::value::get_properties("anchor", properties, arguments),
+            role: ftd::js::value::get_properties("role", properties, arguments),
+            region: ftd::js::value::get_properties("region", properties, arguments),
+            cursor: ftd::js::value::get_properties("cursor", properties, arguments),
+            classes: ftd::js::value::get_properties("classes", properties, arguments),
+            padding_horizontal: ftd::js::value::get_properties(
+                "padding-horizontal",
+                properties,
+                arguments,
+            ),
+            padding_vertical: ftd::js::value::get_properties(
+                "padding-vertical",
+                properties,
+                arguments,
+            ),
+            padding_left: ftd::js::value::get_properties("padding-left", properties, arguments),
+            padding_right: ftd::js::value::get_properties("padding-right", properties, arguments),
+            padding_top: ftd::js::value::get_properties("padding-top", properties, arguments),
+            padding_bottom: ftd::js::value::get_properties("padding-bottom", properties, arguments),
+            margin_horizontal: ftd::js::value::get_properties(
+                "margin-horizontal",
+                properties,
+                arguments,
+            ),
+            margin_vertical: ftd::js::value::get_properties(
+                "margin-vertical",
+                properties,
+                arguments,
+            ),
+            margin_left: ftd::js::value::get_properties("margin-left", properties, arguments),
+            margin_right: ftd::js::value::get_properties("margin-right", properties, arguments),
+            margin_top: ftd::js::value::get_properties("margin-top", properties, arguments),
+            margin_bottom: ftd::js::value::get_properties("margin-bottom", properties, arguments),
+            border_radius: ftd::js::value::get_properties("border-radius", properties, arguments),
+            border_color: ftd::js::value::get_properties("border-color", properties, arguments),
+            border_top_width: ftd::js::value::get_properties(
+                "border-top-width",
+                properties,
+                arguments,
+            ),
+            border_top_color: ftd::js::value::get_properties(
+                "border-top-color",
+                properties,
+                arguments,
+            ),
+            border_bottom_width: ftd::js::value::get_properties(
+                "border-bottom-width",
+                properties,
+                arguments,
+            ),
+            border_bottom_color: ftd::js::value::get_properties(
+                "border-bottom-color",
+                properties,
+                arguments,
+            ),
+            border_left_width: ftd::js::value::get_properties(
+                "border-left-width",
+                properties,
+                arguments,
+            ),
+            border_left_color: ftd::js::value::get_properties(
+                "border-left-color",
+                properties,
+                arguments,
+            ),
+            border_right_width: ftd::js::value::get_properties(
+                "border-right-width",
+                properties,
+                arguments,
+            ),
+            border_right_color: ftd::js::value::get_properties(
+                "border-right-color",
+                properties,
+                arguments,
+            ),
+            border_top_left_radius: ftd::js::value::get_properties(
+                "border-top-left-radius",
+                properties,
+                arguments,
+            ),
+            border_top_right_radius: ftd::js::value::get_properties(
+                "border-top-right-radius",
+                properties,
+                arguments,
+            ),
+            border_bottom_left_radius: ftd::js::value::get_properties(
+                "border-bottom-left-radius",
+                properties,
+                arguments,
+            ),
+            border_bottom_right_radius: ftd::js::value::get_properties(
+                "border-bottom-right-radius",
+                properties,
+                arguments,
+            ),
+            border_style_vertical: ftd::js::value::get_properties(
+                "border-style-vertical",
+                properties,
+                arguments,
+            ),
+            border_style_horizontal: ftd::js::value::get_properties(
+                "border-style-horizontal",
+                properties,
+                arguments,
+            ),
+            border_style_left: ftd::js::value::get_properties(
+                "border-style-left",
+                properties,
+                arguments,
+            ),
+            border_style_right: ftd::js::value::get_properties(
+                "border-style-right",
+                properties,
+                arguments,
+            ),
+            border_style_top: ftd::js::value::get_properties(
+                "border-style-top",
+                properties,
+                arguments,
+            ),
+            border_style_bottom: ftd::js::value::get_properties(
+                "border-style-bottom",
+                properties,
+                arguments,
+            ),
+            min_width: ftd::js::value::get_properties("min-width", properties, arguments),
+            max_width: ftd::js::value::get_properties("max-width", properties, arguments),
+            min_height: ftd::js::value::get_properties("min-height", properties, arguments),
+            max_height: ftd::js::value::get_properties("max-height", properties, arguments),
+            link: ftd::js::value::get_properties("link", properties, arguments),
+            open_in_new_tab: ftd::js::value::get_properties(
+                "open-in-new-tab",
+                properties,
+                arguments,
+            ),
+            background: ftd::js::value::get_properties("background", properties, arguments),
+            color: ftd::js::value::get_properties("color", properties, arguments),
+            align_self: ftd::js::value::get_properties("align-self", properties, arguments),
+            overflow: ftd::js::value::get_properties("overflow", properties, arguments),
+            overflow_x: ftd::js::value::get_properties("overflow-x", properties, arguments),
+            overflow_y: ftd::js::value::get_properties("overflow-y", properties, arguments),
+            opacity: ftd::js::value::get_properties("opacity", properties, arguments),
+            resize: ftd::js::value::get_properties("resize", properties, arguments),
+            white_space: ftd::js::value::get_properties("white-space", properties, arguments),
+            text_transform: ftd::js::value::get_properties("text-transform", properties, arguments),
+            sticky: ftd::js::value::get_properties("sticky", properties, arguments),
+            shadow: ftd::js::value::get_properties("shadow", properties, arguments),
+            color_hover: ftd::js::value::get_properties("color.hover", properties, arguments),
+            background_hover: ftd::js::value::get_properties(
+                "background.hover",
+                properties,
+                arguments,
+            ),
+            border_color_hover: ftd::js::value::get_properties(
+                "border-color.hover",
+                properties,
+                arguments,
+            ),
+            shadow_hover: ftd::js::value::get_properties("shadow.hover", properties, arguments),
+            color_focus: ftd::js::value::get_properties("color.focus", properties, arguments),
+            background_focus: ftd::js::value::get_properties(
+                "background.focus",
+                properties,
+                arguments,
+            ),
+            border_color_focus: ftd::js::value::get_properties(
+                "border-color.focus",
+                properties,
+                arguments,
+            ),
+            shadow_focus: ftd::js::value::get_properties("shadow.focus", properties, arguments),
+            color_active: ftd::js::value::get_properties("color.active", properties, arguments),
+            background_active: ftd::js::value::get_properties(
+                "background.active",
+                properties,
+                arguments,
+            ),
+            border_color_active: ftd::js::value::get_properties(
+                "border-color.active",
+                properties,
+                arguments,
+            ),
+            shadow_active: ftd::js::value::get_properties("shadow.active", properties, arguments),
+            transform: ftd::js::value::get_properties("transform", properties, arguments),
+            transition: ftd::js::value::get_properties("transition", properties, arguments),
+            animation: ftd::js::value::get_properties("animation", properties, arguments),
+            alt: ftd::js::value::get_properties("alt", properties, arguments),
+            aria_label: ftd::js::value::get_properties("aria-label", properties, arguments),
+            aria_described_by: ftd::js::value::get_properties(
+                "aria-describedby",
+                properties,
+                arguments,
+            ),
+            aria_hidden: ftd::js::value::get_properties("aria-hidden", properties, arguments),
+            aria_live: ftd::js::value::get_properties("aria-live", properties, arguments),
+            landmark: ftd::js::value::get_properties("landmark", properties, arguments),
+            tab_index: ftd::js::value::get_properties("tab-index", properties, arguments),
             events: events.to_vec(),
         }
     }
@@ -295,76 +2815,194 @@ impl Common {
                 ),
             ));
         }
-        if let Some(ref id) = self.id {
-            component_statements.push(fastn_js::ComponentStatement::SetProperty(
-                id.to_set_property(
-                    fastn_js::PropertyKind::Id,
-                    element_name,
-                    component_definition_name.clone(),
-                    loop_alias.clone(),
+        component_statements.extend(set_properties(
+            element_name,
+            vec![
+                (&self.id, fastn_js::PropertyKind::Id),
+                (&self.width, fastn_js::PropertyKind::Width),
+                (&self.height, fastn_js::PropertyKind::Height),
+                (&self.padding, fastn_js::PropertyKind::Padding),
+                (&self.margin, fastn_js::PropertyKind::Margin),
+                (&self.border_width, fastn_js::PropertyKind::BorderWidth),
+                (&self.border_style, fastn_js::PropertyKind::BorderStyle),
+                (&self.z_index, fastn_js::PropertyKind::ZIndex),
+                (&self.grid_area, fastn_js::PropertyKind::GridArea),
+                (&self.column_start, fastn_js::PropertyKind::GridColumnStart),
+                (&self.column_span, fastn_js::PropertyKind::GridColumnSpan),
+                (&self.row_start, fastn_js::PropertyKind::GridRowStart),
+                (&self.row_span, fastn_js::PropertyKind::GridRowSpan),
+                (&self.left, fastn_js::PropertyKind::Left),
+                (&self.right, fastn_js::PropertyKind::Right),
+                (&self.top, fastn_js::PropertyKind::Top),
+                (&self.bottom, fastn_js::PropertyKind::Bottom),
+                (&self.anchor, fastn_js::PropertyKind::Anchor),
+                (&self.role, fastn_js::PropertyKind::Role),
+                (&self.region, fastn_js::PropertyKind::Region),
+                (&self.cursor, fastn_js::PropertyKind::Cursor),
+                (&self.classes, fastn_js::PropertyKind::Classes),
+                (
+                    &self.padding_horizontal,
+                    fastn_js::PropertyKind::PaddingHorizontal,
                 ),
-            ));
-        }
-        if let Some(ref width) = self.width {
-            component_statements.push(fastn_js::ComponentStatement::SetProperty(
-                width.to_set_property(
-                    fastn_js::PropertyKind::Width,
-                    element_name,
-                    component_definition_name.clone(),
-                    loop_alias.clone(),
+                (
+                    &self.padding_vertical,
+                    fastn_js::PropertyKind::PaddingVertical,
                 ),
-            ));
-        }
-        if let Some(ref height) = self.height {
-            component_statements.push(fastn_js::ComponentStatement::SetProperty(
-                height.to_set_property(
-                    fastn_js::PropertyKind::Height,
-                    element_name,
-                    component_definition_name.clone(),
-                    loop_alias.clone(),
+                (&self.padding_left, fastn_js::PropertyKind::PaddingLeft),
+                (&self.padding_right, fastn_js::PropertyKind::PaddingRight),
+                (&self.padding_top, fastn_js::PropertyKind::PaddingTop),
+                (&self.padding_bottom, fastn_js::PropertyKind::PaddingBottom),
+                (
+                    &self.margin_horizontal,
+                    fastn_js::PropertyKind::MarginHorizontal,
                 ),
-            ));
-        }
-        if let Some(ref padding) = self.padding {
-            component_statements.push(fastn_js::ComponentStatement::SetProperty(
-                padding.to_set_property(
-                    fastn_js::PropertyKind::Padding,
-                    element_name,
-                    component_definition_name.clone(),
-                    loop_alias.clone(),
+                (
+                    &self.margin_vertical,
+                    fastn_js::PropertyKind::MarginVertical,
                 ),
-            ));
-        }
-        if let Some(ref margin) = self.margin {
-            component_statements.push(fastn_js::ComponentStatement::SetProperty(
-                margin.to_set_property(
-                    fastn_js::PropertyKind::Margin,
-                    element_name,
-                    component_definition_name.clone(),
-                    loop_alias.clone(),
+                (&self.margin_left, fastn_js::PropertyKind::MarginLeft),
+                (&self.margin_right, fastn_js::PropertyKind::MarginRight),
+                (&self.margin_top, fastn_js::PropertyKind::MarginTop),
+                (&self.margin_bottom, fastn_js::PropertyKind::MarginBottom),
+                (&self.border_radius, fastn_js::PropertyKind::BorderRadius),
+                (&self.border_color, fastn_js::PropertyKind::BorderColor),
+                (
+                    &self.border_top_width,
+                    fastn_js::PropertyKind::BorderTopWidth,
                 ),
-            ));
-        }
-        if let Some(ref border_width) = self.border_width {
-            component_statements.push(fastn_js::ComponentStatement::SetProperty(
-                border_width.to_set_property(
-                    fastn_js::PropertyKind::BorderWidth,
-                    element_name,
-                    component_definition_name.clone(),
-                    loop_alias.clone(),
+                (
+                    &self.border_top_color,
+                    fastn_js::PropertyKind::BorderTopColor,
                 ),
-            ));
-        }
-        if let Some(ref border_style) = self.border_style {
-            component_statements.push(fastn_js::ComponentStatement::SetProperty(
-                border_style.to_set_property(
-                    fastn_js::PropertyKind::BorderStyle,
-                    element_name,
-                    component_definition_name,
-                    loop_alias,
+                (
+                    &self.border_bottom_width,
+                    fastn_js::PropertyKind::BorderBottomWidth,
                 ),
-            ));
-        }
+                (
+                    &self.border_bottom_color,
+                    fastn_js::PropertyKind::BorderBottomColor,
+                ),
+                (
+                    &self.border_left_width,
+                    fastn_js::PropertyKind::BorderLeftWidth,
+                ),
+                (
+                    &self.border_left_color,
+                    fastn_js::PropertyKind::BorderLeftColor,
+                ),
+                (
+                    &self.border_right_width,
+                    fastn_js::PropertyKind::BorderRightWidth,
+                ),
+                (
+                    &self.border_right_color,
+                    fastn_js::PropertyKind::BorderRightColor,
+                ),
+                (
+                    &self.border_top_left_radius,
+                    fastn_js::PropertyKind::BorderTopLeftRadius,
+                ),
+                (
+                    &self.border_top_right_radius,
+                    fastn_js::PropertyKind::BorderTopRightRadius,
+                ),
+                (
+                    &self.border_bottom_left_radius,
+                    fastn_js::PropertyKind::BorderBottomLeftRadius,
+                ),
+                (
+                    &self.border_bottom_right_radius,
+                    fastn_js::PropertyKind::BorderBottomRightRadius,
+                ),
+                (
+                    &self.border_style_vertical,
+                    fastn_js::PropertyKind::BorderStyleVertical,
+                ),
+                (
+                    &self.border_style_horizontal,
+                    fastn_js::PropertyKind::BorderStyleHorizontal,
+                ),
+                (
+                    &self.border_style_left,
+                    fastn_js::PropertyKind::BorderStyleLeft,
+                ),
+                (
+                    &self.border_style_right,
+                    fastn_js::PropertyKind::BorderStyleRight,
+                ),
+                (
+                    &self.border_style_top,
+                    fastn_js::PropertyKind::BorderStyleTop,
+                ),
+                (
+                    &self.border_style_bottom,
+                    fastn_js::PropertyKind::BorderStyleBottom,
+                ),
+                (&self.min_width, fastn_js::PropertyKind::MinWidth),
+                (&self.max_width, fastn_js::PropertyKind::MaxWidth),
+                (&self.min_height, fastn_js::PropertyKind::MinHeight),
+                (&self.max_height, fastn_js::PropertyKind::MaxHeight),
+                (&self.link, fastn_js::PropertyKind::Link),
+                (&self.open_in_new_tab, fastn_js::PropertyKind::OpenInNewTab),
+                (&self.background, fastn_js::PropertyKind::Background),
+                (&self.color, fastn_js::PropertyKind::Color),
+                (&self.align_self, fastn_js::PropertyKind::AlignSelf),
+                (&self.overflow, fastn_js::PropertyKind::Overflow),
+                (&self.overflow_x, fastn_js::PropertyKind::OverflowX),
+                (&self.overflow_y, fastn_js::PropertyKind::OverflowY),
+                (&self.opacity, fastn_js::PropertyKind::Opacity),
+                (&self.resize, fastn_js::PropertyKind::Resize),
+                (&self.white_space, fastn_js::PropertyKind::WhiteSpace),
+                (&self.text_transform, fastn_js::PropertyKind::TextTransform),
+                (&self.sticky, fastn_js::PropertyKind::Sticky),
+                (&self.shadow, fastn_js::PropertyKind::Shadow),
+                (&self.color_hover, fastn_js::PropertyKind::ColorHover),
+                (
+                    &self.background_hover,
+                    fastn_js::PropertyKind::BackgroundHover,
+                ),
+                (
+                    &self.border_color_hover,
+                    fastn_js::PropertyKind::BorderColorHover,
+                ),
+                (&self.shadow_hover, fastn_js::PropertyKind::ShadowHover),
+                (&self.color_focus, fastn_js::PropertyKind::ColorFocus),
+                (
+                    &self.background_focus,
+                    fastn_js::PropertyKind::BackgroundFocus,
+                ),
+                (
+                    &self.border_color_focus,
+                    fastn_js::PropertyKind::BorderColorFocus,
+                ),
+                (&self.shadow_focus, fastn_js::PropertyKind::ShadowFocus),
+                (&self.color_active, fastn_js::PropertyKind::ColorActive),
+                (
+                    &self.background_active,
+                    fastn_js::PropertyKind::BackgroundActive,
+                ),
+                (
+                    &self.border_color_active,
+                    fastn_js::PropertyKind::BorderColorActive,
+                ),
+                (&self.shadow_active, fastn_js::PropertyKind::ShadowActive),
+                (&self.transform, fastn_js::PropertyKind::Transform),
+                (&self.transition, fastn_js::PropertyKind::Transition),
+                (&self.animation, fastn_js::PropertyKind::Animation),
+                (&self.alt, fastn_js::PropertyKind::Alt),
+                (&self.aria_label, fastn_js::PropertyKind::AriaLabel),
+                (
+                    &self.aria_described_by,
+                    fastn_js::PropertyKind::AriaDescribedBy,
+                ),
+                (&self.aria_hidden, fastn_js::PropertyKind::AriaHidden),
+                (&self.aria_live, fastn_js::PropertyKind::AriaLive),
+                (&self.landmark, fastn_js::PropertyKind::Landmark),
+                (&self.tab_index, fastn_js::PropertyKind::TabIndex),
+            ],
+            &component_definition_name,
+            &loop_alias,
+        ));
         component_statements
     }
 }
@@ -421,11 +3059,65 @@ impl ftd::interpreter::EventName {
     fn to_js_event_name(&self) -> fastn_js::Event {
         match self {
             ftd::interpreter::EventName::Click => fastn_js::Event::OnClick,
-            _ => todo!(),
+            ftd::interpreter::EventName::MouseEnter => fastn_js::Event::OnMouseEnter,
+            ftd::interpreter::EventName::MouseLeave => fastn_js::Event::OnMouseLeave,
+            ftd::interpreter::EventName::ClickOutside => fastn_js::Event::OnClickOutside,
+            ftd::interpreter::EventName::GlobalKey(keys) => {
+                fastn_js::Event::OnGlobalKey(keys.to_owned())
+            }
+            ftd::interpreter::EventName::GlobalKeySeq(keys) => {
+                fastn_js::Event::OnGlobalKeySeq(keys.to_owned())
+            }
+            ftd::interpreter::EventName::Input => fastn_js::Event::OnInput,
+            ftd::interpreter::EventName::Change => fastn_js::Event::OnChange,
+            ftd::interpreter::EventName::Blur => fastn_js::Event::OnBlur,
+            ftd::interpreter::EventName::Focus => fastn_js::Event::OnFocus,
+            ftd::interpreter::EventName::Submit => fastn_js::Event::OnSubmit,
+            ftd::interpreter::EventName::Play => fastn_js::Event::OnPlay,
+            ftd::interpreter::EventName::Pause => fastn_js::Event::OnPause,
+            ftd::interpreter::EventName::Ended => fastn_js::Event::OnEnded,
+            ftd::interpreter::EventName::TimeUpdate => fastn_js::Event::OnTimeUpdate,
+            ftd::interpreter::EventName::RivePlay(name) => {
+                fastn_js::Event::OnRivePlay(name.to_owned())
+            }
+            ftd::interpreter::EventName::RiveStateChange(name) => {
+                fastn_js::Event::OnRiveStateChange(name.to_owned())
+            }
+            ftd::interpreter::EventName::RivePause(name) => {
+                fastn_js::Event::OnRivePause(name.to_owned())
+            }
         }
     }
 }
 
 pub fn is_kernel(s: &str) -> bool {
-    ["ftd#text", "ftd#row", "ftd#column", "ftd#integer"].contains(&s)
+    [
+        "ftd#text",
+        "ftd#row",
+        "ftd#column",
+        "ftd#integer",
+        "ftd#decimal",
+        "ftd#boolean",
+        "ftd#container",
+        "ftd#grid",
+        "ftd#image",
+        "ftd#code",
+        "ftd#iframe",
+        "ftd#text-input",
+        "ftd#checkbox",
+        "ftd#select",
+        "ftd#radio-group",
+        "ftd#form",
+        "ftd#video",
+        "ftd#audio",
+        "ftd#svg",
+        "ftd#icon",
+        "ftd#table",
+        "ftd#dialog",
+        "ftd#popover",
+        "ftd#tooltip",
+        "ftd#rive",
+        "ftd#document",
+    ]
+    .contains(&s)
 }
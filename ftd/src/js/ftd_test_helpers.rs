@@ -15,14 +15,19 @@ pub fn interpret_helper(
             ftd::interpreter::Interpreter::StuckOnImport {
                 module, state: st, ..
             } => {
-                let source = "";
+                let mut source = "".to_string();
                 let mut foreign_variable = vec![];
                 let mut foreign_function = vec![];
                 if module.eq("test") {
                     foreign_variable.push("var".to_string());
                     foreign_function.push("fn".to_string());
                 }
-                let document = ftd::interpreter::ParsedDocument::parse(module.as_str(), source)?;
+                // the `t/html` cases import each other
+                if let Ok(value) = std::fs::read_to_string(format!("./t/html/{}.ftd", module)) {
+                    source = value;
+                }
+                let document =
+                    ftd::interpreter::ParsedDocument::parse(module.as_str(), source.as_str())?;
                 s = st.continue_after_import(
                     module.as_str(),
                     document,
@@ -73,7 +78,7 @@ pub fn interpret_helper(
 #[track_caller]
 fn p(s: &str, t: &str, fix: bool, manual: bool, file_location: &std::path::PathBuf) {
     let i = interpret_helper("foo", s).unwrap_or_else(|e| panic!("{:?}", e));
-    let js_ast = ftd::js::document_into_js_ast(i);
+    let js_ast = ftd::js::document_into_js_ast(i).unwrap_or_else(|e| panic!("{:?}", e));
     let ssr_body = fastn_js::ssr(js_ast.as_slice());
     let js_script = prettify_js::prettyprint(fastn_js::to_js(js_ast.as_slice()).as_str()).0;
     let html_str = std::fs::read_to_string("ftd-js.html")
@@ -154,9 +159,13 @@ fn find_all_files_matching_extension_recursively(
     files
 }
 
+/// The `t/js` cases, and the `t/html` ones, whose js output is in `t/js/html`.
 fn find_file_groups(manual: bool) -> Vec<(Vec<std::path::PathBuf>, std::path::PathBuf)> {
     let files = {
         let mut f = find_all_files_matching_extension_recursively("t/js", "ftd");
+        f.extend(find_all_files_matching_extension_recursively(
+            "t/html", "ftd",
+        ));
         f.sort();
         f
     };
@@ -164,7 +173,10 @@ fn find_file_groups(manual: bool) -> Vec<(Vec<std::path::PathBuf>, std::path::Pa
     let mut o: Vec<(Vec<std::path::PathBuf>, std::path::PathBuf)> = vec![];
 
     for f in files {
-        let json = filename_with_second_last_extension_replaced_with_json(&f, manual);
+        let mut json = filename_with_second_last_extension_replaced_with_json(&f, manual);
+        if let Ok(name) = json.strip_prefix("t/html") {
+            json = std::path::Path::new("t/js/html").join(name);
+        }
         match o.last_mut() {
             Some((v, j)) if j == &json => v.push(f),
             _ => o.push((vec![f], json)),
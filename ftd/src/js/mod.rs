@@ -10,28 +10,274 @@ mod value;
 pub use element::{Common, Element};
 pub use value::Value;
 
-pub fn document_into_js_ast(document: ftd::interpreter::Document) -> Vec<fastn_js::Ast> {
+pub fn document_into_js_ast(
+    mut document: ftd::interpreter::Document,
+) -> ftd::interpreter::Result<Vec<fastn_js::Ast>> {
     use itertools::Itertools;
-    let doc = ftd::interpreter::TDoc::new(&document.name, &document.aliases, &document.data);
-    let mut asts = vec![ftd::js::from_tree(document.tree.as_slice(), &doc)];
     let default_thing_name = ftd::interpreter::default::default_bag()
         .into_iter()
         .map(|v| v.0)
         .collect_vec();
+    let inherited = inherited_names(&mut document, default_thing_name.as_slice());
+    let doc = ftd::interpreter::TDoc::new(&document.name, &document.aliases, &document.data);
+    let mut asts = vec![ftd::js::from_tree(
+        document.tree.as_slice(),
+        &doc,
+        inherited.as_slice(),
+    )?];
 
+    let mut pushed_variables = std::collections::HashSet::new();
+    if !inherited.is_empty() {
+        // the variables read `$inherited` values too, they are declared before the variables
+        let mut defaults = vec![];
+        for name in inherited.iter() {
+            if let Some(default) = inherited_default(name) {
+                push_variable_ast(
+                    &doc.get_variable(default, 0)?,
+                    &doc,
+                    &[],
+                    &default_thing_name,
+                    &mut pushed_variables,
+                    &mut asts,
+                )?;
+                defaults.push((
+                    name.to_string(),
+                    fastn_js::SetPropertyValue::Reference(default.to_string()),
+                ));
+            }
+        }
+        asts.push(fastn_js::Ast::DeclareInherited(defaults));
+    }
+    let mut foreign_variables = vec![];
+    let mut exports = vec![];
     for (key, thing) in document.data.iter() {
         if default_thing_name.contains(key) {
             continue;
         }
         if let ftd::interpreter::Thing::Component(c) = thing {
-            asts.push(c.to_ast(&doc));
+            asts.push(c.to_ast(&doc, inherited.as_slice())?);
         } else if let ftd::interpreter::Thing::Variable(v) = thing {
-            asts.push(v.to_ast(&doc));
+            // the foreign variables are stored by their full name, `test#var.name`
+            if v.name
+                .split_once('#')
+                .map_or(false, |(_, v)| v.contains('.'))
+            {
+                foreign_variables.push(v);
+                continue;
+            }
+            push_variable_ast(
+                v,
+                &doc,
+                inherited.as_slice(),
+                &default_thing_name,
+                &mut pushed_variables,
+                &mut asts,
+            )?;
         } else if let ftd::interpreter::Thing::Function(f) = thing {
             asts.push(f.to_ast());
+        } else if let ftd::interpreter::Thing::Export { to, .. } = thing {
+            exports.push(to);
         }
     }
-    asts
+    asts.extend(foreign_variables_ast(foreign_variables.as_slice()));
+    for to in exports {
+        asts.push(export_ast(to, &doc));
+    }
+    Ok(asts)
+}
+
+/// The variable a `$inherited.<name>` value is read from when no ancestor sets it.
+fn inherited_default(name: &str) -> Option<&'static str> {
+    match name {
+        "colors" => Some("ftd#default-colors"),
+        "types" => Some("ftd#default-types"),
+        _ => None,
+    }
+}
+
+/// The names read as `$inherited.<name>` in the document, the arguments a component passes
+/// down to its descendants. A variable whose value is an `$inherited` value,
+/// `-- string name: $inherited.name`, is read where it is used, and is removed.
+fn inherited_names(
+    document: &mut ftd::interpreter::Document,
+    default_thing_name: &[String],
+) -> Vec<String> {
+    use itertools::Itertools;
+
+    let renames: ftd::Map<String> = document
+        .data
+        .iter()
+        .filter_map(|(key, thing)| match thing {
+            ftd::interpreter::Thing::Variable(ftd::interpreter::Variable {
+                value: ftd::interpreter::PropertyValue::Reference { name, .. },
+                ..
+            }) if name.starts_with("inherited.") => Some((key.to_string(), name.to_string())),
+            _ => None,
+        })
+        .collect();
+    for key in renames.keys() {
+        document.data.remove(key);
+    }
+
+    let mut names = vec![];
+    let mut visit = |name: &mut String| {
+        for (from, to) in renames.iter() {
+            if from.eq(name) {
+                *name = to.to_string();
+            } else if let Some(rest) = name
+                .strip_prefix(from.as_str())
+                .filter(|rest| rest.starts_with('.'))
+            {
+                *name = format!("{}{}", to, rest);
+            }
+        }
+        if let Some(rest) = name.strip_prefix("inherited.") {
+            names.push(rest.split('.').next().unwrap_or(rest).to_string());
+        }
+    };
+    for component in document.tree.iter_mut() {
+        component.visit_references_mut(&mut visit);
+    }
+    for (key, thing) in document.data.iter_mut() {
+        if default_thing_name.contains(key) {
+            continue;
+        }
+        match thing {
+            ftd::interpreter::Thing::Component(c) => {
+                c.definition.visit_references_mut(&mut visit);
+                for argument in c.arguments.iter_mut() {
+                    if let Some(value) = argument.value.as_mut() {
+                        value.visit_references_mut(&mut visit);
+                    }
+                }
+            }
+            ftd::interpreter::Thing::Variable(v) => {
+                v.value.visit_references_mut(&mut visit);
+                for conditional_value in v.conditional_value.iter_mut() {
+                    conditional_value.value.visit_references_mut(&mut visit);
+                    conditional_value.condition.visit_references_mut(&mut visit);
+                }
+            }
+            _ => {}
+        }
+    }
+    names.into_iter().unique().sorted().collect()
+}
+
+/// The foreign variables `test#var.name` and `test#var.title` are the fields of a record
+/// instance `test#var`, the way references to them are read.
+fn foreign_variables_ast(variables: &[&ftd::interpreter::Variable]) -> Vec<fastn_js::Ast> {
+    fn insert(
+        fields: &mut Vec<(String, fastn_js::SetPropertyValue)>,
+        path: &str,
+        value: fastn_js::SetPropertyValue,
+    ) {
+        let (field, rest) = match path.split_once('.') {
+            Some((field, rest)) => (field, rest),
+            None => {
+                fields.push((path.to_string(), value));
+                return;
+            }
+        };
+        let index = match fields.iter().position(|(k, _)| k.eq(field)) {
+            Some(index) => index,
+            None => {
+                fields.push((
+                    field.to_string(),
+                    fastn_js::SetPropertyValue::Value(fastn_js::Value::Record { fields: vec![] }),
+                ));
+                fields.len() - 1
+            }
+        };
+        if let fastn_js::SetPropertyValue::Value(fastn_js::Value::Record { fields }) =
+            &mut fields[index].1
+        {
+            insert(fields, rest, value);
+        }
+    }
+
+    let mut records: Vec<(String, Vec<(String, fastn_js::SetPropertyValue)>)> = vec![];
+    for variable in variables {
+        let (module, thing) = variable.name.split_once('#').unwrap();
+        let (name, path) = thing.split_once('.').unwrap();
+        let name = format!("{}#{}", module, name);
+        let index = match records.iter().position(|(k, _)| k.eq(&name)) {
+            Some(index) => index,
+            None => {
+                records.push((name, vec![]));
+                records.len() - 1
+            }
+        };
+        insert(
+            &mut records[index].1,
+            path,
+            variable.value.to_fastn_js_value(),
+        );
+    }
+    records
+        .into_iter()
+        .map(|(name, fields)| {
+            fastn_js::Ast::RecordInstance(fastn_js::RecordInstance {
+                name,
+                fields: fastn_js::SetPropertyValue::Value(fastn_js::Value::Record { fields }),
+            })
+        })
+        .collect()
+}
+
+/// A re-exported thing is the thing it is exported from, through any number of re-exports.
+fn export_ast(to: &str, doc: &ftd::interpreter::TDoc) -> fastn_js::Ast {
+    let mut from = to.to_string();
+    while let Some(ftd::interpreter::Thing::Export { from: f, .. }) = doc.bag().get(from.as_str()) {
+        from = f.to_string();
+    }
+    fastn_js::Ast::StaticVariable(fastn_js::StaticVariable {
+        name: to.to_string(),
+        value: fastn_js::SetPropertyValue::Reference(from),
+    })
+}
+
+/// Pushes the variables `variable` refers to before `variable` itself, the js `let` of a
+/// variable has to run before any other variable reads it.
+fn push_variable_ast(
+    variable: &ftd::interpreter::Variable,
+    doc: &ftd::interpreter::TDoc,
+    inherited: &[String],
+    default_thing_name: &[String],
+    pushed_variables: &mut std::collections::HashSet<String>,
+    asts: &mut Vec<fastn_js::Ast>,
+) -> ftd::interpreter::Result<()> {
+    if !pushed_variables.insert(variable.name.to_string()) {
+        return Ok(());
+    }
+    let mut references = vec![];
+    variable.value.references(&mut references);
+    for reference in references {
+        // `foo#person.name` is a field of `foo#person`
+        let name = match reference.split_once('#') {
+            Some((module, thing)) => format!(
+                "{}#{}",
+                module,
+                thing.split_once('.').map(|(v, _)| v).unwrap_or(thing)
+            ),
+            None => continue,
+        };
+        if default_thing_name.contains(&name) {
+            continue;
+        }
+        if let Some(ftd::interpreter::Thing::Variable(v)) = doc.bag().get(name.as_str()) {
+            push_variable_ast(
+                v,
+                doc,
+                inherited,
+                default_thing_name,
+                pushed_variables,
+                asts,
+            )?;
+        }
+    }
+    asts.push(variable.to_ast(doc, inherited)?);
+    Ok(())
 }
 
 impl ftd::interpreter::Function {
@@ -55,7 +301,11 @@ impl ftd::interpreter::Function {
 }
 
 impl ftd::interpreter::Variable {
-    pub fn to_ast(&self, doc: &ftd::interpreter::TDoc) -> fastn_js::Ast {
+    pub fn to_ast(
+        &self,
+        doc: &ftd::interpreter::TDoc,
+        inherited: &[String],
+    ) -> ftd::interpreter::Result<fastn_js::Ast> {
         if let Ok(value) = self.value.value(doc.name, self.value.line_number()) {
             if let ftd::interpreter::Kind::Record { name } = &self.kind.kind {
                 let record = doc.get_record(name, self.line_number).unwrap();
@@ -65,7 +315,10 @@ impl ftd::interpreter::Variable {
                 let mut fields = vec![];
                 for field in record.fields {
                     if let Some(value) = record_fields.get(field.name.as_str()) {
-                        fields.push((field.name.to_string(), value.to_fastn_js_value()));
+                        fields.push((
+                            field.name.to_string(),
+                            value.to_fastn_js_value_with_ui(doc, inherited, None, None)?,
+                        ));
                     } else {
                         fields.push((
                             field.name.to_string(),
@@ -76,32 +329,48 @@ impl ftd::interpreter::Variable {
                         ));
                     }
                 }
-                return fastn_js::Ast::RecordInstance(fastn_js::RecordInstance {
+                return Ok(fastn_js::Ast::RecordInstance(fastn_js::RecordInstance {
                     name: self.name.to_string(),
                     fields: fastn_js::SetPropertyValue::Value(fastn_js::Value::Record { fields }),
-                });
+                }));
+            } else if self.kind.is_map() {
+                // the keys of a map are the fields of a record instance, mutable or not
+                return Ok(fastn_js::Ast::RecordInstance(fastn_js::RecordInstance {
+                    name: self.name.to_string(),
+                    fields: self.value.to_fastn_js_value(),
+                }));
             } else if self.kind.is_list() {
                 // Todo: It should be only for Mutable not Static
-                return fastn_js::Ast::MutableList(fastn_js::MutableList {
+                return Ok(fastn_js::Ast::MutableList(fastn_js::MutableList {
                     name: self.name.to_string(),
-                    value: self.value.to_fastn_js_value(),
-                });
+                    value: self
+                        .value
+                        .to_fastn_js_value_with_ui(doc, inherited, None, None)?,
+                }));
             } else if self.mutable {
-                return fastn_js::Ast::MutableVariable(fastn_js::MutableVariable {
+                return Ok(fastn_js::Ast::MutableVariable(fastn_js::MutableVariable {
                     name: self.name.to_string(),
-                    value: self.value.to_fastn_js_value(),
-                });
+                    value: self
+                        .value
+                        .to_fastn_js_value_with_ui(doc, inherited, None, None)?,
+                }));
             }
         }
-        fastn_js::Ast::StaticVariable(fastn_js::StaticVariable {
+        Ok(fastn_js::Ast::StaticVariable(fastn_js::StaticVariable {
             name: self.name.to_string(),
-            value: self.value.to_fastn_js_value(),
-        })
+            value: self
+                .value
+                .to_fastn_js_value_with_ui(doc, inherited, None, None)?,
+        }))
     }
 }
 
 impl ftd::interpreter::ComponentDefinition {
-    pub fn to_ast(&self, doc: &ftd::interpreter::TDoc) -> fastn_js::Ast {
+    pub fn to_ast(
+        &self,
+        doc: &ftd::interpreter::TDoc,
+        inherited: &[String],
+    ) -> ftd::interpreter::Result<fastn_js::Ast> {
         use itertools::Itertools;
 
         let mut statements = vec![];
@@ -111,27 +380,33 @@ impl ftd::interpreter::ComponentDefinition {
             doc,
             Some(self.name.to_string()),
             true,
-        ));
-        fastn_js::component_with_params(
+            inherited,
+        )?);
+        Ok(fastn_js::component_with_params(
             self.name.as_str(),
             statements,
-            self.arguments
-                .iter()
-                .map(|v| v.name.to_string())
+            // the `$inherited` values of the instance come right after its parent
+            (!inherited.is_empty())
+                .then(|| "inherited".to_string())
+                .into_iter()
+                .chain(self.arguments.iter().map(|v| v.name.to_string()))
                 .collect_vec(),
-        )
+        ))
     }
 }
 
 pub fn from_tree(
     tree: &[ftd::interpreter::Component],
     doc: &ftd::interpreter::TDoc,
-) -> fastn_js::Ast {
+    inherited: &[String],
+) -> ftd::interpreter::Result<fastn_js::Ast> {
     let mut statements = vec![];
     for (index, component) in tree.iter().enumerate() {
-        statements.extend(component.to_component_statements("parent", index, doc, None, false))
+        statements.extend(
+            component.to_component_statements("parent", index, doc, None, false, inherited)?,
+        )
     }
-    fastn_js::component0("main", statements)
+    Ok(fastn_js::component0("main", statements))
 }
 
 impl ftd::interpreter::Component {
@@ -142,7 +417,8 @@ impl ftd::interpreter::Component {
         doc: &ftd::interpreter::TDoc,
         component_definition_name: Option<String>,
         should_return: bool,
-    ) -> Vec<fastn_js::ComponentStatement> {
+        inherited: &[String],
+    ) -> ftd::interpreter::Result<Vec<fastn_js::ComponentStatement>> {
         use itertools::Itertools;
 
         let loop_alias = self.iteration.clone().map(|v| v.alias);
@@ -154,7 +430,8 @@ impl ftd::interpreter::Component {
                 component_definition_name.clone(),
                 true,
                 loop_alias.clone(),
-            )
+                inherited,
+            )?
         } else {
             self.to_component_statements_(
                 parent,
@@ -163,7 +440,8 @@ impl ftd::interpreter::Component {
                 component_definition_name.clone(),
                 should_return,
                 None,
-            )
+                inherited,
+            )?
         };
 
         if let Some(condition) = self.condition.as_ref() {
@@ -177,7 +455,7 @@ impl ftd::interpreter::Component {
                         })
                         .collect_vec(),
                     condition: condition.update_node_with_variable_reference_js(
-                        component_definition_name,
+                        component_definition_name.clone(),
                         loop_alias,
                     ),
                     statements: component_statements,
@@ -189,16 +467,23 @@ impl ftd::interpreter::Component {
 
         if let Some(iteration) = self.iteration.as_ref() {
             component_statements = vec![fastn_js::ComponentStatement::ForLoop(fastn_js::ForLoop {
-                list_variable: iteration.on.to_fastn_js_value(),
+                list_variable: iteration
+                    .on
+                    .to_fastn_js_value_with_reference(component_definition_name, None),
                 statements: component_statements,
                 parent: parent.to_string(),
                 should_return,
+                integer_keys: matches!(
+                    iteration.on.kind(),
+                    ftd::interpreter::Kind::Map { key, .. } if key.is_integer()
+                ),
             })]
         }
 
-        component_statements
+        Ok(component_statements)
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn to_component_statements_(
         &self,
         parent: &str,
@@ -207,47 +492,217 @@ impl ftd::interpreter::Component {
         component_definition_name: Option<String>,
         should_return: bool,
         loop_alias: Option<String>,
-    ) -> Vec<fastn_js::ComponentStatement> {
-        use itertools::Itertools;
-        if ftd::js::element::is_kernel(self.name.as_str()) {
-            ftd::js::Element::from_interpreter_component(self, doc).to_component_statements(
+        inherited: &[String],
+    ) -> ftd::interpreter::Result<Vec<fastn_js::ComponentStatement>> {
+        if let Some(device) = ftd::js::element::device(self.name.as_str()) {
+            return ftd::js::element::device_statements(
+                device,
+                self.get_children(doc)?.as_slice(),
+                parent,
+                doc,
+                component_definition_name,
+                should_return,
+                inherited,
+            );
+        }
+        if self.is_variable() {
+            // a `ftd.ui` value, `-- uis.0:`, or a component of a `module` value, `-- page.m.box:`
+            let arguments = self.module_component_arguments(doc, &component_definition_name);
+            return Ok(vec![fastn_js::ComponentStatement::InstantiateComponent(
+                fastn_js::InstantiateComponent {
+                    component: fastn_js::InstantiateComponentData::Definition(Box::new(
+                        fastn_js::SetPropertyValue::Reference(ftd::js::utils::update_reference(
+                            self.name.as_str(),
+                            component_definition_name.clone(),
+                            loop_alias.clone(),
+                        )),
+                    )),
+                    arguments: self.arguments_to_set_property_values(
+                        self.name.as_str(),
+                        arguments.as_slice(),
+                        doc,
+                        component_definition_name,
+                        loop_alias,
+                        inherited,
+                    )?,
+                    parent: parent.to_string(),
+                    inherited: (!inherited.is_empty()).then(Default::default),
+                    should_return,
+                },
+            )]);
+        }
+        if ftd::js::element::is_kernel(self.name.as_str())
+            || doc
+                .get_web_component(self.name.as_str(), self.line_number)
+                .is_ok()
+        {
+            ftd::js::Element::from_interpreter_component(self, doc)?.to_component_statements(
                 parent,
                 index,
                 doc,
                 component_definition_name,
                 loop_alias,
                 should_return,
+                inherited,
             )
         } else if let Ok(component_definition) =
             doc.get_component(self.name.as_str(), self.line_number)
         {
-            let arguments = component_definition
-                .arguments
-                .iter()
-                .map(|v| {
-                    v.get_value(self.properties.as_slice())
-                        .to_set_property_value(
-                            component_definition_name.clone(),
-                            loop_alias.clone(),
-                        )
-                })
-                .collect_vec();
+            let arguments = self.arguments_to_set_property_values(
+                component_definition.name.as_str(),
+                component_definition.arguments.as_slice(),
+                doc,
+                component_definition_name,
+                loop_alias,
+                inherited,
+            )?;
+            // the arguments read as `$inherited.<name>`, the ones given to the instance are
+            // passed down, the defaults are used when no ancestor passes a value down
+            let mut values = vec![];
+            let mut defaults = vec![];
+            for (argument, value) in component_definition.arguments.iter().zip(arguments.iter()) {
+                if !inherited.contains(&argument.name) {
+                    continue;
+                }
+                if self.is_argument_given(argument) {
+                    values.push((argument.name.to_string(), value.clone()));
+                } else if argument.value.is_some() {
+                    defaults.push((argument.name.to_string(), value.clone()));
+                }
+            }
             // Todo: Add event
             /*for event in self.events.iter() {
                 component_statements.push(fastn_js::ComponentStatement::AddEventHandler(
                     event.to_event_handler_js(element_name, doc, component_definition_name.clone()),
                 ));
             }*/
-            vec![fastn_js::ComponentStatement::InstantiateComponent(
+            Ok(vec![fastn_js::ComponentStatement::InstantiateComponent(
                 fastn_js::InstantiateComponent {
-                    name: self.name.to_string(),
+                    component: fastn_js::InstantiateComponentData::Name(self.name.to_string()),
                     arguments,
                     parent: parent.to_string(),
+                    inherited: (!inherited.is_empty())
+                        .then_some(fastn_js::Inherited { values, defaults }),
                     should_return,
                 },
-            )]
+            )])
         } else {
-            panic!("Can't find, {}", self.name)
+            ftd::interpreter::utils::e2(
+                format!("Can't find, {}", self.name),
+                doc.name,
+                self.line_number,
+            )
+        }
+    }
+
+    /// The values of `arguments`, the arguments of the component this is an instance of,
+    /// `component_name`, in order.
+    fn arguments_to_set_property_values(
+        &self,
+        component_name: &str,
+        arguments: &[ftd::interpreter::Argument],
+        doc: &ftd::interpreter::TDoc,
+        component_definition_name: Option<String>,
+        loop_alias: Option<String>,
+        inherited: &[String],
+    ) -> ftd::interpreter::Result<Vec<fastn_js::SetPropertyValue>> {
+        let mut values = vec![];
+        for argument in arguments {
+            let value = ftd::js::value::get_properties_with_arguments(
+                argument.name.as_str(),
+                component_name,
+                self.properties.as_slice(),
+                arguments,
+            )
+            .or_else(|| argument.get_default_value())
+            .unwrap_or_else(|| panic!("Expected value for argument: {:?}", argument));
+            values.push(match module_value(&value, argument) {
+                Some(value) => value,
+                None => value.to_set_property_value_with_ui(
+                    doc,
+                    inherited,
+                    component_definition_name.clone(),
+                    loop_alias.clone(),
+                )?,
+            });
         }
+        Ok(values)
+    }
+
+    /// Whether the instance gives a value for `argument`, rather than using its default.
+    fn is_argument_given(&self, argument: &ftd::interpreter::Argument) -> bool {
+        ftd::interpreter::utils::find_properties_by_source(
+            argument.to_sources().as_slice(),
+            self.properties.as_slice(),
+            "", // doc_name
+            argument,
+            0, // line_number
+        )
+        .map_or(false, |properties| {
+            properties.iter().any(|v| !v.source.is_default())
+        })
+    }
+
+    /// The arguments of the component of a `module` argument this is an instance of,
+    /// `-- page.m.box:`, none for a `ftd.ui` value.
+    fn module_component_arguments(
+        &self,
+        doc: &ftd::interpreter::TDoc,
+        component_definition_name: &Option<String>,
+    ) -> Vec<ftd::interpreter::Argument> {
+        let component_definition = match component_definition_name
+            .as_ref()
+            .and_then(|name| doc.get_component(name, self.line_number).ok())
+        {
+            Some(component_definition) => component_definition,
+            None => return vec![],
+        };
+        component_definition
+            .arguments
+            .iter()
+            .find_map(|argument| match argument.value.as_ref() {
+                Some(ftd::interpreter::PropertyValue::Value {
+                    value: ftd::interpreter::Value::Module { things, .. },
+                    ..
+                }) => match things.get(self.name.as_str()) {
+                    Some(ftd::interpreter::ModuleThing::Component(component)) => {
+                        Some(component.arguments.clone())
+                    }
+                    _ => None,
+                },
+                _ => None,
+            })
+            .unwrap_or_default()
     }
 }
+
+/// A `module` value is a record with the things the component reads from it, `$page.m.name` is
+/// the `name` of the module given as `m`.
+fn module_value(
+    value: &ftd::js::Value,
+    argument: &ftd::interpreter::Argument,
+) -> Option<fastn_js::SetPropertyValue> {
+    let module = match value {
+        ftd::js::Value::Data(ftd::interpreter::Value::Module { name, .. }) => name,
+        _ => return None,
+    };
+    let things = match argument.value.as_ref() {
+        Some(ftd::interpreter::PropertyValue::Value {
+            value: ftd::interpreter::Value::Module { things, .. },
+            ..
+        }) => things,
+        _ => return None,
+    };
+    Some(fastn_js::SetPropertyValue::Value(fastn_js::Value::Record {
+        fields: things
+            .keys()
+            .map(|key| {
+                let name = key.rsplit_once('.').map_or(key.as_str(), |(_, v)| v);
+                (
+                    name.to_string(),
+                    fastn_js::SetPropertyValue::Reference(format!("{}#{}", module, name)),
+                )
+            })
+            .collect(),
+    }))
+}
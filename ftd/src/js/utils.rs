@@ -23,6 +23,8 @@ pub(crate) fn update_reference(
 
     if name.contains(ftd::interpreter::FTD_LOOP_COUNTER) {
         name = "index".to_string()
+    } else if name.contains(ftd::interpreter::FTD_LOOP_KEY) {
+        name = "key".to_string()
     }
 
     name
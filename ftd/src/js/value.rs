@@ -16,7 +16,9 @@ impl Value {
         loop_alias: Option<String>,
     ) -> fastn_js::SetPropertyValue {
         match self {
-            Value::Data(value) => value.to_fastn_js_value(),
+            Value::Data(value) => {
+                value.to_fastn_js_value_with_reference(component_definition_name, loop_alias)
+            }
             Value::Reference(name) => fastn_js::SetPropertyValue::Reference(
                 ftd::js::utils::update_reference(name, component_definition_name, loop_alias),
             ),
@@ -26,6 +28,26 @@ impl Value {
         }
     }
 
+    /// `to_set_property_value` for a value that can be or hold a `ftd.ui`, see
+    /// `ftd::interpreter::Value::to_fastn_js_value_with_ui`.
+    pub(crate) fn to_set_property_value_with_ui(
+        &self,
+        doc: &ftd::interpreter::TDoc,
+        inherited: &[String],
+        component_definition_name: Option<String>,
+        loop_alias: Option<String>,
+    ) -> ftd::interpreter::Result<fastn_js::SetPropertyValue> {
+        match self {
+            Value::Data(value) => value.to_fastn_js_value_with_ui(
+                doc,
+                inherited,
+                component_definition_name,
+                loop_alias,
+            ),
+            _ => Ok(self.to_set_property_value(component_definition_name, loop_alias)),
+        }
+    }
+
     pub(crate) fn to_set_property(
         &self,
         kind: fastn_js::PropertyKind,
@@ -65,7 +87,10 @@ fn formulas_to_fastn_js_value(
                     loop_alias.clone(),
                 )
             }),
-            expression: property.value.to_fastn_js_value(),
+            expression: property.value.to_fastn_js_value_with_reference(
+                component_definition_name.clone(),
+                loop_alias.clone(),
+            ),
         });
     }
 
@@ -116,6 +141,10 @@ impl ftd::interpreter::Expression {
                     operator = fastn_grammar::evalexpr::Operator::VariableIdentifierRead {
                         identifier: "index".to_string(),
                     }
+                } else if format!("${}", ftd::interpreter::FTD_LOOP_KEY).eq(identifier) {
+                    operator = fastn_grammar::evalexpr::Operator::VariableIdentifierRead {
+                        identifier: "key".to_string(),
+                    }
                 } else if let Some(ftd::interpreter::PropertyValue::Reference { name, .. }) =
                     references.get(identifier)
                 {
@@ -125,7 +154,7 @@ impl ftd::interpreter::Expression {
                         loop_alias.clone(),
                     );
                     operator = fastn_grammar::evalexpr::Operator::VariableIdentifierRead {
-                        identifier: fastn_js::utils::name_to_js(name.as_str()),
+                        identifier: fastn_js::utils::reference_to_js(name.as_str()),
                     }
                 }
             }
@@ -163,6 +192,128 @@ impl ftd::interpreter::PropertyValue {
         }
         deps
     }
+
+    /// Collects the names of every variable this value refers to, including the ones inside
+    /// record fields, list items and function arguments.
+    pub(crate) fn references(&self, references: &mut Vec<String>) {
+        match self {
+            ftd::interpreter::PropertyValue::Reference { name, .. }
+            | ftd::interpreter::PropertyValue::Clone { name, .. } => {
+                references.push(name.to_string())
+            }
+            ftd::interpreter::PropertyValue::FunctionCall(function) => {
+                for value in function.values.values() {
+                    value.references(references);
+                }
+            }
+            ftd::interpreter::PropertyValue::Value { value, .. } => value.references(references),
+        }
+    }
+
+    pub(crate) fn visit_references_mut<F: FnMut(&mut String)>(&mut self, f: &mut F) {
+        match self {
+            ftd::interpreter::PropertyValue::Reference { name, .. }
+            | ftd::interpreter::PropertyValue::Clone { name, .. } => f(name),
+            ftd::interpreter::PropertyValue::FunctionCall(function) => {
+                for value in function.values.values_mut() {
+                    value.visit_references_mut(f);
+                }
+            }
+            ftd::interpreter::PropertyValue::Value { value, .. } => value.visit_references_mut(f),
+        }
+    }
+}
+
+impl ftd::interpreter::Value {
+    fn references(&self, references: &mut Vec<String>) {
+        match self {
+            ftd::interpreter::Value::Object { values: data }
+            | ftd::interpreter::Value::Record { fields: data, .. }
+            | ftd::interpreter::Value::Map { data, .. } => {
+                for value in data.values() {
+                    value.references(references);
+                }
+            }
+            ftd::interpreter::Value::List { data, .. } => {
+                for value in data {
+                    value.references(references);
+                }
+            }
+            ftd::interpreter::Value::OrType { value, .. } => value.references(references),
+            ftd::interpreter::Value::Optional { data, .. } => {
+                if let Some(value) = data.as_ref() {
+                    value.references(references);
+                }
+            }
+            ftd::interpreter::Value::UI { component, .. } => {
+                let mut component = component.clone();
+                component.visit_references_mut(&mut |name| references.push(name.to_string()));
+            }
+            _ => {}
+        }
+    }
+
+    /// Calls `f` with the name of every variable this value refers to, so that it can be
+    /// renamed, including the ones in the components of `ftd.ui` values.
+    pub(crate) fn visit_references_mut<F: FnMut(&mut String)>(&mut self, f: &mut F) {
+        match self {
+            ftd::interpreter::Value::Object { values: data }
+            | ftd::interpreter::Value::Record { fields: data, .. }
+            | ftd::interpreter::Value::Map { data, .. } => {
+                for value in data.values_mut() {
+                    value.visit_references_mut(f);
+                }
+            }
+            ftd::interpreter::Value::List { data, .. } => {
+                for value in data {
+                    value.visit_references_mut(f);
+                }
+            }
+            ftd::interpreter::Value::OrType { value, .. } => value.visit_references_mut(f),
+            ftd::interpreter::Value::Optional { data, .. } => {
+                if let Some(value) = data.as_mut() {
+                    value.visit_references_mut(f);
+                }
+            }
+            ftd::interpreter::Value::UI { component, .. } => component.visit_references_mut(f),
+            _ => {}
+        }
+    }
+}
+
+impl ftd::interpreter::Component {
+    /// `visit_references_mut` for the properties, condition, loop and events of the component
+    /// and of its children.
+    pub(crate) fn visit_references_mut<F: FnMut(&mut String)>(&mut self, f: &mut F) {
+        for property in self.properties.iter_mut() {
+            property.value.visit_references_mut(f);
+            if let Some(condition) = property.condition.as_mut() {
+                condition.visit_references_mut(f);
+            }
+        }
+        if let Some(condition) = self.condition.as_mut() {
+            condition.visit_references_mut(f);
+        }
+        if let Some(iteration) = self.iteration.as_mut() {
+            iteration.on.visit_references_mut(f);
+        }
+        for event in self.events.iter_mut() {
+            for value in event.action.values.values_mut() {
+                value.visit_references_mut(f);
+            }
+        }
+        for child in self.children.iter_mut() {
+            child.visit_references_mut(f);
+        }
+    }
+}
+
+impl ftd::interpreter::Expression {
+    pub(crate) fn visit_references_mut<F: FnMut(&mut String)>(&mut self, f: &mut F) {
+        for value in self.references.values_mut() {
+            value.visit_references_mut(f);
+        }
+    }
 }
 
 impl ftd::interpreter::Argument {
@@ -236,14 +387,271 @@ pub(crate) fn get_properties(
         .get_optional_value(properties)
 }
 
+/// `get_properties` for an argument of an instance of `component_name`, with the references its
+/// default makes to the other arguments, `$code.title` in the default of `code.name`, replaced by
+/// the values these arguments have in the instance.
+pub(crate) fn get_properties_with_arguments(
+    key: &str,
+    component_name: &str,
+    properties: &[ftd::interpreter::Property],
+    arguments: &[ftd::interpreter::Argument],
+) -> Option<Value> {
+    ArgumentScope {
+        component_name,
+        properties,
+        arguments,
+    }
+    .value(key, 0)
+}
+
+/// The arguments of an instance of a component, that the defaults of its arguments can refer to.
+struct ArgumentScope<'a> {
+    component_name: &'a str,
+    properties: &'a [ftd::interpreter::Property],
+    arguments: &'a [ftd::interpreter::Argument],
+}
+
+impl ArgumentScope<'_> {
+    /// Arguments defaulting to each other in a cycle are left as they are.
+    const MAX_DEPTH: usize = 16;
+
+    fn value(&self, key: &str, depth: usize) -> Option<Value> {
+        let argument = self.arguments.iter().find(|v| v.name.eq(key))?;
+        let properties = ftd::interpreter::utils::find_properties_by_source(
+            argument.to_sources().as_slice(),
+            self.properties,
+            "", // doc_name
+            argument,
+            0, // line_number
+        )
+        .unwrap();
+
+        let mut resolved = vec![];
+        for mut property in properties {
+            if !property.source.is_default() || depth >= Self::MAX_DEPTH {
+                resolved.push(property);
+                continue;
+            }
+            // the default is the other argument, with all of its conditions
+            if property.condition.is_none() {
+                if let Some(Value::Formula(properties)) = property
+                    .value
+                    .reference_name()
+                    .and_then(|name| self.reference(name, depth))
+                {
+                    resolved.extend(properties);
+                    continue;
+                }
+            }
+            self.resolve(&mut property.value, depth);
+            if let Some(condition) = property.condition.as_mut() {
+                for value in condition.references.values_mut() {
+                    self.resolve(value, depth);
+                }
+            }
+            resolved.push(property);
+        }
+
+        match resolved.as_slice() {
+            [] => None,
+            [property] if property.condition.is_none() => Some(property.value.to_value()),
+            _ => Some(Value::Formula(resolved)),
+        }
+    }
+
+    /// The value of `name`, if it is one of the arguments or a field of one.
+    fn reference(&self, name: &str, depth: usize) -> Option<Value> {
+        let name = name.strip_prefix(format!("{}.", self.component_name).as_str())?;
+        let (key, rest) = match name.split_once('.') {
+            Some((key, rest)) => (key, Some(rest)),
+            None => (name, None),
+        };
+        let value = self.value(key, depth + 1).or_else(|| {
+            self.arguments
+                .iter()
+                .find(|v| v.name.eq(key))
+                .and_then(|v| v.get_default_value())
+        })?;
+        match rest {
+            Some(rest) => field_value(value, rest),
+            None => Some(value),
+        }
+    }
+
+    fn resolve(&self, value: &mut ftd::interpreter::PropertyValue, depth: usize) {
+        let resolved = match value {
+            ftd::interpreter::PropertyValue::Reference { name, .. }
+            | ftd::interpreter::PropertyValue::Clone { name, .. } => self.reference(name, depth),
+            ftd::interpreter::PropertyValue::FunctionCall(function_call) => {
+                for value in function_call.values.values_mut() {
+                    self.resolve(value, depth);
+                }
+                None
+            }
+            ftd::interpreter::PropertyValue::Value { .. } => None,
+        };
+        match resolved {
+            Some(Value::Reference(reference)) => value.set_reference_or_clone(reference.as_str()),
+            Some(Value::Data(data)) => {
+                *value = ftd::interpreter::PropertyValue::Value {
+                    value: data,
+                    is_mutable: value.is_mutable(),
+                    line_number: value.line_number(),
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// The field `rest`, `name` or `address.city`, of a record value.
+fn field_value(value: Value, rest: &str) -> Option<Value> {
+    match value {
+        Value::Reference(name) => Some(Value::Reference(format!("{}.{}", name, rest))),
+        Value::Data(ftd::interpreter::Value::Record { fields, .. }) => {
+            let (key, rest) = match rest.split_once('.') {
+                Some((key, rest)) => (key, Some(rest)),
+                None => (rest, None),
+            };
+            let value = fields.get(key)?.to_value();
+            match rest {
+                Some(rest) => field_value(value, rest),
+                None => Some(value),
+            }
+        }
+        _ => None,
+    }
+}
+
+/// The expression given to `ftd#map`, `ftd#filter` or `ftd#sort-by`, which is compiled to a
+/// function of the list item, as the browser can't evaluate it.
+fn item_expression(
+    function_name: &str,
+    argument: &str,
+    value: &ftd::interpreter::PropertyValue,
+) -> Option<fastn_grammar::evalexpr::ExprNode> {
+    if !matches!(
+        (function_name, argument),
+        ("ftd#map", "expression") | ("ftd#filter", "condition") | ("ftd#sort-by", "key")
+    ) {
+        return None;
+    }
+    match value {
+        ftd::interpreter::PropertyValue::Value {
+            value: ftd::interpreter::Value::String { text },
+            ..
+        } => fastn_grammar::evalexpr::build_operator_tree(text).ok(),
+        _ => None,
+    }
+}
+
 impl ftd::interpreter::PropertyValue {
     pub(crate) fn to_fastn_js_value(&self) -> fastn_js::SetPropertyValue {
         match self {
             ftd::interpreter::PropertyValue::Value { ref value, .. } => value.to_fastn_js_value(),
-            ftd::interpreter::PropertyValue::Reference { ref name, .. } => {
+            ftd::interpreter::PropertyValue::Reference { ref name, .. }
+            | ftd::interpreter::PropertyValue::Clone { ref name, .. } => {
                 fastn_js::SetPropertyValue::Reference(name.to_string())
             }
-            _ => todo!(),
+            ftd::interpreter::PropertyValue::FunctionCall(_) => {
+                self.to_fastn_js_value_with_reference(None, None)
+            }
+        }
+    }
+
+    /// Same as `to_fastn_js_value`, with the references resolved in the scope of the component
+    /// definition and the loop, if any.
+    pub(crate) fn to_fastn_js_value_with_reference(
+        &self,
+        component_definition_name: Option<String>,
+        loop_alias: Option<String>,
+    ) -> fastn_js::SetPropertyValue {
+        match self {
+            ftd::interpreter::PropertyValue::Reference { ref name, .. }
+            | ftd::interpreter::PropertyValue::Clone { ref name, .. } => {
+                if let Some(value) = self.inherited_variant() {
+                    return value
+                        .to_fastn_js_value_with_reference(component_definition_name, loop_alias);
+                }
+                fastn_js::SetPropertyValue::Reference(ftd::js::utils::update_reference(
+                    name,
+                    component_definition_name,
+                    loop_alias,
+                ))
+            }
+            ftd::interpreter::PropertyValue::FunctionCall(ref function_call) => {
+                fastn_js::SetPropertyValue::Value(fastn_js::Value::FunctionCall(
+                    fastn_js::Function {
+                        name: function_call.name.to_string(),
+                        parameters: function_call
+                            .order
+                            .iter()
+                            .filter_map(|v| function_call.values.get(v).map(|value| (v, value)))
+                            .map(|(name, v)| {
+                                match item_expression(function_call.name.as_str(), name, v) {
+                                    Some(expression) => fastn_js::SetPropertyValue::Value(
+                                        fastn_js::Value::ItemExpression(expression),
+                                    ),
+                                    None => v.to_fastn_js_value_with_reference(
+                                        component_definition_name.clone(),
+                                        loop_alias.clone(),
+                                    ),
+                                }
+                            })
+                            .collect(),
+                    },
+                ))
+            }
+            ftd::interpreter::PropertyValue::Value { ref value, .. } => {
+                value.to_fastn_js_value_with_reference(component_definition_name, loop_alias)
+            }
+        }
+    }
+
+    /// `to_fastn_js_value_with_reference` for a value that can be or hold a `ftd.ui`, see
+    /// `ftd::interpreter::Value::to_fastn_js_value_with_ui`.
+    pub(crate) fn to_fastn_js_value_with_ui(
+        &self,
+        doc: &ftd::interpreter::TDoc,
+        inherited: &[String],
+        component_definition_name: Option<String>,
+        loop_alias: Option<String>,
+    ) -> ftd::interpreter::Result<fastn_js::SetPropertyValue> {
+        match self {
+            ftd::interpreter::PropertyValue::Value { ref value, .. } => value
+                .to_fastn_js_value_with_ui(doc, inherited, component_definition_name, loop_alias),
+            _ => Ok(self.to_fastn_js_value_with_reference(component_definition_name, loop_alias)),
+        }
+    }
+
+    /// `background.solid: $inherited.colors.background.base` is a reference typed as the
+    /// `solid` variant of `ftd.background`, its value is that variant of the referred value.
+    fn inherited_variant(&self) -> Option<ftd::interpreter::Value> {
+        let mut value = self.clone();
+        let kind = match &mut value {
+            ftd::interpreter::PropertyValue::Reference { name, kind, .. }
+            | ftd::interpreter::PropertyValue::Clone { name, kind, .. }
+                if name.starts_with(format!("{}.", ftd::interpreter::FTD_INHERITED).as_str()) =>
+            {
+                kind
+            }
+            _ => return None,
+        };
+        match kind.kind.clone().inner() {
+            ftd::interpreter::Kind::OrType {
+                name,
+                variant: Some(variant),
+                full_variant: Some(full_variant),
+            } => {
+                *kind = ftd::interpreter::Kind::or_type(name.as_str()).into_kind_data();
+                Some(ftd::interpreter::Value::OrType {
+                    name,
+                    variant,
+                    full_variant,
+                    value: Box::new(value),
+                })
+            }
+            _ => None,
         }
     }
 
@@ -252,16 +660,36 @@ impl ftd::interpreter::PropertyValue {
             ftd::interpreter::PropertyValue::Value { ref value, .. } => {
                 Value::Data(value.to_owned())
             }
-            ftd::interpreter::PropertyValue::Reference { ref name, .. } => {
-                Value::Reference(name.to_owned())
+            ftd::interpreter::PropertyValue::Reference { ref name, .. }
+            | ftd::interpreter::PropertyValue::Clone { ref name, .. } => {
+                match self.inherited_variant() {
+                    Some(value) => Value::Data(value),
+                    None => Value::Reference(name.to_owned()),
+                }
+            }
+            // A function call is recomputed whenever one of its arguments changes
+            ftd::interpreter::PropertyValue::FunctionCall(ref function_call) => {
+                Value::Formula(vec![ftd::interpreter::Property {
+                    value: self.to_owned(),
+                    source: Default::default(),
+                    condition: None,
+                    line_number: function_call.line_number,
+                }])
             }
-            _ => todo!(),
         }
     }
 }
 
 impl ftd::interpreter::Value {
     pub(crate) fn to_fastn_js_value(&self) -> fastn_js::SetPropertyValue {
+        self.to_fastn_js_value_with_reference(None, None)
+    }
+
+    pub(crate) fn to_fastn_js_value_with_reference(
+        &self,
+        component_definition_name: Option<String>,
+        loop_alias: Option<String>,
+    ) -> fastn_js::SetPropertyValue {
         use itertools::Itertools;
 
         match self {
@@ -274,6 +702,41 @@ impl ftd::interpreter::Value {
             ftd::interpreter::Value::Decimal { value } => {
                 fastn_js::SetPropertyValue::Value(fastn_js::Value::Decimal(*value))
             }
+            ftd::interpreter::Value::Boolean { value } => {
+                fastn_js::SetPropertyValue::Value(fastn_js::Value::Boolean(*value))
+            }
+            // the same as in expressions: ISO-8601 strings and a number of seconds
+            ftd::interpreter::Value::DateTime { value }
+            | ftd::interpreter::Value::Date { value } => {
+                fastn_js::SetPropertyValue::Value(fastn_js::Value::String(value.to_string()))
+            }
+            ftd::interpreter::Value::Duration { seconds } => {
+                fastn_js::SetPropertyValue::Value(fastn_js::Value::Integer(*seconds))
+            }
+            ftd::interpreter::Value::Optional { data, .. } => match data.as_ref() {
+                Some(value) => {
+                    value.to_fastn_js_value_with_reference(component_definition_name, loop_alias)
+                }
+                None => fastn_js::SetPropertyValue::Value(fastn_js::Value::Null),
+            },
+            // Or-types defined in ftd documents are a record with the variant as its only field,
+            // `{circle: {radius: 2.0}}`
+            ftd::interpreter::Value::OrType {
+                name,
+                variant,
+                value,
+                ..
+            } if !name.starts_with("ftd#") => {
+                fastn_js::SetPropertyValue::Value(fastn_js::Value::Record {
+                    fields: vec![(
+                        ftd::interpreter::utils::variant_short_name(variant).to_string(),
+                        value.to_fastn_js_value_with_reference(
+                            component_definition_name,
+                            loop_alias,
+                        ),
+                    )],
+                })
+            }
             ftd::interpreter::Value::OrType {
                 name,
                 variant,
@@ -284,7 +747,10 @@ impl ftd::interpreter::Value {
                 if has_value {
                     return fastn_js::SetPropertyValue::Value(fastn_js::Value::OrType {
                         variant: js_variant,
-                        value: Some(Box::new(value.to_fastn_js_value())),
+                        value: Some(Box::new(value.to_fastn_js_value_with_reference(
+                            component_definition_name,
+                            loop_alias,
+                        ))),
                     });
                 }
                 fastn_js::SetPropertyValue::Value(fastn_js::Value::OrType {
@@ -294,76 +760,142 @@ impl ftd::interpreter::Value {
             }
             ftd::interpreter::Value::List { data, .. } => {
                 fastn_js::SetPropertyValue::Value(fastn_js::Value::List {
-                    value: data.iter().map(|v| v.to_fastn_js_value()).collect_vec(),
+                    value: data
+                        .iter()
+                        .map(|v| {
+                            v.to_fastn_js_value_with_reference(
+                                component_definition_name.clone(),
+                                loop_alias.clone(),
+                            )
+                        })
+                        .collect_vec(),
                 })
             }
-            ftd::interpreter::Value::Record { fields, .. } => {
+            // a map is a record instance with its keys as fields, so that lookups and loops
+            // work the same way on both
+            ftd::interpreter::Value::Record { fields, .. }
+            | ftd::interpreter::Value::Map { data: fields, .. } => {
                 fastn_js::SetPropertyValue::Value(fastn_js::Value::Record {
                     fields: fields
                         .iter()
-                        .map(|(k, v)| (k.to_string(), v.to_fastn_js_value()))
+                        .map(|(k, v)| {
+                            (
+                                k.to_string(),
+                                v.to_fastn_js_value_with_reference(
+                                    component_definition_name.clone(),
+                                    loop_alias.clone(),
+                                ),
+                            )
+                        })
                         .collect_vec(),
                 })
             }
             _ => todo!(),
         }
     }
-}
-
-fn ftd_to_js_variant(name: &str, variant: &str) -> (String, bool) {
-    // returns (JSVariant, has_value)
-    let variant = variant.strip_prefix(format!("{}.", name).as_str()).unwrap();
-    match name {
-        "ftd#resizing" => {
-            let js_variant = resizing_variants(variant);
-            (format!("fastn_dom.Resizing.{}", js_variant.0), js_variant.1)
-        }
-        "ftd#length" => {
-            let js_variant = length_variants(variant);
-            (format!("fastn_dom.Length.{}", js_variant), true)
-        }
-        "ftd#border-style" => {
-            let js_variant = border_style_variants(variant);
-            (format!("fastn_dom.BorderStyle.{}", js_variant), false)
-        }
-        _ => todo!(),
-    }
-}
 
-// Returns the corresponding js string and has_value
-// Todo: Remove has_value flag
-fn resizing_variants(name: &str) -> (&'static str, bool) {
-    match name {
-        "fixed" => ("Fixed", true),
-        "fill-container" => ("FillContainer", false),
-        "hug-content" => ("HugContent", false),
-        _ => todo!(),
+    /// `to_fastn_js_value_with_reference` for a value that can be or hold a `ftd.ui`. A `ftd.ui`
+    /// is a function of the element it is shown in, and of the `$inherited` values when the
+    /// document reads any, `inherited` are the names it reads.
+    pub(crate) fn to_fastn_js_value_with_ui(
+        &self,
+        doc: &ftd::interpreter::TDoc,
+        inherited: &[String],
+        component_definition_name: Option<String>,
+        loop_alias: Option<String>,
+    ) -> ftd::interpreter::Result<fastn_js::SetPropertyValue> {
+        Ok(match self {
+            ftd::interpreter::Value::UI { component, .. } => {
+                fastn_js::SetPropertyValue::Value(fastn_js::Value::UI {
+                    statements: component.to_component_statements(
+                        "root",
+                        0,
+                        doc,
+                        component_definition_name,
+                        true,
+                        inherited,
+                    )?,
+                    inherited: !inherited.is_empty(),
+                })
+            }
+            ftd::interpreter::Value::Optional { data, .. } => match data.as_ref() {
+                Some(value) => value.to_fastn_js_value_with_ui(
+                    doc,
+                    inherited,
+                    component_definition_name,
+                    loop_alias,
+                )?,
+                None => fastn_js::SetPropertyValue::Value(fastn_js::Value::Null),
+            },
+            ftd::interpreter::Value::List { data, .. } => {
+                let mut value = vec![];
+                for v in data {
+                    value.push(v.to_fastn_js_value_with_ui(
+                        doc,
+                        inherited,
+                        component_definition_name.clone(),
+                        loop_alias.clone(),
+                    )?);
+                }
+                fastn_js::SetPropertyValue::Value(fastn_js::Value::List { value })
+            }
+            ftd::interpreter::Value::Record { fields, .. }
+            | ftd::interpreter::Value::Map { data: fields, .. } => {
+                let mut value = vec![];
+                for (k, v) in fields {
+                    value.push((
+                        k.to_string(),
+                        v.to_fastn_js_value_with_ui(
+                            doc,
+                            inherited,
+                            component_definition_name.clone(),
+                            loop_alias.clone(),
+                        )?,
+                    ));
+                }
+                fastn_js::SetPropertyValue::Value(fastn_js::Value::Record { fields: value })
+            }
+            _ => self.to_fastn_js_value_with_reference(component_definition_name, loop_alias),
+        })
     }
 }
 
-fn length_variants(name: &str) -> &'static str {
-    match name {
-        "px" => "Px",
-        "em" => "Em",
-        "rem" => "Rem",
-        "percent" => "Percent",
-        "vh" => "Vh",
-        "vw" => "Vw",
-        "calc" => "Calc",
-        _ => todo!(),
-    }
+/// `ftd` or-types are mirrored in `fastn_dom` with pascal cased names, `ftd#text-align.start`
+/// is `fastn_dom.TextAlign.Start`. Constant variants are plain values, the rest are functions of
+/// the variant value.
+fn ftd_to_js_variant(name: &str, variant: &str) -> (String, bool) {
+    // returns (JSVariant, has_value)
+    let has_value = match ftd::interpreter::default::default_bag().get(name) {
+        Some(ftd::interpreter::Thing::OrType(or_type)) => or_type
+            .variants
+            .iter()
+            .find(|v| v.name().eq(variant))
+            .map(|v| !v.is_constant())
+            .unwrap_or(true),
+        _ => true,
+    };
+    let name = name.strip_prefix("ftd#").unwrap_or(name);
+    let variant = variant
+        .strip_prefix(format!("ftd#{}.", name).as_str())
+        .unwrap_or(variant);
+    (
+        format!(
+            "fastn_dom.{}.{}",
+            kebab_to_pascal(name),
+            kebab_to_pascal(variant)
+        ),
+        has_value,
+    )
 }
 
-fn border_style_variants(name: &str) -> &'static str {
-    match name {
-        "solid" => "Solid",
-        "dashed" => "Dashed",
-        "dotted" => "Dotted",
-        "groove" => "Groove",
-        "inset" => "Inset",
-        "outset" => "Outset",
-        "ridge" => "Ridge",
-        "double" => "Double",
-        _ => todo!(),
-    }
+fn kebab_to_pascal(s: &str) -> String {
+    s.split('-')
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect()
 }
@@ -17,6 +17,7 @@ pub use ftd2021::value_with_default::ValueWithDefault;
 pub use ftd2021::variable::{PropertyValue, TextSource, Value, Variable, VariableFlags};
 
 pub mod ast;
+pub mod diagnostic;
 pub mod executor;
 pub mod ftd2021;
 pub mod html;
@@ -0,0 +1,102 @@
+/// A problem in the rendered document that makes it harder to use with assistive technologies,
+/// reported by `fastn build --test`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct AccessibilityIssue {
+    pub line_number: usize,
+    pub message: String,
+}
+
+impl AccessibilityIssue {
+    fn new(line_number: usize, message: String) -> AccessibilityIssue {
+        AccessibilityIssue {
+            line_number,
+            message,
+        }
+    }
+}
+
+impl ftd::node::NodeData {
+    /// Images without alt text, links without an accessible name and headings that skip a
+    /// level, in document order.
+    pub fn accessibility_issues(&self) -> Vec<AccessibilityIssue> {
+        let mut issues = vec![];
+        check_node(&self.node, &mut None, &mut issues);
+        issues
+    }
+}
+
+fn check_node(
+    node: &ftd::node::Node,
+    last_heading: &mut Option<usize>,
+    issues: &mut Vec<AccessibilityIssue>,
+) {
+    if is_hidden(node) {
+        return;
+    }
+
+    match node.node.as_str() {
+        "img" if !is_set(node.attrs.get("alt")) => issues.push(AccessibilityIssue::new(
+            node.line_number,
+            "image has no `alt` text".to_string(),
+        )),
+        "a" if !has_accessible_name(node) => issues.push(AccessibilityIssue::new(
+            node.line_number,
+            "link has no text or `aria-label`".to_string(),
+        )),
+        _ => {}
+    }
+
+    if let Some(level) = heading_level(node.node.as_str()) {
+        if let Some(last) = *last_heading {
+            if level > last + 1 {
+                issues.push(AccessibilityIssue::new(
+                    node.line_number,
+                    format!("heading `h{}` skips a level after `h{}`", level, last),
+                ));
+            }
+        }
+        *last_heading = Some(level);
+    }
+
+    for child in node.children.iter() {
+        check_node(child, last_heading, issues);
+    }
+}
+
+fn heading_level(tag: &str) -> Option<usize> {
+    match tag {
+        "h1" => Some(1),
+        "h2" => Some(2),
+        "h3" => Some(3),
+        "h4" => Some(4),
+        "h5" => Some(5),
+        "h6" => Some(6),
+        _ => None,
+    }
+}
+
+/// The value is non-empty, or is computed from a variable when the page runs.
+fn is_set(value: Option<&ftd::node::Value>) -> bool {
+    value.is_some_and(|value| {
+        value.value.as_ref().is_some_and(|v| !v.trim().is_empty()) || !value.properties.is_empty()
+    })
+}
+
+fn is_hidden(node: &ftd::node::Node) -> bool {
+    node.null
+        || node
+            .attrs
+            .get("aria-hidden")
+            .and_then(|v| v.value.as_ref())
+            .is_some_and(|v| v.eq("true"))
+}
+
+fn has_accessible_name(node: &ftd::node::Node) -> bool {
+    if is_hidden(node) {
+        return false;
+    }
+    is_set(node.attrs.get("aria-label"))
+        || is_set(Some(&node.text))
+        || (node.node.eq("img") && is_set(node.attrs.get("alt")))
+        || node.children.iter().any(has_accessible_name)
+}
@@ -15,6 +15,8 @@ pub struct Node {
     pub raw_data: Option<RawNodeData>,
     pub web_component: Option<WebComponentData>,
     pub device: Option<ftd::executor::Device>,
+    pub pseudo_class_styles: Vec<PseudoClassStyle>,
+    pub keyframes: Vec<String>,
 }
 
 #[derive(serde::Deserialize, Debug, PartialEq, Default, Clone, serde::Serialize)]
@@ -106,6 +108,16 @@ pub struct RawNodeData {
     pub iteration: Option<ftd::interpreter::Loop>,
 }
 
+/// A css property of an element for a pseudo-class, `color.hover` is the `color` for `hover`.
+/// `dark` is the value in dark mode, when it is not `light`.
+#[derive(serde::Deserialize, Debug, PartialEq, Default, Clone, serde::Serialize)]
+pub struct PseudoClassStyle {
+    pub pseudo_class: String,
+    pub property: String,
+    pub light: String,
+    pub dark: Option<String>,
+}
+
 #[derive(serde::Deserialize, Debug, PartialEq, Default, Clone, serde::Serialize)]
 pub struct WebComponentData {
     pub properties: ftd::Map<ftd::interpreter::PropertyValue>,
@@ -137,6 +149,8 @@ impl Node {
             raw_data: None,
             web_component: None,
             device: common.device.to_owned(),
+            pseudo_class_styles: common.pseudo_class_styles(),
+            keyframes: common.keyframes(),
         }
     }
 
@@ -174,6 +188,8 @@ impl Node {
             raw_data: None,
             web_component: None,
             device: common.device.to_owned(),
+            pseudo_class_styles: common.pseudo_class_styles(),
+            keyframes: common.keyframes(),
         }
     }
 
@@ -219,6 +235,8 @@ impl Node {
             raw_data: None,
             web_component: None,
             device: common.device.to_owned(),
+            pseudo_class_styles: common.pseudo_class_styles(),
+            keyframes: common.keyframes(),
         }
     }
 
@@ -233,6 +251,7 @@ impl ftd::executor::Element {
             ftd::executor::Element::Row(r) => r.to_node(doc_id, anchor_ids),
             ftd::executor::Element::Column(c) => c.to_node(doc_id, anchor_ids),
             ftd::executor::Element::Container(e) => e.to_node(doc_id, anchor_ids),
+            ftd::executor::Element::Grid(g) => g.to_node(doc_id, anchor_ids),
             ftd::executor::Element::Text(t) => t.to_node(doc_id, anchor_ids),
             ftd::executor::Element::Integer(t) => t.to_node(doc_id, anchor_ids),
             ftd::executor::Element::Decimal(t) => t.to_node(doc_id, anchor_ids),
@@ -242,6 +261,17 @@ impl ftd::executor::Element {
             ftd::executor::Element::Iframe(i) => i.to_node(doc_id, anchor_ids),
             ftd::executor::Element::TextInput(i) => i.to_node(doc_id, anchor_ids),
             ftd::executor::Element::CheckBox(c) => c.to_node(doc_id, anchor_ids),
+            ftd::executor::Element::Select(c) => c.to_node(doc_id, anchor_ids),
+            ftd::executor::Element::RadioGroup(r) => r.to_node(doc_id, anchor_ids),
+            ftd::executor::Element::Form(f) => f.to_node(doc_id, anchor_ids),
+            ftd::executor::Element::Video(v) => v.to_node(doc_id, anchor_ids),
+            ftd::executor::Element::Audio(a) => a.to_node(doc_id, anchor_ids),
+            ftd::executor::Element::Svg(v) => v.to_node(doc_id, anchor_ids),
+            ftd::executor::Element::Icon(i) => i.to_node(doc_id, anchor_ids),
+            ftd::executor::Element::Table(t) => t.to_node(doc_id, anchor_ids),
+            ftd::executor::Element::Dialog(o) => o.to_node("ftd#dialog", doc_id, anchor_ids),
+            ftd::executor::Element::Popover(o) => o.to_node("ftd#popover", doc_id, anchor_ids),
+            ftd::executor::Element::Tooltip(o) => o.to_node("ftd#tooltip", doc_id, anchor_ids),
             ftd::executor::Element::Rive(r) => r.to_node(doc_id, anchor_ids),
             ftd::executor::Element::Null { line_number } => Node {
                 classes: vec![],
@@ -259,6 +289,8 @@ impl ftd::executor::Element {
                 raw_data: None,
                 web_component: None,
                 device: None,
+                pseudo_class_styles: vec![],
+                keyframes: vec![],
             },
             ftd::executor::Element::RawElement(r) => r.to_node(doc_id, anchor_ids),
             ftd::executor::Element::IterativeElement(i) => i.to_node(doc_id, anchor_ids),
@@ -325,6 +357,8 @@ impl ftd::executor::RawElement {
             }),
             web_component: None,
             device: None,
+            pseudo_class_styles: vec![],
+            keyframes: vec![],
         }
     }
 }
@@ -484,6 +518,99 @@ impl ftd::executor::ContainerElement {
     }
 }
 
+impl ftd::executor::Grid {
+    pub fn to_node(&self, doc_id: &str, anchor_ids: &mut Vec<String>) -> Node {
+        use ftd::node::utils::CheckMap;
+
+        let mut n = Node::from_children(&self.common, &self.children, doc_id, "grid", anchor_ids);
+        if !self.common.is_not_visible {
+            n.style
+                .insert(s("display"), ftd::node::Value::from_string("grid"));
+        }
+
+        n.style.check_and_insert(
+            "grid-template-columns",
+            ftd::node::Value::from_executor_value(
+                self.columns
+                    .value
+                    .as_ref()
+                    .map(|v| v.to_css_string(&self.common.device)),
+                self.columns.to_owned(),
+                None,
+                doc_id,
+            ),
+        );
+
+        n.style.check_and_insert(
+            "grid-template-rows",
+            ftd::node::Value::from_executor_value(
+                self.rows
+                    .value
+                    .as_ref()
+                    .map(|v| v.to_css_string(&self.common.device)),
+                self.rows.to_owned(),
+                None,
+                doc_id,
+            ),
+        );
+
+        if !self.areas.value.is_empty() {
+            n.style.check_and_insert(
+                "grid-template-areas",
+                ftd::node::Value::from_string(
+                    self.areas
+                        .value
+                        .iter()
+                        .map(|row| format!("'{}'", row))
+                        .collect::<Vec<_>>()
+                        .join(" "),
+                ),
+            );
+        }
+
+        n.style.check_and_insert(
+            "gap",
+            ftd::node::Value::from_executor_value(
+                self.gap
+                    .value
+                    .as_ref()
+                    .map(|v| v.to_css_string(&self.common.device)),
+                self.gap.to_owned(),
+                None,
+                doc_id,
+            ),
+        );
+
+        n.style.check_and_insert(
+            "row-gap",
+            ftd::node::Value::from_executor_value(
+                self.row_gap
+                    .value
+                    .as_ref()
+                    .map(|v| v.to_css_string(&self.common.device)),
+                self.row_gap.to_owned(),
+                None,
+                doc_id,
+            ),
+        );
+
+        n.style.check_and_insert(
+            "column-gap",
+            ftd::node::Value::from_executor_value(
+                self.column_gap
+                    .value
+                    .as_ref()
+                    .map(|v| v.to_css_string(&self.common.device)),
+                self.column_gap.to_owned(),
+                None,
+                doc_id,
+            ),
+        );
+
+        n
+    }
+}
+
 impl ftd::executor::Text {
     pub fn to_node(&self, doc_id: &str, anchor_ids: &mut Vec<String>) -> Node {
         use ftd::node::utils::CheckMap;
@@ -659,6 +786,8 @@ impl ftd::executor::Rive {
             raw_data: None,
             web_component: None,
             device: self.common.device.to_owned(),
+            pseudo_class_styles: self.common.pseudo_class_styles(),
+            keyframes: self.common.keyframes(),
         }
     }
 
@@ -934,6 +1063,48 @@ impl ftd::executor::TextInput {
             ),
         );
 
+        n.attrs.check_and_insert(
+            "min",
+            ftd::node::Value::from_executor_value(
+                self.min.to_owned().value,
+                self.min.to_owned(),
+                None,
+                doc_id,
+            ),
+        );
+
+        n.attrs.check_and_insert(
+            "max",
+            ftd::node::Value::from_executor_value(
+                self.max.to_owned().value,
+                self.max.to_owned(),
+                None,
+                doc_id,
+            ),
+        );
+
+        n.attrs.check_and_insert(
+            "step",
+            ftd::node::Value::from_executor_value(
+                self.step.to_owned().map(|v| v.map(|v| v.to_string())).value,
+                self.step.to_owned(),
+                None,
+                doc_id,
+            ),
+        );
+
+        n.attrs.check_and_insert(
+            "pattern",
+            ftd::node::Value::from_executor_value(
+                self.pattern.to_owned().value,
+                self.pattern.to_owned(),
+                None,
+                doc_id,
+            ),
+        );
+
+        n.attrs.extend(self.field.attrs(doc_id));
+
         n.classes.extend(self.common.add_class());
         n.classes.push("ft_md".to_string());
         n
@@ -993,151 +1164,1152 @@ impl ftd::executor::CheckBox {
             ),
         );
 
+        n.attrs.extend(self.field.attrs(doc_id));
+
         n.classes.extend(self.common.add_class());
         n.classes.push("ft_md".to_string());
         n
     }
 }
 
-impl ftd::executor::Image {
-    pub fn to_node(&self, doc_id: &str, anchor_ids: &mut Vec<String>) -> Node {
-        return if self.common.link.value.is_some() {
-            let mut n = Node::from_common("a", "block", &self.common, doc_id, anchor_ids);
-            n.attrs.insert(
-                s("data-id"),
-                ftd::node::Value::from_string(format!("{}:parent", self.common.data_id).as_str()),
-            );
-
-            let img = update_img(self, doc_id, anchor_ids);
-            n.children.push(img);
-            n
-        } else {
-            update_img(self, doc_id, anchor_ids)
-        };
-
-        fn update_img(
-            image: &ftd::executor::Image,
-            doc_id: &str,
-            anchor_ids: &mut Vec<String>,
-        ) -> Node {
-            let mut n = Node::from_common("img", "block", &image.common, doc_id, anchor_ids);
-            n.classes.extend(image.common.add_class());
-            n.attrs.insert(
-                s("src"),
-                ftd::node::Value::from_executor_value(
-                    Some(image.src.value.light.value.to_string()),
-                    image.src.to_owned(),
-                    None,
-                    doc_id,
-                ),
-            );
-            n.attrs.insert(
-                s("alt"),
-                ftd::node::Value::from_executor_value(
-                    image.alt.to_owned().value,
-                    image.alt.to_owned(),
-                    None,
-                    doc_id,
-                ),
-            );
-            n
-        }
-    }
-}
-
-impl ftd::executor::Common {
-    fn classes(&self) -> Vec<String> {
-        let mut classes = self.classes.to_owned().value;
-        classes.push("ft_common".to_string());
-        classes
-    }
-
+impl ftd::executor::FormField {
     fn attrs(&self, doc_id: &str) -> ftd::Map<ftd::node::Value> {
         use ftd::node::utils::CheckMap;
 
         let mut d: ftd::Map<ftd::node::Value> = Default::default();
 
         d.check_and_insert(
-            "id",
+            "name",
             ftd::node::Value::from_executor_value(
-                self.id.value.to_owned(),
-                self.id.to_owned(),
+                self.name.to_owned().value,
+                self.name.to_owned(),
                 None,
                 doc_id,
             ),
         );
 
         d.check_and_insert(
-            "data-id",
-            ftd::node::Value::from_string(self.data_id.as_str()),
-        );
-
-        d.check_and_insert(
-            "class",
+            "required",
             ftd::node::Value::from_executor_value(
-                Some(self.classes.to_owned().value.join(", ")),
-                self.classes.to_owned(),
-                None,
+                self.required
+                    .to_owned()
+                    .map(|v| {
+                        v.map(|b| {
+                            if b {
+                                s(ftd::interpreter::FTD_NO_VALUE)
+                            } else {
+                                s(ftd::interpreter::FTD_IGNORE_KEY)
+                            }
+                        })
+                    })
+                    .value,
+                self.required.to_owned(),
+                Some(ftd::executor::FormField::required_pattern()),
                 doc_id,
             ),
         );
 
-        d.check_and_insert(
-            "href",
+        d
+    }
+}
+
+impl ftd::executor::Select {
+    pub fn to_node(&self, doc_id: &str, anchor_ids: &mut Vec<String>) -> Node {
+        use ftd::node::utils::CheckMap;
+
+        let mut n = Node::from_common("select", "block", &self.common, doc_id, anchor_ids);
+
+        n.attrs.check_and_insert(
+            "disabled",
             ftd::node::Value::from_executor_value(
-                self.link.value.as_ref().map(ToString::to_string),
-                self.link.to_owned(),
-                None,
+                self.enabled
+                    .to_owned()
+                    .map(|v| {
+                        v.map(|b| {
+                            if b {
+                                s(ftd::interpreter::FTD_IGNORE_KEY)
+                            } else {
+                                s(ftd::interpreter::FTD_NO_VALUE)
+                            }
+                        })
+                    })
+                    .value,
+                self.enabled.to_owned(),
+                Some(ftd::executor::TextInput::enabled_pattern()),
                 doc_id,
             ),
         );
 
-        if self.open_in_new_tab.value.is_some() && self.open_in_new_tab.value.unwrap() {
-            d.check_and_insert(
-                "target",
-                ftd::node::Value::from_executor_value(
-                    Some(ftd::node::utils::escape("_blank")),
-                    self.open_in_new_tab.to_owned(),
-                    Some((s("if ({0}) {\"_blank\"} else {null}"), true)),
-                    doc_id,
-                ),
+        n.attrs.extend(self.field.attrs(doc_id));
+
+        // the options are rendered with the values they have when the page is built
+        let value = self.value.value.as_deref();
+        if let Some(ref placeholder) = self.placeholder.value {
+            let mut attrs: ftd::Map<ftd::node::Value> = Default::default();
+            attrs.insert(s("value"), ftd::node::Value::from_string(""));
+            attrs.insert(
+                s("disabled"),
+                ftd::node::Value::from_string(ftd::interpreter::FTD_NO_VALUE),
             );
+            if value.is_none() {
+                attrs.insert(
+                    s("selected"),
+                    ftd::node::Value::from_string(ftd::interpreter::FTD_NO_VALUE),
+                );
+            }
+            n.children.push(option_node(attrs, placeholder));
+        }
+        for option in self.options.value.iter() {
+            let mut attrs: ftd::Map<ftd::node::Value> = Default::default();
+            attrs.insert(s("value"), ftd::node::Value::from_string(option.value()));
+            if value == Some(option.value()) {
+                attrs.insert(
+                    s("selected"),
+                    ftd::node::Value::from_string(ftd::interpreter::FTD_NO_VALUE),
+                );
+            }
+            n.children.push(option_node(attrs, option.label.as_str()));
         }
 
-        d
+        n.classes.extend(self.common.add_class());
+        n.classes.push("ft_md".to_string());
+        n
     }
+}
 
-    fn style(
-        &self,
-        doc_id: &str,
-        _classes: &mut [String],
-        anchor_ids: &mut Vec<String>,
-    ) -> ftd::Map<ftd::node::Value> {
-        use ftd::node::utils::CheckMap;
-
-        let mut d: ftd::Map<ftd::node::Value> = Default::default();
-
-        if let Some(id) = self.id.value.as_ref() {
-            if anchor_ids.contains(id) {
-                d.check_and_insert("position", ftd::node::Value::from_string("relative"));
+impl ftd::executor::RadioGroup {
+    pub fn to_node(&self, doc_id: &str, anchor_ids: &mut Vec<String>) -> Node {
+        let mut n = Node::from_common("div", "block", &self.common, doc_id, anchor_ids);
+        n.attrs
+            .insert(s("role"), ftd::node::Value::from_string("radiogroup"));
+
+        // the radio buttons of a group share a name, a group without one gets it from its id
+        let name = self
+            .field
+            .name
+            .value
+            .to_owned()
+            .unwrap_or_else(|| format!("ftd-radio-{}", self.common.data_id));
+        let value = self.value.value.as_deref();
+        for option in self.options.value.iter() {
+            let mut attrs: ftd::Map<ftd::node::Value> = Default::default();
+            attrs.insert(s("type"), ftd::node::Value::from_string("radio"));
+            attrs.insert(s("name"), ftd::node::Value::from_string(name.as_str()));
+            attrs.insert(s("value"), ftd::node::Value::from_string(option.value()));
+            if value == Some(option.value()) {
+                attrs.insert(
+                    s("checked"),
+                    ftd::node::Value::from_string(ftd::interpreter::FTD_NO_VALUE),
+                );
+            }
+            if self.enabled.value == Some(false) {
+                attrs.insert(
+                    s("disabled"),
+                    ftd::node::Value::from_string(ftd::interpreter::FTD_NO_VALUE),
+                );
             }
+            if self.field.required.value == Some(true) {
+                attrs.insert(
+                    s("required"),
+                    ftd::node::Value::from_string(ftd::interpreter::FTD_NO_VALUE),
+                );
+            }
+            n.children.push(Node {
+                node: s("label"),
+                display: s("block"),
+                children: vec![
+                    Node {
+                        node: s("input"),
+                        display: s("inline-block"),
+                        attrs,
+                        ..Default::default()
+                    },
+                    Node {
+                        node: s("span"),
+                        display: s("inline"),
+                        text: ftd::node::Value::from_string(ftd::node::utils::escape_html(
+                            option.label.as_str(),
+                        )),
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            });
         }
 
-        if let Some(ftd::executor::Anchor::Id(anchor_id)) = self.anchor.value.as_ref() {
-            anchor_ids.push(anchor_id.clone());
-            d.check_and_insert("position", ftd::node::Value::from_string("absolute"));
-        }
+        n.classes.extend(self.common.add_class());
+        n.classes.push("ft_md".to_string());
+        n
+    }
+}
 
-        if ftd::node::utils::has_click_event(self.event.as_slice()) {
-            d.check_and_insert("cursor", ftd::node::Value::from_string("pointer"));
-        }
+impl ftd::executor::Form {
+    pub fn to_node(&self, doc_id: &str, anchor_ids: &mut Vec<String>) -> Node {
+        use ftd::node::utils::CheckMap;
 
-        if self.is_not_visible {
-            d.check_and_insert("display", ftd::node::Value::from_string("none"));
+        // the controls of a form are laid out like the children of a column
+        let mut n = ftd::executor::Column {
+            container: self.container.to_owned(),
+            common: self.common.to_owned(),
         }
+        .to_node(doc_id, anchor_ids);
+        n.node = s("form");
 
-        d.check_and_insert(
-            "z-index",
+        n.attrs.check_and_insert(
+            "action",
+            ftd::node::Value::from_executor_value(
+                self.action.to_owned().value,
+                self.action.to_owned(),
+                None,
+                doc_id,
+            ),
+        );
+
+        n.attrs.check_and_insert(
+            "method",
+            ftd::node::Value::from_executor_value(
+                self.method.to_owned().value,
+                self.method.to_owned(),
+                None,
+                doc_id,
+            ),
+        );
+
+        n
+    }
+}
+
+impl ftd::executor::Overlay {
+    /// A dialog is a `<dialog>`, a popover or tooltip a `popover` element, both are shown in the
+    /// top layer of the page. `post_init` opens and closes them as `data-open` changes and sets
+    /// the variable in `data-open-variable` to false when they are dismissed.
+    pub fn to_node(
+        &self,
+        component_name: &str,
+        doc_id: &str,
+        anchor_ids: &mut Vec<String>,
+    ) -> Node {
+        use ftd::node::utils::CheckMap;
+
+        let mut n = ftd::executor::Column {
+            container: self.container.to_owned(),
+            common: self.common.to_owned(),
+        }
+        .to_node(doc_id, anchor_ids);
+
+        let (class, default_placement) = match component_name {
+            "ftd#dialog" => ("ft_dialog", None),
+            "ftd#popover" => ("ft_popover", Some(ftd::executor::Placement::Bottom)),
+            _ => ("ft_tooltip", Some(ftd::executor::Placement::Top)),
+        };
+        n.classes.push(s(class));
+
+        if component_name.eq("ftd#dialog") {
+            n.node = s("dialog");
+            n.attrs
+                .check_and_insert("aria-modal", ftd::node::Value::from_string("true"));
+            // a dialog open when the page is built is shown without waiting for the script
+            if self.open.value {
+                n.attrs
+                    .check_and_insert("open", ftd::node::Value::from_string(""));
+            }
+        } else {
+            n.attrs
+                .check_and_insert("popover", ftd::node::Value::from_string("manual"));
+            if component_name.eq("ftd#tooltip") {
+                n.attrs
+                    .check_and_insert("role", ftd::node::Value::from_string("tooltip"));
+            }
+        }
+
+        n.attrs.check_and_insert(
+            "data-open",
+            ftd::node::Value::from_executor_value(
+                Some(self.open.value.to_string()),
+                self.open.to_owned(),
+                Some((s("if ({0}) {\"true\"} else {\"false\"}"), true)),
+                doc_id,
+            ),
+        );
+
+        if let Some(variable) = self
+            .open
+            .properties
+            .first()
+            .and_then(|p| p.value.get_reference_or_clone())
+        {
+            n.attrs.check_and_insert(
+                "data-open-variable",
+                ftd::node::Value::from_string(variable),
+            );
+        }
+
+        for (key, value) in [
+            ("data-close-on-escape", &self.close_on_escape),
+            ("data-close-on-outside-click", &self.close_on_outside_click),
+        ] {
+            n.attrs.check_and_insert(
+                key,
+                ftd::node::Value::from_executor_value(
+                    value.value.map(|v| v.to_string()),
+                    value.to_owned(),
+                    Some((s("if ({0}) {\"true\"} else {\"false\"}"), true)),
+                    doc_id,
+                ),
+            );
+        }
+
+        n.attrs.check_and_insert(
+            "data-trigger",
+            ftd::node::Value::from_executor_value(
+                self.trigger.value.to_owned(),
+                self.trigger.to_owned(),
+                None,
+                doc_id,
+            ),
+        );
+
+        n.attrs.check_and_insert(
+            "data-placement",
+            ftd::node::Value::from_executor_value(
+                self.placement
+                    .value
+                    .as_ref()
+                    .or(default_placement.as_ref())
+                    .map(|v| v.to_css_string()),
+                self.placement.to_owned(),
+                None,
+                doc_id,
+            ),
+        );
+
+        n
+    }
+}
+
+fn option_node(attrs: ftd::Map<ftd::node::Value>, label: &str) -> Node {
+    Node {
+        node: s("option"),
+        display: s("block"),
+        attrs,
+        text: ftd::node::Value::from_string(ftd::node::utils::escape_html(label)),
+        ..Default::default()
+    }
+}
+
+impl ftd::executor::Media {
+    fn attrs(&self, doc_id: &str) -> ftd::Map<ftd::node::Value> {
+        use ftd::node::utils::CheckMap;
+
+        let mut d: ftd::Map<ftd::node::Value> = Default::default();
+
+        d.check_and_insert(
+            "controls",
+            ftd::node::Value::from_executor_value(
+                Some(s(if self.controls.value == Some(false) {
+                    ftd::interpreter::FTD_IGNORE_KEY
+                } else {
+                    ftd::interpreter::FTD_NO_VALUE
+                })),
+                self.controls.to_owned(),
+                Some(ftd::executor::Media::controls_pattern()),
+                doc_id,
+            ),
+        );
+
+        for (key, value) in [
+            ("autoplay", &self.autoplay),
+            ("muted", &self.muted),
+            ("loop", &self.loop_),
+        ] {
+            d.check_and_insert(
+                key,
+                ftd::node::Value::from_executor_value(
+                    value
+                        .to_owned()
+                        .map(|v| {
+                            v.map(|b| {
+                                if b {
+                                    s(ftd::interpreter::FTD_NO_VALUE)
+                                } else {
+                                    s(ftd::interpreter::FTD_IGNORE_KEY)
+                                }
+                            })
+                        })
+                        .value,
+                    value.to_owned(),
+                    Some(ftd::executor::Media::option_pattern()),
+                    doc_id,
+                ),
+            );
+        }
+
+        d
+    }
+
+    /// The `<source>` of each format, the browser plays the first one it supports.
+    fn source_nodes(&self) -> Vec<Node> {
+        self.sources
+            .value
+            .iter()
+            .map(|source| {
+                let mut attrs: ftd::Map<ftd::node::Value> = Default::default();
+                attrs.insert(s("src"), ftd::node::Value::from_string(source.src.as_str()));
+                if let Some(ref type_) = source.type_ {
+                    attrs.insert(s("type"), ftd::node::Value::from_string(type_.as_str()));
+                }
+                Node {
+                    node: s("source"),
+                    attrs,
+                    ..Default::default()
+                }
+            })
+            .collect()
+    }
+}
+
+impl ftd::executor::Video {
+    pub fn to_node(&self, doc_id: &str, anchor_ids: &mut Vec<String>) -> Node {
+        let mut n = Node::from_common("video", "block", &self.common, doc_id, anchor_ids);
+        n.classes.extend(self.common.add_class());
+        n.attrs.extend(self.media.attrs(doc_id));
+
+        if self.poster.value.is_some() {
+            n.attrs.insert(
+                s("poster"),
+                ftd::node::Value::from_executor_value(
+                    self.poster
+                        .value
+                        .as_ref()
+                        .map(|poster| poster.light.value.to_string()),
+                    self.poster.to_owned(),
+                    Some(ftd::executor::ImageSrc::image_pattern()),
+                    doc_id,
+                ),
+            );
+        }
+
+        n.children.extend(self.media.source_nodes());
+        for track in self.captions.value.iter() {
+            let mut attrs: ftd::Map<ftd::node::Value> = Default::default();
+            attrs.insert(s("kind"), ftd::node::Value::from_string("captions"));
+            attrs.insert(s("src"), ftd::node::Value::from_string(track.src.as_str()));
+            if let Some(ref label) = track.label {
+                attrs.insert(s("label"), ftd::node::Value::from_string(label.as_str()));
+            }
+            if let Some(ref lang) = track.lang {
+                attrs.insert(s("srclang"), ftd::node::Value::from_string(lang.as_str()));
+            }
+            if track.default {
+                attrs.insert(
+                    s("default"),
+                    ftd::node::Value::from_string(ftd::interpreter::FTD_NO_VALUE),
+                );
+            }
+            n.children.push(Node {
+                node: s("track"),
+                attrs,
+                ..Default::default()
+            });
+        }
+        n
+    }
+}
+
+impl ftd::executor::Audio {
+    pub fn to_node(&self, doc_id: &str, anchor_ids: &mut Vec<String>) -> Node {
+        let mut n = Node::from_common("audio", "block", &self.common, doc_id, anchor_ids);
+        n.classes.extend(self.common.add_class());
+        n.attrs.extend(self.media.attrs(doc_id));
+        n.children.extend(self.media.source_nodes());
+        n
+    }
+}
+
+impl ftd::executor::Paint {
+    fn styles(&self, doc_id: &str) -> ftd::Map<ftd::node::Value> {
+        use ftd::node::utils::CheckMap;
+
+        let mut d: ftd::Map<ftd::node::Value> = Default::default();
+
+        for (key, value) in [("fill", &self.fill), ("stroke", &self.stroke)] {
+            d.check_and_insert(
+                key,
+                ftd::node::Value::from_executor_value(
+                    value.to_owned().map(|v| v.map(|v| v.to_css_string())).value,
+                    value.to_owned(),
+                    None,
+                    doc_id,
+                ),
+            );
+        }
+
+        d
+    }
+}
+
+impl ftd::executor::Svg {
+    pub fn to_node(&self, doc_id: &str, anchor_ids: &mut Vec<String>) -> Node {
+        // the svg itself is inlined into the wrapper by the build, or else at runtime
+        let mut n = Node::from_common("div", "block", &self.common, doc_id, anchor_ids);
+        n.classes.extend(self.common.add_class());
+        n.classes.push(s("ft_svg"));
+        n.style.extend(self.paint.styles(doc_id));
+        n.attrs.insert(
+            s("data-svg-src"),
+            ftd::node::Value::from_executor_value(
+                Some(self.src.value.light.value.to_string()),
+                self.src.to_owned(),
+                Some(ftd::executor::ImageSrc::image_pattern()),
+                doc_id,
+            ),
+        );
+        n
+    }
+}
+
+impl ftd::executor::Icon {
+    pub fn to_node(&self, doc_id: &str, anchor_ids: &mut Vec<String>) -> Node {
+        let mut n = Node::from_common("div", "block", &self.common, doc_id, anchor_ids);
+        n.classes.extend(self.common.add_class());
+        n.classes.push(s("ft_svg"));
+        n.style.extend(self.paint.styles(doc_id));
+        n.attrs.insert(
+            s("data-icon-name"),
+            ftd::node::Value::from_executor_value(
+                Some(self.name.value.to_string()),
+                self.name.to_owned(),
+                None,
+                doc_id,
+            ),
+        );
+        n.attrs.insert(
+            s("data-icon-sprite"),
+            ftd::node::Value::from_executor_value(
+                Some(self.sprite.value.light.value.to_string()),
+                self.sprite.to_owned(),
+                Some(ftd::executor::ImageSrc::image_pattern()),
+                doc_id,
+            ),
+        );
+
+        let mut use_attrs: ftd::Map<ftd::node::Value> = Default::default();
+        use_attrs.insert(s("href"), ftd::node::Value::from_string(self.href()));
+        let mut svg_attrs: ftd::Map<ftd::node::Value> = Default::default();
+        svg_attrs.insert(s("width"), ftd::node::Value::from_string("100%"));
+        svg_attrs.insert(s("height"), ftd::node::Value::from_string("100%"));
+        n.children.push(Node {
+            node: s("svg"),
+            attrs: svg_attrs,
+            children: vec![Node {
+                node: s("use"),
+                attrs: use_attrs,
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+        n
+    }
+}
+
+impl ftd::executor::Table {
+    pub fn to_node(&self, doc_id: &str, anchor_ids: &mut Vec<String>) -> Node {
+        // the rows are sorted, filtered and paginated in the page, by the text of their cells
+        let mut n = Node::from_common("div", "block", &self.common, doc_id, anchor_ids);
+        n.classes.extend(self.common.add_class());
+        n.classes.push(s("ft_table"));
+
+        if self.filterable.value == Some(true) {
+            let mut attrs: ftd::Map<ftd::node::Value> = Default::default();
+            attrs.insert(s("type"), ftd::node::Value::from_string("search"));
+            attrs.insert(s("placeholder"), ftd::node::Value::from_string("Filter"));
+            attrs.insert(
+                s("aria-label"),
+                ftd::node::Value::from_string("Filter rows"),
+            );
+            n.children.push(Node {
+                node: s("input"),
+                attrs,
+                classes: vec![s("ft_table_filter")],
+                ..Default::default()
+            });
+        }
+
+        let mut table = Node {
+            node: s("table"),
+            ..Default::default()
+        };
+        if let Some(ref title) = self.title.value {
+            table.children.push(Node {
+                node: s("caption"),
+                text: ftd::node::Value::from_string(ftd::node::utils::escape_html(title)),
+                ..Default::default()
+            });
+        }
+
+        let mut header = Node {
+            node: s("tr"),
+            ..Default::default()
+        };
+        for column in self.columns.value.iter() {
+            let mut th = Node {
+                node: s("th"),
+                style: self.column_styles(column),
+                ..Default::default()
+            };
+            if let Some(ref width) = column.width {
+                th.style.insert(
+                    s("width"),
+                    ftd::node::Value::from_string(width.to_css_string(&self.common.device)),
+                );
+            }
+            th.attrs
+                .insert(s("scope"), ftd::node::Value::from_string("col"));
+            th.attrs.insert(
+                s("data-type"),
+                ftd::node::Value::from_string(column.type_.to_css_string()),
+            );
+            let text = ftd::node::Value::from_string(ftd::node::utils::escape_html(
+                column.header.as_str(),
+            ));
+            if column.sortable {
+                let mut attrs: ftd::Map<ftd::node::Value> = Default::default();
+                attrs.insert(s("type"), ftd::node::Value::from_string("button"));
+                th.attrs
+                    .insert(s("aria-sort"), ftd::node::Value::from_string("none"));
+                th.children.push(Node {
+                    node: s("button"),
+                    attrs,
+                    text,
+                    ..Default::default()
+                });
+            } else {
+                th.text = text;
+            }
+            header.children.push(th);
+        }
+        table.children.push(Node {
+            node: s("thead"),
+            children: vec![header],
+            ..Default::default()
+        });
+
+        let mut body = Node {
+            node: s("tbody"),
+            ..Default::default()
+        };
+        let mut cells = self.cells.iter();
+        for row in self.rows.iter() {
+            let mut tr = Node {
+                node: s("tr"),
+                ..Default::default()
+            };
+            for (column, text) in self.columns.value.iter().zip(row.iter()) {
+                let mut td = Node {
+                    node: s("td"),
+                    style: self.column_styles(column),
+                    ..Default::default()
+                };
+                td.attrs.insert(
+                    s("data-sort"),
+                    ftd::node::Value::from_string(ftd::node::utils::escape_html(text)),
+                );
+                if let Some(cell) = cells.next() {
+                    td.children.push(cell.to_node(doc_id, anchor_ids));
+                }
+                tr.children.push(td);
+            }
+            body.children.push(tr);
+        }
+        table.children.push(body);
+        n.children.push(table);
+
+        match (self.page_param.value.as_ref(), self.page_count()) {
+            (Some(param), Some(pages)) => {
+                n.children.push(self.page_links(param.as_str(), pages));
+            }
+            (None, _) => {
+                if let Some(page_size) = self.page_size.value {
+                    n.attrs.insert(
+                        s("data-page-size"),
+                        ftd::node::Value::from_string(page_size.to_string()),
+                    );
+                }
+            }
+            _ => {}
+        }
+        n
+    }
+
+    fn column_styles(&self, column: &ftd::executor::TableColumn) -> ftd::Map<ftd::node::Value> {
+        let mut d: ftd::Map<ftd::node::Value> = Default::default();
+        if let Some(align) = column.text_align() {
+            d.insert(
+                s("text-align"),
+                ftd::node::Value::from_string(align.to_css_string()),
+            );
+        }
+        d
+    }
+
+    /// The links to the previous and the next page of a table paginated by the server, which
+    /// reads the page from the `param` query parameter.
+    fn page_links(&self, param: &str, pages: i64) -> Node {
+        let page = self.page.value.unwrap_or(1).clamp(1, pages);
+        let link = |page: i64, rel: &str, label: &str| {
+            let mut attrs: ftd::Map<ftd::node::Value> = Default::default();
+            attrs.insert(
+                s("href"),
+                ftd::node::Value::from_string(format!("?{}={}", param, page)),
+            );
+            attrs.insert(s("rel"), ftd::node::Value::from_string(rel));
+            Node {
+                node: s("a"),
+                attrs,
+                text: ftd::node::Value::from_string(label),
+                ..Default::default()
+            }
+        };
+
+        let mut nav = Node {
+            node: s("nav"),
+            classes: vec![s("ft_table_pages")],
+            ..Default::default()
+        };
+        nav.attrs
+            .insert(s("aria-label"), ftd::node::Value::from_string("Pages"));
+        if page > 1 {
+            nav.children.push(link(page - 1, "prev", "Previous"));
+        }
+        nav.children.push(Node {
+            node: s("span"),
+            text: ftd::node::Value::from_string(format!("Page {} of {}", page, pages)),
+            ..Default::default()
+        });
+        if page < pages {
+            nav.children.push(link(page + 1, "next", "Next"));
+        }
+        nav
+    }
+}
+
+impl ftd::executor::Image {
+    pub fn to_node(&self, doc_id: &str, anchor_ids: &mut Vec<String>) -> Node {
+        return if self.common.link.value.is_some() {
+            let mut n = Node::from_common("a", "block", &self.common, doc_id, anchor_ids);
+            n.attrs.insert(
+                s("data-id"),
+                ftd::node::Value::from_string(format!("{}:parent", self.common.data_id).as_str()),
+            );
+
+            let img = update_img(self, doc_id, anchor_ids);
+            n.children.push(img);
+            n
+        } else {
+            update_img(self, doc_id, anchor_ids)
+        };
+
+        fn update_img(
+            image: &ftd::executor::Image,
+            doc_id: &str,
+            anchor_ids: &mut Vec<String>,
+        ) -> Node {
+            let mut n = Node::from_common("img", "block", &image.common, doc_id, anchor_ids);
+            n.classes.extend(image.common.add_class());
+            n.attrs.insert(
+                s("src"),
+                ftd::node::Value::from_executor_value(
+                    Some(image.src.value.light.value.to_string()),
+                    image.src.to_owned(),
+                    None,
+                    doc_id,
+                ),
+            );
+            n.attrs.insert(
+                s("alt"),
+                ftd::node::Value::from_executor_value(
+                    image.alt.to_owned().value,
+                    image.alt.to_owned(),
+                    None,
+                    doc_id,
+                ),
+            );
+
+            let src = &image.src.value;
+            if let (Some(width), Some(height)) = (src.width, src.height) {
+                n.attrs
+                    .insert(s("width"), ftd::node::Value::from_string(width));
+                n.attrs
+                    .insert(s("height"), ftd::node::Value::from_string(height));
+                n.attrs
+                    .insert(s("loading"), ftd::node::Value::from_string("lazy"));
+                n.attrs
+                    .insert(s("decoding"), ftd::node::Value::from_string("async"));
+            }
+            // the browser shows the `srcset` image over `src`, so it is only used when `src` can
+            // not change, neither by dark mode nor by a condition or a mutable variable
+            let static_src = src.light.value.eq(&src.dark.value)
+                && image
+                    .src
+                    .properties
+                    .iter()
+                    .all(|p| p.condition.is_none() && !p.value.is_mutable());
+            let sizes = match image.common.width.value {
+                Some(ftd::executor::Resizing::Fixed(ftd::executor::Length::Px(px))) => {
+                    format!("{}px", px)
+                }
+                _ => "100vw".to_string(),
+            };
+            if let Some(srcset) = src.srcset.as_ref().filter(|_| static_src) {
+                n.attrs
+                    .insert(s("srcset"), ftd::node::Value::from_string(srcset));
+                n.attrs
+                    .insert(s("sizes"), ftd::node::Value::from_string(sizes.as_str()));
+            }
+            if let Some(placeholder) = src.placeholder.as_ref() {
+                if !n.style.contains_key("background-image") {
+                    n.style.insert(
+                        s("background-image"),
+                        ftd::node::Value::from_string(format!("url({})", placeholder)),
+                    );
+                    n.style
+                        .insert(s("background-size"), ftd::node::Value::from_string("cover"));
+                }
+            }
+            // `<img srcset>` can not fall back to another format, the `avif` variants are a
+            // `<source>` of a `<picture>` the browsers lacking `avif` skip
+            if let Some(avif_srcset) = src.avif_srcset.as_ref().filter(|_| static_src) {
+                let mut attrs: ftd::Map<ftd::node::Value> = Default::default();
+                attrs.insert(s("type"), ftd::node::Value::from_string("image/avif"));
+                attrs.insert(s("srcset"), ftd::node::Value::from_string(avif_srcset));
+                attrs.insert(s("sizes"), ftd::node::Value::from_string(sizes));
+                let mut picture = Node {
+                    node: s("picture"),
+                    children: vec![
+                        Node {
+                            node: s("source"),
+                            attrs,
+                            ..Default::default()
+                        },
+                        n,
+                    ],
+                    ..Default::default()
+                };
+                // the `<img>` is laid out as if it was not wrapped
+                picture
+                    .style
+                    .insert(s("display"), ftd::node::Value::from_string("contents"));
+                return picture;
+            }
+            n
+        }
+    }
+}
+
+impl ftd::executor::Common {
+    fn classes(&self) -> Vec<String> {
+        let mut classes = self.classes.to_owned().value;
+        classes.push("ft_common".to_string());
+        if self.transition.value.is_some() || self.animation.value.is_some() {
+            classes.push("ftd-motion".to_string());
+        }
+        classes
+    }
+
+    /// The `@keyframes` rule of `animation`, the element refers to it by name.
+    fn keyframes(&self) -> Vec<String> {
+        self.animation
+            .value
+            .iter()
+            .map(|animation| animation.keyframes_rule(&self.device))
+            .collect()
+    }
+
+    /// The css of `color.hover`, `background.focus` etc, these use the values the properties
+    /// have when the page is built.
+    fn pseudo_class_styles(&self) -> Vec<ftd::node::PseudoClassStyle> {
+        let mut styles = vec![];
+        for (state, style) in self.state_styles() {
+            let mut push = |property: &str, light: String, dark: String| {
+                styles.push(ftd::node::PseudoClassStyle {
+                    pseudo_class: state.to_string(),
+                    property: property.to_string(),
+                    dark: Some(dark).filter(|dark| dark.ne(&light)),
+                    light,
+                })
+            };
+            if let Some(color) = style.color.value.as_ref() {
+                push(
+                    "color",
+                    color.light.value.to_css_string(),
+                    color.dark.value.to_css_string(),
+                );
+            }
+            match style.background.value.as_ref() {
+                Some(ftd::executor::Background::Solid(color)) => push(
+                    "background-color",
+                    color.light.value.to_css_string(),
+                    color.dark.value.to_css_string(),
+                ),
+                Some(background) => {
+                    let image = background.to_image_src_css_string(&self.device);
+                    push("background-image", image.clone(), image)
+                }
+                None => {}
+            }
+            if let Some(color) = style.border_color.value.as_ref() {
+                push(
+                    "border-color",
+                    color.light.value.to_css_string(),
+                    color.dark.value.to_css_string(),
+                );
+            }
+            if let Some(shadow) = style.shadow.value.as_ref() {
+                let mut dark_shadow = shadow.clone();
+                dark_shadow.color.value.light = shadow.color.value.dark.clone();
+                push(
+                    "box-shadow",
+                    shadow.to_css_string(&self.device),
+                    dark_shadow.to_css_string(&self.device),
+                );
+            }
+        }
+        styles
+    }
+
+    fn attrs(&self, doc_id: &str) -> ftd::Map<ftd::node::Value> {
+        use ftd::node::utils::CheckMap;
+
+        let mut d: ftd::Map<ftd::node::Value> = Default::default();
+
+        d.check_and_insert(
+            "id",
+            ftd::node::Value::from_executor_value(
+                self.id.value.to_owned(),
+                self.id.to_owned(),
+                None,
+                doc_id,
+            ),
+        );
+
+        d.check_and_insert(
+            "data-id",
+            ftd::node::Value::from_string(self.data_id.as_str()),
+        );
+
+        d.check_and_insert(
+            "class",
+            ftd::node::Value::from_executor_value(
+                Some(self.classes.to_owned().value.join(", ")),
+                self.classes.to_owned(),
+                None,
+                doc_id,
+            ),
+        );
+
+        d.check_and_insert(
+            "href",
+            ftd::node::Value::from_executor_value(
+                self.link.value.as_ref().map(ToString::to_string),
+                self.link.to_owned(),
+                None,
+                doc_id,
+            ),
+        );
+
+        if self.open_in_new_tab.value.is_some() && self.open_in_new_tab.value.unwrap() {
+            d.check_and_insert(
+                "target",
+                ftd::node::Value::from_executor_value(
+                    Some(ftd::node::utils::escape("_blank")),
+                    self.open_in_new_tab.to_owned(),
+                    Some((s("if ({0}) {\"_blank\"} else {null}"), true)),
+                    doc_id,
+                ),
+            );
+        }
+
+        d.extend(self.accessibility_attrs(doc_id));
+
+        d
+    }
+
+    /// `aria-*`, `role` and `tabindex` from the accessibility properties. An `alt` on an element
+    /// other than an image makes it an `img` named by the alt text.
+    fn accessibility_attrs(&self, doc_id: &str) -> ftd::Map<ftd::node::Value> {
+        use ftd::node::utils::CheckMap;
+
+        let accessibility = self.accessibility.as_ref();
+        let mut d: ftd::Map<ftd::node::Value> = Default::default();
+
+        d.check_and_insert(
+            "aria-label",
+            ftd::node::Value::from_executor_value(
+                accessibility.label.value.to_owned(),
+                accessibility.label.to_owned(),
+                None,
+                doc_id,
+            ),
+        );
+
+        d.check_and_insert(
+            "aria-describedby",
+            ftd::node::Value::from_executor_value(
+                accessibility.described_by.value.to_owned(),
+                accessibility.described_by.to_owned(),
+                None,
+                doc_id,
+            ),
+        );
+
+        d.check_and_insert(
+            "aria-hidden",
+            ftd::node::Value::from_executor_value(
+                accessibility.hidden.value.map(|v| v.to_string()),
+                accessibility.hidden.to_owned(),
+                Some((s("if ({0}) {\"true\"} else {\"false\"}"), true)),
+                doc_id,
+            ),
+        );
+
+        d.check_and_insert(
+            "aria-live",
+            ftd::node::Value::from_executor_value(
+                accessibility.live.value.as_ref().map(|v| v.to_css_string()),
+                accessibility.live.to_owned(),
+                None,
+                doc_id,
+            ),
+        );
+
+        d.check_and_insert(
+            "role",
+            ftd::node::Value::from_executor_value(
+                accessibility
+                    .landmark
+                    .value
+                    .as_ref()
+                    .map(|v| v.to_css_string()),
+                accessibility.landmark.to_owned(),
+                None,
+                doc_id,
+            ),
+        );
+
+        d.check_and_insert(
+            "tabindex",
+            ftd::node::Value::from_executor_value(
+                accessibility.tab_index.value.map(|v| v.to_string()),
+                accessibility.tab_index.to_owned(),
+                None,
+                doc_id,
+            ),
+        );
+
+        if accessibility.alt.value.is_some() {
+            if accessibility.landmark.value.is_none() {
+                d.check_and_insert("role", ftd::node::Value::from_string("img"));
+            }
+            if accessibility.label.value.is_none() {
+                d.check_and_insert(
+                    "aria-label",
+                    ftd::node::Value::from_executor_value(
+                        accessibility.alt.value.to_owned(),
+                        accessibility.alt.to_owned(),
+                        None,
+                        doc_id,
+                    ),
+                );
+            }
+        }
+
+        d
+    }
+
+    fn style(
+        &self,
+        doc_id: &str,
+        _classes: &mut [String],
+        anchor_ids: &mut Vec<String>,
+    ) -> ftd::Map<ftd::node::Value> {
+        use ftd::node::utils::CheckMap;
+
+        let mut d: ftd::Map<ftd::node::Value> = Default::default();
+
+        if let Some(id) = self.id.value.as_ref() {
+            if anchor_ids.contains(id) {
+                d.check_and_insert("position", ftd::node::Value::from_string("relative"));
+            }
+        }
+
+        if let Some(ftd::executor::Anchor::Id(anchor_id)) = self.anchor.value.as_ref() {
+            anchor_ids.push(anchor_id.clone());
+            d.check_and_insert("position", ftd::node::Value::from_string("absolute"));
+        }
+
+        if ftd::node::utils::has_click_event(self.event.as_slice()) {
+            d.check_and_insert("cursor", ftd::node::Value::from_string("pointer"));
+        }
+
+        if self.is_not_visible {
+            d.check_and_insert("display", ftd::node::Value::from_string("none"));
+        }
+
+        d.check_and_insert(
+            "grid-area",
+            ftd::node::Value::from_executor_value(
+                self.grid_area.value.to_owned(),
+                self.grid_area.to_owned(),
+                None,
+                doc_id,
+            ),
+        );
+
+        d.check_and_insert(
+            "grid-column-start",
+            ftd::node::Value::from_executor_value(
+                self.column_start.value.as_ref().map(|v| v.to_string()),
+                self.column_start.to_owned(),
+                None,
+                doc_id,
+            ),
+        );
+
+        d.check_and_insert(
+            "grid-column-end",
+            ftd::node::Value::from_executor_value(
+                self.column_span
+                    .value
+                    .as_ref()
+                    .map(|v| format!("span {}", v)),
+                self.column_span.to_owned(),
+                Some((s("span {0}"), false)),
+                doc_id,
+            ),
+        );
+
+        d.check_and_insert(
+            "grid-row-start",
+            ftd::node::Value::from_executor_value(
+                self.row_start.value.as_ref().map(|v| v.to_string()),
+                self.row_start.to_owned(),
+                None,
+                doc_id,
+            ),
+        );
+
+        d.check_and_insert(
+            "grid-row-end",
+            ftd::node::Value::from_executor_value(
+                self.row_span.value.as_ref().map(|v| format!("span {}", v)),
+                self.row_span.to_owned(),
+                Some((s("span {0}"), false)),
+                doc_id,
+            ),
+        );
+
+        d.check_and_insert(
+            "z-index",
             ftd::node::Value::from_executor_value(
                 self.z_index.value.as_ref().map(|v| v.to_string()),
                 self.z_index.to_owned(),
@@ -1201,6 +2373,42 @@ impl ftd::executor::Common {
             }
         }
 
+        d.check_and_insert(
+            "transform",
+            ftd::node::Value::from_executor_value(
+                self.transform
+                    .value
+                    .as_ref()
+                    .map(|v| v.to_css_string(&self.device)),
+                self.transform.to_owned(),
+                Some(ftd::executor::Transform::transform_pattern()),
+                doc_id,
+            ),
+        );
+
+        d.check_and_insert(
+            "transition",
+            ftd::node::Value::from_executor_value(
+                self.transition.value.as_ref().map(|v| v.to_css_string()),
+                self.transition.to_owned(),
+                Some(ftd::executor::Transition::transition_pattern()),
+                doc_id,
+            ),
+        );
+
+        d.check_and_insert(
+            "animation",
+            ftd::node::Value::from_executor_value(
+                self.animation
+                    .value
+                    .as_ref()
+                    .map(|v| v.to_css_string(&self.device)),
+                self.animation.to_owned(),
+                Some(ftd::executor::Animation::animation_pattern()),
+                doc_id,
+            ),
+        );
+
         d.check_and_insert(
             "box-shadow",
             ftd::node::Value::from_executor_value(
@@ -2,6 +2,7 @@
 #[macro_use]
 mod test;
 
+mod accessibility;
 mod main;
 mod node_data;
 mod value;
@@ -9,7 +10,8 @@ mod value;
 mod raw_node;
 pub(crate) mod utils;
 
-pub use main::{Event, HTMLData, Node};
+pub use accessibility::AccessibilityIssue;
+pub use main::{Event, HTMLData, Node, PseudoClassStyle};
 pub use node_data::NodeData;
 pub use raw_node::{DummyNode, RawNode};
 pub use value::{PropertyWithPattern, Value};
@@ -110,6 +110,48 @@ fn node_test_all() {
     }
 }
 
+#[test]
+fn accessibility_issues() {
+    let source = indoc::indoc!(
+        "
+        -- ftd.text: Title
+        region: h1
+
+        -- ftd.text: Section
+        region: h3
+
+        -- ftd.image:
+        src: https://fastn.com/logo.svg
+
+        -- ftd.image:
+        src: https://fastn.com/logo.svg
+        alt: fastn logo
+        link: /
+
+        -- ftd.column:
+        link: /
+
+        -- end: ftd.column
+        "
+    );
+    let doc = interpret_helper("foo", source).unwrap_or_else(|e| panic!("{:?}", e));
+    let executor =
+        ftd::executor::ExecuteDoc::from_interpreter(doc).unwrap_or_else(|e| panic!("{:?}", e));
+    let issues = ftd::node::NodeData::from_rt(executor).accessibility_issues();
+    let issues: Vec<(usize, &str)> = issues
+        .iter()
+        .map(|i| (i.line_number, i.message.as_str()))
+        .collect();
+    assert_eq!(
+        issues,
+        vec![
+            (4, "heading `h3` skips a level after `h1`"),
+            (7, "image has no `alt` text"),
+            (15, "link has no text or `aria-label`"),
+        ]
+    );
+}
+
 fn find_all_files_matching_extension_recursively(
     dir: impl AsRef<std::path::Path>,
     extension: &str,
@@ -58,6 +58,13 @@ pub(crate) fn escape(s: &str) -> String {
     s.replace('&', "\\u0026")
 }
 
+/// `s` as the text of an html element.
+pub(crate) fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
 pub(crate) fn count_children_with_absolute_parent(children: &[ftd::executor::Element]) -> usize {
     children
         .iter()
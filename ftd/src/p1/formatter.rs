@@ -0,0 +1,350 @@
+/// Formats ftd source in the canonical layout.
+///
+/// The document is parsed into `ftd::p1::Section`s and printed back:
+///
+/// - `-- <kind> <name>: <caption>` followed by the inline headers without blank lines,
+/// - a blank line before every block header, before the body and between sections,
+/// - `-- end: <name>` for sections that have children, and for the ones that are ended in
+///   the source.
+///
+/// `;;` comments are not part of the parsed sections, they are re-attached in front of the
+/// section, header or body that follows them in the source, so formatting never drops a
+/// comment. The output parses to the same sections as the input, and formatting it again
+/// gives back the same output.
+pub fn format(content: &str, doc_id: &str) -> ftd::p1::Result<String> {
+    let sections = ftd::p1::parse(content, doc_id)?;
+    let mut printer = Printer::new(content);
+    for section in sections.iter() {
+        printer.section(section);
+    }
+    printer.comments_before(usize::MAX);
+    Ok(printer.finish())
+}
+
+struct Printer<'a> {
+    source: Vec<&'a str>,
+    /// the `;;` lines not yet printed: (1 based line number, line, followed by a blank line)
+    comments: std::collections::VecDeque<(usize, &'a str, bool)>,
+    /// the (1 based) source line up to which the document has been printed
+    cursor: usize,
+    /// the names of the sections being printed, outermost first
+    ancestors: Vec<String>,
+    /// the (1 based) lines of the sections that have an `-- end` in the source
+    ended: std::collections::HashSet<usize>,
+    lines: Vec<String>,
+}
+
+impl<'a> Printer<'a> {
+    fn new(content: &'a str) -> Printer<'a> {
+        let source = content.split('\n').collect::<Vec<_>>();
+        let comments = source
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| line.starts_with(";;"))
+            .map(|(index, line)| {
+                let followed_by_blank = source
+                    .get(index + 1)
+                    .map(|next| next.trim().is_empty())
+                    .unwrap_or(false);
+                (index + 1, line.trim_end(), followed_by_blank)
+            })
+            .collect();
+        let ended = super::parser::section_ends(source.as_slice())
+            .into_iter()
+            .map(|(start, _)| start + 1)
+            .collect();
+        Printer {
+            source,
+            comments,
+            cursor: 0,
+            ancestors: vec![],
+            ended,
+            lines: vec![],
+        }
+    }
+
+    fn finish(mut self) -> String {
+        while self.lines.last().map(|l| l.is_empty()).unwrap_or(false) {
+            self.lines.pop();
+        }
+        let mut output = self.lines.join("\n");
+        output.push('\n');
+        output
+    }
+
+    fn push<S: Into<String>>(&mut self, line: S) {
+        self.lines.push(line.into());
+    }
+
+    fn blank_line(&mut self) {
+        if self.lines.last().map(|l| !l.is_empty()).unwrap_or(false) {
+            self.lines.push("".to_string());
+        }
+    }
+
+    /// Prints the comments that appear in the source before `line_number`.
+    fn comments_before(&mut self, line_number: usize) {
+        self.cursor = self.cursor.max(line_number.min(self.source.len()));
+        while let Some((comment_line, _, _)) = self.comments.front() {
+            if *comment_line >= line_number {
+                break;
+            }
+            let (_, comment, followed_by_blank) = self.comments.pop_front().unwrap();
+            self.push(comment);
+            if followed_by_blank {
+                self.blank_line();
+            }
+        }
+    }
+
+    /// Like `comments_before`, but separated from what was printed before by a blank line.
+    fn comments_before_block(&mut self, line_number: usize) {
+        self.blank_line();
+        self.comments_before(line_number);
+    }
+
+    fn section(&mut self, section: &ftd::p1::Section) {
+        self.comments_before_block(section.line_number);
+
+        let start = format!(
+            "{}-- {}:",
+            if section.is_commented { "/" } else { "" },
+            with_kind(section.kind.as_deref(), section.name.as_str())
+        );
+        let caption = section
+            .caption
+            .as_ref()
+            .and_then(|c| c.get_value(section.name.as_str()).ok().flatten());
+        let block_caption = match caption {
+            Some(ref caption) if caption.contains('\n') => {
+                self.push(start);
+                Some(caption.as_str())
+            }
+            Some(ref caption) => {
+                self.push(format!("{} {}", start, caption));
+                None
+            }
+            None => {
+                self.push(start);
+                None
+            }
+        };
+
+        let (inline, block): (Vec<_>, Vec<_>) =
+            section.headers.0.iter().partition(|h| is_inline_header(h));
+        for header in inline {
+            if let ftd::p1::Header::KV(kv) = header {
+                self.comments_before(kv.line_number);
+                self.push(inline_kv(kv));
+            }
+        }
+
+        let mut has_block_headers = false;
+        if let Some(caption) = block_caption {
+            let line_number = section
+                .caption
+                .as_ref()
+                .map(|c| self.last_content_line(c.get_line_number()))
+                .unwrap_or_default();
+            self.comments_before_block(line_number);
+            self.push(format!("-- {}.caption:", section.name));
+            self.value(caption);
+            has_block_headers = true;
+        }
+        for header in block {
+            self.block_header(section.name.as_str(), header);
+            has_block_headers = true;
+        }
+
+        if let Some(ref body) = section.body {
+            let line_number = self.last_content_line(body.line_number);
+            self.comments_before_block(line_number);
+            if has_block_headers {
+                self.push(format!("-- {}.body:", section.name));
+            }
+            self.value(body.value.as_str());
+        }
+
+        // `-- end: foo` closes the closest open `foo`, so a `foo` without children still needs
+        // its `-- end` when it is nested in another `foo`
+        if section.sub_sections.is_empty()
+            && !self.ancestors.contains(&section.name)
+            && !self.ended.contains(&section.line_number)
+        {
+            return;
+        }
+        self.ancestors.push(section.name.to_string());
+        for sub_section in section.sub_sections.iter() {
+            self.section(sub_section);
+        }
+        self.ancestors.pop();
+        self.end(section.name.as_str());
+    }
+
+    fn block_header(&mut self, section_name: &str, header: &ftd::p1::Header) {
+        match header {
+            ftd::p1::Header::KV(kv) => {
+                let line_number = match kv.source {
+                    ftd::p1::header::KVSource::Body => self.last_content_line(kv.line_number),
+                    _ => kv.line_number,
+                };
+                self.comments_before_block(line_number);
+                let key = format!("{}.{}", section_name, kv.key);
+                let kind = kv_kind(kv);
+                match kv.value {
+                    Some(ref value) if !matches!(kv.source, ftd::p1::header::KVSource::Body) => {
+                        self.push(format!(
+                            "-- {}: {}",
+                            with_kind(kind.as_deref(), &key),
+                            value
+                        ));
+                        self.condition(kv.condition.as_deref());
+                    }
+                    ref value => {
+                        self.push(format!("-- {}:", with_kind(kind.as_deref(), &key)));
+                        self.condition(kv.condition.as_deref());
+                        if let Some(value) = value {
+                            self.value(value);
+                        }
+                    }
+                }
+            }
+            ftd::p1::Header::Section(s) => {
+                let line_number = s
+                    .section
+                    .first()
+                    .map(|v| v.line_number)
+                    .unwrap_or(s.line_number);
+                self.comments_before_block(line_number);
+                let key = format!("{}.{}", section_name, s.key);
+                self.push(format!("-- {}:", with_kind(s.kind.as_deref(), &key)));
+                self.condition(s.condition.as_deref());
+                for section in s.section.iter() {
+                    self.section(section);
+                }
+                self.end(key.as_str());
+            }
+            ftd::p1::Header::BlockRecordHeader(b) => {
+                self.comments_before_block(b.line_number);
+                let key = format!("{}.{}", section_name, b.key);
+                let mut start = format!("-- {}:", with_kind(b.kind.as_deref(), &key));
+                if let Some(ref caption) = b.caption {
+                    start.push_str(format!(" {}", caption).as_str());
+                }
+                self.push(start);
+                self.condition(b.condition.as_deref());
+
+                let (inline, block): (Vec<_>, Vec<_>) =
+                    b.fields.iter().partition(|h| is_inline_header(h));
+                for field in inline {
+                    if let ftd::p1::Header::KV(kv) = field {
+                        self.comments_before(kv.line_number);
+                        self.push(inline_kv(kv));
+                    }
+                }
+                if let (Some(ref body), line_number) = b.body {
+                    let line_number = self.last_content_line(line_number.unwrap_or_default());
+                    self.comments_before(line_number);
+                    self.value(body);
+                }
+                for field in block {
+                    self.block_header(key.as_str(), field);
+                }
+            }
+        }
+    }
+
+    fn condition(&mut self, condition: Option<&str>) {
+        if let Some(condition) = condition {
+            self.push(format!("{}: {}", ftd::p1::utils::IF, condition));
+        }
+    }
+
+    /// A multi-line value (body, block caption or header value) after a blank line, lines
+    /// that would otherwise be read as a comment or a section are escaped.
+    fn value(&mut self, value: &str) {
+        self.blank_line();
+        for line in value.split('\n') {
+            if line.starts_with(";;") || line.starts_with("-- ") {
+                self.push(format!("\\{}", line));
+            } else {
+                self.push(line.trim_end());
+            }
+        }
+    }
+
+    fn end(&mut self, name: &str) {
+        let cursor = self.cursor;
+        let end_line = self
+            .source
+            .iter()
+            .enumerate()
+            .skip(cursor)
+            .find(|(_, line)| {
+                line.trim()
+                    .strip_prefix("-- end:")
+                    .map(|v| v.trim().eq(name))
+                    .unwrap_or(false)
+            })
+            .map(|(index, _)| index + 1);
+        if let Some(end_line) = end_line {
+            self.comments_before_block(end_line);
+        }
+        self.blank_line();
+        self.push(format!("-- end: {}", name));
+    }
+
+    /// Values and bodies carry the line number of the last line read for them, which
+    /// includes the blank and comment lines before the next section. Those comments belong
+    /// to what follows, so this finds the last line of the value itself.
+    fn last_content_line(&self, line_number: usize) -> usize {
+        let mut line_number = line_number.min(self.source.len());
+        while line_number > 1 {
+            let line = self.source[line_number - 1];
+            if !line.trim().is_empty() && !line.starts_with(";;") {
+                break;
+            }
+            line_number -= 1;
+        }
+        line_number
+    }
+}
+
+fn is_inline_header(header: &ftd::p1::Header) -> bool {
+    matches!(
+        header,
+        ftd::p1::Header::KV(ftd::p1::header::KV {
+            source: ftd::p1::header::KVSource::Header,
+            ..
+        })
+    )
+}
+
+fn inline_kv(kv: &ftd::p1::header::KV) -> String {
+    let mut line = with_kind(kv_kind(kv).as_deref(), kv.key.as_str());
+    if let Some(ref condition) = kv.condition {
+        line.push_str(format!("{}{}", ftd::p1::utils::INLINE_IF, condition).as_str());
+    }
+    line.push(':');
+    if let Some(ref value) = kv.value {
+        line.push_str(format!(" {}", value).as_str());
+    }
+    line
+}
+
+/// The kind of a header as written in the source, `KV` keeps the access modifier apart.
+fn kv_kind(kv: &ftd::p1::header::KV) -> Option<String> {
+    match (&kv.kind, &kv.access_modifier) {
+        (Some(kind), ftd::p1::header::AccessModifier::Private) => {
+            Some(format!("private {}", kind).trim().to_string())
+        }
+        (kind, _) => kind.clone(),
+    }
+}
+
+fn with_kind(kind: Option<&str>, name: &str) -> String {
+    match kind {
+        Some(kind) if !kind.is_empty() => format!("{} {}", kind, name),
+        _ => name.to_string(),
+    }
+}
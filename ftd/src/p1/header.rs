@@ -58,6 +58,10 @@ pub struct KV {
     pub condition: Option<String>,
     pub access_modifier: AccessModifier,
     pub source: KVSource,
+    /// the span of the value of the header, or of its key if the value is not on the same
+    /// line, recorded by the parser
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub span: Option<ftd::diagnostic::Span>,
 }
 
 impl KV {
@@ -84,6 +88,7 @@ impl KV {
             condition,
             access_modifier,
             source: source.unwrap_or_default(),
+            span: None,
         }
     }
 }
@@ -213,6 +218,7 @@ impl Header {
             Header::KV(kv) => {
                 let mut kv = (*kv).clone();
                 kv.line_number = 0;
+                kv.span = None;
                 Header::KV(kv)
             }
             Header::Section(s) => {
@@ -293,6 +299,7 @@ impl Header {
                 ),
                 doc_id: doc_id.to_string(),
                 line_number: self.get_line_number(),
+                span: None,
             }),
             Header::BlockRecordHeader(_) => Err(ftd::p1::Error::ParseError {
                 message: format!(
@@ -301,6 +308,7 @@ impl Header {
                 ),
                 doc_id: doc_id.to_string(),
                 line_number: self.get_line_number(),
+                span: None,
             }),
         }
     }
@@ -314,6 +322,7 @@ impl Header {
                 ),
                 doc_id: doc_id.to_string(),
                 line_number: self.get_line_number(),
+                span: None,
             }),
             Header::Section(ftd::p1::header::Section { section, .. }) => Ok(section),
         }
@@ -427,12 +436,14 @@ impl Headers {
             key: key.to_string(),
             doc_id: doc_id.to_string(),
             line_number,
+            span: None,
         })?;
         if headers.len() > 1 {
             return Err(ftd::p1::Error::MoreThanOneHeader {
                 key: key.to_string(),
                 doc_id: doc_id.to_string(),
                 line_number: header.get_line_number(),
+                span: None,
             });
         }
         Ok(header)
@@ -451,6 +462,7 @@ impl Headers {
                 key: key.to_string(),
                 doc_id: doc_id.to_string(),
                 line_number,
+                span: None,
             })
     }
 
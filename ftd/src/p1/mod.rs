@@ -2,29 +2,40 @@
 #[macro_use]
 mod test;
 
+mod formatter;
 pub(crate) mod header;
 mod parser;
 mod section;
 pub mod utils;
 
+pub use formatter::format;
 pub use header::{Header, Headers, Section as HSection, KV};
-pub use parser::{parse, parse_with_line_number};
+pub use parser::{parse, parse_with_errors, parse_with_line_number};
 pub use section::Body;
 pub use section::Section;
 
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
     #[error("{doc_id}:{line_number} -> SectionNotFound")]
-    SectionNotFound { doc_id: String, line_number: usize },
+    SectionNotFound {
+        doc_id: String,
+        line_number: usize,
+        span: Option<ftd::diagnostic::Span>,
+    },
 
     #[error("{doc_id}:{line_number} -> MoreThanOneCaption")]
-    MoreThanOneCaption { doc_id: String, line_number: usize },
+    MoreThanOneCaption {
+        doc_id: String,
+        line_number: usize,
+        span: Option<ftd::diagnostic::Span>,
+    },
 
     #[error("{doc_id}:{line_number} -> {message}")]
     ParseError {
         message: String,
         doc_id: String,
         line_number: usize,
+        span: Option<ftd::diagnostic::Span>,
     },
 
     #[error("{doc_id}:{line_number} -> MoreThanOneHeader for key {key}")]
@@ -32,6 +43,7 @@ pub enum Error {
         key: String,
         doc_id: String,
         line_number: usize,
+        span: Option<ftd::diagnostic::Span>,
     },
 
     #[error("{doc_id}:{line_number} -> HeaderNotFound for key {key}")]
@@ -39,6 +51,7 @@ pub enum Error {
         key: String,
         doc_id: String,
         line_number: usize,
+        span: Option<ftd::diagnostic::Span>,
     },
 
     #[error("forbidden usage: {message}, line_number: {line_number}, doc: {doc_id}")]
@@ -75,4 +88,33 @@ pub enum Error {
     },
 }
 
+impl Error {
+    /// Sets the span of the error, unless it already has one, `f` is called with the line
+    /// number of the error.
+    pub(crate) fn or_span<F>(mut self, f: F) -> Error
+    where
+        F: FnOnce(usize) -> Option<ftd::diagnostic::Span>,
+    {
+        match &mut self {
+            Error::SectionNotFound {
+                line_number, span, ..
+            }
+            | Error::MoreThanOneCaption {
+                line_number, span, ..
+            }
+            | Error::ParseError {
+                line_number, span, ..
+            }
+            | Error::MoreThanOneHeader {
+                line_number, span, ..
+            }
+            | Error::HeaderNotFound {
+                line_number, span, ..
+            } if span.is_none() => *span = f(*line_number),
+            _ => {}
+        }
+        self
+    }
+}
+
 pub type Result<T> = std::result::Result<T, Error>;
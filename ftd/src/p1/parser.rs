@@ -20,6 +20,152 @@ pub struct State {
     content: String,
     doc_id: String,
     state: Vec<(ftd::p1::Section, Vec<ParsingStateReading>)>,
+    source: SourceLines,
+}
+
+/// The lines of the content being parsed, kept to give the errors a span.
+#[derive(Debug)]
+struct SourceLines {
+    lines: Vec<String>,
+    /// the byte offset of each line from the start of line 1 of the document, `None` for the
+    /// lines before it
+    starts: Vec<Option<usize>>,
+    /// the line number of the line before the first one
+    line_number: i32,
+}
+
+impl SourceLines {
+    /// `byte_offset` is the offset of `content` from the start of line 1 of the document,
+    /// negative if `content` starts before it.
+    fn new(content: &str, line_number: i32, byte_offset: isize) -> SourceLines {
+        let lines = content
+            .split('\n')
+            .map(|v| v.to_string())
+            .collect::<Vec<String>>();
+        let mut starts = vec![];
+        let mut start = byte_offset;
+        for line in lines.iter() {
+            starts.push(usize::try_from(start).ok());
+            start += line.len() as isize + 1;
+        }
+        SourceLines {
+            lines,
+            starts,
+            line_number,
+        }
+    }
+
+    /// The span of the last occurrence of `token` on `line_number`, or of the whole line
+    /// (without its indentation) if the token is not given or not on the line.
+    fn span(&self, line_number: usize, token: Option<&str>) -> Option<ftd::diagnostic::Span> {
+        let index = usize::try_from(line_number as i32 - self.line_number - 1).ok()?;
+        let line = self.lines.get(index)?;
+        let start = (*self.starts.get(index)?)?;
+        let (offset, len) = match token
+            .filter(|v| !v.is_empty())
+            .and_then(|v| line.rfind(v).map(|offset| (offset, v.len())))
+        {
+            Some(found) => found,
+            None => {
+                let trimmed = line.trim_start();
+                (line.len() - trimmed.len(), trimmed.trim_end().len().max(1))
+            }
+        };
+        Some(ftd::diagnostic::Span {
+            start: start + offset,
+            end: start + offset + len,
+            line_number,
+            column: line[..offset].chars().count() + 1,
+        })
+    }
+
+    /// The span from byte `offset` of `line_number` to `len` bytes after it, or to the end of
+    /// the line (without trailing whitespace) if `len` is not given.
+    fn span_from(
+        &self,
+        line_number: usize,
+        offset: usize,
+        len: Option<usize>,
+    ) -> Option<ftd::diagnostic::Span> {
+        let index = usize::try_from(line_number as i32 - self.line_number - 1).ok()?;
+        let line = self.lines.get(index)?;
+        let start = (*self.starts.get(index)?)?;
+        let len = len.unwrap_or_else(|| line.trim_end().len().saturating_sub(offset).max(1));
+        Some(ftd::diagnostic::Span {
+            start: start + offset,
+            end: start + offset + len,
+            line_number,
+            column: line.get(..offset)?.chars().count() + 1,
+        })
+    }
+
+    /// The span of the last occurrence of `value` on `line_number`, if it is there.
+    fn value_span(&self, line_number: usize, value: &str) -> Option<ftd::diagnostic::Span> {
+        let index = usize::try_from(line_number as i32 - self.line_number - 1).ok()?;
+        let offset = self
+            .lines
+            .get(index)?
+            .rfind(value)
+            .filter(|_| !value.is_empty())?;
+        self.span_from(line_number, offset, Some(value.len()))
+    }
+
+    /// The byte offset of `name` on `line_number` where it is written as the name of a
+    /// section or the key of a header: after the `-- ` of a section line and any kind, and
+    /// followed by the `:` (or the condition).
+    fn name_offset(&self, line_number: usize, name: &str) -> Option<usize> {
+        let index = usize::try_from(line_number as i32 - self.line_number - 1).ok()?;
+        let line = self.lines.get(index)?;
+        let from = line.find("-- ").map(|v| v + 3).unwrap_or_default();
+        line.get(from..)?
+            .match_indices(name)
+            .map(|(offset, _)| from + offset)
+            .find(|offset| {
+                let before = line[..*offset].chars().next_back();
+                let after = line[offset + name.len()..].chars().next();
+                matches!(before, None | Some(' ' | '.' | '-'))
+                    && matches!(after, None | Some(':' | ' '))
+            })
+    }
+
+    /// Records the spans of `sections`, their headers and sub-sections, see
+    /// `ftd::p1::Section::span` and `ftd::p1::header::KV::span`.
+    fn record_spans(&self, sections: &mut [ftd::p1::Section]) {
+        for section in sections.iter_mut() {
+            section.span = self
+                .name_offset(section.line_number, section.name.as_str())
+                .and_then(|offset| self.span_from(section.line_number, offset, None));
+            if let Some(ref mut caption) = section.caption {
+                self.record_header_spans(caption);
+            }
+            for header in section.headers.0.iter_mut() {
+                self.record_header_spans(header);
+            }
+            self.record_spans(section.sub_sections.as_mut_slice());
+        }
+    }
+
+    fn record_header_spans(&self, header: &mut ftd::p1::Header) {
+        match header {
+            ftd::p1::Header::KV(kv) => {
+                kv.span = kv
+                    .value
+                    .as_ref()
+                    .and_then(|v| v.lines().next())
+                    .and_then(|value| self.value_span(kv.line_number, value.trim()))
+                    .or_else(|| {
+                        let offset = self.name_offset(kv.line_number, kv.key.as_str())?;
+                        self.span_from(kv.line_number, offset, Some(kv.key.len()))
+                    });
+            }
+            ftd::p1::Header::Section(s) => self.record_spans(s.section.as_mut_slice()),
+            ftd::p1::Header::BlockRecordHeader(b) => {
+                for field in b.fields.iter_mut() {
+                    self.record_header_spans(field);
+                }
+            }
+        }
+    }
 }
 
 impl State {
@@ -32,7 +178,8 @@ impl State {
             let mut change_state = None;
             self.end(&mut change_state)?;
 
-            if self.content.trim().is_empty() {
+            // the rest of the document can only be comments and blank lines
+            if self.clean_content().1.trim().is_empty() {
                 let sections = self.state.iter().map(|(v, _)| v.clone()).collect_vec();
                 self.state = vec![];
                 self.sections.extend(sections);
@@ -85,10 +232,13 @@ impl State {
             self.doc_id.as_str(),
         )?;
         if is_end(name.as_str()) {
+            let end_line_number =
+                ftd::p1::utils::i32_to_usize(self.line_number + (scan_line_number as i32) + 1);
             let caption = caption.ok_or_else(|| ftd::p1::Error::ParseError {
                 message: "section name not provided for `end`".to_string(),
                 doc_id: self.doc_id.to_string(),
-                line_number: ftd::p1::utils::i32_to_usize(self.line_number),
+                line_number: end_line_number,
+                span: None,
             })?;
             let mut sections = vec![];
             loop {
@@ -100,14 +250,17 @@ impl State {
                         ftd::p1::Error::ParseError {
                             message: format!("No section found to end: {}", caption),
                             doc_id: self.doc_id.to_string(),
-                            line_number: ftd::p1::utils::i32_to_usize(self.line_number),
+                            line_number: end_line_number,
+                            span: self.source.span(end_line_number, Some(caption.as_str())),
                         }
                     })?;
                     sections.push(section);
                     continue;
                 };
                 match state {
-                    ParsingStateReading::Section if caption.eq(section.name.as_str()) => {
+                    ParsingStateReading::Section
+                        if ends(caption.as_str(), section.name.as_str()) =>
+                    {
                         sections.reverse();
                         section.sub_sections.extend(sections);
                         *change_state = None;
@@ -175,6 +328,7 @@ impl State {
                     line_number: ftd::p1::utils::i32_to_usize(
                         self.line_number + (scan_line_number as i32) + 1,
                     ),
+                    span: None,
                 })
             };
         }
@@ -216,6 +370,7 @@ impl State {
                         line_number: ftd::p1::utils::i32_to_usize(
                             self.line_number + (scan_line_number as i32) + 1,
                         ),
+                        span: None,
                     });
                 }
             }
@@ -238,6 +393,7 @@ impl State {
             is_commented,
             line_number: ftd::p1::utils::i32_to_usize(self.line_number),
             block_body: false,
+            span: None,
         };
 
         self.state
@@ -354,6 +510,7 @@ impl State {
                 .ok_or_else(|| ftd::p1::Error::SectionNotFound {
                     doc_id: self.doc_id.to_string(),
                     line_number: ftd::p1::utils::i32_to_usize(self.line_number),
+                    span: None,
                 })?;
 
         let header_not_found_next_state = if !section.block_body {
@@ -423,6 +580,7 @@ impl State {
             return Err(ftd::p1::Error::MoreThanOneCaption {
                 doc_id: self.doc_id.to_string(),
                 line_number: section.line_number,
+                span: None,
             });
         }
 
@@ -489,6 +647,7 @@ impl State {
                             message: format!("start section body '{}' after a newline!!", line),
                             doc_id: self.doc_id.to_string(),
                             line_number: ftd::p1::utils::i32_to_usize(self.line_number),
+                            span: None,
                         });
                     }
                     first_line = false;
@@ -531,6 +690,7 @@ impl State {
                 .ok_or(ftd::p1::Error::SectionNotFound {
                     doc_id: doc_id.clone(),
                     line_number: header_line_number,
+                    span: None,
                 })?
                 .0;
             let value = (value.0.join("\n").trim().to_string(), value.1);
@@ -601,6 +761,7 @@ impl State {
                         message: format!("start section caption '{}' after a newline!!", line),
                         doc_id: self.doc_id.to_string(),
                         line_number: ftd::p1::utils::i32_to_usize(self.line_number),
+                        span: None,
                     });
                 }
                 first_line = false;
@@ -615,6 +776,7 @@ impl State {
             .ok_or(ftd::p1::Error::SectionNotFound {
                 doc_id,
                 line_number: ftd::p1::utils::i32_to_usize(line_number),
+                span: None,
             })?
             .0;
 
@@ -646,6 +808,7 @@ impl State {
                         message: format!("start section body '{}' after a newline!!", line),
                         doc_id: self.doc_id.to_string(),
                         line_number: ftd::p1::utils::i32_to_usize(self.line_number),
+                        span: None,
                     });
                 }
                 first_line = false;
@@ -661,6 +824,7 @@ impl State {
             .ok_or(ftd::p1::Error::SectionNotFound {
                 doc_id,
                 line_number: ftd::p1::utils::i32_to_usize(line_number),
+                span: None,
             })?
             .0;
         let value = value.join("\n").trim().to_string();
@@ -721,6 +885,7 @@ impl State {
             .ok_or(ftd::p1::Error::SectionNotFound {
                 doc_id,
                 line_number: ftd::p1::utils::i32_to_usize(line_number),
+                span: None,
             })?
             .0;
         section.headers.0.extend(headers);
@@ -752,6 +917,7 @@ impl State {
                     message: format!("`{}` section state is not yet empty", section.name),
                     doc_id: self.doc_id.to_string(),
                     line_number: ftd::p1::utils::i32_to_usize(self.line_number),
+                    span: None,
                 });
             }
         }
@@ -803,22 +969,237 @@ pub fn parse_with_line_number(
     content: &str,
     doc_id: &str,
     line_number: usize,
+) -> ftd::p1::Result<Vec<ftd::p1::Section>> {
+    let prefix = content.split('\n').take(line_number).collect::<Vec<&str>>();
+    parse_from(
+        content,
+        doc_id,
+        -(line_number as i32),
+        -byte_len(prefix.as_slice()),
+    )
+}
+
+/// Parses `content` and keeps going after a failure, so that every independent error in the
+/// document is reported in one run.
+///
+/// When the document parses cleanly the result is the same as `parse_with_line_number`.
+/// Otherwise the document is split into its top level sections (a section along with its
+/// block headers, children and `-- end`), each of which is parsed on its own. A section that
+/// fails is parsed again without its children, and each child on its own the same way, so an
+/// error deep inside a component only drops the innermost section it is in. The errors are
+/// returned alongside the sections that did parse.
+pub fn parse_with_errors(
+    content: &str,
+    doc_id: &str,
+    line_number: usize,
+) -> (Vec<ftd::p1::Section>, Vec<ftd::p1::Error>) {
+    let lines = content.split('\n').collect::<Vec<&str>>();
+    let prefix = &lines[..line_number.min(lines.len())];
+    parse_lines_with_errors(
+        lines.as_slice(),
+        doc_id,
+        -(line_number as i32),
+        -byte_len(prefix),
+    )
+}
+
+fn parse_lines_with_errors(
+    lines: &[&str],
+    doc_id: &str,
+    line_number: i32,
+    byte_offset: isize,
+) -> (Vec<ftd::p1::Section>, Vec<ftd::p1::Error>) {
+    let error = match parse_from(lines.join("\n").as_str(), doc_id, line_number, byte_offset) {
+        Ok(sections) => return (sections, vec![]),
+        Err(e) => e,
+    };
+
+    let starts = section_starts(lines, None);
+    if starts.len() > 1 {
+        let mut sections = vec![];
+        let mut errors = vec![];
+        for (index, start) in starts.iter().enumerate() {
+            // the lines before the first section (comments, blank lines) stay with it
+            let from = if index == 0 { 0 } else { *start };
+            let to = starts.get(index + 1).copied().unwrap_or(lines.len());
+            let (s, e) = parse_lines_with_errors(
+                &lines[from..to],
+                doc_id,
+                line_number + from as i32,
+                byte_offset + byte_len(&lines[..from]),
+            );
+            sections.extend(s);
+            errors.extend(e);
+        }
+        return (sections, errors);
+    }
+
+    match parse_children_with_errors(lines, doc_id, line_number, byte_offset) {
+        Some((section, errors)) if !errors.is_empty() => (vec![section], errors),
+        _ => (vec![], vec![error]),
+    }
+}
+
+/// `lines` hold a single section that failed to parse. Parses the section without its
+/// children, then its children on their own. Returns `None` if the section has no children,
+/// or fails without them.
+fn parse_children_with_errors(
+    lines: &[&str],
+    doc_id: &str,
+    line_number: i32,
+    byte_offset: isize,
+) -> Option<(ftd::p1::Section, Vec<ftd::p1::Error>)> {
+    let start = *section_starts(lines, None).first()?;
+    let end = section_ends(lines)
+        .into_iter()
+        .find_map(|(s, e)| (s == start).then_some(e))?;
+    let first_child = *section_starts(lines, Some(start)).first()?;
+
+    let mut sections = parse_from(
+        lines[..first_child].join("\n").as_str(),
+        doc_id,
+        line_number,
+        byte_offset,
+    )
+    .ok()?;
+    if sections.len() != 1 {
+        return None;
+    }
+    let mut section = sections.pop()?;
+    let (sub_sections, errors) = parse_lines_with_errors(
+        &lines[first_child..end],
+        doc_id,
+        line_number + first_child as i32,
+        byte_offset + byte_len(&lines[..first_child]),
+    );
+    section.sub_sections = sub_sections;
+    Some((section, errors))
+}
+
+fn parse_from(
+    content: &str,
+    doc_id: &str,
+    line_number: i32,
+    byte_offset: isize,
 ) -> ftd::p1::Result<Vec<ftd::p1::Section>> {
     let mut state = State {
         content: content.to_string(),
         doc_id: doc_id.to_string(),
-        line_number: if line_number > 0 {
-            -(line_number as i32)
-        } else {
-            0
-        },
+        line_number,
         sections: Default::default(),
         state: Default::default(),
+        source: SourceLines::new(content, line_number, byte_offset),
     };
-    state.next()?;
+    state
+        .next()
+        .map_err(|e| e.or_span(|line_number| state.source.span(line_number, None)))?;
+    state.source.record_spans(state.sections.as_mut_slice());
     Ok(state.sections)
 }
 
+/// The number of bytes in `lines`, counting the new line after each of them.
+fn byte_len(lines: &[&str]) -> isize {
+    lines.iter().map(|v| v.len() as isize + 1).sum()
+}
+
+/// Returns the (0 based) indices of the lines that start a top level section, or the
+/// children of the section that starts at line `parent`.
+///
+/// A `-- <name>:` line is a section unless it is a block header of the section before it
+/// (`-- <section>.<header>:`). It is top level (or a child of `parent`) unless it sits between
+/// another section and its matching `-- end: <name>`.
+fn section_starts(lines: &[&str], parent: Option<usize>) -> Vec<usize> {
+    let enclosed = section_ends(lines);
+    let within = match parent {
+        Some(parent) => match enclosed.iter().find(|(start, _)| *start == parent) {
+            Some((_, end)) => Some((parent, *end)),
+            None => return vec![],
+        },
+        None => None,
+    };
+    section_lines(lines)
+        .into_iter()
+        .filter_map(|(index, line)| match line {
+            SectionLine::Start(_) => Some(index),
+            SectionLine::End(_) => None,
+        })
+        .filter(|index| match within {
+            Some((start, end)) => start < *index && *index < end,
+            None => true,
+        })
+        .filter(|index| {
+            // the sections that enclose `parent` enclose its children as well
+            !enclosed.iter().any(|(start, end)| {
+                within.map_or(true, |(parent, _)| *start > parent) && start < index && index <= end
+            })
+        })
+        .collect()
+}
+
+/// Returns the (0 based) line indices of every section that is closed by an `-- end`, along
+/// with the index of that `-- end`. Like the parser, an `-- end: <name>` closes the closest
+/// open section of that name.
+pub(super) fn section_ends(lines: &[&str]) -> Vec<(usize, usize)> {
+    let mut ended = vec![];
+    // (section name, line index) of the sections that may still get an `-- end`
+    let mut open: Vec<(String, usize)> = vec![];
+    for (index, line) in section_lines(lines) {
+        match line {
+            SectionLine::Start(name) => open.push((name, index)),
+            SectionLine::End(name) => {
+                if let Some(position) = open.iter().rposition(|(n, _)| ends(name, n)) {
+                    ended.push((open[position].1, index));
+                    open.truncate(position);
+                }
+            }
+        }
+    }
+    ended
+}
+
+enum SectionLine<'a> {
+    Start(String),
+    End(&'a str),
+}
+
+/// The `-- <name>:` and `-- end: <name>` lines, leaving out the block headers of a section
+/// (`-- <section>.<header>:`).
+fn section_lines<'a>(lines: &[&'a str]) -> Vec<(usize, SectionLine<'a>)> {
+    let mut section_lines = vec![];
+    let mut last_section: Option<String> = None;
+
+    for (index, line) in lines.iter().enumerate() {
+        let line = line.trim_start();
+        let line = if let Some(line) = line.strip_prefix("-- ") {
+            line
+        } else if let Some(line) = line.strip_prefix("/-- ") {
+            line
+        } else {
+            continue;
+        };
+        let (name_with_kind, value) = match line.split_once(':') {
+            Some((name_with_kind, value)) => (name_with_kind.trim(), value.trim()),
+            None => continue,
+        };
+
+        if is_end(name_with_kind) {
+            section_lines.push((index, SectionLine::End(value)));
+            continue;
+        }
+
+        let (name, _) = get_name_and_kind(name_with_kind);
+        if let Some(ref section) = last_section {
+            if name.starts_with(format!("{}.", section).as_str()) {
+                continue;
+            }
+        }
+        section_lines.push((index, SectionLine::Start(name.to_string())));
+        last_section = Some(name);
+    }
+
+    section_lines
+}
+
 fn colon_separated_values(
     line_number: usize,
     line: &str,
@@ -830,6 +1211,7 @@ fn colon_separated_values(
             // TODO: context should be a few lines before and after the input
             doc_id: doc_id.to_string(),
             line_number,
+            span: None,
         });
     }
 
@@ -846,7 +1228,7 @@ fn colon_separated_values(
 }
 
 fn get_name_and_kind(name_with_kind: &str) -> (String, Option<String>) {
-    if let Some((kind, name)) = name_with_kind.rsplit_once(' ') {
+    if let Some((kind, name)) = split_kind_and_name(name_with_kind) {
         return (name.to_string(), Some(kind.to_string()));
     }
 
@@ -861,13 +1243,28 @@ fn get_name_kind_and_condition(name_with_kind: &str) -> (String, Option<String>,
     } else {
         (name_with_kind.to_string(), None)
     };
-    if let Some((kind, name)) = name_with_kind.rsplit_once(' ') {
+    if let Some((kind, name)) = split_kind_and_name(name_with_kind.as_str()) {
         return (name.to_string(), Some(kind.to_string()), condition);
     }
 
     (name_with_kind, None, condition)
 }
 
+/// Splits `record paginated<K, V>` at the last space that is not inside the angle brackets of
+/// type parameters, so the name keeps them: `("record", "paginated<K, V>")`.
+fn split_kind_and_name(name_with_kind: &str) -> Option<(&str, &str)> {
+    let mut depth = 0;
+    for (i, c) in name_with_kind.char_indices().rev() {
+        match c {
+            '>' => depth += 1,
+            '<' if depth > 0 => depth -= 1,
+            ' ' if depth == 0 => return Some((&name_with_kind[..i], &name_with_kind[i + 1..])),
+            _ => {}
+        }
+    }
+    None
+}
+
 fn clean_line(line: &str) -> String {
     if line.starts_with("\\;;") || line.starts_with("\\-- ") {
         return line[1..].to_string();
@@ -887,6 +1284,12 @@ fn is_body(s: &str) -> bool {
     s.eq("body")
 }
 
+/// Whether `-- end: <end>` ends the section `name`. The type parameters of a generic
+/// component can be left out: `-- end: list-view` ends `-- component list-view<T>:`.
+fn ends(end: &str, name: &str) -> bool {
+    end.eq(name) || name.split_once('<').map_or(false, |(name, _)| end.eq(name))
+}
+
 fn is_end(s: &str) -> bool {
     s.eq("end")
 }
@@ -14,6 +14,7 @@ use itertools::Itertools;
  * - `is_commented`: A boolean representing whether the section is commented or not
  * - `line_number`: A usize representing the line number where the section starts in the document
  * - `block_body`: A boolean representing whether the section body is present as a block
+ * - `span`: The span of the name and caption of the section, set by the parser
  *
  */
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Default)]
@@ -28,6 +29,8 @@ pub struct Section {
     pub is_commented: bool,
     pub line_number: usize,
     pub block_body: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub span: Option<ftd::diagnostic::Span>,
 }
 
 impl Section {
@@ -42,6 +45,7 @@ impl Section {
             line_number: 0,
             headers: ftd::p1::Headers(vec![]),
             block_body: false,
+            span: None,
         }
     }
 
@@ -71,6 +75,7 @@ impl Section {
             is_commented: self.is_commented.to_owned(),
             line_number: 0,
             block_body: false,
+            span: None,
         }
     }
 
@@ -166,8 +171,48 @@ impl Section {
             is_commented: false,
             line_number: self.line_number,
             block_body: self.block_body,
+            span: self.span,
         })
     }
+
+    /// The spans the parser recorded for this section, its headers and its sub-sections,
+    /// along with the line number of each, in the order they are in the document.
+    pub fn spans(&self) -> Vec<(usize, ftd::diagnostic::Span)> {
+        fn header_spans(header: &ftd::p1::Header, spans: &mut Vec<(usize, ftd::diagnostic::Span)>) {
+            match header {
+                ftd::p1::Header::KV(kv) => spans.extend(kv.span.map(|v| (kv.line_number, v))),
+                ftd::p1::Header::Section(s) => {
+                    spans.extend(s.section.iter().flat_map(|v| v.spans()))
+                }
+                ftd::p1::Header::BlockRecordHeader(b) => {
+                    b.fields.iter().for_each(|v| header_spans(v, spans))
+                }
+            }
+        }
+
+        let mut spans = self
+            .span
+            .map(|v| (self.line_number, v))
+            .into_iter()
+            .collect_vec();
+        if let Some(ref caption) = self.caption {
+            header_spans(caption, &mut spans);
+        }
+        self.headers
+            .0
+            .iter()
+            .for_each(|v| header_spans(v, &mut spans));
+        spans.extend(self.sub_sections.iter().flat_map(|v| v.spans()));
+        spans
+    }
+
+    /// The span of the section or header on `line_number`, the section itself if its name is
+    /// on that line.
+    pub fn span_at(&self, line_number: usize) -> Option<ftd::diagnostic::Span> {
+        self.spans()
+            .into_iter()
+            .find_map(|(l, span)| (l == line_number).then_some(span))
+    }
 }
 
 #[derive(Debug, PartialEq, Clone, Default, serde::Serialize, serde::Deserialize)]
@@ -940,3 +940,234 @@ fn kind() {
             .list(),
     );
 }
+
+#[test]
+fn parse_with_errors() {
+    let source = indoc!(
+        "
+        -- foo: one
+
+        -- bar:
+        x
+
+        -- column:
+
+        -- baz:
+        y
+
+        -- qux: three
+
+        -- end: column
+
+        -- moo: two
+        "
+    );
+    let (sections, errors) = super::parse_with_errors(source, "foo", 0);
+    assert_eq!(
+        sections
+            .iter()
+            .map(|v| (v.name.as_str(), v.line_number))
+            .collect::<Vec<_>>(),
+        vec![("foo", 1), ("column", 6), ("moo", 15)]
+    );
+    assert_eq!(
+        sections[1]
+            .sub_sections
+            .iter()
+            .map(|v| (v.name.as_str(), v.line_number))
+            .collect::<Vec<_>>(),
+        vec![("qux", 11)]
+    );
+    assert_eq!(
+        errors.iter().map(|v| v.to_string()).collect::<Vec<_>>(),
+        vec![
+            "foo:4 -> start section body 'x' after a newline!!".to_string(),
+            "foo:9 -> start section body 'y' after a newline!!".to_string()
+        ]
+    );
+    assert_eq!(
+        errors[0].to_diagnostic().render(Some(source)),
+        indoc!(
+            "
+            error: start section body 'x' after a newline!!
+             --> foo:4:1
+              |
+            4 | x
+              | ^
+            "
+        )
+    );
+}
+
+#[test]
+fn error_span() {
+    let source = indoc!(
+        "
+        -- import: fastn
+
+        -- ftd.text: hello
+
+        -- end: ftd.column
+        "
+    );
+    let error = super::parse_with_line_number(source, "foo", 2).unwrap_err();
+    let content = source.split('\n').skip(2).collect::<Vec<_>>().join("\n");
+    assert_eq!(
+        error.to_diagnostic().render(Some(content.as_str())),
+        indoc!(
+            "
+            error: No section found to end: ftd.column
+             --> foo:3:9
+              |
+            3 | -- end: ftd.column
+              |         ^^^^^^^^^^
+            "
+        )
+    );
+}
+
+#[test]
+fn section_spans() {
+    let source = indoc!(
+        "
+        -- string text: text
+        padding.px if { flag }: 10
+
+        -- ftd.text: $text
+        "
+    );
+    let sections = super::parse(source, "foo").unwrap();
+    let spans = sections
+        .iter()
+        .flat_map(|v| v.spans())
+        .map(|(line_number, span)| (line_number, span.column, &source[span.start..span.end]))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        spans,
+        vec![
+            (1, 11, "text: text"),
+            (1, 17, "text"),
+            (2, 25, "10"),
+            (4, 4, "ftd.text: $text"),
+            (4, 14, "$text"),
+        ]
+    );
+}
+
+#[test]
+fn format() {
+    let source = indoc!(
+        "
+        ;; The page
+
+        --   ftd.column  :
+        width:fill-container
+        ;; no padding
+        padding.px if { flag }:   0
+
+
+        -- ftd.column.id: main
+        if: { flag }
+
+        -- ftd.text:   hello
+
+        \\;; not a comment
+
+
+
+        -- end: ftd.column
+        ;; trailing
+        "
+    );
+    let formatted = super::format(source, "foo").unwrap();
+    assert_eq!(
+        formatted,
+        indoc!(
+            "
+            ;; The page
+
+            -- ftd.column:
+            width: fill-container
+            ;; no padding
+            padding.px if { flag }: 0
+
+            -- ftd.column.id: main
+            if: { flag }
+
+            -- ftd.text: hello
+
+            \\;; not a comment
+
+            -- end: ftd.column
+            ;; trailing
+            "
+        )
+    );
+    assert_eq!(super::format(formatted.as_str(), "foo").unwrap(), formatted);
+}
+
+#[test]
+fn format_keeps_end() {
+    let source = indoc!(
+        "
+        -- ftd.column:
+        width: fill-container
+        -- end: ftd.column
+
+        -- ftd.text: hello
+        "
+    );
+    let formatted = super::format(source, "foo").unwrap();
+    assert_eq!(
+        formatted,
+        indoc!(
+            "
+            -- ftd.column:
+            width: fill-container
+
+            -- end: ftd.column
+
+            -- ftd.text: hello
+            "
+        )
+    );
+    assert_eq!(super::format(formatted.as_str(), "foo").unwrap(), formatted);
+}
+
+/// Formatting keeps the parsed sections and comments of every document of the `t/p1` and
+/// `t/ast` corpus, and formatting the output again does not change it.
+#[test]
+fn format_test_all() {
+    let mut files = find_all_files_matching_extension_recursively("t/p1", "ftd");
+    files.extend(find_all_files_matching_extension_recursively(
+        "t/ast", "ftd",
+    ));
+    files.sort();
+    for f in files {
+        let s = std::fs::read_to_string(&f).unwrap();
+        let sections = match super::parse(&s, "foo") {
+            Ok(sections) => sections,
+            Err(_) => continue,
+        };
+        println!("testing {}", f.display());
+        let formatted = super::format(&s, "foo").unwrap_or_else(|e| panic!("{:?}", e));
+        let formatted_sections = super::parse(&formatted, "foo")
+            .unwrap_or_else(|e| panic!("{:?}\n{}", e, formatted))
+            .iter()
+            .map(|v| v.without_line_number())
+            .collect::<Vec<ftd::p1::Section>>();
+        assert_eq!(
+            sections
+                .iter()
+                .map(|v| v.without_line_number())
+                .collect::<Vec<ftd::p1::Section>>(),
+            formatted_sections,
+            "{}",
+            formatted
+        );
+        for comment in s.lines().filter(|l| l.starts_with(";;")) {
+            assert!(formatted.contains(comment.trim_end()), "{}", comment);
+        }
+        assert_eq!(super::format(&formatted, "foo").unwrap(), formatted);
+    }
+}
@@ -41,7 +41,17 @@ window.ftd = (function() {
                             obj_value = null;
                             obj_checked = null;
                         }
-                        let value = resolve_reference(function_argument.reference, ftd_data[id], obj_value, obj_checked);
+                        if (obj_value === undefined && !!evt && !!evt.target) {
+                            // the radio button that changed in a radio group
+                            obj_value = (<HTMLInputElement>evt.target).value;
+                        }
+                        let obj_form = null;
+                        if (function_argument.reference === "FORM") {
+                            obj_form = window.ftd.utils.form_values(obj);
+                        }
+                        // the playback position of a video or an audio
+                        let obj_time = function_argument.reference === "TIME" ? (<HTMLMediaElement>obj).currentTime : null;
+                        let value = resolve_reference(function_argument.reference, ftd_data[id], obj_value, obj_checked, obj_form, obj_time);
                         if (!!function_argument.mutable) {
                             function_argument.value = value;
                             function_arguments.push(function_argument);
@@ -71,6 +81,10 @@ window.ftd = (function() {
     exports.handle_event = function (evt: Event, id: string, event: string, obj: Element) {
         window.ftd.utils.reset_full_height();
         console_log(id, event);
+        if (!!evt && evt.type === "submit") {
+            // the form is handled by its `submit` event instead of being sent to its `action`
+            evt.preventDefault();
+        }
         let actions = JSON.parse(event);
         for (const action in actions) {
             handle_event(evt, id, actions[action], obj);
@@ -154,6 +168,16 @@ window.ftd = (function() {
         return (!str || str.length === 0 );
     }
 
+    // a value of an or-type defined in an ftd document is an object with its variant as the
+    // only key
+    exports.is_variant = function(value: any, variant: string) {
+        return !!value && variant in value;
+    }
+
+    exports.variant_value = function(value: any, variant: string) {
+        return value[variant];
+    }
+
     exports.set_list = function(array: any[], value: any[], args: any, data: any, id: string) {
         args["CHANGE_VALUE"]= false;
         window.ftd.clear(array, args, data, id);
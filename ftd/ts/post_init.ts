@@ -8,10 +8,8 @@ window.ftd.post_init = function () {
     const COOKIE_DARK_MODE = "dark";
     const COOKIE_LIGHT_MODE = "light";
     const DARK_MODE_CLASS = "fpm-dark";
-    const MOBILE_CLASS = "ftd-mobile";
-    const XL_CLASS = "ftd-xl";
     const FTD_DEVICE = "ftd#device";
-    const FTD_BREAKPOINT_WIDTH = "ftd#breakpoint-width";
+    const FTD_BREAKPOINTS = "ftd#breakpoints";
     let last_device: string;
 
    function initialise_device() {
@@ -96,44 +94,22 @@ window.ftd.post_init = function () {
    }*/
 
    function get_device() {
-       // not at all sure about this functions logic.
        let width = window.innerWidth;
-
-       // in future we may want to have more than one break points, and then
-       // we may also want the theme builders to decide where the breakpoints
-       // should go. we should be able to fetch fpm variables here, or maybe
-       // simply pass the width, user agent etc to fpm and let people put the
-       // checks on width user agent etc, but it would be good if we can
-       // standardize few breakpoints. or maybe we should do both, some
-       // standard breakpoints and pass the raw data.
-
-       // we would then rename this function to detect_device() which will
-       // return one of "desktop", "tablet", "mobile". and also maybe have
-       // another function detect_orientation(), "landscape" and "portrait" etc,
-       // and instead of setting `fpm#mobile: boolean` we set `fpm-ui#device`
-       // and `fpm#view-port-orientation` etc.
-       let mobile_breakpoint = window.ftd.get_value("main", FTD_BREAKPOINT_WIDTH + ".mobile");
-       if (width <= mobile_breakpoint) {
-           document.body.classList.add(MOBILE_CLASS);
-           if (document.body.classList.contains(XL_CLASS)) {
-               document.body.classList.remove(XL_CLASS);
+       // the breakpoints go from the narrowest to the widest, the device is the first one the
+       // window is not wider than, the body gets a `ftd-<device>` class for it
+       let breakpoints = window.ftd.get_value("main", FTD_BREAKPOINTS) || [];
+       let device = "desktop";
+       for (let breakpoint of breakpoints) {
+           let max_width = breakpoint["max-width"];
+           if (max_width === null || max_width === undefined || width <= max_width) {
+               device = breakpoint.name;
+               break;
            }
-           return "mobile";
        }
-       /*if (width > desktop_breakpoint) {
-           document.body.classList.add(XL_CLASS);
-           if (document.body.classList.contains(MOBILE_CLASS)) {
-               document.body.classList.remove(MOBILE_CLASS);
-           }
-           return "xl";
-       }*/
-       if (document.body.classList.contains(MOBILE_CLASS)) {
-           document.body.classList.remove(MOBILE_CLASS);
+       for (let breakpoint of breakpoints) {
+           document.body.classList.toggle("ftd-" + breakpoint.name, device === breakpoint.name);
        }
-       /*if (document.body.classList.contains(XL_CLASS)) {
-           document.body.classList.remove(XL_CLASS);
-       }*/
-       return "desktop";
+       return device;
    }
 
     /*
@@ -256,8 +232,349 @@ window.ftd.post_init = function () {
             "change", update_dark_mode
         );
     }
+
+    function inline_svg(wrapper: HTMLElement) {
+        let src = wrapper.getAttribute("data-svg-src");
+        if (!src) {
+            return;
+        }
+        fetch(src)
+            .then(response => response.text())
+            .then(text => {
+                // the src may have changed while the svg was being fetched
+                if (wrapper.getAttribute("data-svg-src") === src) {
+                    wrapper.innerHTML = text;
+                }
+            })
+            .catch(error => console_log("failed to fetch svg", src, error));
+    }
+
+    function update_icon(wrapper: HTMLElement) {
+        let use = wrapper.querySelector("use");
+        if (!!use) {
+            use.setAttribute(
+                "href",
+                `${wrapper.getAttribute("data-icon-sprite")}#${wrapper.getAttribute("data-icon-name")}`
+            );
+        }
+    }
+
+    function initialise_svgs() {
+        // the build inlines the svgs into the page, only the ones it could not are fetched
+        document.querySelectorAll<HTMLElement>("[data-svg-src]").forEach(wrapper => {
+            if (!wrapper.firstElementChild) {
+                inline_svg(wrapper);
+            }
+        });
+        new MutationObserver(mutations => {
+            for (let mutation of mutations) {
+                let wrapper = <HTMLElement>mutation.target;
+                if (mutation.attributeName === "data-svg-src") {
+                    inline_svg(wrapper);
+                } else {
+                    update_icon(wrapper);
+                }
+            }
+        }).observe(document.body, {
+            subtree: true,
+            attributeFilter: ["data-svg-src", "data-icon-name", "data-icon-sprite"],
+        });
+    }
+
+    function compare_cells(type: string | null, a: string, b: string): number {
+        if (type === "number") {
+            return (parseFloat(a) || 0) - (parseFloat(b) || 0);
+        }
+        if (type === "date") {
+            return (Date.parse(a) || 0) - (Date.parse(b) || 0);
+        }
+        return a.localeCompare(b);
+    }
+
+    function initialise_table(table: HTMLElement) {
+        let body = table.querySelector("tbody");
+        if (!body) {
+            return;
+        }
+        let tbody = body;
+        // the rows in their current order, the text of a cell is in its `data-sort`
+        let rows = Array.from(tbody.rows);
+        let text = (row: HTMLTableRowElement, index: number) =>
+            (row.cells[index] && row.cells[index].getAttribute("data-sort")) || "";
+        let filter = table.querySelector<HTMLInputElement>(".ft_table_filter");
+        let page_size = parseInt(table.getAttribute("data-page-size") || "0") || 0;
+        let page = 0;
+        let nav: HTMLElement | null = null;
+        if (page_size > 0) {
+            nav = document.createElement("nav");
+            nav.className = "ft_table_pages";
+            nav.setAttribute("aria-label", "Pages");
+            table.appendChild(nav);
+        }
+
+        let update = function () {
+            let query = !!filter ? filter.value.trim().toLowerCase() : "";
+            let matching = rows.filter(row => !query || Array.from(row.cells).some(
+                (_, index) => text(row, index).toLowerCase().includes(query)
+            ));
+            let pages = page_size > 0 ? Math.max(1, Math.ceil(matching.length / page_size)) : 1;
+            page = Math.min(page, pages - 1);
+            rows.forEach(row => row.hidden = true);
+            matching.forEach((row, index) => {
+                row.hidden = page_size > 0 && Math.floor(index / page_size) !== page;
+            });
+            if (!nav) {
+                return;
+            }
+            let button = (label: string, to: number, enabled: boolean) => {
+                let button = document.createElement("button");
+                button.type = "button";
+                button.textContent = label;
+                button.disabled = !enabled;
+                button.onclick = () => {
+                    page = to;
+                    update();
+                };
+                return button;
+            };
+            let current = document.createElement("span");
+            current.textContent = `Page ${page + 1} of ${pages}`;
+            nav.innerHTML = "";
+            nav.append(
+                button("Previous", page - 1, page > 0),
+                current,
+                button("Next", page + 1, page < pages - 1),
+            );
+        };
+
+        table.querySelectorAll<HTMLTableCellElement>("th[aria-sort]").forEach(th => {
+            let button = th.querySelector("button");
+            if (!button) {
+                return;
+            }
+            button.addEventListener("click", () => {
+                let ascending = th.getAttribute("aria-sort") !== "ascending";
+                table.querySelectorAll("th[aria-sort]").forEach(
+                    other => other.setAttribute("aria-sort", "none")
+                );
+                th.setAttribute("aria-sort", ascending ? "ascending" : "descending");
+                let type = th.getAttribute("data-type");
+                rows.sort((a, b) => {
+                    let order = compare_cells(type, text(a, th.cellIndex), text(b, th.cellIndex));
+                    return ascending ? order : -order;
+                });
+                rows.forEach(row => tbody.appendChild(row));
+                page = 0;
+                update();
+            });
+        });
+        if (!!filter) {
+            filter.addEventListener("input", () => {
+                page = 0;
+                update();
+            });
+        }
+        update();
+    }
+
+    function initialise_tables() {
+        document.querySelectorAll<HTMLElement>(".ft_table").forEach(initialise_table);
+    }
+
+    function overlay_focusable(overlay: HTMLElement): HTMLElement[] {
+        return Array.from(overlay.querySelectorAll<HTMLElement>(
+            "a[href], button:not([disabled]), input:not([disabled]), select:not([disabled]), "
+            + "textarea:not([disabled]), [tabindex]:not([tabindex='-1'])"
+        ));
+    }
+
+    // places a popover or a tooltip on the `data-placement` side of its trigger, on the opposite
+    // side when there is no room there, and inside the window
+    function position_overlay(overlay: HTMLElement, trigger: HTMLElement) {
+        let gap = 8;
+        let target = trigger.getBoundingClientRect();
+        let rect = overlay.getBoundingClientRect();
+        let opposite: Record<string, string> = {top: "bottom", bottom: "top", left: "right", right: "left"};
+        let place = (side: string): number[] => {
+            if (side === "top") {
+                return [target.top - rect.height - gap, target.left + (target.width - rect.width) / 2];
+            } else if (side === "bottom") {
+                return [target.bottom + gap, target.left + (target.width - rect.width) / 2];
+            } else if (side === "left") {
+                return [target.top + (target.height - rect.height) / 2, target.left - rect.width - gap];
+            }
+            return [target.top + (target.height - rect.height) / 2, target.right + gap];
+        };
+        let fits = (position: number[]) => position[0] >= 0 && position[1] >= 0
+            && position[0] + rect.height <= window.innerHeight
+            && position[1] + rect.width <= window.innerWidth;
+        let placement = overlay.getAttribute("data-placement") || "bottom";
+        let position = place(placement);
+        if (!fits(position) && !!opposite[placement] && fits(place(opposite[placement]))) {
+            position = place(opposite[placement]);
+        }
+        overlay.style.top = `${Math.max(gap, Math.min(position[0], window.innerHeight - rect.height - gap))}px`;
+        overlay.style.left = `${Math.max(gap, Math.min(position[1], window.innerWidth - rect.width - gap))}px`;
+    }
+
+    function initialise_overlay(overlay: HTMLElement) {
+        let is_dialog = overlay.classList.contains("ft_dialog");
+        let is_tooltip = overlay.classList.contains("ft_tooltip");
+        let trigger_id = overlay.getAttribute("data-trigger");
+        let trigger = !!trigger_id ? document.getElementById(trigger_id) : null;
+        let is_trigger = (target: EventTarget | null) => !!trigger_id && target instanceof Element
+            && !!target.closest(`#${CSS.escape(trigger_id)}`);
+        let is_open = () => overlay.getAttribute("data-open") === "true";
+        let opened_at = 0;
+        let restore_focus: Element | null = null;
+
+        // the `open` variable is the source of truth, the attribute follows it
+        let set_open = (open: boolean) => {
+            let variable = overlay.getAttribute("data-open-variable");
+            if (!!variable) {
+                window.ftd.set_bool_for_all(variable, open);
+            } else {
+                overlay.setAttribute("data-open", open ? "true" : "false");
+            }
+        };
+
+        let update = () => {
+            if (!!trigger && !is_dialog && !!overlay.id) {
+                if (is_tooltip) {
+                    trigger.setAttribute("aria-describedby", overlay.id);
+                } else {
+                    trigger.setAttribute("aria-expanded", is_open() ? "true" : "false");
+                    trigger.setAttribute("aria-controls", overlay.id);
+                }
+            }
+            let shown = overlay.matches(is_dialog ? ":modal" : ":popover-open");
+            if (is_open()) {
+                if (!shown) {
+                    opened_at = performance.now();
+                    if (!is_tooltip) {
+                        restore_focus = document.activeElement;
+                    }
+                    if (is_dialog) {
+                        let dialog = overlay as HTMLDialogElement;
+                        // a dialog open in the rendered page is not modal yet
+                        if (dialog.open) {
+                            dialog.close();
+                        }
+                        dialog.showModal();
+                    } else {
+                        overlay.showPopover();
+                        if (!is_tooltip) {
+                            let first = overlay_focusable(overlay)[0];
+                            if (!!first) {
+                                first.focus();
+                            }
+                        }
+                    }
+                }
+                if (!!trigger && !is_dialog) {
+                    position_overlay(overlay, trigger);
+                }
+                return;
+            }
+            if (is_dialog) {
+                let dialog = overlay as HTMLDialogElement;
+                if (dialog.open) {
+                    dialog.close();
+                }
+            } else if (shown) {
+                overlay.hidePopover();
+            }
+            let restore = restore_focus;
+            restore_focus = null;
+            if (shown && restore instanceof HTMLElement && restore.isConnected) {
+                restore.focus();
+            }
+        };
+
+        new MutationObserver(update).observe(overlay, {attributes: true, attributeFilter: ["data-open"]});
+
+        document.addEventListener("keydown", (e) => {
+            if (!is_open()) {
+                return;
+            }
+            if (e.key === "Escape" && overlay.getAttribute("data-close-on-escape") !== "false") {
+                e.preventDefault();
+                set_open(false);
+            } else if (e.key === "Tab" && is_dialog) {
+                // Tab does not leave an open dialog
+                let elements = overlay_focusable(overlay);
+                if (elements.length === 0) {
+                    e.preventDefault();
+                    return;
+                }
+                let first = elements[0];
+                let last = elements[elements.length - 1];
+                let outside = !overlay.contains(document.activeElement);
+                if (e.shiftKey && (document.activeElement === first || outside)) {
+                    e.preventDefault();
+                    last.focus();
+                } else if (!e.shiftKey && (document.activeElement === last || outside)) {
+                    e.preventDefault();
+                    first.focus();
+                }
+            }
+        });
+
+        let close_on_outside_click = overlay.getAttribute("data-close-on-outside-click") !== "false";
+        if (is_dialog) {
+            // the browser closes a modal dialog on Escape by itself, `open` decides instead
+            overlay.addEventListener("cancel", (e) => e.preventDefault());
+            // a click on the backdrop of a modal dialog has the dialog as its target
+            overlay.addEventListener("click", (e) => {
+                let rect = overlay.getBoundingClientRect();
+                let inside = rect.top <= e.clientY && e.clientY <= rect.bottom
+                    && rect.left <= e.clientX && e.clientX <= rect.right;
+                if (e.target === overlay && !inside && close_on_outside_click) {
+                    set_open(false);
+                }
+            });
+        } else if (is_tooltip) {
+            let hover = (open: boolean) => (e: Event) => {
+                let related = (e as MouseEvent | FocusEvent).relatedTarget;
+                if (is_trigger(e.target) && !is_trigger(related)) {
+                    set_open(open);
+                }
+            };
+            document.addEventListener("mouseover", hover(true));
+            document.addEventListener("mouseout", hover(false));
+            document.addEventListener("focusin", hover(true));
+            document.addEventListener("focusout", hover(false));
+        } else {
+            document.addEventListener("click", (e) => {
+                if (is_trigger(e.target)) {
+                    set_open(!is_open());
+                } else if (is_open() && close_on_outside_click && e.timeStamp >= opened_at
+                    && e.target instanceof Node && !overlay.contains(e.target)) {
+                    set_open(false);
+                }
+            });
+        }
+
+        let position = () => {
+            if (!!trigger && !is_dialog && is_open()) {
+                position_overlay(overlay, trigger);
+            }
+        };
+        window.addEventListener("resize", position);
+        window.addEventListener("scroll", position, true);
+        update();
+    }
+
+    function initialise_overlays() {
+        document.querySelectorAll<HTMLElement>(".ft_dialog, .ft_popover, .ft_tooltip")
+            .forEach(initialise_overlay);
+    }
+
     initialise_dark_mode();
     initialise_device();
+    initialise_svgs();
+    initialise_tables();
+    initialise_overlays();
     window.ftd.utils.set_full_height();
     // update_markdown_colors();
 };
@@ -125,13 +125,19 @@ function set_data_value(data: any, name: string, value: any) {
     }
 }
 
-function resolve_reference(reference: string, data: any, value: any, checked: any) {
+function resolve_reference(reference: string, data: any, value: any, checked: any, form?: any, time?: any) {
     if (reference === "VALUE") {
         return value;
     }
     if (reference === "CHECKED") {
         return checked;
     }
+    if (reference === "FORM") {
+        return form;
+    }
+    if (reference === "TIME") {
+        return time;
+    }
     if (!!data[reference]) {
         return deepCopy(data[reference]);
     }
@@ -203,6 +209,24 @@ window.ftd.utils.reset_full_height = function () {
     document.body.style.height = `100%`;
 };
 
+// the values of the named controls of a form, by name
+window.ftd.utils.form_values = function (form: HTMLFormElement) {
+    let values: any = {};
+    for (let element of <any>form.elements) {
+        if (!element.name || (element.type === "radio" && !element.checked)) {
+            continue;
+        }
+        if (element.type === "checkbox") {
+            values[element.name] = element.checked;
+        } else if (element.type === "number" || element.type === "range") {
+            values[element.name] = element.value === "" ? null : Number(element.value);
+        } else {
+            values[element.name] = element.value === "" ? null : element.value;
+        }
+    }
+    return values;
+};
+
 window.ftd.utils.get_event_key = function (event: any) {
     if (65 <= event.keyCode && event.keyCode <= 90) {
         return String.fromCharCode(event.keyCode).toLowerCase();
@@ -379,6 +403,71 @@ window.ftd.dependencies.eval_box_shadow = function(shadow: any, data: any) {
     }
 }
 
+window.ftd.dependencies.eval_transform = function(transform: any, data: any) {
+    if (typeof transform === 'object' && !!transform) {
+        let functions = [];
+        if ("translate-x" in transform || "translate-y" in transform) {
+            let x = transform["translate-x"] || "0px";
+            let y = transform["translate-y"] || "0px";
+            functions.push(`translate(${x}, ${y})`);
+        }
+        if ("rotate" in transform) functions.push(`rotate(${transform.rotate}deg)`);
+        if ("scale" in transform) functions.push(`scale(${transform.scale})`);
+        if ("skew-x" in transform || "skew-y" in transform) {
+            let x = transform["skew-x"] || 0;
+            let y = transform["skew-y"] || 0;
+            functions.push(`skew(${x}deg, ${y}deg)`);
+        }
+        return functions.length === 0 ? "none" : functions.join(" ");
+    }
+    else {
+        return null;
+    }
+}
+
+window.ftd.dependencies.eval_transition = function(transition: any, data: any) {
+    if (typeof transition === 'object' && !!transition) {
+        return `${transition.property} ${transition.duration}s ${transition.easing} ${transition.delay}s`;
+    }
+    else {
+        return null;
+    }
+}
+
+window.ftd.dependencies.eval_animation = function(animation: any, data: any) {
+    if (typeof animation === 'object' && !!animation) {
+        let keyframes = animation.keyframes.map(function (keyframe: any) {
+            let declarations = [];
+            if ("opacity" in keyframe) declarations.push(`opacity: ${keyframe.opacity};`);
+            if ("transform" in keyframe) {
+                let transform = window.ftd.dependencies.eval_transform(keyframe.transform, data);
+                declarations.push(`transform: ${transform};`);
+            }
+            return `${keyframe.at}% { ${declarations.join(" ")} }`;
+        }).join(" ");
+
+        // FNV-1a, the same name as the rule added when the page is built
+        let hash = 0x811c9dc5;
+        for (let i = 0; i < keyframes.length; i++) {
+            hash = Math.imul(hash ^ keyframes.charCodeAt(i), 0x01000193);
+        }
+        let name = `ftd-keyframes-${(hash >>> 0).toString(16).padStart(8, "0")}`;
+        if (!document.getElementById(name)) {
+            let style = document.createElement("style");
+            style.id = name;
+            style.innerHTML = `@keyframes ${name} { ${keyframes} }`;
+            document.head.appendChild(style);
+        }
+
+        let count = animation.infinite ? "infinite" : animation["iteration-count"];
+        let direction = animation.alternate ? "alternate" : "normal";
+        return `${name} ${animation.duration}s ${animation.easing} ${animation.delay}s ${count} ${direction}`;
+    }
+    else {
+        return null;
+    }
+}
+
 window.ftd.utils.add_extra_in_id = function (node_id: string) {
     let element = document.querySelector(`[data-id=\"${node_id}\"]`);
     if (element) {